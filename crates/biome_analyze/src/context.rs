@@ -1,3 +1,4 @@
+use crate::options::{AnalyzerGlobals, GlobalAccess};
 use crate::{registry::RuleRoot, FromServices, Queryable, Rule, RuleKey, ServiceBag};
 use biome_diagnostics::{Error, Result};
 use std::ops::Deref;
@@ -14,7 +15,7 @@ where
     root: &'a RuleRoot<R>,
     bag: &'a ServiceBag,
     services: RuleServiceBag<R>,
-    globals: &'a [&'a str],
+    globals: &'a AnalyzerGlobals,
     file_path: &'a Path,
     options: &'a R::Options,
 }
@@ -27,7 +28,7 @@ where
         query_result: &'a RuleQueryResult<R>,
         root: &'a RuleRoot<R>,
         services: &'a ServiceBag,
-        globals: &'a [&'a str],
+        globals: &'a AnalyzerGlobals,
         file_path: &'a Path,
         options: &'a R::Options,
     ) -> Result<Self, Error> {
@@ -95,7 +96,20 @@ where
 
     /// Checks whether the provided text belongs to globals
     pub fn is_global(&self, text: &str) -> bool {
-        self.globals.contains(&text)
+        matches!(
+            self.globals.access(text),
+            Some(GlobalAccess::Readonly | GlobalAccess::Writable)
+        )
+    }
+
+    /// Returns the access of a global binding declared in the configuration
+    pub fn global_access(&self, text: &str) -> Option<GlobalAccess> {
+        self.globals.access(text)
+    }
+
+    /// Returns the environments that provide the global bindings, if they are configured
+    pub fn global_environments(&self) -> Option<&[String]> {
+        self.globals.environments.as_deref()
     }
 
     /// Returns the source type of the current file
@@ -5,8 +5,10 @@ use biome_diagnostics::{
 };
 use biome_rowan::TextRange;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
 use crate::rule::RuleDiagnostic;
+use crate::RuleKey;
 
 /// Small wrapper for diagnostics during the analysis phase.
 ///
@@ -171,3 +173,27 @@ impl SuppressionDiagnostic {
         self
     }
 }
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "analyzer/timeout", severity = Warning)]
+pub(crate) struct TimeoutDiagnostic {
+    #[location(span)]
+    range: TextRange,
+    #[message]
+    #[description]
+    message: String,
+}
+
+impl TimeoutDiagnostic {
+    pub(crate) fn new(rule: RuleKey, range: TextRange, timeout: Duration) -> Self {
+        Self {
+            range,
+            message: format!(
+                "The analysis of this file exceeded the time limit of {}ms while running the rule {}/{}, the remaining rules weren't run.",
+                timeout.as_millis(),
+                rule.group(),
+                rule.rule_name()
+            ),
+        }
+    }
+}
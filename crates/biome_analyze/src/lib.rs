@@ -5,6 +5,7 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, BinaryHeap};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops;
+use std::time::Instant;
 
 mod categories;
 pub mod context;
@@ -26,9 +27,12 @@ pub use crate::categories::{
     ActionCategory, RefactorKind, RuleCategories, RuleCategory, SourceActionKind,
 };
 pub use crate::diagnostics::AnalyzerDiagnostic;
-use crate::diagnostics::SuppressionDiagnostic;
+use crate::diagnostics::{SuppressionDiagnostic, TimeoutDiagnostic};
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
-pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
+pub use crate::options::{
+    AnalyzerConfiguration, AnalyzerGlobals, AnalyzerOptions, AnalyzerRules, GlobalAccess,
+    RuleTiming, RuleTimings,
+};
 pub use crate::query::{AddVisitor, QueryKey, QueryMatch, Queryable};
 pub use crate::registry::{
     LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
@@ -128,6 +132,13 @@ where
 
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
+        let mut file_suppressions = Vec::new();
+        let mut range_suppressions = Vec::new();
+        let deadline = ctx
+            .options
+            .configuration
+            .timeout
+            .map(|timeout| Instant::now() + timeout);
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
             let runner = PhaseRunner {
@@ -139,12 +150,15 @@ where
                 parse_suppression_comment,
                 line_index: &mut line_index,
                 line_suppressions: &mut line_suppressions,
+                file_suppressions: &mut file_suppressions,
+                range_suppressions: &mut range_suppressions,
                 emit_signal: &mut emit_signal,
                 root: &ctx.root,
                 services: &ctx.services,
                 range: ctx.range,
                 apply_suppression_comment,
                 options: ctx.options,
+                deadline,
             };
 
             // The first phase being run will inspect the tokens and parse the
@@ -160,6 +174,13 @@ where
                 return Some(br);
             }
 
+            // A rule ran past the deadline: the remaining phases are skipped, and
+            // so is the detection of unused suppression comments since not all
+            // the rules were run
+            if query_matcher.timed_out_rule().is_some() {
+                return None;
+            }
+
             // Finish all the active visitors, this is executed outside of the
             // phase runner as it needs mutable access to the service bag (the
             // runner borrows the services for the entire phase)
@@ -171,18 +192,48 @@ where
             }
         }
 
-        for suppression in line_suppressions {
-            if suppression.did_suppress_signal {
-                continue;
+        for suppression in range_suppressions
+            .iter()
+            .filter(|suppression| suppression.end.is_none())
+        {
+            let comment_span = suppression.comment_span;
+            let signal = DiagnosticSignal::new(move || {
+                SuppressionDiagnostic::new(
+                    category!("suppressions/unmatchedRange"),
+                    comment_span,
+                    "This biome-ignore-start comment has no matching biome-ignore-end comment",
+                )
+            });
+
+            if let ControlFlow::Break(br) = (emit_signal)(&signal) {
+                return Some(br);
             }
+        }
 
+        let unused_suppressions = line_suppressions
+            .into_iter()
+            .map(|suppression| (suppression.comment_span, suppression.did_suppress_signal))
+            .chain(
+                file_suppressions
+                    .into_iter()
+                    .map(|suppression| (suppression.comment_span, suppression.did_suppress_signal)),
+            )
+            .chain(
+                range_suppressions
+                    .into_iter()
+                    .map(|suppression| (suppression.comment_span, suppression.did_suppress_signal)),
+            )
+            .filter(|(_, did_suppress_signal)| !did_suppress_signal);
+
+        for (comment_span, _) in unused_suppressions {
             let signal = DiagnosticSignal::new(|| {
                 SuppressionDiagnostic::new(
                     category!("suppressions/unused"),
-                    suppression.comment_span,
+                    comment_span,
                     "Suppression comment is not being used",
                 )
-            });
+            })
+            .with_action(|| remove_suppression_comment(&ctx.root, comment_span));
 
             if let ControlFlow::Break(br) = (emit_signal)(&signal) {
                 return Some(br);
@@ -213,6 +264,11 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     line_index: &'phase mut usize,
     /// Track active suppression comments per-line, ordered by line index
     line_suppressions: &'phase mut Vec<LineSuppression>,
+    /// Suppression comments at the top of the file, that apply to the whole file
+    file_suppressions: &'phase mut Vec<FileSuppression>,
+    /// Suppression comments that apply to the code until a `biome-ignore-end` comment,
+    /// ordered by position
+    range_suppressions: &'phase mut Vec<RangeSuppression>,
     /// Handles analyzer signals emitted by individual rules
     emit_signal: &'phase mut SignalHandler<'analyzer, L, Break>,
     /// Root node of the file being analyzed
@@ -223,6 +279,8 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     range: Option<TextRange>,
     /// Analyzer options
     options: &'phase AnalyzerOptions,
+    /// Point in time after which the analysis of the file should stop
+    deadline: Option<Instant>,
 }
 
 /// Single entry for a suppression comment in the `line_suppressions` buffer
@@ -245,6 +303,47 @@ struct LineSuppression {
     did_suppress_signal: bool,
 }
 
+/// Single entry for a `biome-ignore-all` suppression comment, which suppresses
+/// lint rules in the whole file
+#[derive(Debug)]
+struct FileSuppression {
+    /// Range of source text covered by the suppression comment
+    comment_span: TextRange,
+    /// Set to true if this comment suppresses all the lint rules
+    suppress_all: bool,
+    /// List of all the rules this comment is suppressing
+    suppressed_rules: Vec<RuleFilter<'static>>,
+    /// Set to `true` when a signal matching this suppression was emitted and
+    /// suppressed
+    did_suppress_signal: bool,
+}
+
+/// Single entry for a `biome-ignore-start` suppression comment, which suppresses
+/// lint rules until the matching `biome-ignore-end` comment
+#[derive(Debug)]
+struct RangeSuppression {
+    /// Range of source text covered by the `biome-ignore-start` comment
+    comment_span: TextRange,
+    /// Start of the `biome-ignore-end` comment, or [None] while the range isn't closed
+    end: Option<TextSize>,
+    /// Set to true if this comment suppresses all the lint rules
+    suppress_all: bool,
+    /// List of all the rules this comment is suppressing
+    suppressed_rules: Vec<RuleFilter<'static>>,
+    /// Set to `true` when a signal matching this suppression was emitted and
+    /// suppressed
+    did_suppress_signal: bool,
+}
+
+impl RangeSuppression {
+    /// Whether this comment suppresses the signal of `rule` that starts at `start`
+    fn suppresses(&self, rule: &RuleKey, start: TextSize) -> bool {
+        self.comment_span.end() <= start
+            && self.end.map_or(true, |end| start < end)
+            && (self.suppress_all || self.suppressed_rules.iter().any(|filter| *filter == *rule))
+    }
+}
+
 impl<'a, 'phase, L, Matcher, Break, Diag> PhaseRunner<'a, 'phase, L, Matcher, Break, Diag>
 where
     L: Language,
@@ -254,6 +353,7 @@ where
     /// Runs phase 0 over nodes and tokens to process line breaks and
     /// suppression comments
     fn run_first_phase(mut self) -> ControlFlow<Break> {
+        let mut timed_out = None;
         let iter = self.root.syntax().preorder_with_tokens(Direction::Next);
         for event in iter {
             let node_event = match event {
@@ -271,6 +371,13 @@ where
                 }
             };
 
+            // Once a rule went past the deadline the visitors aren't run
+            // anymore, but the tokens are still processed so the pending
+            // signals are checked against all the suppression comments
+            if timed_out.is_some() {
+                continue;
+            }
+
             // If this is a node event pass it to the visitors for this phase
             for visitor in self.visitors.iter_mut() {
                 let ctx = VisitorContext {
@@ -282,10 +389,17 @@ where
                     signal_queue: &mut self.signal_queue,
                     apply_suppression_comment: self.apply_suppression_comment,
                     options: self.options,
+                    deadline: self.deadline,
                 };
 
                 visitor.visit(&node_event, ctx);
             }
+
+            timed_out = self.query_matcher.timed_out_rule();
+        }
+
+        if let Some((rule, range)) = timed_out {
+            return self.emit_timeout(rule, range);
         }
 
         // Flush all remaining pending events
@@ -307,6 +421,7 @@ where
                     signal_queue: &mut self.signal_queue,
                     apply_suppression_comment: self.apply_suppression_comment,
                     options: self.options,
+                    deadline: self.deadline,
                 };
 
                 visitor.visit(&event, ctx);
@@ -314,11 +429,26 @@ where
 
             // Flush all pending query signals
             self.flush_matches(None)?;
+
+            if let Some((rule, range)) = self.query_matcher.timed_out_rule() {
+                return self.emit_timeout(rule, range);
+            }
         }
 
         ControlFlow::Continue(())
     }
 
+    /// Flush all the signals emitted before the analysis went past its
+    /// deadline, then emit a diagnostic identifying the rule that was running
+    fn emit_timeout(&mut self, rule: RuleKey, range: TextRange) -> ControlFlow<Break> {
+        self.flush_matches(None)?;
+
+        let timeout = self.options.configuration.timeout.unwrap_or_default();
+        let signal = DiagnosticSignal::new(move || TimeoutDiagnostic::new(rule, range, timeout));
+
+        (self.emit_signal)(&signal)
+    }
+
     /// Process the text for a single token, parsing suppression comments and
     /// handling line breaks, then flush all pending query signals in the queue
     /// whose position is less then the end of the token within the file
@@ -372,6 +502,33 @@ where
                 }
             }
 
+            // Signals suppressed in the whole file don't need to look for a
+            // line suppression
+            let file_suppression = self.file_suppressions.iter_mut().find(|suppression| {
+                suppression.suppress_all
+                    || suppression
+                        .suppressed_rules
+                        .iter()
+                        .any(|filter| *filter == entry.rule)
+            });
+            if let Some(suppression) = file_suppression {
+                suppression.did_suppress_signal = true;
+                self.signal_queue.pop().unwrap();
+                continue;
+            }
+
+            // Same for the signals in a range of suppressed code
+            let range_suppression = self
+                .range_suppressions
+                .iter_mut()
+                .rev()
+                .find(|suppression| suppression.suppresses(&entry.rule, start));
+            if let Some(suppression) = range_suppression {
+                suppression.did_suppress_signal = true;
+                self.signal_queue.pop().unwrap();
+                continue;
+            }
+
             // Search for an active suppression comment covering the range of
             // this signal: first try to load the last line suppression and see
             // if it matches the current line index, otherwise perform a binary
@@ -438,6 +595,12 @@ where
     ) -> ControlFlow<Break> {
         let mut suppress_all = false;
         let mut suppressions = Vec::new();
+        let mut suppress_all_in_file = false;
+        let mut file_suppressions = Vec::new();
+        let mut suppress_all_in_range = false;
+        let mut range_suppressions = Vec::new();
+        let mut end_all_ranges = false;
+        let mut ended_ranges = Vec::new();
         let mut has_legacy = false;
 
         for result in (self.parse_suppression_comment)(text) {
@@ -471,11 +634,32 @@ where
                 (self.emit_signal)(&signal)?;
             }
 
-            let rule = match kind {
-                SuppressionKind::Everything => None,
-                SuppressionKind::Rule(rule) => Some(rule),
-                SuppressionKind::MaybeLegacy(rule) => Some(rule),
-                SuppressionKind::Deprecated => None,
+            let (rule, target) = match kind {
+                SuppressionKind::Everything => (None, SuppressionTarget::Line),
+                SuppressionKind::Rule(rule) => (Some(rule), SuppressionTarget::Line),
+                SuppressionKind::MaybeLegacy(rule) => (Some(rule), SuppressionTarget::Line),
+                SuppressionKind::Deprecated => (None, SuppressionTarget::Line),
+                SuppressionKind::FileEverything => (None, SuppressionTarget::File),
+                SuppressionKind::FileRule(rule) => (Some(rule), SuppressionTarget::File),
+                SuppressionKind::RangeStart(rule) => (rule, SuppressionTarget::RangeStart),
+                SuppressionKind::RangeEnd(rule) => (rule, SuppressionTarget::RangeEnd),
+                SuppressionKind::MissingReason => {
+                    if self.options.configuration.require_suppression_reasons
+                        && range_match(self.range, range)
+                    {
+                        let signal = DiagnosticSignal::new(move || {
+                            SuppressionDiagnostic::new(
+                                category!("suppressions/missingReason"),
+                                range,
+                                "Suppression comment has no explanation",
+                            )
+                            .with_severity(Severity::Error)
+                        });
+
+                        (self.emit_signal)(&signal)?;
+                    }
+                    continue;
+                }
             };
 
             if let Some(rule) = rule {
@@ -492,7 +676,12 @@ where
                 };
 
                 if let Some(key) = key {
-                    suppressions.push(key);
+                    match target {
+                        SuppressionTarget::Line => suppressions.push(key),
+                        SuppressionTarget::File => file_suppressions.push(key),
+                        SuppressionTarget::RangeStart => range_suppressions.push(key),
+                        SuppressionTarget::RangeEnd => ended_ranges.push(key),
+                    }
                     has_legacy |= matches!(kind, SuppressionKind::MaybeLegacy(_));
                 } else if range_match(self.range, range) {
                     // Emit a warning for the unknown rule
@@ -513,14 +702,44 @@ where
                     (self.emit_signal)(&signal)?;
                 }
             } else {
-                suppressions.clear();
-                suppress_all = true;
-                // If this if a "suppress all lints" comment, no need to
-                // parse anything else
-                break;
+                match target {
+                    SuppressionTarget::File => {
+                        file_suppressions.clear();
+                        suppress_all_in_file = true;
+                    }
+                    SuppressionTarget::RangeStart => {
+                        range_suppressions.clear();
+                        suppress_all_in_range = true;
+                    }
+                    SuppressionTarget::RangeEnd => {
+                        ended_ranges.clear();
+                        end_all_ranges = true;
+                    }
+                    SuppressionTarget::Line => {
+                        suppressions.clear();
+                        suppress_all = true;
+                        // If this if a "suppress all lints" comment, no need to
+                        // parse anything else
+                        break;
+                    }
+                }
             }
         }
 
+        if suppress_all_in_range || !range_suppressions.is_empty() {
+            self.range_suppressions.push(RangeSuppression {
+                comment_span: range,
+                end: None,
+                suppress_all: suppress_all_in_range,
+                suppressed_rules: range_suppressions,
+                did_suppress_signal: false,
+            });
+        }
+
+        if end_all_ranges || !ended_ranges.is_empty() {
+            self.end_range_suppressions(range, end_all_ranges, &ended_ranges)?;
+        }
+
         // Emit a warning for legacy suppression syntax
         if has_legacy && range_match(self.range, range) {
             let signal = DiagnosticSignal::new(move || {
@@ -538,6 +757,34 @@ where
             (self.emit_signal)(&signal)?;
         }
 
+        if suppress_all_in_file || !file_suppressions.is_empty() {
+            // The suppressions of the whole file must be before the first token,
+            // so they are known before any signal is emitted. Only the
+            // interpreter directive (`#!`) of the file can precede them.
+            let is_top_of_file = is_leading
+                && token.prev_token().map_or(true, |prev_token| {
+                    prev_token.prev_token().is_none() && prev_token.text_trimmed().starts_with("#!")
+                });
+            if is_top_of_file {
+                self.file_suppressions.push(FileSuppression {
+                    comment_span: range,
+                    suppress_all: suppress_all_in_file,
+                    suppressed_rules: file_suppressions,
+                    did_suppress_signal: false,
+                });
+            } else if range_match(self.range, range) {
+                let signal = DiagnosticSignal::new(move || {
+                    SuppressionDiagnostic::new(
+                        category!("suppressions/misplaced"),
+                        range,
+                        "The suppression comments of the whole file must be at the top of the file",
+                    )
+                });
+
+                (self.emit_signal)(&signal)?;
+            }
+        }
+
         if !suppress_all && suppressions.is_empty() {
             return ControlFlow::Continue(());
         }
@@ -577,6 +824,55 @@ where
         ControlFlow::Continue(())
     }
 
+    /// Closes the ranges started by `biome-ignore-start` comments at the
+    /// `biome-ignore-end` comment found at `range`: all the open ranges if
+    /// `end_all` is true, otherwise the last open range of each rule in `rules`
+    fn end_range_suppressions(
+        &mut self,
+        range: TextRange,
+        end_all: bool,
+        rules: &[RuleFilter<'static>],
+    ) -> ControlFlow<Break> {
+        let mut is_matched = false;
+        let open_suppressions = self
+            .range_suppressions
+            .iter_mut()
+            .rev()
+            .filter(|suppression| suppression.end.is_none());
+        if end_all {
+            for suppression in open_suppressions {
+                suppression.end = Some(range.start());
+                is_matched = true;
+            }
+        } else {
+            let mut open_suppressions: Vec<_> = open_suppressions.collect();
+            for rule in rules {
+                let suppression = open_suppressions.iter_mut().find(|suppression| {
+                    suppression.end.is_none()
+                        && (suppression.suppress_all || suppression.suppressed_rules.contains(rule))
+                });
+                if let Some(suppression) = suppression {
+                    suppression.end = Some(range.start());
+                    is_matched = true;
+                }
+            }
+        }
+
+        if !is_matched && range_match(self.range, range) {
+            let signal = DiagnosticSignal::new(move || {
+                SuppressionDiagnostic::new(
+                    category!("suppressions/unmatchedRange"),
+                    range,
+                    "This biome-ignore-end comment has no matching biome-ignore-start comment",
+                )
+            });
+
+            (self.emit_signal)(&signal)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+
     /// Check a piece of source text (token or trivia) for line breaks and
     /// increment the line index accordingly, extending the range of the
     /// current suppression as required
@@ -686,6 +982,31 @@ pub enum SuppressionKind<'a> {
     MaybeLegacy(&'a str),
     /// `rome-ignore` is legacy
     Deprecated,
+    /// A suppression at the top of the file disabling all lints in the file eg. `// biome-ignore-all lint`
+    FileEverything,
+    /// A suppression at the top of the file disabling a specific rule in the file eg. `// biome-ignore-all lint/style/useWhile`
+    FileRule(&'a str),
+    /// A suppression that doesn't explain why the lints are disabled eg. `// biome-ignore lint/style/useWhile:`
+    MissingReason,
+    /// A suppression disabling a specific rule, or all the lints when it's [None], until the
+    /// matching `biome-ignore-end` comment eg. `// biome-ignore-start lint/style/useWhile`
+    RangeStart(Option<&'a str>),
+    /// The end of the suppressions of a specific rule, or of all the suppressions when it's
+    /// [None], started by `biome-ignore-start` comments eg. `// biome-ignore-end`
+    RangeEnd(Option<&'a str>),
+}
+
+/// Which suppressions a [SuppressionKind] adds to
+#[derive(Clone, Copy)]
+enum SuppressionTarget {
+    /// The suppressions of the next line
+    Line,
+    /// The suppressions of the whole file
+    File,
+    /// The suppressions of a range that starts at the comment
+    RangeStart,
+    /// The suppressions of the ranges that end at the comment
+    RangeEnd,
 }
 
 fn update_suppression<L: Language>(
@@ -743,6 +1064,75 @@ fn update_suppression<L: Language>(
     })
 }
 
+/// Returns an action that removes the unused suppression comment at `comment_span`, along with
+/// the whitespace before it and, when the comment starts its line, the line break or the
+/// whitespace after it
+fn remove_suppression_comment<L: Language>(
+    root: &L::Root,
+    comment_span: TextRange,
+) -> Option<AnalyzerAction<L>> {
+    let old_token = root
+        .syntax()
+        .token_at_offset(comment_span.start())
+        .find(|token| token.text_range().contains_range(comment_span))?;
+    let new_token = old_token.clone().detach();
+
+    let is_leading = comment_span.end() <= old_token.text_trimmed_range().start();
+    let old_trivia = if is_leading {
+        old_token.leading_trivia()
+    } else {
+        old_token.trailing_trivia()
+    };
+
+    let old_trivia: Vec<_> = old_trivia.pieces().collect();
+    let index = old_trivia
+        .iter()
+        .position(|piece| piece.text_range() == comment_span)?;
+
+    let mut start = index;
+    while start > 0 && old_trivia[start - 1].is_whitespace() {
+        start -= 1;
+    }
+    let mut end = index + 1;
+    let is_own_line = start == 0 || old_trivia[start - 1].is_newline();
+    if is_leading
+        && is_own_line
+        && old_trivia
+            .get(end)
+            .is_some_and(|piece| piece.is_newline() || piece.is_whitespace())
+    {
+        end += 1;
+    }
+
+    let new_trivia: Vec<(TriviaPieceKind, &str)> = old_trivia
+        .iter()
+        .enumerate()
+        .filter(|(piece_index, _)| !(start..end).contains(piece_index))
+        .map(|(_, piece)| (piece.kind(), piece.text()))
+        .collect();
+
+    let new_token = if is_leading {
+        new_token.with_leading_trivia(new_trivia)
+    } else {
+        new_token.with_trailing_trivia(new_trivia)
+    };
+
+    let mut mutation = BatchMutation::new(root.syntax().clone());
+    mutation.replace_token_discard_trivia(old_token, new_token);
+
+    Some(AnalyzerAction {
+        rule_name: None,
+        category: ActionCategory::QuickFix,
+        // The suppression may be unused only because some rules were disabled for this run
+        applicability: Applicability::MaybeIncorrect,
+        message: markup! {
+            "Remove the unused suppression comment"
+        }
+        .to_owned(),
+        mutation,
+    })
+}
+
 /// Payload received by the function responsible to mark a suppression comment
 pub struct SuppressionCommentEmitterPayload<'a, L: Language> {
     /// The possible offset found in the [TextRange] of the emitted diagnostic
@@ -751,6 +1141,8 @@ pub struct SuppressionCommentEmitterPayload<'a, L: Language> {
     pub mutation: &'a mut BatchMutation<L>,
     /// A string equals to "rome-ignore: lint(<RULE_GROUP>/<RULE_NAME>)"
     pub suppression_text: &'a str,
+    /// The explanation of the suppression comment, if the consumer provided one
+    pub suppression_reason: Option<&'a str>,
     /// The original range of the diagnostic where the rule was triggered
     pub diagnostic_text_range: &'a TextRange,
 }
@@ -7,6 +7,7 @@ use std::{
     any::{Any, TypeId},
     cmp::Ordering,
     collections::BinaryHeap,
+    time::Instant,
 };
 
 /// The [QueryMatcher] trait is responsible of running lint rules on
@@ -16,6 +17,12 @@ use std::{
 pub trait QueryMatcher<L: Language> {
     /// Execute a single query match
     fn match_query(&mut self, params: MatchQueryParams<L>);
+
+    /// Returns the rule that was running when the analysis went past its
+    /// deadline, along with the range of the query it was matching
+    fn timed_out_rule(&self) -> Option<(RuleKey, TextRange)> {
+        None
+    }
 }
 
 /// Parameters provided to [QueryMatcher::match_query] and require to run lint rules
@@ -27,6 +34,8 @@ pub struct MatchQueryParams<'phase, 'query, L: Language> {
     pub signal_queue: &'query mut BinaryHeap<SignalEntry<'phase, L>>,
     pub apply_suppression_comment: SuppressionCommentEmitter<L>,
     pub options: &'phase AnalyzerOptions,
+    /// Point in time after which no more rules should be run
+    pub deadline: Option<Instant>,
 }
 
 /// Wrapper type for a [QueryMatch]
@@ -192,6 +201,10 @@ where
         (self.func)(&params);
         self.inner.match_query(params);
     }
+
+    fn timed_out_rule(&self) -> Option<(RuleKey, TextRange)> {
+        self.inner.timed_out_rule()
+    }
 }
 
 #[cfg(test)]
@@ -409,4 +422,113 @@ mod tests {
             ]
         );
     }
+
+    /// Emits a diagnostic for the first literal expression, then reports it
+    /// went past the deadline of the analysis
+    #[derive(Default)]
+    struct TimeoutMatcher {
+        timed_out: Option<(RuleKey, TextRange)>,
+    }
+
+    impl QueryMatcher<RawLanguage> for TimeoutMatcher {
+        fn match_query(&mut self, params: MatchQueryParams<RawLanguage>) {
+            let node = params.query.downcast::<SyntaxNode<RawLanguage>>().unwrap();
+
+            if self.timed_out.is_some() || node.kind() != RawLanguageKind::LITERAL_EXPRESSION {
+                return;
+            }
+
+            let span = node.text_trimmed_range();
+            params.signal_queue.push(SignalEntry {
+                signal: Box::new(DiagnosticSignal::new(move || TestDiagnostic { span })),
+                rule: RuleKey::new("group", "rule"),
+                text_range: span,
+            });
+
+            self.timed_out = Some((RuleKey::new("group", "rule"), span));
+        }
+
+        fn timed_out_rule(&self) -> Option<(RuleKey, TextRange)> {
+            self.timed_out
+        }
+    }
+
+    #[test]
+    fn timeout() {
+        let root = {
+            let mut builder = RawSyntaxTreeBuilder::new();
+
+            builder.start_node(RawLanguageKind::ROOT);
+            builder.start_node(RawLanguageKind::SEPARATED_EXPRESSION_LIST);
+
+            builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+            builder.token(RawLanguageKind::NUMBER_TOKEN, "1");
+            builder.finish_node();
+
+            builder.token(RawLanguageKind::SEMICOLON_TOKEN, ";");
+
+            builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+            builder.token_with_trivia(
+                RawLanguageKind::NUMBER_TOKEN,
+                "//group/rule\n2",
+                &[
+                    TriviaPiece::new(TriviaPieceKind::SingleLineComment, 12),
+                    TriviaPiece::new(TriviaPieceKind::Newline, 1),
+                ],
+                &[],
+            );
+            builder.finish_node();
+
+            builder.finish_node();
+            builder.finish_node();
+
+            RawLanguageRoot::unwrap_cast(builder.finish())
+        };
+
+        let mut diagnostics = Vec::new();
+        let mut emit_signal = |signal: &dyn AnalyzerSignal<RawLanguage>| -> ControlFlow<Never> {
+            let diag = signal.diagnostic().expect("diagnostic");
+            let range = diag.get_span().expect("range");
+            let code = diag.category().expect("code");
+
+            diagnostics.push((code, range));
+            ControlFlow::Continue(())
+        };
+
+        let mut metadata = MetadataRegistry::default();
+        metadata.insert_rule("group", "rule");
+
+        let mut analyzer = Analyzer::new(
+            &metadata,
+            TimeoutMatcher::default(),
+            |comment| -> Vec<Result<_, Infallible>> {
+                vec![Ok(SuppressionKind::Rule(comment.trim_start_matches("//")))]
+            },
+            |_| unreachable!(),
+            &mut emit_signal,
+        );
+
+        analyzer.add_visitor(Phases::Syntax, Box::<SyntaxVisitor<RawLanguage>>::default());
+
+        let ctx: AnalyzerContext<RawLanguage> = AnalyzerContext {
+            root,
+            range: None,
+            services: ServiceBag::default(),
+            options: &AnalyzerOptions::default(),
+        };
+
+        let result: Option<Never> = analyzer.run(ctx);
+        assert!(result.is_none());
+
+        // The second literal isn't analyzed, and the suppression comment
+        // isn't reported as unused
+        let span = TextRange::new(TextSize::from(0), TextSize::from(1));
+        assert_eq!(
+            diagnostics.as_slice(),
+            &[
+                (category!("args/fileNotFound"), span),
+                (category!("analyzer/timeout"), span),
+            ]
+        );
+    }
 }
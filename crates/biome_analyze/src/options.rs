@@ -1,9 +1,13 @@
 use rustc_hash::FxHashMap;
 
 use crate::{Rule, RuleKey};
+use biome_diagnostics::Applicability;
 use std::any::{Any, TypeId};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
 
 /// A convenient new type data structure to store the options that belong to a rule
 #[derive(Debug)]
@@ -27,19 +31,33 @@ impl RuleOptions {
     }
 }
 
-/// A convenient new type data structure to insert and get rules
+/// A convenient data structure to insert and get rules
 #[derive(Debug, Default)]
-pub struct AnalyzerRules(FxHashMap<RuleKey, RuleOptions>);
+pub struct AnalyzerRules {
+    options: FxHashMap<RuleKey, RuleOptions>,
+    /// The applicability of the fixes of the rules, when it's overridden by the configuration
+    applicabilities: FxHashMap<RuleKey, Applicability>,
+}
 
 impl AnalyzerRules {
     /// It tracks the options of a specific rule
     pub fn push_rule(&mut self, rule_key: RuleKey, options: RuleOptions) {
-        self.0.insert(rule_key, options);
+        self.options.insert(rule_key, options);
     }
 
     /// It retrieves the options of a stored rule, given its name
     pub fn get_rule_options<O: 'static>(&self, rule_key: &RuleKey) -> Option<&O> {
-        self.0.get(rule_key).map(|o| o.value::<O>())
+        self.options.get(rule_key).map(|o| o.value::<O>())
+    }
+
+    /// It overrides the applicability of the fixes of a specific rule
+    pub fn push_rule_applicability(&mut self, rule_key: RuleKey, applicability: Applicability) {
+        self.applicabilities.insert(rule_key, applicability);
+    }
+
+    /// It retrieves the applicability of the fixes of a rule, when it's overridden
+    pub fn get_rule_applicability(&self, rule_key: &RuleKey) -> Option<Applicability> {
+        self.applicabilities.get(rule_key).copied()
     }
 }
 
@@ -52,7 +70,53 @@ pub struct AnalyzerConfiguration {
     /// A collections of bindings that the analyzers should consider as "external".
     ///
     /// For example, lint rules should ignore them.
-    pub globals: Vec<String>,
+    pub globals: AnalyzerGlobals,
+
+    /// The maximum amount of time the analyzer can spend on a single file.
+    ///
+    /// When it's exceeded, the analysis of the file stops and a diagnostic
+    /// reports the rule that was running.
+    pub timeout: Option<Duration>,
+
+    /// Whether the suppression comments without an explanation are reported
+    pub require_suppression_reasons: bool,
+
+    /// The explanation written in the suppression comments added by the suppression actions.
+    /// When it's [None], a placeholder is written instead.
+    pub suppression_reason: Option<String>,
+}
+
+/// How a global binding declared in the configuration can be used
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GlobalAccess {
+    /// The binding can be read, but not assigned
+    Readonly,
+    /// The binding can be read and assigned
+    Writable,
+    /// The binding isn't available, even if an environment provides it
+    Off,
+}
+
+/// The global bindings that are available to the analyzed files
+#[derive(Debug, Default)]
+pub struct AnalyzerGlobals {
+    /// The environments that provide global bindings, e.g. `browser` or `node`.
+    ///
+    /// When it's [None], the rules fall back to their default environments.
+    pub environments: Option<Vec<String>>,
+
+    /// The global bindings declared in the configuration, along with their access
+    pub bindings: Vec<(String, GlobalAccess)>,
+}
+
+impl AnalyzerGlobals {
+    /// Returns the access of a global binding declared in the configuration
+    pub fn access(&self, name: &str) -> Option<GlobalAccess> {
+        self.bindings
+            .iter()
+            .find(|(binding, _)| binding == name)
+            .map(|(_, access)| *access)
+    }
 }
 
 /// A set of information useful to the analyzer infrastructure
@@ -63,14 +127,40 @@ pub struct AnalyzerOptions {
 
     /// The file that is being analyzed
     pub file_path: PathBuf,
+
+    /// When it's set, the time spent running each rule is recorded in it
+    pub rule_timings: Option<RuleTimings>,
+}
+
+/// The time spent running each rule during an analysis
+#[derive(Debug, Default)]
+pub struct RuleTimings {
+    rules: Mutex<BTreeMap<RuleKey, RuleTiming>>,
+}
+
+/// The time spent running a rule, and the number of times it ran
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RuleTiming {
+    pub duration: Duration,
+    pub runs: u64,
+}
+
+impl RuleTimings {
+    pub(crate) fn record(&self, rule: RuleKey, duration: Duration) {
+        let mut rules = self.rules.lock().unwrap();
+        let timing = rules.entry(rule).or_default();
+        timing.duration += duration;
+        timing.runs += 1;
+    }
+
+    /// Returns the time spent running each rule that ran at least once
+    pub fn into_timings(self) -> BTreeMap<RuleKey, RuleTiming> {
+        self.rules.into_inner().unwrap()
+    }
 }
 impl AnalyzerOptions {
-    pub fn globals(&self) -> Vec<&str> {
-        self.configuration
-            .globals
-            .iter()
-            .map(|global| global.as_str())
-            .collect()
+    pub fn globals(&self) -> &AnalyzerGlobals {
+        &self.configuration.globals
     }
 
     pub fn rule_options<R: 'static>(&self) -> Option<R::Options>
@@ -83,4 +173,15 @@ impl AnalyzerOptions {
             .get_rule_options::<R::Options>(&RuleKey::rule::<R>())
             .map(R::Options::clone)
     }
+
+    /// Returns the applicability of the fixes of the rule `R`, when the configuration
+    /// overrides it
+    pub fn rule_applicability<R>(&self) -> Option<Applicability>
+    where
+        R: Rule + 'static,
+    {
+        self.configuration
+            .rules
+            .get_rule_applicability(&RuleKey::rule::<R>())
+    }
 }
@@ -4,15 +4,16 @@ use crate::{
     query::{QueryKey, Queryable},
     signals::RuleSignal,
     AddVisitor, AnalysisFilter, GroupCategory, QueryMatcher, Rule, RuleGroup, RuleKey,
-    RuleMetadata, ServiceBag, SignalEntry, Visitor,
+    RuleMetadata, RuleTimings, ServiceBag, SignalEntry, Visitor,
 };
 use biome_diagnostics::Error;
-use biome_rowan::{AstNode, Language, RawSyntaxKind, SyntaxKind, SyntaxNode};
+use biome_rowan::{AstNode, Language, RawSyntaxKind, SyntaxKind, SyntaxNode, TextRange};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::{
     any::TypeId,
     borrow,
     collections::{BTreeMap, BTreeSet},
+    time::Instant,
 };
 
 /// Defines all the phases that the [RuleRegistry] supports.
@@ -103,6 +104,9 @@ impl<L: Language> RegistryVisitor<L> for MetadataRegistry {
 pub struct RuleRegistry<L: Language> {
     /// Holds a collection of rules for each phase.
     phase_rules: [PhaseRules<L>; 2],
+    /// Set to the rule that was running, and the range of the query it was
+    /// matching, when the analysis went past its deadline
+    timed_out: Option<(RuleKey, TextRange)>,
 }
 
 impl<L: Language + Default> RuleRegistry<L> {
@@ -115,6 +119,7 @@ impl<L: Language + Default> RuleRegistry<L> {
             root,
             registry: RuleRegistry {
                 phase_rules: Default::default(),
+                timed_out: None,
             },
             visitors: BTreeMap::default(),
             services: ServiceBag::default(),
@@ -258,6 +263,11 @@ impl<L: Language> RuleRegistryBuilder<'_, L> {
 
 impl<L: Language + 'static> QueryMatcher<L> for RuleRegistry<L> {
     fn match_query(&mut self, mut params: MatchQueryParams<L>) {
+        // Once the deadline has been exceeded no other rule gets to run
+        if self.timed_out.is_some() {
+            return;
+        }
+
         let phase = &mut self.phase_rules[params.phase as usize];
 
         let query_type = params.query.type_id();
@@ -288,8 +298,20 @@ impl<L: Language + 'static> QueryMatcher<L> for RuleRegistry<L> {
             let state = &mut phase.rule_states[rule.state_index];
             // TODO: #3394 track error in the signal queue
             let _ = (rule.run)(&mut params, state);
+
+            if params
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                self.timed_out = Some((rule.key, params.query.text_range()));
+                return;
+            }
         }
     }
+
+    fn timed_out_rule(&self) -> Option<(RuleKey, TextRange)> {
+        self.timed_out
+    }
 }
 
 /// [SyntaxKindRules] holds a collection of [Rule]s that match a specific [SyntaxKind] value
@@ -356,6 +378,7 @@ impl RegistryRuleMetadata {
 #[derive(Copy, Clone)]
 pub struct RegistryRule<L: Language> {
     run: RuleExecutor<L>,
+    key: RuleKey,
     state_index: usize,
 }
 
@@ -378,6 +401,29 @@ impl<L: Language> RuleSuppressions<L> {
     }
 }
 
+/// Records the time spent running a rule in [RuleTimings] when it's dropped
+struct RuleTimer<'a> {
+    timings: &'a RuleTimings,
+    rule: RuleKey,
+    start: Instant,
+}
+
+impl<'a> RuleTimer<'a> {
+    fn new(timings: &'a RuleTimings, rule: RuleKey) -> Self {
+        Self {
+            timings,
+            rule,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for RuleTimer<'_> {
+    fn drop(&mut self) {
+        self.timings.record(self.rule, self.start.elapsed());
+    }
+}
+
 /// Executor for rule as a generic function pointer
 type RuleExecutor<L> = fn(&mut MatchQueryParams<L>, &mut RuleState<L>) -> Result<(), Error>;
 
@@ -406,6 +452,13 @@ impl<L: Language + Default> RegistryRule<L> {
                 }
             }
 
+            let _span = tracing::trace_span!("rule", name = R::METADATA.name).entered();
+            let options = params.options;
+            let _timer = options
+                .rule_timings
+                .as_ref()
+                .map(|timings| RuleTimer::new(timings, RuleKey::rule::<R>()));
+
             // SAFETY: The rule should never get executed in the first place
             // if the query doesn't match
             let query_result = params.query.downcast_ref().unwrap();
@@ -416,7 +469,7 @@ impl<L: Language + Default> RegistryRule<L> {
                 &query_result,
                 params.root,
                 params.services,
-                &globals,
+                globals,
                 &params.options.file_path,
                 &options,
             ) {
@@ -451,6 +504,7 @@ impl<L: Language + Default> RegistryRule<L> {
 
         Self {
             run: run::<R>,
+            key: RuleKey::rule::<R>(),
             state_index,
         }
     }
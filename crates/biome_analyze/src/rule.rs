@@ -200,6 +200,23 @@ macro_rules! declare_category {
             }
         }
 
+        pub(self) use $id as Category;
+    };
+    // The groups can be compiled out with `cfg` attributes, so the language of the category
+    // can't be inferred from them
+    ( $vis:vis $id:ident { kind: $kind:ident, language: $language:ty, groups: [ $( $( #[$meta:meta] )* $( $group:ident )::* , )* ] } ) => {
+        $vis enum $id {}
+
+        impl $crate::GroupCategory for $id {
+            type Language = $language;
+
+            const CATEGORY: $crate::RuleCategory = $crate::RuleCategory::$kind;
+
+            fn record_groups<V: $crate::RegistryVisitor<Self::Language> + ?Sized>(registry: &mut V) {
+                $( $( #[$meta] )* registry.record_group::<$( $group )::*>(); )*
+            }
+        }
+
         pub(self) use $id as Category;
     };
 }
@@ -353,9 +370,12 @@ pub trait Rule: RuleMeta + Sized {
 
     /// Create a code action that allows to suppress the rule. The function
     /// returns the node to which the suppression comment is applied.
+    ///
+    /// The suppression comment is explained by `suppression_reason`, when it's provided.
     fn suppress(
         ctx: &RuleContext<Self>,
         text_range: &TextRange,
+        suppression_reason: Option<&str>,
         apply_suppression_comment: SuppressionCommentEmitter<RuleLanguage<Self>>,
     ) -> Option<SuppressAction<RuleLanguage<Self>>>
     where
@@ -374,6 +394,7 @@ pub trait Rule: RuleMeta + Sized {
             let mut mutation = root.begin();
             apply_suppression_comment(SuppressionCommentEmitterPayload {
                 suppression_text: suppression_text.as_str(),
+                suppression_reason,
                 mutation: &mut mutation,
                 token_offset: token,
                 diagnostic_text_range: text_range,
@@ -388,6 +409,45 @@ pub trait Rule: RuleMeta + Sized {
         }
     }
 
+    /// Create a code action that allows to suppress the rule in the whole file,
+    /// with a suppression comment at the top of the file.
+    fn suppress_in_file(
+        ctx: &RuleContext<Self>,
+        suppression_reason: Option<&str>,
+        apply_suppression_comment: SuppressionCommentEmitter<RuleLanguage<Self>>,
+    ) -> Option<SuppressAction<RuleLanguage<Self>>>
+    where
+        Self: 'static,
+    {
+        if <Self::Group as RuleGroup>::Category::CATEGORY == RuleCategory::Lint {
+            let rule_category = format!(
+                "lint/{}/{}",
+                <Self::Group as RuleGroup>::NAME,
+                Self::METADATA.name
+            );
+            let suppression_text = format!("biome-ignore-all {}", rule_category);
+            let root = ctx.root();
+            let text_range = TextRange::empty(root.syntax().text_range().start());
+            let token = root.syntax().token_at_offset(text_range.start());
+            let mut mutation = root.begin();
+            apply_suppression_comment(SuppressionCommentEmitterPayload {
+                suppression_text: suppression_text.as_str(),
+                suppression_reason,
+                mutation: &mut mutation,
+                token_offset: token,
+                diagnostic_text_range: &text_range,
+            });
+
+            Some(SuppressAction {
+                mutation,
+                message: markup! { "Suppress rule " {rule_category} " for the whole file" }
+                    .to_owned(),
+            })
+        } else {
+            None
+        }
+    }
+
     /// Returns a mutation to apply to the code
     fn transform(
         _ctx: &RuleContext<Self>,
@@ -435,7 +495,12 @@ impl Advices for RuleAdvice {
                 detail.log_category,
                 &markup! { {detail.message} }.to_owned(),
             )?;
-            visitor.record_frame(Location::builder().span(&detail.range).build())?;
+            visitor.record_frame(
+                Location::builder()
+                    .resource(&detail.file_path)
+                    .span(&detail.range)
+                    .build(),
+            )?;
         }
         // we then print notes
         for (log_category, note) in &self.notes {
@@ -469,6 +534,8 @@ pub struct Detail {
     pub log_category: LogCategory,
     pub message: MarkupBuf,
     pub range: Option<TextRange>,
+    /// The path of the file of `range`, when it's not the file of the diagnostic
+    pub file_path: Option<String>,
 }
 
 impl RuleDiagnostic {
@@ -517,6 +584,7 @@ impl RuleDiagnostic {
             log_category: LogCategory::Info,
             message: markup!({ msg }).to_owned(),
             range: span.as_span(),
+            file_path: None,
         });
         self
     }
@@ -526,6 +594,26 @@ impl RuleDiagnostic {
         self.label(span, msg)
     }
 
+    /// Attaches a detailed message to this [`RuleDiagnostic`], pointing to a span in
+    /// another file, e.g. the conflicting declaration of a project-level rule.
+    ///
+    /// The given span has to be in the file at `file_path`, which is the path of the file
+    /// as the workspace knows it.
+    pub fn detail_in_file(
+        mut self,
+        file_path: impl Into<String>,
+        span: impl AsSpan,
+        msg: impl Display,
+    ) -> Self {
+        self.rule_advice.details.push(Detail {
+            log_category: LogCategory::Info,
+            message: markup!({ msg }).to_owned(),
+            range: span.as_span(),
+            file_path: Some(file_path.into()),
+        });
+        self
+    }
+
     /// Adds a footer to this [`RuleDiagnostic`], which will be displayed under the actual error.
     fn footer(mut self, log_category: LogCategory, msg: impl Display) -> Self {
         self.rule_advice
@@ -352,7 +352,7 @@ where
             &self.query_result,
             self.root,
             self.services,
-            &globals,
+            globals,
             &self.options.file_path,
             &options,
         )
@@ -368,7 +368,7 @@ where
             &self.query_result,
             self.root,
             self.services,
-            &globals,
+            globals,
             &self.options.file_path,
             &options,
         )
@@ -379,14 +379,33 @@ where
                 actions.push(AnalyzerAction {
                     rule_name: Some((<R::Group as RuleGroup>::NAME, R::METADATA.name)),
                     category: action.category,
-                    applicability: action.applicability,
+                    applicability: self
+                        .options
+                        .rule_applicability::<R>()
+                        .unwrap_or(action.applicability),
                     mutation: action.mutation,
                     message: action.message,
                 });
             };
             if let Some(text_range) = R::text_range(&ctx, &self.state) {
+                let suppression_reason = self.options.configuration.suppression_reason.as_deref();
+                if let Some(suppression_action) = R::suppress(
+                    &ctx,
+                    &text_range,
+                    suppression_reason,
+                    self.apply_suppression_comment,
+                ) {
+                    let action = AnalyzerAction {
+                        rule_name: Some((<R::Group as RuleGroup>::NAME, R::METADATA.name)),
+                        category: ActionCategory::Other(Cow::Borrowed(SUPPRESSION_ACTION_CATEGORY)),
+                        applicability: Applicability::Always,
+                        mutation: suppression_action.mutation,
+                        message: suppression_action.message,
+                    };
+                    actions.push(action);
+                }
                 if let Some(suppression_action) =
-                    R::suppress(&ctx, &text_range, self.apply_suppression_comment)
+                    R::suppress_in_file(&ctx, suppression_reason, self.apply_suppression_comment)
                 {
                     let action = AnalyzerAction {
                         rule_name: Some((<R::Group as RuleGroup>::NAME, R::METADATA.name)),
@@ -412,7 +431,7 @@ where
             &self.query_result,
             self.root,
             self.services,
-            &globals,
+            globals,
             &self.options.file_path,
             &options,
         )
@@ -6,6 +6,7 @@ use crate::{
 };
 use biome_rowan::{AstNode, Language, SyntaxNode, TextRange, WalkEvent};
 use std::collections::BinaryHeap;
+use std::time::Instant;
 
 /// Mutable context objects shared by all visitors
 pub struct VisitorContext<'phase, 'query, L: Language> {
@@ -17,6 +18,7 @@ pub struct VisitorContext<'phase, 'query, L: Language> {
     pub(crate) signal_queue: &'query mut BinaryHeap<SignalEntry<'phase, L>>,
     pub apply_suppression_comment: SuppressionCommentEmitter<L>,
     pub options: &'phase AnalyzerOptions,
+    pub(crate) deadline: Option<Instant>,
 }
 
 impl<'phase, 'query, L: Language> VisitorContext<'phase, 'query, L> {
@@ -29,6 +31,7 @@ impl<'phase, 'query, L: Language> VisitorContext<'phase, 'query, L> {
             signal_queue: self.signal_queue,
             apply_suppression_comment: self.apply_suppression_comment,
             options: self.options,
+            deadline: self.deadline,
         })
     }
 }
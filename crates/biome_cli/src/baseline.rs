@@ -0,0 +1,170 @@
+use crate::keys::{file_key, fnv1a, FNV_OFFSET_BASIS};
+use crate::CliDiagnostic;
+use biome_diagnostics::Error;
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_formatter::context::JsonFormatOptions;
+use biome_json_parser::{parse_json, JsonParserOptions};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The name of the file, next to the configuration file, that records the known diagnostics
+pub(crate) const BASELINE_FILE_NAME: &str = ".biome-baseline.json";
+
+/// The fingerprints of the diagnostics of each file, with their number of occurrences, by path
+/// relative to the directory of the baseline file
+type BaselineFiles = BTreeMap<String, BTreeMap<String, usize>>;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BaselineContent {
+    /// The diagnostics recorded for each file, by path
+    #[serde(default)]
+    files: BaselineFiles,
+}
+
+/// The known diagnostics of a project, recorded in the baseline file with `--write-baseline`.
+///
+/// With `--baseline`, the diagnostics recorded in the baseline aren't reported, while the new
+/// ones are. The recorded diagnostics that don't occur anymore in the processed files are
+/// removed from the baseline file.
+#[derive(Debug)]
+pub(crate) struct Baseline {
+    path: PathBuf,
+    /// The working directory, against which the paths of the processed files are resolved
+    working_directory: PathBuf,
+    /// The directory of the baseline file, against which the paths of the files are recorded
+    root_directory: PathBuf,
+    /// Whether all the diagnostics of the processed files are recorded, instead of only the
+    /// ones that were already recorded
+    write: bool,
+    /// The diagnostics recorded in the baseline file when it was loaded
+    known: BaselineFiles,
+    /// The diagnostics recorded for the files processed so far
+    found: Mutex<BaselineFiles>,
+}
+
+impl Baseline {
+    /// Loads the baseline file found in `directory_path`. When `write` is `true`, the
+    /// diagnostics of the processed files are recorded again, and the file doesn't need to exist.
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        directory_path: &Path,
+        write: bool,
+    ) -> Result<Self, CliDiagnostic> {
+        let path = directory_path.join(BASELINE_FILE_NAME);
+        let known = match fs.open_with_options(&path, OpenOptions::default().read(true)) {
+            Ok(mut file) => {
+                let mut content = String::new();
+                file.read_to_string(&mut content)?;
+                let content: BaselineContent = serde_json::from_str(&content).map_err(|error| {
+                    CliDiagnostic::incompatible_end_configuration(format!(
+                        "The baseline file {} is invalid: {error}. Run the command with --write-baseline to record it again.",
+                        path.display()
+                    ))
+                })?;
+                content.files
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                if !write {
+                    return Err(CliDiagnostic::incompatible_end_configuration(format!(
+                        "The argument --baseline was used, but the baseline file {} doesn't exist. Run the command with --write-baseline to record it.",
+                        path.display()
+                    )));
+                }
+                BaselineFiles::default()
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        let working_directory = fs.working_directory().unwrap_or_default();
+        Ok(Self {
+            path,
+            root_directory: working_directory.join(directory_path),
+            working_directory,
+            write,
+            known,
+            found: Mutex::new(BaselineFiles::default()),
+        })
+    }
+
+    /// Removes from `diagnostics` the diagnostics of the file at `path`, whose content is
+    /// `content`, that are recorded in the baseline. With `--write-baseline`, all the
+    /// diagnostics are recorded and removed.
+    pub(crate) fn filter_diagnostics(
+        &self,
+        path: &Path,
+        content: &str,
+        diagnostics: &mut Vec<Error>,
+    ) {
+        let file = file_key(&self.root_directory, &self.working_directory.join(path));
+        let mut remaining = self.known.get(&file).cloned().unwrap_or_default();
+        let mut found: BTreeMap<String, usize> = BTreeMap::new();
+        diagnostics.retain(|diagnostic| {
+            let fingerprint = fingerprint(content, diagnostic);
+            if !self.write {
+                match remaining.get_mut(&fingerprint) {
+                    Some(count) if *count > 0 => *count -= 1,
+                    _ => return true,
+                }
+            }
+            *found.entry(fingerprint).or_default() += 1;
+            false
+        });
+        self.found.lock().unwrap().insert(file, found);
+    }
+
+    /// Writes the baseline file. The diagnostics recorded for the processed files replace the
+    /// ones of the baseline file, and the other files keep their diagnostics.
+    pub(crate) fn save(&self, fs: &dyn FileSystem) -> io::Result<()> {
+        let mut files = self.known.clone();
+        for (file, found) in self.found.lock().unwrap().iter() {
+            if found.is_empty() {
+                files.remove(file);
+            } else {
+                files.insert(file.clone(), found.clone());
+            }
+        }
+        if !self.write && files == self.known {
+            return Ok(());
+        }
+
+        let content = serde_json::to_string_pretty(&BaselineContent { files })?;
+        let parsed = parse_json(&content, JsonParserOptions::default());
+        let formatted =
+            biome_json_formatter::format_node(JsonFormatOptions::default(), &parsed.syntax())
+                .expect("valid format document")
+                .print()
+                .expect("valid format document");
+
+        fs.open_with_options(
+            &self.path,
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?
+        .set_content(formatted.as_code().as_bytes())
+    }
+}
+
+/// The fingerprint of a diagnostic: its category, and a hash of the code it reports. The
+/// whitespace of the code is collapsed, so the fingerprint doesn't change when the code is
+/// moved or reformatted.
+fn fingerprint(content: &str, diagnostic: &Error) -> String {
+    let category = diagnostic.category().map_or("", |category| category.name());
+    let code = diagnostic
+        .location()
+        .span
+        .and_then(|span| content.get(usize::from(span.start())..usize::from(span.end())))
+        .unwrap_or_default();
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for word in code.split_whitespace() {
+        hash = fnv1a(hash, word.as_bytes());
+        hash = fnv1a(hash, b" ");
+    }
+    format!("{category}:{hash:016x}")
+}
@@ -0,0 +1,220 @@
+use crate::keys::{file_key, fnv1a, FNV_OFFSET_BASIS};
+use crate::{Execution, TraversalMode, VERSION};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_service::configuration::NestedConfigurations;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The name of the file, in the working directory, that records the files without diagnostics
+pub(crate) const CACHE_FILE_NAME: &str = ".biome-cache.json";
+
+/// The key of the last result without diagnostics of each file, by command
+type CacheFiles = BTreeMap<String, BTreeMap<String, String>>;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct CacheContent {
+    /// The version of Biome that wrote the cache
+    #[serde(default)]
+    version: String,
+    /// The keys recorded for each file, by path
+    #[serde(default)]
+    files: CacheFiles,
+}
+
+/// The files that didn't emit any diagnostic in the previous runs, recorded in the cache file
+/// with `--cache`.
+///
+/// The result of a file is identified by a key, a hash of the version of Biome, of the command,
+/// of the configuration applied to the file and of its content. When the key of a file matches
+/// the one recorded in the cache, the file is skipped.
+#[derive(Debug)]
+pub(crate) struct ResultCache {
+    path: PathBuf,
+    /// The working directory, against which the paths of the files are recorded
+    working_directory: PathBuf,
+    /// The command whose results are cached, along with the options that change its result,
+    /// e.g. `lint --rule=style/useConst`
+    command: String,
+    /// The keys recorded in the cache file when it was loaded
+    known: CacheFiles,
+    /// The keys of the files processed so far, or `None` when the file emitted diagnostics
+    found: Mutex<BTreeMap<String, Option<String>>>,
+    /// The hash of the configuration of the directories processed so far
+    configurations: Mutex<FxHashMap<PathBuf, u64>>,
+}
+
+impl ResultCache {
+    /// Loads the cache file of the working directory, to skip the files of `execution` that
+    /// didn't change. A cache file that is invalid, or written by another version of Biome, is
+    /// replaced.
+    ///
+    /// Returns `None` when the results of `execution` can't be cached: when it writes files, or
+    /// when its diagnostics depend on more than the content of the files.
+    pub(crate) fn load(fs: &dyn FileSystem, execution: &Execution) -> Option<Self> {
+        let command = command_key(execution)?;
+        let working_directory = fs.working_directory().unwrap_or_default();
+        let path = working_directory.join(CACHE_FILE_NAME);
+        let known = fs
+            .open_with_options(&path, OpenOptions::default().read(true))
+            .ok()
+            .and_then(|mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content).ok()?;
+                serde_json::from_str::<CacheContent>(&content).ok()
+            })
+            .filter(|content| content.version == VERSION)
+            .map(|content| content.files)
+            .unwrap_or_default();
+
+        Some(Self {
+            path,
+            working_directory,
+            command,
+            known,
+            found: Mutex::default(),
+            configurations: Mutex::default(),
+        })
+    }
+
+    /// Returns the key of the result of the file at `path`, whose content is `content`
+    pub(crate) fn result_key(
+        &self,
+        nested_configurations: &NestedConfigurations,
+        path: &Path,
+        content: &str,
+    ) -> String {
+        let directory = path.parent().unwrap_or(Path::new(""));
+        let configuration = *self
+            .configurations
+            .lock()
+            .unwrap()
+            .entry(directory.to_path_buf())
+            .or_insert_with(|| {
+                let configuration = nested_configurations.configuration_of(directory);
+                let configuration = serde_json::to_string(&configuration).unwrap_or_default();
+                fnv1a(FNV_OFFSET_BASIS, configuration.as_bytes())
+            });
+
+        let mut hash = FNV_OFFSET_BASIS;
+        for part in [VERSION.as_bytes(), self.command.as_bytes()] {
+            hash = fnv1a(hash, part);
+            hash = fnv1a(hash, &[0]);
+        }
+        hash = fnv1a(hash, &configuration.to_le_bytes());
+        hash = fnv1a(hash, content.as_bytes());
+        format!("{hash:016x}")
+    }
+
+    /// Whether the file at `path` didn't emit any diagnostic the last time its key was `key`.
+    /// A cached file is kept in the cache.
+    pub(crate) fn is_cached(&self, path: &Path, key: &str) -> bool {
+        let file = self.file_key(path);
+        let is_cached = self
+            .known
+            .get(&file)
+            .and_then(|commands| commands.get(&self.command))
+            .is_some_and(|known| known == key);
+        if is_cached {
+            self.found
+                .lock()
+                .unwrap()
+                .insert(file, Some(key.to_string()));
+        }
+        is_cached
+    }
+
+    /// Records the result of the file at `path`: its key when it didn't emit any diagnostic,
+    /// `None` otherwise
+    pub(crate) fn record(&self, path: &Path, key: Option<String>) {
+        self.found.lock().unwrap().insert(self.file_key(path), key);
+    }
+
+    fn file_key(&self, path: &Path) -> String {
+        file_key(&self.working_directory, &self.working_directory.join(path))
+    }
+
+    /// Writes the cache file. The keys of the processed files replace the ones of the cache
+    /// file, and the other files keep their keys.
+    pub(crate) fn save(&self, fs: &dyn FileSystem) -> io::Result<()> {
+        let mut files = self.known.clone();
+        for (file, key) in self.found.lock().unwrap().iter() {
+            match key {
+                Some(key) => {
+                    files
+                        .entry(file.clone())
+                        .or_default()
+                        .insert(self.command.clone(), key.clone());
+                }
+                None => {
+                    if let Some(commands) = files.get_mut(file) {
+                        commands.remove(&self.command);
+                        if commands.is_empty() {
+                            files.remove(file);
+                        }
+                    }
+                }
+            }
+        }
+        if files == self.known {
+            return Ok(());
+        }
+
+        let content = serde_json::to_string(&CacheContent {
+            version: VERSION.to_string(),
+            files,
+        })?;
+        fs.open_with_options(
+            &self.path,
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?
+        .set_content(content.as_bytes())
+    }
+}
+
+/// The command of `execution`, along with the options that change its result. Returns `None`
+/// when the results of `execution` can't be cached.
+fn command_key(execution: &Execution) -> Option<String> {
+    if execution.requires_write_access()
+        || execution.as_stdin_file().is_some()
+        || execution.as_changed_lines().is_some()
+        || execution.as_baseline().is_some()
+        || execution.has_staged_files()
+        || execution.as_nested_configurations().is_none()
+        || !execution.should_collect_diagnostics()
+        // The rules must run on every file for their time to be reported
+        || execution.should_show_rule_timings()
+    {
+        return None;
+    }
+
+    let mut command = execution.traversal_mode().to_string();
+    match execution.traversal_mode() {
+        TraversalMode::Lint { rules, .. } => {
+            for rule in rules {
+                command.push_str(&format!(" --rule={rule}"));
+            }
+        }
+        TraversalMode::Format {
+            ignore_errors,
+            verify_idempotence,
+            ..
+        } => {
+            if *ignore_errors {
+                command.push_str(" --skip-errors");
+            }
+            if *verify_idempotence {
+                command.push_str(" --verify-idempotence");
+            }
+        }
+        TraversalMode::Check { .. } | TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => {}
+    }
+    Some(command)
+}
@@ -2,6 +2,8 @@ use crate::logging::LoggingKind;
 use crate::LoggingLevel;
 use biome_diagnostics::Severity;
 use bpaf::Bpaf;
+use std::fmt::{Display, Formatter};
+use std::num::NonZeroUsize;
 use std::str::FromStr;
 
 /// Global options applied to all commands
@@ -19,18 +21,43 @@ pub struct CliOptions {
     #[bpaf(long("verbose"), switch, fallback(false))]
     pub verbose: bool,
 
+    /// Explain why the rules exist and how to fix their diagnostics.
+    #[bpaf(long("verbose-explanations"), switch)]
+    pub verbose_explanations: bool,
+
     /// Set the filesystem path to the directory of the biome.json configuration file
     #[bpaf(long("config-path"), argument("PATH"), optional)]
     pub config_path: Option<String>,
 
-    /// Cap the amount of diagnostics displayed.
+    /// Don't download the remote configurations of `extends`, and report the ones missing from the lock file or from its cache
+    #[bpaf(long("frozen"), switch)]
+    pub frozen: bool,
+
+    /// Skip the files that didn't change since the last run with --cache
+    #[bpaf(long("cache"), switch)]
+    pub cache: bool,
+
+    /// Process all the files, even with --cache
+    #[bpaf(long("no-cache"), switch)]
+    pub no_cache: bool,
+
+    /// The number of threads used to process the files. By default, Biome uses as many
+    /// threads as there are CPUs.
+    #[bpaf(env("BIOME_THREADS"), long("threads"), argument("NUMBER"), optional)]
+    pub threads: Option<NonZeroUsize>,
+
+    /// Print the time spent running each lint rule, the slowest first, at the end of the run
+    #[bpaf(long("show-rule-timings"), switch)]
+    pub show_rule_timings: bool,
+
+    /// Cap the amount of diagnostics displayed. It accepts a number, shared by the diagnostics of all severities, or a budget for each severity, e.g. `error=500,warn=50`. The severities without a budget use the default.
     #[bpaf(
         long("max-diagnostics"),
-        argument("NUMBER"),
-        fallback(20),
+        argument("NUMBER|SEVERITY=NUMBER,..."),
+        fallback(MaxDiagnostics::default()),
         display_fallback
     )]
-    pub max_diagnostics: u16,
+    pub max_diagnostics: MaxDiagnostics,
 
     /// Skip over files containing syntax errors instead of emitting an error diagnostic.
     #[bpaf(long("skip-errors"), switch)]
@@ -41,13 +68,37 @@ pub struct CliOptions {
     pub no_errors_on_unmatched: bool,
 
     /// Tell Biome to exit with an error code if some diagnostics emit warnings.
+    ///
+    /// It's the same as `--min-severity=warn`.
     #[bpaf(long("error-on-warnings"), switch)]
     pub error_on_warnings: bool,
 
+    /// The lowest severity of the diagnostics that cause Biome to exit with an error code. In order, from the lowest to the most important: hint, info, warn, error. By default, only errors cause Biome to exit with an error code.
+    #[bpaf(long("min-severity"), argument("hint|info|warn|error"), optional)]
+    pub min_severity: Option<Severity>,
+
     /// Reports information using the JSON format
     #[bpaf(long("json"), switch, hide_usage, hide)]
     pub json: bool,
 
+    /// Allows to change how diagnostics and summary are reported.
+    #[bpaf(
+        long("reporter"),
+        argument("default|sarif|junit|gitlab|github|checkstyle|json-lines|summary"),
+        fallback(CliReporter::default()),
+        display_fallback
+    )]
+    pub reporter: CliReporter,
+
+    /// How the terminal groups the diagnostics: by file, or by rule.
+    #[bpaf(
+        long("group-by"),
+        argument("file|rule"),
+        fallback(GroupBy::default()),
+        display_fallback
+    )]
+    pub group_by: GroupBy,
+
     #[bpaf(
         long("log-level"),
         argument("none|debug|info|warn|error"),
@@ -70,14 +121,221 @@ pub struct CliOptions {
 
     #[bpaf(
         long("diagnostic-level"),
-        argument("info|warn|error"),
+        argument("hint|info|warn|error"),
         fallback(Severity::default()),
         display_fallback
     )]
-    /// The level of diagnostics to show. In order, from the lowest to the most important: info, warn, error. Passing `--diagnostic-level=error` will cause Biome to print only diagnostics that contain only errors.
+    /// The level of diagnostics to show. In order, from the lowest to the most important: hint, info, warn, error. Passing `--diagnostic-level=error` will cause Biome to print only diagnostics that contain only errors.
     pub diagnostic_level: Severity,
 }
 
+impl CliOptions {
+    /// Returns the lowest severity of the diagnostics that make the command fail
+    pub(crate) fn failing_severity(&self) -> Severity {
+        let severity = self.min_severity.unwrap_or(Severity::Error);
+        if self.error_on_warnings {
+            severity.min(Severity::Warning)
+        } else {
+            severity
+        }
+    }
+}
+
+/// The maximum number of diagnostics that are printed
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MaxDiagnostics {
+    /// The diagnostics of all severities share the same budget
+    Total(u16),
+    /// Each severity has its own budget
+    PerSeverity {
+        error: u16,
+        warning: u16,
+        information: u16,
+    },
+}
+
+impl MaxDiagnostics {
+    const DEFAULT: u16 = 20;
+
+    /// The number of diagnostics that can be printed, for all the severities
+    pub(crate) fn total(&self) -> u16 {
+        match self {
+            MaxDiagnostics::Total(total) => *total,
+            MaxDiagnostics::PerSeverity {
+                error,
+                warning,
+                information,
+            } => error.saturating_add(*warning).saturating_add(*information),
+        }
+    }
+
+    /// The number of diagnostics with the given `severity` that can be printed, or `None`
+    /// when all the severities share the same budget
+    pub(crate) fn budget(&self, severity: Severity) -> Option<u16> {
+        match (self, severity) {
+            (MaxDiagnostics::Total(_), _) => None,
+            (MaxDiagnostics::PerSeverity { error, .. }, Severity::Error | Severity::Fatal) => {
+                Some(*error)
+            }
+            (MaxDiagnostics::PerSeverity { warning, .. }, Severity::Warning) => Some(*warning),
+            (
+                MaxDiagnostics::PerSeverity { information, .. },
+                Severity::Information | Severity::Hint,
+            ) => Some(*information),
+        }
+    }
+}
+
+impl Default for MaxDiagnostics {
+    fn default() -> Self {
+        Self::Total(Self::DEFAULT)
+    }
+}
+
+impl FromStr for MaxDiagnostics {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(total) = s.parse() {
+            return Ok(Self::Total(total));
+        }
+
+        let mut error = None;
+        let mut warning = None;
+        let mut information = None;
+        for budget in s.split(',') {
+            let Some((severity, number)) = budget.split_once('=') else {
+                return Err(format!(
+                    "value {s:?} is not valid for the --max-diagnostics argument, expected a number or a list like \"error=500,warn=50\""
+                ));
+            };
+            let number = number
+                .trim()
+                .parse()
+                .map_err(|err| format!("invalid number of diagnostics {number:?}: {err}"))?;
+            let budget = match severity.trim() {
+                "error" => &mut error,
+                "warn" => &mut warning,
+                "info" => &mut information,
+                severity => {
+                    return Err(format!(
+                    "Found unexpected severity ({severity}), valid values are: info, warn, error."
+                ))
+                }
+            };
+            *budget = Some(number);
+        }
+
+        Ok(Self::PerSeverity {
+            error: error.unwrap_or(Self::DEFAULT),
+            warning: warning.unwrap_or(Self::DEFAULT),
+            information: information.unwrap_or(Self::DEFAULT),
+        })
+    }
+}
+
+impl Display for MaxDiagnostics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaxDiagnostics::Total(total) => write!(f, "{total}"),
+            MaxDiagnostics::PerSeverity {
+                error,
+                warning,
+                information,
+            } => write!(f, "error={error},warn={warning},info={information}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum CliReporter {
+    /// The default reporter, which prints the diagnostics in the terminal
+    #[default]
+    Default,
+    /// Prints the diagnostics using the SARIF 2.1.0 format
+    Sarif,
+    /// Prints the diagnostics using the JUnit XML format
+    Junit,
+    /// Prints the diagnostics using the Code Quality format of GitLab
+    GitLab,
+    /// Prints the diagnostics as annotations of GitHub Actions
+    GitHub,
+    /// Prints the diagnostics using the Checkstyle XML format
+    Checkstyle,
+    /// Prints each diagnostic as a line of JSON, as soon as it's emitted
+    JsonLines,
+    /// Prints how many diagnostics each rule emitted, instead of the diagnostics
+    Summary,
+}
+
+impl FromStr for CliReporter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "sarif" => Ok(Self::Sarif),
+            "junit" => Ok(Self::Junit),
+            "gitlab" => Ok(Self::GitLab),
+            "github" => Ok(Self::GitHub),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "json-lines" => Ok(Self::JsonLines),
+            "summary" => Ok(Self::Summary),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --reporter argument"
+            )),
+        }
+    }
+}
+
+impl Display for CliReporter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliReporter::Default => write!(f, "default"),
+            CliReporter::Sarif => write!(f, "sarif"),
+            CliReporter::Junit => write!(f, "junit"),
+            CliReporter::GitLab => write!(f, "gitlab"),
+            CliReporter::GitHub => write!(f, "github"),
+            CliReporter::Checkstyle => write!(f, "checkstyle"),
+            CliReporter::JsonLines => write!(f, "json-lines"),
+            CliReporter::Summary => write!(f, "summary"),
+        }
+    }
+}
+
+/// How the diagnostics are grouped when they're printed in the terminal
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum GroupBy {
+    /// The diagnostics are printed file by file, in the order they're emitted
+    #[default]
+    File,
+    /// The diagnostics are printed under a header for each rule, with its description
+    Rule,
+}
+
+impl FromStr for GroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "file" => Ok(Self::File),
+            "rule" => Ok(Self::Rule),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --group-by argument"
+            )),
+        }
+    }
+}
+
+impl Display for GroupBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupBy::File => write!(f, "file"),
+            GroupBy::Rule => write!(f, "rule"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ColorsArg {
     Off,
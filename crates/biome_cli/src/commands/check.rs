@@ -1,11 +1,13 @@
 use crate::cli_options::CliOptions;
-use crate::configuration::{load_configuration, LoadedConfiguration};
-use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::configuration::{load_configuration, LoadedConfiguration, LoadedConfigurationExt};
+use crate::vcs::{get_changed_files, get_staged_files, store_path_to_ignore_from_vcs};
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
 use biome_service::configuration::organize_imports::OrganizeImports;
-use biome_service::configuration::{FormatterConfiguration, LinterConfiguration};
+use biome_service::configuration::{
+    FormatterConfiguration, IgnoreFiles, LinterConfiguration, NestedConfigurations,
+};
 use biome_service::workspace::{FixFileMode, UpdateSettingsParams};
 use biome_service::{Configuration, MergeWith};
 use std::ffi::OsString;
@@ -18,6 +20,13 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) configuration: Option<Configuration>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_batch: bool,
+    pub(crate) changed: bool,
+    pub(crate) since: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) watch: bool,
+    pub(crate) interactive: bool,
+    pub(crate) profile: Option<PathBuf>,
     pub(crate) formatter_enabled: Option<bool>,
     pub(crate) linter_enabled: Option<bool>,
     pub(crate) organize_imports_enabled: Option<bool>,
@@ -33,8 +42,15 @@ pub(crate) fn check(
         apply_unsafe,
         cli_options,
         configuration,
-        paths,
+        mut paths,
         stdin_file_path,
+        stdin_batch,
+        changed,
+        since,
+        staged,
+        watch,
+        interactive,
+        profile,
         linter_enabled,
         organize_imports_enabled,
         formatter_enabled,
@@ -54,6 +70,71 @@ pub(crate) fn check(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
+    if staged {
+        if fix_file_mode.is_some() {
+            let fix_argument = if apply { "--apply" } else { "--apply-unsafe" };
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--staged",
+                fix_argument,
+            ));
+        }
+        if changed || since.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--staged",
+                "--changed",
+            ));
+        }
+        if watch {
+            return Err(CliDiagnostic::incompatible_arguments("--staged", "--watch"));
+        }
+    }
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    }
+    if watch && stdin_batch {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-batch",
+        ));
+    }
+
+    if interactive {
+        if fix_file_mode.is_some() {
+            let fix_argument = if apply { "--apply" } else { "--apply-unsafe" };
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--interactive",
+                fix_argument,
+            ));
+        }
+        if stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--interactive",
+                "--stdin-file-path",
+            ));
+        }
+        if stdin_batch {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--interactive",
+                "--stdin-batch",
+            ));
+        }
+        if staged {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--interactive",
+                "--staged",
+            ));
+        }
+        if watch {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--interactive",
+                "--watch",
+            ));
+        }
+    }
+
     let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
 
     loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
@@ -92,13 +173,54 @@ pub(crate) fn check(
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    if changed || since.is_some() {
+        paths = get_changed_files(
+            &mut session,
+            &fs_configuration,
+            vcs_base_path.clone(),
+            since.as_deref(),
+        )?;
+        if paths.is_empty() {
+            return Ok(());
+        }
+    }
+    let staged_files = if staged {
+        let staged_files =
+            get_staged_files(&mut session, &fs_configuration, vcs_base_path.clone())?;
+        if staged_files.is_empty() {
+            return Ok(());
+        }
+        paths = staged_files
+            .keys()
+            .map(|path| path.clone().into_os_string())
+            .collect();
+        Some(staged_files)
+    } else {
+        None
+    };
+    let root_directory = vcs_base_path.clone().unwrap_or_default();
+    let mut ignore_files = IgnoreFiles::new(root_directory.clone(), &*session.app.fs);
     store_path_to_ignore_from_vcs(
         &mut session,
         &mut fs_configuration,
         vcs_base_path,
         &cli_options,
+        Some(&mut ignore_files),
     )?;
+    // The configuration files of the subdirectories are applied on top of this one
+    let nested_configurations = NestedConfigurations::new(
+        root_directory.clone(),
+        fs_configuration.clone(),
+        cli_options.frozen,
+        &*session.app.fs,
+    );
 
+    if stdin_batch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--stdin-batch",
+            "--stdin-file-path",
+        ));
+    }
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
         let console = &mut session.app.console;
         let input_code = console.read();
@@ -118,15 +240,31 @@ pub(crate) fn check(
         .workspace
         .update_settings(UpdateSettingsParams {
             configuration: fs_configuration,
+            project_directory: None,
         })?;
 
-    execute_mode(
-        Execution::new(TraversalMode::Check {
-            fix_file_mode,
-            stdin,
-        }),
-        session,
-        &cli_options,
-        paths,
-    )
+    let mut execution = Execution::new(TraversalMode::Check {
+        fix_file_mode,
+        stdin,
+    });
+    execution = execution
+        .with_nested_configurations(nested_configurations)
+        .with_ignore_files(ignore_files);
+    if let Some(staged_files) = staged_files {
+        execution = execution.with_staged_files(staged_files);
+    }
+    if stdin_batch {
+        execution = execution.with_stdin_batch();
+    }
+    if watch {
+        execution = execution.with_watch();
+    }
+    if interactive {
+        execution = execution.with_interactive();
+    }
+    if let Some(profile) = profile {
+        execution = execution.with_profile(profile);
+    }
+
+    execute_mode(execution, session, &cli_options, paths)
 }
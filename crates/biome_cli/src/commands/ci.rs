@@ -1,12 +1,14 @@
 use crate::cli_options::CliOptions;
-use crate::configuration::LoadedConfiguration;
-use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::configuration::{LoadedConfiguration, LoadedConfigurationExt};
+use crate::vcs::{get_changed_lines, store_path_to_ignore_from_vcs};
 use crate::{
     configuration::load_configuration, execute_mode, setup_cli_subscriber, CliDiagnostic,
     CliSession, Execution,
 };
 use biome_service::configuration::organize_imports::OrganizeImports;
-use biome_service::configuration::{FormatterConfiguration, LinterConfiguration};
+use biome_service::configuration::{
+    FormatterConfiguration, IgnoreFiles, LinterConfiguration, NestedConfigurations,
+};
 use biome_service::workspace::UpdateSettingsParams;
 use biome_service::{Configuration, MergeWith};
 use std::ffi::OsString;
@@ -17,6 +19,8 @@ pub(crate) struct CiCommandPayload {
     pub(crate) organize_imports_enabled: Option<bool>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) rome_configuration: Configuration,
+    pub(crate) changed_lines_only: bool,
+    pub(crate) since: Option<String>,
     pub(crate) cli_options: CliOptions,
 }
 
@@ -81,22 +85,48 @@ pub(crate) fn ci(mut session: CliSession, payload: CiCommandPayload) -> Result<(
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    let mut execution = Execution::new_ci();
+    let mut paths = payload.paths;
+    if payload.changed_lines_only || payload.since.is_some() {
+        let changed_lines = get_changed_lines(
+            &mut session,
+            &configuration,
+            vcs_base_path.clone(),
+            payload.since.as_deref(),
+        )?;
+        if changed_lines.is_empty() {
+            return Ok(());
+        }
+        paths = changed_lines.paths();
+        execution = execution.with_changed_lines(changed_lines);
+    }
+    let root_directory = vcs_base_path.clone().unwrap_or_default();
+    let mut ignore_files = IgnoreFiles::new(root_directory.clone(), &*session.app.fs);
     store_path_to_ignore_from_vcs(
         &mut session,
         &mut configuration,
         vcs_base_path,
         &payload.cli_options,
+        Some(&mut ignore_files),
     )?;
+    // The configuration files of the subdirectories are applied on top of this one
+    let nested_configurations = NestedConfigurations::new(
+        root_directory.clone(),
+        configuration.clone(),
+        payload.cli_options.frozen,
+        &*session.app.fs,
+    );
 
     session
         .app
         .workspace
-        .update_settings(UpdateSettingsParams { configuration })?;
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            project_directory: None,
+        })?;
 
-    execute_mode(
-        Execution::new_ci(),
-        session,
-        &payload.cli_options,
-        payload.paths,
-    )
+    execution = execution
+        .with_nested_configurations(nested_configurations)
+        .with_ignore_files(ignore_files);
+    execute_mode(execution, session, &payload.cli_options, paths)
 }
@@ -0,0 +1,67 @@
+use crate::cache::CACHE_FILE_NAME;
+use crate::commands::daemon::log_files;
+use crate::service::enumerate_stale_sockets;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use std::fs;
+use std::path::PathBuf;
+
+/// Handler for the `clean` command. It removes the files that Biome writes outside
+/// of the project: the logs of the daemon and the sockets of the daemons that
+/// aren't running anymore. It also removes the cache of the results written with
+/// `--cache` in the working directory.
+///
+/// With `dry_run`, it only prints the files that would be removed.
+pub(crate) fn clean(session: CliSession, dry_run: bool) -> Result<(), CliDiagnostic> {
+    let mut files: Vec<PathBuf> = log_files()?;
+    files.extend(enumerate_stale_sockets()?);
+    if let Some(working_directory) = session.app.fs.working_directory() {
+        let cache_path = working_directory.join(CACHE_FILE_NAME);
+        if session.app.fs.path_exists(&cache_path) {
+            files.push(cache_path);
+        }
+    }
+
+    if files.is_empty() {
+        session
+            .app
+            .console
+            .log(markup! {"There's nothing to remove."});
+        return Ok(());
+    }
+
+    if dry_run {
+        session
+            .app
+            .console
+            .log(markup! {"The following files would be removed:"});
+        for file in &files {
+            let file = file.display().to_string();
+            session.app.console.log(markup! {"  "{file}});
+        }
+        return Ok(());
+    }
+
+    let mut removed = 0usize;
+    for file in &files {
+        let path = file.display().to_string();
+        match fs::remove_file(file) {
+            Ok(()) => {
+                removed += 1;
+                session.app.console.log(markup! {"Removed "{path}});
+            }
+            Err(err) => {
+                let err = err.to_string();
+                session.app.console.error(markup! {
+                    <Warn>"Failed to remove "{path}": "{err}</Warn>
+                });
+            }
+        }
+    }
+
+    session.app.console.log(markup! {
+        <Success>"Removed "{removed}" file(s)."</Success>
+    });
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_service::configuration::{RuleDocumentation, RuleSelector};
+use std::str::FromStr;
+
+/// The shells that support the completions of the CLI
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    PowerShell,
+}
+
+impl Shell {
+    const ALL: [(&'static str, Shell); 4] = [
+        ("bash", Shell::Bash),
+        ("zsh", Shell::Zsh),
+        ("fish", Shell::Fish),
+        ("powershell", Shell::PowerShell),
+    ];
+}
+
+impl FromStr for Shell {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find_map(|(name, shell)| (name == s).then_some(shell))
+            .ok_or_else(|| {
+                format!("The shell {s} isn't supported, use one of: bash, zsh, fish, powershell")
+            })
+    }
+}
+
+/// The scripts don't contain the completions: they run `biome` with the
+/// `--bpaf-complete-rev` flag, and bpaf prints the completions of the command line
+/// in the format of the revision. This way, the completions never go out of sync.
+const BASH_SCRIPT: &str = r#"_biome_completion()
+{
+    source <( "$1" --bpaf-complete-rev=8 "${COMP_WORDS[@]:1}" )
+}
+complete -o nosort -F _biome_completion biome"#;
+
+const ZSH_SCRIPT: &str = r#"#compdef biome
+source <( "${words[1]}" --bpaf-complete-rev=7 "${words[@]:1}" )"#;
+
+const FISH_SCRIPT: &str = r#"function _biome_completion
+    set -l current (commandline --tokenize --current-process)
+    set -l tmpline $current[1] --bpaf-complete-rev=9 $current[2..]
+    if test (commandline --current-process) != (string trim (commandline --current-process))
+        set tmpline $tmpline ""
+    end
+    source ( $tmpline | psub )
+end
+complete --no-files --command biome --arguments '(_biome_completion)'"#;
+
+/// The revision 1 prints a candidate per line, followed by its description after a tab
+const POWERSHELL_SCRIPT: &str = r#"Register-ArgumentCompleter -Native -CommandName biome -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+    $words = @($commandAst.CommandElements | Select-Object -Skip 1 | ForEach-Object { $_.ToString() })
+    if ($wordToComplete -eq '') {
+        $words += ''
+    }
+    & $commandAst.CommandElements[0].ToString() --bpaf-complete-rev=1 @words | ForEach-Object {
+        $candidate, $description = $_ -split "`t", 2
+        if (-not $description) {
+            $description = $candidate
+        }
+        [System.Management.Automation.CompletionResult]::new($candidate, $candidate, 'ParameterValue', $description)
+    }
+}"#;
+
+/// Handler for the `completions` command. Prints the script that enables the
+/// completions of the CLI in `shell`.
+pub(crate) fn completions(session: CliSession, shell: Shell) -> Result<(), CliDiagnostic> {
+    let script = match shell {
+        Shell::Bash => BASH_SCRIPT,
+        Shell::Zsh => ZSH_SCRIPT,
+        Shell::Fish => FISH_SCRIPT,
+        Shell::PowerShell => POWERSHELL_SCRIPT,
+    };
+    session.app.console.log(markup! {{script}});
+    Ok(())
+}
+
+/// Completes the name of a shell
+#[allow(clippy::ptr_arg)]
+pub(crate) fn complete_shell(input: &String) -> Vec<(&'static str, Option<&'static str>)> {
+    Shell::ALL
+        .into_iter()
+        .filter(|(name, _)| name.starts_with(input.as_str()))
+        .map(|(name, _)| (name, None))
+        .collect()
+}
+
+/// Completes the name of a lint rule, using the syntax `<group>/<rule>`. The
+/// description of each rule is the first line of its documentation.
+#[allow(clippy::ptr_arg)]
+pub(crate) fn complete_rule_name(input: &String) -> Vec<(String, Option<String>)> {
+    RuleDocumentation::all()
+        .into_iter()
+        .filter_map(|documentation| {
+            let name = format!("{}/{}", documentation.group, documentation.metadata.name);
+            if !name.starts_with(input.as_str())
+                && !documentation.metadata.name.starts_with(input.as_str())
+            {
+                return None;
+            }
+            let description = documentation
+                .metadata
+                .docs
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .map(str::to_string);
+            Some((name, description))
+        })
+        .collect()
+}
+
+/// Parses the name of a shell completed by [complete_shell]
+pub(crate) fn parse_shell(input: String) -> Result<Shell, String> {
+    Shell::from_str(&input)
+}
+
+/// Parses the name of a lint rule completed by [complete_rule_name]
+pub(crate) fn parse_rule_selector(input: String) -> Result<RuleSelector, String> {
+    RuleSelector::from_str(&input)
+}
@@ -1,4 +1,5 @@
 use crate::{
+    commands::LspProxyTransport,
     open_transport,
     service::{self, ensure_daemon, open_socket, run_daemon},
     CliDiagnostic, CliSession,
@@ -95,9 +96,29 @@ pub(crate) fn print_socket() -> Result<(), CliDiagnostic> {
     Ok(())
 }
 
-pub(crate) fn lsp_proxy(config_path: Option<PathBuf>) -> Result<(), CliDiagnostic> {
+pub(crate) fn lsp_proxy(
+    config_path: Option<PathBuf>,
+    transport: Option<LspProxyTransport>,
+) -> Result<(), CliDiagnostic> {
     let rt = Runtime::new()?;
-    rt.block_on(start_lsp_proxy(&rt, config_path))?;
+    match transport {
+        None => rt.block_on(start_lsp_proxy(&rt, config_path))?,
+        Some(transport) => {
+            let res = match transport {
+                LspProxyTransport::Tcp(address) => {
+                    rt.block_on(service::run_tcp_proxy(address, config_path))
+                }
+                LspProxyTransport::Pipe(name) => {
+                    rt.block_on(service::run_pipe_proxy(name, config_path))
+                }
+            };
+
+            match res {
+                Ok(never) => match never {},
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
 
     Ok(())
 }
@@ -176,6 +197,28 @@ pub(crate) fn read_most_recent_log_file() -> io::Result<Option<String>> {
     }
 }
 
+/// Returns the log files written by the daemon
+pub(crate) fn log_files() -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(rome_log_dir()) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut files: Vec<_> = entries
+        .flatten()
+        .filter(|file| file.file_type().map_or(false, |ty| ty.is_file()))
+        .filter(|file| {
+            file.file_name()
+                .to_str()
+                .map_or(false, |name| name.starts_with(log_file_name_prefix()))
+        })
+        .map(|file| file.path())
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
 /// Setup the [tracing]-based logging system for the server
 /// The events received by the subscriber are filtered at the `info` level,
 /// then printed using the [HierarchicalLayer] layer, and the resulting text
@@ -0,0 +1,181 @@
+use crate::cli_options::CliOptions;
+use crate::commands::rage::KeyValuePair;
+use crate::configuration::{load_configuration, LoadedConfiguration, LoadedConfigurationExt};
+use crate::service;
+use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_fs::RomePath;
+use biome_service::settings::{PathIgnoreStatus, WorkspaceSettings};
+use biome_service::workspace::{
+    client, FeatureName, SupportKind, SupportsFeatureParams, UpdateSettingsParams,
+};
+use biome_service::Configuration;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use tokio::runtime::Runtime;
+
+const FEATURES: [FeatureName; 3] = [
+    FeatureName::Format,
+    FeatureName::Lint,
+    FeatureName::OrganizeImports,
+];
+
+/// Handler for the `doctor` command. It prints the configuration file that was loaded,
+/// the configuration resolved after `extends`, whether each of the `paths` is processed
+/// by each tool and which glob decided it, and the status of the daemon.
+pub(crate) fn doctor(
+    mut session: CliSession,
+    cli_options: CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
+    loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
+    let LoadedConfiguration {
+        configuration,
+        directory_path,
+        file_path,
+        ..
+    } = loaded_configuration;
+
+    let file_path = file_path.map_or_else(
+        || String::from("none, using the defaults"),
+        |file_path| file_path.display().to_string(),
+    );
+    let extends = configuration
+        .extends
+        .as_ref()
+        .map(|extends| extends.iter().cloned().collect::<Vec<_>>().join(", "))
+        .filter(|extends| !extends.is_empty())
+        .unwrap_or_else(|| String::from("none"));
+    let resolved = serde_json::to_string_pretty(&configuration).unwrap_or_default();
+    session.app.console.log(markup! {
+        <Emphasis>"Configuration"</Emphasis>"\n\n"
+        {KeyValuePair("File", markup!({file_path}))}
+        {KeyValuePair("Extends", markup!({extends}))}
+        "\n"<Emphasis>"Resolved configuration"</Emphasis>"\n\n"{resolved}
+    });
+
+    let mut workspace_configuration = configuration.clone();
+    let vcs_base_path = directory_path.or(session.app.fs.working_directory());
+    store_path_to_ignore_from_vcs(
+        &mut session,
+        &mut workspace_configuration,
+        vcs_base_path,
+        &cli_options,
+        None,
+    )?;
+    let vcs_patterns = ignored_patterns(&workspace_configuration)
+        .into_iter()
+        .filter(|pattern| !ignored_patterns(&configuration).contains(pattern))
+        .collect::<Vec<_>>();
+
+    let mut settings = WorkspaceSettings::default();
+    settings.merge_with_configuration(workspace_configuration.clone())?;
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            configuration: workspace_configuration,
+            project_directory: None,
+        })?;
+
+    if paths.is_empty() {
+        session.app.console.log(markup! {
+            <Emphasis>"Files"</Emphasis>"\n\n"
+            <Dim>"Pass some paths to the command to know whether Biome processes them."</Dim>
+        });
+    } else {
+        session
+            .app
+            .console
+            .log(markup! {<Emphasis>"Files"</Emphasis>});
+    }
+    for path in paths {
+        let path = PathBuf::from(path);
+        let features = session.app.workspace.file_features(SupportsFeatureParams {
+            path: RomePath::new(&path),
+            feature: FEATURES.to_vec(),
+        })?;
+        let [formatter, linter, organize_imports] = FEATURES.map(|feature| {
+            let status = settings.path_ignore_status(&path, &feature);
+            let support = features
+                .features_supported
+                .get(&feature)
+                .cloned()
+                .unwrap_or(SupportKind::FileNotSupported);
+            describe(&status, &support, &vcs_patterns)
+        });
+        session.app.console.log(markup! {
+            "\n"{path.display().to_string()}"\n"
+            {KeyValuePair("Formatter", markup!({formatter}))}
+            {KeyValuePair("Linter", markup!({linter}))}
+            {KeyValuePair("Organize imports", markup!({organize_imports}))}
+        });
+    }
+
+    let daemon = daemon_status(&session);
+    session.app.console.log(markup! {
+        "\n"<Emphasis>"Daemon"</Emphasis>"\n\n"
+        {KeyValuePair("Status", markup!({daemon}))}
+    });
+
+    Ok(())
+}
+
+/// Returns the patterns of `files.ignore`
+fn ignored_patterns(configuration: &Configuration) -> Vec<String> {
+    configuration
+        .files
+        .as_ref()
+        .and_then(|files| files.ignore.as_ref())
+        .map(|ignore| ignore.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Explains why a tool processes a file or not
+fn describe(status: &PathIgnoreStatus, support: &SupportKind, vcs_patterns: &[String]) -> String {
+    let verdict = match support {
+        SupportKind::FileNotSupported => return String::from("not processed, unsupported file"),
+        SupportKind::FeatureNotEnabled => return String::from("not processed, tool disabled"),
+        SupportKind::Supported | SupportKind::Ignored if status.ignored => "ignored",
+        SupportKind::Supported | SupportKind::Ignored => "processed",
+    };
+    match (&status.setting, &status.pattern) {
+        (Some(_), Some(pattern)) if vcs_patterns.contains(pattern) => {
+            format!("{verdict}, matched \"{pattern}\" of the VCS ignore file")
+        }
+        (Some(setting), Some(pattern)) => {
+            format!("{verdict}, matched \"{pattern}\" of {setting}")
+        }
+        (Some(setting), None) => format!("{verdict}, no pattern of {setting} matched"),
+        (None, _) => format!("{verdict}, no pattern matched"),
+    }
+}
+
+/// Tells whether the CLI is connected to the daemon, or whether a daemon is running
+fn daemon_status(session: &CliSession) -> String {
+    if let Some(server_info) = session.app.workspace.server_info() {
+        return format!(
+            "connected to {} {}",
+            server_info.name,
+            server_info.version.as_deref().unwrap_or_default()
+        );
+    }
+    let transport = Runtime::new().and_then(service::open_transport);
+    match transport {
+        Ok(None) => String::from("not running"),
+        Ok(Some(transport)) => match client(transport) {
+            Ok(client) => match client.server_info() {
+                Some(server_info) => format!(
+                    "running {} {}, not used by this command",
+                    server_info.name,
+                    server_info.version.as_deref().unwrap_or_default()
+                ),
+                None => String::from("running, not used by this command"),
+            },
+            Err(err) => format!("running, but it doesn't respond: {err}"),
+        },
+        Err(err) => format!("unreachable: {err}"),
+    }
+}
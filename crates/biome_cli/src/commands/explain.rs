@@ -0,0 +1,75 @@
+use crate::cli_options::CliOptions;
+use crate::commands::rage::KeyValuePair;
+use crate::configuration::{load_configuration, LoadedConfiguration, LoadedConfigurationExt};
+use crate::{CliDiagnostic, CliSession};
+use biome_analyze::FixKind;
+use biome_console::{markup, ConsoleExt};
+use biome_service::configuration::RuleDocumentation;
+
+/// Handler for the `explain` command. Prints the documentation embedded in the binary
+/// for the rule `rule_name`, so it's available offline.
+pub(crate) fn explain(
+    mut session: CliSession,
+    cli_options: CliOptions,
+    rule_name: String,
+) -> Result<(), CliDiagnostic> {
+    let Some(documentation) = RuleDocumentation::find(&rule_name) else {
+        return Err(CliDiagnostic::unknown_rule(rule_name));
+    };
+
+    let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
+    loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
+    let LoadedConfiguration { configuration, .. } = loaded_configuration;
+
+    let RuleDocumentation {
+        group,
+        metadata,
+        default_options,
+    } = &documentation;
+
+    let status = if configuration.is_linter_disabled() {
+        markup!(<Dim>"disabled, the linter is disabled"</Dim>)
+    } else if configuration
+        .linter
+        .as_ref()
+        .and_then(|linter| linter.rules.as_ref())
+        .cloned()
+        .unwrap_or_default()
+        .as_enabled_rules()
+        .contains(&documentation.as_rule_filter())
+    {
+        markup!(<Success>"enabled"</Success>)
+    } else {
+        markup!(<Dim>"disabled"</Dim>)
+    };
+    let fix = match metadata.fix_kind {
+        Some(FixKind::Safe) => markup!("safe"),
+        Some(FixKind::Unsafe) => markup!("unsafe"),
+        None => markup!(<Dim>"none"</Dim>),
+    };
+    let category = format!("lint/{group}/{}", metadata.name);
+    let options = match default_options {
+        Some(options) => serde_json::to_string_pretty(options).unwrap_or_default(),
+        None => String::from("none"),
+    };
+
+    session.app.console.log(markup! {
+        <Emphasis>{metadata.name}</Emphasis>"\n\n"
+        {KeyValuePair("Category", markup!({category}))}
+        {KeyValuePair("Since", markup!("v"{metadata.version}))}
+        {KeyValuePair("Recommended", markup!({if metadata.recommended { "yes" } else { "no" }}))}
+        {KeyValuePair("Fix", fix)}
+        {KeyValuePair("In the current configuration", status)}
+    });
+    if let Some(reason) = metadata.deprecated {
+        session.app.console.log(markup! {
+            {KeyValuePair("Deprecated", markup!(<Warn>{reason}</Warn>))}
+        });
+    }
+    session.app.console.log(markup! {
+        <Emphasis>"Options"</Emphasis>"\n\n"{options}"\n\n"
+        <Emphasis>"Documentation"</Emphasis>"\n\n"{metadata.docs.trim()}
+    });
+
+    Ok(())
+}
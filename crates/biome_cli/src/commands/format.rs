@@ -1,8 +1,8 @@
-use crate::cli_options::CliOptions;
-use crate::configuration::{load_configuration, LoadedConfiguration};
+use crate::cli_options::{CliOptions, CliReporter};
+use crate::configuration::{load_configuration, LoadedConfiguration, LoadedConfigurationExt};
 use crate::diagnostics::DeprecatedArgument;
 use crate::execute::ReportMode;
-use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::vcs::{get_changed_files, get_staged_files, store_path_to_ignore_from_vcs};
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
@@ -10,7 +10,9 @@ use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::PrintDiagnostic;
 use biome_service::configuration::json::JsonFormatter;
 use biome_service::configuration::vcs::VcsConfiguration;
-use biome_service::configuration::{FilesConfiguration, FormatterConfiguration};
+use biome_service::configuration::{
+    FilesConfiguration, FormatterConfiguration, IgnoreFiles, NestedConfigurations,
+};
 use biome_service::workspace::UpdateSettingsParams;
 use biome_service::{JavascriptFormatter, MergeWith};
 use std::ffi::OsString;
@@ -23,7 +25,14 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
     pub(crate) files_configuration: Option<FilesConfiguration>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_batch: bool,
     pub(crate) write: bool,
+    pub(crate) check: bool,
+    pub(crate) patch: bool,
+    pub(crate) verify_idempotence: bool,
+    pub(crate) changed: bool,
+    pub(crate) since: Option<String>,
+    pub(crate) staged: bool,
     pub(crate) cli_options: CliOptions,
     pub(crate) paths: Vec<OsString>,
 }
@@ -37,15 +46,66 @@ pub(crate) fn format(
         javascript_formatter,
         formatter_configuration,
         vcs_configuration,
-        paths,
+        mut paths,
         cli_options,
         stdin_file_path,
+        stdin_batch,
         files_configuration,
         write,
+        check,
+        patch,
+        verify_idempotence,
+        changed,
+        since,
+        staged,
         json_formatter,
     } = payload;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
+    if write {
+        if check {
+            return Err(CliDiagnostic::incompatible_arguments("--write", "--check"));
+        }
+        if patch {
+            return Err(CliDiagnostic::incompatible_arguments("--write", "--patch"));
+        }
+    }
+    if patch {
+        if cli_options.json {
+            return Err(CliDiagnostic::incompatible_arguments("--patch", "--json"));
+        }
+        if cli_options.reporter != CliReporter::Default {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--patch",
+                "--reporter",
+            ));
+        }
+        if stdin_file_path.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--patch",
+                "--stdin-file-path",
+            ));
+        }
+        if stdin_batch {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--patch",
+                "--stdin-batch",
+            ));
+        }
+    }
+
+    if staged {
+        if write {
+            return Err(CliDiagnostic::incompatible_arguments("--staged", "--write"));
+        }
+        if changed || since.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--staged",
+                "--changed",
+            ));
+        }
+    }
+
     let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
 
     loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
@@ -103,17 +163,60 @@ pub(crate) fn format(
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    if changed || since.is_some() {
+        paths = get_changed_files(
+            &mut session,
+            &configuration,
+            vcs_base_path.clone(),
+            since.as_deref(),
+        )?;
+        if paths.is_empty() {
+            return Ok(());
+        }
+    }
+    let staged_files = if staged {
+        let staged_files = get_staged_files(&mut session, &configuration, vcs_base_path.clone())?;
+        if staged_files.is_empty() {
+            return Ok(());
+        }
+        paths = staged_files
+            .keys()
+            .map(|path| path.clone().into_os_string())
+            .collect();
+        Some(staged_files)
+    } else {
+        None
+    };
+    let root_directory = vcs_base_path.clone().unwrap_or_default();
+    let mut ignore_files = IgnoreFiles::new(root_directory.clone(), &*session.app.fs);
     store_path_to_ignore_from_vcs(
         &mut session,
         &mut configuration,
         vcs_base_path,
         &cli_options,
+        Some(&mut ignore_files),
     )?;
+    // The configuration files of the subdirectories are applied on top of this one
+    let nested_configurations = NestedConfigurations::new(
+        root_directory.clone(),
+        configuration.clone(),
+        cli_options.frozen,
+        &*session.app.fs,
+    );
     session
         .app
         .workspace
-        .update_settings(UpdateSettingsParams { configuration })?;
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            project_directory: None,
+        })?;
 
+    if stdin_batch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--stdin-batch",
+            "--stdin-file-path",
+        ));
+    }
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
         let console = &mut session.app.console;
         let input_code = console.read();
@@ -128,22 +231,41 @@ pub(crate) fn format(
         None
     };
 
-    let execution = if cli_options.json {
+    let report_mode = if cli_options.json {
+        Some(ReportMode::Json)
+    } else if patch {
+        Some(ReportMode::Patch)
+    } else {
+        None
+    };
+    let mut execution = if let Some(report_mode) = report_mode {
         Execution::with_report(
             TraversalMode::Format {
                 ignore_errors: cli_options.skip_errors,
                 write,
+                verify_idempotence,
                 stdin,
             },
-            ReportMode::Json,
+            report_mode,
         )
     } else {
         Execution::new(TraversalMode::Format {
             ignore_errors: cli_options.skip_errors,
             write,
+            verify_idempotence,
             stdin,
         })
     };
 
+    execution = execution
+        .with_nested_configurations(nested_configurations)
+        .with_ignore_files(ignore_files);
+    if let Some(staged_files) = staged_files {
+        execution = execution.with_staged_files(staged_files);
+    }
+    if stdin_batch {
+        execution = execution.with_stdin_batch();
+    }
+
     execute_mode(execution, session, &cli_options, paths)
 }
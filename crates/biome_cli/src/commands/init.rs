@@ -1,11 +1,41 @@
 use crate::{CliDiagnostic, CliSession};
-use biome_console::{markup, ConsoleExt, HorizontalLine};
-use biome_service::configuration::Configuration;
-use biome_service::create_config;
+use biome_console::{markup, Console, ConsoleExt, HorizontalLine};
+use biome_deserialize::StringSet;
+use biome_formatter::LineWidth;
+use biome_fs::{FileSystem, OpenOptions};
+use biome_js_formatter::context::{QuoteStyle, Semicolons};
+use biome_service::configuration::diagnostics::ConfigurationDiagnostic;
+use biome_service::configuration::linter::{
+    Correctness, Nursery, RuleConfiguration, RulePlainConfiguration, Suspicious,
+};
+use biome_service::configuration::vcs::{VcsClientKind, VcsConfiguration};
+use biome_service::configuration::{
+    Configuration, FilesConfiguration, FormatterConfiguration, JavascriptFormatter,
+    PlainIndentStyle,
+};
+use biome_service::{create_config, WorkspaceError};
+use serde_json::Value;
+use std::path::Path;
+use std::str::FromStr;
+
+pub(crate) fn init(mut session: CliSession, interactive: bool) -> Result<(), CliDiagnostic> {
+    let configuration = if interactive {
+        let fs = &*session.app.fs;
+        if fs.path_exists(Path::new(fs.config_name())) {
+            return Err(WorkspaceError::Configuration(
+                ConfigurationDiagnostic::new_already_exists(),
+            )
+            .into());
+        }
+        let project = ProjectKind::detect(fs);
+        let has_git = fs.path_exists(Path::new(".git/HEAD"));
+        ask_configuration(&mut *session.app.console, project, has_git)
+    } else {
+        Configuration::default()
+    };
 
-pub(crate) fn init(mut session: CliSession) -> Result<(), CliDiagnostic> {
     let fs = &mut session.app.fs;
-    create_config(fs, Configuration::default())?;
+    create_config(fs, configuration)?;
 
     session.app.console.log(markup! {
 "\n"<Inverse>"Welcome to Biome! Let's get you started..."</Inverse>"
@@ -32,3 +62,229 @@ pub(crate) fn init(mut session: CliSession) -> Result<(), CliDiagnostic> {
 
     Ok(())
 }
+
+/// The kinds of project that have a preset
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum ProjectKind {
+    React,
+    Next,
+    Node,
+    Library,
+    Other,
+}
+
+impl ProjectKind {
+    const ALL: [(&'static str, ProjectKind); 5] = [
+        ("react", ProjectKind::React),
+        ("next", ProjectKind::Next),
+        ("node", ProjectKind::Node),
+        ("library", ProjectKind::Library),
+        ("other", ProjectKind::Other),
+    ];
+
+    fn name(self) -> &'static str {
+        Self::ALL
+            .into_iter()
+            .find_map(|(name, kind)| (kind == self).then_some(name))
+            .unwrap_or_default()
+    }
+
+    /// Guesses the kind of the project from the manifest `package.json` of the working
+    /// directory
+    fn detect(fs: &dyn FileSystem) -> Self {
+        let Some(manifest) = read_manifest(fs) else {
+            return ProjectKind::Other;
+        };
+        let has_dependency = |name: &str| {
+            ["dependencies", "devDependencies", "peerDependencies"]
+                .into_iter()
+                .any(|field| {
+                    manifest
+                        .get(field)
+                        .and_then(|deps| deps.get(name))
+                        .is_some()
+                })
+        };
+        let is_published = manifest.get("private") != Some(&Value::Bool(true))
+            && ["main", "module", "exports", "types"]
+                .into_iter()
+                .any(|field| manifest.get(field).is_some());
+
+        if has_dependency("next") {
+            ProjectKind::Next
+        } else if is_published {
+            ProjectKind::Library
+        } else if has_dependency("react") {
+            ProjectKind::React
+        } else {
+            ProjectKind::Node
+        }
+    }
+}
+
+fn read_manifest(fs: &dyn FileSystem) -> Option<Value> {
+    let mut file = fs
+        .open_with_options(Path::new("package.json"), OpenOptions::default().read(true))
+        .ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Asks the preferences of the user, and creates a configuration tailored to the project
+fn ask_configuration(
+    console: &mut dyn Console,
+    detected_project: ProjectKind,
+    has_git: bool,
+) -> Configuration {
+    if detected_project != ProjectKind::Other {
+        console.log(markup! {
+            "This looks like a "<Emphasis>{detected_project.name()}</Emphasis>" project."
+        });
+    }
+    let project = ask(
+        console,
+        "What kind of project is it? react, next, node, library, other",
+        detected_project.name(),
+        |answer| {
+            ProjectKind::ALL
+                .into_iter()
+                .find_map(|(name, kind)| (name == answer).then_some(kind))
+        },
+    );
+    let indent_style = ask(
+        console,
+        "Indent with tabs or spaces? tab, space",
+        "tab",
+        |answer| PlainIndentStyle::from_str(answer).ok(),
+    );
+    let indent_width = ask(
+        console,
+        "How many columns is an indentation level?",
+        "2",
+        |answer| answer.parse::<u8>().ok().filter(|width| *width > 0),
+    );
+    let line_width = ask(
+        console,
+        "What's the maximum width of a line?",
+        "80",
+        |answer| {
+            answer
+                .parse::<u16>()
+                .ok()
+                .and_then(|width| LineWidth::try_from(width).ok())
+        },
+    );
+    let quote_style = ask(
+        console,
+        "Which quotes do JavaScript strings use? double, single",
+        "double",
+        |answer| QuoteStyle::from_str(answer).ok(),
+    );
+    let semicolons = ask(
+        console,
+        "When do JavaScript statements end with a semicolon? always, as-needed",
+        "always",
+        |answer| Semicolons::from_str(answer).ok(),
+    );
+
+    let mut configuration = Configuration {
+        formatter: Some(FormatterConfiguration {
+            indent_style: Some(indent_style),
+            indent_width: Some(indent_width),
+            line_width: Some(line_width),
+            ..FormatterConfiguration::empty()
+        }),
+        ..Configuration::default()
+    };
+    configuration
+        .javascript
+        .get_or_insert_with(Default::default)
+        .formatter = Some(JavascriptFormatter {
+        quote_style: Some(quote_style),
+        semicolons: Some(semicolons),
+        ..JavascriptFormatter::default()
+    });
+    if has_git {
+        configuration.vcs = Some(VcsConfiguration {
+            client_kind: Some(VcsClientKind::Git),
+            enabled: Some(true),
+            use_ignore_file: Some(true),
+            ..VcsConfiguration::default()
+        });
+    }
+    apply_preset(&mut configuration, project);
+    configuration
+}
+
+/// Enables the rules and ignores the files that are specific to the kind of project
+fn apply_preset(configuration: &mut Configuration, project: ProjectKind) {
+    let error = || Some(RuleConfiguration::Plain(RulePlainConfiguration::Error));
+    let rules = configuration
+        .linter
+        .get_or_insert_with(Default::default)
+        .rules
+        .get_or_insert_with(Default::default);
+    let ignore = match project {
+        ProjectKind::React | ProjectKind::Next => {
+            rules.correctness = Some(Correctness {
+                use_hook_at_top_level: error(),
+                ..Correctness::default()
+            });
+            if project == ProjectKind::Next {
+                vec![".next"]
+            } else {
+                vec!["build"]
+            }
+        }
+        ProjectKind::Library => {
+            // A library shouldn't print to the console of its consumers, and named exports
+            // work better with the tools of its consumers
+            rules.suspicious = Some(Suspicious {
+                no_console_log: error(),
+                ..Suspicious::default()
+            });
+            rules.nursery = Some(Nursery {
+                no_default_export: error(),
+                ..Nursery::default()
+            });
+            vec!["dist"]
+        }
+        ProjectKind::Node => vec!["dist"],
+        ProjectKind::Other => vec![],
+    };
+    if !ignore.is_empty() {
+        configuration.files = Some(FilesConfiguration {
+            ignore: Some(StringSet::new(
+                ignore.into_iter().map(String::from).collect(),
+            )),
+            ..FilesConfiguration::default()
+        });
+    }
+}
+
+/// Asks a question until the answer is valid. An empty answer, or the end of the
+/// input, selects the default answer.
+fn ask<T>(
+    console: &mut dyn Console,
+    question: &str,
+    default_answer: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> T {
+    loop {
+        console.log(markup! {
+            <Emphasis>{question}</Emphasis>" "<Dim>"("{default_answer}")"</Dim>
+        });
+        let answer = console
+            .read_line()
+            .map(|answer| answer.trim().to_string())
+            .filter(|answer| !answer.is_empty())
+            .unwrap_or_else(|| default_answer.to_string());
+        match parse(&answer) {
+            Some(value) => return value,
+            None => console.error(markup! {
+                <Warn>"The answer "<Emphasis>{answer}</Emphasis>" isn't valid."</Warn>
+            }),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::diagnostics::NoVcsFolderFound;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::adapters::IoError;
+use biome_diagnostics::Error;
+use biome_fs::{FileSystem, OpenOptions};
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The first line of the commands that Biome writes in a hook
+const START_MARKER: &str = "# >>> biome >>>";
+/// The last line of the commands that Biome writes in a hook
+const END_MARKER: &str = "# <<< biome <<<";
+
+/// The git hooks that Biome can install
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum GitHook {
+    /// Checks the staged files before each commit
+    #[default]
+    PreCommit,
+    /// Checks the project before each push
+    PrePush,
+}
+
+impl GitHook {
+    /// The arguments of `biome` that the hook runs
+    fn arguments(&self) -> &'static str {
+        match self {
+            GitHook::PreCommit => "check --staged --no-errors-on-unmatched",
+            GitHook::PrePush => "check --no-errors-on-unmatched .",
+        }
+    }
+
+    /// The commands that Biome writes in the hook, between the markers
+    fn commands(&self) -> String {
+        let arguments = self.arguments();
+        format!(
+            "{START_MARKER}\n\
+            biome=\"./node_modules/.bin/biome\"\n\
+            [ -x \"$biome\" ] || biome=\"biome\"\n\
+            \"$biome\" {arguments} || exit 1\n\
+            {END_MARKER}\n"
+        )
+    }
+}
+
+impl FromStr for GitHook {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pre-commit" => Ok(Self::PreCommit),
+            "pre-push" => Ok(Self::PrePush),
+            _ => Err(format!("value {s:?} is not valid for the --hook argument")),
+        }
+    }
+}
+
+impl Display for GitHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitHook::PreCommit => write!(f, "pre-commit"),
+            GitHook::PrePush => write!(f, "pre-push"),
+        }
+    }
+}
+
+/// Handler for the `install-git-hooks` command. It writes the commands that run Biome
+/// in the `hook` of the git repository that contains the working directory, in the folder
+/// of hooks resolved by git (worktrees, submodules and `core.hooksPath`), between two
+/// markers, so running it again replaces them instead of adding them twice.
+///
+/// With `remove`, it removes the commands written by Biome, and keeps the rest of the hook.
+pub(crate) fn install_git_hooks(
+    session: CliSession,
+    hook: GitHook,
+    remove: bool,
+) -> Result<(), CliDiagnostic> {
+    let fs = &*session.app.fs;
+    let working_directory = fs.working_directory().unwrap_or_default();
+    let hooks_directory = fs
+        .git_hooks_directory(&working_directory)
+        .map_err(|error| {
+            CliDiagnostic::NoVcsFolderFound(NoVcsFolderFound {
+                path: working_directory.join(".git").display().to_string(),
+                source: Some(Error::from(IoError::from(error))),
+            })
+        })?;
+    let hook_path = hooks_directory.join(hook.to_string());
+    let display_path = hook_path
+        .strip_prefix(&working_directory)
+        .unwrap_or(&hook_path)
+        .display()
+        .to_string();
+
+    let existing = read_hook(fs, &hook_path)?;
+    let hook_name = hook.to_string();
+    if remove {
+        let Some(existing) = existing.filter(|content| content.contains(START_MARKER)) else {
+            session.app.console.log(markup! {
+                "The "<Emphasis>{hook_name}</Emphasis>" hook doesn't run Biome, there's nothing to remove."
+            });
+            return Ok(());
+        };
+        write_hook(fs, &hook_path, &remove_commands(&existing))?;
+        session.app.console.log(markup! {
+            <Success>"Removed Biome from the "<Emphasis>{hook_name}</Emphasis>" hook "{display_path}"."</Success>
+        });
+        return Ok(());
+    }
+
+    let content = match &existing {
+        Some(existing) => replace_commands(existing, &hook.commands()),
+        None => format!("#!/bin/sh\n{}", hook.commands()),
+    };
+    if existing.as_deref() == Some(content.as_str()) {
+        session.app.console.log(markup! {
+            "The "<Emphasis>{hook_name}</Emphasis>" hook "{display_path}" is already up to date."
+        });
+        return Ok(());
+    }
+
+    write_hook(fs, &hook_path, &content)?;
+    session.app.console.log(markup! {
+        <Success>"Installed the "<Emphasis>{hook_name}</Emphasis>" hook in "{display_path}"."</Success>
+    });
+
+    Ok(())
+}
+
+/// Returns the content of the hook, or [None] if the hook doesn't exist
+fn read_hook(fs: &dyn FileSystem, path: &Path) -> Result<Option<String>, CliDiagnostic> {
+    let mut file = match fs.open_with_options(path, OpenOptions::default().read(true)) {
+        Ok(file) => file,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(CliDiagnostic::io_error(error)),
+    };
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(Some(content))
+}
+
+fn write_hook(fs: &dyn FileSystem, path: &Path, content: &str) -> Result<(), CliDiagnostic> {
+    let mut file = fs.open_with_options(
+        path,
+        OpenOptions::default()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .executable(true),
+    )?;
+    file.set_content(content.as_bytes())?;
+    Ok(())
+}
+
+/// Replaces the commands written by Biome in `content` with `commands`, or appends
+/// `commands` when Biome didn't write any
+fn replace_commands(content: &str, commands: &str) -> String {
+    match content.find(START_MARKER) {
+        Some(start) => {
+            let end = content[start..]
+                .find(END_MARKER)
+                .map_or(content.len(), |end| {
+                    let end = start + end + END_MARKER.len();
+                    // Includes the line break after the marker
+                    content[end..].strip_prefix('\n').map_or(end, |_| end + 1)
+                });
+            format!("{}{commands}{}", &content[..start], &content[end..])
+        }
+        None if content.is_empty() || content.ends_with('\n') => format!("{content}{commands}"),
+        None => format!("{content}\n{commands}"),
+    }
+}
+
+/// Removes the commands written by Biome from `content`
+fn remove_commands(content: &str) -> String {
+    replace_commands(content, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{remove_commands, replace_commands, GitHook};
+
+    #[test]
+    fn replaces_the_commands_of_biome() {
+        let commands = GitHook::PreCommit.commands();
+        let existing = format!(
+            "#!/bin/sh\nnpm test\n{}echo done\n",
+            GitHook::PrePush.commands()
+        );
+
+        let content = replace_commands(&existing, &commands);
+
+        assert_eq!(
+            content,
+            format!("#!/bin/sh\nnpm test\n{commands}echo done\n")
+        );
+        assert_eq!(replace_commands(&content, &commands), content);
+        assert_eq!(
+            remove_commands(&content),
+            "#!/bin/sh\nnpm test\necho done\n"
+        );
+    }
+
+    #[test]
+    fn appends_the_commands_of_biome() {
+        let commands = GitHook::PreCommit.commands();
+
+        assert_eq!(
+            replace_commands("#!/bin/sh\nnpm test", &commands),
+            format!("#!/bin/sh\nnpm test\n{commands}")
+        );
+    }
+}
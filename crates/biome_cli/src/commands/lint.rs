@@ -1,11 +1,14 @@
+use crate::baseline::Baseline;
 use crate::cli_options::CliOptions;
-use crate::configuration::{load_configuration, LoadedConfiguration};
-use crate::vcs::store_path_to_ignore_from_vcs;
+use crate::configuration::{load_configuration, LoadedConfiguration, LoadedConfigurationExt};
+use crate::vcs::{get_changed_files, get_staged_files, store_path_to_ignore_from_vcs};
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
 use biome_service::configuration::vcs::VcsConfiguration;
-use biome_service::configuration::{FilesConfiguration, LinterConfiguration};
+use biome_service::configuration::{
+    FilesConfiguration, IgnoreFiles, LinterConfiguration, NestedConfigurations, RuleSelector,
+};
 use biome_service::workspace::{FixFileMode, UpdateSettingsParams};
 use biome_service::MergeWith;
 use std::ffi::OsString;
@@ -20,6 +23,16 @@ pub(crate) struct LintCommandPayload {
     pub(crate) files_configuration: Option<FilesConfiguration>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_batch: bool,
+    pub(crate) changed: bool,
+    pub(crate) since: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) rules: Vec<RuleSelector>,
+    pub(crate) fixed_rules: Vec<RuleSelector>,
+    pub(crate) suppress: bool,
+    pub(crate) suppression_reason: Option<String>,
+    pub(crate) baseline: bool,
+    pub(crate) write_baseline: bool,
 }
 
 /// Handler for the "lint" command of the Biome CLI
@@ -32,8 +45,18 @@ pub(crate) fn lint(
         apply_unsafe,
         cli_options,
         linter_configuration,
-        paths,
+        mut paths,
         stdin_file_path,
+        stdin_batch,
+        changed,
+        since,
+        staged,
+        rules,
+        fixed_rules,
+        suppress,
+        suppression_reason,
+        baseline,
+        write_baseline,
         vcs_configuration,
         files_configuration,
     } = payload;
@@ -44,6 +67,14 @@ pub(crate) fn lint(
             "--apply",
             "--apply-unsafe",
         ));
+    } else if suppress && (apply || apply_unsafe) {
+        let fix_argument = if apply { "--apply" } else { "--apply-unsafe" };
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--suppress",
+            fix_argument,
+        ));
+    } else if suppress {
+        Some(FixFileMode::ApplySuppressions)
     } else if !apply && !apply_unsafe {
         None
     } else if apply && !apply_unsafe {
@@ -52,6 +83,43 @@ pub(crate) fn lint(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
+    if !fixed_rules.is_empty() && fix_file_mode.is_none() {
+        return Err(CliDiagnostic::missing_argument("--apply", "lint"));
+    }
+
+    if suppression_reason.is_some() && !suppress {
+        return Err(CliDiagnostic::missing_argument("--suppress", "lint"));
+    }
+
+    if baseline && write_baseline {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--baseline",
+            "--write-baseline",
+        ));
+    }
+
+    if staged {
+        if fix_file_mode.is_some() {
+            let fix_argument = if suppress {
+                "--suppress"
+            } else if apply {
+                "--apply"
+            } else {
+                "--apply-unsafe"
+            };
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--staged",
+                fix_argument,
+            ));
+        }
+        if changed || since.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--staged",
+                "--changed",
+            ));
+        }
+    }
+
     let loaded_configuration = load_configuration(&mut session, &cli_options)?.with_file_path();
 
     loaded_configuration.check_for_errors(session.app.console, cli_options.verbose)?;
@@ -67,13 +135,54 @@ pub(crate) fn lint(
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    if changed || since.is_some() {
+        paths = get_changed_files(
+            &mut session,
+            &fs_configuration,
+            vcs_base_path.clone(),
+            since.as_deref(),
+        )?;
+        if paths.is_empty() {
+            return Ok(());
+        }
+    }
+    let staged_files = if staged {
+        let staged_files =
+            get_staged_files(&mut session, &fs_configuration, vcs_base_path.clone())?;
+        if staged_files.is_empty() {
+            return Ok(());
+        }
+        paths = staged_files
+            .keys()
+            .map(|path| path.clone().into_os_string())
+            .collect();
+        Some(staged_files)
+    } else {
+        None
+    };
+    let root_directory = vcs_base_path.clone().unwrap_or_default();
+    let mut ignore_files = IgnoreFiles::new(root_directory.clone(), &*session.app.fs);
     store_path_to_ignore_from_vcs(
         &mut session,
         &mut fs_configuration,
         vcs_base_path,
         &cli_options,
+        Some(&mut ignore_files),
     )?;
+    // The configuration files of the subdirectories are applied on top of this one
+    let nested_configurations = NestedConfigurations::new(
+        root_directory.clone(),
+        fs_configuration.clone(),
+        cli_options.frozen,
+        &*session.app.fs,
+    );
 
+    if stdin_batch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--stdin-batch",
+            "--stdin-file-path",
+        ));
+    }
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
         let console = &mut session.app.console;
         let input_code = console.read();
@@ -93,15 +202,38 @@ pub(crate) fn lint(
         .workspace
         .update_settings(UpdateSettingsParams {
             configuration: fs_configuration,
+            project_directory: None,
         })?;
 
-    execute_mode(
-        Execution::new(TraversalMode::Lint {
-            fix_file_mode,
-            stdin,
-        }),
-        session,
-        &cli_options,
-        paths,
-    )
+    let baseline = if baseline || write_baseline {
+        Some(Baseline::load(
+            &*session.app.fs,
+            &root_directory,
+            write_baseline,
+        )?)
+    } else {
+        None
+    };
+
+    let mut execution = Execution::new(TraversalMode::Lint {
+        fix_file_mode,
+        stdin,
+        rules,
+        fixed_rules,
+        suppression_reason,
+    });
+    execution = execution
+        .with_nested_configurations(nested_configurations)
+        .with_ignore_files(ignore_files);
+    if let Some(staged_files) = staged_files {
+        execution = execution.with_staged_files(staged_files);
+    }
+    if stdin_batch {
+        execution = execution.with_stdin_batch();
+    }
+    if let Some(baseline) = baseline {
+        execution = execution.with_baseline(baseline);
+    }
+
+    execute_mode(execution, session, &cli_options, paths)
 }
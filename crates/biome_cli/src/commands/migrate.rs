@@ -1,14 +1,18 @@
 use crate::cli_options::CliOptions;
+use crate::commands::MigrateSubCommand;
 use crate::configuration::{load_configuration, LoadedConfiguration};
 use crate::diagnostics::MigrationDiagnostic;
 use crate::execute::{execute_mode, Execution, TraversalMode};
 use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use std::ffi::OsStr;
+use std::path::PathBuf;
 
 /// Handler for the "check" command of the Biome CLI
 pub(crate) fn migrate(
     mut session: CliSession,
     cli_options: CliOptions,
     write: bool,
+    sub_command: Option<MigrateSubCommand>,
 ) -> Result<(), CliDiagnostic> {
     let LoadedConfiguration {
         configuration: _,
@@ -18,12 +22,30 @@ pub(crate) fn migrate(
     } = load_configuration(&mut session, &cli_options)?;
     setup_cli_subscriber(cli_options.log_level.clone(), cli_options.log_kind.clone());
 
-    if let (Some(path), Some(directory_path)) = (file_path, directory_path) {
+    if file_path
+        .as_ref()
+        .is_some_and(|path| path.file_name() == Some(OsStr::new("package.json")))
+    {
+        return Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+            reason: "Biome can't migrate the configuration of the \"biome\" field of package.json, move it to a biome.json file first".to_string(),
+        }));
+    }
+
+    let paths = match (file_path, directory_path, sub_command) {
+        (Some(path), Some(directory_path), _) => Some((path, directory_path)),
+        // The configuration of other tools can be migrated to a new configuration file,
+        // created in the working directory
+        (_, _, Some(_)) => Some((PathBuf::from(session.app.fs.config_name()), PathBuf::new())),
+        _ => None,
+    };
+
+    if let Some((path, directory_path)) = paths {
         execute_mode(
             Execution::new(TraversalMode::Migrate {
                 write,
                 configuration_file_path: path,
                 configuration_directory_path: directory_path,
+                sub_command,
             }),
             session,
             &cli_options,
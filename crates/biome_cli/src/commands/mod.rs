@@ -1,4 +1,8 @@
 use crate::cli_options::{cli_options, CliOptions, ColorsArg};
+use crate::commands::completions::{
+    complete_rule_name, complete_shell, parse_rule_selector, parse_shell, Shell,
+};
+use crate::commands::install_git_hooks::GitHook;
 use crate::logging::LoggingKind;
 use crate::{LoggingLevel, VERSION};
 use biome_service::configuration::json::JsonFormatter;
@@ -6,7 +10,7 @@ use biome_service::configuration::vcs::VcsConfiguration;
 use biome_service::configuration::{
     configuration, files_configuration, formatter_configuration, javascript::javascript_formatter,
     json::json_formatter, linter_configuration, vcs::vcs_configuration, FilesConfiguration,
-    FormatterConfiguration, JavascriptFormatter, LinterConfiguration,
+    FormatterConfiguration, JavascriptFormatter, LinterConfiguration, RuleSelector,
 };
 use biome_service::Configuration;
 use bpaf::Bpaf;
@@ -15,9 +19,14 @@ use std::path::PathBuf;
 
 pub(crate) mod check;
 pub(crate) mod ci;
+pub(crate) mod clean;
+pub(crate) mod completions;
 pub(crate) mod daemon;
+pub(crate) mod doctor;
+pub(crate) mod explain;
 pub(crate) mod format;
 pub(crate) mod init;
+pub(crate) mod install_git_hooks;
 pub(crate) mod lint;
 pub(crate) mod migrate;
 pub(crate) mod rage;
@@ -51,6 +60,33 @@ pub enum BiomeCommand {
     #[bpaf(command)]
     Stop,
 
+    /// Removes the logs of the daemon, the socket files left behind by the daemons
+    /// that aren't running anymore, and the cache of the results written with `--cache`
+    #[bpaf(command)]
+    Clean {
+        /// Prints the files that would be removed, without removing them
+        #[bpaf(long("dry-run"), switch)]
+        dry_run: bool,
+    },
+
+    /// Installs a git hook that runs Biome. Running the command again updates the hook,
+    /// and the rest of the hook file is left untouched.
+    #[bpaf(command("install-git-hooks"))]
+    InstallGitHooks {
+        /// The git hook to install: `pre-commit` checks the staged files, and `pre-push`
+        /// checks the whole project.
+        #[bpaf(
+            long("hook"),
+            argument("pre-commit|pre-push"),
+            fallback(GitHook::PreCommit),
+            display_fallback
+        )]
+        hook: GitHook,
+        /// Removes the commands installed by Biome from the hook
+        #[bpaf(long("remove"), switch)]
+        remove: bool,
+    },
+
     /// Runs formatter, linter and import sorting to the requested files.
     #[bpaf(command)]
     Check {
@@ -90,6 +126,36 @@ pub enum BiomeCommand {
         /// Example: `echo 'let a;' | biome check --stdin-file-path=file.js`
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
+        /// Read several files from `stdin`, and print the output of each of them to `stdout`,
+        /// so a build tool can process many files without spawning a process for each of them.
+        ///
+        /// Each file is sent as its path on a line, the length of its content in bytes on a
+        /// line, and its content. The output of each file is printed with the same framing.
+        #[bpaf(long("stdin-batch"), switch, hide_usage)]
+        stdin_batch: bool,
+        /// Process only the files that changed compared to `vcs.defaultBranch`, or compared to
+        /// the git reference passed with `--since`. The paths passed to the command are ignored.
+        #[bpaf(long("changed"), switch)]
+        changed: bool,
+        /// The git reference, e.g. a branch or a commit, to compare against. Implies `--changed`.
+        #[bpaf(long("since"), argument("REF"))]
+        since: Option<String>,
+        /// Process only the files staged in the git index, reading their staged content instead
+        /// of their content in the working tree. The paths passed to the command are ignored.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+        /// Keep running after the first check, and check again the files when they change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+        /// Walk through the lint diagnostics one by one, and choose for each of them whether
+        /// to apply its fix, suppress it or skip it.
+        #[bpaf(long("interactive"), switch)]
+        interactive: bool,
+        /// Write a profile of the run to the file PATH, which can be opened with
+        /// `chrome://tracing` or Perfetto. It contains the time spent parsing, linting and
+        /// formatting each file, and the time spent by each rule.
+        #[bpaf(long("profile"), argument("PATH"))]
+        profile: Option<PathBuf>,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -121,6 +187,57 @@ pub enum BiomeCommand {
         /// Example: `echo 'let a;' | biome lint --stdin-file-path=file.js`
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
+        /// Read several files from `stdin`, and print the output of each of them to `stdout`,
+        /// so a build tool can process many files without spawning a process for each of them.
+        ///
+        /// Each file is sent as its path on a line, the length of its content in bytes on a
+        /// line, and its content. The output of each file is printed with the same framing.
+        #[bpaf(long("stdin-batch"), switch, hide_usage)]
+        stdin_batch: bool,
+        /// Process only the files that changed compared to `vcs.defaultBranch`, or compared to
+        /// the git reference passed with `--since`. The paths passed to the command are ignored.
+        #[bpaf(long("changed"), switch)]
+        changed: bool,
+        /// The git reference, e.g. a branch or a commit, to compare against. Implies `--changed`.
+        #[bpaf(long("since"), argument("REF"))]
+        since: Option<String>,
+        /// Process only the files staged in the git index, reading their staged content instead
+        /// of their content in the working tree. The paths passed to the command are ignored.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+        /// Run only the given rule, regardless of the configuration. The rule uses the syntax
+        /// `<group>/<rule>`, e.g. `--rule=suspicious/noDebugger`. It can be passed multiple times.
+        #[bpaf(
+            long("rule"),
+            argument::<String>("GROUP/RULE"),
+            complete(complete_rule_name),
+            parse(parse_rule_selector),
+            many
+        )]
+        rule: Vec<RuleSelector>,
+        /// Apply only the fixes of the given rule, and leave the other diagnostics untouched.
+        /// It requires `--apply` or `--apply-unsafe`, and it can be passed multiple times.
+        #[bpaf(
+            long("only"),
+            argument::<String>("GROUP/RULE"),
+            complete(complete_rule_name),
+            parse(parse_rule_selector),
+            many
+        )]
+        only: Vec<RuleSelector>,
+        /// Add a suppression comment for each diagnostic, instead of reporting it.
+        #[bpaf(long("suppress"), switch)]
+        suppress: bool,
+        /// The explanation of the suppression comments added by `--suppress`.
+        #[bpaf(long("reason"), argument("REASON"))]
+        reason: Option<String>,
+        /// Don't report the diagnostics recorded in `.biome-baseline.json`. The recorded
+        /// diagnostics that don't occur anymore are removed from it.
+        #[bpaf(long("baseline"), switch)]
+        baseline: bool,
+        /// Record the diagnostics of the processed files in `.biome-baseline.json`.
+        #[bpaf(long("write-baseline"), switch)]
+        write_baseline: bool,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -149,6 +266,13 @@ pub enum BiomeCommand {
         /// Example: `echo 'let a;' | biome format --stdin-file-path=file.js`
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
+        /// Read several files from `stdin`, and print the output of each of them to `stdout`,
+        /// so a build tool can process many files without spawning a process for each of them.
+        ///
+        /// Each file is sent as its path on a line, the length of its content in bytes on a
+        /// line, and its content. The output of each file is printed with the same framing.
+        #[bpaf(long("stdin-batch"), switch, hide_usage)]
+        stdin_batch: bool,
 
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
@@ -157,6 +281,33 @@ pub enum BiomeCommand {
         #[bpaf(switch)]
         write: bool,
 
+        /// Checks that the files are formatted, without writing them. It's the default when
+        /// `--write` isn't passed.
+        #[bpaf(long("check"), switch)]
+        check: bool,
+
+        /// Prints the changes that formatting would make to the files as a unified diff, which
+        /// can be applied with `git apply`. The other diagnostics are printed to `stderr`.
+        #[bpaf(long("patch"), switch)]
+        patch: bool,
+
+        /// Formats each file a second time, and reports the files whose output changes again.
+        /// Use it to catch formatter bugs before adopting Biome in a codebase.
+        #[bpaf(long("verify-idempotence"), switch)]
+        verify_idempotence: bool,
+
+        /// Process only the files that changed compared to `vcs.defaultBranch`, or compared to
+        /// the git reference passed with `--since`. The paths passed to the command are ignored.
+        #[bpaf(long("changed"), switch)]
+        changed: bool,
+        /// The git reference, e.g. a branch or a commit, to compare against. Implies `--changed`.
+        #[bpaf(long("since"), argument("REF"))]
+        since: Option<String>,
+        /// Process only the files staged in the git index, reading their staged content instead
+        /// of their content in the working tree. The paths passed to the command are ignored.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -181,6 +332,17 @@ pub enum BiomeCommand {
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
 
+        /// Report only the diagnostics whose range intersects the lines added or modified
+        /// compared to `vcs.defaultBranch`, or compared to the git reference passed with
+        /// `--since`. Only the changed files are processed, the paths passed to the command
+        /// are ignored.
+        #[bpaf(long("changed-lines-only"), switch)]
+        changed_lines_only: bool,
+        /// The git reference, e.g. a branch or a commit, to compare against. Implies
+        /// `--changed-lines-only`.
+        #[bpaf(long("since"), argument("REF"))]
+        since: Option<String>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -188,21 +350,64 @@ pub enum BiomeCommand {
 
     /// Bootstraps a new biome project. Creates a configuration file with some defaults.
     #[bpaf(command)]
-    Init,
-    /// Acts as a server for the Language Server Protocol over stdin/stdout
+    Init {
+        /// Asks about the project and the formatting preferences, and creates a
+        /// configuration tailored to the answers
+        #[bpaf(long("interactive"), switch)]
+        interactive: bool,
+    },
+    /// Acts as a server for the Language Server Protocol over stdin/stdout, or over a TCP
+    /// address or a named pipe
     #[bpaf(command("lsp-proxy"))]
     LspProxy(
         /// Allows to set a custom path when discovering the configuration file `biome.json`
         #[bpaf(env("BIOME_CONFIG_PATH"), long("config-path"), argument("PATH"))]
         Option<PathBuf>,
+        #[bpaf(external(lsp_proxy_transport), optional)] Option<LspProxyTransport>,
     ),
-    /// It updates the configuration when there are breaking changes
+    /// It updates the configuration when there are breaking changes, and replaces the unknown names of the configuration with the known names that are likely intended
     #[bpaf(command)]
     Migrate(
         #[bpaf(external(cli_options), hide_usage)] CliOptions,
         /// Writes the new configuration file to disk
         #[bpaf(long("write"), switch)]
         bool,
+        #[bpaf(external(migrate_sub_command), optional)] Option<MigrateSubCommand>,
+    ),
+
+    /// Prints the documentation of a lint rule, its options and whether it's enabled
+    #[bpaf(command)]
+    Explain(
+        #[bpaf(external(cli_options), hide_usage)] CliOptions,
+        /// The name of the rule, optionally prefixed by its group, e.g. `noDebugger`
+        /// or `suspicious/noDebugger`
+        #[bpaf(positional("RULE"), complete(complete_rule_name))]
+        String,
+    ),
+
+    /// Prints the script that enables the completions of the CLI in a shell
+    ///
+    /// Example: `biome completions bash > ~/.local/share/bash-completion/completions/biome`
+    #[bpaf(command)]
+    Completions(
+        /// The shell: `bash`, `zsh`, `fish` or `powershell`
+        #[bpaf(
+            positional::<String>("SHELL"),
+            complete(complete_shell),
+            parse(parse_shell)
+        )]
+        Shell,
+    ),
+
+    /// Prints the configuration file that was loaded, the configuration resolved after
+    /// `extends`, whether the given files are processed or ignored and why, and the status
+    /// of the daemon
+    #[bpaf(command)]
+    Doctor(
+        #[bpaf(external(cli_options), hide_usage)] CliOptions,
+        /// The files to check
+        #[bpaf(positional("PATH"), many)]
+        Vec<OsString>,
     ),
 
     #[bpaf(command("__run_server"), hide)]
@@ -226,11 +431,16 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.colors.as_ref(),
-            BiomeCommand::LspProxy(_)
+            | BiomeCommand::Migrate(cli_options, ..)
+            | BiomeCommand::Explain(cli_options, _)
+            | BiomeCommand::Doctor(cli_options, _) => cli_options.colors.as_ref(),
+            BiomeCommand::LspProxy(..)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Init { .. }
+            | BiomeCommand::Clean { .. }
+            | BiomeCommand::InstallGitHooks { .. }
+            | BiomeCommand::Completions(_)
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::PrintSocket => None,
         }
@@ -244,11 +454,16 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.use_server,
-            BiomeCommand::Init
+            | BiomeCommand::Migrate(cli_options, ..)
+            | BiomeCommand::Explain(cli_options, _)
+            | BiomeCommand::Doctor(cli_options, _) => cli_options.use_server,
+            BiomeCommand::Init { .. }
+            | BiomeCommand::Clean { .. }
+            | BiomeCommand::InstallGitHooks { .. }
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::LspProxy(_)
+            | BiomeCommand::LspProxy(..)
+            | BiomeCommand::Completions(_)
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::PrintSocket => false,
         }
@@ -264,13 +479,18 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.verbose,
+            | BiomeCommand::Migrate(cli_options, ..)
+            | BiomeCommand::Explain(cli_options, _)
+            | BiomeCommand::Doctor(cli_options, _) => cli_options.verbose,
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
-            | BiomeCommand::LspProxy(_)
+            | BiomeCommand::Init { .. }
+            | BiomeCommand::Clean { .. }
+            | BiomeCommand::InstallGitHooks { .. }
+            | BiomeCommand::LspProxy(..)
+            | BiomeCommand::Completions(_)
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::PrintSocket => false,
         }
@@ -282,13 +502,18 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.log_level.clone(),
+            | BiomeCommand::Migrate(cli_options, ..)
+            | BiomeCommand::Explain(cli_options, _)
+            | BiomeCommand::Doctor(cli_options, _) => cli_options.log_level.clone(),
             BiomeCommand::Version(_)
-            | BiomeCommand::LspProxy(_)
+            | BiomeCommand::LspProxy(..)
             | BiomeCommand::Rage(..)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Init { .. }
+            | BiomeCommand::Clean { .. }
+            | BiomeCommand::InstallGitHooks { .. }
+            | BiomeCommand::Completions(_)
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::PrintSocket => LoggingLevel::default(),
         }
@@ -299,15 +524,56 @@ impl BiomeCommand {
             | BiomeCommand::Lint { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
-            | BiomeCommand::Migrate(cli_options, _) => cli_options.log_kind.clone(),
+            | BiomeCommand::Migrate(cli_options, ..)
+            | BiomeCommand::Explain(cli_options, _)
+            | BiomeCommand::Doctor(cli_options, _) => cli_options.log_kind.clone(),
             BiomeCommand::Version(_)
             | BiomeCommand::Rage(..)
-            | BiomeCommand::LspProxy(_)
+            | BiomeCommand::LspProxy(..)
             | BiomeCommand::Start(_)
             | BiomeCommand::Stop
-            | BiomeCommand::Init
+            | BiomeCommand::Init { .. }
+            | BiomeCommand::Clean { .. }
+            | BiomeCommand::InstallGitHooks { .. }
+            | BiomeCommand::Completions(_)
             | BiomeCommand::RunServer { .. }
             | BiomeCommand::PrintSocket => LoggingKind::default(),
         }
     }
 }
+
+/// The transports of `lsp-proxy` other than stdin/stdout. The proxy listens for the
+/// connections of the editors, and each connection gets its own session of the language server.
+#[derive(Debug, Clone, Bpaf)]
+pub enum LspProxyTransport {
+    Tcp(
+        /// Listens for the editors on a TCP address, e.g. `127.0.0.1:9000`, so an editor on the
+        /// host can connect to Biome running in a container or in WSL
+        #[bpaf(long("tcp"), argument("ADDRESS"))]
+        String,
+    ),
+    Pipe(
+        /// Listens for the editors on a named pipe, or on a Unix domain socket at the given path
+        /// on Unix systems
+        #[bpaf(long("pipe"), argument("NAME"))]
+        String,
+    ),
+}
+
+/// The tools whose configuration can be migrated to Biome
+#[derive(Debug, Clone, Copy, Bpaf)]
+pub enum MigrateSubCommand {
+    /// Migrates the configuration of ESLint to Biome
+    ///
+    /// It migrates the rules, the globals and the ignored files of the ESLint configuration
+    /// file found in the working directory into the Biome configuration file.
+    #[bpaf(command)]
+    Eslint,
+    /// Migrates the configuration of Prettier to Biome
+    ///
+    /// It migrates the options of the Prettier configuration file found in the working
+    /// directory into the formatter options of the Biome configuration file, and the
+    /// patterns of `.prettierignore` into `files.ignore`.
+    #[bpaf(command)]
+    Prettier,
+}
@@ -239,7 +239,7 @@ impl Display for Section<'_> {
     }
 }
 
-struct KeyValuePair<'a>(&'a str, Markup<'a>);
+pub(super) struct KeyValuePair<'a>(pub(super) &'a str, pub(super) Markup<'a>);
 
 impl Display for KeyValuePair<'_> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
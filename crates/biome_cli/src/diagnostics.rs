@@ -57,6 +57,10 @@ pub enum CliDiagnostic {
     MigrateError(MigrationDiagnostic),
     /// When the VCS folder couldn't be found
     NoVcsFolderFound(NoVcsFolderFound),
+    /// Returned when a command is called with a rule that doesn't exist
+    UnknownRule(UnknownRule),
+    /// Returned when the files read from stdin with `--stdin-batch` aren't framed correctly
+    InvalidStdinBatch(InvalidStdinBatch),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -85,6 +89,48 @@ pub struct UnknownCommandHelp {
     command_name: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "Unknown rule {rule_name}",
+        message("Unknown rule "<Emphasis>{self.rule_name}</Emphasis>)
+    ),
+)]
+pub struct UnknownRule {
+    rule_name: String,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "The content of the file {path} couldn't be read from stdin",
+        message("The content of the file "<Emphasis>{self.path}</Emphasis>" couldn't be read from stdin")
+    ),
+)]
+pub struct InvalidStdinBatch {
+    path: String,
+    #[advice]
+    advice: InvalidStdinBatchAdvice,
+}
+
+#[derive(Debug, Default)]
+struct InvalidStdinBatchAdvice;
+
+impl Advices for InvalidStdinBatchAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "With "<Emphasis>"--stdin-batch"</Emphasis>", each file is sent as its path on a line, the length in bytes of its content on a line, and its content encoded in UTF-8."
+            },
+        )
+    }
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     category = "flags/invalid",
@@ -366,6 +412,21 @@ impl CliDiagnostic {
         })
     }
 
+    /// Returned when a command is called with a rule that doesn't exist
+    pub fn unknown_rule(rule_name: impl Into<String>) -> Self {
+        Self::UnknownRule(UnknownRule {
+            rule_name: rule_name.into(),
+        })
+    }
+
+    /// Returned when the content of a file read with `--stdin-batch` is missing or invalid
+    pub fn invalid_stdin_batch(path: impl Into<String>) -> Self {
+        Self::InvalidStdinBatch(InvalidStdinBatch {
+            path: path.into(),
+            advice: InvalidStdinBatchAdvice,
+        })
+    }
+
     /// Returned when a subcommand is called without any arguments
     pub fn empty_arguments() -> Self {
         Self::EmptyArguments(EmptyArguments)
@@ -455,6 +516,67 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when information diagnostics were emitted while running `check` command
+    pub fn check_infos(category: &'static Category) -> Self {
+        Self::CheckError(CheckError {
+            category,
+            message: MessageAndDescription::from(
+                markup! {
+                    "Some "<Emphasis>"information"</Emphasis>" diagnostics were emitted while "<Emphasis>"running checks"</Emphasis>"."
+                }
+                .to_owned(),
+            ),
+        })
+    }
+
+    /// Emitted when information diagnostics were emitted while apply code fixes
+    pub fn apply_infos(category: &'static Category) -> Self {
+        Self::CheckError(CheckError {
+            category,
+            message: MessageAndDescription::from(
+                markup! {
+                    "Some "<Emphasis>"information"</Emphasis>" diagnostics were emitted while "<Emphasis>"applying fixes"</Emphasis>"."
+                }
+                .to_owned(),
+            ),
+        })
+    }
+
+    /// Returns the code the process exits with when the command fails with this diagnostic:
+    ///
+    /// - `1`: the command emitted diagnostics with a severity that makes it fail;
+    /// - `2`: the command line arguments or the configuration are invalid;
+    /// - `3`: Biome encountered an internal error, e.g. it couldn't read a file or
+    ///   connect to the daemon.
+    ///
+    /// Diagnostics that don't have an error severity exit with `0`.
+    pub fn exit_code(&self) -> u8 {
+        if self.severity() < Severity::Error {
+            return 0;
+        }
+        match self {
+            CliDiagnostic::CheckError(_) | CliDiagnostic::FileCheck(_) => 1,
+            CliDiagnostic::UnknownCommand(_)
+            | CliDiagnostic::UnknownCommandHelp(_)
+            | CliDiagnostic::ParseError(_)
+            | CliDiagnostic::UnexpectedArgument(_)
+            | CliDiagnostic::MissingArgument(_)
+            | CliDiagnostic::EmptyArguments(_)
+            | CliDiagnostic::IncompatibleArguments(_)
+            | CliDiagnostic::OverflowNumberArgument(_)
+            | CliDiagnostic::IncompatibleEndConfiguration(_)
+            | CliDiagnostic::NoFilesWereProcessed(_)
+            | CliDiagnostic::MigrateError(_)
+            | CliDiagnostic::NoVcsFolderFound(_)
+            | CliDiagnostic::UnknownRule(_)
+            | CliDiagnostic::InvalidStdinBatch(_)
+            | CliDiagnostic::WorkspaceError(WorkspaceError::Configuration(_)) => 2,
+            CliDiagnostic::WorkspaceError(_)
+            | CliDiagnostic::IoError(_)
+            | CliDiagnostic::ServerNotRunning(_) => 3,
+        }
+    }
+
     /// Emitted for a file that has code fixes, but still has diagnostics to address
     pub fn file_check_apply_error(
         file_path: impl Into<String>,
@@ -537,6 +659,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.category(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.category(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.category(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.category(),
+            CliDiagnostic::InvalidStdinBatch(diagnostic) => diagnostic.category(),
         }
     }
 
@@ -559,6 +683,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.tags(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.tags(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.tags(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.tags(),
+            CliDiagnostic::InvalidStdinBatch(diagnostic) => diagnostic.tags(),
         }
     }
 
@@ -581,6 +707,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.severity(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.severity(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.severity(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.severity(),
+            CliDiagnostic::InvalidStdinBatch(diagnostic) => diagnostic.severity(),
         }
     }
 
@@ -603,6 +731,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.location(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.location(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.location(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.location(),
+            CliDiagnostic::InvalidStdinBatch(diagnostic) => diagnostic.location(),
         }
     }
 
@@ -625,6 +755,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.message(fmt),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.message(fmt),
+            CliDiagnostic::InvalidStdinBatch(diagnostic) => diagnostic.message(fmt),
         }
     }
 
@@ -647,6 +779,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.description(fmt),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.description(fmt),
+            CliDiagnostic::InvalidStdinBatch(diagnostic) => diagnostic.description(fmt),
         }
     }
 
@@ -669,6 +803,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.advices(visitor),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.advices(visitor),
+            CliDiagnostic::InvalidStdinBatch(diagnostic) => diagnostic.advices(visitor),
         }
     }
 
@@ -695,6 +831,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.verbose_advices(visitor),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.verbose_advices(visitor),
+            CliDiagnostic::InvalidStdinBatch(diagnostic) => diagnostic.verbose_advices(visitor),
         }
     }
 
@@ -717,6 +855,8 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheck(diagnostic) => diagnostic.source(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.source(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.source(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.source(),
+            CliDiagnostic::InvalidStdinBatch(diagnostic) => diagnostic.source(),
         }
     }
 }
@@ -735,12 +875,7 @@ impl From<std::io::Error> for CliDiagnostic {
 
 impl Termination for CliDiagnostic {
     fn report(self) -> ExitCode {
-        let severity = self.severity();
-        if severity >= Severity::Error {
-            ExitCode::FAILURE
-        } else {
-            ExitCode::SUCCESS
-        }
+        ExitCode::from(self.exit_code())
     }
 }
 
@@ -40,6 +40,18 @@ pub(crate) struct FormatDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "format",
+    message = "Formatting this file isn't idempotent, formatting it a second time printed the following content:"
+)]
+pub(crate) struct FormatIdempotenceDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 	category = "organizeImports",
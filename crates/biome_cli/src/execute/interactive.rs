@@ -0,0 +1,275 @@
+use crate::execute::diagnostics::{ResultExt, ResultIoExt};
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Error, PrintDiagnostic};
+use biome_fs::{FileSystem, OpenOptions, PathInterner, RomePath, TraversalContext, TraversalScope};
+use biome_service::workspace::{
+    FeatureName, FeaturesBuilder, FileGuard, IsPathIgnoredParams, OpenFileParams, RuleCategories,
+    SupportsFeatureParams,
+};
+use biome_service::Workspace;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Walks through the lint diagnostics of the files at `paths`, one by one. For each
+/// diagnostic, the user chooses whether to apply its fix, suppress it, skip it, or stop.
+///
+/// The files are processed sequentially, and the changes are written to disk as soon as
+/// they are accepted.
+pub(crate) fn run(session: &mut CliSession, paths: Vec<OsString>) -> Result<(), CliDiagnostic> {
+    if paths.is_empty() {
+        return Err(CliDiagnostic::missing_argument("<INPUT>", "check"));
+    }
+
+    let fs = &*session.app.fs;
+    let workspace = &*session.app.workspace;
+    let console = &mut *session.app.console;
+
+    let (files, errors) = collect_files(fs, workspace, paths);
+    for error in errors {
+        console.error(markup! {{PrintDiagnostic::simple(&error)}});
+    }
+
+    let mut summary = InteractiveSummary::default();
+    for path in files {
+        match review_file(fs, workspace, console, &path, &mut summary) {
+            Ok(Review::Continue) => {}
+            Ok(Review::Quit) => break,
+            Err(error) => console.error(markup! {{PrintDiagnostic::simple(&error)}}),
+        }
+    }
+
+    console.log(markup! {
+        <Info>"Fixed "{summary.fixed}" diagnostic(s), suppressed "{summary.suppressed}", skipped "{summary.skipped}"."</Info>
+    });
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct InteractiveSummary {
+    fixed: usize,
+    suppressed: usize,
+    skipped: usize,
+}
+
+enum Review {
+    Continue,
+    Quit,
+}
+
+/// The answers that the user can give for a diagnostic
+enum Answer {
+    Apply,
+    Skip,
+    Suppress,
+    Quit,
+}
+
+impl Answer {
+    fn parse(answer: &str) -> Option<Self> {
+        match answer.trim().to_lowercase().as_str() {
+            "a" | "apply" => Some(Self::Apply),
+            "s" | "skip" => Some(Self::Skip),
+            "u" | "suppress" => Some(Self::Suppress),
+            "q" | "quit" => Some(Self::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Traverses the file system, and collects the files that can be linted
+fn collect_files(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    paths: Vec<OsString>,
+) -> (Vec<PathBuf>, Vec<Error>) {
+    let (interner, _) = PathInterner::new();
+    let ctx = CollectFilesContext {
+        interner,
+        workspace,
+        files: Mutex::new(Vec::new()),
+        errors: Mutex::new(Vec::new()),
+    };
+
+    fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+        for path in paths {
+            scope.spawn(&ctx, PathBuf::from(path));
+        }
+    }));
+
+    let mut files = ctx.files.into_inner().unwrap();
+    files.sort();
+    (files, ctx.errors.into_inner().unwrap())
+}
+
+struct CollectFilesContext<'app> {
+    interner: PathInterner,
+    workspace: &'app dyn Workspace,
+    files: Mutex<Vec<PathBuf>>,
+    errors: Mutex<Vec<Error>>,
+}
+
+impl<'app> TraversalContext for CollectFilesContext<'app> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, error: Error) {
+        self.errors.lock().unwrap().push(error);
+    }
+
+    fn can_handle(&self, rome_path: &RomePath) -> bool {
+        if rome_path.is_dir() {
+            return self
+                .workspace
+                .is_path_ignored(IsPathIgnoredParams {
+                    rome_path: rome_path.clone(),
+                    feature: FeatureName::Lint,
+                })
+                .is_ok_and(|is_ignored| !is_ignored);
+        }
+
+        self.workspace
+            .file_features(SupportsFeatureParams {
+                path: rome_path.clone(),
+                feature: FeaturesBuilder::new().with_linter().build(),
+            })
+            .is_ok_and(|file_features| file_features.supports_for(&FeatureName::Lint))
+    }
+
+    fn handle_file(&self, path: &Path) {
+        self.files.lock().unwrap().push(path.to_path_buf());
+    }
+}
+
+/// Asks the user what to do with each lint diagnostic of the file at `path`, until the file
+/// doesn't have diagnostics left to review
+fn review_file(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    console: &mut dyn Console,
+    path: &Path,
+    summary: &mut InteractiveSummary,
+) -> Result<Review, Error> {
+    let file_path = path.display().to_string();
+    let mut file = fs
+        .open_with_options(path, OpenOptions::default().read(true).write(true))
+        .with_file_path(file_path.clone())?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .with_file_path(file_path.clone())?;
+
+    let guard = FileGuard::open(
+        workspace,
+        OpenFileParams {
+            path: RomePath::new(path),
+            version: 0,
+            content: content.clone(),
+            language_hint: Default::default(),
+        },
+    )
+    .with_file_path_and_code(file_path.clone(), category!("internalError/fs"))?;
+
+    let mut version = 0;
+    // Diagnostics are sorted by position, the skipped ones are always the first ones
+    let mut skipped = 0;
+    loop {
+        let mut diagnostics = guard
+            .pull_diagnostics(RuleCategories::LINT, u64::MAX, Vec::new(), false)
+            .with_file_path_and_code(file_path.clone(), category!("lint"))?
+            .diagnostics;
+        diagnostics.sort_by_key(|diagnostic| diagnostic.location().span.map(|span| span.start()));
+        let Some(diagnostic) = diagnostics.into_iter().nth(skipped) else {
+            return Ok(Review::Continue);
+        };
+
+        let actions = match diagnostic.location().span {
+            Some(span) => {
+                guard
+                    .pull_actions(span)
+                    .with_file_path_and_code(file_path.clone(), category!("lint"))?
+                    .actions
+            }
+            None => Vec::new(),
+        };
+        let rule_name = diagnostic
+            .category()
+            .and_then(|category| category.name().strip_prefix("lint/"))
+            .and_then(|name| name.split_once('/'));
+        let find_action = |is_suppression: bool| {
+            actions.iter().find(|action| {
+                action
+                    .rule_name
+                    .as_ref()
+                    .map(|(group, rule)| (group.as_ref(), rule.as_ref()))
+                    == rule_name
+                    && action.category.matches("quickfix.suppressRule") == is_suppression
+                    && (is_suppression || action.category.matches("quickfix.biome"))
+            })
+        };
+        let fix = find_action(false);
+        let suppression = find_action(true);
+
+        let error = Error::from(diagnostic)
+            .with_file_path(file_path.clone())
+            .with_file_source_code(&content);
+        console.error(markup! {{PrintDiagnostic::verbose(&error)}});
+
+        let action = loop {
+            match (fix.is_some(), suppression.is_some()) {
+                (true, true) => console.log(markup! {
+                    <Emphasis>"[a]pply the fix, [s]kip, s[u]ppress, [q]uit?"</Emphasis>
+                }),
+                (true, false) => console.log(markup! {
+                    <Emphasis>"[a]pply the fix, [s]kip, [q]uit?"</Emphasis>
+                }),
+                (false, true) => console.log(markup! {
+                    <Emphasis>"[s]kip, s[u]ppress, [q]uit?"</Emphasis>
+                }),
+                (false, false) => console.log(markup! {
+                    <Emphasis>"[s]kip, [q]uit?"</Emphasis>
+                }),
+            }
+
+            let Some(answer) = console.read_line() else {
+                return Ok(Review::Quit);
+            };
+            match Answer::parse(&answer) {
+                Some(Answer::Apply) if fix.is_some() => break fix.map(|fix| (fix, true)),
+                Some(Answer::Suppress) if suppression.is_some() => {
+                    break suppression.map(|suppression| (suppression, false))
+                }
+                Some(Answer::Skip) => break None,
+                Some(Answer::Quit) => return Ok(Review::Quit),
+                _ => console.error(markup! {
+                    <Warn>"The answer "<Emphasis>{answer}</Emphasis>" isn't valid for this diagnostic."</Warn>
+                }),
+            }
+        };
+
+        let Some((action, is_fix)) = action else {
+            skipped += 1;
+            summary.skipped += 1;
+            continue;
+        };
+        let new_content = action.suggestion.suggestion.new_string(&content);
+        if new_content == content {
+            skipped += 1;
+            summary.skipped += 1;
+            continue;
+        }
+
+        file.set_content(new_content.as_bytes())
+            .with_file_path(file_path.clone())?;
+        version += 1;
+        guard.change_file(version, new_content.clone())?;
+        content = new_content;
+        if is_fix {
+            summary.fixed += 1;
+        } else {
+            summary.suppressed += 1;
+        }
+    }
+}
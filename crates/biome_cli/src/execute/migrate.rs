@@ -1,6 +1,12 @@
+mod config_file;
+pub(crate) mod eslint;
+mod eslint_rules;
+pub(crate) mod prettier;
+
 use crate::execute::diagnostics::{ContentDiffAdvice, MigrateDiffDiagnostic};
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, ConsoleExt};
+use biome_deserialize::Deserializable;
 use biome_diagnostics::{category, PrintDiagnostic};
 use biome_fs::{FileSystemExt, OpenOptions};
 use biome_json_parser::JsonParserOptions;
@@ -8,7 +14,9 @@ use biome_json_syntax::JsonRoot;
 use biome_migrate::{migrate_configuration, ControlFlow};
 use biome_rowan::AstNode;
 use biome_service::workspace::FixAction;
+use biome_service::Configuration;
 use std::borrow::Cow;
+use std::cmp::Reverse;
 use std::ffi::OsStr;
 use std::path::PathBuf;
 
@@ -74,7 +82,7 @@ pub(crate) fn run(
         }
     }
     let console = &mut *session.app.console;
-    let new_configuration_content = tree.to_string();
+    let new_configuration_content = apply_suggestions(tree.to_string());
 
     if configuration_content != new_configuration_content || has_deprecated_configuration {
         if write {
@@ -84,7 +92,7 @@ pub(crate) fn run(
             } else {
                 configuration_file
             };
-            configuration_file.set_content(tree.to_string().as_bytes())?;
+            configuration_file.set_content(new_configuration_content.as_bytes())?;
             console.log(markup!{
                 <Info>"The configuration "<Emphasis>{{configuration_file_path.display().to_string()}}</Emphasis>" has been successfully migrated."</Info>
             })
@@ -123,3 +131,32 @@ pub(crate) fn run(
     }
     Ok(())
 }
+
+/// Replaces the unknown keys and values of the configuration with the known names that are
+/// likely the intended ones, for example `noUnusedVarables` with `noUnusedVariables`.
+fn apply_suggestions(mut content: String) -> String {
+    // The values of an unknown key are checked only once the key is replaced
+    loop {
+        let tree = biome_json_parser::parse_json(&content, JsonParserOptions::default()).tree();
+        let Ok(value) = tree.value() else {
+            return content;
+        };
+        let mut diagnostics = vec![];
+        Configuration::deserialize(&value, "", &mut diagnostics);
+        let mut suggestions: Vec<_> = diagnostics
+            .iter()
+            .filter_map(|diagnostic| diagnostic.suggestion())
+            .collect();
+        if suggestions.is_empty() {
+            return content;
+        }
+        // The suggestions are applied from the end, so that the ranges of the others stay valid
+        suggestions.sort_by_key(|(range, _)| Reverse(range.start()));
+        for (range, suggestion) in suggestions {
+            content.replace_range(
+                usize::from(range.start())..usize::from(range.end()),
+                &format!("\"{suggestion}\""),
+            );
+        }
+    }
+}
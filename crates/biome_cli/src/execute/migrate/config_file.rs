@@ -0,0 +1,348 @@
+//! Loads the configuration files of other tools, so that they can be migrated.
+//!
+//! JSON files are read with the JSON parser of Biome, which accepts the comments and the
+//! trailing commas that many tools allow. JavaScript and YAML files can't be read statically,
+//! so they are evaluated by Node.js, which prints the resulting configuration as JSON.
+//!
+//! The migrated settings are then merged into the Biome configuration file.
+
+use crate::diagnostics::MigrationDiagnostic;
+use crate::execute::diagnostics::{ContentDiffAdvice, MigrateDiffDiagnostic};
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::PrintDiagnostic;
+use biome_fs::{FileSystem, FileSystemExt, OpenOptions};
+use biome_json_formatter::context::JsonFormatOptions;
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::AnyJsonValue;
+use biome_rowan::AstSeparatedList;
+use biome_service::Configuration;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::path::Path;
+use std::process::Command;
+
+/// Script evaluated by Node.js. It loads the configuration file passed as first argument,
+/// as YAML if the second argument is `yaml`, and prints it as JSON. Values that can't be serialized, like functions and the objects of
+/// plugins and parsers, are omitted.
+const NODE_SCRIPT: &str = r#"
+const path = require("node:path").resolve(process.argv[1]);
+const loadYaml = (content) => {
+    for (const [name, parse] of [["js-yaml", (yaml) => yaml.load], ["yaml", (yaml) => yaml.parse]]) {
+        try {
+            return parse(require(require.resolve(name, { paths: [process.cwd()] })))(content);
+        } catch (error) {
+            if (error.code !== "MODULE_NOT_FOUND") {
+                throw error;
+            }
+        }
+    }
+    throw new Error("YAML files can be read only if the package js-yaml or yaml is installed");
+};
+const load = process.argv[2] === "yaml"
+    ? Promise.resolve().then(() => loadYaml(require("node:fs").readFileSync(path, "utf8")))
+    : import(require("node:url").pathToFileURL(path)).then((module) => module.default);
+load.then(async (config) => {
+    const ancestors = [];
+    const json = JSON.stringify(await config, function (key, value) {
+        if (key === "parser" || key === "processor" || (key === "plugins" && !Array.isArray(value))) {
+            return undefined;
+        }
+        if (typeof value !== "object" || value === null) {
+            return value;
+        }
+        while (ancestors.length > 0 && ancestors.at(-1) !== this) {
+            ancestors.pop();
+        }
+        if (ancestors.includes(value)) {
+            return undefined;
+        }
+        ancestors.push(value);
+        return value;
+    });
+    process.stdout.write(json ?? "null");
+}).catch((error) => {
+    process.stderr.write(String(error));
+    process.exit(1);
+});
+"#;
+
+/// Returns the strings of a value that is either a string or an array of strings
+pub(super) fn strings(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(string) => vec![string.clone()],
+        Value::Array(items) => items.iter().flat_map(strings).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns `true` if the file exists and can be read
+pub(super) fn exists(fs: &dyn FileSystem, path: &Path) -> bool {
+    fs.open_with_options(path, OpenOptions::default().read(true))
+        .is_ok()
+}
+
+/// Reads the content of a file
+pub(super) fn read_to_string(fs: &dyn FileSystem, path: &Path) -> Result<String, CliDiagnostic> {
+    let mut file = fs.open_with_options(path, OpenOptions::default().read(true))?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+/// Loads a configuration file, using its extension to determine its format. Files without
+/// extension can contain either JSON or YAML.
+pub(super) fn load(fs: &dyn FileSystem, path: &Path) -> Result<Value, CliDiagnostic> {
+    let syntax_error = || {
+        migration_error(format!(
+            "The file {} can't be parsed, it contains syntax errors",
+            path.display()
+        ))
+    };
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("js" | "cjs" | "mjs") => evaluate_with_node(path, false),
+        Some("yaml" | "yml") => evaluate_with_node(path, true),
+        Some("toml") => Err(migration_error(format!(
+            "The file {} can't be migrated, TOML files aren't supported",
+            path.display()
+        ))),
+        None => match parse(&read_to_string(fs, path)?) {
+            Some(value) => Ok(value),
+            None => evaluate_with_node(path, true),
+        },
+        _ => parse(&read_to_string(fs, path)?).ok_or_else(syntax_error),
+    }
+}
+
+/// Parses JSON, allowing comments and trailing commas
+pub(super) fn parse(content: &str) -> Option<Value> {
+    let options = JsonParserOptions::default()
+        .with_allow_comments()
+        .with_allow_trailing_commas();
+    let parsed = parse_json(content, options);
+    if parsed.has_errors() {
+        return None;
+    }
+    to_value(parsed.tree().value().ok()?)
+}
+
+fn to_value(value: AnyJsonValue) -> Option<Value> {
+    let value = match value {
+        AnyJsonValue::JsonArrayValue(array) => Value::Array(
+            array
+                .elements()
+                .iter()
+                .map(|element| to_value(element.ok()?))
+                .collect::<Option<_>>()?,
+        ),
+        AnyJsonValue::JsonObjectValue(object) => Value::Object(
+            object
+                .json_member_list()
+                .iter()
+                .map(|member| {
+                    let member = member.ok()?;
+                    let name = member.name().ok()?.value_token().ok()?;
+                    let name = serde_json::from_str(name.text_trimmed()).ok()?;
+                    Some((name, to_value(member.value().ok()?)?))
+                })
+                .collect::<Option<Map<_, _>>>()?,
+        ),
+        AnyJsonValue::JsonBooleanValue(value) => {
+            Value::Bool(value.value_token().ok()?.text_trimmed() == "true")
+        }
+        AnyJsonValue::JsonNullValue(_) => Value::Null,
+        // The JSON parser of Biome validated the literals, `serde_json` only has to unescape
+        // the strings
+        AnyJsonValue::JsonNumberValue(value) => {
+            serde_json::from_str(value.value_token().ok()?.text_trimmed()).ok()?
+        }
+        AnyJsonValue::JsonStringValue(value) => {
+            serde_json::from_str(value.value_token().ok()?.text_trimmed()).ok()?
+        }
+        AnyJsonValue::JsonBogusValue(_) => return None,
+    };
+    Some(value)
+}
+
+fn evaluate_with_node(path: &Path, yaml: bool) -> Result<Value, CliDiagnostic> {
+    let output = Command::new("node")
+        .arg("--eval")
+        .arg(NODE_SCRIPT)
+        .arg(path)
+        .arg(if yaml { "yaml" } else { "module" })
+        .output()
+        .map_err(|_| {
+            migration_error(format!(
+                "Biome needs Node.js to evaluate the file {}, but the command `node` couldn't be run",
+                path.display()
+            ))
+        })?;
+
+    if !output.status.success() {
+        return Err(migration_error(format!(
+            "Node.js couldn't evaluate the file {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|_| {
+        migration_error(format!(
+            "Node.js evaluated the file {}, but its output isn't valid JSON",
+            path.display()
+        ))
+    })
+}
+
+pub(super) fn migration_error(reason: String) -> CliDiagnostic {
+    CliDiagnostic::MigrateError(MigrationDiagnostic { reason })
+}
+
+/// Applies `migrate` to the Biome configuration file `configuration_file_path`, which is
+/// created if it doesn't exist. The new configuration is written when `write` is `true`,
+/// otherwise the changes are printed.
+pub(super) fn update_biome_configuration(
+    session: &mut CliSession,
+    configuration_file_path: &Path,
+    source_path: &Path,
+    write: bool,
+    verbose: bool,
+    migrate: impl FnOnce(&mut Configuration),
+) -> Result<(), CliDiagnostic> {
+    let fs = &*session.app.fs;
+    let configuration_exists = exists(fs, configuration_file_path);
+    let configuration_content = if configuration_exists {
+        read_to_string(fs, configuration_file_path)?
+    } else {
+        String::new()
+    };
+    let read_error = || {
+        migration_error(format!(
+            "Biome couldn't read the configuration file {}",
+            configuration_file_path.display()
+        ))
+    };
+    let (up_to_date, new_configuration_content) = if configuration_exists {
+        // Deserializing fills the missing fields with their default values, so only the
+        // settings changed by the migration are copied into the existing file
+        let mut content = parse(&configuration_content).ok_or_else(read_error)?;
+        let mut configuration: Configuration =
+            serde_json::from_value(content.clone()).map_err(|_| read_error())?;
+        let old_value = serialize(&configuration)?;
+        migrate(&mut configuration);
+        let new_value = serialize(&configuration)?;
+        let up_to_date = old_value == new_value;
+        patch(&mut content, &old_value, new_value);
+        (up_to_date, print_configuration(&content)?)
+    } else {
+        let mut configuration = Configuration::default();
+        migrate(&mut configuration);
+        (false, print_configuration(&configuration)?)
+    };
+
+    let console = &mut *session.app.console;
+    let file_name = configuration_file_path.display().to_string();
+    let source_file_name = source_path.display().to_string();
+    if up_to_date {
+        console.log(markup! {
+            <Info>"The configuration "<Emphasis>{file_name}</Emphasis>" is already up to date with the configuration "<Emphasis>{source_file_name}</Emphasis>"."</Info>
+        });
+    } else if write {
+        let mut file = if configuration_exists {
+            fs.open_with_options(
+                configuration_file_path,
+                OpenOptions::default().read(true).write(true),
+            )?
+        } else {
+            fs.create_new(configuration_file_path)?
+        };
+        file.set_content(new_configuration_content.as_bytes())?;
+        console.log(markup! {
+            <Info>"The configuration "<Emphasis>{source_file_name}</Emphasis>" has been successfully migrated to "<Emphasis>{file_name}</Emphasis>"."</Info>
+        });
+    } else {
+        let diagnostic = MigrateDiffDiagnostic {
+            file_name,
+            diff: ContentDiffAdvice {
+                old: configuration_content,
+                new: new_configuration_content,
+            },
+        };
+        console.error(markup! {
+            {if verbose { PrintDiagnostic::verbose(&diagnostic) } else { PrintDiagnostic::simple(&diagnostic) }}
+        });
+    }
+
+    Ok(())
+}
+
+fn serialize(configuration: &Configuration) -> Result<Value, CliDiagnostic> {
+    serde_json::to_value(configuration).map_err(|_| {
+        migration_error("Biome couldn't serialize the migrated configuration".to_string())
+    })
+}
+
+/// Copies into `content` the members of `new_value` that are different in `old_value`,
+/// and removes the ones that aren't in `new_value` anymore. Objects are patched member
+/// by member, any other value is replaced.
+fn patch(content: &mut Value, old_value: &Value, new_value: Value) {
+    match (content, new_value) {
+        (Value::Object(content), Value::Object(new_value)) => {
+            content.retain(|key, _| old_value.get(key).is_none() || new_value.contains_key(key));
+            for (key, new_member) in new_value {
+                let old_member = old_value.get(&key).unwrap_or(&Value::Null);
+                if *old_member == new_member {
+                    continue;
+                }
+                match content.get_mut(&key) {
+                    Some(member) if member.is_object() && old_member.is_object() => {
+                        patch(member, old_member, new_member);
+                    }
+                    _ => {
+                        content.insert(key, new_member);
+                    }
+                }
+            }
+        }
+        (content, new_value) => *content = new_value,
+    }
+}
+
+/// Prints the configuration like `biome init` does
+fn print_configuration(configuration: &impl Serialize) -> Result<String, CliDiagnostic> {
+    let content = serde_json::to_string_pretty(configuration).map_err(|_| {
+        migration_error("Biome couldn't serialize the migrated configuration".to_string())
+    })?;
+    let parsed = parse_json(&content, JsonParserOptions::default());
+    let formatted =
+        biome_json_formatter::format_node(JsonFormatOptions::default(), &parsed.syntax())
+            .ok()
+            .and_then(|formatted| formatted.print().ok())
+            .ok_or_else(|| {
+                migration_error("Biome couldn't format the migrated configuration".to_string())
+            })?;
+    Ok(formatted.into_code())
+}
+
+/// Prints a list of settings that couldn't be migrated, if there are any
+pub(super) fn print_report_section<'a>(
+    console: &mut dyn Console,
+    title: &str,
+    items: impl IntoIterator<Item = &'a String>,
+) {
+    let items: Vec<_> = items.into_iter().map(|item| format!("- {item}")).collect();
+    if items.is_empty() {
+        return;
+    }
+    let items = items.join("\n");
+    console.log(markup! {
+        <Warn>{title}</Warn>"\n"{items}
+    });
+}
+
+/// Tells how to apply the changes printed by the command `biome migrate <command>`
+pub(super) fn print_write_hint(console: &mut dyn Console, command: &str) {
+    console.log(markup! {
+        "Run the command "<Emphasis>"biome migrate "{command}" --write"</Emphasis>" to apply the changes."
+    });
+}
@@ -0,0 +1,542 @@
+//! Migrates the configuration of ESLint to Biome.
+//!
+//! Both the legacy configuration files (`.eslintrc.*` and the `eslintConfig` field of
+//! `package.json`) and the flat configuration files (`eslint.config.*`) are supported.
+//! The rules that have an equivalent in Biome are enabled with the same severity, the
+//! globals and the ignored files are copied, and the overrides become Biome's `overrides`.
+//! Everything else is listed in a report, so that it can be migrated manually.
+
+use crate::execute::migrate::config_file::{self, migration_error};
+use crate::execute::migrate::eslint_rules;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_deserialize::StringSet;
+use biome_fs::FileSystem;
+use biome_service::configuration::javascript::{JavascriptGlobalAccess, JavascriptGlobals};
+use biome_service::configuration::linter::RulePlainConfiguration;
+use biome_service::configuration::{
+    JavascriptConfiguration, LinterConfiguration, MergeWith, OverrideLinterConfiguration,
+    OverridePattern, Overrides,
+};
+use biome_service::{Configuration, Rules};
+use indexmap::{IndexMap, IndexSet};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// The configuration files of ESLint, in the order ESLint looks for them
+const CONFIG_FILES: &[&str] = &[
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.cjs",
+    ".eslintrc.js",
+    ".eslintrc.cjs",
+    ".eslintrc.yaml",
+    ".eslintrc.yml",
+    ".eslintrc.json",
+    ".eslintrc",
+];
+
+pub(crate) fn run(
+    mut session: CliSession,
+    write: bool,
+    configuration_file_path: PathBuf,
+    configuration_directory_path: PathBuf,
+    verbose: bool,
+) -> Result<(), CliDiagnostic> {
+    let fs = &*session.app.fs;
+    let (eslint_path, eslint_configuration) =
+        load_eslint_configuration(fs, &configuration_directory_path)?;
+
+    let mut migration = EslintMigration::default();
+    let is_flat = eslint_path
+        .file_name()
+        .and_then(|file_name| file_name.to_str())
+        .is_some_and(|file_name| file_name.starts_with("eslint.config"));
+    if is_flat {
+        match eslint_configuration {
+            Value::Array(configurations) => {
+                for configuration in &configurations {
+                    migration.add_flat_configuration(configuration);
+                }
+            }
+            configuration => migration.add_flat_configuration(&configuration),
+        }
+    } else {
+        migration.add_legacy_configuration(&eslint_configuration);
+        let eslint_ignore_path = configuration_directory_path.join(".eslintignore");
+        if config_file::exists(fs, &eslint_ignore_path) {
+            let content = config_file::read_to_string(fs, &eslint_ignore_path)?;
+            migration.add_ignore_patterns(
+                content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#')),
+            );
+        }
+    }
+
+    config_file::update_biome_configuration(
+        &mut session,
+        &configuration_file_path,
+        &eslint_path,
+        write,
+        verbose,
+        |configuration| migration.apply(configuration),
+    )?;
+
+    let console = &mut *session.app.console;
+    migration.report(console);
+    if !write {
+        config_file::print_write_hint(console, "eslint");
+    }
+
+    Ok(())
+}
+
+/// Finds the configuration of ESLint inside `directory`, and returns its path and its content
+fn load_eslint_configuration(
+    fs: &dyn FileSystem,
+    directory: &Path,
+) -> Result<(PathBuf, Value), CliDiagnostic> {
+    for file_name in CONFIG_FILES {
+        let path = directory.join(file_name);
+        if config_file::exists(fs, &path) {
+            let configuration = config_file::load(fs, &path)?;
+            return Ok((path, configuration));
+        }
+    }
+
+    let package_json_path = directory.join("package.json");
+    if config_file::exists(fs, &package_json_path) {
+        let package_json = config_file::load(fs, &package_json_path)?;
+        if let Some(configuration) = package_json.get("eslintConfig") {
+            return Ok((package_json_path, configuration.clone()));
+        }
+    }
+
+    Err(migration_error(
+        "Biome couldn't find the configuration file of ESLint".to_string(),
+    ))
+}
+
+/// A rule of Biome, identified by its group and its name
+type BiomeRule = (&'static str, &'static str);
+
+/// The ESLint rules enabled in a scope, which is either the whole project or the files
+/// of an override. The rules of ESLint are kept in the order they're configured: when a
+/// rule is configured twice, the last configuration wins, like in ESLint.
+#[derive(Default)]
+struct EslintRules(IndexMap<String, (RulePlainConfiguration, Vec<Value>)>);
+
+impl EslintRules {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// An override of ESLint, which applies some rules only to some files
+struct EslintOverride {
+    include: Vec<String>,
+    ignore: Vec<String>,
+    rules: EslintRules,
+    globals: Vec<String>,
+}
+
+#[derive(Default)]
+struct EslintMigration {
+    rules: EslintRules,
+    overrides: Vec<EslintOverride>,
+    ignore: IndexSet<String>,
+    globals: IndexSet<String>,
+    /// The number of rules of Biome enabled or disabled by the migration
+    migrated_rules: usize,
+    /// The rules of ESLint that don't have an equivalent in Biome
+    unsupported_rules: BTreeSet<String>,
+    /// The rules of ESLint whose options don't have an equivalent in Biome
+    unsupported_options: BTreeSet<String>,
+    /// The settings of ESLint that can't be migrated
+    unsupported_settings: BTreeSet<String>,
+}
+
+impl EslintMigration {
+    /// Adds a configuration object of the legacy format, `.eslintrc.*`
+    fn add_legacy_configuration(&mut self, configuration: &Value) {
+        let Some(configuration) = configuration.as_object() else {
+            return;
+        };
+
+        if let Some(extends) = configuration.get("extends") {
+            for extended in config_file::strings(extends) {
+                self.unsupported_settings
+                    .insert(format!("extends: {extended}"));
+            }
+        }
+        if let Some(environments) = configuration.get("env").and_then(Value::as_object) {
+            for environment in environments.keys() {
+                self.unsupported_settings
+                    .insert(format!("env: {environment}"));
+            }
+        }
+        if let Some(ignore_patterns) = configuration.get("ignorePatterns") {
+            self.add_ignore_patterns(config_file::strings(ignore_patterns));
+        }
+        if let Some(globals) = configuration.get("globals") {
+            self.globals.extend(enabled_globals(globals));
+        }
+        if let Some(rules) = configuration.get("rules") {
+            self.add_rules(rules, Scope::Project);
+        }
+        for override_configuration in configuration
+            .get("overrides")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let mut rules = EslintRules::default();
+            if let Some(override_rules) = override_configuration.get("rules") {
+                self.add_rules(override_rules, Scope::Override(&mut rules));
+            }
+            self.overrides.push(EslintOverride {
+                include: override_configuration
+                    .get("files")
+                    .map(config_file::strings)
+                    .unwrap_or_default(),
+                ignore: override_configuration
+                    .get("excludedFiles")
+                    .map(config_file::strings)
+                    .unwrap_or_default(),
+                rules,
+                globals: override_configuration
+                    .get("globals")
+                    .map(enabled_globals)
+                    .unwrap_or_default(),
+            });
+        }
+    }
+
+    /// Adds a configuration object of the flat format, `eslint.config.*`
+    fn add_flat_configuration(&mut self, configuration: &Value) {
+        let Some(configuration) = configuration.as_object() else {
+            return;
+        };
+
+        let files = configuration
+            .get("files")
+            .map(config_file::strings)
+            .unwrap_or_default();
+        let ignores = configuration
+            .get("ignores")
+            .map(config_file::strings)
+            .unwrap_or_default();
+        let globals = configuration
+            .get("languageOptions")
+            .and_then(|language_options| language_options.get("globals"))
+            .map(enabled_globals)
+            .unwrap_or_default();
+
+        if files.is_empty() {
+            // A configuration object that only contains `ignores` ignores files globally
+            if configuration
+                .keys()
+                .all(|key| key == "ignores" || key == "name")
+            {
+                self.add_ignore_patterns(ignores);
+                return;
+            }
+            if !ignores.is_empty() {
+                self.unsupported_settings.insert(format!(
+                    "ignores of a configuration object without files: {}",
+                    ignores.join(", ")
+                ));
+            }
+            self.globals.extend(globals);
+            if let Some(rules) = configuration.get("rules") {
+                self.add_rules(rules, Scope::Project);
+            }
+        } else {
+            let mut rules = EslintRules::default();
+            if let Some(override_rules) = configuration.get("rules") {
+                self.add_rules(override_rules, Scope::Override(&mut rules));
+            }
+            if !rules.is_empty() || !globals.is_empty() {
+                self.overrides.push(EslintOverride {
+                    include: files,
+                    ignore: ignores,
+                    rules,
+                    globals,
+                });
+            }
+        }
+    }
+
+    fn add_rules(&mut self, rules: &Value, scope: Scope) {
+        let Some(rules) = rules.as_object() else {
+            return;
+        };
+        let target = match scope {
+            Scope::Project => &mut self.rules,
+            Scope::Override(rules) => rules,
+        };
+        for (name, configuration) in rules {
+            let (severity, options) = match configuration {
+                Value::Array(items) => match items.split_first() {
+                    Some((severity, options)) => (severity, options.to_vec()),
+                    None => continue,
+                },
+                severity => (severity, Vec::new()),
+            };
+            let Some(level) = to_biome_level(severity) else {
+                continue;
+            };
+            // Remove the rule first, so that it's moved at the end
+            target.0.shift_remove(name);
+            target.0.insert(name.clone(), (level, options));
+        }
+    }
+
+    fn add_ignore_patterns(&mut self, patterns: impl IntoIterator<Item = impl Into<String>>) {
+        for pattern in patterns {
+            let pattern = pattern.into();
+            self.ignore
+                .insert(pattern.trim_end_matches('/').to_string());
+        }
+    }
+
+    /// Updates the Biome `configuration` with the migrated settings
+    fn apply(&mut self, configuration: &mut Configuration) {
+        let rules = std::mem::take(&mut self.rules);
+        let rules = self.migrate_rules(rules);
+        let linter = configuration
+            .linter
+            .get_or_insert_with(LinterConfiguration::default);
+        if let Some(rules) = rules {
+            linter
+                .rules
+                .get_or_insert_with(Rules::default)
+                .merge_with(rules);
+        }
+        if !self.ignore.is_empty() {
+            let mut ignore = linter
+                .ignore
+                .take()
+                .map(StringSet::into_index_set)
+                .unwrap_or_default();
+            ignore.extend(self.ignore.drain(..));
+            linter.ignore = Some(StringSet::new(ignore));
+        }
+
+        if !self.globals.is_empty() {
+            let javascript = configuration
+                .javascript
+                .get_or_insert_with(JavascriptConfiguration::default);
+            let mut globals = match javascript.globals.take() {
+                Some(JavascriptGlobals::List(globals)) => globals.into_index_set(),
+                Some(JavascriptGlobals::Map(globals)) => globals
+                    .into_iter()
+                    .filter(|(_, access)| *access != JavascriptGlobalAccess::Off)
+                    .map(|(name, _)| name)
+                    .collect(),
+                None => IndexSet::new(),
+            };
+            globals.extend(self.globals.drain(..));
+            javascript.globals = Some(JavascriptGlobals::List(StringSet::new(globals)));
+        }
+
+        for eslint_override in std::mem::take(&mut self.overrides) {
+            let rules = self.migrate_rules(eslint_override.rules);
+            let javascript =
+                (!eslint_override.globals.is_empty()).then(|| JavascriptConfiguration {
+                    globals: Some(JavascriptGlobals::List(StringSet::new(
+                        eslint_override.globals.into_iter().collect(),
+                    ))),
+                    ..JavascriptConfiguration::default()
+                });
+            if rules.is_none() && javascript.is_none() {
+                continue;
+            }
+            let pattern = OverridePattern {
+                include: Some(StringSet::new(
+                    eslint_override.include.into_iter().collect(),
+                )),
+                ignore: (!eslint_override.ignore.is_empty())
+                    .then(|| StringSet::new(eslint_override.ignore.into_iter().collect())),
+                javascript,
+                linter: rules.map(|rules| OverrideLinterConfiguration {
+                    enabled: None,
+                    rules: Some(rules),
+                }),
+                ..OverridePattern::default()
+            };
+            let overrides = &mut configuration
+                .overrides
+                .get_or_insert_with(|| Overrides(Vec::new()))
+                .0;
+            // The override may have been migrated already
+            if !overrides.contains(&pattern) {
+                overrides.push(pattern);
+            }
+        }
+    }
+
+    /// Maps the rules of ESLint to the rules of Biome. When many rules of ESLint map to the same
+    /// rule of Biome, the most severe configuration wins.
+    fn migrate_rules(&mut self, eslint_rules: EslintRules) -> Option<Rules> {
+        let mut biome_rules: IndexMap<BiomeRule, (RulePlainConfiguration, Option<Value>)> =
+            IndexMap::new();
+        for (eslint_name, (level, options)) in eslint_rules.0 {
+            let equivalents: Vec<_> = eslint_rules::find(&eslint_name).collect();
+            if equivalents.is_empty() {
+                if level != RulePlainConfiguration::Off {
+                    self.unsupported_rules.insert(eslint_name);
+                }
+                continue;
+            }
+            for equivalent in equivalents {
+                let biome_options = if options.is_empty() {
+                    None
+                } else {
+                    let biome_options = to_biome_options(equivalent.rule_name, &options);
+                    if biome_options.is_none() {
+                        self.unsupported_options.insert(eslint_name.clone());
+                    }
+                    biome_options
+                };
+                let key = (equivalent.group, equivalent.rule_name);
+                match biome_rules.get(&key) {
+                    Some((existing_level, _)) if severity(existing_level) > severity(&level) => {}
+                    _ => {
+                        biome_rules.insert(key, (level.clone(), biome_options));
+                    }
+                }
+            }
+        }
+
+        if biome_rules.is_empty() {
+            return None;
+        }
+        let mut rules = Rules {
+            recommended: None,
+            ..Rules::default()
+        };
+        for ((group, rule_name), (level, options)) in biome_rules {
+            let rule = match options {
+                Some(options) => {
+                    let with_options =
+                        json!({ group: { rule_name: { "level": level, "options": options } } });
+                    match serde_json::from_value(with_options) {
+                        Ok(rule) => rule,
+                        Err(_) => {
+                            self.unsupported_options.insert(rule_name.to_string());
+                            plain_rule(group, rule_name, &level)
+                        }
+                    }
+                }
+                None => plain_rule(group, rule_name, &level),
+            };
+            rules.merge_with(rule);
+            self.migrated_rules += 1;
+        }
+        Some(rules)
+    }
+
+    /// Prints the settings that couldn't be migrated
+    fn report(&self, console: &mut dyn biome_console::Console) {
+        let migrated_rules = self.migrated_rules;
+        console.log(markup! {
+            "Migrated "{migrated_rules}" rule(s) of ESLint."
+        });
+        let sections = [
+            (
+                "The following rules of ESLint don't have an equivalent in Biome:",
+                &self.unsupported_rules,
+            ),
+            (
+                "The options of the following rules couldn't be migrated, the default options of Biome are used:",
+                &self.unsupported_options,
+            ),
+            (
+                "The following settings of ESLint couldn't be migrated:",
+                &self.unsupported_settings,
+            ),
+        ];
+        for (title, items) in sections {
+            config_file::print_report_section(console, title, items);
+        }
+    }
+}
+
+/// The scope where rules are configured
+enum Scope<'a> {
+    Project,
+    Override(&'a mut EslintRules),
+}
+
+fn plain_rule(group: &str, rule_name: &str, level: &RulePlainConfiguration) -> Rules {
+    serde_json::from_value(json!({ group: { rule_name: level } }))
+        .expect("the table of ESLint rules to contain only existing rules")
+}
+
+/// Maps the severity of an ESLint rule, which is a number or a string
+fn to_biome_level(severity: &Value) -> Option<RulePlainConfiguration> {
+    match severity {
+        Value::Number(number) => match number.as_u64()? {
+            0 => Some(RulePlainConfiguration::Off),
+            1 => Some(RulePlainConfiguration::Warn),
+            2 => Some(RulePlainConfiguration::Error),
+            _ => None,
+        },
+        Value::String(string) => match string.as_str() {
+            "off" => Some(RulePlainConfiguration::Off),
+            "warn" => Some(RulePlainConfiguration::Warn),
+            "error" => Some(RulePlainConfiguration::Error),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+const fn severity(level: &RulePlainConfiguration) -> u8 {
+    match level {
+        RulePlainConfiguration::Off => 0,
+        RulePlainConfiguration::Hint => 1,
+        RulePlainConfiguration::Info => 2,
+        RulePlainConfiguration::Warn => 3,
+        RulePlainConfiguration::Error => 4,
+    }
+}
+
+/// Maps the options of an ESLint rule to the options of the Biome rule `rule_name`.
+/// Returns [None] if the options don't have an equivalent.
+fn to_biome_options(rule_name: &str, options: &[Value]) -> Option<Value> {
+    match rule_name {
+        "noRestrictedGlobals" => {
+            let denied_globals: Vec<_> = options
+                .iter()
+                .filter_map(|option| match option {
+                    Value::String(name) => Some(name.as_str()),
+                    Value::Object(option) => option.get("name")?.as_str(),
+                    _ => None,
+                })
+                .collect();
+            Some(json!({ "deniedGlobals": denied_globals }))
+        }
+        "noExcessiveCognitiveComplexity" => {
+            let threshold = options.first()?.as_u64()?;
+            Some(json!({ "maxAllowedComplexity": threshold }))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the names of the globals that aren't turned off
+fn enabled_globals(globals: &Value) -> Vec<String> {
+    globals
+        .as_object()
+        .map(Map::iter)
+        .into_iter()
+        .flatten()
+        .filter(|(_, value)| value.as_str() != Some("off"))
+        .map(|(name, _)| name.clone())
+        .collect()
+}
@@ -0,0 +1,512 @@
+//! The ESLint rules that have an equivalent in Biome. The rules of plugins are prefixed
+//! by the name of the plugin, like ESLint does.
+
+/// A rule of ESLint, with the group and the name of the equivalent rule of Biome
+pub(super) struct EslintRule {
+    pub(super) eslint_name: &'static str,
+    pub(super) group: &'static str,
+    pub(super) rule_name: &'static str,
+}
+
+const fn rule(
+    eslint_name: &'static str,
+    group: &'static str,
+    rule_name: &'static str,
+) -> EslintRule {
+    EslintRule {
+        eslint_name,
+        group,
+        rule_name,
+    }
+}
+
+/// Returns the rules of Biome that are equivalent to the ESLint rule `eslint_name`.
+/// A few ESLint rules are covered by more than one rule of Biome.
+pub(super) fn find(eslint_name: &str) -> impl Iterator<Item = &'static EslintRule> + '_ {
+    ESLINT_RULES
+        .iter()
+        .filter(move |rule| rule.eslint_name == eslint_name)
+}
+
+const ESLINT_RULES: &[EslintRule] = &[
+    // ESLint
+    rule(
+        "constructor-super",
+        "correctness",
+        "noInvalidConstructorSuper",
+    ),
+    rule("curly", "style", "useBlockStatements"),
+    rule(
+        "default-case-last",
+        "suspicious",
+        "useDefaultSwitchClauseLast",
+    ),
+    rule("default-param-last", "style", "useDefaultParameterLast"),
+    rule("dot-notation", "complexity", "useLiteralKeys"),
+    rule("eqeqeq", "suspicious", "noDoubleEquals"),
+    rule("for-direction", "correctness", "useValidForDirection"),
+    rule("getter-return", "suspicious", "useGetterReturn"),
+    rule(
+        "no-async-promise-executor",
+        "suspicious",
+        "noAsyncPromiseExecutor",
+    ),
+    rule(
+        "no-case-declarations",
+        "correctness",
+        "noSwitchDeclarations",
+    ),
+    rule("no-class-assign", "suspicious", "noClassAssign"),
+    rule("no-compare-neg-zero", "suspicious", "noCompareNegZero"),
+    rule("no-cond-assign", "suspicious", "noAssignInExpressions"),
+    rule("no-console", "suspicious", "noConsoleLog"),
+    rule("no-const-assign", "correctness", "noConstAssign"),
+    rule(
+        "no-constant-condition",
+        "correctness",
+        "noConstantCondition",
+    ),
+    rule(
+        "no-constructor-return",
+        "correctness",
+        "noConstructorReturn",
+    ),
+    rule(
+        "no-control-regex",
+        "suspicious",
+        "noControlCharactersInRegex",
+    ),
+    rule("no-debugger", "suspicious", "noDebugger"),
+    rule("no-dupe-args", "suspicious", "noDuplicateParameters"),
+    rule(
+        "no-dupe-class-members",
+        "suspicious",
+        "noDuplicateClassMembers",
+    ),
+    rule("no-dupe-keys", "suspicious", "noDuplicateObjectKeys"),
+    rule("no-duplicate-case", "suspicious", "noDuplicateCase"),
+    rule("no-else-return", "style", "noUselessElse"),
+    rule("no-empty", "nursery", "noEmptyBlockStatements"),
+    rule(
+        "no-empty-character-class",
+        "correctness",
+        "noEmptyCharacterClassInRegex",
+    ),
+    rule("no-empty-function", "nursery", "noEmptyBlockStatements"),
+    rule("no-empty-pattern", "correctness", "noEmptyPattern"),
+    rule("no-empty-static-block", "nursery", "noEmptyBlockStatements"),
+    rule("no-ex-assign", "suspicious", "noCatchAssign"),
+    rule("no-extra-boolean-cast", "complexity", "noExtraBooleanCast"),
+    rule("no-extra-label", "complexity", "noUselessLabel"),
+    rule("no-fallthrough", "suspicious", "noFallthroughSwitchClause"),
+    rule("no-func-assign", "suspicious", "noFunctionAssign"),
+    rule("no-import-assign", "suspicious", "noImportAssign"),
+    rule(
+        "no-inner-declarations",
+        "correctness",
+        "noInnerDeclarations",
+    ),
+    rule("no-label-var", "suspicious", "noLabelVar"),
+    rule("no-labels", "suspicious", "noConfusingLabels"),
+    rule("no-lone-blocks", "nursery", "noUselessLoneBlockStatements"),
+    rule("no-lonely-if", "style", "useCollapsedElseIf"),
+    rule("no-loss-of-precision", "correctness", "noPrecisionLoss"),
+    rule(
+        "no-misleading-character-class",
+        "nursery",
+        "noMisleadingCharacterClass",
+    ),
+    rule("no-negated-condition", "style", "noNegationElse"),
+    rule(
+        "no-new-native-nonconstructor",
+        "correctness",
+        "noInvalidNewBuiltin",
+    ),
+    rule("no-new-symbol", "correctness", "noNewSymbol"),
+    rule(
+        "no-nonoctal-decimal-escape",
+        "correctness",
+        "noNonoctalDecimalEscape",
+    ),
+    rule("no-obj-calls", "correctness", "noGlobalObjectCalls"),
+    rule("no-param-reassign", "style", "noParameterAssign"),
+    rule("no-prototype-builtins", "suspicious", "noPrototypeBuiltins"),
+    rule("no-redeclare", "suspicious", "noRedeclare"),
+    rule(
+        "no-regex-spaces",
+        "complexity",
+        "noMultipleSpacesInRegularExpressionLiterals",
+    ),
+    rule("no-restricted-globals", "style", "noRestrictedGlobals"),
+    rule("no-self-assign", "correctness", "noSelfAssign"),
+    rule("no-self-compare", "suspicious", "noSelfCompare"),
+    rule("no-sequences", "style", "noCommaOperator"),
+    rule("no-setter-return", "correctness", "noSetterReturn"),
+    rule(
+        "no-shadow-restricted-names",
+        "suspicious",
+        "noShadowRestrictedNames",
+    ),
+    rule("no-sparse-arrays", "suspicious", "noSparseArray"),
+    rule("no-this-before-super", "correctness", "noUnreachableSuper"),
+    rule("no-undef", "correctness", "noUndeclaredVariables"),
+    rule("no-unreachable", "correctness", "noUnreachable"),
+    rule("no-unsafe-finally", "correctness", "noUnsafeFinally"),
+    rule("no-unsafe-negation", "suspicious", "noUnsafeNegation"),
+    rule(
+        "no-unsafe-optional-chaining",
+        "correctness",
+        "noUnsafeOptionalChaining",
+    ),
+    rule("no-unused-labels", "correctness", "noUnusedLabels"),
+    rule(
+        "no-unused-private-class-members",
+        "nursery",
+        "noUnusedPrivateClassMembers",
+    ),
+    rule("no-unused-vars", "correctness", "noUnusedVariables"),
+    rule("no-useless-catch", "complexity", "noUselessCatch"),
+    rule(
+        "no-useless-constructor",
+        "complexity",
+        "noUselessConstructor",
+    ),
+    rule("no-useless-rename", "complexity", "noUselessRename"),
+    rule("no-var", "style", "noVar"),
+    rule("no-void", "complexity", "noVoid"),
+    rule("no-with", "complexity", "noWith"),
+    rule("one-var", "style", "useSingleVarDeclarator"),
+    rule("operator-assignment", "style", "useShorthandAssign"),
+    rule("prefer-arrow-callback", "complexity", "useArrowFunction"),
+    rule("prefer-const", "style", "useConst"),
+    rule(
+        "prefer-exponentiation-operator",
+        "style",
+        "useExponentiationOperator",
+    ),
+    rule("prefer-numeric-literals", "style", "useNumericLiterals"),
+    rule("prefer-regex-literals", "nursery", "useRegexLiterals"),
+    rule("prefer-rest-params", "style", "noArguments"),
+    rule("prefer-template", "style", "useTemplate"),
+    rule("require-await", "nursery", "useAwait"),
+    rule("require-yield", "correctness", "useYield"),
+    rule("use-isnan", "correctness", "useIsNan"),
+    rule("valid-typeof", "suspicious", "useValidTypeof"),
+    // typescript-eslint
+    rule(
+        "@typescript-eslint/array-type",
+        "style",
+        "useShorthandArrayType",
+    ),
+    rule(
+        "@typescript-eslint/ban-types",
+        "complexity",
+        "noBannedTypes",
+    ),
+    rule(
+        "@typescript-eslint/consistent-type-exports",
+        "nursery",
+        "useExportType",
+    ),
+    rule(
+        "@typescript-eslint/default-param-last",
+        "style",
+        "useDefaultParameterLast",
+    ),
+    rule(
+        "@typescript-eslint/dot-notation",
+        "complexity",
+        "useLiteralKeys",
+    ),
+    rule(
+        "@typescript-eslint/naming-convention",
+        "style",
+        "useNamingConvention",
+    ),
+    rule(
+        "@typescript-eslint/no-dupe-class-members",
+        "suspicious",
+        "noDuplicateClassMembers",
+    ),
+    rule(
+        "@typescript-eslint/no-empty-function",
+        "nursery",
+        "noEmptyBlockStatements",
+    ),
+    rule(
+        "@typescript-eslint/no-empty-interface",
+        "suspicious",
+        "noEmptyInterface",
+    ),
+    rule(
+        "@typescript-eslint/no-explicit-any",
+        "suspicious",
+        "noExplicitAny",
+    ),
+    rule(
+        "@typescript-eslint/no-extra-non-null-assertion",
+        "suspicious",
+        "noExtraNonNullAssertion",
+    ),
+    rule(
+        "@typescript-eslint/no-extraneous-class",
+        "complexity",
+        "noStaticOnlyClass",
+    ),
+    rule(
+        "@typescript-eslint/no-inferrable-types",
+        "style",
+        "noInferrableTypes",
+    ),
+    rule(
+        "@typescript-eslint/no-invalid-void-type",
+        "suspicious",
+        "noConfusingVoidType",
+    ),
+    rule(
+        "@typescript-eslint/no-loss-of-precision",
+        "correctness",
+        "noPrecisionLoss",
+    ),
+    rule(
+        "@typescript-eslint/no-misused-new",
+        "suspicious",
+        "noMisleadingInstantiator",
+    ),
+    rule("@typescript-eslint/no-namespace", "style", "noNamespace"),
+    rule(
+        "@typescript-eslint/no-non-null-assertion",
+        "style",
+        "noNonNullAssertion",
+    ),
+    rule(
+        "@typescript-eslint/no-redeclare",
+        "suspicious",
+        "noRedeclare",
+    ),
+    rule(
+        "@typescript-eslint/no-this-alias",
+        "complexity",
+        "noUselessThisAlias",
+    ),
+    rule(
+        "@typescript-eslint/no-unnecessary-type-constraint",
+        "complexity",
+        "noUselessTypeConstraint",
+    ),
+    rule(
+        "@typescript-eslint/no-unsafe-declaration-merging",
+        "suspicious",
+        "noUnsafeDeclarationMerging",
+    ),
+    rule(
+        "@typescript-eslint/no-unused-vars",
+        "correctness",
+        "noUnusedVariables",
+    ),
+    rule(
+        "@typescript-eslint/no-useless-constructor",
+        "complexity",
+        "noUselessConstructor",
+    ),
+    rule(
+        "@typescript-eslint/no-useless-empty-export",
+        "complexity",
+        "noUselessEmptyExport",
+    ),
+    rule(
+        "@typescript-eslint/parameter-properties",
+        "style",
+        "noParameterProperties",
+    ),
+    rule(
+        "@typescript-eslint/prefer-as-const",
+        "style",
+        "useAsConstAssertion",
+    ),
+    rule(
+        "@typescript-eslint/prefer-enum-initializers",
+        "style",
+        "useEnumInitializers",
+    ),
+    rule("@typescript-eslint/prefer-for-of", "nursery", "useForOf"),
+    rule(
+        "@typescript-eslint/prefer-function-type",
+        "nursery",
+        "useShorthandFunctionType",
+    ),
+    rule(
+        "@typescript-eslint/prefer-literal-enum-member",
+        "style",
+        "useLiteralEnumMembers",
+    ),
+    rule(
+        "@typescript-eslint/prefer-namespace-keyword",
+        "suspicious",
+        "useNamespaceKeyword",
+    ),
+    rule(
+        "@typescript-eslint/prefer-optional-chain",
+        "complexity",
+        "useOptionalChain",
+    ),
+    rule("@typescript-eslint/require-await", "nursery", "useAwait"),
+    // eslint-plugin-react
+    rule("react/button-has-type", "a11y", "useButtonType"),
+    rule("react/jsx-fragments", "style", "useFragmentSyntax"),
+    rule(
+        "react/jsx-no-comment-textnodes",
+        "suspicious",
+        "noCommentText",
+    ),
+    rule(
+        "react/jsx-no-duplicate-props",
+        "suspicious",
+        "noDuplicateJsxProps",
+    ),
+    rule("react/jsx-no-target-blank", "a11y", "noBlankTarget"),
+    rule(
+        "react/jsx-no-useless-fragment",
+        "complexity",
+        "noUselessFragments",
+    ),
+    rule("react/no-array-index-key", "suspicious", "noArrayIndexKey"),
+    rule("react/no-children-prop", "correctness", "noChildrenProp"),
+    rule("react/no-danger", "security", "noDangerouslySetInnerHtml"),
+    rule(
+        "react/no-danger-with-children",
+        "security",
+        "noDangerouslySetInnerHtmlWithChildren",
+    ),
+    rule(
+        "react/no-render-return-value",
+        "correctness",
+        "noRenderReturnValue",
+    ),
+    rule("react/self-closing-comp", "style", "useSelfClosingElements"),
+    rule(
+        "react/void-dom-elements-no-children",
+        "correctness",
+        "noVoidElementsWithChildren",
+    ),
+    // eslint-plugin-react-hooks
+    rule(
+        "react-hooks/exhaustive-deps",
+        "correctness",
+        "useExhaustiveDependencies",
+    ),
+    rule(
+        "react-hooks/rules-of-hooks",
+        "correctness",
+        "useHookAtTopLevel",
+    ),
+    // eslint-plugin-jsx-a11y
+    rule("jsx-a11y/alt-text", "a11y", "useAltText"),
+    rule("jsx-a11y/anchor-has-content", "a11y", "useAnchorContent"),
+    rule("jsx-a11y/anchor-is-valid", "a11y", "useValidAnchor"),
+    rule(
+        "jsx-a11y/aria-activedescendant-has-tabindex",
+        "a11y",
+        "useAriaActivedescendantWithTabindex",
+    ),
+    rule("jsx-a11y/aria-props", "a11y", "useValidAriaProps"),
+    rule("jsx-a11y/aria-proptypes", "a11y", "useValidAriaValues"),
+    rule("jsx-a11y/aria-role", "nursery", "useValidAriaRole"),
+    rule(
+        "jsx-a11y/aria-unsupported-elements",
+        "a11y",
+        "noAriaUnsupportedElements",
+    ),
+    rule(
+        "jsx-a11y/click-events-have-key-events",
+        "a11y",
+        "useKeyWithClickEvents",
+    ),
+    rule("jsx-a11y/heading-has-content", "a11y", "useHeadingContent"),
+    rule("jsx-a11y/html-has-lang", "a11y", "useHtmlLang"),
+    rule("jsx-a11y/iframe-has-title", "a11y", "useIframeTitle"),
+    rule("jsx-a11y/img-redundant-alt", "a11y", "noRedundantAlt"),
+    rule("jsx-a11y/lang", "a11y", "useValidLang"),
+    rule("jsx-a11y/media-has-caption", "a11y", "useMediaCaption"),
+    rule(
+        "jsx-a11y/mouse-events-have-key-events",
+        "a11y",
+        "useKeyWithMouseEvents",
+    ),
+    rule("jsx-a11y/no-access-key", "a11y", "noAccessKey"),
+    rule(
+        "jsx-a11y/no-aria-hidden-on-focusable",
+        "nursery",
+        "noAriaHiddenOnFocusable",
+    ),
+    rule("jsx-a11y/no-autofocus", "a11y", "noAutofocus"),
+    rule(
+        "jsx-a11y/no-distracting-elements",
+        "a11y",
+        "noDistractingElements",
+    ),
+    rule(
+        "jsx-a11y/no-interactive-element-to-noninteractive-role",
+        "a11y",
+        "noInteractiveElementToNoninteractiveRole",
+    ),
+    rule(
+        "jsx-a11y/no-noninteractive-element-to-interactive-role",
+        "a11y",
+        "noNoninteractiveElementToInteractiveRole",
+    ),
+    rule(
+        "jsx-a11y/no-noninteractive-tabindex",
+        "a11y",
+        "noNoninteractiveTabindex",
+    ),
+    rule("jsx-a11y/no-redundant-roles", "a11y", "noRedundantRoles"),
+    rule(
+        "jsx-a11y/role-has-required-aria-props",
+        "a11y",
+        "useAriaPropsForRole",
+    ),
+    rule("jsx-a11y/scope", "a11y", "noHeaderScope"),
+    rule(
+        "jsx-a11y/tabindex-no-positive",
+        "a11y",
+        "noPositiveTabindex",
+    ),
+    // eslint-plugin-unicorn
+    rule("unicorn/no-array-for-each", "complexity", "noForEach"),
+    rule("unicorn/no-for-loop", "nursery", "useForOf"),
+    rule("unicorn/no-instanceof-array", "suspicious", "useIsArray"),
+    rule(
+        "unicorn/no-static-only-class",
+        "complexity",
+        "noStaticOnlyClass",
+    ),
+    rule(
+        "unicorn/no-useless-switch-case",
+        "complexity",
+        "noUselessSwitchCase",
+    ),
+    rule("unicorn/prefer-array-flat-map", "complexity", "useFlatMap"),
+    rule(
+        "unicorn/prefer-number-properties",
+        "suspicious",
+        "noGlobalIsFinite",
+    ),
+    rule(
+        "unicorn/prefer-number-properties",
+        "suspicious",
+        "noGlobalIsNan",
+    ),
+    // Other plugins
+    rule("import/no-default-export", "nursery", "noDefaultExport"),
+    rule(
+        "sonarjs/cognitive-complexity",
+        "complexity",
+        "noExcessiveCognitiveComplexity",
+    ),
+    rule(
+        "unused-imports/no-unused-imports",
+        "nursery",
+        "noUnusedImports",
+    ),
+];
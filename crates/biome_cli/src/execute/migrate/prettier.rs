@@ -0,0 +1,380 @@
+//! Migrates the configuration of Prettier to Biome.
+//!
+//! The options of Prettier become the options of Biome's formatter, the `overrides` of
+//! Prettier become Biome's `overrides`, and the patterns of `.prettierignore` are ignored.
+//! The options that don't have an equivalent in Biome are listed in a report.
+
+use crate::execute::migrate::config_file::{self, migration_error};
+use crate::{CliDiagnostic, CliSession};
+use biome_deserialize::StringSet;
+use biome_formatter::{LineEnding, LineWidth};
+use biome_fs::FileSystem;
+use biome_js_formatter::context::trailing_comma::TrailingComma;
+use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, QuoteStyle, Semicolons};
+use biome_service::configuration::{
+    FilesConfiguration, FormatterConfiguration, JavascriptConfiguration, JavascriptFormatter,
+    MergeWith, OverrideFormatterConfiguration, OverridePattern, Overrides, PlainIndentStyle,
+};
+use biome_service::Configuration;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// The configuration files of Prettier, in the order Prettier looks for them
+const CONFIG_FILES: &[&str] = &[
+    ".prettierrc",
+    ".prettierrc.json",
+    ".prettierrc.yaml",
+    ".prettierrc.yml",
+    ".prettierrc.json5",
+    ".prettierrc.js",
+    "prettier.config.js",
+    ".prettierrc.mjs",
+    "prettier.config.mjs",
+    ".prettierrc.cjs",
+    "prettier.config.cjs",
+    ".prettierrc.toml",
+];
+
+pub(crate) fn run(
+    mut session: CliSession,
+    write: bool,
+    configuration_file_path: PathBuf,
+    configuration_directory_path: PathBuf,
+    verbose: bool,
+) -> Result<(), CliDiagnostic> {
+    let fs = &*session.app.fs;
+    let (prettier_path, prettier_configuration) =
+        load_prettier_configuration(fs, &configuration_directory_path)?;
+    let Value::Object(prettier_configuration) = prettier_configuration else {
+        return Err(migration_error(format!(
+            "The configuration {} isn't an object, shared configurations can't be migrated",
+            prettier_path.display()
+        )));
+    };
+
+    let mut migration = PrettierMigration::default();
+    // Prettier and Biome have different defaults, the options that aren't set must
+    // have the default value of Prettier
+    let mut options = PrettierOptions::prettier_defaults();
+    migration.read_options(&prettier_configuration, &mut options);
+    migration.options = options;
+    for prettier_override in prettier_configuration
+        .get("overrides")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let mut options = PrettierOptions::default();
+        if let Some(override_options) = prettier_override.get("options").and_then(Value::as_object)
+        {
+            migration.read_options(override_options, &mut options);
+        }
+        migration.overrides.push(PrettierOverride {
+            include: prettier_override
+                .get("files")
+                .map(config_file::strings)
+                .unwrap_or_default(),
+            ignore: prettier_override
+                .get("excludeFiles")
+                .map(config_file::strings)
+                .unwrap_or_default(),
+            options,
+        });
+    }
+
+    let prettier_ignore_path = configuration_directory_path.join(".prettierignore");
+    if config_file::exists(fs, &prettier_ignore_path) {
+        let content = config_file::read_to_string(fs, &prettier_ignore_path)?;
+        for pattern in content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        {
+            migration
+                .ignore
+                .push(pattern.trim_end_matches('/').to_string());
+        }
+    }
+
+    config_file::update_biome_configuration(
+        &mut session,
+        &configuration_file_path,
+        &prettier_path,
+        write,
+        verbose,
+        |configuration| migration.apply(configuration),
+    )?;
+
+    let console = &mut *session.app.console;
+    config_file::print_report_section(
+        console,
+        "The following options of Prettier couldn't be migrated:",
+        &migration.unsupported_options,
+    );
+    if !write {
+        config_file::print_write_hint(console, "prettier");
+    }
+
+    Ok(())
+}
+
+/// Finds the configuration of Prettier inside `directory`, and returns its path and its content
+fn load_prettier_configuration(
+    fs: &dyn FileSystem,
+    directory: &Path,
+) -> Result<(PathBuf, Value), CliDiagnostic> {
+    // The field `prettier` of `package.json` has the highest priority
+    let package_json_path = directory.join("package.json");
+    if config_file::exists(fs, &package_json_path) {
+        let package_json = config_file::load(fs, &package_json_path)?;
+        if let Some(configuration) = package_json.get("prettier") {
+            return Ok((package_json_path, configuration.clone()));
+        }
+    }
+
+    for file_name in CONFIG_FILES {
+        let path = directory.join(file_name);
+        if config_file::exists(fs, &path) {
+            let configuration = config_file::load(fs, &path)?;
+            return Ok((path, configuration));
+        }
+    }
+
+    Err(migration_error(
+        "Biome couldn't find the configuration file of Prettier".to_string(),
+    ))
+}
+
+/// The options of Prettier that have an equivalent in Biome
+#[derive(Default)]
+struct PrettierOptions {
+    indent_style: Option<PlainIndentStyle>,
+    indent_width: Option<u8>,
+    line_width: Option<LineWidth>,
+    line_ending: Option<LineEnding>,
+    javascript: JavascriptFormatter,
+}
+
+impl PrettierOptions {
+    /// The default options of Prettier
+    fn prettier_defaults() -> Self {
+        Self {
+            indent_style: Some(PlainIndentStyle::Space),
+            indent_width: Some(2),
+            line_width: LineWidth::try_from(80).ok(),
+            line_ending: Some(LineEnding::Lf),
+            javascript: JavascriptFormatter {
+                quote_style: Some(QuoteStyle::Double),
+                jsx_quote_style: Some(QuoteStyle::Double),
+                quote_properties: Some(QuoteProperties::AsNeeded),
+                trailing_comma: Some(TrailingComma::All),
+                semicolons: Some(Semicolons::Always),
+                arrow_parentheses: Some(ArrowParentheses::Always),
+                bracket_spacing: Some(true),
+                bracket_same_line: Some(false),
+                ..JavascriptFormatter::default()
+            },
+        }
+    }
+
+    fn to_override_formatter(&self) -> Option<OverrideFormatterConfiguration> {
+        let formatter = OverrideFormatterConfiguration {
+            indent_style: self.indent_style.clone(),
+            indent_width: self.indent_width,
+            line_width: self.line_width,
+            line_ending: self.line_ending,
+            ..OverrideFormatterConfiguration::default()
+        };
+        (formatter != OverrideFormatterConfiguration::default()).then_some(formatter)
+    }
+
+    fn to_javascript(&self) -> Option<JavascriptConfiguration> {
+        (self.javascript != JavascriptFormatter::default()).then(|| JavascriptConfiguration {
+            formatter: Some(self.javascript.clone()),
+            ..JavascriptConfiguration::default()
+        })
+    }
+}
+
+/// An override of Prettier, which applies some options only to some files
+struct PrettierOverride {
+    include: Vec<String>,
+    ignore: Vec<String>,
+    options: PrettierOptions,
+}
+
+#[derive(Default)]
+struct PrettierMigration {
+    options: PrettierOptions,
+    overrides: Vec<PrettierOverride>,
+    ignore: Vec<String>,
+    /// The options of Prettier that can't be migrated
+    unsupported_options: BTreeSet<String>,
+}
+
+impl PrettierMigration {
+    /// Reads the options of a configuration object of Prettier into `options`
+    fn read_options(&mut self, configuration: &Map<String, Value>, options: &mut PrettierOptions) {
+        for (name, value) in configuration {
+            let migrated = match (name.as_str(), value) {
+                ("$schema" | "overrides", _) => true,
+                ("useTabs", Value::Bool(use_tabs)) => {
+                    options.indent_style = Some(if *use_tabs {
+                        PlainIndentStyle::Tab
+                    } else {
+                        PlainIndentStyle::Space
+                    });
+                    true
+                }
+                ("tabWidth", Value::Number(width)) => {
+                    options.indent_width = width.as_u64().and_then(|width| width.try_into().ok());
+                    options.indent_width.is_some()
+                }
+                ("printWidth", Value::Number(width)) => {
+                    options.line_width = width
+                        .as_u64()
+                        .and_then(|width| u16::try_from(width).ok())
+                        .and_then(|width| LineWidth::try_from(width).ok());
+                    options.line_width.is_some()
+                }
+                ("endOfLine", Value::String(line_ending)) => {
+                    options.line_ending = match line_ending.as_str() {
+                        "lf" => Some(LineEnding::Lf),
+                        "crlf" => Some(LineEnding::Crlf),
+                        "cr" => Some(LineEnding::Cr),
+                        _ => None,
+                    };
+                    options.line_ending.is_some()
+                }
+                ("semi", Value::Bool(semi)) => {
+                    options.javascript.semicolons = Some(if *semi {
+                        Semicolons::Always
+                    } else {
+                        Semicolons::AsNeeded
+                    });
+                    true
+                }
+                ("singleQuote", Value::Bool(single_quote)) => {
+                    options.javascript.quote_style = Some(to_quote_style(*single_quote));
+                    true
+                }
+                ("jsxSingleQuote", Value::Bool(single_quote)) => {
+                    options.javascript.jsx_quote_style = Some(to_quote_style(*single_quote));
+                    true
+                }
+                ("quoteProps", Value::String(quote_properties)) => {
+                    options.javascript.quote_properties = match quote_properties.as_str() {
+                        "as-needed" => Some(QuoteProperties::AsNeeded),
+                        "preserve" => Some(QuoteProperties::Preserve),
+                        _ => None,
+                    };
+                    options.javascript.quote_properties.is_some()
+                }
+                ("trailingComma", Value::String(trailing_comma)) => {
+                    options.javascript.trailing_comma = match trailing_comma.as_str() {
+                        "all" => Some(TrailingComma::All),
+                        "es5" => Some(TrailingComma::Es5),
+                        "none" => Some(TrailingComma::None),
+                        _ => None,
+                    };
+                    options.javascript.trailing_comma.is_some()
+                }
+                ("arrowParens", Value::String(arrow_parentheses)) => {
+                    options.javascript.arrow_parentheses = match arrow_parentheses.as_str() {
+                        "always" => Some(ArrowParentheses::Always),
+                        "avoid" => Some(ArrowParentheses::AsNeeded),
+                        _ => None,
+                    };
+                    options.javascript.arrow_parentheses.is_some()
+                }
+                ("bracketSpacing", Value::Bool(bracket_spacing)) => {
+                    options.javascript.bracket_spacing = Some(*bracket_spacing);
+                    true
+                }
+                ("bracketSameLine" | "jsxBracketSameLine", Value::Bool(bracket_same_line)) => {
+                    options.javascript.bracket_same_line = Some(*bracket_same_line);
+                    true
+                }
+                _ => false,
+            };
+            if !migrated {
+                self.unsupported_options.insert(format!("{name}: {value}"));
+            }
+        }
+    }
+
+    /// Updates the Biome `configuration` with the migrated options
+    fn apply(&mut self, configuration: &mut Configuration) {
+        let formatter = configuration
+            .formatter
+            .get_or_insert_with(FormatterConfiguration::empty);
+        if let Some(indent_style) = self.options.indent_style.take() {
+            formatter.indent_style = Some(indent_style);
+        }
+        if let Some(indent_width) = self.options.indent_width {
+            formatter.indent_width = Some(indent_width);
+        }
+        if let Some(line_width) = self.options.line_width {
+            formatter.line_width = Some(line_width);
+        }
+        if let Some(line_ending) = self.options.line_ending {
+            formatter.line_ending = Some(line_ending);
+        }
+        formatter.prettier_compatibility = Some(true);
+
+        configuration
+            .javascript
+            .get_or_insert_with(JavascriptConfiguration::default)
+            .formatter
+            .get_or_insert_with(JavascriptFormatter::default)
+            .merge_with(std::mem::take(&mut self.options.javascript));
+
+        if !self.ignore.is_empty() {
+            let files = configuration
+                .files
+                .get_or_insert_with(FilesConfiguration::default);
+            let mut ignore = files
+                .ignore
+                .take()
+                .map(StringSet::into_index_set)
+                .unwrap_or_default();
+            ignore.extend(self.ignore.drain(..));
+            files.ignore = Some(StringSet::new(ignore));
+        }
+
+        for prettier_override in self.overrides.drain(..) {
+            let formatter = prettier_override.options.to_override_formatter();
+            let javascript = prettier_override.options.to_javascript();
+            if formatter.is_none() && javascript.is_none() {
+                continue;
+            }
+            let pattern = OverridePattern {
+                include: Some(StringSet::new(
+                    prettier_override.include.into_iter().collect(),
+                )),
+                ignore: (!prettier_override.ignore.is_empty())
+                    .then(|| StringSet::new(prettier_override.ignore.into_iter().collect())),
+                formatter,
+                javascript,
+                ..OverridePattern::default()
+            };
+            let overrides = &mut configuration
+                .overrides
+                .get_or_insert_with(|| Overrides(Vec::new()))
+                .0;
+            // The override may have been migrated already
+            if !overrides.contains(&pattern) {
+                overrides.push(pattern);
+            }
+        }
+    }
+}
+
+const fn to_quote_style(single_quote: bool) -> QuoteStyle {
+    if single_quote {
+        QuoteStyle::Single
+    } else {
+        QuoteStyle::Double
+    }
+}
@@ -1,18 +1,26 @@
 mod diagnostics;
+mod interactive;
 mod migrate;
 mod process_file;
 mod std_in;
 mod traverse;
+mod watch;
 
-use crate::cli_options::CliOptions;
+use crate::baseline::Baseline;
+use crate::cache::ResultCache;
+use crate::cli_options::{CliOptions, CliReporter, MaxDiagnostics};
+use crate::commands::MigrateSubCommand;
 use crate::execute::traverse::traverse;
+use crate::vcs::ChangedLines;
 use crate::{CliDiagnostic, CliSession};
 use biome_diagnostics::{category, Category, MAXIMUM_DISPLAYABLE_DIAGNOSTICS};
 use biome_fs::RomePath;
+use biome_service::configuration::{IgnoreFiles, NestedConfigurations, RuleSelector};
 use biome_service::workspace::{FeatureName, FixFileMode};
+use rustc_hash::FxHashMap;
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Useful information during the traversal of files and virtual content
 pub(crate) struct Execution {
@@ -23,7 +31,46 @@ pub(crate) struct Execution {
     traversal_mode: TraversalMode,
 
     /// The maximum number of diagnostics that can be printed in console
-    max_diagnostics: u16,
+    max_diagnostics: MaxDiagnostics,
+
+    /// Whether the time spent running each lint rule is recorded, to be printed with
+    /// `--show-rule-timings` or reported by `--reporter=summary`
+    rule_timings: bool,
+
+    /// The content staged in the git index of the files to process, when running with `--staged`
+    staged_files: Option<FxHashMap<PathBuf, String>>,
+
+    /// Whether the files are processed again when they change, when running with `--watch`
+    watch: bool,
+
+    /// Whether the diagnostics are reviewed one by one, when running with `--interactive`
+    interactive: bool,
+
+    /// Whether several files are read from stdin, when running with `--stdin-batch`
+    stdin_batch: bool,
+
+    /// The file where the profile of the traversal is written, when running with `--profile`
+    profile: Option<PathBuf>,
+
+    /// The lines the diagnostics must intersect to be reported, when running with
+    /// `--changed-lines-only`
+    changed_lines: Option<ChangedLines>,
+
+    /// The known diagnostics that aren't reported, when running with `--baseline` or
+    /// `--write-baseline`
+    baseline: Option<Baseline>,
+
+    /// The files without diagnostics in the previous runs, which are skipped when they didn't
+    /// change, when running with `--cache`
+    cache: Option<ResultCache>,
+
+    /// The configuration files of the subdirectories of the root configuration, applied to
+    /// the files of their directory during the traversal
+    nested_configurations: Option<NestedConfigurations>,
+
+    /// The `.biomeignore` files of the traversed directories, applied to the files of their
+    /// directory during the traversal
+    ignore_files: Option<IgnoreFiles>,
 }
 
 impl Execution {
@@ -65,6 +112,14 @@ pub(crate) enum TraversalMode {
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<(PathBuf, String)>,
+        /// The rules to run, regardless of the configuration. When it's empty, the rules
+        /// enabled by the configuration are run.
+        rules: Vec<RuleSelector>,
+        /// The rules whose fixes are applied. When it's empty, the fixes of all the rules
+        /// that run are applied.
+        fixed_rules: Vec<RuleSelector>,
+        /// The explanation of the suppression comments added with `--suppress`
+        suppression_reason: Option<String>,
     },
     /// This mode is enabled when running the command `biome ci`
     CI {
@@ -77,6 +132,9 @@ pub(crate) enum TraversalMode {
         ignore_errors: bool,
         /// It writes the new content on file
         write: bool,
+        /// It formats the formatted content a second time, and reports the files
+        /// whose content changes again
+        verify_idempotence: bool,
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
@@ -87,6 +145,8 @@ pub(crate) enum TraversalMode {
         write: bool,
         configuration_file_path: PathBuf,
         configuration_directory_path: PathBuf,
+        /// The tool whose configuration is migrated, when it isn't Biome
+        sub_command: Option<MigrateSubCommand>,
     },
 }
 
@@ -110,6 +170,53 @@ pub(crate) enum ReportMode {
     Terminal,
     /// Reports information in JSON format
     Json,
+    /// Reports the diagnostics using the SARIF 2.1.0 format
+    Sarif,
+    /// Reports the diagnostics using the JUnit XML format
+    Junit,
+    /// Reports the diagnostics using the Code Quality format of GitLab
+    GitLab,
+    /// Reports the diagnostics as annotations of GitHub Actions
+    GitHub,
+    /// Reports the diagnostics using the Checkstyle XML format
+    Checkstyle,
+    /// Reports each diagnostic as a line of JSON, as soon as it's emitted
+    JsonLines,
+    /// Reports how many diagnostics each rule emitted
+    Summary,
+    /// Reports the changes of the formatter as a unified diff
+    Patch,
+}
+
+impl From<CliReporter> for ReportMode {
+    fn from(reporter: CliReporter) -> Self {
+        match reporter {
+            CliReporter::Default => Self::Terminal,
+            CliReporter::Sarif => Self::Sarif,
+            CliReporter::Junit => Self::Junit,
+            CliReporter::GitLab => Self::GitLab,
+            CliReporter::GitHub => Self::GitHub,
+            CliReporter::Checkstyle => Self::Checkstyle,
+            CliReporter::JsonLines => Self::JsonLines,
+            CliReporter::Summary => Self::Summary,
+        }
+    }
+}
+
+impl ReportMode {
+    /// Whether the report is built from all the diagnostics, regardless of `--max-diagnostics`,
+    /// because it's read by a tool rather than in the terminal
+    const fn reports_all_diagnostics(&self) -> bool {
+        matches!(
+            self,
+            ReportMode::Summary
+                | ReportMode::Sarif
+                | ReportMode::Junit
+                | ReportMode::GitLab
+                | ReportMode::GitHub
+                | ReportMode::Checkstyle
+        )
+    }
 }
 
 impl Execution {
@@ -117,7 +224,18 @@ impl Execution {
         Self {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
-            max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            max_diagnostics: MaxDiagnostics::Total(MAXIMUM_DISPLAYABLE_DIAGNOSTICS),
+            rule_timings: false,
+            staged_files: None,
+            watch: false,
+            interactive: false,
+            stdin_batch: false,
+            profile: None,
+            changed_lines: None,
+            baseline: None,
+            cache: None,
+            nested_configurations: None,
+            ignore_files: None,
         }
     }
 
@@ -137,7 +255,18 @@ impl Execution {
                     None
                 },
             },
-            max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            max_diagnostics: MaxDiagnostics::Total(MAXIMUM_DISPLAYABLE_DIAGNOSTICS),
+            rule_timings: false,
+            staged_files: None,
+            watch: false,
+            interactive: false,
+            stdin_batch: false,
+            profile: None,
+            changed_lines: None,
+            baseline: None,
+            cache: None,
+            nested_configurations: None,
+            ignore_files: None,
         }
     }
 
@@ -146,23 +275,162 @@ impl Execution {
         Self {
             traversal_mode,
             report_mode,
-            max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            max_diagnostics: MaxDiagnostics::Total(MAXIMUM_DISPLAYABLE_DIAGNOSTICS),
+            rule_timings: false,
+            staged_files: None,
+            watch: false,
+            interactive: false,
+            stdin_batch: false,
+            profile: None,
+            changed_lines: None,
+            baseline: None,
+            cache: None,
+            nested_configurations: None,
+            ignore_files: None,
         }
     }
 
+    /// Watches the processed files, and processes them again when they change
+    pub(crate) fn with_watch(mut self) -> Self {
+        self.watch = true;
+        self
+    }
+
+    /// Reviews the lint diagnostics one by one, asking for each of them which action to take
+    pub(crate) fn with_interactive(mut self) -> Self {
+        self.interactive = true;
+        self
+    }
+
+    /// Reads the files to process from stdin, instead of traversing the file system
+    pub(crate) fn with_stdin_batch(mut self) -> Self {
+        self.stdin_batch = true;
+        self
+    }
+
+    /// Writes a profile of the traversal to the file at `path`
+    pub(crate) fn with_profile(mut self, path: PathBuf) -> Self {
+        self.profile = Some(path);
+        self
+    }
+
+    /// Reports only the diagnostics that intersect the given changed lines
+    pub(crate) fn with_changed_lines(mut self, changed_lines: ChangedLines) -> Self {
+        self.changed_lines = Some(changed_lines);
+        self
+    }
+
+    /// Doesn't report the diagnostics recorded in the given baseline
+    pub(crate) fn with_baseline(mut self, baseline: Baseline) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Returns the baseline of the known diagnostics, if the execution uses one
+    pub(crate) fn as_baseline(&self) -> Option<&Baseline> {
+        self.baseline.as_ref()
+    }
+
+    /// Skips the files whose results are recorded in the given cache
+    pub(crate) fn with_cache(mut self, cache: ResultCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Returns the cache of the results of the files, if the execution uses one
+    pub(crate) fn as_cache(&self) -> Option<&ResultCache> {
+        self.cache.as_ref()
+    }
+
+    /// Applies the configuration files found in the traversed directories to their files
+    pub(crate) fn with_nested_configurations(
+        mut self,
+        nested_configurations: NestedConfigurations,
+    ) -> Self {
+        self.nested_configurations = Some(nested_configurations);
+        self
+    }
+
+    /// Returns the configuration files of the traversed directories, if they are applied
+    pub(crate) fn as_nested_configurations(&self) -> Option<&NestedConfigurations> {
+        self.nested_configurations.as_ref()
+    }
+
+    /// Ignores the files matched by the `.biomeignore` files found in the traversed directories
+    pub(crate) fn with_ignore_files(mut self, ignore_files: IgnoreFiles) -> Self {
+        self.ignore_files = Some(ignore_files);
+        self
+    }
+
+    /// Returns the `.biomeignore` files of the traversed directories, if they are applied
+    pub(crate) fn as_ignore_files(&self) -> Option<&IgnoreFiles> {
+        self.ignore_files.as_ref()
+    }
+
+    /// Returns the lines the diagnostics must intersect to be reported
+    pub(crate) fn as_changed_lines(&self) -> Option<&ChangedLines> {
+        self.changed_lines.as_ref()
+    }
+
+    /// Returns the file where the profile of the traversal is written
+    pub(crate) fn as_profile_path(&self) -> Option<&Path> {
+        self.profile.as_deref()
+    }
+
+    /// Processes the content staged in the git index of the given files, instead of their
+    /// content on the file system
+    pub(crate) fn with_staged_files(mut self, staged_files: FxHashMap<PathBuf, String>) -> Self {
+        self.staged_files = Some(staged_files);
+        self
+    }
+
+    /// Returns the content staged in the git index of the file at `path`, when the execution
+    /// processes staged files
+    pub(crate) fn as_staged_content(&self, path: &Path) -> Option<&str> {
+        self.staged_files
+            .as_ref()
+            .and_then(|staged_files| staged_files.get(path))
+            .map(String::as_str)
+    }
+
+    /// Whether the execution processes the content staged in the git index
+    pub(crate) fn has_staged_files(&self) -> bool {
+        self.staged_files.is_some()
+    }
+
     /// Tells if the reporting is happening straight to terminal
     pub(crate) fn should_report_to_terminal(&self) -> bool {
         matches!(self.report_mode, ReportMode::Terminal)
     }
 
+    /// Tells if the diagnostics are collected, to be printed to terminal or through a reporter
+    pub(crate) fn should_collect_diagnostics(&self) -> bool {
+        !matches!(self.report_mode, ReportMode::Json)
+    }
+
+    /// Tells if the summary of the traversal, such as the number of checked files,
+    /// should be printed to terminal
+    pub(crate) fn should_report_summary(&self) -> bool {
+        matches!(self.report_mode, ReportMode::Terminal | ReportMode::Summary)
+    }
+
+    pub(crate) fn report_mode(&self) -> ReportMode {
+        self.report_mode
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
 
-    pub(crate) fn get_max_diagnostics(&self) -> u16 {
+    pub(crate) fn get_max_diagnostics(&self) -> MaxDiagnostics {
         self.max_diagnostics
     }
 
+    /// Whether the time spent running each lint rule is recorded and printed
+    pub(crate) fn should_show_rule_timings(&self) -> bool {
+        self.rule_timings
+    }
+
     /// `true` only when running the traversal in [TraversalMode::Check] and `should_fix` is `true`
     pub(crate) fn as_fix_file_mode(&self) -> Option<&FixFileMode> {
         match &self.traversal_mode {
@@ -174,6 +442,40 @@ impl Execution {
         }
     }
 
+    /// The rules selected with `--rule`. When it's empty, the rules enabled by the
+    /// configuration are run.
+    pub(crate) fn as_selected_rules(&self) -> &[RuleSelector] {
+        match &self.traversal_mode {
+            TraversalMode::Lint { rules, .. } => rules,
+            TraversalMode::Check { .. }
+            | TraversalMode::Format { .. }
+            | TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. } => &[],
+        }
+    }
+
+    /// The rules whose fixes are applied, selected with `--only`. When none is selected, the
+    /// fixes of the rules that run are applied.
+    pub(crate) fn as_fixed_rules(&self) -> &[RuleSelector] {
+        match &self.traversal_mode {
+            TraversalMode::Lint { fixed_rules, .. } if !fixed_rules.is_empty() => fixed_rules,
+            _ => self.as_selected_rules(),
+        }
+    }
+
+    /// The explanation of the suppression comments, passed with `--reason`
+    pub(crate) fn as_suppression_reason(&self) -> Option<&str> {
+        match &self.traversal_mode {
+            TraversalMode::Lint {
+                suppression_reason, ..
+            } => suppression_reason.as_deref(),
+            TraversalMode::Check { .. }
+            | TraversalMode::Format { .. }
+            | TraversalMode::CI { .. }
+            | TraversalMode::Migrate { .. } => None,
+        }
+    }
+
     pub(crate) fn as_diagnostic_category(&self) -> &'static Category {
         match self.traversal_mode {
             TraversalMode::Check { .. } => category!("check"),
@@ -253,37 +555,79 @@ impl Execution {
 /// or handles the stdin file.
 pub(crate) fn execute_mode(
     mut mode: Execution,
-    session: CliSession,
+    mut session: CliSession,
     cli_options: &CliOptions,
     paths: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
-    if cli_options.max_diagnostics > MAXIMUM_DISPLAYABLE_DIAGNOSTICS {
-        return Err(CliDiagnostic::overflown_argument(
-            "--max-diagnostics",
-            MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
-        ));
+    // The budgets of each severity are explicit, so they aren't capped
+    if let MaxDiagnostics::Total(total) = cli_options.max_diagnostics {
+        if total > MAXIMUM_DISPLAYABLE_DIAGNOSTICS {
+            return Err(CliDiagnostic::overflown_argument(
+                "--max-diagnostics",
+                MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            ));
+        }
     }
-
     mode.max_diagnostics = cli_options.max_diagnostics;
+    mode.rule_timings = cli_options.show_rule_timings;
+    if cli_options.reporter != CliReporter::Default {
+        mode.report_mode = ReportMode::from(cli_options.reporter);
+    }
+    if mode.report_mode.reports_all_diagnostics() {
+        // The reports and the statistics take into account all the diagnostics
+        mode.max_diagnostics = MaxDiagnostics::Total(u16::MAX);
+    }
+    if matches!(mode.report_mode, ReportMode::Summary) {
+        // The summary reports the time spent running each rule
+        mode.rule_timings = true;
+    }
 
     // don't do any traversal if there's some content coming from stdin
     if let Some((path, content)) = mode.as_stdin_file() {
         let rome_path = RomePath::new(path);
         std_in::run(session, &mode, rome_path, content.as_str())
+    } else if mode.stdin_batch {
+        std_in::run_batch(session, &mode)
     } else if let TraversalMode::Migrate {
         write,
         configuration_file_path,
         configuration_directory_path,
+        sub_command,
     } = mode.traversal_mode
     {
-        migrate::run(
-            session,
-            write,
-            configuration_file_path,
-            configuration_directory_path,
-            cli_options.verbose,
-        )
+        match sub_command {
+            Some(MigrateSubCommand::Eslint) => migrate::eslint::run(
+                session,
+                write,
+                configuration_file_path,
+                configuration_directory_path,
+                cli_options.verbose,
+            ),
+            Some(MigrateSubCommand::Prettier) => migrate::prettier::run(
+                session,
+                write,
+                configuration_file_path,
+                configuration_directory_path,
+                cli_options.verbose,
+            ),
+            None => migrate::run(
+                session,
+                write,
+                configuration_file_path,
+                configuration_directory_path,
+                cli_options.verbose,
+            ),
+        }
+    } else if mode.interactive {
+        interactive::run(&mut session, paths)
+    } else if mode.watch {
+        watch::run(&mode, &mut session, cli_options, paths)
     } else {
-        traverse(mode, session, cli_options, paths)
+        if cli_options.cache && !cli_options.no_cache {
+            if let Some(cache) = ResultCache::load(&*session.app.fs, &mode) {
+                mode = mode.with_cache(cache);
+            }
+        }
+        traverse(&mode, &mut session, cli_options, paths)
     }
 }
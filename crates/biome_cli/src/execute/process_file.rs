@@ -8,12 +8,17 @@ use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::process_file::check::check_file;
 use crate::execute::process_file::format::format;
 use crate::execute::process_file::lint::lint;
+use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
 use crate::CliDiagnostic;
-use biome_diagnostics::{category, DiagnosticExt, Error};
+use biome_diagnostics::{category, Category, DiagnosticExt, Error, Severity};
 use biome_fs::RomePath;
-use biome_service::workspace::{FeatureName, FeaturesBuilder, SupportKind, SupportsFeatureParams};
+use biome_service::workspace::{
+    FeatureName, FeaturesBuilder, RuleTimingEntry, SupportKind, SupportsFeatureParams,
+};
+use std::cell::Cell;
+use std::collections::BTreeMap;
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::path::Path;
@@ -39,6 +44,8 @@ pub(crate) enum Message {
         content: String,
         diagnostics: Vec<Error>,
         skipped_diagnostics: u64,
+        /// The number of diagnostics the workspace didn't return, by category
+        skipped_diagnostics_by_category: BTreeMap<String, u64>,
     },
     Diff {
         file_name: String,
@@ -46,12 +53,32 @@ pub(crate) enum Message {
         new: String,
         diff_kind: DiffKind,
     },
+    /// The time spent running each rule on a file, with `--show-rule-timings`
+    RuleTimings(Vec<RuleTimingEntry>),
+}
+
+impl DiffKind {
+    pub(crate) fn category(&self) -> &'static Category {
+        match self {
+            DiffKind::Format => category!("format"),
+            DiffKind::OrganizeImports => category!("organizeImports"),
+        }
+    }
 }
 
 impl Message {
     pub(crate) const fn is_diagnostic(&self) -> bool {
         matches!(self, Message::Diff { .. } | Message::Diagnostics { .. })
     }
+
+    /// Whether the message reports a failure, as opposed to an information such as a file
+    /// that was skipped because it's too large
+    pub(crate) fn is_error(&self) -> bool {
+        match self {
+            Message::Error(error) => error.severity() >= Severity::Error,
+            _ => true,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -85,6 +112,8 @@ pub(crate) type FileResult = Result<FileStatus, Message>;
 /// compiler constraints set by the lifetimes of the [TraversalOptions]
 pub(crate) struct SharedTraversalOptions<'ctx, 'app> {
     inner: &'app TraversalOptions<'ctx, 'app>,
+    /// Whether messages were sent to the display thread while processing the file
+    has_messages: Cell<bool>,
     _p: PhantomData<&'app ()>,
 }
 
@@ -93,8 +122,15 @@ impl<'ctx, 'app> SharedTraversalOptions<'ctx, 'app> {
         Self {
             _p: PhantomData,
             inner: t,
+            has_messages: Cell::new(false),
         }
     }
+
+    /// Send a message to the display thread
+    pub(crate) fn push_message(&self, msg: impl Into<Message>) {
+        self.has_messages.set(true);
+        self.inner.push_message(msg);
+    }
 }
 
 impl<'ctx, 'app> Deref for SharedTraversalOptions<'ctx, 'app> {
@@ -212,24 +248,57 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
 
         let shared_context = &SharedTraversalOptions::new(ctx);
         ctx.increment_processed();
-        match ctx.execution.traversal_mode {
+
+        let (file, input) = WorkspaceFile::read(shared_context, path)?;
+
+        // The files without diagnostics that didn't change since the last run are skipped
+        let cache_key = cache_key(ctx, path, &input);
+        if let (Some(cache), Some(key)) = (ctx.execution.as_cache(), &cache_key) {
+            if cache.is_cached(path, key) {
+                return Ok(FileStatus::Success);
+            }
+        }
+
+        let workspace_file = WorkspaceFile::open(shared_context, path, file, input)?;
+        let result = match ctx.execution.traversal_mode {
             TraversalMode::Lint { .. } => {
                 // the unsupported case should be handled already at this point
-                lint(shared_context, path)
+                lint(shared_context, workspace_file)
             }
             TraversalMode::Format { .. } => {
                 // the unsupported case should be handled already at this point
-                format(shared_context, path)
-            }
-            TraversalMode::Check { .. } => {
-                check_file(shared_context, path, &file_features, category!("check"))
-            }
-            TraversalMode::CI { .. } => {
-                check_file(shared_context, path, &file_features, category!("ci"))
+                format(shared_context, workspace_file)
             }
+            TraversalMode::Check { .. } => check_file(
+                shared_context,
+                workspace_file,
+                &file_features,
+                category!("check"),
+            ),
+            TraversalMode::CI { .. } => check_file(
+                shared_context,
+                workspace_file,
+                &file_features,
+                category!("ci"),
+            ),
             TraversalMode::Migrate { .. } => {
                 unreachable!("The migration should not be called for this file")
             }
+        };
+
+        if let (Some(cache), Some(key)) = (ctx.execution.as_cache(), cache_key) {
+            let has_diagnostics =
+                shared_context.has_messages.get() || !matches!(result, Ok(FileStatus::Success));
+            cache.record(path, (!has_diagnostics).then_some(key));
         }
+        result
     })
 }
+
+/// Returns the key of the result of the file at `path`, whose content is `content`, in the
+/// cache, when the execution uses one
+fn cache_key(ctx: &TraversalOptions, path: &Path, content: &str) -> Option<String> {
+    let cache = ctx.execution.as_cache()?;
+    let nested_configurations = ctx.execution.as_nested_configurations()?;
+    Some(cache.result_key(nested_configurations, path, content))
+}
@@ -6,16 +6,14 @@ use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraver
 use crate::CliDiagnostic;
 use biome_diagnostics::Category;
 use biome_service::workspace::{FeatureName, FileFeaturesResult};
-use std::path::Path;
 
 pub(crate) fn check_file<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
-    path: &Path,
+    mut workspace_file: WorkspaceFile,
     file_features: &'ctx FileFeaturesResult,
     category: &'static Category,
 ) -> FileResult {
     let mut has_errors = false;
-    let mut workspace_file = WorkspaceFile::new(ctx, path)?;
     tracing::info_span!("Process check", path =? workspace_file.path.display()).in_scope(
         move || {
             if file_features.supports_for(&FeatureName::Lint) {
@@ -30,8 +28,8 @@ pub(crate) fn check_file<'ctx>(
                         }
                     }
                     Err(err) => {
+                        has_errors |= err.is_error();
                         ctx.push_message(err);
-                        has_errors = true;
                     }
                 }
             }
@@ -47,8 +45,8 @@ pub(crate) fn check_file<'ctx>(
                         }
                     }
                     Err(err) => {
+                        has_errors |= err.is_error();
                         ctx.push_message(err);
-                        has_errors = true;
                     }
                 }
             }
@@ -65,8 +63,8 @@ pub(crate) fn check_file<'ctx>(
                         }
                     }
                     Err(err) => {
+                        has_errors |= err.is_error();
                         ctx.push_message(err);
-                        has_errors = true;
                     }
                 }
             }
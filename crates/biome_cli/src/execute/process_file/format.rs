@@ -1,4 +1,6 @@
-use crate::execute::diagnostics::{ResultExt, SkippedDiagnostic};
+use crate::execute::diagnostics::{
+    ContentDiffAdvice, FormatIdempotenceDiagnostic, ResultExt, SkippedDiagnostic,
+};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{
     DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
@@ -7,12 +9,13 @@ use crate::execute::TraversalMode;
 use crate::FormatterReportFileDetail;
 use biome_diagnostics::{category, DiagnosticExt};
 use biome_service::workspace::RuleCategories;
-use std::path::Path;
 use std::sync::atomic::Ordering;
 use tracing::debug;
 
-pub(crate) fn format<'ctx>(ctx: &'ctx SharedTraversalOptions<'ctx, '_>, path: &Path) -> FileResult {
-    let mut workspace_file = WorkspaceFile::new(ctx, path)?;
+pub(crate) fn format<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    mut workspace_file: WorkspaceFile,
+) -> FileResult {
     format_with_guard(ctx, &mut workspace_file)
 }
 
@@ -26,25 +29,33 @@ pub(crate) fn format_with_guard<'ctx>(
             debug!("Pulling diagnostics from parsed file");
             let diagnostics_result = workspace_file
                 .guard()
-                .pull_diagnostics(RuleCategories::SYNTAX, max_diagnostics.into())
+                .pull_diagnostics(
+                    RuleCategories::SYNTAX,
+                    max_diagnostics.into(),
+                    Vec::new(),
+                    false,
+                )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("format"),
                 )?;
 
             let input = workspace_file.input()?;
-            let (should_write, ignore_errors) = match ctx.execution.traversal_mode {
-                TraversalMode::Format {
-                    write,
-                    ignore_errors,
-                    ..
-                } => (write, ignore_errors),
+            let (should_write, ignore_errors, verify_idempotence) =
+                match ctx.execution.traversal_mode {
+                    TraversalMode::Format {
+                        write,
+                        ignore_errors,
+                        verify_idempotence,
+                        ..
+                    } => (write, ignore_errors, verify_idempotence),
 
-                _ => (
-                    ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe(),
-                    false,
-                ),
-            };
+                    _ => (
+                        ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe(),
+                        false,
+                        false,
+                    ),
+                };
             debug!("Should write the file to disk? {}", should_write);
             debug!("Should ignore errors? {}", ignore_errors);
 
@@ -69,11 +80,45 @@ pub(crate) fn format_with_guard<'ctx>(
                 return Ok(FileStatus::Ignored);
             }
 
+            // A file that is already formatted is trivially stable, the other files are formatted
+            // a second time to check that the formatter doesn't change its own output. The output
+            // is only written once it passed the check.
+            if verify_idempotence && output != input {
+                workspace_file.change_content(output.clone())?;
+                let second_output = workspace_file
+                    .guard()
+                    .format_file()
+                    .with_file_path_and_code(
+                        workspace_file.path.display().to_string(),
+                        category!("format"),
+                    )?
+                    .into_code();
+
+                if second_output != output {
+                    return Err(Message::from(FormatIdempotenceDiagnostic {
+                        file_name: workspace_file.path.display().to_string(),
+                        diff: ContentDiffAdvice {
+                            old: output,
+                            new: second_output,
+                        },
+                    }));
+                }
+                if should_write {
+                    workspace_file.write_content(&output)?;
+                    return Ok(FileStatus::Success);
+                }
+            }
+
             if output != input {
                 if should_write {
                     workspace_file.update_file(output)?;
                 } else {
-                    if !ctx.execution.should_report_to_terminal() {
+                    if let Some(changed_lines) = ctx.execution.as_changed_lines() {
+                        if !changed_lines.contains_diff(&workspace_file.path, &input, &output) {
+                            return Ok(FileStatus::Success);
+                        }
+                    }
+                    if !ctx.execution.should_collect_diagnostics() {
                         ctx.push_format_stat(
                             workspace_file.path.display().to_string(),
                             FormatterReportFileDetail {
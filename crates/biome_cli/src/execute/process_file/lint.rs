@@ -2,14 +2,15 @@ use crate::execute::diagnostics::ResultExt;
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use crate::CliDiagnostic;
-use biome_diagnostics::{category, Error};
+use biome_diagnostics::{category, Error, Severity};
 use biome_service::workspace::RuleCategories;
-use std::path::Path;
 use std::sync::atomic::Ordering;
 
 /// Lints a single file and returns a [FileResult]
-pub(crate) fn lint<'ctx>(ctx: &'ctx SharedTraversalOptions<'ctx, '_>, path: &Path) -> FileResult {
-    let mut workspace_file = WorkspaceFile::new(ctx, path)?;
+pub(crate) fn lint<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    mut workspace_file: WorkspaceFile,
+) -> FileResult {
     lint_with_guard(ctx, &mut workspace_file)
 }
 
@@ -25,7 +26,12 @@ pub(crate) fn lint_with_guard<'ctx>(
             if let Some(fix_mode) = ctx.execution.as_fix_file_mode() {
                 let fix_result = workspace_file
                     .guard()
-                    .fix_file(*fix_mode, false)
+                    .fix_file(
+                        *fix_mode,
+                        false,
+                        ctx.execution.as_fixed_rules().to_vec(),
+                        ctx.execution.as_suppression_reason().map(String::from),
+                    )
                     .with_file_path_and_code(
                         workspace_file.path.display().to_string(),
                         category!("lint"),
@@ -42,29 +48,63 @@ pub(crate) fn lint_with_guard<'ctx>(
                 errors = fix_result.errors;
             }
 
-            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
+            // The baseline needs all the diagnostics of the file, the ones that are printed are
+            // limited when the messages are processed
+            let max_diagnostics = if ctx.execution.as_baseline().is_some() {
+                u64::MAX
+            } else {
+                ctx.remaining_diagnostics.load(Ordering::Relaxed).into()
+            };
             let pull_diagnostics_result = workspace_file
                 .guard()
-                .pull_diagnostics(RuleCategories::LINT, max_diagnostics.into())
+                .pull_diagnostics(
+                    RuleCategories::LINT,
+                    max_diagnostics,
+                    ctx.execution.as_selected_rules().to_vec(),
+                    ctx.execution.should_show_rule_timings(),
+                )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("lint"),
                 )?;
 
-            let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
-                && pull_diagnostics_result.skipped_diagnostics == 0;
-            errors += pull_diagnostics_result.errors;
+            let mut diagnostics: Vec<_> = pull_diagnostics_result
+                .diagnostics
+                .into_iter()
+                .map(Error::from)
+                .collect();
+            if let Some(changed_lines) = ctx.execution.as_changed_lines() {
+                diagnostics.retain(|diagnostic| {
+                    changed_lines.contains_diagnostic(&workspace_file.path, &input, diagnostic)
+                });
+            }
+            if let Some(baseline) = ctx.execution.as_baseline() {
+                baseline.filter_diagnostics(&workspace_file.path, &input, &mut diagnostics);
+            }
+            if ctx.execution.as_changed_lines().is_some() || ctx.execution.as_baseline().is_some() {
+                errors += diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.severity() >= Severity::Error)
+                    .count();
+            } else {
+                errors += pull_diagnostics_result.errors;
+            }
+
+            if !pull_diagnostics_result.rule_timings.is_empty() {
+                ctx.push_message(Message::RuleTimings(pull_diagnostics_result.rule_timings));
+            }
+
+            let no_diagnostics =
+                diagnostics.is_empty() && pull_diagnostics_result.skipped_diagnostics == 0;
 
             if !no_diagnostics {
                 ctx.push_message(Message::Diagnostics {
                     name: workspace_file.path.display().to_string(),
                     content: input,
-                    diagnostics: pull_diagnostics_result
-                        .diagnostics
-                        .into_iter()
-                        .map(Error::from)
-                        .collect(),
+                    diagnostics,
                     skipped_diagnostics: pull_diagnostics_result.skipped_diagnostics,
+                    skipped_diagnostics_by_category: pull_diagnostics_result
+                        .skipped_diagnostics_by_category,
                 });
             }
 
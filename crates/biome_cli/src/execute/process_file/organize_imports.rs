@@ -25,6 +25,12 @@ pub(crate) fn organize_imports_with_guard<'ctx>(
                 if ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe() {
                     workspace_file.update_file(sorted.code)?;
                 } else {
+                    if let Some(changed_lines) = ctx.execution.as_changed_lines() {
+                        if !changed_lines.contains_diff(&workspace_file.path, &input, &sorted.code)
+                        {
+                            return Ok(FileStatus::Success);
+                        }
+                    }
                     return Ok(FileStatus::Message(Message::Diff {
                         file_name: workspace_file.path.display().to_string(),
                         old: input,
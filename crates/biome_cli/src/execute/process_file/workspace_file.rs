@@ -15,13 +15,12 @@ pub(crate) struct WorkspaceFile<'ctx, 'app> {
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
-    /// It attempts to read the file from disk, creating a [FileGuard] and
-    /// saving these information internally
-    pub(crate) fn new(
+    /// It attempts to read the file from disk, or its staged content, without opening it in
+    /// the workspace
+    pub(crate) fn read(
         ctx: &SharedTraversalOptions<'ctx, 'app>,
         path: &Path,
-    ) -> Result<Self, Error> {
-        let rome_path = RomePath::new(path);
+    ) -> Result<(Box<dyn File>, String), Error> {
         let open_options = OpenOptions::default()
             .read(true)
             .write(ctx.execution.requires_write_access());
@@ -31,15 +30,29 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             .with_file_path(path.display().to_string())?;
 
         let mut input = String::new();
-        file.read_to_string(&mut input)
-            .with_file_path(path.display().to_string())?;
+        match ctx.execution.as_staged_content(path) {
+            Some(staged_content) => input.push_str(staged_content),
+            None => file
+                .read_to_string(&mut input)
+                .with_file_path(path.display().to_string())?,
+        }
+        Ok((file, input))
+    }
 
+    /// Opens the file read by [WorkspaceFile::read] in the workspace, creating a [FileGuard]
+    pub(crate) fn open(
+        ctx: &SharedTraversalOptions<'ctx, 'app>,
+        path: &Path,
+        file: Box<dyn File>,
+        input: String,
+    ) -> Result<Self, Error> {
+        let rome_path = RomePath::new(path);
         let guard = FileGuard::open(
             ctx.workspace,
             OpenFileParams {
                 path: rome_path,
                 version: 0,
-                content: input.clone(),
+                content: input,
                 language_hint: Language::default(),
             },
         )
@@ -70,4 +83,19 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
             .change_file(self.file.file_version(), new_content)?;
         Ok(())
     }
+
+    /// It writes `new_content` to disk, when the workspace file already has this content
+    pub(crate) fn write_content(&mut self, new_content: &str) -> Result<(), Error> {
+        self.file
+            .set_content(new_content.as_bytes())
+            .with_file_path(self.path.display().to_string())?;
+        Ok(())
+    }
+
+    /// It updates the workspace file with `new_content`, without writing it to disk
+    pub(crate) fn change_content(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
+        self.guard
+            .change_file(self.file.file_version() + 1, new_content.into())?;
+        Ok(())
+    }
 }
@@ -3,13 +3,13 @@
 use crate::execute::diagnostics::{ContentDiffAdvice, FormatDiffDiagnostic};
 use crate::execute::Execution;
 use crate::{CliDiagnostic, CliSession};
-use biome_console::{markup, ConsoleExt};
-use biome_diagnostics::PrintDiagnostic;
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::{Error, PrintDiagnostic};
 use biome_fs::RomePath;
 use biome_service::workspace::{
-    ChangeFileParams, FeatureName, FeaturesBuilder, FixFileParams, FormatFileParams, Language,
-    OpenFileParams, OrganizeImportsParams, PullDiagnosticsParams, RuleCategories,
-    SupportsFeatureParams,
+    ChangeFileParams, CloseFileParams, FeatureName, FeaturesBuilder, FixFileParams,
+    FormatFileParams, Language, OpenFileParams, OrganizeImportsParams, PullDiagnosticsParams,
+    RuleCategories, SupportsFeatureParams, Workspace,
 };
 use std::borrow::Cow;
 
@@ -21,6 +21,100 @@ pub(crate) fn run<'a>(
 ) -> Result<(), CliDiagnostic> {
     let workspace = &*session.app.workspace;
     let console = &mut *session.app.console;
+    process(
+        workspace,
+        console,
+        mode,
+        rome_path,
+        content,
+        &mut false,
+        |console, output| {
+            console.append(markup! {
+                {output}
+            });
+        },
+    )
+}
+
+/// Reads the files from stdin one after the other, and prints the output of each of them
+/// as soon as it's processed, until stdin ends.
+///
+/// Each file is read as its path on a line, the length in bytes of its content on a line,
+/// and its content. The output of each file is printed with the same framing: its path on
+/// a line, the length in bytes of the output on a line, and the output. The diagnostics are
+/// printed to stderr, like with `--stdin-file-path`.
+///
+/// A file that can't be processed doesn't stop the batch: its content is printed unchanged in
+/// its frame, and the error is printed to stderr.
+pub(crate) fn run_batch(session: CliSession, mode: &Execution) -> Result<(), CliDiagnostic> {
+    let workspace = &*session.app.workspace;
+    let console = &mut *session.app.console;
+
+    while let Some(path) = console.read_line() {
+        if path.is_empty() {
+            continue;
+        }
+        let content = console
+            .read_line()
+            .and_then(|length| length.trim().parse::<usize>().ok())
+            .and_then(|length| console.read_exact(length))
+            .ok_or_else(|| CliDiagnostic::invalid_stdin_batch(&path))?;
+
+        let rome_path = RomePath::new(&path);
+        let mut opened = false;
+        let mut printed = false;
+        let result = process(
+            workspace,
+            console,
+            mode,
+            rome_path.clone(),
+            &content,
+            &mut opened,
+            |console, output| {
+                print_frame(console, &path, output);
+                printed = true;
+            },
+        );
+        // The file isn't opened when it's not supported, ignored, or when its feature is disabled
+        let result = result.and_then(|()| {
+            if opened {
+                workspace.close_file(CloseFileParams { path: rome_path })?;
+            }
+            Ok(())
+        });
+        if let Err(error) = result {
+            if !printed {
+                print_frame(console, &path, &content);
+            }
+            console.error(markup! {
+                {PrintDiagnostic::simple(&Error::from(error))}
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the `output` of the file at `path` in the framing of `--stdin-batch`
+fn print_frame(console: &mut dyn Console, path: &str, output: &str) {
+    let length = output.len();
+    console.append(markup! {
+        {path}"\n"{length}"\n"{output}
+    });
+}
+
+/// Processes the `content` of the file at `rome_path` based on `mode`. The output, which is
+/// the code after formatting or applying the fixes, is printed with `print_output` before
+/// the diagnostics. `opened` is set when the file is opened in the workspace.
+fn process(
+    workspace: &dyn Workspace,
+    console: &mut dyn Console,
+    mode: &Execution,
+    rome_path: RomePath,
+    content: &str,
+    opened: &mut bool,
+    print_output: impl FnOnce(&mut dyn Console, &str),
+) -> Result<(), CliDiagnostic> {
     let mut version = 0;
 
     if mode.is_format() {
@@ -35,15 +129,12 @@ pub(crate) fn run<'a>(
                 content: content.into(),
                 language_hint: Language::default(),
             })?;
+            *opened = true;
             let printed = workspace.format_file(FormatFileParams { path: rome_path })?;
 
-            console.append(markup! {
-                {printed.as_code()}
-            });
+            print_output(console, printed.as_code());
         } else {
-            console.append(markup! {
-                {content}
-            });
+            print_output(console, content);
             console.error(markup!{
                     <Warn>"The content was not formatted because the formatter is currently disabled."</Warn>
                 })
@@ -58,6 +149,7 @@ pub(crate) fn run<'a>(
             content: content.into(),
             language_hint: Language::default(),
         })?;
+        *opened = true;
         // apply fix file of the linter
         let file_features = workspace.file_features(SupportsFeatureParams {
             path: rome_path.clone(),
@@ -74,6 +166,8 @@ pub(crate) fn run<'a>(
                     path: rome_path.clone(),
                     should_format: mode.is_check()
                         && file_features.supports_for(&FeatureName::Format),
+                    only: mode.as_fixed_rules().to_vec(),
+                    suppression_reason: mode.as_suppression_reason().map(String::from),
                 })?;
                 if fix_file_result.code != new_content {
                     version += 1;
@@ -106,7 +200,9 @@ pub(crate) fn run<'a>(
             let result = workspace.pull_diagnostics(PullDiagnosticsParams {
                 categories: RuleCategories::LINT | RuleCategories::SYNTAX,
                 path: rome_path.clone(),
-                max_diagnostics: mode.max_diagnostics.into(),
+                max_diagnostics: mode.get_max_diagnostics().total().into(),
+                only: mode.as_selected_rules().to_vec(),
+                rule_timings: false,
             })?;
             diagnostics.extend(result.diagnostics);
         }
@@ -131,18 +227,7 @@ pub(crate) fn run<'a>(
             }
         }
 
-        match new_content {
-            Cow::Borrowed(original_content) => {
-                console.append(markup! {
-                    {original_content}
-                });
-            }
-            Cow::Owned(new_content) => {
-                console.append(markup! {
-                    {new_content}
-                });
-            }
-        }
+        print_output(console, &new_content);
         if !diagnostics.is_empty() {
             for diag in diagnostics {
                 console.error(markup! {
@@ -151,7 +236,7 @@ pub(crate) fn run<'a>(
             }
         }
     } else {
-        console.append(markup! {{content}});
+        print_output(console, content);
     }
     Ok(())
 }
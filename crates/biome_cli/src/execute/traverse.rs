@@ -1,10 +1,22 @@
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
-use super::ExecutionEnvironment;
-use crate::cli_options::CliOptions;
+use super::{ExecutionEnvironment, ReportMode};
+use crate::cli_options::{CliOptions, GroupBy, MaxDiagnostics};
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice,
     FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
 };
+use crate::profile::Profiler;
+use crate::reports::checkstyle::CheckstyleReport;
+use crate::reports::explanation::RuleExplanation;
+use crate::reports::github::GitHubReport;
+use crate::reports::gitlab::GitLabReport;
+use crate::reports::json_lines::JsonLine;
+use crate::reports::junit::JunitReport;
+use crate::reports::patch::PatchReport;
+use crate::reports::rule_groups::RuleGroupsReport;
+use crate::reports::rule_timings::RuleTimingsReport;
+use crate::reports::sarif::SarifReport;
+use crate::reports::summary::SummaryReport;
 use crate::{
     CliDiagnostic, CliSession, Execution, FormatterReportFileDetail, FormatterReportSummary,
     Report, ReportDiagnostic, ReportDiff, ReportErrorKind, ReportKind, TraversalMode,
@@ -12,24 +24,27 @@ use crate::{
 use biome_console::{fmt, markup, Console, ConsoleExt};
 use biome_diagnostics::PrintGitHubDiagnostic;
 use biome_diagnostics::{
-    adapters::StdError, category, DiagnosticExt, Error, PrintDescription, PrintDiagnostic,
-    Resource, Severity,
+    adapters::StdError, category, related_file_paths, Category, Diagnostic, DiagnosticExt, Error,
+    PrintDescription, PrintDiagnostic, Resource, Severity,
 };
-use biome_fs::{FileSystem, PathInterner, RomePath};
+use biome_fs::{FileSystem, OpenOptions, PathInterner, RomePath};
 use biome_fs::{TraversalContext, TraversalScope};
-use biome_service::workspace::{FeaturesBuilder, IsPathIgnoredParams};
+use biome_service::workspace::{FeaturesBuilder, FixFileMode, IsPathIgnoredParams};
 use biome_service::{
     workspace::{FeatureName, SupportsFeatureParams},
     Workspace, WorkspaceError,
 };
 use crossbeam::{
-    channel::{unbounded, Receiver, Sender},
+    channel::{bounded, unbounded, Receiver, Sender},
     select,
 };
+use rayon::ThreadPool;
 use rustc_hash::FxHashSet;
 use std::{
+    collections::BTreeMap,
     ffi::OsString,
     io,
+    num::NonZeroUsize,
     panic::catch_unwind,
     path::{Path, PathBuf},
     sync::{
@@ -39,6 +54,7 @@ use std::{
     thread,
     time::{Duration, Instant},
 };
+use tracing::{dispatcher, Dispatch};
 
 struct CheckResult {
     count: usize,
@@ -56,14 +72,29 @@ impl fmt::Display for CheckResult {
     }
 }
 
+/// The number of messages the workers can send before waiting for the console thread to handle
+/// them. The messages hold the content of the files along with their diagnostics, so the workers
+/// mustn't get too far ahead of the console.
+const MESSAGES_CAPACITY: usize = 256;
+
 ///
 pub(crate) fn traverse(
-    execution: Execution,
-    session: CliSession,
+    execution: &Execution,
+    session: &mut CliSession,
     cli_options: &CliOptions,
     inputs: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
     init_thread_pool();
+    let profiler = execution.as_profile_path().map(|_| Profiler::new());
+    // The global thread pool is used, unless the threads must be configured
+    let thread_pool = if cli_options.threads.is_some() || profiler.is_some() {
+        Some(build_thread_pool(
+            cli_options.threads,
+            profiler.as_ref().map(Profiler::dispatch),
+        ))
+    } else {
+        None
+    };
     if inputs.is_empty() && execution.as_stdin_file().is_none() {
         return Err(CliDiagnostic::missing_argument(
             "<INPUT>",
@@ -72,7 +103,7 @@ pub(crate) fn traverse(
     }
 
     let (interner, recv_files) = PathInterner::new();
-    let (send_msgs, recv_msgs) = unbounded();
+    let (send_msgs, recv_msgs) = bounded(MESSAGES_CAPACITY);
     let (sender_reports, recv_reports) = unbounded();
 
     let processed = AtomicUsize::new(0);
@@ -83,10 +114,12 @@ pub(crate) fn traverse(
     let console = &mut *session.app.console;
 
     let max_diagnostics = execution.get_max_diagnostics();
-    let remaining_diagnostics = AtomicU16::new(max_diagnostics);
+    let remaining_diagnostics = AtomicU16::new(max_diagnostics.total());
 
     let mut errors: usize = 0;
     let mut warnings: usize = 0;
+    let mut infos: usize = 0;
+    let mut hints: usize = 0;
     let mut report = Report::default();
 
     let duration = thread::scope(|s| {
@@ -94,7 +127,8 @@ pub(crate) fn traverse(
             .name(String::from("biome::console"))
             .spawn_scoped(s, || {
                 process_messages(ProcessMessagesOptions {
-                    execution: &execution,
+                    execution,
+                    fs,
                     console,
                     recv_reports,
                     recv_files,
@@ -104,38 +138,74 @@ pub(crate) fn traverse(
                     errors: &mut errors,
                     report: &mut report,
                     verbose: cli_options.verbose,
+                    verbose_explanations: cli_options.verbose_explanations,
                     warnings: &mut warnings,
+                    infos: &mut infos,
+                    hints: &mut hints,
                     diagnostic_level: &cli_options.diagnostic_level,
+                    group_by: cli_options.group_by,
                 });
             })
             .expect("failed to spawn console thread");
 
         // The traversal context is scoped to ensure all the channels it
         // contains are properly closed once the traversal finishes
-        traverse_inputs(
-            fs,
-            inputs,
-            &TraversalOptions {
+        let traversal = || {
+            traverse_inputs(
                 fs,
-                workspace,
-                execution: &execution,
-                interner,
-                processed: &processed,
-                skipped: &skipped,
-                messages: send_msgs,
-                sender_reports,
-                remaining_diagnostics: &remaining_diagnostics,
-            },
-        )
+                inputs,
+                &TraversalOptions {
+                    fs,
+                    workspace,
+                    execution,
+                    interner,
+                    processed: &processed,
+                    skipped: &skipped,
+                    messages: send_msgs,
+                    sender_reports,
+                    remaining_diagnostics: &remaining_diagnostics,
+                },
+            )
+        };
+        match &thread_pool {
+            Some(thread_pool) => thread_pool.install(traversal),
+            None => traversal(),
+        }
     });
 
+    if let (Some(profiler), Some(path)) = (profiler, execution.as_profile_path()) {
+        let mut file = fs.open_with_options(
+            path,
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?;
+        file.set_content(profiler.to_json().as_bytes())?;
+    }
+
+    if let Some(baseline) = execution.as_baseline() {
+        baseline.save(fs)?;
+    }
+
+    if let Some(cache) = execution.as_cache() {
+        cache.save(fs)?;
+    }
+
     let count = processed.load(Ordering::Relaxed);
     let skipped = skipped.load(Ordering::Relaxed);
 
-    if execution.should_report_to_terminal() {
+    if execution.should_report_summary() {
         match execution.traversal_mode() {
             TraversalMode::Check { .. } | TraversalMode::Lint { .. } => {
-                if execution.as_fix_file_mode().is_some() {
+                if matches!(
+                    execution.as_fix_file_mode(),
+                    Some(FixFileMode::ApplySuppressions)
+                ) {
+                    console.log(markup! {
+                        <Info>"Suppressed the diagnostics of "{count}" file(s) in "{duration}</Info>
+                    });
+                } else if execution.as_fix_file_mode().is_some() {
                     console.log(markup! {
                         <Info>"Fixed "{count}" file(s) in "{duration}</Info>
                     });
@@ -181,7 +251,7 @@ pub(crate) fn traverse(
                 });
             }
         }
-    } else {
+    } else if !execution.should_collect_diagnostics() {
         if let TraversalMode::Format { write, .. } = execution.traversal_mode() {
             let mut summary = FormatterReportSummary::default();
             if *write {
@@ -199,28 +269,37 @@ pub(crate) fn traverse(
         return Ok(());
     }
 
-    if skipped > 0 {
+    if skipped > 0 && execution.should_report_to_terminal() {
         console.log(markup! {
             <Warn>"Skipped "{skipped}" file(s)"</Warn>
         });
     }
 
-    let should_exit_on_warnings = warnings > 0 && cli_options.error_on_warnings;
+    let failing_severity = cli_options.failing_severity();
+    let should_exit_on_warnings = warnings > 0 && failing_severity <= Severity::Warning;
+    let should_exit_on_infos = (infos > 0 && failing_severity <= Severity::Information)
+        || (hints > 0 && failing_severity <= Severity::Hint);
     // Processing emitted error diagnostics, exit with a non-zero code
     if count.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
         Err(CliDiagnostic::no_files_processed())
-    } else if errors > 0 || should_exit_on_warnings {
+    } else if errors > 0 || should_exit_on_warnings || should_exit_on_infos {
         let category = execution.as_diagnostic_category();
-        if should_exit_on_warnings {
+        if errors > 0 {
+            if execution.is_check_apply() {
+                Err(CliDiagnostic::apply_error(category))
+            } else {
+                Err(CliDiagnostic::check_error(category))
+            }
+        } else if should_exit_on_warnings {
             if execution.is_check_apply() {
                 Err(CliDiagnostic::apply_warnings(category))
             } else {
                 Err(CliDiagnostic::check_warnings(category))
             }
         } else if execution.is_check_apply() {
-            Err(CliDiagnostic::apply_error(category))
+            Err(CliDiagnostic::apply_infos(category))
         } else {
-            Err(CliDiagnostic::check_error(category))
+            Err(CliDiagnostic::check_infos(category))
         }
     } else {
         Ok(())
@@ -240,6 +319,35 @@ fn init_thread_pool() {
     });
 }
 
+/// Creates a thread pool used in place of the global thread pool, with `threads` threads
+/// when the number of threads is set with `--threads`.
+///
+/// When `dispatch` is provided, it's the default [Dispatch] of the threads of the pool, so
+/// that it records the spans emitted while processing the files.
+fn build_thread_pool(threads: Option<NonZeroUsize>, dispatch: Option<Dispatch>) -> ThreadPool {
+    let builder = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.map_or(0, NonZeroUsize::get))
+        .thread_name(|index| format!("biome::worker_{index}"));
+    let thread_pool = match dispatch {
+        Some(dispatch) => builder
+            .spawn_handler(move |thread| {
+                let dispatch = dispatch.clone();
+                let mut builder = thread::Builder::new();
+                if let Some(name) = thread.name() {
+                    builder = builder.name(name.to_string());
+                }
+                if let Some(stack_size) = thread.stack_size() {
+                    builder = builder.stack_size(stack_size);
+                }
+                builder.spawn(move || dispatcher::with_default(&dispatch, || thread.run()))?;
+                Ok(())
+            })
+            .build(),
+        None => builder.build(),
+    };
+    thread_pool.expect("failed to initialize the thread pool")
+}
+
 /// Initiate the filesystem traversal tasks with the provided input paths and
 /// run it to completion, returning the duration of the process
 fn traverse_inputs(fs: &dyn FileSystem, inputs: Vec<OsString>, ctx: &TraversalOptions) -> Duration {
@@ -257,6 +365,8 @@ fn traverse_inputs(fs: &dyn FileSystem, inputs: Vec<OsString>, ctx: &TraversalOp
 struct ProcessMessagesOptions<'ctx> {
     ///  Execution of the traversal
     execution: &'ctx Execution,
+    /// The file system, to read the other files the diagnostics point to
+    fs: &'ctx dyn FileSystem,
     /// Mutable reference to the [console](Console)
     console: &'ctx mut dyn Console,
     /// Receiver channel for reporting statistics
@@ -266,7 +376,7 @@ struct ProcessMessagesOptions<'ctx> {
     /// Receiver channel that expects info when a message is sent
     recv_msgs: Receiver<Message>,
     /// The maximum number of diagnostics the console thread is allowed to print
-    max_diagnostics: u16,
+    max_diagnostics: MaxDiagnostics,
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
     remaining_diagnostics: &'ctx AtomicU16,
@@ -276,20 +386,32 @@ struct ProcessMessagesOptions<'ctx> {
     /// Mutable reference to a boolean flag tracking whether the console thread
     /// printed any warnings-level message
     warnings: &'ctx mut usize,
+    /// Mutable reference to a counter of the information-level messages
+    infos: &'ctx mut usize,
+    /// Mutable reference to a counter of the hint-level messages
+    hints: &'ctx mut usize,
     /// Mutable handle to a [Report] instance the console thread should write
     /// stats into
     report: &'ctx mut Report,
     /// Whether the console thread should print diagnostics in verbose mode
     verbose: bool,
+    /// Whether the console thread should explain the diagnostics of the lint rules
+    verbose_explanations: bool,
     /// The diagnostic level the console thread should print
     diagnostic_level: &'ctx Severity,
+    /// How the console thread groups the diagnostics it prints
+    group_by: GroupBy,
 }
 
 /// This thread receives [Message]s from the workers through the `recv_msgs`
-/// and `recv_files` channels and handles them based on [Execution]
+/// and `recv_files` channels and handles them based on [Execution].
+///
+/// The diagnostics printed to the terminal are printed as soon as they are received, so they
+/// aren't kept in memory. Only the reporters that print a single document collect them.
 fn process_messages(options: ProcessMessagesOptions) {
     let ProcessMessagesOptions {
         execution: mode,
+        fs,
         console,
         recv_reports,
         recv_files,
@@ -299,18 +421,36 @@ fn process_messages(options: ProcessMessagesOptions) {
         errors,
         report,
         verbose,
+        verbose_explanations,
         warnings,
+        infos,
+        hints,
         diagnostic_level,
+        group_by,
     } = options;
 
     let mut paths: FxHashSet<String> = FxHashSet::default();
-    let mut printed_diagnostics: u16 = 0;
-    let mut not_printed_diagnostics = 0;
+    let mut budget = DiagnosticsBudget::new(max_diagnostics, remaining_diagnostics);
     let mut total_skipped_suggested_fixes = 0;
+    let mut rule_timings = RuleTimingsReport::default();
 
     let mut is_msg_open = true;
     let mut is_report_open = true;
     let mut diagnostics_to_print = vec![];
+    let mut patch = PatchReport::default();
+    let mut printer = DiagnosticsPrinter {
+        verbose,
+        verbose_explanations,
+        diagnostic_level,
+        group_by,
+        running_on_github: matches!(
+            mode.traversal_mode(),
+            TraversalMode::CI {
+                environment: Some(ExecutionEnvironment::GitHub),
+            }
+        ),
+        grouped: vec![],
+    };
     while is_msg_open || is_report_open {
         let msg = select! {
             recv(recv_msgs) -> msg => match msg {
@@ -340,18 +480,13 @@ fn process_messages(options: ProcessMessagesOptions) {
                 total_skipped_suggested_fixes += skipped_suggested_fixes;
             }
 
+            Message::RuleTimings(entries) => {
+                rule_timings.record(entries);
+            }
+
             Message::ApplyError(error) => {
                 *errors += 1;
-                let should_print = printed_diagnostics < max_diagnostics;
-                if should_print {
-                    printed_diagnostics += 1;
-                    remaining_diagnostics.store(
-                        max_diagnostics.saturating_sub(printed_diagnostics),
-                        Ordering::Relaxed,
-                    );
-                } else {
-                    not_printed_diagnostics += 1;
-                }
+                let should_print = budget.try_print(error.severity(), error.category());
                 if mode.should_report_to_terminal() && should_print {
                     diagnostics_to_print.push(Error::from(error));
                 }
@@ -359,8 +494,11 @@ fn process_messages(options: ProcessMessagesOptions) {
 
             Message::Error(mut err) => {
                 let location = err.location();
-                if err.severity() == Severity::Warning {
-                    *warnings += 1;
+                match err.severity() {
+                    Severity::Warning => *warnings += 1,
+                    Severity::Information => *infos += 1,
+                    Severity::Hint => *hints += 1,
+                    _ => {}
                 }
                 if let Some(Resource::File(file_path)) = location.resource.as_ref() {
                     // Retrieves the file name from the file ID cache, if it's a miss
@@ -388,18 +526,9 @@ fn process_messages(options: ProcessMessagesOptions) {
                     }
                 }
 
-                let should_print = printed_diagnostics < max_diagnostics;
-                if should_print {
-                    printed_diagnostics += 1;
-                    remaining_diagnostics.store(
-                        max_diagnostics.saturating_sub(printed_diagnostics),
-                        Ordering::Relaxed,
-                    );
-                } else {
-                    not_printed_diagnostics += 1;
-                }
+                let should_print = budget.try_print(err.severity(), err.category());
 
-                if mode.should_report_to_terminal() {
+                if mode.should_collect_diagnostics() {
                     if should_print {
                         diagnostics_to_print.push(err);
                     }
@@ -430,49 +559,41 @@ fn process_messages(options: ProcessMessagesOptions) {
                 content,
                 diagnostics,
                 skipped_diagnostics,
+                skipped_diagnostics_by_category,
             } => {
-                not_printed_diagnostics += skipped_diagnostics;
+                budget.skip(skipped_diagnostics, skipped_diagnostics_by_category);
 
                 // is CI mode we want to print all the diagnostics
                 if mode.is_ci() {
                     for diag in diagnostics {
                         let severity = diag.severity();
-                        if severity == Severity::Error {
-                            *errors += 1;
-                        }
-                        if severity == Severity::Warning {
-                            *warnings += 1;
+                        match severity {
+                            Severity::Error | Severity::Fatal => *errors += 1,
+                            Severity::Warning => *warnings += 1,
+                            Severity::Information => *infos += 1,
+                            Severity::Hint => *hints += 1,
                         }
 
                         let diag = diag.with_file_path(&name).with_file_source_code(&content);
-                        diagnostics_to_print.push(diag);
+                        diagnostics_to_print.push(with_related_source_code(fs, diag));
                     }
                 } else {
                     for diag in diagnostics {
                         let severity = diag.severity();
-                        if severity == Severity::Error {
-                            *errors += 1;
-                        }
-                        if severity == Severity::Warning {
-                            *warnings += 1;
+                        match severity {
+                            Severity::Error | Severity::Fatal => *errors += 1,
+                            Severity::Warning => *warnings += 1,
+                            Severity::Information => *infos += 1,
+                            Severity::Hint => *hints += 1,
                         }
 
-                        let should_print = printed_diagnostics < max_diagnostics;
-                        if should_print {
-                            printed_diagnostics += 1;
-                            remaining_diagnostics.store(
-                                max_diagnostics.saturating_sub(printed_diagnostics),
-                                Ordering::Relaxed,
-                            );
-                        } else {
-                            not_printed_diagnostics += 1;
-                        }
+                        let should_print = budget.try_print(severity, diag.category());
 
-                        if mode.should_report_to_terminal() {
+                        if mode.should_collect_diagnostics() {
                             if should_print {
                                 let diag =
                                     diag.with_file_path(&name).with_file_source_code(&content);
-                                diagnostics_to_print.push(diag)
+                                diagnostics_to_print.push(with_related_source_code(fs, diag))
                             }
                         } else {
                             report.push_detail_report(ReportKind::Error(
@@ -498,18 +619,14 @@ fn process_messages(options: ProcessMessagesOptions) {
                     *errors += 1;
                 }
 
-                let should_print = printed_diagnostics < max_diagnostics;
-                if should_print {
-                    printed_diagnostics += 1;
-                    remaining_diagnostics.store(
-                        max_diagnostics.saturating_sub(printed_diagnostics),
-                        Ordering::Relaxed,
-                    );
-                } else {
-                    not_printed_diagnostics += 1;
+                if let ReportMode::Patch = mode.report_mode() {
+                    patch.push(&file_name, old, new);
+                    continue;
                 }
 
-                if mode.should_report_to_terminal() {
+                let should_print = budget.try_print(Severity::Error, Some(diff_kind.category()));
+
+                if mode.should_collect_diagnostics() {
                     if should_print {
                         if mode.is_ci() {
                             match diff_kind {
@@ -571,24 +688,68 @@ fn process_messages(options: ProcessMessagesOptions) {
                 }
             }
         }
+
+        // Streams the diagnostics as soon as they are received
+        match mode.report_mode() {
+            ReportMode::JsonLines => {
+                for diagnostic in diagnostics_to_print.drain(..) {
+                    if diagnostic.severity() >= *diagnostic_level {
+                        let line = JsonLine::new(&diagnostic).to_string();
+                        console.log(markup! {{line}});
+                    }
+                }
+            }
+            ReportMode::Terminal | ReportMode::Json => {
+                for diagnostic in diagnostics_to_print.drain(..) {
+                    printer.print(console, diagnostic);
+                }
+            }
+            _ => {}
+        }
     }
-    let running_on_github = matches!(
-        mode.traversal_mode(),
-        TraversalMode::CI {
-            environment: Some(ExecutionEnvironment::GitHub),
+
+    let reporter: Option<fn(&[Error]) -> String> = match mode.report_mode() {
+        ReportMode::Sarif => Some(|diagnostics| SarifReport::new(diagnostics).to_string()),
+        ReportMode::Junit => Some(|diagnostics| JunitReport::new(diagnostics).to_string()),
+        ReportMode::GitLab => Some(|diagnostics| GitLabReport::new(diagnostics).to_string()),
+        ReportMode::GitHub => Some(|diagnostics| GitHubReport::new(diagnostics).to_string()),
+        ReportMode::Checkstyle => {
+            Some(|diagnostics| CheckstyleReport::new(diagnostics).to_string())
         }
-    );
+        ReportMode::Summary => {
+            diagnostics_to_print.retain(|diagnostic| diagnostic.severity() >= *diagnostic_level);
+            let report = SummaryReport::new(&diagnostics_to_print, &rule_timings).to_string();
+            console.log(markup! {{report}});
+            return;
+        }
+        // The diagnostics were already streamed
+        ReportMode::JsonLines => return,
+        ReportMode::Patch => {
+            if !patch.is_empty() {
+                // The console prints the last line break
+                let patch = patch.to_string();
+                console.log(markup! {{patch.trim_end_matches('\n')}});
+            }
+            // The other diagnostics are printed to the standard error
+            None
+        }
+        ReportMode::Terminal | ReportMode::Json => None,
+    };
+    if let Some(reporter) = reporter {
+        diagnostics_to_print.retain(|diagnostic| diagnostic.severity() >= *diagnostic_level);
+        let report = reporter(&diagnostics_to_print);
+        console.log(markup! {{report}});
+        return;
+    }
 
+    // The diagnostics of the patch mode are printed after the patch
     for diagnostic in diagnostics_to_print {
-        if diagnostic.severity() >= *diagnostic_level {
-            console.error(markup! {
-                {if verbose { PrintDiagnostic::verbose(&diagnostic) } else { PrintDiagnostic::simple(&diagnostic) }}
-            });
-        }
+        printer.print(console, diagnostic);
+    }
+    printer.print_rule_groups(console);
 
-        if running_on_github {
-            console.log(markup! {{PrintGitHubDiagnostic::simple(&diagnostic)}});
-        }
+    if mode.should_report_to_terminal() && !rule_timings.is_empty() {
+        console.log(markup! {{rule_timings}});
     }
 
     if mode.is_check() && total_skipped_suggested_fixes > 0 {
@@ -598,14 +759,163 @@ fn process_messages(options: ProcessMessagesOptions) {
         })
     }
 
-    if !mode.is_ci() && not_printed_diagnostics > 0 {
+    if !mode.is_ci() && budget.not_printed > 0 {
         console.log(markup! {
             <Warn>"The number of diagnostics exceeds the number allowed by Biome.\n"</Warn>
-            <Info>"Diagnostics not shown: "</Info><Emphasis>{not_printed_diagnostics}</Emphasis><Info>"."</Info>
+            <Info>"Diagnostics not shown: "</Info><Emphasis>{budget.not_printed}</Emphasis><Info>"."</Info>
+            {NotPrintedByCategory(&budget.not_printed_by_category)}
         })
     }
 }
 
+/// Prints the diagnostics to the terminal as the console thread receives them
+struct DiagnosticsPrinter<'ctx> {
+    verbose: bool,
+    verbose_explanations: bool,
+    diagnostic_level: &'ctx Severity,
+    group_by: GroupBy,
+    running_on_github: bool,
+    /// The diagnostics of the rules, printed together after the other diagnostics when they
+    /// are grouped by rule
+    grouped: Vec<Error>,
+}
+
+impl DiagnosticsPrinter<'_> {
+    fn print(&mut self, console: &mut dyn Console, diagnostic: Error) {
+        let is_printed = diagnostic.severity() >= *self.diagnostic_level;
+        let is_grouped =
+            self.group_by == GroupBy::Rule && RuleGroupsReport::is_rule_diagnostic(&diagnostic);
+        if is_printed && !is_grouped {
+            console.error(markup! {
+                {if self.verbose { PrintDiagnostic::verbose(&diagnostic) } else { PrintDiagnostic::simple(&diagnostic) }}
+            });
+            if let Some(explanation) = self
+                .verbose_explanations
+                .then(|| RuleExplanation::new(&diagnostic))
+                .flatten()
+            {
+                console.error(markup! {{explanation}});
+            }
+        }
+
+        if self.running_on_github {
+            console.log(markup! {{PrintGitHubDiagnostic::simple(&diagnostic)}});
+        }
+
+        if is_printed && is_grouped {
+            self.grouped.push(diagnostic);
+        }
+    }
+
+    fn print_rule_groups(&self, console: &mut dyn Console) {
+        let rule_groups = RuleGroupsReport::new(&self.grouped);
+        if !rule_groups.is_empty() {
+            console.error(markup! {{rule_groups}});
+        }
+    }
+}
+
+/// Tracks the diagnostics printed by the console thread against the budgets of
+/// `--max-diagnostics`, and counts the diagnostics that aren't printed by category
+struct DiagnosticsBudget<'ctx> {
+    max_diagnostics: MaxDiagnostics,
+    /// Shared with the workers, so they don't pull more diagnostics than the console prints
+    remaining_diagnostics: &'ctx AtomicU16,
+    printed_errors: u16,
+    printed_warnings: u16,
+    printed_infos: u16,
+    not_printed: u64,
+    not_printed_by_category: BTreeMap<String, u64>,
+}
+
+impl<'ctx> DiagnosticsBudget<'ctx> {
+    fn new(max_diagnostics: MaxDiagnostics, remaining_diagnostics: &'ctx AtomicU16) -> Self {
+        Self {
+            max_diagnostics,
+            remaining_diagnostics,
+            printed_errors: 0,
+            printed_warnings: 0,
+            printed_infos: 0,
+            not_printed: 0,
+            not_printed_by_category: BTreeMap::new(),
+        }
+    }
+
+    /// Returns `true` if a diagnostic with the given `severity` can be printed, and
+    /// counts it as printed. Otherwise it's counted as not printed.
+    fn try_print(&mut self, severity: Severity, category: Option<&Category>) -> bool {
+        let printed = self.printed();
+        let printed_with_severity = match severity {
+            Severity::Error | Severity::Fatal => &mut self.printed_errors,
+            Severity::Warning => &mut self.printed_warnings,
+            Severity::Information | Severity::Hint => &mut self.printed_infos,
+        };
+        let should_print = match self.max_diagnostics.budget(severity) {
+            Some(budget) => *printed_with_severity < budget,
+            None => printed < self.max_diagnostics.total(),
+        };
+
+        if should_print {
+            *printed_with_severity += 1;
+            self.remaining_diagnostics
+                .store(self.remaining(), Ordering::Relaxed);
+        } else {
+            self.not_printed += 1;
+            if let Some(category) = category {
+                *self
+                    .not_printed_by_category
+                    .entry(category.name().to_string())
+                    .or_insert(0) += 1;
+            }
+        }
+        should_print
+    }
+
+    /// Counts the diagnostics that the workers didn't pull because of the budget
+    fn skip(&mut self, count: u64, by_category: BTreeMap<String, u64>) {
+        self.not_printed += count;
+        for (category, count) in by_category {
+            *self.not_printed_by_category.entry(category).or_insert(0) += count;
+        }
+    }
+
+    fn printed(&self) -> u16 {
+        self.printed_errors + self.printed_warnings + self.printed_infos
+    }
+
+    /// The number of diagnostics that can still be printed
+    fn remaining(&self) -> u16 {
+        match self.max_diagnostics {
+            MaxDiagnostics::Total(total) => total.saturating_sub(self.printed()),
+            MaxDiagnostics::PerSeverity {
+                error,
+                warning,
+                information,
+            } => error
+                .saturating_sub(self.printed_errors)
+                .saturating_add(warning.saturating_sub(self.printed_warnings))
+                .saturating_add(information.saturating_sub(self.printed_infos)),
+        }
+    }
+}
+
+/// Prints the number of diagnostics that weren't printed for each category, starting
+/// from the categories with the most diagnostics
+struct NotPrintedByCategory<'a>(&'a BTreeMap<String, u64>);
+
+impl fmt::Display for NotPrintedByCategory<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> io::Result<()> {
+        let mut categories: Vec<_> = self.0.iter().collect();
+        categories.sort_by(|(_, first), (_, second)| second.cmp(first));
+        for (category, count) in categories {
+            fmt.write_markup(markup! {
+                "\n  "<Emphasis>{category}</Emphasis>": "{count}
+            })?;
+        }
+        Ok(())
+    }
+}
+
 /// Context object shared between directory traversal tasks
 pub(crate) struct TraversalOptions<'ctx, 'app> {
     /// Shared instance of [FileSystem]
@@ -664,6 +974,29 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     }
 
     fn can_handle(&self, rome_path: &RomePath) -> bool {
+        // The configuration file of a directory applies to its content, the directory itself
+        // is checked against the configuration of its parent
+        if let (Some(nested_configurations), Some(parent)) = (
+            self.execution.as_nested_configurations(),
+            rome_path.parent(),
+        ) {
+            for diagnostic in nested_configurations.load(self.fs, self.workspace, parent) {
+                self.push_diagnostic(diagnostic);
+            }
+        }
+
+        // Like the configuration files, the ignore file of a directory applies to its content
+        if let (Some(ignore_files), Some(parent)) =
+            (self.execution.as_ignore_files(), rome_path.parent())
+        {
+            for diagnostic in ignore_files.load(self.fs, parent) {
+                self.push_diagnostic(diagnostic);
+            }
+            if ignore_files.is_ignored(rome_path, rome_path.is_dir()) {
+                return false;
+            }
+        }
+
         if rome_path.is_dir() {
             let can_handle = !self
                 .workspace
@@ -719,6 +1052,24 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     }
 }
 
+/// Reads the other files that the advices of `diagnostic` point to, so their
+/// code frames can be printed
+fn with_related_source_code(fs: &dyn FileSystem, mut diagnostic: Error) -> Error {
+    for path in related_file_paths(diagnostic.as_ref()) {
+        let content = fs
+            .open_with_options(Path::new(&path), OpenOptions::default().read(true))
+            .and_then(|mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content)?;
+                Ok(content)
+            });
+        if let Ok(content) = content {
+            diagnostic = diagnostic.with_related_source_code(path, content);
+        }
+    }
+    diagnostic
+}
+
 /// This function wraps the [process_file] function implementing the traversal
 /// in a [catch_unwind] block and emit diagnostics in case of error (either the
 /// traversal function returns Err or panics)
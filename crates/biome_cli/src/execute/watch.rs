@@ -0,0 +1,87 @@
+use crate::cli_options::CliOptions;
+use crate::execute::traverse::traverse;
+use crate::execute::Execution;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::PrintDiagnostic;
+use biome_fs::RomePath;
+use biome_service::workspace::{FeatureName, FeaturesBuilder, SupportsFeatureParams};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Processes the files at `paths`, then watches them and processes again the files that
+/// change, until the watcher stops.
+///
+/// The workspace is kept between two runs, and only the files that changed are processed.
+pub(crate) fn run(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let watched_paths: Vec<_> = paths.iter().map(PathBuf::from).collect();
+    let watcher = session
+        .app
+        .fs
+        .watch(&watched_paths)
+        .map_err(CliDiagnostic::io_error)?;
+
+    let result = traverse(execution, session, cli_options, paths);
+    print_result(session, cli_options, result);
+
+    for changed_files in watcher {
+        let changed_files: Vec<_> = changed_files
+            .into_iter()
+            .filter(|path| can_check(session, path))
+            .map(PathBuf::into_os_string)
+            .collect();
+        if changed_files.is_empty() {
+            continue;
+        }
+
+        session.app.console.log(markup! {
+            <Info>"Files changed, checking "{changed_files.len()}" file(s)..."</Info>
+        });
+        let result = traverse(execution, session, cli_options, changed_files);
+        print_result(session, cli_options, result);
+    }
+
+    Ok(())
+}
+
+/// Whether the workspace can check the file at `path`. It filters out the files of
+/// the unsupported languages and the ignored files.
+fn can_check(session: &CliSession, path: &Path) -> bool {
+    session
+        .app
+        .workspace
+        .file_features(SupportsFeatureParams {
+            path: RomePath::new(path),
+            feature: FeaturesBuilder::new()
+                .with_linter()
+                .with_formatter()
+                .with_organize_imports()
+                .build(),
+        })
+        .is_ok_and(|file_features| {
+            file_features.supports_for(&FeatureName::Lint)
+                || file_features.supports_for(&FeatureName::Format)
+                || file_features.supports_for(&FeatureName::OrganizeImports)
+        })
+}
+
+/// The errors of a run don't stop the watcher, they are printed instead
+fn print_result(
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    result: Result<(), CliDiagnostic>,
+) {
+    if let Err(diagnostic) = result {
+        let console = &mut session.app.console;
+        if cli_options.verbose {
+            console.error(markup! {{PrintDiagnostic::verbose(&diagnostic)}});
+        } else {
+            console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! The keys of the files, and the hashes of their content, recorded in the baseline and the
+//! cache files. They must not change across runs and releases.
+
+use std::path::{Component, Path, PathBuf};
+
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+/// FNV-1a, which unlike the hasher of the standard library is stable across releases
+pub(crate) fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// The key of the file at `path`: its path relative to `root_directory`, with `/` as separator
+pub(crate) fn file_key(root_directory: &Path, path: &Path) -> String {
+    // `./src/index.js` and `src/index.js` have the same key
+    let without_current_dir = |path: &Path| -> PathBuf {
+        path.components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .collect()
+    };
+    let root_directory = without_current_dir(root_directory);
+    let path = without_current_dir(path);
+    let path = path.strip_prefix(&root_directory).unwrap_or(&path);
+    path.to_string_lossy().replace('\\', "/")
+}
@@ -11,14 +11,18 @@ use biome_fs::OsFileSystem;
 use biome_service::{App, DynRef, Workspace, WorkspaceRef};
 use std::env;
 
+mod baseline;
+mod cache;
 mod cli_options;
 mod commands;
 mod configuration;
 mod diagnostics;
 mod execute;
+mod keys;
 mod logging;
 mod metrics;
 mod panic;
+mod profile;
 mod reports;
 mod service;
 mod vcs;
@@ -74,8 +78,19 @@ impl<'app> CliSession<'app> {
         let result = match command {
             BiomeCommand::Version(_) => commands::version::full_version(self),
             BiomeCommand::Rage(_, daemon_logs) => commands::rage::rage(self, daemon_logs),
+            BiomeCommand::Explain(cli_options, rule_name) => {
+                commands::explain::explain(self, cli_options, rule_name)
+            }
+            BiomeCommand::Doctor(cli_options, paths) => {
+                commands::doctor::doctor(self, cli_options, paths)
+            }
+            BiomeCommand::Completions(shell) => commands::completions::completions(self, shell),
             BiomeCommand::Start(config_path) => commands::daemon::start(self, config_path),
             BiomeCommand::Stop => commands::daemon::stop(self),
+            BiomeCommand::Clean { dry_run } => commands::clean::clean(self, dry_run),
+            BiomeCommand::InstallGitHooks { hook, remove } => {
+                commands::install_git_hooks::install_git_hooks(self, hook, remove)
+            }
             BiomeCommand::Check {
                 apply,
                 apply_unsafe,
@@ -83,6 +98,13 @@ impl<'app> CliSession<'app> {
                 configuration: rome_configuration,
                 paths,
                 stdin_file_path,
+                stdin_batch,
+                changed,
+                since,
+                staged,
+                watch,
+                interactive,
+                profile,
                 linter_enabled,
                 organize_imports_enabled,
                 formatter_enabled,
@@ -95,6 +117,13 @@ impl<'app> CliSession<'app> {
                     configuration: rome_configuration,
                     paths,
                     stdin_file_path,
+                    stdin_batch,
+                    changed,
+                    since,
+                    staged,
+                    watch,
+                    interactive,
+                    profile,
                     linter_enabled,
                     organize_imports_enabled,
                     formatter_enabled,
@@ -107,6 +136,16 @@ impl<'app> CliSession<'app> {
                 linter_configuration,
                 paths,
                 stdin_file_path,
+                stdin_batch,
+                changed,
+                since,
+                staged,
+                rule,
+                only,
+                suppress,
+                reason,
+                baseline,
+                write_baseline,
                 vcs_configuration,
                 files_configuration,
             } => commands::lint::lint(
@@ -118,6 +157,16 @@ impl<'app> CliSession<'app> {
                     linter_configuration,
                     paths,
                     stdin_file_path,
+                    stdin_batch,
+                    changed,
+                    since,
+                    staged,
+                    rules: rule,
+                    fixed_rules: only,
+                    suppress,
+                    suppression_reason: reason,
+                    baseline,
+                    write_baseline,
                     vcs_configuration,
                     files_configuration,
                 },
@@ -127,6 +176,8 @@ impl<'app> CliSession<'app> {
                 formatter_enabled,
                 organize_imports_enabled,
                 configuration: rome_configuration,
+                changed_lines_only,
+                since,
                 paths,
                 cli_options,
             } => commands::ci::ci(
@@ -136,6 +187,8 @@ impl<'app> CliSession<'app> {
                     formatter_enabled,
                     organize_imports_enabled,
                     rome_configuration,
+                    changed_lines_only,
+                    since,
                     paths,
                     cli_options,
                 },
@@ -144,7 +197,14 @@ impl<'app> CliSession<'app> {
                 javascript_formatter,
                 formatter_configuration,
                 stdin_file_path,
+                stdin_batch,
                 write,
+                check,
+                patch,
+                verify_idempotence,
+                changed,
+                since,
+                staged,
                 cli_options,
                 paths,
                 vcs_configuration,
@@ -156,7 +216,14 @@ impl<'app> CliSession<'app> {
                     javascript_formatter,
                     formatter_configuration,
                     stdin_file_path,
+                    stdin_batch,
                     write,
+                    check,
+                    patch,
+                    verify_idempotence,
+                    changed,
+                    since,
+                    staged,
                     cli_options,
                     paths,
                     vcs_configuration,
@@ -164,10 +231,12 @@ impl<'app> CliSession<'app> {
                     json_formatter,
                 },
             ),
-            BiomeCommand::Init => commands::init::init(self),
-            BiomeCommand::LspProxy(config_path) => commands::daemon::lsp_proxy(config_path),
-            BiomeCommand::Migrate(cli_options, write) => {
-                commands::migrate::migrate(self, cli_options, write)
+            BiomeCommand::Init { interactive } => commands::init::init(self, interactive),
+            BiomeCommand::LspProxy(config_path, transport) => {
+                commands::daemon::lsp_proxy(config_path, transport)
+            }
+            BiomeCommand::Migrate(cli_options, write, sub_command) => {
+                commands::migrate::migrate(self, cli_options, write, sub_command)
             }
             BiomeCommand::RunServer {
                 stop_on_disconnect,
@@ -0,0 +1,246 @@
+//! Records the spans emitted while Biome processes the files, and prints them in the
+//! [Trace Event Format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU),
+//! which can be opened with `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+//!
+//! Each span is recorded as a "complete" event: the parsing, the linting and the
+//! formatting of each file, and each rule that runs on a node.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Dispatch, Metadata, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{registry, Layer};
+
+/// Collects the events of the trace
+pub(crate) struct Profiler {
+    start: Instant,
+    events: Mutex<Vec<TraceEvent>>,
+    /// The names of the threads that emitted events, by identifier
+    threads: Mutex<BTreeMap<usize, String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceFile<'a> {
+    trace_events: &'a [TraceEvent],
+    display_time_unit: &'static str,
+}
+
+/// An event of the Trace Event Format. The timestamps are in microseconds.
+#[derive(Debug, Serialize)]
+struct TraceEvent {
+    name: String,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    cat: &'static str,
+    ph: &'static str,
+    ts: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dur: Option<f64>,
+    pid: u32,
+    tid: usize,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    args: BTreeMap<&'static str, String>,
+}
+
+impl Profiler {
+    pub(crate) fn new() -> Arc<Self> {
+        Arc::new(Self {
+            start: Instant::now(),
+            events: Mutex::default(),
+            threads: Mutex::default(),
+        })
+    }
+
+    /// Returns the dispatcher that records the spans of the threads where it's the default
+    pub(crate) fn dispatch(self: &Arc<Self>) -> Dispatch {
+        Dispatch::new(registry().with(ProfileLayer(self.clone())))
+    }
+
+    /// Returns the trace in the JSON format of the Trace Event Format
+    pub(crate) fn to_json(&self) -> String {
+        let mut events: Vec<_> = self
+            .threads
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(tid, name)| TraceEvent {
+                name: String::from("thread_name"),
+                cat: "",
+                ph: "M",
+                ts: 0.0,
+                dur: None,
+                pid: 1,
+                tid: *tid,
+                args: BTreeMap::from([("name", name.clone())]),
+            })
+            .collect();
+        let mut spans = std::mem::take(&mut *self.events.lock().unwrap());
+        spans.sort_by(|a, b| a.ts.total_cmp(&b.ts).then(a.tid.cmp(&b.tid)));
+        events.extend(spans);
+
+        serde_json::to_string(&TraceFile {
+            trace_events: &events,
+            display_time_unit: "ms",
+        })
+        .unwrap_or_default()
+    }
+
+    /// Returns the identifier of the current thread in the trace
+    fn thread_id(&self) -> usize {
+        static NEXT_THREAD_ID: AtomicUsize = AtomicUsize::new(1);
+        thread_local! {
+            static THREAD_ID: usize = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let tid = THREAD_ID.with(|tid| *tid);
+        self.threads.lock().unwrap().entry(tid).or_insert_with(|| {
+            thread::current()
+                .name()
+                .map_or_else(|| format!("thread {tid}"), str::to_string)
+        });
+        tid
+    }
+}
+
+struct ProfileLayer(Arc<Profiler>);
+
+/// The data of a span stored by [ProfileLayer]
+struct SpanData {
+    args: BTreeMap<&'static str, String>,
+    /// The instants when the span was entered, for each time it's entered
+    entered: Vec<Instant>,
+}
+
+impl<S> Layer<S> for ProfileLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn enabled(&self, metadata: &Metadata<'_>, _: Context<'_, S>) -> bool {
+        metadata.is_span()
+    }
+
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = ArgsVisitor::default();
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(SpanData {
+            args: visitor.0,
+            entered: Vec::new(),
+        });
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(data) = span.extensions_mut().get_mut::<SpanData>() {
+                data.entered.push(Instant::now());
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let end = Instant::now();
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        let Some(data) = extensions.get_mut::<SpanData>() else {
+            return;
+        };
+        let Some(start) = data.entered.pop() else {
+            return;
+        };
+
+        // The spans of the rules are named after the rule
+        let mut args = data.args.clone();
+        let name = args
+            .remove("name")
+            .unwrap_or_else(|| span.name().to_string());
+        let profiler = &self.0;
+        let event = TraceEvent {
+            name,
+            cat: span.name(),
+            ph: "X",
+            ts: start.duration_since(profiler.start).as_secs_f64() * 1e6,
+            dur: Some(end.duration_since(start).as_secs_f64() * 1e6),
+            pid: 1,
+            tid: profiler.thread_id(),
+            args,
+        };
+        profiler.events.lock().unwrap().push(event);
+    }
+}
+
+/// Collects the fields of a span. Only the primitive values and the paths are kept, so
+/// the profiler doesn't spend time printing big values like the settings.
+#[derive(Default)]
+struct ArgsVisitor(BTreeMap<&'static str, String>);
+
+impl Visit for ArgsVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name(), value.to_string());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name(), value.to_string());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name(), value.to_string());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name(), value.to_string());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name().ends_with("path") {
+            let value = format!("{value:?}");
+            self.0
+                .insert(field.name(), value.trim_matches('"').to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Profiler;
+    use std::path::Path;
+    use tracing::dispatcher;
+
+    #[test]
+    fn records_the_spans() {
+        let profiler = Profiler::new();
+        dispatcher::with_default(&profiler.dispatch(), || {
+            let _parse = tracing::trace_span!("parse", path = ?Path::new("file.js")).entered();
+            let _rule = tracing::trace_span!("rule", name = "noDebugger").entered();
+        });
+
+        let profile: serde_json::Value = serde_json::from_str(&profiler.to_json()).unwrap();
+        let events = profile["traceEvents"].as_array().unwrap();
+        let spans: Vec<_> = events.iter().filter(|event| event["ph"] == "X").collect();
+
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0]["cat"], "parse");
+        assert_eq!(spans[0]["name"], "parse");
+        assert_eq!(spans[0]["args"]["path"], "file.js");
+        assert_eq!(spans[1]["cat"], "rule");
+        assert_eq!(spans[1]["name"], "noDebugger");
+        assert!(spans[1]["args"].is_null());
+        assert!(events.iter().any(|event| event["ph"] == "M"));
+    }
+}
@@ -0,0 +1,101 @@
+//! Serializes diagnostics using the Checkstyle XML format, which is understood
+//! by many CI plugins and code review bots.
+
+use crate::reports::XmlEscape;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity, SourceFile};
+use std::fmt::{Display, Formatter};
+
+/// A Checkstyle report, containing the errors of each file that emitted diagnostics
+pub(crate) struct CheckstyleReport {
+    files: Vec<CheckstyleFile>,
+}
+
+impl CheckstyleReport {
+    /// Creates a report from the diagnostics emitted during a traversal. Diagnostics
+    /// that aren't associated with a file are ignored.
+    pub(crate) fn new(diagnostics: &[Error]) -> Self {
+        let mut files: Vec<CheckstyleFile> = Vec::new();
+
+        for diagnostic in diagnostics {
+            let location = diagnostic.location();
+            let Some(Resource::File(path)) = location.resource else {
+                continue;
+            };
+            let position = location
+                .source_code
+                .zip(location.span)
+                .and_then(|(source_code, span)| {
+                    SourceFile::new(source_code).location(span.start()).ok()
+                })
+                .map(|location| (location.line_number.get(), location.column_number.get()));
+
+            let error = CheckstyleError {
+                position,
+                severity: diagnostic.severity(),
+                message: PrintDescription(diagnostic).to_string(),
+                source: diagnostic
+                    .category()
+                    .map_or("unknown", |category| category.name()),
+            };
+
+            match files.iter_mut().find(|file| file.name == path) {
+                Some(file) => file.errors.push(error),
+                None => files.push(CheckstyleFile {
+                    name: path.to_string(),
+                    errors: vec![error],
+                }),
+            }
+        }
+
+        // Files are processed in parallel, sort them to have a stable report
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+        Self { files }
+    }
+}
+
+struct CheckstyleFile {
+    /// The path of the file
+    name: String,
+    errors: Vec<CheckstyleError>,
+}
+
+struct CheckstyleError {
+    /// The one-indexed line and column where the diagnostic starts
+    position: Option<(usize, usize)>,
+    severity: Severity,
+    message: String,
+    /// The category of the diagnostic
+    source: &'static str,
+}
+
+impl CheckstyleError {
+    fn severity(&self) -> &'static str {
+        match self.severity {
+            Severity::Fatal | Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Information | Severity::Hint => "info",
+        }
+    }
+}
+
+impl Display for CheckstyleReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(f, r#"<checkstyle version="4.3">"#)?;
+        for file in &self.files {
+            writeln!(f, r#"  <file name="{}">"#, XmlEscape(&file.name))?;
+            for error in &file.errors {
+                let (line, column) = error.position.unwrap_or((1, 1));
+                writeln!(
+                    f,
+                    r#"    <error line="{line}" column="{column}" severity="{}" message="{}" source="{}" />"#,
+                    error.severity(),
+                    XmlEscape(&error.message),
+                    XmlEscape(error.source)
+                )?;
+            }
+            writeln!(f, "  </file>")?;
+        }
+        write!(f, "</checkstyle>")
+    }
+}
@@ -0,0 +1,123 @@
+//! Explains the diagnostics of the lint rules in the terminal: why the rule exists and how
+//! to fix its diagnostics, taken from the documentation of the rule embedded in the binary.
+
+use biome_analyze::FixKind;
+use biome_console::{fmt, markup};
+use biome_diagnostics::Error;
+use biome_service::configuration::RuleDocumentation;
+use std::io;
+
+#[derive(Debug)]
+pub(crate) struct RuleExplanation {
+    name: &'static str,
+    /// The paragraphs of the documentation between the summary and the first section
+    rationale: Vec<&'static str>,
+    fix_kind: Option<FixKind>,
+    /// The code of the examples of the section `Valid`
+    valid_examples: Vec<&'static str>,
+}
+
+impl RuleExplanation {
+    /// Returns the explanation of the rule that emitted `diagnostic`, or `None` if the
+    /// diagnostic wasn't emitted by a lint rule
+    pub(crate) fn new(diagnostic: &Error) -> Option<Self> {
+        let rule = diagnostic.category()?.name().strip_prefix("lint/")?;
+        let RuleDocumentation { metadata, .. } = RuleDocumentation::find(rule)?;
+
+        // The lines of the documentation start with the space that follows `///`
+        let mut lines = metadata
+            .docs
+            .lines()
+            .map(|line| line.strip_prefix(' ').unwrap_or(line))
+            .skip_while(|line| line.trim().is_empty())
+            // The summary of the rule is already printed in the header of the diagnostic
+            .skip(1)
+            .peekable();
+
+        let mut rationale = Vec::new();
+        while let Some(line) = lines.next_if(|line| !line.starts_with('#')) {
+            let line = line.trim_end();
+            rationale.push(line.strip_prefix('>').map_or(line, str::trim_start));
+        }
+        trim_blank_lines(&mut rationale);
+
+        let mut valid_examples = Vec::new();
+        let mut in_valid_section = false;
+        let mut in_code_block = false;
+        for line in lines {
+            if line.starts_with("```") {
+                if in_code_block && in_valid_section {
+                    valid_examples.push("");
+                }
+                in_code_block = !in_code_block;
+            } else if in_code_block {
+                if in_valid_section {
+                    valid_examples.push(line.trim_end());
+                }
+            } else if line.starts_with('#') {
+                in_valid_section = line.trim_start_matches('#').trim() == "Valid";
+            }
+        }
+        trim_blank_lines(&mut valid_examples);
+
+        Some(Self {
+            name: metadata.name,
+            rationale,
+            fix_kind: metadata.fix_kind,
+            valid_examples,
+        })
+    }
+}
+
+impl fmt::Display for RuleExplanation {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> io::Result<()> {
+        if !self.rationale.is_empty() {
+            fmt.write_markup(markup! {
+                <Emphasis>"Why "{self.name}" exists"</Emphasis>"\n"
+            })?;
+            write_lines(fmt, &self.rationale, "  ")?;
+            fmt.write_str("\n\n")?;
+        }
+
+        fmt.write_markup(markup! {
+            <Emphasis>"How to fix "{self.name}</Emphasis>"\n\n  "
+        })?;
+        match self.fix_kind {
+            Some(FixKind::Safe) => fmt.write_markup(markup! {
+                "Apply the safe fix of the rule with "<Emphasis>"biome lint --apply"</Emphasis>"."
+            })?,
+            Some(FixKind::Unsafe) => fmt.write_markup(markup! {
+                "Review and apply the unsafe fix of the rule with "<Emphasis>"biome lint --apply-unsafe"</Emphasis>"."
+            })?,
+            None => fmt.write_markup(markup! {
+                "The rule doesn't have a fix, change the code so it follows the rule."
+            })?,
+        }
+        if !self.valid_examples.is_empty() {
+            fmt.write_markup(markup! {"\n\n  Examples of valid code:\n"})?;
+            write_lines(fmt, &self.valid_examples, "    ")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes each line of `lines` on a new line, after `indent`. The blank lines aren't indented.
+fn write_lines(fmt: &mut fmt::Formatter, lines: &[&str], indent: &str) -> io::Result<()> {
+    for line in lines {
+        fmt.write_str("\n")?;
+        if !line.is_empty() {
+            fmt.write_str(indent)?;
+            fmt.write_str(line)?;
+        }
+    }
+    Ok(())
+}
+
+/// Removes the blank lines at the start and at the end of `lines`
+fn trim_blank_lines(lines: &mut Vec<&str>) {
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    let leading = lines.iter().take_while(|line| line.is_empty()).count();
+    lines.drain(..leading);
+}
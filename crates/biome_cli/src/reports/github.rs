@@ -0,0 +1,59 @@
+//! Prints diagnostics as [workflow commands] of GitHub Actions, so that they
+//! are displayed inline in pull requests. The diagnostics of each file are
+//! grouped in the log of the workflow.
+//!
+//! [workflow commands]: https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions
+
+use biome_console::fmt::{Formatter, Termcolor};
+use biome_console::markup;
+use biome_diagnostics::termcolor::NoColor;
+use biome_diagnostics::{Error, PrintGitHubDiagnostic, Resource};
+use std::fmt::Display;
+
+/// A report containing the annotations of each file that emitted diagnostics
+pub(crate) struct GitHubReport<'a> {
+    files: Vec<(&'a str, Vec<&'a Error>)>,
+}
+
+impl<'a> GitHubReport<'a> {
+    /// Creates a report from the diagnostics emitted during a traversal. Diagnostics
+    /// that aren't associated with a file are ignored, GitHub can't display them.
+    pub(crate) fn new(diagnostics: &'a [Error]) -> Self {
+        let mut files: Vec<(&str, Vec<&Error>)> = Vec::new();
+        for diagnostic in diagnostics {
+            let Some(Resource::File(path)) = diagnostic.location().resource else {
+                continue;
+            };
+            match files.iter_mut().find(|(file, _)| *file == path) {
+                Some((_, diagnostics)) => diagnostics.push(diagnostic),
+                None => files.push((path, vec![diagnostic])),
+            }
+        }
+
+        // Files are processed in parallel, sort them to have a stable report
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Self { files }
+    }
+}
+
+impl Display for GitHubReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut lines = Vec::new();
+        for (file, diagnostics) in &self.files {
+            lines.push(format!("::group::{file}"));
+            for diagnostic in diagnostics {
+                let mut buffer = Vec::new();
+                Formatter::new(&mut Termcolor(NoColor::new(&mut buffer)))
+                    .write_markup(markup! {{PrintGitHubDiagnostic::simple(*diagnostic)}})
+                    .map_err(|_| std::fmt::Error)?;
+                // Diagnostics without a location don't have an annotation
+                if !buffer.is_empty() {
+                    lines.push(String::from_utf8_lossy(&buffer).into_owned());
+                }
+            }
+            lines.push(String::from("::endgroup::"));
+        }
+
+        f.write_str(&lines.join("\n"))
+    }
+}
@@ -0,0 +1,101 @@
+//! Serializes diagnostics using the [Code Quality] format of GitLab, so that
+//! they are displayed in merge requests.
+//!
+//! [Code Quality]: https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool
+
+use crate::reports::Fingerprints;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity, SourceFile};
+use serde::Serialize;
+
+/// A Code Quality report, which is a list of issues
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub(crate) struct GitLabReport {
+    issues: Vec<GitLabIssue>,
+}
+
+impl GitLabReport {
+    /// Creates a report from the diagnostics emitted during a traversal. Diagnostics
+    /// that aren't associated with a file are ignored, GitLab can't display them.
+    pub(crate) fn new(diagnostics: &[Error]) -> Self {
+        let mut fingerprints = Fingerprints::default();
+        let mut issues = Vec::with_capacity(diagnostics.len());
+
+        for diagnostic in diagnostics {
+            let location = diagnostic.location();
+            let Some(Resource::File(path)) = location.resource else {
+                continue;
+            };
+            let begin = location
+                .source_code
+                .zip(location.span)
+                .and_then(|(source_code, span)| {
+                    SourceFile::new(source_code).location(span.start()).ok()
+                })
+                .map_or(1, |location| location.line_number.get());
+
+            issues.push(GitLabIssue {
+                description: PrintDescription(diagnostic).to_string(),
+                check_name: diagnostic
+                    .category()
+                    .map_or("unknown", |category| category.name()),
+                fingerprint: fingerprints.next(diagnostic),
+                severity: GitLabSeverity::from(diagnostic.severity()),
+                location: GitLabLocation {
+                    path: path.to_string(),
+                    lines: GitLabLines { begin },
+                },
+            });
+        }
+
+        Self { issues }
+    }
+}
+
+impl std::fmt::Display for GitLabReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let report = serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?;
+        f.write_str(&report)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabIssue {
+    description: String,
+    check_name: &'static str,
+    fingerprint: String,
+    severity: GitLabSeverity,
+    location: GitLabLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum GitLabSeverity {
+    Info,
+    Minor,
+    Major,
+    Critical,
+}
+
+impl From<Severity> for GitLabSeverity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Fatal => Self::Critical,
+            Severity::Error => Self::Major,
+            Severity::Warning => Self::Minor,
+            Severity::Information | Severity::Hint => Self::Info,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabLocation {
+    path: String,
+    lines: GitLabLines,
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabLines {
+    /// The one-indexed line where the issue starts
+    begin: usize,
+}
@@ -0,0 +1,106 @@
+//! Serializes each diagnostic as a JSON object on its own line. The lines are
+//! printed as soon as the diagnostics are emitted, so the output can be
+//! processed while the traversal is still running.
+
+use crate::reports::SuggestedFix;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity, SourceFile};
+use biome_service::configuration::linter::RuleFixKind;
+use biome_text_size::TextSize;
+use serde::Serialize;
+
+/// A single diagnostic, printed as one line of JSON
+#[derive(Debug, Serialize)]
+pub(crate) struct JsonLine<'a> {
+    /// The path of the file that emitted the diagnostic
+    file: Option<&'a str>,
+    /// The category of the diagnostic
+    category: Option<&'static str>,
+    severity: Severity,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<JsonPosition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<JsonPosition>,
+    /// The code fixes suggested by the diagnostic
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<JsonFix>,
+}
+
+impl<'a> JsonLine<'a> {
+    pub(crate) fn new(diagnostic: &'a Error) -> Self {
+        let location = diagnostic.location();
+        let file = match location.resource {
+            Some(Resource::File(path)) => Some(path),
+            _ => None,
+        };
+        let source = location.source_code.map(SourceFile::new);
+        let position = |offset| JsonPosition::new(source.as_ref()?, offset);
+        let fixes = match (&source, location.source_code) {
+            (Some(source), Some(source_code)) => {
+                SuggestedFix::collect(diagnostic, source_code.text)
+                    .into_iter()
+                    .filter_map(|fix| JsonFix::new(source, fix))
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+
+        Self {
+            file,
+            category: diagnostic.category().map(|category| category.name()),
+            severity: diagnostic.severity(),
+            message: PrintDescription(diagnostic).to_string(),
+            start: location.span.and_then(|span| position(span.start())),
+            end: location.span.and_then(|span| position(span.end())),
+            fixes,
+        }
+    }
+}
+
+impl std::fmt::Display for JsonLine<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
+        f.write_str(&line)
+    }
+}
+
+/// A one-indexed position in a file
+#[derive(Debug, Serialize)]
+struct JsonPosition {
+    line: usize,
+    column: usize,
+}
+
+impl JsonPosition {
+    fn new(source: &SourceFile, offset: TextSize) -> Option<Self> {
+        let location = source.location(offset).ok()?;
+        Some(Self {
+            line: location.line_number.get(),
+            column: location.column_number.get(),
+        })
+    }
+}
+
+/// A code fix suggested by a diagnostic, which replaces the text between `start` and `end`
+/// with `text`
+#[derive(Debug, Serialize)]
+struct JsonFix {
+    /// Whether the fix is applied by `--apply`, or only by `--apply-unsafe`
+    kind: RuleFixKind,
+    message: String,
+    start: JsonPosition,
+    end: JsonPosition,
+    text: String,
+}
+
+impl JsonFix {
+    fn new(source: &SourceFile, fix: SuggestedFix) -> Option<Self> {
+        Some(Self {
+            kind: fix.kind(),
+            message: fix.message().to_string(),
+            start: JsonPosition::new(source, fix.range.start())?,
+            end: JsonPosition::new(source, fix.range.end())?,
+            text: fix.text,
+        })
+    }
+}
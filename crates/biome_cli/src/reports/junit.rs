@@ -0,0 +1,117 @@
+//! Serializes diagnostics using the JUnit XML format, so that they can be
+//! displayed by CI systems. Each file is a test suite, and each diagnostic is
+//! a failed test case of its file.
+
+use crate::reports::XmlEscape;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity, SourceFile};
+use std::fmt::{Display, Formatter};
+
+/// A JUnit report, containing a test suite for each file that emitted diagnostics
+pub(crate) struct JunitReport {
+    suites: Vec<JunitTestSuite>,
+}
+
+impl JunitReport {
+    /// Creates a report from the diagnostics emitted during a traversal
+    pub(crate) fn new(diagnostics: &[Error]) -> Self {
+        let mut suites: Vec<JunitTestSuite> = Vec::new();
+
+        for diagnostic in diagnostics {
+            let location = diagnostic.location();
+            let file = match location.resource {
+                Some(Resource::File(path)) => path.to_string(),
+                _ => String::from("biome"),
+            };
+            let position = location
+                .source_code
+                .zip(location.span)
+                .and_then(|(source_code, span)| {
+                    SourceFile::new(source_code).location(span.start()).ok()
+                })
+                .map(|location| (location.line_number.get(), location.column_number.get()));
+
+            let test_case = JunitTestCase {
+                name: diagnostic
+                    .category()
+                    .map_or("unknown", |category| category.name()),
+                position,
+                severity: diagnostic.severity(),
+                message: PrintDescription(diagnostic).to_string(),
+            };
+
+            match suites.iter_mut().find(|suite| suite.name == file) {
+                Some(suite) => suite.test_cases.push(test_case),
+                None => suites.push(JunitTestSuite {
+                    name: file,
+                    test_cases: vec![test_case],
+                }),
+            }
+        }
+
+        // Files are processed in parallel, sort them to have a stable report
+        suites.sort_by(|a, b| a.name.cmp(&b.name));
+        Self { suites }
+    }
+}
+
+struct JunitTestSuite {
+    /// The path of the file
+    name: String,
+    test_cases: Vec<JunitTestCase>,
+}
+
+struct JunitTestCase {
+    /// The category of the diagnostic
+    name: &'static str,
+    /// The one-indexed line and column where the diagnostic starts
+    position: Option<(usize, usize)>,
+    severity: Severity,
+    message: String,
+}
+
+impl Display for JunitReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let tests: usize = self.suites.iter().map(|suite| suite.test_cases.len()).sum();
+
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            f,
+            r#"<testsuites name="Biome" tests="{tests}" failures="{tests}" errors="0">"#
+        )?;
+        for suite in &self.suites {
+            let tests = suite.test_cases.len();
+            writeln!(
+                f,
+                r#"  <testsuite name="{}" tests="{tests}" failures="{tests}" errors="0">"#,
+                XmlEscape(&suite.name)
+            )?;
+            for test_case in &suite.test_cases {
+                write!(
+                    f,
+                    r#"    <testcase name="{}" classname="{}""#,
+                    XmlEscape(test_case.name),
+                    XmlEscape(&suite.name)
+                )?;
+                let location = match test_case.position {
+                    Some((line, column)) => {
+                        write!(f, r#" file="{}" line="{line}""#, XmlEscape(&suite.name))?;
+                        format!("{}:{line}:{column}", suite.name)
+                    }
+                    None => suite.name.clone(),
+                };
+                writeln!(f, ">")?;
+                writeln!(
+                    f,
+                    r#"      <failure type="{}" message="{}">{} {}</failure>"#,
+                    test_case.severity,
+                    XmlEscape(&test_case.message),
+                    XmlEscape(&location),
+                    XmlEscape(&test_case.message)
+                )?;
+                writeln!(f, "    </testcase>")?;
+            }
+            writeln!(f, "  </testsuite>")?;
+        }
+        write!(f, "</testsuites>")
+    }
+}
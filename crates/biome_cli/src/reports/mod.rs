@@ -1,11 +1,31 @@
+pub(crate) mod checkstyle;
+pub(crate) mod explanation;
 pub mod formatter;
+pub(crate) mod github;
+pub(crate) mod gitlab;
+pub(crate) mod json_lines;
+pub(crate) mod junit;
+pub(crate) mod patch;
+pub(crate) mod rule_groups;
+pub(crate) mod rule_timings;
+pub(crate) mod sarif;
+pub(crate) mod summary;
 
 use crate::reports::formatter::{FormatterReportFileDetail, FormatterReportSummary};
-use biome_diagnostics::{Category, Severity};
+use biome_console::fmt::{self, Termcolor};
+use biome_console::{markup, MarkupBuf};
+use biome_diagnostics::termcolor::NoColor;
+use biome_diagnostics::{Category, Error, LogCategory, Resource, Severity, Visit};
+use biome_service::configuration::linter::RuleFixKind;
 use biome_service::WorkspaceError;
+use biome_text_edit::TextEdit;
+use biome_text_size::{TextRange, TextSize};
 use formatter::FormatterReport;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHasher};
 use serde::Serialize;
+use std::fmt::{Display, Formatter, Write};
+use std::hash::{Hash, Hasher};
+use std::io;
 
 #[derive(Debug, Default, Serialize)]
 pub struct Report {
@@ -90,3 +110,189 @@ impl Report {
             .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
     }
 }
+
+/// Computes the fingerprints of the diagnostics printed by a reporter. A fingerprint
+/// allows code scanning tools to track a diagnostic across runs, even when the lines
+/// of the file move.
+#[derive(Debug, Default)]
+pub(crate) struct Fingerprints {
+    occurrences: FxHashMap<u64, usize>,
+}
+
+impl Fingerprints {
+    /// Returns the fingerprint of `diagnostic`, computed from its category, its file and
+    /// the text of its span. Identical diagnostics are told apart by the number of times
+    /// they occurred.
+    pub(crate) fn next(&mut self, diagnostic: &Error) -> String {
+        let location = diagnostic.location();
+        let mut hasher = FxHasher::default();
+        diagnostic
+            .category()
+            .map(|category| category.name())
+            .hash(&mut hasher);
+        if let Some(Resource::File(path)) = location.resource {
+            path.hash(&mut hasher);
+        }
+        if let (Some(source_code), Some(span)) = (location.source_code, location.span) {
+            source_code
+                .text
+                .get(span.start().into()..span.end().into())
+                .hash(&mut hasher);
+        }
+
+        let hash = hasher.finish();
+        let occurrence = self.occurrences.entry(hash).or_insert(0);
+        *occurrence += 1;
+        format!("{hash:016x}:{occurrence}")
+    }
+}
+
+/// A code fix suggested in the advices of a diagnostic
+#[derive(Debug)]
+pub(crate) struct SuggestedFix {
+    /// The message that precedes the fix, e.g. `Safe fix: Use const instead.`
+    pub(crate) description: String,
+    /// The range of the source code replaced by the fix
+    pub(crate) range: TextRange,
+    /// The text that replaces `range`
+    pub(crate) text: String,
+}
+
+impl SuggestedFix {
+    /// Returns the fixes suggested by `diagnostic` for `source`, the code it was emitted for.
+    /// Each fix replaces the smallest part of `source` that it changes, without splitting a word.
+    pub(crate) fn collect(diagnostic: &Error, source: &str) -> Vec<Self> {
+        let mut visitor = FixesVisitor::default();
+        let _ = diagnostic.advices(&mut visitor);
+        visitor
+            .fixes
+            .into_iter()
+            .filter_map(|(description, edit)| {
+                let new_source = edit.new_string(source);
+                if new_source == source {
+                    return None;
+                }
+
+                let prefix = common_prefix(source, &new_source);
+                let suffix = common_suffix(&source[prefix..], &new_source[prefix..]);
+                let range = TextRange::new(
+                    TextSize::try_from(prefix).ok()?,
+                    TextSize::try_from(source.len() - suffix).ok()?,
+                );
+                Some(Self {
+                    description,
+                    range,
+                    text: new_source[prefix..new_source.len() - suffix].to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns whether the fix is applied by `--apply`, or only by `--apply-unsafe`
+    pub(crate) fn kind(&self) -> RuleFixKind {
+        if self.description.starts_with("Safe fix") {
+            RuleFixKind::Safe
+        } else {
+            RuleFixKind::Unsafe
+        }
+    }
+
+    /// Returns the message of the fix, without the kind that precedes it
+    pub(crate) fn message(&self) -> &str {
+        self.description
+            .split_once(": ")
+            .map_or(self.description.as_str(), |(_, message)| message)
+    }
+}
+
+/// Escapes the characters that have a special meaning in XML text and attributes
+pub(crate) struct XmlEscape<'a>(pub(crate) &'a str);
+
+impl Display for XmlEscape<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&apos;")?,
+                '\n' => f.write_str("&#10;")?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the length in bytes of the longest common prefix of `a` and `b`,
+/// without splitting a word
+fn common_prefix(a: &str, b: &str) -> usize {
+    let mut prefix = a
+        .char_indices()
+        .zip(b.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or_else(|| a.len().min(b.len()), |((index, _), _)| index);
+
+    while let Some(last) = a[..prefix].chars().next_back().filter(|c| is_word(*c)) {
+        let splits_word = a[prefix..].starts_with(is_word) || b[prefix..].starts_with(is_word);
+        if !splits_word {
+            break;
+        }
+        prefix -= last.len_utf8();
+    }
+    prefix
+}
+
+/// Returns the length in bytes of the longest common suffix of `a` and `b`,
+/// without splitting a word
+fn common_suffix(a: &str, b: &str) -> usize {
+    let mut suffix: usize = a
+        .chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum();
+
+    while let Some(first) = a[a.len() - suffix..].chars().next().filter(|c| is_word(*c)) {
+        let splits_word =
+            a[..a.len() - suffix].ends_with(is_word) || b[..b.len() - suffix].ends_with(is_word);
+        if !splits_word {
+            break;
+        }
+        suffix -= first.len_utf8();
+    }
+    suffix
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Collects the code fixes suggested in the advices of a diagnostic, along with
+/// the message that precedes each of them
+#[derive(Default)]
+struct FixesVisitor {
+    last_log: Option<String>,
+    fixes: Vec<(String, TextEdit)>,
+}
+
+impl Visit for FixesVisitor {
+    fn record_log(&mut self, _category: LogCategory, text: &dyn fmt::Display) -> io::Result<()> {
+        let mut message = MarkupBuf::default();
+        fmt::Formatter::new(&mut message).write_markup(markup! {{text}})?;
+
+        let mut buffer = Vec::new();
+        fmt::Formatter::new(&mut Termcolor(NoColor::new(&mut buffer)))
+            .write_markup(markup! {{message}})?;
+        self.last_log = Some(String::from_utf8_lossy(&buffer).into_owned());
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        let description = self.last_log.take().unwrap_or_default();
+        self.fixes.push((description, diff.clone()));
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+//! Prints the changes that formatting would make to the files as a unified diff,
+//! which can be applied with `git apply`.
+
+use similar::TextDiff;
+use std::fmt::{Display, Formatter};
+use std::path::{Component, Path, PathBuf};
+
+/// The number of unchanged lines printed around each change
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Default)]
+pub(crate) struct PatchReport {
+    /// The path, the old content and the new content of each file that would change
+    files: Vec<(String, String, String)>,
+}
+
+impl PatchReport {
+    /// Adds the changes from `old` to `new` of the file at `path` to the patch
+    pub(crate) fn push(&mut self, path: &str, old: String, new: String) {
+        self.files.push((patch_path(path), old, new));
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+impl Display for PatchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // The files are processed in parallel, the patch is sorted so it's stable
+        let mut files: Vec<_> = self.files.iter().collect();
+        files.sort_unstable_by(|(left, ..), (right, ..)| left.cmp(right));
+
+        for (path, old, new) in files {
+            writeln!(f, "diff --git a/{path} b/{path}")?;
+            write!(
+                f,
+                "{}",
+                TextDiff::from_lines(old.as_str(), new.as_str())
+                    .unified_diff()
+                    .context_radius(CONTEXT_LINES)
+                    .header(&format!("a/{path}"), &format!("b/{path}"))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the path of the file as git expects it in a patch: with forward slashes,
+/// and without the leading `./`
+fn patch_path(path: &str) -> String {
+    let path: PathBuf = Path::new(path)
+        .components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect();
+    path.to_string_lossy().replace('\\', "/")
+}
@@ -0,0 +1,128 @@
+//! Prints the diagnostics in the terminal grouped by rule: a header for each rule, with its
+//! description, followed by the locations of its diagnostics. When a rule is enabled across
+//! a project, it's easier to triage than the diagnostics printed file by file.
+
+use biome_console::{fmt, markup};
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity, SourceFile};
+use biome_service::configuration::RuleDocumentation;
+use std::collections::BTreeMap;
+use std::io;
+
+#[derive(Debug, Default)]
+pub(crate) struct RuleGroupsReport<'a> {
+    /// The diagnostics of each rule, keyed by the category of its diagnostics
+    groups: BTreeMap<&'static str, Vec<RuleGroupEntry<'a>>>,
+}
+
+#[derive(Debug)]
+struct RuleGroupEntry<'a> {
+    path: Option<&'a str>,
+    /// The one-indexed line and column where the diagnostic starts
+    position: Option<(usize, usize)>,
+    severity: Severity,
+    message: String,
+}
+
+impl<'a> RuleGroupsReport<'a> {
+    /// Creates a report from the diagnostics emitted during a traversal
+    pub(crate) fn new(diagnostics: impl IntoIterator<Item = &'a Error>) -> Self {
+        let mut report = Self::default();
+        for diagnostic in diagnostics {
+            let Some(category) = diagnostic.category() else {
+                continue;
+            };
+            let location = diagnostic.location();
+            let path = match location.resource {
+                Some(Resource::File(path)) => Some(path),
+                _ => None,
+            };
+            let position = match (location.source_code, location.span) {
+                (Some(source_code), Some(span)) => SourceFile::new(source_code)
+                    .location(span.start())
+                    .ok()
+                    .map(|location| (location.line_number.get(), location.column_number.get())),
+                _ => None,
+            };
+            report
+                .groups
+                .entry(category.name())
+                .or_default()
+                .push(RuleGroupEntry {
+                    path,
+                    position,
+                    severity: diagnostic.severity(),
+                    message: PrintDescription(diagnostic).to_string(),
+                });
+        }
+
+        // The files are processed in parallel, the locations are sorted so they're stable
+        for entries in report.groups.values_mut() {
+            entries.sort_by(|a, b| (a.path, a.position).cmp(&(b.path, b.position)));
+        }
+        report
+    }
+
+    /// Whether `diagnostic` was emitted by a lint rule, so it's printed in a group
+    pub(crate) fn is_rule_diagnostic(diagnostic: &Error) -> bool {
+        diagnostic
+            .category()
+            .is_some_and(|category| category.name().starts_with("lint/"))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+}
+
+impl fmt::Display for RuleGroupsReport<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> io::Result<()> {
+        for (index, (category, entries)) in self.groups.iter().enumerate() {
+            if index > 0 {
+                fmt.write_str("\n\n")?;
+            }
+            let count = entries.len();
+            fmt.write_markup(markup! {
+                <Emphasis>{category}</Emphasis>" "<Dim>"("{count}" "{if count == 1 { "diagnostic" } else { "diagnostics" }}")"</Dim>
+            })?;
+            if let Some(description) = description(category) {
+                fmt.write_markup(markup! {"\n  "{description}})?;
+            }
+            fmt.write_str("\n")?;
+
+            for entry in entries {
+                let path = entry.path.unwrap_or("<unknown>");
+                let location = match entry.position {
+                    Some((line, column)) => format!("{path}:{line}:{column}"),
+                    None => path.to_string(),
+                };
+                fmt.write_markup(markup! {"\n  "})?;
+                match entry.severity {
+                    Severity::Fatal | Severity::Error => {
+                        fmt.write_markup(markup! {<Error>"×"</Error>})?
+                    }
+                    Severity::Warning => fmt.write_markup(markup! {<Warn>"!"</Warn>})?,
+                    Severity::Information | Severity::Hint => {
+                        fmt.write_markup(markup! {<Info>"i"</Info>})?
+                    }
+                }
+                fmt.write_markup(markup! {
+                    " "<Emphasis>{location}</Emphasis>" "{entry.message}
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the first line of the documentation of the lint rule that emits the diagnostics
+/// of `category`
+fn description(category: &str) -> Option<String> {
+    let documentation = RuleDocumentation::find(category.strip_prefix("lint/")?)?;
+    documentation
+        .metadata
+        .docs
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
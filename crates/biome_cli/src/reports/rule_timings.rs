@@ -0,0 +1,63 @@
+//! Prints the time spent running each lint rule across the files of a traversal, with
+//! `--show-rule-timings`. The slowest rules are printed first, to find the ones worth disabling
+//! or optimizing.
+
+use biome_console::{fmt, markup};
+use biome_service::workspace::RuleTimingEntry;
+use std::collections::BTreeMap;
+use std::io;
+use std::time::Duration;
+
+#[derive(Debug, Default)]
+pub(crate) struct RuleTimingsReport {
+    /// The time spent running each rule and its number of runs, keyed by the category of the
+    /// diagnostics of the rule
+    rules: BTreeMap<String, (Duration, u64)>,
+}
+
+impl RuleTimingsReport {
+    /// Adds the time spent running the rules on a file
+    pub(crate) fn record(&mut self, entries: Vec<RuleTimingEntry>) {
+        for entry in entries {
+            let (duration, runs) = self
+                .rules
+                .entry(format!("lint/{}/{}", entry.group, entry.rule))
+                .or_default();
+            *duration += Duration::from_nanos(entry.nanos);
+            *runs += entry.runs;
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the time spent running the rule whose diagnostics have the category `category`
+    pub(crate) fn duration_of(&self, category: &str) -> Option<Duration> {
+        self.rules.get(category).map(|(duration, _)| *duration)
+    }
+}
+
+impl fmt::Display for RuleTimingsReport {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> io::Result<()> {
+        let mut rules: Vec<_> = self.rules.iter().collect();
+        rules.sort_by(|(_, (left, _)), (_, (right, _))| right.cmp(left));
+        let total: Duration = rules.iter().map(|(_, (duration, _))| *duration).sum();
+
+        fmt.write_markup(markup! {
+            <Emphasis>"Rule timings"</Emphasis>" "<Dim>"(total "{format_duration(total)}")"</Dim>"\n"
+        })?;
+        for (category, (duration, runs)) in rules {
+            let duration = format!("{:>10}", format_duration(*duration));
+            let runs = format!("{runs:>8}");
+            fmt.write_markup(markup! {
+                "\n  "{duration}"  "<Dim>{runs}" runs"</Dim>"  "<Emphasis>{category}</Emphasis>
+            })?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
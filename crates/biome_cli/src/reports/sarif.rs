@@ -0,0 +1,283 @@
+//! Serializes diagnostics using the [SARIF] format, so that they can be
+//! consumed by code scanning tools.
+//!
+//! [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use crate::reports::{Fingerprints, SuggestedFix};
+use crate::VERSION;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity, SourceCode, SourceFile};
+use biome_text_size::TextSize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// A SARIF log containing a single run of Biome
+#[derive(Debug, Serialize)]
+pub(crate) struct SarifReport {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun; 1],
+}
+
+impl SarifReport {
+    /// Creates a report from the diagnostics emitted during a traversal
+    pub(crate) fn new(diagnostics: &[Error]) -> Self {
+        let mut rules = Vec::new();
+        let mut rule_indexes = FxHashMap::default();
+        let mut fingerprints = Fingerprints::default();
+        let mut results = Vec::with_capacity(diagnostics.len());
+
+        for diagnostic in diagnostics {
+            let rule_index = diagnostic.category().map(|category| {
+                *rule_indexes.entry(category.name()).or_insert_with(|| {
+                    rules.push(SarifRule {
+                        id: category.name(),
+                        name: category.name().rsplit('/').next().unwrap_or_default(),
+                        help_uri: category.link(),
+                    });
+                    rules.len() - 1
+                })
+            });
+
+            let location = diagnostic.location();
+            let path = match location.resource {
+                Some(Resource::File(path)) => Some(path),
+                _ => None,
+            };
+            let source = location.source_code.map(SourceFile::new);
+            let region = match (&source, location.span) {
+                (Some(source), Some(span)) => SarifRegion::new(source, span.start(), span.end()),
+                _ => None,
+            };
+
+            let fixes = match (path, location.source_code) {
+                (Some(path), Some(source_code)) => {
+                    SuggestedFix::collect(diagnostic, source_code.text)
+                        .into_iter()
+                        .filter_map(|fix| SarifFix::new(path, source_code.text, fix))
+                        .collect()
+                }
+                _ => Vec::new(),
+            };
+
+            results.push(SarifResult {
+                rule_id: diagnostic.category().map(|category| category.name()),
+                rule_index,
+                level: SarifLevel::from(diagnostic.severity()),
+                message: SarifMessage {
+                    text: PrintDescription(diagnostic).to_string(),
+                },
+                locations: path
+                    .map(|path| {
+                        vec![SarifLocation {
+                            physical_location: SarifPhysicalLocation {
+                                artifact_location: SarifArtifactLocation {
+                                    uri: path.to_string(),
+                                },
+                                region,
+                            },
+                        }]
+                    })
+                    .unwrap_or_default(),
+                partial_fingerprints: SarifFingerprints {
+                    biome: fingerprints.next(diagnostic),
+                },
+                fixes,
+            });
+        }
+
+        Self {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: [SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "Biome",
+                        version: VERSION,
+                        information_uri: "https://biomejs.dev",
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+}
+
+impl std::fmt::Display for SarifReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let report = serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?;
+        f.write_str(&report)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: &'static str,
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: SarifLevel,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    partial_fingerprints: SarifFingerprints,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum SarifLevel {
+    Error,
+    Warning,
+    Note,
+}
+
+impl From<Severity> for SarifLevel {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Fatal | Severity::Error => Self::Error,
+            Severity::Warning => Self::Warning,
+            Severity::Information | Severity::Hint => Self::Note,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+/// A range of text, with one-indexed lines and columns
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl SarifRegion {
+    fn new(source: &SourceFile, start: TextSize, end: TextSize) -> Option<Self> {
+        let start = source.location(start).ok()?;
+        let end = source.location(end).ok()?;
+        Some(Self {
+            start_line: start.line_number.get(),
+            start_column: start.column_number.get(),
+            end_line: end.line_number.get(),
+            end_column: end.column_number.get(),
+        })
+    }
+}
+
+/// Fingerprints that allow code scanning tools to track a result across runs
+#[derive(Debug, Serialize)]
+struct SarifFingerprints {
+    #[serde(rename = "biome/v1")]
+    biome: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifMessage,
+    artifact_changes: [SarifArtifactChange; 1],
+}
+
+impl SarifFix {
+    /// Creates a fix that replaces the part of `source` changed by `fix`
+    fn new(path: &str, source: &str, fix: SuggestedFix) -> Option<Self> {
+        let source_file = SourceFile::new(SourceCode {
+            text: source,
+            line_starts: None,
+        });
+        let deleted_region = SarifRegion::new(&source_file, fix.range.start(), fix.range.end())?;
+
+        Some(Self {
+            description: SarifMessage {
+                text: fix.description,
+            },
+            artifact_changes: [SarifArtifactChange {
+                artifact_location: SarifArtifactLocation {
+                    uri: path.to_string(),
+                },
+                replacements: [SarifReplacement {
+                    deleted_region,
+                    inserted_content: SarifInsertedContent { text: fix.text },
+                }],
+            }],
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: [SarifReplacement; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifInsertedContent,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifInsertedContent {
+    text: String,
+}
@@ -0,0 +1,179 @@
+//! Prints statistics about the diagnostics emitted during a traversal, instead
+//! of the diagnostics themselves: how many diagnostics each rule emitted, how
+//! many of them can be fixed and the time spent running the rule, along with a
+//! breakdown by file extension.
+
+use crate::reports::rule_timings::{format_duration, RuleTimingsReport};
+use biome_diagnostics::{Error, Resource, Visit};
+use biome_text_edit::TextEdit;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Default)]
+pub(crate) struct SummaryReport {
+    /// The statistics of each rule, keyed by the category of its diagnostics
+    rules: FxHashMap<&'static str, SummaryStats>,
+    /// The time spent running each rule, keyed by the category of its diagnostics
+    durations: FxHashMap<&'static str, Duration>,
+    /// The statistics of each file extension
+    extensions: FxHashMap<String, SummaryStats>,
+    /// The files that emitted diagnostics, for each file extension
+    files: FxHashMap<String, FxHashSet<String>>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct SummaryStats {
+    diagnostics: usize,
+    fixable: usize,
+}
+
+impl SummaryStats {
+    fn record(&mut self, fixable: bool) {
+        self.diagnostics += 1;
+        if fixable {
+            self.fixable += 1;
+        }
+    }
+}
+
+impl SummaryReport {
+    /// Creates a report from the diagnostics emitted during a traversal, and the time spent
+    /// running the rules
+    pub(crate) fn new(diagnostics: &[Error], rule_timings: &RuleTimingsReport) -> Self {
+        let mut report = Self::default();
+        for diagnostic in diagnostics {
+            let mut visitor = FixableVisitor::default();
+            let _ = diagnostic.advices(&mut visitor);
+            let fixable = visitor.fixable;
+
+            let category = diagnostic
+                .category()
+                .map_or("unknown", |category| category.name());
+            report.rules.entry(category).or_default().record(fixable);
+            if let Some(duration) = rule_timings.duration_of(category) {
+                report.durations.insert(category, duration);
+            }
+
+            if let Some(Resource::File(path)) = diagnostic.location().resource {
+                let extension = Path::new(path).extension().map_or_else(
+                    || String::from("(none)"),
+                    |extension| extension.to_string_lossy().into_owned(),
+                );
+                report
+                    .files
+                    .entry(extension.clone())
+                    .or_default()
+                    .insert(path.to_string());
+                report
+                    .extensions
+                    .entry(extension)
+                    .or_default()
+                    .record(fixable);
+            }
+        }
+        report
+    }
+}
+
+impl Display for SummaryReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.rules.is_empty() {
+            return f.write_str("No diagnostics.");
+        }
+
+        // The rules that emit the most diagnostics come first
+        let mut rules: Vec<_> = self.rules.iter().collect();
+        rules.sort_by(|(a_name, a), (b_name, b)| {
+            b.diagnostics.cmp(&a.diagnostics).then(a_name.cmp(b_name))
+        });
+        let rows: Vec<_> = rules
+            .into_iter()
+            .map(|(rule, stats)| {
+                vec![
+                    (*rule).to_string(),
+                    stats.diagnostics.to_string(),
+                    stats.fixable.to_string(),
+                    // The parse errors, for instance, aren't emitted by a rule
+                    self.durations
+                        .get(rule)
+                        .map_or_else(|| String::from("-"), |duration| format_duration(*duration)),
+                ]
+            })
+            .collect();
+        write_table(f, &["Rule", "Diagnostics", "Fixable", "Time"], &rows)?;
+
+        if !self.extensions.is_empty() {
+            f.write_str("\n\n")?;
+            let mut extensions: Vec<_> = self.extensions.iter().collect();
+            extensions.sort_by(|(a_name, a), (b_name, b)| {
+                b.diagnostics.cmp(&a.diagnostics).then(a_name.cmp(b_name))
+            });
+            let rows: Vec<_> = extensions
+                .into_iter()
+                .map(|(extension, stats)| {
+                    vec![
+                        extension.clone(),
+                        self.files
+                            .get(extension)
+                            .map_or(0, |files| files.len())
+                            .to_string(),
+                        stats.diagnostics.to_string(),
+                        stats.fixable.to_string(),
+                    ]
+                })
+                .collect();
+            write_table(f, &["Extension", "Files", "Diagnostics", "Fixable"], &rows)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a table, where the first column is aligned to the left and the others,
+/// which contain numbers, are aligned to the right
+fn write_table(f: &mut Formatter<'_>, header: &[&str], rows: &[Vec<String>]) -> std::fmt::Result {
+    let widths: Vec<_> = header
+        .iter()
+        .enumerate()
+        .map(|(index, title)| {
+            rows.iter()
+                .map(|row| row[index].len())
+                .chain([title.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let header: Vec<_> = header.iter().map(|title| (*title).to_string()).collect();
+    let separator: Vec<_> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    let lines = [&header, &separator].into_iter().chain(rows);
+    for (line_index, cells) in lines.enumerate() {
+        if line_index > 0 {
+            writeln!(f)?;
+        }
+        for (index, (cell, width)) in cells.iter().zip(&widths).enumerate() {
+            if index == 0 {
+                write!(f, "{cell:<width$}")?;
+            } else {
+                write!(f, "  {cell:>width$}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Tells whether the advices of a diagnostic contain a code fix
+#[derive(Default)]
+struct FixableVisitor {
+    fixable: bool,
+}
+
+impl Visit for FixableVisitor {
+    fn record_diff(&mut self, _diff: &TextEdit) -> io::Result<()> {
+        self.fixable = true;
+        Ok(())
+    }
+}
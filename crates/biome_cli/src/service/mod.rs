@@ -7,9 +7,11 @@
 use std::{
     any::type_name,
     borrow::Cow,
+    convert::Infallible,
     io,
     ops::Deref,
     panic::RefUnwindSafe,
+    path::PathBuf,
     str::{from_utf8, FromStr},
     sync::Arc,
     time::Duration,
@@ -32,6 +34,7 @@ use tokio::{
         AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt,
         BufReader, BufWriter,
     },
+    net::TcpListener,
     runtime::Runtime,
     sync::{
         mpsc::{channel, Receiver, Sender},
@@ -44,16 +47,78 @@ use tokio::{
 mod windows;
 #[cfg(windows)]
 pub(crate) use self::windows::{
-    ensure_daemon, enumerate_pipes, open_socket, print_socket, run_daemon,
+    ensure_daemon, enumerate_pipes, enumerate_stale_sockets, open_socket, print_socket, run_daemon,
+    run_pipe_proxy,
 };
 
 #[cfg(unix)]
 mod unix;
 #[cfg(unix)]
 pub(crate) use self::unix::{
-    ensure_daemon, enumerate_pipes, open_socket, print_socket, run_daemon,
+    ensure_daemon, enumerate_pipes, enumerate_stale_sockets, open_socket, print_socket, run_daemon,
+    run_pipe_proxy,
 };
 
+/// Start listening on the provided TCP address and forward each accepted
+/// connection to the daemon, spawning it if needed
+pub(crate) async fn run_tcp_proxy(
+    address: String,
+    config_path: Option<PathBuf>,
+) -> io::Result<Infallible> {
+    let listener = TcpListener::bind(&address).await?;
+    tracing::info!(
+        "Listening for LSP connections on {}",
+        listener.local_addr()?
+    );
+
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        tracing::info!("Accepted LSP connection from {peer}");
+        let (read, write) = stream.into_split();
+        spawn_proxy_connection(read, write, config_path.clone());
+    }
+}
+
+/// Spawns a task running [proxy_connection], and logs the error that ends the connection
+pub(crate) fn spawn_proxy_connection<R, W>(read: R, write: W, config_path: Option<PathBuf>)
+where
+    R: AsyncRead + Unpin + Send + 'static,
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        if let Err(error) = proxy_connection(read, write, config_path).await {
+            tracing::error!("LSP proxy connection failed: {error}");
+        }
+    });
+}
+
+/// Forward the messages received on `read` to the daemon, and the messages
+/// sent by the daemon to `write`, until one of the two sides closes the
+/// connection
+async fn proxy_connection<R, W>(
+    mut read: R,
+    mut write: W,
+    config_path: Option<PathBuf>,
+) -> io::Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    ensure_daemon(true, config_path).await?;
+
+    let Some((mut socket_read, mut socket_write)) = open_socket().await? else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotConnected,
+            "the Biome daemon isn't running",
+        ));
+    };
+
+    tokio::select! {
+        res = tokio::io::copy(&mut read, &mut socket_write) => res.map(drop),
+        res = tokio::io::copy(&mut socket_read, &mut write) => res.map(drop),
+    }
+}
+
 /// Tries to open a connection to a running daemon instance, returning a
 /// [WorkspaceTransport] instance if the socket is currently active
 pub fn open_transport(runtime: Runtime) -> io::Result<Option<impl WorkspaceTransport>> {
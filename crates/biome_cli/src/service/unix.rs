@@ -2,6 +2,7 @@ use std::{
     convert::Infallible,
     env, fs,
     io::{self, ErrorKind},
+    os::unix::fs::FileTypeExt,
     path::PathBuf,
     time::Duration,
 };
@@ -41,6 +42,26 @@ pub(crate) fn enumerate_pipes() -> io::Result<impl Iterator<Item = String>> {
     })
 }
 
+/// Returns the socket files left behind by the daemons that aren't running anymore
+pub(crate) fn enumerate_stale_sockets() -> io::Result<Vec<PathBuf>> {
+    let mut sockets: Vec<_> = fs::read_dir(env::temp_dir())?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if !path.file_name()?.to_str()?.starts_with("biome-socket") {
+                return None;
+            }
+            // A running daemon accepts the connection. The daemon doesn't stop when
+            // the connection is closed, because the client never initializes it.
+            match std::os::unix::net::UnixStream::connect(&path) {
+                Ok(_) => None,
+                Err(_) => Some(path),
+            }
+        })
+        .collect();
+    sockets.sort();
+    Ok(sockets)
+}
+
 /// Try to connect to the global socket and wait for the connection to become ready
 async fn try_connect() -> io::Result<UnixStream> {
     let socket_name = get_socket_name();
@@ -211,3 +232,37 @@ async fn run_server(connection: ServerConnection, stream: UnixStream) {
     let (read, write) = stream.into_split();
     connection.accept(read, write).await;
 }
+
+/// Start listening on the Unix domain socket at `name` and forward each
+/// accepted connection to the daemon
+pub(crate) async fn run_pipe_proxy(
+    name: String,
+    config_path: Option<PathBuf>,
+) -> io::Result<Infallible> {
+    let path = PathBuf::from(name);
+
+    // Try to remove the socket file if it already exists, but never another kind of file
+    match fs::symlink_metadata(&path) {
+        Ok(metadata) if metadata.file_type().is_socket() => {
+            info!("Remove socket file {}", path.display());
+            fs::remove_file(&path)?;
+        }
+        Ok(_) => {
+            return Err(io::Error::new(
+                ErrorKind::AlreadyExists,
+                format!("{} already exists and isn't a socket", path.display()),
+            ));
+        }
+        Err(error) if error.kind() == ErrorKind::NotFound => {}
+        Err(error) => return Err(error),
+    }
+
+    let listener = UnixListener::bind(&path)?;
+    info!("Listening for LSP connections on {}", path.display());
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let (read, write) = stream.into_split();
+        super::spawn_proxy_connection(read, write, config_path.clone());
+    }
+}
@@ -44,6 +44,12 @@ pub(crate) fn enumerate_pipes() -> io::Result<impl Iterator<Item = String>> {
     })
 }
 
+/// The named pipes are removed by the system when the daemon stops, so there are
+/// never stale pipes
+pub(crate) fn enumerate_stale_sockets() -> io::Result<Vec<PathBuf>> {
+    Ok(Vec::new())
+}
+
 /// Error code from the Win32 API
 const ERROR_PIPE_BUSY: i32 = 231;
 
@@ -223,6 +229,37 @@ pub(crate) async fn run_daemon(
     }
 }
 
+/// Start listening on the named pipe `name` and forward each accepted
+/// connection to the daemon. The `\\.\pipe\` prefix is added to the name
+/// if it's missing
+pub(crate) async fn run_pipe_proxy(
+    name: String,
+    config_path: Option<PathBuf>,
+) -> io::Result<Infallible> {
+    let name = if name.starts_with(r"\\.\pipe\") {
+        name
+    } else {
+        format!(r"\\.\pipe\{name}")
+    };
+
+    let mut prev_server = ServerOptions::new()
+        .first_pipe_instance(true)
+        .create(&name)?;
+
+    loop {
+        prev_server.connect().await?;
+        let mut next_server = ServerOptions::new().create(&name)?;
+        swap(&mut prev_server, &mut next_server);
+
+        let inner = Arc::new(next_server);
+        let read = ServerReadHalf {
+            inner: inner.clone(),
+        };
+        let write = ServerWriteHalf { inner };
+        super::spawn_proxy_connection(read, write, config_path.clone());
+    }
+}
+
 /// Async task driving a single client connection
 async fn run_server(connection: ServerConnection, stream: NamedPipeServer) {
     let inner = Arc::new(stream);
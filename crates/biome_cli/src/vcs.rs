@@ -3,29 +3,39 @@ use crate::diagnostics::DisabledVcs;
 use crate::{CliDiagnostic, CliSession};
 use biome_console::{markup, ConsoleExt};
 use biome_deserialize::StringSet;
-use biome_diagnostics::PrintDiagnostic;
-use biome_service::configuration::vcs::VcsConfiguration;
-use biome_service::configuration::FilesConfiguration;
+use biome_diagnostics::{Error, PrintDiagnostic};
+use biome_fs::FileSystem;
+use biome_service::configuration::vcs::{VcsClientKind, VcsConfiguration};
+use biome_service::configuration::{
+    FilesConfiguration, IgnoreFiles, VCS_EXCLUDE_FILE_NAME, VCS_IGNORE_FILE_NAME,
+};
 use biome_service::{Configuration, WorkspaceError};
-use std::path::PathBuf;
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use rustc_hash::FxHashMap;
+use std::ffi::OsString;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
 
 /// This function will check if the configuration is set to use the VCS integration and try to
 /// read the ignored files.
+///
+/// When `ignore_files` is provided and the VCS uses `.gitignore` files, they are applied by
+/// `ignore_files` with the semantics of git. Otherwise, the patterns of the root ignore file
+/// are added to `files.ignore`.
 pub(crate) fn store_path_to_ignore_from_vcs(
     session: &mut CliSession,
     configuration: &mut Configuration,
     vcs_base_path: Option<PathBuf>,
     cli_options: &CliOptions,
+    ignore_files: Option<&mut IgnoreFiles>,
 ) -> Result<(), CliDiagnostic> {
     let Some(vcs) = &configuration.vcs else {
         return Ok(());
     };
     if vcs.is_enabled() {
-        let vcs_base_path = match (vcs_base_path, &vcs.root) {
-            (Some(vcs_base_path), Some(root)) => vcs_base_path.join(root),
-            (None, Some(root)) => PathBuf::from(root),
-            (Some(vcs_base_path), None) => vcs_base_path,
-            (None, None) => {
+        let vcs_base_path = match resolve_vcs_root(vcs_base_path, vcs) {
+            Some(vcs_base_path) => vcs_base_path,
+            None => {
                 let console = &mut session.app.console;
                 let diagnostic = DisabledVcs {};
                 console.error(markup! {
@@ -35,6 +45,14 @@ pub(crate) fn store_path_to_ignore_from_vcs(
             }
         };
 
+        if let Some(ignore_files) = ignore_files {
+            let file_system = &**session.app.fs;
+            let client_kind = resolve_client_kind(file_system, &vcs_base_path, vcs);
+            if !vcs.ignore_file_disabled() && client_kind != VcsClientKind::Mercurial {
+                return use_git_ignore_files(file_system, vcs_base_path, ignore_files);
+            }
+        }
+
         let files_to_ignore = read_vcs_ignore_file(session, vcs_base_path, vcs)?;
 
         if !files_to_ignore.is_empty() {
@@ -48,6 +66,313 @@ pub(crate) fn store_path_to_ignore_from_vcs(
     Ok(())
 }
 
+/// Applies the `.gitignore` files of the repository to `ignore_files`: the root ignore file,
+/// found in `vcs_root` or in one of its parents, the ignore files of its subdirectories, and
+/// `.git/info/exclude`.
+fn use_git_ignore_files(
+    file_system: &dyn FileSystem,
+    vcs_root: PathBuf,
+    ignore_files: &mut IgnoreFiles,
+) -> Result<(), CliDiagnostic> {
+    let root_ignore_file = file_system
+        .auto_search(vcs_root.clone(), VCS_IGNORE_FILE_NAME, false)
+        .map_err(WorkspaceError::from)?;
+    // In a worktree or in a submodule, `.git` is a file and there's no exclude file to read
+    let exclude_file = file_system
+        .auto_search(vcs_root.clone(), VCS_EXCLUDE_FILE_NAME, false)
+        .ok()
+        .flatten();
+    let vcs_directory = root_ignore_file
+        .as_ref()
+        .map_or(vcs_root, |file| file.directory_path.clone());
+
+    ignore_files.use_vcs_ignore_files(
+        &vcs_directory,
+        root_ignore_file.as_ref().map(|file| file.content.as_str()),
+        exclude_file
+            .as_ref()
+            .map(|file| (file.directory_path.as_path(), file.content.as_str())),
+    )?;
+    Ok(())
+}
+
+/// Returns the folder of the VCS: `vcs.root` resolved from `vcs_base_path`, or `vcs_base_path`
+/// itself when `vcs.root` isn't set.
+fn resolve_vcs_root(vcs_base_path: Option<PathBuf>, vcs: &VcsConfiguration) -> Option<PathBuf> {
+    match (vcs_base_path, &vcs.root) {
+        (Some(vcs_base_path), Some(root)) => Some(vcs_base_path.join(root)),
+        (None, Some(root)) => Some(PathBuf::from(root)),
+        (Some(vcs_base_path), None) => Some(vcs_base_path),
+        (None, None) => None,
+    }
+}
+
+/// Returns the kind of VCS client: `vcs.clientKind`, or the client whose repository folder,
+/// e.g. `.hg`, is found in `vcs_root` or in one of its parents. It defaults to git.
+fn resolve_client_kind(
+    fs: &dyn FileSystem,
+    vcs_root: &Path,
+    vcs: &VcsConfiguration,
+) -> VcsClientKind {
+    vcs.client_kind.clone().unwrap_or_else(|| {
+        VcsClientKind::DETECTION_ORDER
+            .into_iter()
+            .find(|client_kind| {
+                vcs_root
+                    .ancestors()
+                    .any(|folder| fs.path_exists(&folder.join(client_kind.repository_folder())))
+            })
+            .unwrap_or_default()
+    })
+}
+
+/// Returns the files changed compared to the git reference `since`, or compared to
+/// `vcs.defaultBranch` when `since` isn't provided.
+///
+/// The returned paths are relative to the working directory when possible.
+pub(crate) fn get_changed_files(
+    session: &mut CliSession,
+    configuration: &Configuration,
+    vcs_base_path: Option<PathBuf>,
+    since: Option<&str>,
+) -> Result<Vec<OsString>, CliDiagnostic> {
+    let vcs = configuration.vcs.clone().unwrap_or_default();
+    let base = resolve_base(&vcs, since, "--changed")?;
+
+    let vcs_root = resolve_vcs_root(vcs_base_path, &vcs).unwrap_or_default();
+    let file_system = &session.app.fs;
+    let client = resolve_client_kind(&**file_system, &vcs_root, &vcs).client();
+    let working_directory = file_system.working_directory();
+    let changed_files = file_system
+        .get_changed_files(client, &vcs_root, base)
+        .map_err(CliDiagnostic::io_error)?
+        .into_iter()
+        .map(|file| {
+            resolve_vcs_file(&vcs_root, working_directory.as_deref(), &file).into_os_string()
+        })
+        .collect::<Vec<_>>();
+
+    if changed_files.is_empty() {
+        session.app.console.log(markup! {
+            "No files have changed compared to "<Emphasis>{base}</Emphasis>"."
+        });
+    }
+
+    Ok(changed_files)
+}
+
+/// Returns the lines added or modified compared to the git reference `since`, or compared
+/// to `vcs.defaultBranch` when `since` isn't provided.
+pub(crate) fn get_changed_lines(
+    session: &mut CliSession,
+    configuration: &Configuration,
+    vcs_base_path: Option<PathBuf>,
+    since: Option<&str>,
+) -> Result<ChangedLines, CliDiagnostic> {
+    let vcs = configuration.vcs.clone().unwrap_or_default();
+    let base = resolve_base(&vcs, since, "--changed-lines-only")?;
+
+    let vcs_root = resolve_vcs_root(vcs_base_path, &vcs).unwrap_or_default();
+    let file_system = &session.app.fs;
+    let client = resolve_client_kind(&**file_system, &vcs_root, &vcs).client();
+    let working_directory = file_system.working_directory();
+    let diff = file_system
+        .get_diff(client, &vcs_root, base)
+        .map_err(CliDiagnostic::io_error)?;
+    let changed_lines = ChangedLines::from_diff(&diff, |file| {
+        resolve_vcs_file(&vcs_root, working_directory.as_deref(), file)
+    });
+
+    if changed_lines.is_empty() {
+        session.app.console.log(markup! {
+            "No lines have changed compared to "<Emphasis>{base}</Emphasis>"."
+        });
+    }
+
+    Ok(changed_lines)
+}
+
+/// Returns the git reference to compare against: `since`, or `vcs.defaultBranch` when
+/// `since` isn't provided. `argument` is the argument that requires the reference.
+fn resolve_base<'a>(
+    vcs: &'a VcsConfiguration,
+    since: Option<&'a str>,
+    argument: &str,
+) -> Result<&'a str, CliDiagnostic> {
+    since.or(vcs.default_branch.as_deref()).ok_or_else(|| {
+        CliDiagnostic::incompatible_end_configuration(format!(
+            "The argument {argument} was used, but Biome couldn't determine the git reference to compare against. Either set vcs.defaultBranch in the configuration file, or use the argument --since.",
+        ))
+    })
+}
+
+/// The lines, starting from 1, added or modified in each file compared to a git reference
+#[derive(Debug, Default)]
+pub(crate) struct ChangedLines {
+    files: FxHashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+}
+
+impl ChangedLines {
+    /// Reads the changed lines from a diff without context lines. `resolve_file` maps the
+    /// paths of the diff to the paths of the processed files.
+    fn from_diff(diff: &str, resolve_file: impl Fn(&str) -> PathBuf) -> Self {
+        let mut files: FxHashMap<PathBuf, Vec<RangeInclusive<usize>>> = FxHashMap::default();
+        let mut current_file = None;
+        for line in diff.lines() {
+            if let Some(file) = line.strip_prefix("+++ ") {
+                current_file = (file != "/dev/null").then(|| resolve_file(file));
+            } else if let Some(hunk) = line.strip_prefix("@@ ") {
+                let (Some(file), Some(lines)) = (&current_file, parse_hunk(hunk)) else {
+                    continue;
+                };
+                files.entry(file.clone()).or_default().push(lines);
+            }
+        }
+        Self { files }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// The files that have changed lines
+    pub(crate) fn paths(&self) -> Vec<OsString> {
+        let mut paths: Vec<_> = self
+            .files
+            .keys()
+            .map(|path| path.clone().into_os_string())
+            .collect();
+        paths.sort_unstable();
+        paths
+    }
+
+    /// Whether one of the `lines` of the file at `path` has changed
+    fn intersects(&self, path: &Path, lines: RangeInclusive<usize>) -> bool {
+        self.files.get(path).map_or(false, |changed_lines| {
+            changed_lines
+                .iter()
+                .any(|changed| changed.start() <= lines.end() && lines.start() <= changed.end())
+        })
+    }
+
+    /// Whether `diagnostic` intersects the changed lines of the file at `path`, whose
+    /// content is `content`. A diagnostic without a range intersects them when the file
+    /// has changed lines.
+    pub(crate) fn contains_diagnostic(
+        &self,
+        path: &Path,
+        content: &str,
+        diagnostic: &Error,
+    ) -> bool {
+        match diagnostic.location().span {
+            Some(span) => {
+                let start = usize::from(span.start());
+                // The end of the range is exclusive
+                let end = usize::from(span.end()).saturating_sub(1).max(start);
+                self.intersects(path, line_at(content, start)..=line_at(content, end))
+            }
+            None => self.files.contains_key(path),
+        }
+    }
+
+    /// Whether the changes from `old` to `new` intersect the changed lines of the file at `path`
+    pub(crate) fn contains_diff(&self, path: &Path, old: &str, new: &str) -> bool {
+        let diff = TextEdit::from_unicode_words(old, new);
+        // The line of `old` where the current operation starts
+        let mut line = 1;
+        for op in diff.iter() {
+            match op {
+                CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                    line += count_line_breaks(diff.get_text(*range));
+                }
+                // The compressed lines are surrounded by the line breaks of the equal
+                // operations before and after them
+                CompressedOp::EqualLines { line_count } => {
+                    line += line_count.get() as usize + 1;
+                }
+                CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                    let end = line + count_line_breaks(diff.get_text(*range));
+                    if self.intersects(path, line..=end) {
+                        return true;
+                    }
+                    line = end;
+                }
+                CompressedOp::DiffOp(DiffOp::Insert { .. }) => {
+                    if self.intersects(path, line..=line) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Returns the line, starting from 1, of the byte at `offset` in `content`
+fn line_at(content: &str, offset: usize) -> usize {
+    count_line_breaks(content.get(..offset).unwrap_or(content)) + 1
+}
+
+fn count_line_breaks(text: &str) -> usize {
+    text.matches('\n').count()
+}
+
+/// Returns the lines of the new file in a hunk header like `-1,2 +3,4 @@`, or [None]
+/// when the hunk only removes lines
+fn parse_hunk(hunk: &str) -> Option<RangeInclusive<usize>> {
+    let new_lines = hunk.split(' ').find_map(|part| part.strip_prefix('+'))?;
+    let (start, count) = match new_lines.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>().ok()?, count.parse::<usize>().ok()?),
+        None => (new_lines.parse::<usize>().ok()?, 1),
+    };
+    (count > 0).then_some(start..=start + count - 1)
+}
+
+/// Returns the files staged in the git index, along with their staged content.
+///
+/// The returned paths are relative to the working directory when possible.
+pub(crate) fn get_staged_files(
+    session: &mut CliSession,
+    configuration: &Configuration,
+    vcs_base_path: Option<PathBuf>,
+) -> Result<FxHashMap<PathBuf, String>, CliDiagnostic> {
+    let vcs = configuration.vcs.clone().unwrap_or_default();
+    let vcs_root = resolve_vcs_root(vcs_base_path, &vcs).unwrap_or_default();
+    let file_system = &session.app.fs;
+    let client = resolve_client_kind(&**file_system, &vcs_root, &vcs).client();
+    let working_directory = file_system.working_directory();
+    let mut staged_files = FxHashMap::default();
+    for file in file_system
+        .get_staged_files(client, &vcs_root)
+        .map_err(CliDiagnostic::io_error)?
+    {
+        let content = file_system
+            .read_staged_file(client, &vcs_root, &file)
+            .map_err(CliDiagnostic::io_error)?;
+        let path = resolve_vcs_file(&vcs_root, working_directory.as_deref(), &file);
+        staged_files.insert(path, content);
+    }
+
+    if staged_files.is_empty() {
+        session.app.console.log(markup! {
+            "No files are staged."
+        });
+    }
+
+    Ok(staged_files)
+}
+
+/// Resolves the path of a file returned by the VCS, relative to `vcs_root`. The path
+/// is made relative to the working directory when possible.
+fn resolve_vcs_file(vcs_root: &Path, working_directory: Option<&Path>, file: &str) -> PathBuf {
+    let path = vcs_root.join(file);
+    match working_directory.and_then(|working_directory| path.strip_prefix(working_directory).ok())
+    {
+        Some(relative_path) => relative_path.to_path_buf(),
+        None => path,
+    }
+}
+
 pub(crate) fn read_vcs_ignore_file(
     session: &mut CliSession,
     current_directory: PathBuf,
@@ -57,15 +382,17 @@ pub(crate) fn read_vcs_ignore_file(
         return Ok(vec![]);
     }
     let file_system = &session.app.fs;
+    let client_kind = resolve_client_kind(&**file_system, &current_directory, configuration);
 
-    if let Some(client_kind) = &configuration.client_kind {
-        if !configuration.ignore_file_disabled() {
-            let result = file_system
-                .auto_search(current_directory, client_kind.ignore_file(), false)
-                .map_err(WorkspaceError::from)?;
+    if !configuration.ignore_file_disabled() {
+        let result = file_system
+            .auto_search(current_directory, client_kind.ignore_file(), false)
+            .map_err(WorkspaceError::from)?;
 
-            if let Some(result) = result {
-                return Ok(result
+        if let Some(result) = result {
+            return Ok(match client_kind {
+                VcsClientKind::Mercurial => read_hg_ignore_patterns(&result.content),
+                VcsClientKind::Git | VcsClientKind::Jujutsu => result
                     .content
                     .lines()
                     // remove empty lines
@@ -79,10 +406,38 @@ pub(crate) fn read_vcs_ignore_file(
                             None
                         }
                     })
-                    .collect::<Vec<String>>());
-            }
+                    .collect::<Vec<String>>(),
+            });
         }
     }
 
     Ok(vec![])
 }
+
+/// Returns the glob patterns of a `.hgignore` file.
+///
+/// The patterns of a `.hgignore` file are regular expressions by default, and the syntax can
+/// be changed with a `syntax: glob` line, or with the `glob:` prefix of a pattern. Regular
+/// expressions can't be converted to glob patterns, so they are skipped.
+fn read_hg_ignore_patterns(content: &str) -> Vec<String> {
+    let mut is_glob = false;
+    let mut patterns = vec![];
+    for line in content.lines() {
+        // remove comments
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(syntax) = line.strip_prefix("syntax:") {
+            is_glob = matches!(syntax.trim(), "glob" | "rootglob");
+        } else if let Some(pattern) = line
+            .strip_prefix("glob:")
+            .or_else(|| line.strip_prefix("rootglob:"))
+        {
+            patterns.push(pattern.trim().to_string());
+        } else if is_glob && !line.starts_with("re:") && !line.starts_with("regexp:") {
+            patterns.push(line.to_string());
+        }
+    }
+    patterns
+}
@@ -0,0 +1,108 @@
+use crate::run_cli;
+use biome_console::BufferConsole;
+use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const FORMATTED: &str = "statement();\n";
+const UNFORMATTED: &str = "  statement(  )  ";
+const CACHE_FILE: &str = ".biome-cache.json";
+
+fn read_cache(fs: &MemoryFileSystem) -> Option<String> {
+    let mut file = fs.open(Path::new(CACHE_FILE)).ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+    Some(content)
+}
+
+#[test]
+fn cache_records_files_without_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("clean.js").into(), FORMATTED.as_bytes());
+    fs.insert(Path::new("unformatted.js").into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--cache"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let cache = read_cache(&fs).expect("the cache file wasn't written");
+    assert!(cache.contains("\"clean.js\""), "{cache}");
+    assert!(!cache.contains("\"unformatted.js\""), "{cache}");
+}
+
+#[test]
+fn cache_processes_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from([("check"), ("--cache"), ("./")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    let cache = read_cache(&fs).expect("the cache file wasn't written");
+
+    // The file didn't change, so it's still recorded with the same key
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from([("check"), ("--cache"), ("./")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(read_cache(&fs).as_deref(), Some(cache.as_str()));
+
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from([("check"), ("--cache"), ("./")].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let cache = read_cache(&fs).expect("the cache file was removed");
+    assert!(!cache.contains("\"file.js\""), "{cache}");
+}
+
+#[test]
+fn no_cache_overrides_cache() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--cache"), ("--no-cache"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(read_cache(&fs), None);
+}
+
+#[test]
+fn cache_is_not_used_when_writing_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--cache"), ("--write"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(read_cache(&fs), None);
+}
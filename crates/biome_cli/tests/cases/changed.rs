@@ -0,0 +1,264 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "  statement(  )  ";
+const FORMATTED: &str = "statement();\n";
+
+const CONFIG_WITH_DEFAULT_BRANCH: &str = r#"{
+    "vcs": {
+        "enabled": true,
+        "clientKind": "git",
+        "defaultBranch": "main"
+    }
+}"#;
+
+#[test]
+fn format_only_changed_files() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        CONFIG_WITH_DEFAULT_BRANCH.as_bytes(),
+    );
+
+    let changed = Path::new("changed.js");
+    fs.insert(changed.into(), UNFORMATTED.as_bytes());
+    let unchanged = Path::new("unchanged.js");
+    fs.insert(unchanged.into(), UNFORMATTED.as_bytes());
+    fs.set_changed_files("main", vec![String::from("changed.js")]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--write", "--changed", "."].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, changed, FORMATTED);
+    assert_file_contents(&fs, unchanged, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_only_changed_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_files_changed_since_reference() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        CONFIG_WITH_DEFAULT_BRANCH.as_bytes(),
+    );
+
+    fs.insert(Path::new("changed.js").into(), "debugger;".as_bytes());
+    fs.insert(Path::new("unchanged.js").into(), "debugger;".as_bytes());
+    fs.set_changed_files("main", vec![String::from("unchanged.js")]);
+    fs.set_changed_files("feature", vec![String::from("changed.js")]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--since=feature"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_files_changed_since_reference",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn changed_without_default_branch() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(Path::new("changed.js").into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), "--changed"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "changed_without_default_branch",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn changed_without_changed_files() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        CONFIG_WITH_DEFAULT_BRANCH.as_bytes(),
+    );
+
+    let unchanged = Path::new("unchanged.js");
+    fs.insert(unchanged.into(), UNFORMATTED.as_bytes());
+    fs.set_changed_files("main", vec![]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), "--changed"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, unchanged, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "changed_without_changed_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ci_reports_only_the_diagnostics_on_changed_lines() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        CONFIG_WITH_DEFAULT_BRANCH.as_bytes(),
+    );
+
+    fs.insert(
+        Path::new("changed.js").into(),
+        "debugger;\ndebugger;\n".as_bytes(),
+    );
+    fs.insert(Path::new("unchanged.js").into(), "debugger;\n".as_bytes());
+    fs.set_diff(
+        "main",
+        "diff --git changed.js changed.js\n\
+        --- changed.js\n\
+        +++ changed.js\n\
+        @@ -1,0 +2 @@\n\
+        +debugger;\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("ci"), "--changed-lines-only", "."].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ci_reports_only_the_diagnostics_on_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ci_ignores_the_formatting_of_unchanged_lines() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        CONFIG_WITH_DEFAULT_BRANCH.as_bytes(),
+    );
+
+    fs.insert(
+        Path::new("changed.js").into(),
+        "statement();\n  statement(  )\nstatement();\n".as_bytes(),
+    );
+    fs.set_diff(
+        "feature",
+        "diff --git changed.js changed.js\n\
+        --- changed.js\n\
+        +++ changed.js\n\
+        @@ -1 +1 @@\n\
+        -other();\n\
+        +statement();\n\
+        @@ -3,2 +3 @@\n\
+        -other();\n\
+        -other();\n\
+        +statement();\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("ci"), "--since=feature"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ci_ignores_the_formatting_of_unchanged_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ci_reports_the_formatting_of_changed_lines() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        CONFIG_WITH_DEFAULT_BRANCH.as_bytes(),
+    );
+
+    fs.insert(
+        Path::new("changed.js").into(),
+        "statement();\n  statement(  )\nstatement();\n".as_bytes(),
+    );
+    fs.set_diff(
+        "main",
+        "diff --git changed.js changed.js\n\
+        --- changed.js\n\
+        +++ changed.js\n\
+        @@ -2 +2 @@\n\
+        -other();\n\
+        +  statement(  )\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("ci"), "--changed-lines-only"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ci_reports_the_formatting_of_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
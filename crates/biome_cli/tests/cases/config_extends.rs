@@ -1,5 +1,5 @@
 use crate::run_cli;
-use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
 use biome_console::BufferConsole;
 use biome_fs::MemoryFileSystem;
 use biome_service::DynRef;
@@ -266,3 +266,340 @@ fn applies_extended_values_in_current_config() {
         result,
     ));
 }
+
+#[test]
+fn extends_config_from_package() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("node_modules/@org/biome-config/package.json").into(),
+        r#"{
+    "name": "@org/biome-config",
+    "exports": {
+        ".": "./biome.json",
+        "./strict": { "biome": "./strict.json" }
+    }
+}"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@org/biome-config/biome.json").into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+    fs.insert(
+        Path::new("node_modules/@org/biome-config/strict.json").into(),
+        r#"{ "formatter": { "lineWidth": 20 } }"#,
+    );
+
+    let rome_json = Path::new("biome.json");
+    fs.insert(
+        rome_json.into(),
+        r#"{ "extends": ["@org/biome-config", "@org/biome-config/strict"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"const a = ["lorem", "ipsum"]; "#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_config_from_package",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_should_raise_an_error_for_unresolved_package() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let rome_json = Path::new("biome.json");
+    fs.insert(rome_json.into(), r#"{ "extends": ["@org/biome-config"] }"#);
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), r#"debugger; console.log("string"); "#);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--verbose",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_should_raise_an_error_for_unresolved_package",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_remote_config_and_records_it_in_lock_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_remote_file(
+        "https://example.com/biome.json",
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+    let rome_json = Path::new("biome.json");
+    fs.insert(
+        rome_json.into(),
+        r#"{ "extends": ["https://example.com/biome.json"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "console.log(\"string\");\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test_file, "console.log('string');\n");
+    assert_file_contents(
+        &fs,
+        Path::new("biome.lock.json"),
+        r#"{
+	"extends": {
+		"https://example.com/biome.json": "sha256:b1569fb6e6c12b57587b1bd60f1852c4b2106589db058071c42e02f4b6c84824"
+	}
+}
+"#,
+    );
+
+    assert_file_contents(
+        &fs,
+        Path::new(".biome-extends-cache.json"),
+        r#"{
+	"sha256:b1569fb6e6c12b57587b1bd60f1852c4b2106589db058071c42e02f4b6c84824": "{ \"javascript\": { \"formatter\": { \"quoteStyle\": \"single\" } } }"
+}
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_remote_config_and_records_it_in_lock_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_remote_config_missing_from_lock_file_when_frozen() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_remote_file(
+        "https://example.com/biome.json",
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+    let rome_json = Path::new("biome.json");
+    fs.insert(
+        rome_json.into(),
+        r#"{ "extends": ["https://example.com/biome.json"] }"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "console.log(\"string\");\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--frozen",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_remote_config_missing_from_lock_file_when_frozen",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn extends_remote_config_that_doesnt_match_its_hash_in_lock_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_remote_file(
+        "https://example.com/biome.json",
+        r#"{ "javascript": { "formatter": { "quoteStyle": "double" } } }"#,
+    );
+    let rome_json = Path::new("biome.json");
+    fs.insert(
+        rome_json.into(),
+        r#"{ "extends": ["https://example.com/biome.json"] }"#,
+    );
+    fs.insert(
+        Path::new("biome.lock.json").into(),
+        r#"{
+	"extends": {
+		"https://example.com/biome.json": "sha256:b1569fb6e6c12b57587b1bd60f1852c4b2106589db058071c42e02f4b6c84824"
+	}
+}
+"#,
+    );
+
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "console.log('string');\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test_file, "console.log('string');\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "extends_remote_config_that_doesnt_match_its_hash_in_lock_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+/// The configuration and its hash, as written by a previous run
+fn insert_cached_remote_config(fs: &mut MemoryFileSystem) {
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "extends": ["https://example.com/biome.json"] }"#,
+    );
+    fs.insert(
+        Path::new("biome.lock.json").into(),
+        r#"{
+	"extends": {
+		"https://example.com/biome.json": "sha256:b1569fb6e6c12b57587b1bd60f1852c4b2106589db058071c42e02f4b6c84824"
+	}
+}
+"#,
+    );
+    fs.insert(
+        Path::new(".biome-extends-cache.json").into(),
+        r#"{
+	"sha256:b1569fb6e6c12b57587b1bd60f1852c4b2106589db058071c42e02f4b6c84824": "{ \"javascript\": { \"formatter\": { \"quoteStyle\": \"single\" } } }"
+}
+"#,
+    );
+}
+
+#[test]
+fn extends_remote_config_from_cache_without_downloading_it() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    // The remote file isn't set, so a download fails
+    insert_cached_remote_config(&mut fs);
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "console.log(\"string\");\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--frozen",
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test_file, "console.log('string');\n");
+}
+
+#[test]
+fn extends_remote_config_missing_from_cache_when_frozen() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    insert_cached_remote_config(&mut fs);
+    fs.remove(Path::new(".biome-extends-cache.json"));
+    fs.set_remote_file(
+        "https://example.com/biome.json",
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+    let test_file = Path::new("test.js");
+    fs.insert(test_file.into(), "console.log(\"string\");\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--frozen",
+                "--write",
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    // `--frozen` doesn't download the configuration, even though it's available
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test_file, "console.log(\"string\");\n");
+}
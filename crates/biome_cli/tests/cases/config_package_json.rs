@@ -0,0 +1,139 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = r#"function f() {
+return "string";
+}
+"#;
+
+#[test]
+fn loads_configuration_from_package_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "package",
+    "biome": {
+        "formatter": { "indentStyle": "space" }
+    }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "function f() {\n  return \"string\";\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "loads_configuration_from_package_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn biome_json_takes_precedence_over_package_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "package",
+    "biome": {
+        "formatter": { "indentStyle": "space" }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#.as_bytes(),
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "function f() {\n\treturn 'string';\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "biome_json_takes_precedence_over_package_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn package_json_configuration_reports_errors() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "name": "package",
+    "biome": {
+        "formatter": { "indentStyle": "spaces" }
+    }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "package_json_configuration_reports_errors",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,126 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "  statement(  )  ";
+
+#[test]
+fn interpolates_environment_variables_in_ignore() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    std::env::set_var("BIOME_TEST_IGNORED_DIRECTORY", "generated");
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "files": { "ignore": ["${env:BIOME_TEST_IGNORED_DIRECTORY}/**"] } }"#.as_bytes(),
+    );
+    let ignored = Path::new("generated/file.js");
+    fs.insert(ignored.into(), UNFORMATTED.as_bytes());
+    let formatted = Path::new("src/file.js");
+    fs.insert(formatted.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                ignored.as_os_str().to_str().unwrap(),
+                formatted.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, ignored, UNFORMATTED);
+    assert_file_contents(&fs, formatted, "statement();\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interpolates_environment_variables_in_ignore",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_unset_environment_variables() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "files": { "ignore": ["${env:BIOME_TEST_UNSET_VARIABLE}/**"] } }"#.as_bytes(),
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_unset_environment_variables",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_unknown_variables() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "files": { "ignore": ["${projectRoot}/**"] } }"#.as_bytes(),
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_unknown_variables",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -69,3 +69,97 @@ fn logs_the_appropriate_messages_according_to_set_diagonstics_level() {
         result,
     ));
 }
+
+#[test]
+fn explains_why_the_rule_exists() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let test = Path::new("test.js");
+    fs.insert(test.into(), "x === x;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--verbose-explanations"),
+                test.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "explains_why_the_rule_exists",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn explains_how_to_fix_the_diagnostics_of_the_rule() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let test = Path::new("test.js");
+    fs.insert(test.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--verbose-explanations"),
+                test.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "explains_how_to_fix_the_diagnostics_of_the_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_diagnostics_of_many_files() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    // More files than the messages that the workers can send before the console prints them
+    for index in 0..1000 {
+        fs.insert(
+            format!("src/file{index}.js").into(),
+            TEST_CONTENTS.as_bytes(),
+        );
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--max-diagnostics=2000"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let printed = console
+        .out_buffer
+        .iter()
+        .filter(|m| m.level == LogLevel::Error)
+        .filter(|m| format!("{:?}", m.content).contains("noDebugger"))
+        .count();
+    assert_eq!(printed, 1000);
+}
@@ -0,0 +1,131 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const REACT_COMPONENT: &str = r#"import { useEffect } from "react";
+export function Component({ items, visible }) {
+    if (visible) {
+        useEffect(() => {});
+    }
+    return items.map((item, index) => <li key={index}>{item}</li>);
+}
+"#;
+
+#[test]
+fn react_domain_enables_react_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "domains": ["react"] }"#.as_bytes(),
+    );
+    let file_path = Path::new("component.jsx");
+    fs.insert(file_path.into(), REACT_COMPONENT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "react_domain_enables_react_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn configured_rules_take_precedence_over_domains() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "domains": ["react"],
+    "linter": {
+        "rules": {
+            "correctness": { "useHookAtTopLevel": "off" },
+            "suspicious": { "noArrayIndexKey": "warn" }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("component.jsx");
+    fs.insert(file_path.into(), REACT_COMPONENT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "configured_rules_take_precedence_over_domains",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn vitest_domain_declares_globals() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "domains": ["vitest"],
+    "linter": {
+        "rules": {
+            "correctness": { "noUndeclaredVariables": "error" },
+            "nursery": { "noGlobalAssign": "error" }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Path::new("file.test.js");
+    fs.insert(
+        file_path.into(),
+        r#"describe("suite", () => {
+    it("works", () => {
+        expect(vi.fn()).toBeDefined();
+    });
+});
+expect = null;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "vitest_domain_declares_globals",
+        fs,
+        console,
+        result,
+    ));
+}
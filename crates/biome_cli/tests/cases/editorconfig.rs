@@ -0,0 +1,192 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = r#"function f() {
+return ["lorem", "ipsum", "dolor"];
+}
+"#;
+
+const EDITORCONFIG: &str = r#"root = true
+
+[*]
+indent_style = space
+indent_size = 4
+max_line_length = 30
+"#;
+
+#[test]
+fn applies_editorconfig_when_enabled() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "useEditorconfig": true } }"#.as_bytes(),
+    );
+    fs.insert(Path::new(".editorconfig").into(), EDITORCONFIG.as_bytes());
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "function f() {\n    return [\n        \"lorem\",\n        \"ipsum\",\n        \"dolor\",\n    ];\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_editorconfig_when_enabled",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn configuration_takes_precedence_over_editorconfig() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "useEditorconfig": true, "indentWidth": 2, "lineWidth": 80 } }"#
+            .as_bytes(),
+    );
+    fs.insert(Path::new(".editorconfig").into(), EDITORCONFIG.as_bytes());
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "function f() {\n  return [\"lorem\", \"ipsum\", \"dolor\"];\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "configuration_takes_precedence_over_editorconfig",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignores_editorconfig_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new(".editorconfig").into(), EDITORCONFIG.as_bytes());
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "function f() {\n\treturn [\"lorem\", \"ipsum\", \"dolor\"];\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignores_editorconfig_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_unsupported_editorconfig_properties() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "useEditorconfig": true } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new(".editorconfig").into(),
+        "[*]\nindent_style = space\ninsert_final_newline = false\n".as_bytes(),
+    );
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "function f() {\n  return [\"lorem\", \"ipsum\", \"dolor\"];\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_unsupported_editorconfig_properties",
+        fs,
+        console,
+        result,
+    ));
+}
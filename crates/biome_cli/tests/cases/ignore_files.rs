@@ -0,0 +1,182 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = r#"function f() {
+return "string";
+}
+"#;
+const FORMATTED: &str = "function f() {\n\treturn \"string\";\n}\n";
+
+#[test]
+fn ignore_file_is_merged_with_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "files": { "ignore": ["generated/**"] } }"#,
+    );
+    fs.insert(Path::new(".biomeignore").into(), "# build output\ndist\n");
+
+    let source_file = Path::new("src/file.js");
+    fs.insert(source_file.into(), UNFORMATTED.as_bytes());
+    let dist_file = Path::new("dist/file.js");
+    fs.insert(dist_file.into(), UNFORMATTED.as_bytes());
+    let generated_file = Path::new("generated/file.js");
+    fs.insert(generated_file.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, source_file, FORMATTED);
+    assert_file_contents(&fs, dist_file, UNFORMATTED);
+    assert_file_contents(&fs, generated_file, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_file_is_merged_with_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_ignore_file_applies_to_its_directory() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("packages/a/.biomeignore").into(),
+        "*.gen.js\n!keep.gen.js\n",
+    );
+
+    let ignored_file = Path::new("packages/a/file.gen.js");
+    fs.insert(ignored_file.into(), UNFORMATTED.as_bytes());
+    let kept_file = Path::new("packages/a/keep.gen.js");
+    fs.insert(kept_file.into(), UNFORMATTED.as_bytes());
+    let other_package_file = Path::new("packages/b/file.gen.js");
+    fs.insert(other_package_file.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, ignored_file, UNFORMATTED);
+    assert_file_contents(&fs, kept_file, FORMATTED);
+    assert_file_contents(&fs, other_package_file, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_ignore_file_applies_to_its_directory",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_vcs_ignore_files_follow_git_semantics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "useIgnoreFile": true } }"#,
+    );
+    fs.insert(Path::new(".gitignore").into(), "*.gen.js\n/build\n");
+    fs.insert(
+        Path::new("packages/a/.gitignore").into(),
+        "!keep.gen.js\nlocal/\n",
+    );
+
+    let root_build_file = Path::new("build/file.js");
+    fs.insert(root_build_file.into(), UNFORMATTED.as_bytes());
+    let nested_build_file = Path::new("packages/a/build/file.js");
+    fs.insert(nested_build_file.into(), UNFORMATTED.as_bytes());
+    let ignored_file = Path::new("packages/a/file.gen.js");
+    fs.insert(ignored_file.into(), UNFORMATTED.as_bytes());
+    let kept_file = Path::new("packages/a/keep.gen.js");
+    fs.insert(kept_file.into(), UNFORMATTED.as_bytes());
+    let local_file = Path::new("packages/a/local/file.js");
+    fs.insert(local_file.into(), UNFORMATTED.as_bytes());
+    let other_package_file = Path::new("packages/b/keep.gen.js");
+    fs.insert(other_package_file.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, root_build_file, UNFORMATTED);
+    assert_file_contents(&fs, nested_build_file, FORMATTED);
+    assert_file_contents(&fs, ignored_file, UNFORMATTED);
+    assert_file_contents(&fs, kept_file, FORMATTED);
+    assert_file_contents(&fs, local_file, UNFORMATTED);
+    assert_file_contents(&fs, other_package_file, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_vcs_ignore_files_follow_git_semantics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn vcs_exclude_file_has_lower_precedence() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "vcs": { "enabled": true, "useIgnoreFile": true } }"#,
+    );
+    fs.insert(
+        Path::new(".git/info/exclude").into(),
+        "*.local.js\nscratch.js\n",
+    );
+    fs.insert(Path::new(".gitignore").into(), "!scratch.js\n");
+
+    let excluded_file = Path::new("src/file.local.js");
+    fs.insert(excluded_file.into(), UNFORMATTED.as_bytes());
+    let scratch_file = Path::new("src/scratch.js");
+    fs.insert(scratch_file.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, excluded_file, UNFORMATTED);
+    assert_file_contents(&fs, scratch_file, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "vcs_exclude_file_has_lower_precedence",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -399,3 +399,93 @@ fn does_not_handle_included_files_if_overridden_by_organize_imports() {
         result,
     ));
 }
+
+#[test]
+fn does_not_handle_files_excluded_by_negated_include() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "files": { "include": ["src/**", "!src/generated/**"] }
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("src/test.js");
+    fs.insert(test.into(), UNFORMATTED.as_bytes());
+
+    let generated = Path::new("src/generated/test.js");
+    fs.insert(generated.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test, FORMATTED);
+
+    assert_file_contents(&fs, generated, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_handle_files_excluded_by_negated_include",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_handle_files_negated_in_ignore() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "files": { "ignore": ["*.js", "!keep.js"] }
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.js");
+    fs.insert(test.into(), UNFORMATTED.as_bytes());
+
+    let keep = Path::new("keep.js");
+    fs.insert(keep.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                test.as_os_str().to_str().unwrap(),
+                keep.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test, UNFORMATTED);
+
+    assert_file_contents(&fs, keep, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_handle_files_negated_in_ignore",
+        fs,
+        console,
+        result,
+    ));
+}
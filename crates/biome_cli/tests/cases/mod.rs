@@ -2,9 +2,27 @@
 //! case that affects many commands
 
 mod biome_json_support;
+mod cache;
+mod changed;
 mod config_extends;
+mod config_package_json;
+mod config_variables;
 mod diagnostics;
+mod domains;
+mod editorconfig;
+mod group_by_rule;
+mod ignore_files;
 mod included_files;
+mod nested_configurations;
+mod overrides_files;
 mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
+mod reporter_checkstyle;
+mod reporter_github;
+mod reporter_gitlab;
+mod reporter_json_lines;
+mod reporter_junit;
+mod reporter_sarif;
+mod reporter_summary;
+mod staged;
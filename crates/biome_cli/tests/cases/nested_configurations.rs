@@ -0,0 +1,138 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = r#"function f() {
+return "string";
+}
+"#;
+
+#[test]
+fn nested_configuration_extends_root_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space" } }"#,
+    );
+
+    let root_file = Path::new("file.js");
+    fs.insert(root_file.into(), UNFORMATTED.as_bytes());
+    let nested_file = Path::new("packages/a/file.js");
+    fs.insert(nested_file.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, root_file, "function f() {\n\treturn 'string';\n}\n");
+    assert_file_contents(&fs, nested_file, "function f() {\n  return 'string';\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_configuration_extends_root_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_root_configuration_does_not_extend_root_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "root": true, "formatter": { "indentStyle": "space" } }"#,
+    );
+
+    let nested_file = Path::new("packages/a/file.js");
+    fs.insert(nested_file.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        nested_file,
+        "function f() {\n  return \"string\";\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_root_configuration_does_not_extend_root_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_configuration_applies_to_subdirectories() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("packages/a/biome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space" } }"#,
+    );
+    fs.insert(
+        Path::new("packages/a/src/nested/biome.json").into(),
+        r#"{ "javascript": { "formatter": { "quoteStyle": "single" } } }"#,
+    );
+
+    let nested_file = Path::new("packages/a/src/file.js");
+    fs.insert(nested_file.into(), UNFORMATTED.as_bytes());
+    let deeply_nested_file = Path::new("packages/a/src/nested/file.js");
+    fs.insert(deeply_nested_file.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("./")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        nested_file,
+        "function f() {\n  return \"string\";\n}\n",
+    );
+    assert_file_contents(
+        &fs,
+        deeply_nested_file,
+        "function f() {\n  return 'string';\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_configuration_applies_to_subdirectories",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,58 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const CONTENT: &str = "statement1();\nstatement2();";
+
+#[test]
+fn does_handle_included_file_with_different_max_size() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "files": {
+    "maxSize": 16
+  },
+  "overrides": [{ "include": ["generated/**"], "files": { "maxSize": 1024 } }]
+}
+
+"#
+        .as_bytes(),
+    );
+
+    let source = Path::new("src/index.js");
+    fs.insert(source.into(), CONTENT.as_bytes());
+
+    let bundle = Path::new("generated/bundle.js");
+    fs.insert(bundle.into(), CONTENT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--error-on-warnings",
+                source.as_os_str().to_str().unwrap(),
+                bundle.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_handle_included_file_with_different_max_size",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -345,3 +345,181 @@ fn does_include_file_with_different_languages_and_files() {
         result,
     ));
 }
+
+#[test]
+fn does_not_override_files_negated_in_include() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "overrides": [
+    { "include": ["special/**", "!special/test.js"], "formatter": { "lineWidth": 20 } }
+  ]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("special/test.js");
+    fs.insert(test.into(), UNFORMATTED_LINE_WIDTH.as_bytes());
+
+    let test2 = Path::new("special/test2.js");
+    fs.insert(test2.into(), UNFORMATTED_LINE_WIDTH.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                test.as_os_str().to_str().unwrap(),
+                test2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test2, FORMATTED_LINE_WIDTH_OVERRIDDEN);
+    assert_file_contents(&fs, test, FORMATTED_LINE_WIDTH);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_override_files_negated_in_include",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_reset_the_options_that_the_override_does_not_set() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "formatter": { "indentStyle": "space" },
+  "overrides": [{ "include": ["special/**"], "formatter": { "lineWidth": 20 } }]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("special/test.js");
+    fs.insert(test.into(), UNFORMATTED_LINE_WIDTH.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), test.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test, FORMATTED_LINE_WITH_SPACES);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_reset_the_options_that_the_override_does_not_set",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_disable_the_language_formatter_of_an_override() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "overrides": [
+    { "include": ["generated/**"], "javascript": { "formatter": { "enabled": false } } }
+  ]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("generated/test.js");
+    fs.insert(test.into(), UNFORMATTED.as_bytes());
+
+    let test2 = Path::new("generated/test.json");
+    fs.insert(test2.into(), UNFORMATTED_JSON.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                test.as_os_str().to_str().unwrap(),
+                test2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test, UNFORMATTED);
+    assert_file_contents(
+        &fs,
+        test2,
+        "{ \"asta\": [\"lorem\", \"ipsum\", \"first\", \"second\"] }\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_disable_the_language_formatter_of_an_override",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_reset_the_json_parser_options_of_an_override() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "json": { "parser": { "allowComments": true } },
+  "overrides": [{ "include": ["*.json"], "json": { "formatter": { "indentWidth": 4 } } }]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test.json");
+    fs.insert(test.into(), "// comment\n{ \"a\": 1 }".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), test.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, test, "// comment\n{ \"a\": 1 }\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_reset_the_json_parser_options_of_an_override",
+        fs,
+        console,
+        result,
+    ));
+}
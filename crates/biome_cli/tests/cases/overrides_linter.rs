@@ -327,3 +327,48 @@ fn does_override_the_rules() {
         result,
     ));
 }
+
+#[test]
+fn does_apply_the_globals_of_an_override() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": { "rules": { "correctness": { "noUndeclaredVariables": "error" } } },
+  "overrides": [{ "include": ["test/**"], "javascript": { "globals": ["describe"] } }]
+}
+"#
+        .as_bytes(),
+    );
+
+    let test = Path::new("test/test.js");
+    fs.insert(test.into(), "describe();\n".as_bytes());
+
+    let test2 = Path::new("src/test.js");
+    fs.insert(test2.into(), "describe();\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                test.as_os_str().to_str().unwrap(),
+                test2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_apply_the_globals_of_an_override",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,109 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, markup_to_string, SnapshotPayload};
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn reports_diagnostics_in_junit_format() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("file1.js");
+    fs.insert(
+        file_path1.into(),
+        "debugger;\nlet a = 1;\nconsole.log(a);\n".as_bytes(),
+    );
+    let file_path2 = Path::new("file2.js");
+    fs.insert(file_path2.into(), "if (a == b) {}\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--reporter=junit"),
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_diagnostics_in_junit_format",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_no_diagnostics_in_junit_format() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "console.log(1);\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--reporter=junit"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_no_diagnostics_in_junit_format",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_all_the_diagnostics_in_junit_format() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    // More diagnostics than the default of `--max-diagnostics`
+    for index in 0..30 {
+        fs.insert(format!("src/file{index}.js").into(), "debugger;".as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--reporter=junit"), ("src")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let report: String = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .collect();
+    assert_eq!(
+        report
+            .matches("<testcase name=\"lint/suspicious/noDebugger\"")
+            .count(),
+        30,
+        "{report}"
+    );
+}
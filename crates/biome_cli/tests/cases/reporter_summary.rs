@@ -0,0 +1,108 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, markup_to_string, SnapshotPayload};
+use biome_console::{markup, BufferConsole, LogLevel};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn reports_summary_of_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("file1.js");
+    fs.insert(
+        file_path1.into(),
+        "debugger;\ndebugger;\nlet a = 1;\nconsole.log(a);\n".as_bytes(),
+    );
+    let file_path2 = Path::new("file2.ts");
+    fs.insert(file_path2.into(), "debugger;\nif (a == b) {}\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--reporter=summary"),
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let summary = console
+        .out_buffer
+        .iter()
+        .filter(|message| message.level == LogLevel::Log)
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .find(|content| content.starts_with("Rule"))
+        .expect("the summary wasn't printed");
+
+    // The timings change on every run, so the last column of the rules is compared without them
+    let (rules, extensions) = summary.split_once("\n\n").unwrap();
+    let rules: Vec<_> = rules
+        .lines()
+        .map(|line| {
+            let (line, time) = line.trim_end().rsplit_once(' ').unwrap();
+            assert!(
+                time == "Time" || time.starts_with('-') || time.ends_with("ms"),
+                "{summary}"
+            );
+            line.trim_end()
+        })
+        .collect();
+    assert_eq!(
+        rules.join("\n"),
+        "\
+Rule                            Diagnostics  Fixable
+------------------------------  -----------  -------
+lint/suspicious/noDebugger                3        3
+lint/style/useConst                       1        1
+lint/suspicious/noDoubleEquals            1        1"
+    );
+    assert_eq!(
+        extensions,
+        "\
+Extension  Files  Diagnostics  Fixable
+---------  -----  -----------  -------
+js             1            3        3
+ts             1            2        2"
+    );
+}
+
+#[test]
+fn reports_summary_without_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "console.log(1);\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--reporter=summary"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_summary_without_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,121 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = "  statement(  )  ";
+const FORMATTED: &str = "statement();\n";
+
+#[test]
+fn format_staged_content() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    // The staged content is formatted, the unstaged changes must be ignored
+    let staged = Path::new("staged.js");
+    fs.insert(staged.into(), UNFORMATTED.as_bytes());
+    fs.set_staged_file("staged.js", FORMATTED);
+    let unstaged = Path::new("unstaged.js");
+    fs.insert(unstaged.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "--staged"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, staged, UNFORMATTED);
+    assert_file_contents(&fs, unstaged, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_staged_content",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_staged_content() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let staged = Path::new("staged.js");
+    fs.insert(staged.into(), "let a = 1;".as_bytes());
+    fs.set_staged_file("staged.js", "debugger;");
+    fs.insert(Path::new("unstaged.js").into(), "debugger;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--staged"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_staged_content",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn staged_without_staged_files() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.insert(Path::new("unstaged.js").into(), "debugger;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), "--staged"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "staged_without_staged_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn staged_and_apply_are_incompatible() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let staged = Path::new("staged.js");
+    fs.insert(staged.into(), UNFORMATTED.as_bytes());
+    fs.set_staged_file("staged.js", UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), "--staged", "--apply"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, staged, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "staged_and_apply_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1109,7 +1109,7 @@ fn file_too_large() {
         Args::from([("check"), file_path.as_os_str().to_str().unwrap()].as_slice()),
     );
 
-    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
 
     // Do not store the content of the file in the snapshot
     fs.remove(file_path);
@@ -1139,7 +1139,7 @@ fn file_too_large_config_limit() {
         Args::from([("check"), file_path.as_os_str().to_str().unwrap()].as_slice()),
     );
 
-    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -1171,7 +1171,7 @@ fn file_too_large_cli_limit() {
         ),
     );
 
-    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -1238,14 +1238,19 @@ fn max_diagnostics_default() {
 
     for msg in console.out_buffer {
         let MarkupBuf(nodes) = &msg.content;
-        let is_diagnostic = nodes.iter().any(|node| {
-            node.content.contains("useWhile")
-                || node.content.contains("useBlockStatements")
-                || node.content.contains("noConstantCondition")
-                || node.content.contains("format")
-                || node.content.contains("lint")
-                || node.content.contains("check")
-        });
+        // The summary of the diagnostics that weren't printed lists the rules
+        let is_summary = nodes
+            .iter()
+            .any(|node| node.content.contains("Diagnostics not shown"));
+        let is_diagnostic = !is_summary
+            && nodes.iter().any(|node| {
+                node.content.contains("useWhile")
+                    || node.content.contains("useBlockStatements")
+                    || node.content.contains("noConstantCondition")
+                    || node.content.contains("format")
+                    || node.content.contains("lint")
+                    || node.content.contains("check")
+            });
 
         if is_diagnostic {
             diagnostic_count += 1;
@@ -1296,15 +1301,20 @@ fn max_diagnostics() {
 
     for msg in console.out_buffer {
         let MarkupBuf(nodes) = &msg.content;
-        let is_diagnostic = nodes.iter().any(|node| {
-            node.content.contains("useWhile")
-                || node.content.contains("useBlockStatements")
-                || node.content.contains("noConstantCondition")
-                || node.content.contains("format")
-                || node.content.contains("lint")
-                || node.content.contains("check")
-                || node.content.contains("Some errors were emitted while")
-        });
+        // The summary of the diagnostics that weren't printed lists the rules
+        let is_summary = nodes
+            .iter()
+            .any(|node| node.content.contains("Diagnostics not shown"));
+        let is_diagnostic = !is_summary
+            && nodes.iter().any(|node| {
+                node.content.contains("useWhile")
+                    || node.content.contains("useBlockStatements")
+                    || node.content.contains("noConstantCondition")
+                    || node.content.contains("format")
+                    || node.content.contains("lint")
+                    || node.content.contains("check")
+                    || node.content.contains("Some errors were emitted while")
+            });
 
         if is_diagnostic {
             diagnostic_count += 1;
@@ -2147,6 +2157,72 @@ fn check_stdin_apply_successfully() {
     ));
 }
 
+#[test]
+fn check_stdin_batch_apply() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    // Each line of the buffer ends with a line break, which is part of the content
+    console.in_buffer.extend(
+        [
+            "a.js",
+            "25",
+            "debugger;",
+            "let a = [1,2];",
+            "b.js",
+            "14",
+            "let b = {  };",
+        ]
+        .map(String::from),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), "--apply", ("--stdin-batch")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_stdin_batch_apply",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_stdin_batch_and_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--stdin-batch"),
+                ("--stdin-file-path"),
+                ("mock.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_stdin_batch_and_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn check_stdin_apply_unsafe_successfully() {
     let mut fs = MemoryFileSystem::default();
@@ -2730,3 +2806,272 @@ fn use_literal_keys_should_emit_correct_ast_issue_266() {
         result,
     ));
 }
+
+#[test]
+fn watch_checks_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let valid = Path::new("valid.js");
+    fs.insert(valid.into(), "statement();\n".as_bytes());
+    let invalid = Path::new("invalid.js");
+    fs.insert(invalid.into(), "debugger;\n".as_bytes());
+    let readme = Path::new("README.md");
+    fs.insert(readme.into(), "# Readme\n".as_bytes());
+
+    // The errors of a run must not stop the watcher
+    fs.add_watch_event(vec![invalid.into(), readme.into()]);
+    fs.add_watch_event(vec![valid.into()]);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--watch",
+                valid.as_os_str().to_str().unwrap(),
+                invalid.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "watch_checks_changed_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn interactive_reviews_diagnostics_one_by_one() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\ndebugger;\ndebugger;\n".as_bytes(),
+    );
+
+    // Apply the first fix, suppress the second diagnostic, skip the third one
+    console.in_buffer.push("a".to_string());
+    console.in_buffer.push("u".to_string());
+    console.in_buffer.push("s".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--interactive",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interactive_reviews_diagnostics_one_by_one",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn interactive_stops_on_quit() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\ndebugger;\n".as_bytes());
+
+    console.in_buffer.push("q".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--interactive",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "debugger;\ndebugger;\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interactive_stops_on_quit",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn interactive_incompatible_with_apply() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--interactive",
+                "--apply",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "interactive_incompatible_with_apply",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn processes_files_with_the_given_number_of_threads() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("file1.js");
+    fs.insert(file_path1.into(), LINT_ERROR.as_bytes());
+    let file_path2 = Path::new("file2.js");
+    fs.insert(file_path2.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--threads=1",
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "processes_files_with_the_given_number_of_threads",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn zero_threads_is_rejected() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--threads=0",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "zero_threads_is_rejected",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn writes_a_profile_of_the_run() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+    let profile_path = Path::new("profile.json");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--profile",
+                profile_path.as_os_str().to_str().unwrap(),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(profile_path)
+        .expect("the profile should have been written");
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read the profile");
+    let profile: serde_json::Value =
+        serde_json::from_str(&content).expect("the profile should be valid JSON");
+    let events = profile["traceEvents"]
+        .as_array()
+        .expect("the profile should contain the events");
+    // In the tests, the workspace runs in another thread, like the daemon, so the profile
+    // contains only the spans of the CLI
+    for name in [
+        "Processes linting",
+        "Processes formatting",
+        "Processes import sorting",
+    ] {
+        assert!(
+            events.iter().any(|event| event["ph"] == "X"
+                && event["name"] == name
+                && event["args"]["path"] == "file.js"),
+            "missing the span {name}: {content}"
+        );
+    }
+}
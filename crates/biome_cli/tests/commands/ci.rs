@@ -379,7 +379,7 @@ fn file_too_large() {
         Args::from([("ci"), file_path.as_os_str().to_str().unwrap()].as_slice()),
     );
 
-    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
 
     // Do not store the content of the file in the snapshot
     fs.remove(file_path);
@@ -409,7 +409,7 @@ fn file_too_large_config_limit() {
         Args::from([("ci"), file_path.as_os_str().to_str().unwrap()].as_slice()),
     );
 
-    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -441,7 +441,7 @@ fn file_too_large_cli_limit() {
         ),
     );
 
-    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert!(result.is_ok(), "run_cli returned {result:?}");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
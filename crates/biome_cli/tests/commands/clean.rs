@@ -0,0 +1,103 @@
+use crate::commands::rage::TestLogDir;
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, markup_to_string, SnapshotPayload};
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::fs;
+
+#[test]
+fn clean_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("clean"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "clean_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn removes_the_logs_of_the_daemon() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let log_dir = TestLogDir::new("biome-clean-test");
+    fs::create_dir_all(&log_dir.path).expect("Failed to create the log directory");
+    let log_file = log_dir.path.join("server.log.2022-10-14-16");
+    fs::write(&log_file, "log").expect("Failed to write the log file");
+    let other_file = log_dir.path.join("notes.txt");
+    fs::write(&other_file, "notes").expect("Failed to write the file");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("clean")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(!log_file.exists(), "the log file should have been removed");
+    assert!(other_file.exists(), "the other files should be kept");
+
+    let messages = messages(&console);
+    let removed = format!("Removed {}", log_file.display());
+    assert!(
+        messages.iter().any(|message| message == &removed),
+        "the removed file should be reported: {messages:?}"
+    );
+}
+
+#[test]
+fn dry_run_keeps_the_logs_of_the_daemon() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let log_dir = TestLogDir::new("biome-clean-dry-run-test");
+    fs::create_dir_all(&log_dir.path).expect("Failed to create the log directory");
+    let log_file = log_dir.path.join("server.log.2022-10-14-16");
+    fs::write(&log_file, "log").expect("Failed to write the log file");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("clean"), "--dry-run"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(
+        log_file.exists(),
+        "the log file shouldn't have been removed"
+    );
+
+    let messages = messages(&console);
+    assert_eq!(messages[0], "The following files would be removed:");
+    let listed = format!("  {}", log_file.display());
+    assert!(
+        messages.iter().any(|message| message == &listed),
+        "the log file should be listed: {messages:?}"
+    );
+}
+
+fn messages(console: &BufferConsole) -> Vec<String> {
+    console
+        .out_buffer
+        .iter()
+        .map(|message| {
+            markup_to_string(markup! {
+                {message.content}
+            })
+        })
+        .collect()
+}
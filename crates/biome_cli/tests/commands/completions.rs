@@ -0,0 +1,135 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_cli::biome_command;
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::{Args, ParseFailure};
+use std::{panic, thread};
+
+/// The parser of the CLI is deeply nested, and in debug builds generating its completions needs
+/// more stack than the threads spawned by the tests have by default
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Runs `test` on a thread with a stack of [STACK_SIZE]
+fn with_large_stack(test: impl FnOnce() + Send + 'static) {
+    let handle = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(test)
+        .unwrap();
+    if let Err(error) = handle.join() {
+        panic::resume_unwind(error);
+    }
+}
+
+#[test]
+fn completions_help() {
+    with_large_stack(|| {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from([("completions"), "--help"].as_slice()),
+        );
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "completions_help",
+            fs,
+            console,
+            result,
+        ));
+    });
+}
+
+#[test]
+fn completions_bash() {
+    with_large_stack(|| {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from([("completions"), ("bash")].as_slice()),
+        );
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "completions_bash",
+            fs,
+            console,
+            result,
+        ));
+    });
+}
+
+#[test]
+fn completions_powershell() {
+    with_large_stack(|| {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from([("completions"), ("powershell")].as_slice()),
+        );
+
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "completions_powershell",
+            fs,
+            console,
+            result,
+        ));
+    });
+}
+
+#[test]
+fn completions_unsupported_shell() {
+    with_large_stack(|| {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from([("completions"), ("tcsh")].as_slice()),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "completions_unsupported_shell",
+            fs,
+            console,
+            result,
+        ));
+    });
+}
+
+#[test]
+fn completes_rule_names() {
+    with_large_stack(|| {
+        let result = biome_command().run_inner(
+            Args::from([("lint"), ("--rule"), ("suspicious/noDo")].as_slice()).set_comp(1),
+        );
+
+        match result {
+            Err(ParseFailure::Completion(completions)) => {
+                assert_eq!(completions, "suspicious/noDoubleEquals\n");
+            }
+            _ => panic!("expected the completions of the rule names, got {result:?}"),
+        }
+    });
+}
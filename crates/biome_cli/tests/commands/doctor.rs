@@ -0,0 +1,187 @@
+use crate::run_cli;
+use crate::snap_test::{CliSnapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn doctor_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("doctor"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_doctor_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "doctor_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn without_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("doctor"), "index.js", "README.md"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_doctor_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "without_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_the_pattern_that_ignores_a_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "files": { "ignore": ["dist/**"] },
+  "formatter": { "include": ["src/**"] },
+  "linter": { "ignore": ["**/*.test.js"] },
+  "organizeImports": { "enabled": false },
+  "overrides": [{ "include": ["dist/keep.js"] }]
+}"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("doctor"),
+                "src/index.test.js",
+                "dist/index.js",
+                "dist/keep.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_doctor_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_the_pattern_that_ignores_a_file",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_the_files_ignored_by_the_vcs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "vcs": { "enabled": true, "clientKind": "git", "useIgnoreFile": true }
+}"#,
+    );
+    fs.insert(Path::new(".gitignore").into(), "generated/\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("doctor"), "generated/index.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_doctor_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_the_files_ignored_by_the_vcs",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn prints_the_configuration_resolved_after_extends() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+  "extends": ["base.json"],
+  "formatter": { "lineWidth": 100 }
+}"#,
+    );
+    fs.insert(
+        Path::new("base.json").into(),
+        r#"{
+  "linter": { "enabled": false }
+}"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("doctor")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_doctor_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "prints_the_configuration_resolved_after_extends",
+        fs,
+        console,
+        result,
+    ));
+}
+
+/// The status of the daemon depends on the machine that runs the tests
+fn assert_doctor_snapshot(payload: SnapshotPayload<'_>) {
+    let test_name = payload.test_name;
+    let module_path = payload.module_path;
+
+    let mut snapshot = CliSnapshot::from(payload);
+
+    for message in snapshot.messages.iter_mut() {
+        *message = message
+            .lines()
+            .map(|line| match line.trim_start().split_once(':') {
+                Some(("Status", value)) => line.replace(value.trim_start(), "**PLACEHOLDER**"),
+                _ => line.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    let content = snapshot.emit_content_snapshot();
+
+    let module_path = module_path.replace("::", "_");
+    let snapshot_path = PathBuf::from("../snapshots").join(module_path);
+
+    insta::with_settings!({
+        prepend_module_to_snapshot => false,
+        snapshot_path => snapshot_path
+    }, {
+        insta::assert_snapshot!(test_name, content);
+
+    });
+}
@@ -5,12 +5,17 @@ use crate::configs::{
 };
 use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
 use crate::{
-    assert_cli_snapshot, run_cli, CUSTOM_FORMAT_BEFORE, FORMATTED, LINT_ERROR, UNFORMATTED,
+    assert_cli_snapshot, run_cli, run_cli_with_transport, CUSTOM_FORMAT_BEFORE, FORMATTED,
+    LINT_ERROR, UNFORMATTED,
 };
-use biome_console::{markup, BufferConsole, MarkupBuf};
+use biome_cli::SocketTransport;
+use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
 use biome_fs::{FileSystemExt, MemoryFileSystem};
-use biome_service::DynRef;
+use biome_service::workspace::{TransportRequest, WorkspaceTransport};
+use biome_service::{DynRef, TransportError};
 use bpaf::Args;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::path::{Path, PathBuf};
 
 // six spaces
@@ -94,6 +99,20 @@ const APPLY_BRACKET_SAME_LINE_BEFORE: &str = r#"<Foo
 </Foo>;
 "#;
 
+const APPLY_DECORATOR_PLACEMENT_BEFORE: &str = r#"class Foo {
+	@dec prop;
+	@dec method() {}
+}
+"#;
+
+const APPLY_DECORATOR_PLACEMENT_AFTER: &str = r#"class Foo {
+	@dec
+	prop;
+	@dec
+	method() {}
+}
+"#;
+
 const APPLY_BRACKET_SAME_LINE_AFTER: &str = r#"<Foo
 	className={style}
 	reallyLongAttributeName1={longComplexValue}
@@ -778,6 +797,45 @@ fn applies_custom_bracket_same_line() {
     ));
 }
 
+#[test]
+fn applies_custom_decorator_placement() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        APPLY_DECORATOR_PLACEMENT_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--decorator-placement"),
+                ("own-line"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, APPLY_DECORATOR_PLACEMENT_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_decorator_placement",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn trailing_comma_parse_errors() {
     let mut console = BufferConsole::default();
@@ -1091,6 +1149,119 @@ fn format_stdin_successfully() {
     ));
 }
 
+#[test]
+fn format_stdin_batch() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    // Each line of the buffer ends with a line break, which is part of the content
+    console.in_buffer.extend(
+        [
+            "a.js",
+            "24",
+            "function f() {return{}}",
+            "b.json",
+            "11",
+            r#"{"a":   1}"#,
+        ]
+        .map(String::from),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--stdin-batch")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .collect();
+
+    assert_eq!(
+        messages,
+        [
+            "a.js\n29\nfunction f() {\n\treturn {};\n}\n",
+            "b.json\n11\n{ \"a\": 1 }\n",
+        ]
+    );
+}
+
+#[test]
+fn format_stdin_batch_with_unsupported_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.extend(
+        [
+            "a.js",
+            "24",
+            "function f() {return{}}",
+            "b.txt",
+            "6",
+            "hello",
+            "c.js",
+            "7",
+            "let c;",
+        ]
+        .map(String::from),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--stdin-batch")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The unsupported file is printed unchanged, and the files after it are processed
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .filter(|message| message.level == LogLevel::Log)
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .collect();
+
+    assert_eq!(
+        messages,
+        [
+            "a.js\n29\nfunction f() {\n\treturn {};\n}\n",
+            "b.txt\n6\nhello\n",
+            "c.js\n7\nlet c;\n",
+        ]
+    );
+}
+
+#[test]
+fn format_stdin_batch_with_missing_content() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console
+        .in_buffer
+        .extend(["a.js", "100", "let a;"].map(String::from));
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--stdin-batch")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_stdin_batch_with_missing_content",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn format_stdin_with_errors() {
     let mut fs = MemoryFileSystem::default();
@@ -1435,6 +1606,50 @@ fn file_too_large_cli_limit() {
     ));
 }
 
+#[test]
+fn files_exclude_generated() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        PathBuf::from("biome.json"),
+        r#"{
+  "files": {
+    "excludeGenerated": true
+  }
+}"#,
+    );
+
+    let generated = Path::new("generated.js");
+    fs.insert(generated.into(), "// @generated\nstatement(  );\n");
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), "statement();\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                generated.as_os_str().to_str().unwrap(),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "files_exclude_generated",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn files_max_size_parse_error() {
     let mut fs = MemoryFileSystem::default();
@@ -2314,3 +2529,641 @@ const a = {
         result,
     ));
 }
+
+#[test]
+fn applies_json_array_fill_from_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+    "json": {
+        "formatter": { "arrayFill": "literals" }
+    }
+}"#;
+    let biome_config = "biome.json";
+    let code = r#"{
+    "strings": ["ab", "cd", "ef", "gh", "ij", "kl", "mn", "op", "qr", "st", "uv", "wx", "yz", "AB", "CD", "EF"]
+}"#;
+    let file_path = Path::new("file.json");
+    fs.insert(file_path.into(), code.as_bytes());
+    fs.insert(biome_config.into(), config_json);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "{\n\t\"strings\": [\n\t\t\"ab\", \"cd\", \"ef\", \"gh\", \"ij\", \"kl\", \"mn\", \"op\", \"qr\", \"st\", \"uv\", \"wx\",\n\t\t\"yz\", \"AB\", \"CD\", \"EF\"\n\t]\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_json_array_fill_from_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_dedent_tags_from_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+    "javascript": {
+        "formatter": { "dedentTags": ["dedent"] }
+    }
+}"#;
+    let biome_config = "biome.json";
+    let code = r#"function foo() {
+    return dedent`
+            first
+              second
+        `;
+}
+"#;
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), code.as_bytes());
+    fs.insert(biome_config.into(), config_json);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "function foo() {\n\treturn dedent`\n\t\tfirst\n\t\t  second\n\t`;\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_dedent_tags_from_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_dedent_tags_to_templates_with_crlf_line_endings() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+    "javascript": {
+        "formatter": { "dedentTags": ["dedent"] }
+    }
+}"#;
+    let biome_config = "biome.json";
+    let code = "function foo() {\r\n    return dedent`\r\n            first\r\n              second\r\n        `;\r\n}\r\n";
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), code.as_bytes());
+    fs.insert(biome_config.into(), config_json);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "function foo() {\n\treturn dedent`\n\t\tfirst\n\t\t  second\n\t`;\n}\n",
+    );
+}
+
+#[test]
+fn applies_sql_tags_from_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+    "javascript": {
+        "formatter": { "sqlTags": ["sql"] }
+    }
+}"#;
+    let biome_config = "biome.json";
+    let code = r#"const users = sql`
+    select id from users where active = true
+`;
+"#;
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), code.as_bytes());
+    fs.insert(biome_config.into(), config_json);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "const users = sql`\n\tSELECT id\n\tFROM users\n\tWHERE active = TRUE\n`;\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_sql_tags_from_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn applies_prettier_compatibility_from_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+    "formatter": { "prettierCompatibility": true }
+}"#;
+    let biome_config = "biome.json";
+    let code = r#"const obj = { "𐊧": true, [x = 0]: 1 };
+"#;
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), code.as_bytes());
+    fs.insert(biome_config.into(), config_json);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "const obj = { \"𐊧\": true, [(x = 0)]: 1 };\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_prettier_compatibility_from_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn formats_markdown_code_blocks() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+    "markdown": {
+        "formatter": { "enabled": true }
+    }
+}"#;
+    let biome_config = "biome.json";
+    let code = r#"# Title
+
+Some  prose   that   is left   untouched.
+
+```js title="example.js"
+const  a  =  {b:1}
+```
+
+```json
+{"a":[1,2,3]}
+```
+
+```css
+a{color:red}
+```
+
+```ts
+const = ;
+```
+
+- A list item
+
+  ```tsx
+  const element = <div   className="a">{ value }</div>
+  ```
+"#;
+    let file_path = Path::new("README.md");
+    fs.insert(file_path.into(), code.as_bytes());
+    fs.insert(biome_config.into(), config_json);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "formats_markdown_code_blocks",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_format_markdown_code_blocks_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let code = r#"```js
+const  a  =  {b:1}
+```
+"#;
+    let file_path = Path::new("README.md");
+    fs.insert(file_path.into(), code.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert_file_contents(&fs, file_path, code);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_format_markdown_code_blocks_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn verify_idempotence_reports_formatting_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--verify-idempotence",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "verify_idempotence_reports_formatting_diff",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn verify_idempotence_with_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--verify-idempotence",
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "verify_idempotence_with_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+/// A transport that appends a line to the formatted code returned by the workspace, so formatting
+/// a file twice never gives the same output
+struct UnstableFormatTransport(SocketTransport);
+
+impl WorkspaceTransport for UnstableFormatTransport {
+    fn request<P, R>(&self, request: TransportRequest<P>) -> Result<R, TransportError>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let method = request.method;
+        let mut response: serde_json::Value = self.0.request(request)?;
+        if method == "biome/format_file" {
+            if let Some(serde_json::Value::String(code)) = response.get_mut("code") {
+                code.push('\n');
+            }
+        }
+        serde_json::from_value(response)
+            .map_err(|error| TransportError::SerdeError(error.to_string()))
+    }
+}
+
+#[test]
+fn verify_idempotence_doesnt_write_unstable_output() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli_with_transport(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--verify-idempotence",
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+        UnstableFormatTransport,
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+}
+
+#[test]
+fn line_ending_auto_preserves_crlf() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+    "formatter": { "lineEnding": "auto" }
+}"#;
+    let biome_config = "biome.json";
+    let crlf_path = Path::new("crlf.js");
+    fs.insert(
+        crlf_path.into(),
+        "statement(  )\r\nstatement(  )\r\n".as_bytes(),
+    );
+    let lf_path = Path::new("lf.js");
+    fs.insert(lf_path.into(), "statement(  )\nstatement(  )\n".as_bytes());
+    fs.insert(biome_config.into(), config_json);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                crlf_path.as_os_str().to_str().unwrap(),
+                lf_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, crlf_path, "statement();\r\nstatement();\r\n");
+    assert_file_contents(&fs, lf_path, "statement();\nstatement();\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "line_ending_auto_preserves_crlf",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn override_line_ending() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+    "formatter": { "lineEnding": "lf" },
+    "overrides": [
+        {
+            "include": ["scripts/**"],
+            "formatter": { "lineEnding": "crlf" }
+        }
+    ]
+}"#;
+    let biome_config = "biome.json";
+    let script_path = Path::new("scripts/build.js");
+    fs.insert(
+        script_path.into(),
+        "statement(  )\nstatement(  )\n".as_bytes(),
+    );
+    let source_path = Path::new("src/index.js");
+    fs.insert(
+        source_path.into(),
+        "statement(  )\nstatement(  )\n".as_bytes(),
+    );
+    fs.insert(biome_config.into(), config_json);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                script_path.as_os_str().to_str().unwrap(),
+                source_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, script_path, "statement();\r\nstatement();\r\n");
+    assert_file_contents(&fs, source_path, "statement();\nstatement();\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "override_line_ending",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_check_patch() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("b.js").into(), "let b  =  1\n".as_bytes());
+    fs.insert(Path::new("a.js").into(), UNFORMATTED.as_bytes());
+    fs.insert(Path::new("formatted.js").into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--check"),
+                ("--patch"),
+                ("./b.js"),
+                ("a.js"),
+                ("formatted.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .collect();
+
+    assert_eq!(
+        messages,
+        ["diff --git a/a.js b/a.js\n\
+            --- a/a.js\n\
+            +++ b/a.js\n\
+            @@ -1 +1 @@\n\
+            -  statement(  )  \n\
+            \\ No newline at end of file\n\
+            +statement();\n\
+            diff --git a/b.js b/b.js\n\
+            --- a/b.js\n\
+            +++ b/b.js\n\
+            @@ -1 +1 @@\n\
+            -let b  =  1\n\
+            +let b = 1;"]
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_check_patch",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_patch_and_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("a.js").into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--write"), ("--patch"), ("a.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, Path::new("a.js"), UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_patch_and_write",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -99,3 +99,82 @@ fn creates_config_file_when_biome_installed_via_package_manager() {
         result,
     ));
 }
+
+#[test]
+fn creates_config_file_interactively() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "private": true, "dependencies": { "react": "^18.0.0" } }"#.as_bytes(),
+    );
+    fs.insert(Path::new(".git/HEAD").into(), *b"ref: refs/heads/main");
+    for answer in ["", "space", "4", "wide", "100", "single", "as-needed"] {
+        console.in_buffer.push(answer.to_string());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), ("--interactive")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "creates_config_file_interactively",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn creates_config_file_interactively_with_default_answers() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "name": "my-library", "main": "dist/index.js" }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), ("--interactive")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "creates_config_file_interactively_with_default_answers",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn init_interactive_when_config_exists() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("biome.json").into(), *b"{}");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("init"), ("--interactive")].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "init_interactive_when_config_exists",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,191 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const PRE_COMMIT: &str = ".git/hooks/pre-commit";
+
+fn git_repository() -> MemoryFileSystem {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new(".git/HEAD").into(),
+        "ref: refs/heads/main\n".as_bytes(),
+    );
+    fs.set_git_hooks_directory(".git/hooks");
+    fs
+}
+
+fn read_hook(fs: &MemoryFileSystem, path: &str) -> String {
+    let mut file = fs.open(Path::new(path)).expect("the hook should exist");
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("the hook should be readable");
+    content
+}
+
+#[test]
+fn install_git_hooks_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("install-git-hooks"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "install_git_hooks_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn installs_the_pre_commit_hook() {
+    let mut fs = git_repository();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("install-git-hooks")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(read_hook(&fs, PRE_COMMIT).contains("check --staged --no-errors-on-unmatched"));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "installs_the_pre_commit_hook",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn installs_the_pre_push_hook() {
+    let mut fs = git_repository();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("install-git-hooks"), "--hook=pre-push"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "installs_the_pre_push_hook",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn installs_the_hook_in_the_hooks_directory_of_git() {
+    let mut fs = git_repository();
+    // e.g. `core.hooksPath`, or the hooks of the repository of a worktree
+    fs.set_git_hooks_directory(".husky");
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("install-git-hooks")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(read_hook(&fs, ".husky/pre-commit").contains("check --staged"));
+    assert!(fs.open(Path::new(PRE_COMMIT)).is_err());
+}
+
+#[test]
+fn installing_twice_keeps_a_single_copy_of_the_commands() {
+    let mut fs = git_repository();
+    fs.insert(
+        Path::new(PRE_COMMIT).into(),
+        "#!/bin/sh\nnpm test\n".as_bytes(),
+    );
+
+    for _ in 0..2 {
+        let mut console = BufferConsole::default();
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from([("install-git-hooks")].as_slice()),
+        );
+        assert!(result.is_ok(), "run_cli returned {result:?}");
+    }
+
+    let content = read_hook(&fs, PRE_COMMIT);
+    assert!(content.starts_with("#!/bin/sh\nnpm test\n"));
+    assert_eq!(content.matches("# >>> biome >>>").count(), 1);
+}
+
+#[test]
+fn removes_the_hook_and_keeps_the_other_commands() {
+    let mut fs = git_repository();
+    fs.insert(
+        Path::new(PRE_COMMIT).into(),
+        "#!/bin/sh\nnpm test\n".as_bytes(),
+    );
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("install-git-hooks")].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("install-git-hooks"), "--remove"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(read_hook(&fs, PRE_COMMIT), "#!/bin/sh\nnpm test\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "removes_the_hook_and_keeps_the_other_commands",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn fails_outside_of_a_git_repository() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("install-git-hooks")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "fails_outside_of_a_git_repository",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -15,10 +15,16 @@ use crate::configs::{
     CONFIG_LINTER_UPGRADE_DIAGNOSTIC, CONFIG_RECOMMENDED_GROUP,
 };
 use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
-use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
+use crate::{
+    assert_cli_snapshot, run_cli, run_cli_with_transport, FORMATTED, LINT_ERROR, PARSE_ERROR,
+};
+use biome_cli::SocketTransport;
 use biome_console::{markup, BufferConsole, LogLevel, MarkupBuf};
 use biome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem, OsFileSystem};
-use biome_service::DynRef;
+use biome_service::workspace::{TransportRequest, WorkspaceTransport};
+use biome_service::{DynRef, TransportError};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 const ERRORS: &str = r#"
 for(;true;);for(;true;);for(;true;);for(;true;);for(;true;);for(;true;);
@@ -1235,12 +1241,17 @@ fn max_diagnostics_default() {
 
     for msg in console.out_buffer {
         let MarkupBuf(nodes) = &msg.content;
-        let is_diagnostic = nodes.iter().any(|node| {
-            node.content.contains("useWhile")
-                || node.content.contains("useBlockStatements")
-                || node.content.contains("noConstantCondition")
-                || node.content.contains("lint")
-        });
+        // The summary of the diagnostics that weren't printed lists the rules
+        let is_summary = nodes
+            .iter()
+            .any(|node| node.content.contains("Diagnostics not shown"));
+        let is_diagnostic = !is_summary
+            && nodes.iter().any(|node| {
+                node.content.contains("useWhile")
+                    || node.content.contains("useBlockStatements")
+                    || node.content.contains("noConstantCondition")
+                    || node.content.contains("lint")
+            });
 
         if is_diagnostic {
             diagnostic_count += 1;
@@ -1291,12 +1302,17 @@ fn max_diagnostics() {
 
     for msg in console.out_buffer {
         let MarkupBuf(nodes) = &msg.content;
-        let is_diagnostic = nodes.iter().any(|node| {
-            node.content.contains("useWhile")
-                || node.content.contains("useBlockStatements")
-                || node.content.contains("noConstantCondition")
-                || node.content.contains("lint")
-        });
+        // The summary of the diagnostics that weren't printed lists the rules
+        let is_summary = nodes
+            .iter()
+            .any(|node| node.content.contains("Diagnostics not shown"));
+        let is_diagnostic = !is_summary
+            && nodes.iter().any(|node| {
+                node.content.contains("useWhile")
+                    || node.content.contains("useBlockStatements")
+                    || node.content.contains("noConstantCondition")
+                    || node.content.contains("lint")
+            });
 
         if is_diagnostic {
             diagnostic_count += 1;
@@ -1450,6 +1466,290 @@ fn suppression_syntax_error() {
     ));
 }
 
+#[test]
+fn requires_suppression_reasons() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "linter": {
+    "requireSuppressionReasons": true
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("check.js");
+    fs.insert(
+        file_path.into(),
+        *b"// biome-ignore lint/suspicious/noDebugger:\ndebugger;\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "requires_suppression_reasons",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppresses_rules_in_ranges() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(
+        file_path.into(),
+        r#"// biome-ignore-start lint/suspicious/noSelfCompare: comparisons of the tests
+x === x;
+x === x;
+// biome-ignore-end
+x === x;
+// biome-ignore-end
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppresses_rules_in_ranges",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn apply_unsafe_removes_unused_suppressions() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(
+        file_path.into(),
+        r#"// biome-ignore lint/suspicious/noDebugger: the debugger was removed
+console.log(1);
+function f() {
+    // biome-ignore lint/suspicious/noDebugger: the debugger was removed
+    return 1; // biome-ignore lint/suspicious/noDebugger: the debugger was removed
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--apply-unsafe",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "console.log(1);\nfunction f() {\n    return 1;\n}\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_unsafe_removes_unused_suppressions",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_adds_suppression_comments() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;\nx == 1;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--suppress",
+                "--reason=legacy code",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "// biome-ignore lint/suspicious/noDebugger: legacy code\ndebugger;\n// biome-ignore lint/suspicious/noDoubleEquals: legacy code\nx == 1;\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_adds_suppression_comments",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn write_baseline_records_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--write-baseline",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_records_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_reports_new_diagnostics_and_prunes_fixed_ones() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new(".biome-baseline.json").into(),
+        r#"{
+  "files": {
+    "check.js": {
+      "lint/suspicious/noDebugger:2e519d491d42a60d": 1,
+      "lint/suspicious/noDoubleEquals:c5c91d8c4e5dfe0c": 1
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;\nx === x;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--baseline",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_reports_new_diagnostics_and_prunes_fixed_ones",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_records_paths_relative_to_its_directory() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("project/biome.json").into(), "{}".as_bytes());
+    fs.insert(
+        Path::new("project/.biome-baseline.json").into(),
+        r#"{
+  "files": {
+    "src/check.js": {
+      "lint/suspicious/noDebugger:2e519d491d42a60d": 1
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("project/src/check.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--config-path=project",
+                "--baseline",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_records_paths_relative_to_its_directory",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn config_recommended_group() {
     let mut fs = MemoryFileSystem::default();
@@ -1632,31 +1932,33 @@ fn top_level_not_all_down_level_all() {
 }
 
 #[test]
-fn ignore_configured_globals() {
+fn top_level_all_down_level_off() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let rome_json = r#"{
-        "javascript": {
-            "globals": ["foo", "bar"]
-        },
+    let biome_json = r#"{
         "linter": {
             "rules": {
-                "correctness": {
-                    "noUndeclaredVariables": "error"
-                }
+                "all": true,
+                "style": "off"
             }
         }
     }"#;
 
+    // style/noArguments
+    // style/noShoutyConstants
     // style/useSingleVarDeclarator
-    let code = r#"foo.call(); bar.call();"#;
+    let code = r#"
+    function f() {arguments;}
+    const FOO = "FOO";
+    var x, y;
+    "#;
 
     let file_path = Path::new("fix.js");
     fs.insert(file_path.into(), code.as_bytes());
 
     let config_path = Path::new("biome.json");
-    fs.insert(config_path.into(), rome_json.as_bytes());
+    fs.insert(config_path.into(), biome_json.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
@@ -1668,7 +1970,7 @@ fn ignore_configured_globals() {
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "ignore_configured_globals",
+        "top_level_all_down_level_off",
         fs,
         console,
         result,
@@ -1676,18 +1978,108 @@ fn ignore_configured_globals() {
 }
 
 #[test]
-fn ignore_vcs_ignored_file() {
+fn top_level_not_all_down_level_warn() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let rome_json = r#"{
-        "vcs": {
-            "enabled": true,
-            "clientKind": "git",
-            "useIgnoreFile": true
-        }
-    }"#;
-
+    let biome_json = r#"{
+        "linter": {
+            "rules": {
+                "all": false,
+                "style": "warn"
+            }
+        }
+    }"#;
+
+    // style/noArguments
+    // style/noShoutyConstants
+    // style/useSingleVarDeclarator
+    let code = r#"
+    function f() {arguments;}
+    const FOO = "FOO";
+    var x, y;
+    "#;
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), code.as_bytes());
+
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), biome_json.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "top_level_not_all_down_level_warn",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignore_configured_globals() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let rome_json = r#"{
+        "javascript": {
+            "globals": ["foo", "bar"]
+        },
+        "linter": {
+            "rules": {
+                "correctness": {
+                    "noUndeclaredVariables": "error"
+                }
+            }
+        }
+    }"#;
+
+    // style/useSingleVarDeclarator
+    let code = r#"foo.call(); bar.call();"#;
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), code.as_bytes());
+
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), rome_json.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_configured_globals",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignore_vcs_ignored_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let rome_json = r#"{
+        "vcs": {
+            "enabled": true,
+            "clientKind": "git",
+            "useIgnoreFile": true
+        }
+    }"#;
+
     let git_ignore = r#"
 file2.js
 "#;
@@ -1733,6 +2125,67 @@ file2.js
     ));
 }
 
+#[test]
+fn ignore_vcs_ignored_file_of_detected_mercurial_repository() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let rome_json = r#"{
+        "vcs": {
+            "enabled": true,
+            "useIgnoreFile": true
+        }
+    }"#;
+
+    let hg_ignore = r#"syntax: glob
+file2.js
+"#;
+
+    let code2 = r#"foo.call(); bar.call();"#;
+    let code1 = r#"array.map(sentence => sentence.split(' ')).flat();"#;
+
+    // ignored files
+    let file_path1 = Path::new("file1.js");
+    fs.insert(file_path1.into(), code1.as_bytes());
+    let file_path2 = Path::new("file2.js");
+    fs.insert(file_path2.into(), code2.as_bytes());
+
+    // configuration
+    let config_path = Path::new("biome.json");
+    fs.insert(config_path.into(), rome_json.as_bytes());
+
+    // mercurial folder
+    let hg_folder = Path::new(".hg");
+    fs.insert(hg_folder.into(), "".as_bytes());
+
+    // mercurial ignore file
+    let ignore_file = Path::new(".hgignore");
+    fs.insert(ignore_file.into(), hg_ignore.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignore_vcs_ignored_file_of_detected_mercurial_repository",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn ignore_vcs_os_independent_parse() {
     let mut fs = MemoryFileSystem::default();
@@ -2328,3 +2781,665 @@ A = 0;
         result,
     ));
 }
+
+#[test]
+fn lint_only_selected_rules() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+        "recommended": true,
+        "suspicious": {
+            "noDebugger": "off"
+        }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"debugger;
+if (a == b) {}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--rule=suspicious/noDebugger",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_only_selected_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_unknown_selected_rule() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--rule=suspicious/noUnknownRule",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_unknown_selected_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn apply_only_fixes_of_selected_rules() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"let a = 1;
+debugger;
+console.log(a);
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--apply-unsafe",
+                "--only=style/useConst",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"const a = 1;
+debugger;
+console.log(a);
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_only_fixes_of_selected_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn apply_unsafe_fix_configured_as_safe() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noDebugger": {
+          "level": "error",
+          "fix": "safe"
+        }
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\nconsole.log(1);\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--apply", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "console.log(1);\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_unsafe_fix_configured_as_safe",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn dont_apply_safe_fix_configured_as_unsafe() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+      "suspicious": {
+        "noCompareNegZero": {
+          "level": "error",
+          "fix": "unsafe"
+        }
+      }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), FIX_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--apply", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FIX_BEFORE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "dont_apply_safe_fix_configured_as_unsafe",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn only_requires_apply() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "let a = 1;\nconsole.log(a);\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--only=style/useConst",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "only_requires_apply",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn min_severity_warn_errors_on_warnings() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+        "recommended": true,
+        "suspicious": {
+            "noClassAssign": "warn"
+        }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "class A {};\nA = 0;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--min-severity=warn",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(result.as_ref().unwrap_err().exit_code(), 1);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "min_severity_warn_errors_on_warnings",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn min_severity_error_ignores_warnings() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+        "recommended": true,
+        "suspicious": {
+            "noClassAssign": "warn"
+        }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "class A {};\nA = 0;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--min-severity=error",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "min_severity_error_ignores_warnings",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rule_level_info_reports_information() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+        "recommended": true,
+        "suspicious": {
+            "noClassAssign": "info"
+        }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "class A {};\nA = 0;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rule_level_info_reports_information",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn rule_level_hint_is_not_printed_by_default() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+        "recommended": true,
+        "suspicious": {
+            "noClassAssign": "hint"
+        }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "class A {};\nA = 0;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "rule_level_hint_is_not_printed_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn min_severity_hint_errors_on_hints() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+        "recommended": true,
+        "suspicious": {
+            "noClassAssign": "hint"
+        }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "class A {};\nA = 0;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--min-severity=hint",
+                "--diagnostic-level=hint",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(result.as_ref().unwrap_err().exit_code(), 1);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "min_severity_hint_errors_on_hints",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn exits_with_a_distinct_code_for_configuration_errors() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "linter": { "enabled": "yes" } }"#.as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(result.unwrap_err().exit_code(), 2);
+}
+
+/// A transport that loses the connection to the workspace when the settings are updated
+struct DisconnectedTransport(SocketTransport);
+
+impl WorkspaceTransport for DisconnectedTransport {
+    fn request<P, R>(&self, request: TransportRequest<P>) -> Result<R, TransportError>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        if request.method == "biome/update_settings" {
+            return Err(TransportError::ChannelClosed);
+        }
+        self.0.request(request)
+    }
+}
+
+#[test]
+fn exits_with_a_distinct_code_for_internal_errors() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli_with_transport(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+        DisconnectedTransport,
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(result.unwrap_err().exit_code(), 3);
+}
+
+#[test]
+fn max_diagnostics_per_severity() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "linter": {
+    "rules": {
+        "recommended": true,
+        "suspicious": {
+            "noDebugger": "warn"
+        }
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\ndebugger;\ndebugger;\nfor(;true;);\nfor(;true;);\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--max-diagnostics=error=2,warn=1"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "max_diagnostics_per_severity",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn max_diagnostics_invalid_severity() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--max-diagnostics=fatal=2"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "max_diagnostics_invalid_severity",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn show_rule_timings() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--show-rule-timings"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // The timings change on every run, so they can't be part of a snapshot
+    let timings = console
+        .out_buffer
+        .iter()
+        .filter(|message| message.level == LogLevel::Log)
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .find(|content| content.starts_with("Rule timings"))
+        .expect("the rule timings weren't printed");
+    assert!(timings.contains("lint/suspicious/noDebugger"), "{timings}");
+}
@@ -26,3 +26,34 @@ fn lsp_proxy_help() {
         result,
     ));
 }
+
+#[test]
+fn lsp_proxy_tcp_and_pipe_are_exclusive() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lsp-proxy"),
+                "--tcp",
+                "127.0.0.1:4000",
+                "--pipe",
+                "biome-lsp",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lsp_proxy_tcp_and_pipe_are_exclusive",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -132,3 +132,42 @@ fn should_create_biome_json_file() {
         result,
     ));
 }
+
+#[test]
+fn replaces_unknown_names_with_suggestions() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{
+  "formater": { "indentStyle": "spaces" },
+  "linter": { "rules": { "correctness": { "noUnusedVarables": "error", "foo": "off" } } }
+}"#;
+
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        configuration_path,
+        r#"{
+  "formatter": { "indentStyle": "space" },
+  "linter": { "rules": { "correctness": { "noUnusedVariables": "error", "foo": "off" } } }
+}"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "replaces_unknown_names_with_suggestions",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,158 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn migrate_eslint_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("eslint"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_eslint_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_eslintrc() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "enabled": true } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new(".eslintrc.json").into(),
+        r#"{
+    // The configuration of the project
+    "extends": ["eslint:recommended"],
+    "env": { "browser": true },
+    "globals": { "jQuery": "readonly", "legacy": "off" },
+    "ignorePatterns": ["dist/", "!dist/keep.js"],
+    "rules": {
+        "eqeqeq": "error",
+        "no-debugger": 1,
+        "no-alert": "error",
+        "no-unused-vars": ["error", { "args": "none" }],
+        "no-restricted-globals": ["error", "event", { "name": "fdescribe" }],
+    },
+    "overrides": [
+        {
+            "files": ["*.test.js"],
+            "excludedFiles": "*.e2e.test.js",
+            "rules": { "no-console": "off" }
+        }
+    ]
+}"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("eslint")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_eslintrc",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_eslintrc_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new(".eslintrc").into(),
+        r#"{ "rules": { "no-var": "error", "prefer-const": "warn" } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("eslint"), ("--write")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(fs.open(Path::new("biome.json")).is_ok());
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_eslintrc_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_eslint_package_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "name": "test", "eslintConfig": { "rules": { "no-debugger": "error" } } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("eslint"), ("--write")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_eslint_package_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_eslint_missing_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("eslint")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_eslint_missing_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,155 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystemExt, MemoryFileSystem};
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn migrate_prettier_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("prettier"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_prettier_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_prettierrc() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "formatter": { "enabled": true } }"#.as_bytes(),
+    );
+    fs.insert(
+        Path::new(".prettierrc").into(),
+        r#"{
+    "useTabs": true,
+    "printWidth": 100,
+    "singleQuote": true,
+    "semi": false,
+    "trailingComma": "es5",
+    "proseWrap": "always",
+    "overrides": [
+        {
+            "files": ["*.json"],
+            "options": { "tabWidth": 4 }
+        }
+    ]
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new(".prettierignore").into(),
+        "# Build output\ndist\n!dist/keep.js\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("prettier")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_prettierrc",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_prettierrc_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new(".prettierrc.json").into(),
+        r#"{ "tabWidth": 4, "arrowParens": "avoid" }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("prettier"), ("--write")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(fs.open(Path::new("biome.json")).is_ok());
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_prettierrc_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_prettier_package_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "name": "test", "prettier": { "bracketSpacing": false } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("prettier"), ("--write")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_prettier_package_json",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_prettier_missing_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), ("prettier")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_prettier_missing_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
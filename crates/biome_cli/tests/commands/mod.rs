@@ -1,9 +1,16 @@
 mod check;
 mod ci;
+mod clean;
+mod completions;
+mod doctor;
+mod explain;
 mod format;
 mod init;
+mod install_git_hooks;
 mod lint;
 mod lsp_proxy;
 mod migrate;
+mod migrate_eslint;
+mod migrate_prettier;
 mod rage;
 mod version;
@@ -227,19 +227,19 @@ fn assert_rage_snapshot(payload: SnapshotPayload<'_>) {
     });
 }
 
-/// Mutex to guarantee that the `rage` tests run sequentially. Necessary to avoid race conditions
-/// when reading the server logs.
+/// Mutex to guarantee that the tests reading the server logs, like the `rage` tests, run
+/// sequentially. Necessary to avoid race conditions when reading the server logs.
 static RAGE_GUARD: Mutex<()> = Mutex::new(());
 
 /// Mocks out the directory from which `rage` reads the server logs. Ensures that the test directory
 /// gets removed at the end of the test.
-struct TestLogDir {
-    path: PathBuf,
+pub(crate) struct TestLogDir {
+    pub(crate) path: PathBuf,
     _guard: MutexGuard<'static, ()>,
 }
 
 impl TestLogDir {
-    fn new(name: &str) -> Self {
+    pub(crate) fn new(name: &str) -> Self {
         let guard = RAGE_GUARD.lock().unwrap();
         let path = env::temp_dir().join(name);
 
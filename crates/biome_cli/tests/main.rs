@@ -376,6 +376,24 @@ pub(crate) fn run_cli<'app>(
     console: &'app mut dyn Console,
     args: bpaf::Args,
 ) -> Result<(), CliDiagnostic> {
+    run_cli_with_transport(fs, console, args, |transport| transport)
+}
+
+/// Like [run_cli], with a transport of the workspace wrapped by `wrap_transport`, to change the
+/// responses of the workspace
+pub(crate) fn run_cli_with_transport<'app, T>(
+    fs: DynRef<'app, dyn FileSystem>,
+    console: &'app mut dyn Console,
+    args: bpaf::Args,
+    wrap_transport: impl FnOnce(biome_cli::SocketTransport) -> T,
+) -> Result<(), CliDiagnostic>
+where
+    T: biome_service::workspace::WorkspaceTransport
+        + std::panic::RefUnwindSafe
+        + Send
+        + Sync
+        + 'static,
+{
     use biome_cli::SocketTransport;
     use biome_lsp::ServerFactory;
     use biome_service::{workspace, WorkspaceRef};
@@ -396,7 +414,7 @@ pub(crate) fn run_cli<'app>(
     let (client_read, client_write) = split(client);
     let transport = SocketTransport::open(runtime, client_read, client_write);
 
-    let workspace = workspace::client(transport).unwrap();
+    let workspace = workspace::client(wrap_transport(transport)).unwrap();
     let app = App::new(fs, console, WorkspaceRef::Owned(workspace));
 
     let mut session = CliSession { app };
@@ -1,5 +1,5 @@
 use std::io;
-use std::io::{stderr, stdin, stdout, IsTerminal, Read, Stdin, Write};
+use std::io::{stderr, stdin, stdout, BufRead, IsTerminal, Read, Stdin, Write};
 use std::panic::RefUnwindSafe;
 use termcolor::{ColorChoice, StandardStream};
 use write::Termcolor;
@@ -36,6 +36,14 @@ pub trait Console: Send + Sync + RefUnwindSafe {
 
     /// It reads from a source, and if this source contains something, it's converted into a [String]
     fn read(&mut self) -> Option<String>;
+
+    /// It reads a line from a source, without the line break. It returns [None] when the
+    /// source doesn't contain anything left to read.
+    fn read_line(&mut self) -> Option<String>;
+
+    /// It reads exactly `len` bytes from a source. It returns [None] when the source ends
+    /// before, or when the bytes aren't valid UTF-8.
+    fn read_exact(&mut self, len: usize) -> Option<String>;
 }
 
 /// Extension trait for [Console] providing convenience printing methods
@@ -181,13 +189,46 @@ impl Console for EnvConsole {
             None
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // A prompt printed before reading doesn't end with a line break
+        let _ = self.out.flush();
+        let mut buffer = String::new();
+        match self.r#in.lock().read_line(&mut buffer) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(buffer.trim_end_matches(['\r', '\n']).to_string()),
+        }
+    }
+
+    fn read_exact(&mut self, len: usize) -> Option<String> {
+        let _ = self.out.flush();
+        let mut buffer = vec![0; len];
+        self.r#in.lock().read_exact(&mut buffer).ok()?;
+        String::from_utf8(buffer).ok()
+    }
 }
 
 /// Implementation of [Console] storing all printed messages to a memory buffer
 #[derive(Default, Debug)]
 pub struct BufferConsole {
     pub out_buffer: Vec<Message>,
+    /// The lines read by [Console::read_line] and [Console::read_exact], which read them as
+    /// if each line ended with a line break
     pub in_buffer: Vec<String>,
+    /// The number of bytes of `in_buffer` already read
+    in_cursor: usize,
+}
+
+impl BufferConsole {
+    /// Returns the bytes of `in_buffer` that weren't read yet
+    fn unread_input(&self) -> String {
+        let input: String = self
+            .in_buffer
+            .iter()
+            .flat_map(|line| [line.as_str(), "\n"])
+            .collect();
+        input[self.in_cursor.min(input.len())..].to_string()
+    }
 }
 
 /// Individual message entry printed to a [BufferConsole]
@@ -220,6 +261,20 @@ impl Console for BufferConsole {
             Some(self.in_buffer[0].clone())
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        let input = self.unread_input();
+        let line = input.split_inclusive('\n').next()?;
+        self.in_cursor += line.len();
+        Some(line.trim_end_matches(['\r', '\n']).to_string())
+    }
+
+    fn read_exact(&mut self, len: usize) -> Option<String> {
+        let input = self.unread_input();
+        let content = input.as_bytes().get(..len)?;
+        self.in_cursor += len;
+        String::from_utf8(content.to_vec()).ok()
+    }
 }
 
 /// A horizontal line with the given print width
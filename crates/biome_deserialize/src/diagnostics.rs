@@ -1,3 +1,4 @@
+use crate::find_similar_name;
 use biome_console::fmt::Display;
 use biome_console::{markup, MarkupBuf};
 use biome_diagnostics::location::AsSpan;
@@ -59,6 +60,8 @@ pub struct DeserializationDiagnostic {
     severity: Severity,
     #[tags]
     tags: DiagnosticTags,
+    /// A known name that likely is the intended one
+    suggestion: Option<String>,
 }
 
 impl DeserializationDiagnostic {
@@ -69,6 +72,7 @@ impl DeserializationDiagnostic {
             deserialization_advice: DeserializationAdvice::default(),
             severity: Severity::Error,
             tags: DiagnosticTags::empty(),
+            suggestion: None,
         }
     }
 
@@ -114,9 +118,15 @@ impl DeserializationDiagnostic {
 
     /// Emitted when there's an unknown key, against a set of known ones
     pub fn new_unknown_key(key_name: &str, range: impl AsSpan, allowed_keys: &[&str]) -> Self {
-        Self::new(markup!("Found an unknown key `"<Emphasis>{key_name}</Emphasis>"`." ))
-            .with_range(range)
-            .note_with_list("Accepted keys", allowed_keys)
+        match find_similar_name(key_name, allowed_keys) {
+            Some(suggestion) => Self::new(markup!(
+                "Found an unknown key `"<Emphasis>{key_name}</Emphasis>"`, did you mean `"<Emphasis>{suggestion}</Emphasis>"`?"
+            ))
+            .with_suggestion(suggestion),
+            None => Self::new(markup!("Found an unknown key `"<Emphasis>{key_name}</Emphasis>"`." )),
+        }
+        .with_range(range)
+        .note_with_list("Accepted keys", allowed_keys)
     }
 
     /// Emitted when there's an unknown value, against a set of known ones
@@ -125,9 +135,15 @@ impl DeserializationDiagnostic {
         range: impl AsSpan,
         allowed_variants: &[&str],
     ) -> Self {
-        Self::new(markup! {"Found an unknown value `"<Emphasis>{variant_name}</Emphasis>"`."})
-            .with_range(range)
-            .note_with_list("Accepted values:", allowed_variants)
+        match find_similar_name(variant_name, allowed_variants) {
+            Some(suggestion) => Self::new(markup! {
+                "Found an unknown value `"<Emphasis>{variant_name}</Emphasis>"`, did you mean `"<Emphasis>{suggestion}</Emphasis>"`?"
+            })
+            .with_suggestion(suggestion),
+            None => Self::new(markup! {"Found an unknown value `"<Emphasis>{variant_name}</Emphasis>"`."}),
+        }
+        .with_range(range)
+        .note_with_list("Accepted values:", allowed_variants)
     }
 
     /// Emitted when there's a deprecated property
@@ -145,6 +161,17 @@ impl DeserializationDiagnostic {
         self
     }
 
+    /// Sets the known name that should replace the unknown one found at the range of the diagnostic
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Returns the range of the unknown name and the known name that should replace it
+    pub fn suggestion(&self) -> Option<(TextRange, &str)> {
+        Some((self.range?, self.suggestion.as_deref()?))
+    }
+
     /// Adds a note to the diagnostic
     pub fn with_note(mut self, message: impl Display) -> Self {
         self.deserialization_advice
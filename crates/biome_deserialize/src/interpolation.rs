@@ -0,0 +1,88 @@
+//! Interpolation of the variables referenced by the strings of a configuration.
+//!
+//! A string references the environment variable `NAME` with `${env:NAME}`, and any other
+//! variable with `${name}`. The values of the variables are provided by the caller.
+
+use crate::DeserializationDiagnostic;
+use biome_console::markup;
+
+/// A variable referenced by a string
+#[derive(Debug, Eq, PartialEq)]
+pub enum Variable<'a> {
+    /// `${env:NAME}`, the environment variable `NAME`
+    Env(&'a str),
+    /// `${name}`, a variable provided by the caller
+    Named(&'a str),
+}
+
+/// Replaces the variables referenced by `text` with the values returned by `resolve`.
+///
+/// It returns a diagnostic when `resolve` doesn't know a variable, or when a reference isn't
+/// closed by `}`.
+pub fn interpolate(
+    text: &str,
+    resolve: impl Fn(&Variable) -> Option<String>,
+) -> Result<String, DeserializationDiagnostic> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            return Err(DeserializationDiagnostic::new(markup! {
+                "The variable referenced by "<Emphasis>{text}</Emphasis>" isn't closed by "<Emphasis>"}"</Emphasis>"."
+            }));
+        };
+        let name = &rest[start + 2..start + end];
+        let variable = match name.strip_prefix("env:") {
+            Some(name) => Variable::Env(name),
+            None => Variable::Named(name),
+        };
+        let Some(value) = resolve(&variable) else {
+            return Err(match variable {
+                Variable::Env(name) => DeserializationDiagnostic::new(markup! {
+                    "The environment variable "<Emphasis>{name}</Emphasis>" referenced by "<Emphasis>{text}</Emphasis>" isn't set."
+                }),
+                Variable::Named(name) => DeserializationDiagnostic::new(markup! {
+                    "The variable "<Emphasis>{name}</Emphasis>" referenced by "<Emphasis>{text}</Emphasis>" doesn't exist."
+                }),
+            });
+        };
+        result.push_str(&value);
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{interpolate, Variable};
+
+    fn resolve(variable: &Variable) -> Option<String> {
+        match variable {
+            Variable::Env("HOME") => Some("/home/user".to_string()),
+            Variable::Named("workspaceRoot") => Some("/workspace".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn replaces_the_variables() {
+        assert_eq!(
+            interpolate("${workspaceRoot}/dist/**", resolve).unwrap(),
+            "/workspace/dist/**"
+        );
+        assert_eq!(
+            interpolate("${env:HOME}/${workspaceRoot}", resolve).unwrap(),
+            "/home/user//workspace"
+        );
+        assert_eq!(interpolate("src/**", resolve).unwrap(), "src/**");
+    }
+
+    #[test]
+    fn reports_unknown_variables() {
+        assert!(interpolate("${env:UNSET}/dist", resolve).is_err());
+        assert!(interpolate("${root}/dist", resolve).is_err());
+        assert!(interpolate("${workspaceRoot/dist", resolve).is_err());
+    }
+}
@@ -31,14 +31,17 @@
 //!
 mod diagnostics;
 mod impls;
+pub mod interpolation;
 pub mod json;
 pub mod string_set;
+mod suggestion;
 use biome_diagnostics::{Error, Severity};
 use biome_rowan::TextRange;
 pub use diagnostics::{DeserializationAdvice, DeserializationDiagnostic, VisitableType};
 pub use impls::*;
 use std::fmt::Debug;
 pub use string_set::StringSet;
+pub use suggestion::find_similar_name;
 
 /// Implemented by data structures that can deserialize any [DeserializableValue].
 ///
@@ -447,4 +450,12 @@ impl<T> Deserialized<T> {
     pub fn consume(self) -> (Option<T>, Vec<Error>) {
         (self.deserialized, self.diagnostics)
     }
+
+    /// Maps the deserialized result with `f`, keeping the diagnostics.
+    pub fn and_then<U>(self, f: impl FnOnce(T) -> Option<U>) -> Deserialized<U> {
+        Deserialized {
+            diagnostics: self.diagnostics,
+            deserialized: self.deserialized.and_then(f),
+        }
+    }
 }
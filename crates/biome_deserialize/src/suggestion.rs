@@ -0,0 +1,68 @@
+//! Suggestions of known names for the unknown names found in a configuration.
+
+/// Returns the name of `candidates` that is the closest to `name`, if it's close enough to be
+/// a likely typo of `name`.
+///
+/// The distance between two names is their edit distance, ignoring the case. A name is close
+/// enough when at most one character out of three differs.
+pub fn find_similar_name<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let name = name.to_lowercase();
+    candidates
+        .iter()
+        .filter_map(|candidate| {
+            let distance = edit_distance(&name, &candidate.to_lowercase());
+            let max_distance = (name.chars().count().max(candidate.chars().count()) / 3).max(1);
+            (distance <= max_distance).then_some((distance, *candidate))
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Computes the Levenshtein distance between `left` and `right`
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right: Vec<_> = right.chars().collect();
+    // Distances between the prefix of `left` read so far and each prefix of `right`
+    let mut distances: Vec<_> = (0..=right.len()).collect();
+    for (i, left_char) in left.chars().enumerate() {
+        let mut previous_diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous_diagonal + usize::from(left_char != *right_char);
+            previous_diagonal = distances[j + 1];
+            distances[j + 1] = substitution
+                .min(distances[j] + 1)
+                .min(previous_diagonal + 1);
+        }
+    }
+    distances[right.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("flaw", "lawn"), 2);
+    }
+
+    #[test]
+    fn finds_similar_names() {
+        let candidates = &["noUnusedVariables", "noUnusedLabels", "noVar"];
+        assert_eq!(
+            find_similar_name("noUnusedVarables", candidates),
+            Some("noUnusedVariables")
+        );
+        assert_eq!(
+            find_similar_name("nounusedlabels", candidates),
+            Some("noUnusedLabels")
+        );
+        assert_eq!(find_similar_name("noVars", candidates), Some("noVar"));
+        assert_eq!(find_similar_name("useConst", candidates), None);
+        assert_eq!(find_similar_name("", candidates), None);
+    }
+}
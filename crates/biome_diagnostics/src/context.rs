@@ -1,10 +1,13 @@
+use std::io;
+
 use biome_console::fmt;
 
 use crate::context::internal::{SeverityDiagnostic, TagsDiagnostic};
 use crate::{
     diagnostic::internal::AsDiagnostic,
     location::{AsResource, AsSourceCode, AsSpan},
-    Category, DiagnosticTags, Error, Resource, Severity, SourceCode,
+    Advices, Category, Diagnostic, DiagnosticTags, Error, Location, Resource, Severity, SourceCode,
+    Visit,
 };
 
 /// This trait is implemented for all types implementing [Diagnostic](super::Diagnostic)
@@ -44,6 +47,17 @@ pub trait DiagnosticExt: internal::Sealed + Sized {
     where
         Error: From<internal::FileSourceCodeDiagnostic<Self>>;
 
+    /// Returns a new diagnostic using the provided `source_code` for the code
+    /// frames of its advices that point to the file at `path`, when they're
+    /// not the file of `self` and don't already have a source code.
+    fn with_related_source_code(
+        self,
+        path: impl Into<String>,
+        source_code: impl AsSourceCode,
+    ) -> Error
+    where
+        Error: From<internal::RelatedSourceCodeDiagnostic<Self>>;
+
     /// Returns a new diagnostic with additional `tags`
     fn with_tags(self, tags: DiagnosticTags) -> Error
     where
@@ -110,6 +124,21 @@ impl<E: AsDiagnostic> DiagnosticExt for E {
         })
     }
 
+    fn with_related_source_code(
+        self,
+        path: impl Into<String>,
+        source_code: impl AsSourceCode,
+    ) -> Error
+    where
+        Error: From<internal::RelatedSourceCodeDiagnostic<Self>>,
+    {
+        Error::from(internal::RelatedSourceCodeDiagnostic {
+            path: path.into(),
+            source_code: source_code.as_source_code().map(SourceCode::to_owned),
+            source: self,
+        })
+    }
+
     fn with_tags(self, tags: DiagnosticTags) -> Error
     where
         Error: From<internal::TagsDiagnostic<Self>>,
@@ -241,6 +270,42 @@ impl<T, E: AsDiagnostic> Context<T, E> for Result<T, E> {
     }
 }
 
+/// Returns the paths of the files, other than the file of `diagnostic`, that
+/// the code frames of its advices point to without a source code. Their source
+/// code can be provided with [DiagnosticExt::with_related_source_code].
+pub fn related_file_paths(diagnostic: &dyn Diagnostic) -> Vec<String> {
+    let mut visitor = RelatedFilesVisitor {
+        resource: diagnostic.location().resource,
+        paths: Vec::new(),
+    };
+
+    // Collecting the paths never fails
+    let _ = diagnostic.advices(&mut visitor);
+    let _ = diagnostic.verbose_advices(&mut visitor);
+
+    visitor.paths
+}
+
+struct RelatedFilesVisitor<'a> {
+    resource: Option<Resource<&'a str>>,
+    paths: Vec<String>,
+}
+
+impl Visit for RelatedFilesVisitor<'_> {
+    fn record_frame(&mut self, location: Location<'_>) -> io::Result<()> {
+        if let (Some(Resource::File(path)), None) = (location.resource, location.source_code) {
+            if self.resource != location.resource && !self.paths.iter().any(|item| item == path) {
+                self.paths.push(path.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn record_group(&mut self, _title: &dyn fmt::Display, advice: &dyn Advices) -> io::Result<()> {
+        advice.record(self)
+    }
+}
+
 mod internal {
     //! These types need to be declared as public as they're referred to in the
     //! `where` clause of other public items, but as they're not part of the
@@ -574,6 +639,8 @@ mod internal {
             if let Some(source_code) = &self.source_code {
                 let mut visitor = FileSourceCodeVisitor {
                     visitor,
+                    resource: self.source.as_diagnostic().location().resource,
+                    is_diagnostic_file: true,
                     source_code: source_code.as_deref(),
                 };
 
@@ -587,6 +654,8 @@ mod internal {
             if let Some(source_code) = &self.source_code {
                 let mut visitor = FileSourceCodeVisitor {
                     visitor,
+                    resource: self.source.as_diagnostic().location().resource,
+                    is_diagnostic_file: true,
                     source_code: source_code.as_deref(),
                 };
 
@@ -611,10 +680,90 @@ mod internal {
         }
     }
 
+    /// Diagnostic type returned by [super::DiagnosticExt::with_related_source_code],
+    /// uses `source_code` in the code frames of the advices of `source` that
+    /// point to the file at `path`.
+    pub struct RelatedSourceCodeDiagnostic<E> {
+        pub(super) path: String,
+        pub(super) source_code: Option<SourceCode<String, LineIndexBuf>>,
+        pub(super) source: E,
+    }
+
+    impl<E: Debug> Debug for RelatedSourceCodeDiagnostic<E> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Diagnostic")
+                .field("path", &self.path)
+                .field("source_code", &self.source_code)
+                .field("source", &self.source)
+                .finish()
+        }
+    }
+
+    impl<E: AsDiagnostic> Diagnostic for RelatedSourceCodeDiagnostic<E> {
+        fn category(&self) -> Option<&'static Category> {
+            self.source.as_diagnostic().category()
+        }
+
+        fn severity(&self) -> Severity {
+            self.source.as_diagnostic().severity()
+        }
+
+        fn description(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.source.as_diagnostic().description(fmt)
+        }
+
+        fn message(&self, fmt: &mut fmt::Formatter<'_>) -> io::Result<()> {
+            self.source.as_diagnostic().message(fmt)
+        }
+
+        fn advices(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+            if let Some(source_code) = &self.source_code {
+                let mut visitor = FileSourceCodeVisitor {
+                    visitor,
+                    resource: Some(Resource::File(&self.path)),
+                    is_diagnostic_file: false,
+                    source_code: source_code.as_deref(),
+                };
+
+                self.source.as_diagnostic().advices(&mut visitor)
+            } else {
+                self.source.as_diagnostic().advices(visitor)
+            }
+        }
+
+        fn verbose_advices(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+            if let Some(source_code) = &self.source_code {
+                let mut visitor = FileSourceCodeVisitor {
+                    visitor,
+                    resource: Some(Resource::File(&self.path)),
+                    is_diagnostic_file: false,
+                    source_code: source_code.as_deref(),
+                };
+
+                self.source.as_diagnostic().verbose_advices(&mut visitor)
+            } else {
+                self.source.as_diagnostic().verbose_advices(visitor)
+            }
+        }
+
+        fn location(&self) -> Location<'_> {
+            self.source.as_diagnostic().location()
+        }
+
+        fn tags(&self) -> DiagnosticTags {
+            self.source.as_diagnostic().tags()
+        }
+    }
+
     /// Helper wrapper for a [Visitor], automatically inject `source_code` into
-    /// the location of code frame advices if they don't have one already.
+    /// the location of code frame advices pointing to `resource` if they don't
+    /// have one already. When `is_diagnostic_file` is true, `resource` is the
+    /// file of the diagnostic, and the code frames without a resource point to
+    /// it too.
     struct FileSourceCodeVisitor<'a> {
         visitor: &'a mut dyn Visit,
+        resource: Option<Resource<&'a str>>,
+        is_diagnostic_file: bool,
         source_code: SourceCode<&'a str, &'a LineIndex>,
     }
 
@@ -628,6 +777,15 @@ mod internal {
         }
 
         fn record_frame(&mut self, location: Location<'_>) -> io::Result<()> {
+            let is_same_file = match location.resource {
+                Some(resource) => self.resource == Some(resource),
+                None => self.is_diagnostic_file,
+            };
+
+            if !is_same_file {
+                return self.visitor.record_frame(location);
+            }
+
             self.visitor.record_frame(Location {
                 source_code: Some(location.source_code.unwrap_or(self.source_code)),
                 ..location
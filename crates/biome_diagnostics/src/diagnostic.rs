@@ -137,12 +137,12 @@ impl FromStr for Severity {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "hint" => Ok(Self::Information),
+            "hint" => Ok(Self::Hint),
             "info" => Ok(Self::Information),
             "warn" => Ok(Self::Warning),
             "error" => Ok(Self::Error),
             v => Err(format!(
-                "Found unexpected value ({}), valid values are: info, warn, error.",
+                "Found unexpected value ({}), valid values are: hint, info, warn, error.",
                 v
             )),
         }
@@ -152,7 +152,7 @@ impl FromStr for Severity {
 impl Display for Severity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Hint => write!(f, "info"),
+            Self::Hint => write!(f, "hint"),
             Self::Information => write!(f, "info"),
             Self::Warning => write!(f, "warn"),
             Self::Error => write!(f, "error"),
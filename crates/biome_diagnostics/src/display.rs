@@ -9,13 +9,13 @@ mod diff;
 pub(super) mod frame;
 mod message;
 
-use crate::display::frame::SourceFile;
 use crate::{
     diagnostic::internal::AsDiagnostic, Advices, Diagnostic, DiagnosticTags, Location, LogCategory,
     Resource, Severity, Visit,
 };
 
 pub use self::backtrace::{set_bottom_frame, Backtrace};
+pub use self::frame::{OneIndexed, SourceFile, SourceLocation};
 pub use self::message::MessageAndDescription;
 
 /// Helper struct from printing the description of a diagnostic into any
@@ -66,7 +66,7 @@ impl<'fmt, D: AsDiagnostic + ?Sized> fmt::Display for PrintDiagnostic<'fmt, D> {
         // Wrap the formatter with an indentation level and print the advices
         let mut slot = None;
         let mut fmt = IndentWriter::wrap(fmt, &mut slot, true, "  ");
-        let mut visitor = PrintAdvices(&mut fmt);
+        let mut visitor = PrintAdvices(&mut fmt, diagnostic.location().resource);
 
         print_advices(&mut visitor, diagnostic, self.verbose)
     }
@@ -327,8 +327,9 @@ impl<'fmt, D: Diagnostic + ?Sized> fmt::Display for PrintCauseChain<'fmt, D> {
     }
 }
 
-/// Implementation of [Visitor] that prints the advices for a diagnostic.
-struct PrintAdvices<'a, 'b>(&'a mut fmt::Formatter<'b>);
+/// Implementation of [Visitor] that prints the advices for a diagnostic, the
+/// second field is the file of the diagnostic.
+struct PrintAdvices<'a, 'b>(&'a mut fmt::Formatter<'b>, Option<Resource<&'a str>>);
 
 impl PrintAdvices<'_, '_> {
     fn print_log(
@@ -382,6 +383,27 @@ impl Visit for PrintAdvices<'_, '_> {
     }
 
     fn record_frame(&mut self, location: Location<'_>) -> io::Result<()> {
+        // Print the path of the code frames that point to another file than
+        // the one of the diagnostic, and their position if it can be resolved
+        if let Some(Resource::File(path)) = location.resource {
+            if location.resource != self.1 {
+                self.0.write_markup(markup! {
+                    <Emphasis>{path}</Emphasis>
+                })?;
+
+                if let (Some(span), Some(source_code)) = (location.span, location.source_code) {
+                    let file = SourceFile::new(source_code);
+                    if let Ok(position) = file.location(span.start()) {
+                        self.0.write_markup(markup! {
+                            <Emphasis>":"{position.line_number.get()}":"{position.column_number.get()}</Emphasis>
+                        })?;
+                    }
+                }
+
+                self.0.write_str("\n\n")?;
+            }
+        }
+
         frame::print_frame(self.0, location)
     }
 
@@ -419,7 +441,7 @@ impl Visit for PrintAdvices<'_, '_> {
 
         let mut slot = None;
         let mut fmt = IndentWriter::wrap(self.0, &mut slot, true, "  ");
-        let mut visitor = PrintAdvices(&mut fmt);
+        let mut visitor = PrintAdvices(&mut fmt, self.1);
         advice.record(&mut visitor)
     }
 }
@@ -573,7 +595,8 @@ mod tests {
 
     use crate::{self as biome_diagnostics};
     use crate::{
-        Advices, Diagnostic, Location, LogCategory, PrintDiagnostic, Resource, SourceCode, Visit,
+        related_file_paths, Advices, Diagnostic, DiagnosticExt, Location, LogCategory,
+        PrintDiagnostic, Resource, SourceCode, Visit,
     };
 
     #[derive(Debug)]
@@ -583,7 +606,7 @@ mod tests {
         source_code: Option<String>,
         advice: Option<A>,
         verbose_advice: Option<A>,
-        source: Option<Box<dyn Diagnostic>>,
+        source: Option<Box<dyn Diagnostic + Send + Sync>>,
     }
 
     impl<A> TestDiagnostic<A> {
@@ -656,7 +679,8 @@ mod tests {
         }
 
         fn source(&self) -> Option<&dyn Diagnostic> {
-            self.source.as_deref()
+            let source = self.source.as_deref()?;
+            Some(source)
         }
     }
 
@@ -697,6 +721,19 @@ mod tests {
         }
     }
 
+    #[derive(Debug)]
+    struct RelatedFrameAdvice;
+
+    impl Advices for RelatedFrameAdvice {
+        fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+            visitor.record_frame(Location {
+                resource: Some(Resource::File("other_path")),
+                span: Some(TextRange::new(TextSize::from(16), TextSize::from(24))),
+                source_code: None,
+            })
+        }
+    }
+
     #[derive(Debug)]
     struct DiffAdvice;
 
@@ -839,6 +876,8 @@ mod tests {
             "\n"
             <Emphasis><Error>"  ✖"</Error></Emphasis>" "<Error>"diagnostic message"</Error>"\n"
             "  \n"
+            <Emphasis>"  other_path:1:9"</Emphasis>"\n"
+            "  \n"
             <Emphasis><Error>"  >"</Error></Emphasis>" "<Emphasis>"1 │ "</Emphasis>"context location context\n"
             "   "<Emphasis>"   │ "</Emphasis>"        "<Emphasis><Error>"^^^^^^^^"</Error></Emphasis>"\n"
             "  \n"
@@ -850,6 +889,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_related_frame_advice() {
+        let diag = TestDiagnostic {
+            advice: Some(RelatedFrameAdvice),
+            ..TestDiagnostic::with_location()
+        };
+
+        assert_eq!(related_file_paths(&diag), vec![String::from("other_path")]);
+
+        let diag = diag
+            .with_file_source_code("source code")
+            .with_related_source_code("other_path", "context\ncontext location context");
+
+        assert!(related_file_paths(&*diag).is_empty());
+
+        let diag = markup!({ PrintDiagnostic::verbose(&diag) }).to_owned();
+
+        let expected = markup!{
+            "path:1:1 internalError/io "<Inverse>" FIXABLE "</Inverse>" ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n"
+            "\n"
+            <Emphasis><Error>"  ✖"</Error></Emphasis>" "<Error>"diagnostic message"</Error>"\n"
+            "  \n"
+            <Emphasis>"  other_path:2:9"</Emphasis>"\n"
+            "  \n"
+            "    "<Emphasis>"1 │ "</Emphasis>"context\n"
+            <Emphasis><Error>"  >"</Error></Emphasis>" "<Emphasis>"2 │ "</Emphasis>"context location context\n"
+            "   "<Emphasis>"   │ "</Emphasis>"        "<Emphasis><Error>"^^^^^^^^"</Error></Emphasis>"\n"
+            "  \n"
+        }.to_owned();
+
+        assert_eq!(
+            diag, expected,
+            "\nactual:\n{diag:#?}\nexpected:\n{expected:#?}"
+        );
+    }
+
     #[test]
     fn test_diff_advice() {
         let diag = TestDiagnostic {
@@ -864,8 +939,8 @@ mod tests {
             "\n"
             <Emphasis><Error>"  ✖"</Error></Emphasis>" "<Error>"diagnostic message"</Error>"\n"
             "  \n"
-            <Error>"  -"</Error>" "<Error>"context"</Error><Error><Dim>"·"</Dim></Error><Error><Emphasis>"before"</Emphasis></Error><Error><Dim>"·"</Dim></Error><Error>"context"</Error>"\n"
-            <Success>"  +"</Success>" "<Success>"context"</Success><Success><Dim>"·"</Dim></Success><Success><Emphasis>"after"</Emphasis></Success><Success><Dim>"·"</Dim></Success><Success>"context"</Success>"\n"
+            <Error>"  -"</Error>" "<Error>"context"</Error><Error><Dim>"·"</Dim></Error><Error><Inverse><Emphasis>"before"</Emphasis></Inverse></Error><Error><Dim>"·"</Dim></Error><Error>"context"</Error>"\n"
+            <Success>"  +"</Success>" "<Success>"context"</Success><Success><Dim>"·"</Dim></Success><Success><Inverse><Emphasis>"after"</Emphasis></Inverse></Success><Success><Dim>"·"</Dim></Success><Success>"context"</Success>"\n"
             "  \n"
         }.to_owned();
 
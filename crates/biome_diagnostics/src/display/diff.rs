@@ -611,6 +611,14 @@ impl fmt::Display for FormatDiffLine<'_> {
         let mut at_line_start = true;
         let last_index = self.ops.len().saturating_sub(1);
 
+        // When only some words of a removed or inserted line were changed, they're
+        // also printed in inverse video, so they stand out from the rest of the line
+        let highlight_changes = !self.is_equal
+            && self
+                .ops
+                .iter()
+                .any(|(tag, text)| *tag == ChangeTag::Equal && !text.trim().is_empty());
+
         for (i, (tag, text)) in self.ops.iter().enumerate() {
             let is_changed = *tag != ChangeTag::Equal;
             let options = PrintInvisiblesOptions {
@@ -624,7 +632,13 @@ impl fmt::Display for FormatDiffLine<'_> {
             let has_non_whitespace = if is_changed {
                 let mut slot = None;
                 let mut fmt = ElementWrapper::wrap(fmt, &mut slot, MarkupElement::Emphasis);
-                print_invisibles(&mut fmt, text, options)?
+                if highlight_changes {
+                    let mut slot = None;
+                    let mut fmt = ElementWrapper::wrap(&mut fmt, &mut slot, MarkupElement::Inverse);
+                    print_invisibles(&mut fmt, text, options)?
+                } else {
+                    print_invisibles(&mut fmt, text, options)?
+                }
             } else {
                 print_invisibles(fmt, text, options)?
             };
@@ -722,8 +736,8 @@ mod tests {
         print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
 
         let expected = markup! {
-            "  "<Emphasis>"1"</Emphasis>"  "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"start"</Error><Error><Dim>"·"</Dim></Error><Error><Emphasis>"before"</Emphasis></Error><Error><Dim>"·"</Dim></Error><Error>"end"</Error>"\n"
-            "    "<Emphasis>"1 │ "</Emphasis><Success>"+"</Success>" "<Success>"start"</Success><Success><Dim>"·"</Dim></Success><Success><Emphasis>"after"</Emphasis></Success><Success><Dim>"·"</Dim></Success><Success>"end"</Success><Success><Dim><Emphasis>"·"</Emphasis></Dim></Success>"\n"
+            "  "<Emphasis>"1"</Emphasis>"  "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"start"</Error><Error><Dim>"·"</Dim></Error><Error><Inverse><Emphasis>"before"</Emphasis></Inverse></Error><Error><Dim>"·"</Dim></Error><Error>"end"</Error>"\n"
+            "    "<Emphasis>"1 │ "</Emphasis><Success>"+"</Success>" "<Success>"start"</Success><Success><Dim>"·"</Dim></Success><Success><Inverse><Emphasis>"after"</Emphasis></Inverse></Success><Success><Dim>"·"</Dim></Success><Success>"end"</Success><Success><Dim><Inverse><Emphasis>"·"</Emphasis></Inverse></Dim></Success>"\n"
             "  "<Emphasis>"2"</Emphasis>" "<Emphasis>"2 │ "</Emphasis>"  \n"
             "\n"
         }
@@ -784,9 +798,9 @@ function name(args) {
             "  "<Emphasis>" 5"</Emphasis>" "<Emphasis>" 5 │ "</Emphasis>"  amet,\n"
             "  "<Emphasis>" 6"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"function"</Error>"\n"
             "  "<Emphasis>" 7"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"name("</Error>"\n"
-            "  "<Emphasis>" 8"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error><Dim><Emphasis>"····"</Emphasis></Dim></Error><Error>"args"</Error>"\n"
+            "  "<Emphasis>" 8"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error><Dim><Inverse><Emphasis>"····"</Emphasis></Inverse></Dim></Error><Error>"args"</Error>"\n"
             "  "<Emphasis>" 9"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>")"</Error><Error><Dim>"·"</Dim></Error><Error>"{}"</Error>"\n"
-            "     "<Emphasis>" 6 │ "</Emphasis><Success>"+"</Success>" "<Success>"function"</Success><Success><Dim><Emphasis>"·"</Emphasis></Dim></Success><Success>"name(args)"</Success><Success><Dim>"·"</Dim></Success><Success>"{"</Success>"\n"
+            "     "<Emphasis>" 6 │ "</Emphasis><Success>"+"</Success>" "<Success>"function"</Success><Success><Dim><Inverse><Emphasis>"·"</Emphasis></Inverse></Dim></Success><Success>"name(args)"</Success><Success><Dim>"·"</Dim></Success><Success>"{"</Success>"\n"
             "     "<Emphasis>" 7 │ "</Emphasis><Success>"+"</Success>" "<Success>"}"</Success>"\n"
             "  "<Emphasis>"10"</Emphasis>" "<Emphasis>" 8 │ "</Emphasis>"  consectetur\n"
             "  "<Emphasis>"11"</Emphasis>" "<Emphasis>" 9 │ "</Emphasis>"  adipiscing\n"
@@ -795,9 +809,9 @@ function name(args) {
             "  "<Emphasis>"17"</Emphasis>" "<Emphasis>"15 │ "</Emphasis>"  incididunt\n"
             "  "<Emphasis>"18"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"function"</Error>"\n"
             "  "<Emphasis>"19"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"name("</Error>"\n"
-            "  "<Emphasis>"20"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error><Dim><Emphasis>"····"</Emphasis></Dim></Error><Error>"args"</Error>"\n"
+            "  "<Emphasis>"20"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error><Dim><Inverse><Emphasis>"····"</Emphasis></Inverse></Dim></Error><Error>"args"</Error>"\n"
             "  "<Emphasis>"21"</Emphasis>"   "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>")"</Error><Error><Dim>"·"</Dim></Error><Error>"{}"</Error>"\n"
-            "     "<Emphasis>"16 │ "</Emphasis><Success>"+"</Success>" "<Success>"function"</Success><Success><Dim><Emphasis>"·"</Emphasis></Dim></Success><Success>"name(args)"</Success><Success><Dim>"·"</Dim></Success><Success>"{"</Success>"\n"
+            "     "<Emphasis>"16 │ "</Emphasis><Success>"+"</Success>" "<Success>"function"</Success><Success><Dim><Inverse><Emphasis>"·"</Emphasis></Inverse></Dim></Success><Success>"name(args)"</Success><Success><Dim>"·"</Dim></Success><Success>"{"</Success>"\n"
             "     "<Emphasis>"17 │ "</Emphasis><Success>"+"</Success>" "<Success>"}"</Success>"\n"
             "\n"
         }.to_owned();
@@ -972,7 +986,7 @@ console.log(\"test\");
         print_diff(&mut fmt::Formatter::new(&mut output), &diff).unwrap();
 
         let expected = markup! {
-            "  "<Emphasis>"1"</Emphasis>"  "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"for"</Error><Error><Dim>"·"</Dim></Error><Error>"(;"</Error><Error><Dim><Emphasis>"·"</Emphasis></Dim></Error><Error>";)"</Error><Error><Dim>"·"</Dim></Error><Error>"{"</Error>"\n"
+            "  "<Emphasis>"1"</Emphasis>"  "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"for"</Error><Error><Dim>"·"</Dim></Error><Error>"(;"</Error><Error><Dim><Inverse><Emphasis>"·"</Emphasis></Inverse></Dim></Error><Error>";)"</Error><Error><Dim>"·"</Dim></Error><Error>"{"</Error>"\n"
             "  "<Emphasis>"2"</Emphasis>"  "<Emphasis>" │ "</Emphasis><Error>"-"</Error>" "<Error>"}"</Error>"\n"
             "    "<Emphasis>"1 │ "</Emphasis><Success>"+"</Success>" "<Success>"for"</Success><Success><Dim>"·"</Dim></Success><Success>"(;;)"</Success><Success><Dim>"·"</Dim></Success><Success>"{}"</Success>"\n"
             "  "<Emphasis>"3"</Emphasis>" "<Emphasis>"2 │ "</Emphasis>"  \n"
@@ -29,10 +29,11 @@ pub use biome_diagnostics_macros::Diagnostic;
 pub use crate::advice::{
     Advices, CodeFrameAdvice, CommandAdvice, DiffAdvice, LogAdvice, LogCategory, Visit,
 };
-pub use crate::context::{Context, DiagnosticExt};
+pub use crate::context::{related_file_paths, Context, DiagnosticExt};
 pub use crate::diagnostic::{Diagnostic, DiagnosticTags, Severity};
 pub use crate::display::{
-    set_bottom_frame, Backtrace, MessageAndDescription, PrintDescription, PrintDiagnostic,
+    set_bottom_frame, Backtrace, MessageAndDescription, OneIndexed, PrintDescription,
+    PrintDiagnostic, SourceFile, SourceLocation,
 };
 pub use crate::display_github::PrintGitHubDiagnostic;
 pub use crate::error::{Error, Result};
@@ -103,6 +103,7 @@ define_categories! {
     "lint/nursery/noDefaultExport": "https://biomejs.dev/lint/rules/no-default-export",
     "lint/nursery/noDuplicateJsonKeys": "https://biomejs.dev/linter/rules/no-duplicate-json-keys",
     "lint/nursery/noEmptyBlockStatements": "https://biomejs.dev/linter/rules/no-empty-block-statements",
+    "lint/nursery/noGlobalAssign": "https://biomejs.dev/linter/rules/no-global-assign",
     "lint/nursery/noImplicitAnyLet": "https://biomejs.dev/lint/rules/no-implicit-any-let",
     "lint/nursery/noMisleadingCharacterClass": "https://biomejs.dev/linter/rules/no-misleading-character-class",
     "lint/nursery/noUnusedImports": "https://biomejs.dev/linter/rules/no-unused-imports",
@@ -211,6 +212,7 @@ define_categories! {
     "internalError/io",
     "internalError/fs",
     "internalError/panic",
+    "analyzer/timeout",
     // parse categories
     "parse",
     "parse/noSuperWithoutExtends",
@@ -233,7 +235,10 @@ define_categories! {
     "suppressions/unknownGroup",
     "suppressions/unknownRule",
     "suppressions/unused",
+    "suppressions/misplaced",
     "suppressions/deprecatedSuppressionComment",
+    "suppressions/missingReason",
+    "suppressions/unmatchedRange",
 
     // Used in tests and examples
     "args/fileNotFound",
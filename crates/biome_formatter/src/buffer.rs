@@ -1,4 +1,5 @@
 use super::{write, Arguments, FormatElement};
+use crate::element_pool;
 use crate::format_element::Interned;
 use crate::prelude::{LineMode, PrintMode, Tag};
 use crate::{Format, FormatResult, FormatState};
@@ -175,8 +176,10 @@ pub struct VecBuffer<'a, Context> {
 }
 
 impl<'a, Context> VecBuffer<'a, Context> {
+    /// Creates a buffer that reuses the allocation of a vector released by another buffer or
+    /// document, when there's one
     pub fn new(state: &'a mut FormatState<Context>) -> Self {
-        Self::new_with_vec(state, Vec::new())
+        Self::new_with_vec(state, element_pool::take_vec())
     }
 
     pub fn new_with_vec(state: &'a mut FormatState<Context>, elements: Vec<FormatElement>) -> Self {
@@ -200,6 +203,12 @@ impl<'a, Context> VecBuffer<'a, Context> {
     pub fn take_vec(&mut self) -> Vec<FormatElement> {
         std::mem::take(&mut self.elements)
     }
+
+    /// Moves the written elements into a boxed slice, allocated with their exact size, and
+    /// keeps the allocation of the buffer for the next elements
+    pub(crate) fn take_boxed_slice(&mut self) -> Box<[FormatElement]> {
+        self.elements.drain(..).collect()
+    }
 }
 
 impl<Context> Deref for VecBuffer<'_, Context> {
@@ -1,7 +1,9 @@
 use crate::format_element::tag::{Condition, Tag};
 use crate::prelude::tag::{DedentMode, GroupMode, LabelId};
 use crate::prelude::*;
-use crate::{format_element, write, Argument, Arguments, GroupId, TextRange, TextSize};
+use crate::{
+    element_pool, format_element, write, Argument, Arguments, GroupId, TextRange, TextSize,
+};
 use crate::{Buffer, VecBuffer};
 use biome_rowan::{Language, SyntaxNode, SyntaxToken, TextLen, TokenText};
 use std::borrow::Cow;
@@ -2373,8 +2375,9 @@ impl<Context> Format<Context> for BestFitting<'_, Context> {
             buffer.write_fmt(Arguments::from(variant))?;
             buffer.write_element(FormatElement::Tag(EndEntry))?;
 
-            formatted_variants.push(buffer.take_vec().into_boxed_slice());
+            formatted_variants.push(buffer.take_boxed_slice());
         }
+        element_pool::release_vec(buffer.into_vec());
 
         // SAFETY: The constructor guarantees that there are always at least two variants. It's, therefore,
         // safe to call into the unsafe `from_vec_unchecked` function
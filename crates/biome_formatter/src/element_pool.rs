@@ -0,0 +1,80 @@
+//! A pool of the vectors of [FormatElement]s released by the formatter, reused by the next
+//! buffers instead of allocating and growing new vectors.
+//!
+//! The formatter fills many short-lived buffers: the content of the interned elements, the
+//! variants of the best fitting elements, and the content measured before it's written. The
+//! vectors of these buffers, and of the documents of the formatted files, are cleared and kept
+//! by the thread that released them, so a file formatted after another one, or a buffer filled
+//! after another one, reuses their allocations.
+//!
+//! The vectors with a capacity above `MAX_POOLED_CAPACITY`, such as the one of the document of
+//! a large file, are released to the allocator. The pool holds at most `MAX_POOLED_VECTORS`
+//! vectors per thread, so 32 × 65,536 elements of 24 bytes, about 50 MB, in the worst case.
+//!
+//! The pool only saves the allocations repeated across buffers and files: the IR of a single
+//! large file is still allocated as a whole, and its peak memory is unchanged.
+
+use crate::FormatElement;
+use std::cell::RefCell;
+
+/// The maximum number of vectors kept by each thread
+const MAX_POOLED_VECTORS: usize = 32;
+
+/// The maximum capacity of a vector kept in the pool, in elements
+const MAX_POOLED_CAPACITY: usize = 1 << 16;
+
+thread_local! {
+    static POOL: RefCell<Vec<Vec<FormatElement>>> = RefCell::new(Vec::new());
+}
+
+/// Returns an empty vector, with the capacity of a released vector when the pool has one
+pub(crate) fn take_vec() -> Vec<FormatElement> {
+    POOL.try_with(|pool| pool.borrow_mut().pop())
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Drops the elements of `elements` and keeps its allocation for the next buffers
+pub(crate) fn release_vec(mut elements: Vec<FormatElement>) {
+    if elements.capacity() == 0 || elements.capacity() > MAX_POOLED_CAPACITY {
+        return;
+    }
+    elements.clear();
+    // The thread local may be destroyed already, when a document is dropped while the thread
+    // exits, in which case the vector is dropped
+    let _ = POOL.try_with(|pool| {
+        let mut pool = pool.borrow_mut();
+        if pool.len() < MAX_POOLED_VECTORS {
+            pool.push(elements);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{release_vec, take_vec, MAX_POOLED_CAPACITY};
+    use crate::FormatElement;
+
+    #[test]
+    fn reuses_the_released_vectors() {
+        let mut elements = take_vec();
+        elements.reserve(64);
+        elements.push(FormatElement::Space);
+        let pointer = elements.as_ptr();
+        release_vec(elements);
+
+        let elements = take_vec();
+        assert!(elements.is_empty());
+        assert_eq!(elements.as_ptr(), pointer);
+    }
+
+    #[test]
+    fn drops_the_large_vectors() {
+        let elements: Vec<FormatElement> = Vec::with_capacity(MAX_POOLED_CAPACITY + 1);
+        let pointer = elements.as_ptr();
+        release_vec(elements);
+
+        assert_ne!(take_vec().as_ptr(), pointer);
+    }
+}
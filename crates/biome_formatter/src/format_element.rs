@@ -4,7 +4,7 @@ pub mod tag;
 use crate::format_element::tag::{LabelId, Tag};
 use std::borrow::Cow;
 
-use crate::{TagKind, TextSize};
+use crate::{element_pool, TagKind, TextSize};
 #[cfg(target_pointer_width = "64")]
 use biome_rowan::static_assert;
 use biome_rowan::TokenText;
@@ -130,8 +130,12 @@ impl PrintMode {
 pub struct Interned(Rc<[FormatElement]>);
 
 impl Interned {
-    pub(super) fn new(content: Vec<FormatElement>) -> Self {
-        Self(content.into())
+    /// Moves the elements of `content` into the interned slice, and releases the vector to the
+    /// [element pool](crate::element_pool)
+    pub(super) fn new(mut content: Vec<FormatElement>) -> Self {
+        let interned = Self(content.drain(..).collect());
+        element_pool::release_vec(content);
+        interned
     }
 }
 
@@ -2,7 +2,7 @@ use super::tag::Tag;
 use crate::format_element::tag::DedentMode;
 use crate::prelude::tag::GroupMode;
 use crate::prelude::*;
-use crate::{format, write};
+use crate::{element_pool, format, write};
 use crate::{
     BufferExtensions, Format, FormatContext, FormatElement, FormatOptions, FormatResult, Formatter,
     IndentStyle, IndentWidth, LineEnding, LineWidth, PrinterOptions, TransformSourceMap,
@@ -106,6 +106,12 @@ impl From<Vec<FormatElement>> for Document {
     }
 }
 
+impl Drop for Document {
+    fn drop(&mut self) {
+        element_pool::release_vec(std::mem::take(&mut self.elements));
+    }
+}
+
 impl Deref for Document {
     type Target = [FormatElement];
 
@@ -2,8 +2,8 @@ use crate::buffer::BufferSnapshot;
 use crate::builders::{FillBuilder, JoinBuilder, JoinNodesBuilder, Line};
 use crate::prelude::*;
 use crate::{
-    Arguments, Buffer, Comments, CstFormatContext, FormatContext, FormatState, FormatStateSnapshot,
-    GroupId, VecBuffer,
+    element_pool, Arguments, Buffer, Comments, CstFormatContext, FormatContext, FormatState,
+    FormatStateSnapshot, GroupId, VecBuffer,
 };
 
 /// Handles the formatting of a CST and stores the context how the CST should be formatted (user preferences).
@@ -211,13 +211,15 @@ impl<'buf, Context> Formatter<'buf, Context> {
     }
 
     pub fn intern_vec(&mut self, mut elements: Vec<FormatElement>) -> Option<FormatElement> {
-        match elements.len() {
+        let element = match elements.len() {
             0 => None,
             // Doesn't get cheaper than calling clone, use the element directly
             // SAFETY: Safe because of the `len == 1` check in the match arm.
             1 => Some(elements.pop().unwrap()),
-            _ => Some(FormatElement::Interned(Interned::new(elements))),
-        }
+            _ => return Some(FormatElement::Interned(Interned::new(elements))),
+        };
+        element_pool::release_vec(elements);
+        element
     }
 }
 
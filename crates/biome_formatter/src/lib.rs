@@ -26,6 +26,7 @@ mod buffer;
 mod builders;
 pub mod comments;
 pub mod diagnostics;
+mod element_pool;
 pub mod format_element;
 mod format_extensions;
 pub mod formatter;
@@ -142,6 +143,10 @@ pub enum LineEnding {
 
     /// Carriage Return character only (\r), used very rarely
     Cr,
+
+    /// The line ending that is the most used in the file, see [LineEnding::detect].
+    /// It must be resolved before printing, Line Feed is used otherwise.
+    Auto,
 }
 
 impl LineEnding {
@@ -151,6 +156,41 @@ impl LineEnding {
             LineEnding::Lf => "\n",
             LineEnding::Crlf => "\r\n",
             LineEnding::Cr => "\r",
+            // An unresolved automatic line ending falls back to the default line ending
+            LineEnding::Auto => "\n",
+        }
+    }
+
+    /// Returns the line ending that is the most used in `text`.
+    ///
+    /// It returns [LineEnding::Lf] when `text` doesn't contain any line break,
+    /// or when several line endings are used the same number of times.
+    ///
+    /// ```
+    /// use biome_formatter::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::detect("a\r\nb\r\nc\n"), LineEnding::Crlf);
+    /// assert_eq!(LineEnding::detect("a\nb\r\n"), LineEnding::Lf);
+    /// assert_eq!(LineEnding::detect("a"), LineEnding::Lf);
+    /// ```
+    pub fn detect(text: &str) -> Self {
+        let (mut lf, mut crlf, mut cr) = (0usize, 0usize, 0usize);
+        let mut bytes = text.bytes().peekable();
+        while let Some(byte) = bytes.next() {
+            match byte {
+                b'\n' => lf += 1,
+                b'\r' if bytes.next_if_eq(&b'\n').is_some() => crlf += 1,
+                b'\r' => cr += 1,
+                _ => {}
+            }
+        }
+
+        if crlf > lf && crlf >= cr {
+            LineEnding::Crlf
+        } else if cr > lf && cr > crlf {
+            LineEnding::Cr
+        } else {
+            LineEnding::Lf
         }
     }
 
@@ -178,6 +218,7 @@ impl FromStr for LineEnding {
             "lf" => Ok(Self::Lf),
             "crlf" => Ok(Self::Crlf),
             "cr" => Ok(Self::Cr),
+            "auto" => Ok(Self::Auto),
             // TODO: replace this error with a diagnostic
             _ => Err("Value not supported for LineEnding"),
         }
@@ -190,6 +231,7 @@ impl std::fmt::Display for LineEnding {
             LineEnding::Lf => std::write!(f, "LF"),
             LineEnding::Crlf => std::write!(f, "CRLF"),
             LineEnding::Cr => std::write!(f, "CR"),
+            LineEnding::Auto => std::write!(f, "Auto"),
         }
     }
 }
@@ -204,7 +246,7 @@ impl Deserializable for LineEnding {
         if let Ok(value) = value_text.parse::<Self>() {
             Some(value)
         } else {
-            const ALLOWED_VARIANTS: &[&str] = &["lf", "crlf", "cr"];
+            const ALLOWED_VARIANTS: &[&str] = &["lf", "crlf", "cr", "auto"];
             diagnostics.push(DeserializationDiagnostic::new_unknown_value(
                 &value_text,
                 value.range(),
@@ -532,6 +574,8 @@ where
 }
 pub type PrintResult<T> = Result<T, PrintError>;
 
+/// The result of printing a formatted document: the formatted code, and the [SourceMarker]s
+/// that map positions in the formatted code to positions in the source code.
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -16,6 +16,15 @@ mod os;
 pub const ROME_JSON: &str = "rome.json";
 pub const BIOME_JSON: &str = "biome.json";
 
+/// The version control systems whose command line client is used to query the changed files
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum VcsClient {
+    #[default]
+    Git,
+    Mercurial,
+    Jujutsu,
+}
+
 pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// It opens a file with the given set of options
     fn open_with_options(&self, path: &Path, options: OpenOptions) -> io::Result<Box<dyn File>>;
@@ -43,6 +52,47 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Checks if the given path exists in the file system
     fn path_exists(&self, path: &Path) -> bool;
 
+    /// Returns the paths, relative to `vcs_root`, of the files changed between the
+    /// reference `base` and the current revision of `client`. Deleted files aren't returned.
+    fn get_changed_files(
+        &self,
+        client: VcsClient,
+        vcs_root: &Path,
+        base: &str,
+    ) -> io::Result<Vec<String>>;
+
+    /// Returns the changes between the reference `base` and the current revision of
+    /// `client`, in the unified diff format without context lines. The paths, relative to
+    /// `vcs_root`, don't have the `a/` and `b/` prefixes. Deleted files aren't returned.
+    fn get_diff(&self, client: VcsClient, vcs_root: &Path, base: &str) -> io::Result<String>;
+
+    /// Returns the paths, relative to `vcs_root`, of the files staged in the git index.
+    /// Mercurial and Jujutsu don't have an index, so the files with uncommitted changes are
+    /// returned instead. Deleted files aren't returned.
+    fn get_staged_files(&self, client: VcsClient, vcs_root: &Path) -> io::Result<Vec<String>>;
+
+    /// Returns the staged content of the file at `path`, relative to `vcs_root`. It's the
+    /// content of the file on disk for the clients that don't have an index.
+    fn read_staged_file(
+        &self,
+        client: VcsClient,
+        vcs_root: &Path,
+        path: &str,
+    ) -> io::Result<String>;
+
+    /// Returns the folder of the hooks of the git repository that contains `directory`, as
+    /// resolved by git. It follows the `.git` files of the worktrees and of the submodules,
+    /// and the `core.hooksPath` setting.
+    fn git_hooks_directory(&self, directory: &Path) -> io::Result<PathBuf>;
+
+    /// Downloads the file at `url` and returns its content. The file system of the OS runs
+    /// `curl`, which must be installed.
+    fn download(&self, url: &str) -> io::Result<String>;
+
+    /// Watches the files and the folders at `paths`, and returns an iterator over the batches
+    /// of files that were created or modified. The iterator blocks until a change happens.
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<BoxedWatcher>;
+
     /// Method that takes a path to a folder `file_path`, and a `file_name`. It attempts to find
     /// and read the file from that folder and if not found, it reads the parent directories recursively
     /// until:
@@ -144,6 +194,9 @@ pub struct AutoSearchResult {
     pub file_path: PathBuf,
 }
 
+/// Iterator over the batches of files changed, returned by [FileSystem::watch]
+pub type BoxedWatcher = Box<dyn Iterator<Item = Vec<PathBuf>> + Send>;
+
 pub trait File {
     /// Read the content of the file into `buffer`
     fn read_to_string(&mut self, buffer: &mut String) -> io::Result<()>;
@@ -167,6 +220,7 @@ pub struct OpenOptions {
     truncate: bool,
     create: bool,
     create_new: bool,
+    executable: bool,
 }
 
 impl OpenOptions {
@@ -190,8 +244,19 @@ impl OpenOptions {
         self.create_new = create_new;
         self
     }
+    /// Creates the file with the permission to execute it, on the platforms
+    /// that have this permission
+    pub fn executable(mut self, executable: bool) -> Self {
+        self.executable = executable;
+        self
+    }
 
     pub fn into_fs_options(self, options: &mut std::fs::OpenOptions) -> &mut std::fs::OpenOptions {
+        #[cfg(unix)]
+        if self.executable {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o755);
+        }
         options
             .read(self.read)
             .write(self.write)
@@ -290,6 +355,44 @@ where
     fn path_exists(&self, path: &Path) -> bool {
         T::path_exists(self, path)
     }
+
+    fn get_changed_files(
+        &self,
+        client: VcsClient,
+        vcs_root: &Path,
+        base: &str,
+    ) -> io::Result<Vec<String>> {
+        T::get_changed_files(self, client, vcs_root, base)
+    }
+
+    fn get_diff(&self, client: VcsClient, vcs_root: &Path, base: &str) -> io::Result<String> {
+        T::get_diff(self, client, vcs_root, base)
+    }
+
+    fn get_staged_files(&self, client: VcsClient, vcs_root: &Path) -> io::Result<Vec<String>> {
+        T::get_staged_files(self, client, vcs_root)
+    }
+
+    fn read_staged_file(
+        &self,
+        client: VcsClient,
+        vcs_root: &Path,
+        path: &str,
+    ) -> io::Result<String> {
+        T::read_staged_file(self, client, vcs_root, path)
+    }
+
+    fn git_hooks_directory(&self, directory: &Path) -> io::Result<PathBuf> {
+        T::git_hooks_directory(self, directory)
+    }
+
+    fn download(&self, url: &str) -> io::Result<String> {
+        T::download(self, url)
+    }
+
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<BoxedWatcher> {
+        T::watch(self, paths)
+    }
 }
 
 #[derive(Debug, Diagnostic, Deserialize, Serialize)]
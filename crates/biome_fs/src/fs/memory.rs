@@ -10,14 +10,20 @@ use biome_diagnostics::{Error, Severity};
 use parking_lot::{lock_api::ArcMutexGuard, Mutex, RawMutex, RwLock};
 
 use crate::fs::OpenOptions;
-use crate::{FileSystem, RomePath, TraversalContext, TraversalScope};
+use crate::{FileSystem, RomePath, TraversalContext, TraversalScope, VcsClient};
 
-use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
+use super::{BoxedTraversal, BoxedWatcher, ErrorKind, File, FileSystemDiagnostic};
 
 /// Fully in-memory file system, stores the content of all known files in a hashmap
 pub struct MemoryFileSystem {
     files: AssertUnwindSafe<RwLock<FxHashMap<PathBuf, FileEntry>>>,
     errors: FxHashMap<PathBuf, ErrorEntry>,
+    changed_files: FxHashMap<String, Vec<String>>,
+    diffs: FxHashMap<String, String>,
+    staged_files: FxHashMap<String, String>,
+    remote_files: FxHashMap<String, String>,
+    git_hooks_directory: Option<PathBuf>,
+    watch_events: Vec<Vec<PathBuf>>,
     allow_write: bool,
 }
 
@@ -26,6 +32,12 @@ impl Default for MemoryFileSystem {
         Self {
             files: Default::default(),
             errors: Default::default(),
+            changed_files: Default::default(),
+            diffs: Default::default(),
+            staged_files: Default::default(),
+            remote_files: Default::default(),
+            git_hooks_directory: None,
+            watch_events: Default::default(),
             allow_write: true,
         }
     }
@@ -81,6 +93,36 @@ impl MemoryFileSystem {
         self.errors.insert(path, kind);
     }
 
+    /// Set the files returned by [FileSystem::get_changed_files] for the git reference `base`
+    pub fn set_changed_files(&mut self, base: impl Into<String>, files: Vec<String>) {
+        self.changed_files.insert(base.into(), files);
+    }
+
+    /// Set the diff returned by [FileSystem::get_diff] for the git reference `base`
+    pub fn set_diff(&mut self, base: impl Into<String>, diff: impl Into<String>) {
+        self.diffs.insert(base.into(), diff.into());
+    }
+
+    /// Stage `content` in the git index for the file at `path`
+    pub fn set_staged_file(&mut self, path: impl Into<String>, content: impl Into<String>) {
+        self.staged_files.insert(path.into(), content.into());
+    }
+
+    /// Set the content returned by [FileSystem::download] for `url`
+    pub fn set_remote_file(&mut self, url: impl Into<String>, content: impl Into<String>) {
+        self.remote_files.insert(url.into(), content.into());
+    }
+
+    /// Set the folder returned by [FileSystem::git_hooks_directory]
+    pub fn set_git_hooks_directory(&mut self, path: impl Into<PathBuf>) {
+        self.git_hooks_directory = Some(path.into());
+    }
+
+    /// Add a batch of changed files to the ones returned by [FileSystem::watch]
+    pub fn add_watch_event(&mut self, paths: Vec<PathBuf>) {
+        self.watch_events.push(paths);
+    }
+
     /// Remove a file from the filesystem
     pub fn remove(&mut self, path: &Path) {
         self.files.0.write().remove(path);
@@ -166,6 +208,76 @@ impl FileSystem for MemoryFileSystem {
         let files = self.files.0.read();
         files.get(path).is_some()
     }
+
+    fn get_changed_files(
+        &self,
+        _client: VcsClient,
+        _vcs_root: &Path,
+        base: &str,
+    ) -> io::Result<Vec<String>> {
+        match self.changed_files.get(base) {
+            Some(files) => Ok(files.clone()),
+            None => Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("fatal: ambiguous argument '{base}...HEAD': unknown revision"),
+            )),
+        }
+    }
+
+    fn get_diff(&self, _client: VcsClient, _vcs_root: &Path, base: &str) -> io::Result<String> {
+        match self.diffs.get(base) {
+            Some(diff) => Ok(diff.clone()),
+            None => Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("fatal: ambiguous argument '{base}...HEAD': unknown revision"),
+            )),
+        }
+    }
+
+    fn get_staged_files(&self, _client: VcsClient, _vcs_root: &Path) -> io::Result<Vec<String>> {
+        let mut files: Vec<_> = self.staged_files.keys().cloned().collect();
+        files.sort_unstable();
+        Ok(files)
+    }
+
+    fn read_staged_file(
+        &self,
+        _client: VcsClient,
+        _vcs_root: &Path,
+        path: &str,
+    ) -> io::Result<String> {
+        match self.staged_files.get(path) {
+            Some(content) => Ok(content.clone()),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("fatal: path '{path}' exists on disk, but not in the index"),
+            )),
+        }
+    }
+
+    fn git_hooks_directory(&self, _directory: &Path) -> io::Result<PathBuf> {
+        match &self.git_hooks_directory {
+            Some(path) => Ok(path.clone()),
+            None => Err(io::Error::new(
+                io::ErrorKind::Other,
+                "fatal: not a git repository (or any of the parent directories): .git",
+            )),
+        }
+    }
+
+    fn download(&self, url: &str) -> io::Result<String> {
+        match self.remote_files.get(url) {
+            Some(content) => Ok(content.clone()),
+            None => Err(io::Error::new(
+                io::ErrorKind::Other,
+                "curl: (22) The requested URL returned error: 404",
+            )),
+        }
+    }
+
+    fn watch(&self, _paths: &[PathBuf]) -> io::Result<BoxedWatcher> {
+        Ok(Box::new(self.watch_events.clone().into_iter()))
+    }
 }
 
 struct MemoryFile {
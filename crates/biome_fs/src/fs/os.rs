@@ -1,11 +1,12 @@
 //! Implementation of the [FileSystem] and related traits for the underlying OS filesystem
-use super::{BoxedTraversal, ErrorKind, File, FileSystemDiagnostic};
+use super::{BoxedTraversal, BoxedWatcher, ErrorKind, File, FileSystemDiagnostic};
 use crate::fs::OpenOptions;
 use crate::{
     fs::{TraversalContext, TraversalScope},
-    FileSystem, RomePath,
+    FileSystem, RomePath, VcsClient,
 };
 use biome_diagnostics::{adapters::IoError, DiagnosticExt, Error, Severity};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use rayon::{scope, Scope};
 use std::fs::{DirEntry, FileType};
 use std::{
@@ -15,6 +16,9 @@ use std::{
     io::{self, ErrorKind as IoErrorKind, Read, Seek, Write},
     mem,
     path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc::{self, Receiver},
+    time::Duration,
 };
 
 const MAX_SYMLINK_DEPTH: u8 = 3;
@@ -47,6 +51,333 @@ impl FileSystem for OsFileSystem {
     fn path_exists(&self, path: &Path) -> bool {
         path.exists()
     }
+
+    fn get_changed_files(
+        &self,
+        client: VcsClient,
+        vcs_root: &Path,
+        base: &str,
+    ) -> io::Result<Vec<String>> {
+        match client {
+            VcsClient::Git => {
+                let output = run_command(
+                    "git",
+                    vcs_root,
+                    &[
+                        "diff",
+                        "--name-only",
+                        "--relative",
+                        // Deleted files can't be processed
+                        "--diff-filter=d",
+                        &format!("{base}...HEAD"),
+                    ],
+                )?;
+                Ok(output.lines().map(String::from).collect())
+            }
+            VcsClient::Mercurial => {
+                let output = run_command(
+                    "hg",
+                    vcs_root,
+                    &[
+                        "status",
+                        "--rev",
+                        &format!("ancestor({base}, .)"),
+                        "--rev",
+                        ".",
+                        // Deleted files can't be processed
+                        "--modified",
+                        "--added",
+                        "--no-status",
+                        // The paths are relative to `vcs_root` when a pattern is passed
+                        ".",
+                    ],
+                )?;
+                Ok(output.lines().map(String::from).collect())
+            }
+            VcsClient::Jujutsu => {
+                let output = run_command(
+                    "jj",
+                    vcs_root,
+                    &[
+                        "diff",
+                        "--summary",
+                        "--color=never",
+                        "--from",
+                        &format!("heads(::{base} & ::@)"),
+                        "--to",
+                        "@",
+                        ".",
+                    ],
+                )?;
+                Ok(parse_jj_summary(&output))
+            }
+        }
+    }
+
+    fn get_diff(&self, client: VcsClient, vcs_root: &Path, base: &str) -> io::Result<String> {
+        match client {
+            VcsClient::Git => run_command(
+                "git",
+                vcs_root,
+                &[
+                    "diff",
+                    "--unified=0",
+                    "--relative",
+                    "--no-prefix",
+                    "--no-color",
+                    "--no-ext-diff",
+                    // Deleted files can't be processed
+                    "--diff-filter=d",
+                    &format!("{base}...HEAD"),
+                ],
+            ),
+            VcsClient::Mercurial => run_command(
+                "hg",
+                vcs_root,
+                &[
+                    "diff",
+                    "--rev",
+                    &format!("ancestor({base}, .)"),
+                    "--rev",
+                    ".",
+                    "--unified=0",
+                    "--root=.",
+                    "--config=diff.git=true",
+                    "--config=diff.noprefix=true",
+                    "--color=never",
+                ],
+            ),
+            VcsClient::Jujutsu => {
+                let diff = run_command(
+                    "jj",
+                    vcs_root,
+                    &[
+                        "diff",
+                        "--git",
+                        "--context=0",
+                        "--color=never",
+                        "--from",
+                        &format!("heads(::{base} & ::@)"),
+                        "--to",
+                        "@",
+                        ".",
+                    ],
+                )?;
+                // Jujutsu doesn't have an option to remove the prefixes of the paths
+                Ok(diff
+                    .lines()
+                    .map(|line| match line.strip_prefix("+++ b/") {
+                        Some(path) => format!("+++ {path}\n"),
+                        None => format!("{line}\n"),
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    fn get_staged_files(&self, client: VcsClient, vcs_root: &Path) -> io::Result<Vec<String>> {
+        match client {
+            VcsClient::Git => {
+                let output = run_command(
+                    "git",
+                    vcs_root,
+                    &[
+                        "diff",
+                        "--cached",
+                        "--name-only",
+                        "--relative",
+                        // Deleted files can't be processed
+                        "--diff-filter=d",
+                    ],
+                )?;
+                Ok(output.lines().map(String::from).collect())
+            }
+            VcsClient::Mercurial => {
+                let output = run_command(
+                    "hg",
+                    vcs_root,
+                    &["status", "--modified", "--added", "--no-status", "."],
+                )?;
+                Ok(output.lines().map(String::from).collect())
+            }
+            // The working copy is a commit, its changes are the ones that aren't committed yet
+            VcsClient::Jujutsu => {
+                let output = run_command(
+                    "jj",
+                    vcs_root,
+                    &["diff", "--summary", "--color=never", "-r", "@", "."],
+                )?;
+                Ok(parse_jj_summary(&output))
+            }
+        }
+    }
+
+    fn read_staged_file(
+        &self,
+        client: VcsClient,
+        vcs_root: &Path,
+        path: &str,
+    ) -> io::Result<String> {
+        match client {
+            // `:./<path>` resolves the path from the current directory instead of the root of the repository
+            VcsClient::Git => run_command("git", vcs_root, &["show", &format!(":./{path}")]),
+            VcsClient::Mercurial | VcsClient::Jujutsu => fs::read_to_string(vcs_root.join(path)),
+        }
+    }
+
+    fn git_hooks_directory(&self, directory: &Path) -> io::Result<PathBuf> {
+        let output = run_command("git", directory, &["rev-parse", "--git-path", "hooks"])?;
+        // The path is relative to `directory`, unless `core.hooksPath` is an absolute path
+        Ok(directory.join(output.trim_end()))
+    }
+
+    fn download(&self, url: &str) -> io::Result<String> {
+        // `--fail` turns the HTTP errors into a failure of the command, and `--proto` prevents
+        // the redirections to other protocols than HTTPS
+        run_command(
+            "curl",
+            Path::new(""),
+            &[
+                "--fail",
+                "--silent",
+                "--show-error",
+                "--location",
+                "--proto",
+                "=https",
+                "--proto-redir",
+                "=https",
+                url,
+            ],
+        )
+        .map_err(|error| {
+            // `curl` isn't installed, e.g. in minimal containers or on older versions of Windows
+            if error.kind() == IoErrorKind::NotFound {
+                io::Error::new(
+                    IoErrorKind::NotFound,
+                    "Biome downloads the remote configurations with `curl`, which wasn't found. Install `curl` and make sure it's in the PATH.",
+                )
+            } else {
+                error
+            }
+        })
+    }
+
+    fn watch(&self, paths: &[PathBuf]) -> io::Result<BoxedWatcher> {
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(into_io_error)?;
+        for path in paths {
+            watcher
+                .watch(path, RecursiveMode::Recursive)
+                .map_err(into_io_error)?;
+        }
+        Ok(Box::new(OsWatcher {
+            _watcher: watcher,
+            receiver,
+            working_directory: env::current_dir().ok(),
+        }))
+    }
+}
+
+/// How long [OsWatcher] waits for other events after a change, so that the files saved
+/// together are processed together
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Iterator over the batches of files changed, returned by [OsFileSystem::watch]
+struct OsWatcher {
+    /// The watcher stops when it's dropped
+    _watcher: RecommendedWatcher,
+    receiver: Receiver<notify::Result<Event>>,
+    working_directory: Option<PathBuf>,
+}
+
+impl OsWatcher {
+    fn collect_paths(&self, event: notify::Result<Event>, paths: &mut Vec<PathBuf>) {
+        let event = match event {
+            Ok(event) => event,
+            Err(error) => {
+                tracing::error!("Error while watching the file system: {error}");
+                return;
+            }
+        };
+        if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+            return;
+        }
+        for path in event.paths {
+            if !path.is_file() {
+                continue;
+            }
+            // Print the same paths as the ones passed by the user
+            let path = match &self.working_directory {
+                Some(working_directory) => path
+                    .strip_prefix(working_directory)
+                    .map(Path::to_path_buf)
+                    .unwrap_or(path),
+                None => path,
+            };
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+    }
+}
+
+impl Iterator for OsWatcher {
+    type Item = Vec<PathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut paths = Vec::new();
+            let event = self.receiver.recv().ok()?;
+            self.collect_paths(event, &mut paths);
+            while let Ok(event) = self.receiver.recv_timeout(WATCH_DEBOUNCE) {
+                self.collect_paths(event, &mut paths);
+            }
+            if !paths.is_empty() {
+                return Some(paths);
+            }
+        }
+    }
+}
+
+fn into_io_error(error: notify::Error) -> io::Error {
+    match error.kind {
+        notify::ErrorKind::Io(error) => error,
+        _ => io::Error::new(IoErrorKind::Other, error.to_string()),
+    }
+}
+
+/// Runs the command line program `program` in the folder `directory`, e.g. the client of a
+/// VCS in its root folder, and returns its standard output
+fn run_command(program: &str, directory: &Path, args: &[&str]) -> io::Result<String> {
+    let mut command = Command::new(program);
+    if !directory.as_os_str().is_empty() {
+        command.current_dir(directory);
+    }
+    let output = command.args(args).output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::new(
+            IoErrorKind::Other,
+            stderr.trim().to_string(),
+        ));
+    }
+
+    String::from_utf8(output.stdout)
+        .map_err(|error| io::Error::new(IoErrorKind::InvalidData, error.utf8_error()))
+}
+
+/// Returns the paths of the files added or modified in the output of `jj diff --summary`,
+/// whose lines are made of the kind of change and the path, e.g. `M src/file.js`
+fn parse_jj_summary(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            line.strip_prefix("M ")
+                .or_else(|| line.strip_prefix("A "))
+                .map(String::from)
+        })
+        .collect()
 }
 
 struct OsFile {
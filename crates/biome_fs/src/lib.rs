@@ -3,9 +3,9 @@ mod interner;
 mod path;
 
 pub use fs::{
-    AutoSearchResult, ErrorEntry, File, FileSystem, FileSystemDiagnostic, FileSystemExt,
-    MemoryFileSystem, OpenOptions, OsFileSystem, TraversalContext, TraversalScope, BIOME_JSON,
-    ROME_JSON,
+    AutoSearchResult, BoxedWatcher, ErrorEntry, File, FileSystem, FileSystemDiagnostic,
+    FileSystemExt, MemoryFileSystem, OpenOptions, OsFileSystem, TraversalContext, TraversalScope,
+    VcsClient, BIOME_JSON, ROME_JSON,
 };
 pub use interner::PathInterner;
 pub use path::RomePath;
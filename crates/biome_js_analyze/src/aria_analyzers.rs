@@ -1,5 +1,7 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+#[cfg(feature = "a11y")]
 pub(crate) mod a11y;
+#[cfg(feature = "nursery")]
 pub(crate) mod nursery;
-::biome_analyze::declare_category! { pub (crate) AriaAnalyzers { kind : Lint , groups : [self :: a11y :: A11y , self :: nursery :: Nursery ,] } }
+::biome_analyze::declare_category! { pub (crate) AriaAnalyzers { kind : Lint , language : biome_js_syntax :: JsLanguage , groups : [# [cfg (feature = "a11y")] self :: a11y :: A11y , # [cfg (feature = "nursery")] self :: nursery :: Nursery ,] } }
@@ -79,12 +79,16 @@ declare_rule! {
     }
 }
 
+/// Options for the rule `useValidAriaRole`.
 #[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct ValidAriaRoleOptions {
+    /// A list of roles that are allowed, even if they aren't valid ARIA roles
     #[bpaf(hide, argument::<String>("roles"), many)]
+    #[serde(rename = "allowInvalidRoles")]
     allowed_invalid_roles: Vec<String>,
+    /// If `true`, the roles of the custom components aren't checked
     #[bpaf(hide)]
     ignore_non_dom: bool,
 }
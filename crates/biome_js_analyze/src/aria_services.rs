@@ -1,41 +1,55 @@
+use crate::aria_analyzers::AriaAnalyzers;
 use biome_analyze::{
-    AddVisitor, FromServices, MissingServicesDiagnostic, Phase, Phases, QueryKey, Queryable,
-    RuleKey, ServiceBag, SyntaxVisitor,
+    AddVisitor, AnalysisFilter, FromServices, GroupCategory, MissingServicesDiagnostic, Phase,
+    Phases, QueryKey, Queryable, RegistryVisitor, Rule, RuleGroup, RuleKey, ServiceBag,
+    SyntaxVisitor,
 };
+#[cfg(feature = "a11y")]
 use biome_aria::iso::{countries, is_valid_country, is_valid_language, languages};
 use biome_aria::{AriaProperties, AriaRoles};
-use biome_js_syntax::{AnyJsRoot, AnyJsxAttribute, JsLanguage, JsSyntaxNode, JsxAttributeList};
+use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode};
+#[cfg(any(feature = "a11y", feature = "nursery"))]
+use biome_js_syntax::{AnyJsxAttribute, JsxAttributeList};
 use biome_rowan::AstNode;
+#[cfg(any(feature = "a11y", feature = "nursery"))]
 use rustc_hash::FxHashMap;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub(crate) struct AriaServices {
+    #[cfg_attr(not(any(feature = "a11y", feature = "nursery")), allow(dead_code))]
     pub(crate) roles: Arc<AriaRoles>,
+    #[cfg_attr(not(feature = "a11y"), allow(dead_code))]
     pub(crate) properties: Arc<AriaProperties>,
 }
 
+#[cfg(any(feature = "a11y", feature = "nursery"))]
 impl AriaServices {
     pub fn aria_roles(&self) -> &AriaRoles {
         &self.roles
     }
 
+    #[cfg(feature = "a11y")]
     pub fn aria_properties(&self) -> &AriaProperties {
         &self.properties
     }
 
+    #[cfg(feature = "a11y")]
     pub fn is_valid_iso_language(&self, language: &str) -> bool {
         is_valid_language(language)
     }
 
+    #[cfg(feature = "a11y")]
     pub fn is_valid_iso_country(&self, country: &str) -> bool {
         is_valid_country(country)
     }
 
+    #[cfg(feature = "a11y")]
     pub fn iso_country_list(&self) -> &'static [&'static str] {
         countries()
     }
 
+    #[cfg(feature = "a11y")]
     pub fn iso_language_list(&self) -> &'static [&'static str] {
         languages()
     }
@@ -71,7 +85,7 @@ impl AriaServices {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, any(feature = "a11y", feature = "nursery")))]
 mod tests {
     use crate::aria_services::AriaServices;
     use biome_aria::{AriaProperties, AriaRoles};
@@ -141,6 +155,45 @@ impl FromServices for AriaServices {
     }
 }
 
+/// Whether `filter` enables any of the rules that query the [AriaServices]. The services are
+/// only registered in the analyzer when it's the case.
+pub(crate) fn has_aria_rules(filter: &AnalysisFilter) -> bool {
+    let mut visitor = AriaRulesVisitor {
+        filter,
+        has_rules: false,
+    };
+    visitor.record_category::<AriaAnalyzers>();
+    visitor.has_rules
+}
+
+struct AriaRulesVisitor<'a> {
+    filter: &'a AnalysisFilter<'a>,
+    has_rules: bool,
+}
+
+impl RegistryVisitor<JsLanguage> for AriaRulesVisitor<'_> {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if self.filter.match_category::<C>() {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = JsLanguage>>(&mut self) {
+        if self.filter.match_group::<G>() {
+            G::record_rules(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.has_rules |= self.filter.match_rule::<R>();
+    }
+}
+
 impl Phase for AriaServices {
     fn phase() -> Phases {
         Phases::Syntax
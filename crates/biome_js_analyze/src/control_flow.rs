@@ -11,6 +11,12 @@ mod nodes;
 mod visitor;
 
 pub(crate) use self::visitor::make_visitor;
+#[cfg(any(
+    feature = "complexity",
+    feature = "correctness",
+    feature = "style",
+    feature = "suspicious"
+))]
 pub(crate) use self::visitor::AnyJsControlFlowRoot;
 
 pub struct ControlFlowGraph {
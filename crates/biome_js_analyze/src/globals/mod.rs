@@ -2,7 +2,81 @@
 //!
 //! The data in this module is a port of: `<https://github.com/sindresorhus/globals/blob/main/globals.json>`
 
+use biome_js_syntax::{JsFileSource, Language};
+
 pub mod browser;
 pub mod node;
 pub mod runtime;
 pub mod typescript;
+
+/// The environments used when the configuration doesn't set them
+const DEFAULT_ENVIRONMENTS: &[&str] = &["browser", "node"];
+
+/// Checks whether `name` is a global binding provided by the ECMAScript version, the
+/// `environments` or the language of the file.
+///
+/// When `environments` is [None], the globals of the browser and Node.js are available.
+/// The ECMAScript version is the latest one, unless an environment such as `es5` or `es2015`
+/// restricts it.
+pub fn is_environment_global(
+    name: &str,
+    environments: Option<&[String]>,
+    source_type: &JsFileSource,
+) -> bool {
+    if is_es_global(name, environments) {
+        return true;
+    }
+
+    let is_typescript_global = matches!(source_type.language(), Language::TypeScript { .. })
+        && typescript::TYPESCRIPT_BUILTIN.binary_search(&name).is_ok();
+    if is_typescript_global {
+        return true;
+    }
+
+    match environments {
+        Some(environments) => environments
+            .iter()
+            .any(|environment| is_global_of_environment(name, environment)),
+        None => DEFAULT_ENVIRONMENTS
+            .iter()
+            .any(|environment| is_global_of_environment(name, environment)),
+    }
+}
+
+/// Checks whether `name` is a global binding of the ECMAScript version, i.e. a builtin that
+/// can't be assigned
+pub fn is_es_global(name: &str, environments: Option<&[String]>) -> bool {
+    match es_version_globals(environments) {
+        Some(es_globals) => es_globals.binary_search(&name).is_ok(),
+        None => runtime::BUILTIN.binary_search(&name).is_ok(),
+    }
+}
+
+/// Returns the globals of the latest ECMAScript version among `environments`, if there's one
+fn es_version_globals(environments: Option<&[String]>) -> Option<&'static [&'static str]> {
+    environments?
+        .iter()
+        .filter_map(|environment| match environment.as_str() {
+            "es5" => Some(&runtime::ES_5[..]),
+            "es2015" => Some(&runtime::ES_2015[..]),
+            "es2017" => Some(&runtime::ES_2017[..]),
+            "es2020" => Some(&runtime::ES_2020[..]),
+            // ES2022, ES2023 and ES2024 don't add global bindings
+            "es2021" | "es2024" => Some(&runtime::ES_2021[..]),
+            _ => None,
+        })
+        // Each version includes the globals of the previous ones
+        .max_by_key(|es_globals| es_globals.len())
+}
+
+fn is_global_of_environment(name: &str, environment: &str) -> bool {
+    let globals: &[&str] = match environment {
+        "browser" => &browser::BROWSER,
+        "worker" => &browser::WORKER,
+        "serviceWorker" => &browser::SERVICE_WORKER,
+        "node" => &node::NODE,
+        "commonjs" => &node::COMMON_JS,
+        _ => return false,
+    };
+    globals.binary_search(&name).is_ok()
+}
@@ -1,5 +1,6 @@
 #![warn(clippy::needless_pass_by_value)]
 
+use crate::aria_services::has_aria_rules;
 use crate::suppression_action::apply_suppression_comment;
 use biome_analyze::{
     AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
@@ -8,7 +9,7 @@ use biome_analyze::{
 };
 use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Diagnostic, Error as DiagnosticError};
-use biome_js_syntax::suppression::SuppressionDiagnostic;
+use biome_js_syntax::suppression::{RangeMarker, SuppressionDiagnostic};
 use biome_js_syntax::{suppression::parse_suppression_comment, JsFileSource, JsLanguage};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -18,13 +19,32 @@ mod analyzers;
 mod aria_analyzers;
 mod aria_services;
 mod assists;
+#[cfg(feature = "style")]
 mod ast_utils;
 mod control_flow;
 pub mod globals;
 pub mod options;
+#[cfg(any(
+    feature = "a11y",
+    feature = "complexity",
+    feature = "correctness",
+    feature = "security",
+    feature = "style",
+    feature = "suspicious"
+))]
 mod react;
 mod registry;
 mod semantic_analyzers;
+#[cfg(any(
+    feature = "a11y",
+    feature = "complexity",
+    feature = "correctness",
+    feature = "nursery",
+    feature = "performance",
+    feature = "security",
+    feature = "style",
+    feature = "suspicious"
+))]
 mod semantic_services;
 mod suppression_action;
 mod syntax;
@@ -73,12 +93,28 @@ where
         let mut result = Vec::new();
 
         for comment in parse_suppression_comment(text) {
-            let categories = match comment {
+            let (categories, is_file, range_marker) = match comment {
                 Ok(comment) => {
                     if comment.is_legacy {
                         result.push(Ok(SuppressionKind::Deprecated));
                     }
-                    comment.categories
+                    let is_lint = comment
+                        .categories
+                        .iter()
+                        .any(|(key, _)| key.name().starts_with("lint"));
+                    if is_lint
+                        && comment.reason.is_empty()
+                        && comment.range_marker != Some(RangeMarker::End)
+                    {
+                        result.push(Ok(SuppressionKind::MissingReason));
+                    }
+                    // `// biome-ignore-end` closes the ranges of all the rules
+                    if comment.categories.is_empty()
+                        && comment.range_marker == Some(RangeMarker::End)
+                    {
+                        result.push(Ok(SuppressionKind::RangeEnd(None)));
+                    }
+                    (comment.categories, comment.is_file, comment.range_marker)
                 }
                 Err(err) => {
                     result.push(Err(err));
@@ -87,18 +123,25 @@ where
             };
 
             for (key, value) in categories {
-                if key == category!("lint") {
-                    if let Some(value) = value {
-                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
-                    } else {
-                        result.push(Ok(SuppressionKind::Everything));
-                    }
+                let rule = if key == category!("lint") {
+                    value
+                } else if let Some(rule) = key.name().strip_prefix("lint/") {
+                    Some(rule)
                 } else {
-                    let category = key.name();
-                    if let Some(rule) = category.strip_prefix("lint/") {
-                        result.push(Ok(SuppressionKind::Rule(rule)));
+                    continue;
+                };
+
+                result.push(Ok(match (range_marker, rule) {
+                    (Some(RangeMarker::Start), rule) => SuppressionKind::RangeStart(rule),
+                    (Some(RangeMarker::End), rule) => SuppressionKind::RangeEnd(rule),
+                    (None, Some(rule)) if is_file => SuppressionKind::FileRule(rule),
+                    (None, None) if is_file => SuppressionKind::FileEverything,
+                    (None, Some(rule)) if key == category!("lint") => {
+                        SuppressionKind::MaybeLegacy(rule)
                     }
-                }
+                    (None, Some(rule)) => SuppressionKind::Rule(rule),
+                    (None, None) => SuppressionKind::Everything,
+                }));
             }
         }
 
@@ -127,8 +170,10 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
-    services.insert_service(Arc::new(AriaRoles));
-    services.insert_service(Arc::new(AriaProperties));
+    if has_aria_rules(&filter) {
+        services.insert_service(Arc::new(AriaRoles));
+        services.insert_service(Arc::new(AriaProperties));
+    }
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -215,22 +260,43 @@ impl Error for RuleError {}
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "correctness")]
     use biome_analyze::options::RuleOptions;
-    use biome_analyze::{AnalyzerOptions, Never, RuleCategories, RuleFilter, RuleKey};
+    #[cfg(any(feature = "a11y", feature = "correctness"))]
+    use biome_analyze::RuleFilter;
+    #[cfg(feature = "correctness")]
+    use biome_analyze::RuleKey;
+    use biome_analyze::{AnalyzerOptions, Never, RuleCategories};
+    #[cfg(feature = "correctness")]
     use biome_console::fmt::{Formatter, Termcolor};
+    #[cfg(feature = "correctness")]
     use biome_console::{markup, Markup};
     use biome_diagnostics::category;
+    #[cfg(feature = "correctness")]
     use biome_diagnostics::termcolor::NoColor;
-    use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic, Severity};
+    use biome_diagnostics::Diagnostic;
+    #[cfg(feature = "correctness")]
+    use biome_diagnostics::PrintDiagnostic;
+    #[cfg(any(feature = "correctness", feature = "suspicious"))]
+    use biome_diagnostics::{DiagnosticExt, Severity};
     use biome_js_parser::{parse, JsParserOptions};
-    use biome_js_syntax::{JsFileSource, TextRange, TextSize};
+    use biome_js_syntax::JsFileSource;
+    #[cfg(any(feature = "correctness", feature = "suspicious"))]
+    use biome_js_syntax::TextRange;
+    #[cfg(feature = "suspicious")]
+    use biome_js_syntax::TextSize;
+    #[cfg(any(feature = "a11y", feature = "correctness"))]
     use std::slice;
 
+    #[cfg(feature = "a11y")]
+    use crate::aria_services::has_aria_rules;
+    #[cfg(feature = "correctness")]
     use crate::semantic_analyzers::correctness::use_exhaustive_dependencies::{
         Hooks, HooksOptions,
     };
     use crate::{analyze, AnalysisFilter, ControlFlow};
 
+    #[cfg(feature = "correctness")]
     #[ignore]
     #[test]
     fn quick_test() {
@@ -298,6 +364,7 @@ mod tests {
         // assert_eq!(error_ranges.as_slice(), &[]);
     }
 
+    #[cfg(feature = "suspicious")]
     #[test]
     fn suppression() {
         const SOURCE: &str = "
@@ -424,6 +491,63 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "suspicious")]
+    #[test]
+    fn file_suppression() {
+        const SOURCE: &str = "#!/usr/bin/env node
+// biome-ignore-all lint/suspicious/noDoubleEquals: generated code
+a == b;
+function checkSuppressions(a, b) {
+    a == b;
+    // biome-ignore-all lint/suspicious/noDebugger: not at the top of the file
+    debugger;
+}
+";
+
+        let parsed = parse(
+            SOURCE,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+
+        let mut codes = Vec::new();
+
+        let options = AnalyzerOptions::default();
+        analyze(
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            JsFileSource::js_module(),
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    let code = diag.category().unwrap();
+                    if code == category!("lint/suspicious/noDoubleEquals")
+                        || code == category!("lint/suspicious/noDebugger")
+                        || code.name().starts_with("suppressions/")
+                    {
+                        codes.push((code.name(), diag.get_span().unwrap()));
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        assert_eq!(
+            codes.as_slice(),
+            &[
+                (
+                    "suppressions/misplaced",
+                    TextRange::new(TextSize::from(146), TextSize::from(220))
+                ),
+                (
+                    "lint/suspicious/noDebugger",
+                    TextRange::new(TextSize::from(225), TextSize::from(234))
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn suppression_syntax() {
         const SOURCE: &str = "
@@ -460,4 +584,88 @@ mod tests {
             },
         );
     }
+
+    /// Applies the fix of the `suppressions/unused` diagnostic emitted for `source`
+    fn remove_unused_suppression(source: &str) -> String {
+        let parsed = parse(
+            source,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+
+        let filter = AnalysisFilter {
+            categories: RuleCategories::SYNTAX,
+            ..AnalysisFilter::default()
+        };
+
+        let mut fixed = Vec::new();
+        let options = AnalyzerOptions::default();
+        analyze(
+            &parsed.tree(),
+            filter,
+            &options,
+            JsFileSource::js_module(),
+            |signal| {
+                let is_unused = signal
+                    .diagnostic()
+                    .is_some_and(|diag| diag.category() == Some(category!("suppressions/unused")));
+                if is_unused {
+                    for action in signal.actions() {
+                        fixed.push(action.mutation.commit().to_string());
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        assert_eq!(fixed.len(), 1, "expected a single fix, got {fixed:?}");
+        fixed.remove(0)
+    }
+
+    #[test]
+    fn removes_unused_suppression_comments() {
+        assert_eq!(
+            remove_unused_suppression(
+                "a == b;\n    // biome-ignore lint/suspicious/noDoubleEquals: unused\n    c == d;\n"
+            ),
+            "a == b;\n    c == d;\n"
+        );
+        assert_eq!(
+            remove_unused_suppression(
+                "a == b; // biome-ignore lint/suspicious/noDoubleEquals: unused\nc == d;\n"
+            ),
+            "a == b;\nc == d;\n"
+        );
+        assert_eq!(
+            remove_unused_suppression(
+                "a == b;\n/* biome-ignore lint/suspicious/noDoubleEquals: unused */ c == d;\n"
+            ),
+            "a == b;\nc == d;\n"
+        );
+    }
+
+    #[cfg(feature = "a11y")]
+    #[test]
+    fn aria_services_are_only_registered_for_aria_rules() {
+        let syntax_filter = AnalysisFilter {
+            categories: RuleCategories::SYNTAX,
+            ..AnalysisFilter::default()
+        };
+        assert!(!has_aria_rules(&syntax_filter));
+
+        let rule_filter = RuleFilter::Rule("correctness", "noSelfAssign");
+        let lint_filter = AnalysisFilter {
+            enabled_rules: Some(slice::from_ref(&rule_filter)),
+            ..AnalysisFilter::default()
+        };
+        assert!(!has_aria_rules(&lint_filter));
+
+        let rule_filter = RuleFilter::Rule("a11y", "useValidAriaProps");
+        let aria_filter = AnalysisFilter {
+            enabled_rules: Some(slice::from_ref(&rule_filter)),
+            ..AnalysisFilter::default()
+        };
+        assert!(has_aria_rules(&aria_filter));
+    }
 }
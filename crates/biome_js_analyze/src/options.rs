@@ -1,17 +1,22 @@
 //! This module contains the rules that have options
 
+#[cfg(feature = "complexity")]
 use crate::analyzers::complexity::no_excessive_cognitive_complexity::{
     complexity_options, ComplexityOptions,
 };
+#[cfg(feature = "nursery")]
 use crate::aria_analyzers::nursery::use_valid_aria_role::{
     valid_aria_role_options, ValidAriaRoleOptions,
 };
+#[cfg(feature = "correctness")]
 use crate::semantic_analyzers::correctness::use_exhaustive_dependencies::{
     hooks_options, HooksOptions,
 };
+#[cfg(feature = "style")]
 use crate::semantic_analyzers::style::no_restricted_globals::{
     restricted_globals_options, RestrictedGlobalsOptions,
 };
+#[cfg(feature = "style")]
 use crate::semantic_analyzers::style::use_naming_convention::{
     naming_convention_options, NamingConventionOptions,
 };
@@ -30,14 +35,19 @@ use std::str::FromStr;
 #[serde(rename_all = "camelCase", deny_unknown_fields, untagged)]
 pub enum PossibleOptions {
     /// Options for `noExcessiveComplexity` rule
+    #[cfg(feature = "complexity")]
     Complexity(#[bpaf(external(complexity_options), hide)] ComplexityOptions),
     /// Options for `useExhaustiveDependencies` and `useHookAtTopLevel` rule
+    #[cfg(feature = "correctness")]
     Hooks(#[bpaf(external(hooks_options), hide)] HooksOptions),
     /// Options for `useNamingConvention` rule
+    #[cfg(feature = "style")]
     NamingConvention(#[bpaf(external(naming_convention_options), hide)] NamingConventionOptions),
     /// Options for `noRestrictedGlobals` rule
+    #[cfg(feature = "style")]
     RestrictedGlobals(#[bpaf(external(restricted_globals_options), hide)] RestrictedGlobalsOptions),
     /// Options for `useValidAriaRole` rule
+    #[cfg(feature = "nursery")]
     ValidAriaRole(#[bpaf(external(valid_aria_role_options), hide)] ValidAriaRoleOptions),
 }
 
@@ -45,14 +55,47 @@ pub enum PossibleOptions {
 impl FromStr for PossibleOptions {
     type Err = ();
 
+    #[cfg(feature = "complexity")]
     fn from_str(_s: &str) -> Result<Self, Self::Err> {
         Ok(Self::Complexity(ComplexityOptions::default()))
     }
+
+    #[cfg(not(feature = "complexity"))]
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Err(())
+    }
 }
 
 impl PossibleOptions {
+    /// Returns the default options of the rule `rule_name`, or [None] if the rule
+    /// doesn't accept options
+    pub fn default_for_rule(rule_name: &str) -> Option<Self> {
+        match rule_name {
+            #[cfg(feature = "complexity")]
+            "noExcessiveCognitiveComplexity" => {
+                Some(Self::Complexity(ComplexityOptions::default()))
+            }
+            #[cfg(feature = "correctness")]
+            "useExhaustiveDependencies" | "useHookAtTopLevel" => {
+                Some(Self::Hooks(HooksOptions::default()))
+            }
+            #[cfg(feature = "style")]
+            "useNamingConvention" => {
+                Some(Self::NamingConvention(NamingConventionOptions::default()))
+            }
+            #[cfg(feature = "style")]
+            "noRestrictedGlobals" => {
+                Some(Self::RestrictedGlobals(RestrictedGlobalsOptions::default()))
+            }
+            #[cfg(feature = "nursery")]
+            "useValidAriaRole" => Some(Self::ValidAriaRole(ValidAriaRoleOptions::default())),
+            _ => None,
+        }
+    }
+
     pub fn extract_option(&self, rule_key: &RuleKey) -> RuleOptions {
         match rule_key.rule_name() {
+            #[cfg(feature = "complexity")]
             "noExcessiveCognitiveComplexity" => {
                 let options = match self {
                     PossibleOptions::Complexity(options) => options.clone(),
@@ -60,6 +103,7 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            #[cfg(feature = "correctness")]
             "useExhaustiveDependencies" | "useHookAtTopLevel" => {
                 let options = match self {
                     PossibleOptions::Hooks(options) => options.clone(),
@@ -67,6 +111,7 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            #[cfg(feature = "style")]
             "useNamingConvention" => {
                 let options = match self {
                     PossibleOptions::NamingConvention(options) => options.clone(),
@@ -74,6 +119,7 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            #[cfg(feature = "style")]
             "noRestrictedGlobals" => {
                 let options = match self {
                     PossibleOptions::RestrictedGlobals(options) => options.clone(),
@@ -81,6 +127,7 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            #[cfg(feature = "nursery")]
             "useValidAriaRole" => {
                 let options = match self {
                     PossibleOptions::ValidAriaRole(options) => options.clone(),
@@ -101,16 +148,21 @@ impl Deserializable for PossibleOptions {
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self> {
         match rule_name {
+            #[cfg(feature = "complexity")]
             "noExcessiveCognitiveComplexity" => {
                 Deserializable::deserialize(value, "options", diagnostics).map(Self::Complexity)
             }
+            #[cfg(feature = "style")]
             "noRestrictedGlobals" => Deserializable::deserialize(value, "options", diagnostics)
                 .map(Self::RestrictedGlobals),
+            #[cfg(feature = "correctness")]
             "useExhaustiveDependencies" | "useHookAtTopLevel" => {
                 Deserializable::deserialize(value, "options", diagnostics).map(Self::Hooks)
             }
+            #[cfg(feature = "style")]
             "useNamingConvention" => Deserializable::deserialize(value, "options", diagnostics)
                 .map(Self::NamingConvention),
+            #[cfg(feature = "nursery")]
             "useValidAriaRole" => {
                 Deserializable::deserialize(value, "options", diagnostics).map(Self::ValidAriaRole)
             }
@@ -1,17 +1,33 @@
 //! A series of AST utilities to work with the React library
 
+#[cfg(feature = "correctness")]
 pub mod hooks;
 
 use biome_js_semantic::{Binding, SemanticModel};
+#[cfg(any(feature = "a11y", feature = "correctness", feature = "security"))]
 use biome_js_syntax::{
-    AnyJsCallArgument, AnyJsExpression, AnyJsMemberExpression, AnyJsNamedImportSpecifier,
-    AnyJsObjectMember, JsCallExpression, JsIdentifierBinding, JsImport, JsImportNamedClause,
-    JsNamedImportSpecifierList, JsNamedImportSpecifiers, JsObjectExpression,
-    JsPropertyObjectMember, JsxMemberName, JsxReferenceIdentifier,
+    AnyJsCallArgument, AnyJsObjectMember, JsCallExpression, JsObjectExpression,
+    JsPropertyObjectMember,
 };
-use biome_rowan::{AstNode, AstSeparatedList};
+#[cfg(any(
+    feature = "a11y",
+    feature = "correctness",
+    feature = "security",
+    feature = "suspicious"
+))]
+use biome_js_syntax::{AnyJsExpression, AnyJsMemberExpression};
+use biome_js_syntax::{
+    AnyJsNamedImportSpecifier, JsIdentifierBinding, JsImport, JsImportNamedClause,
+    JsNamedImportSpecifierList, JsNamedImportSpecifiers,
+};
+#[cfg(any(feature = "complexity", feature = "style"))]
+use biome_js_syntax::{JsxMemberName, JsxReferenceIdentifier};
+use biome_rowan::AstNode;
+#[cfg(any(feature = "a11y", feature = "correctness", feature = "security"))]
+use biome_rowan::AstSeparatedList;
 
 /// A trait to share common logic among data structures that "mimic" react APIs
+#[cfg(any(feature = "a11y", feature = "correctness", feature = "security"))]
 pub(crate) trait ReactApiCall {
     /// It scans the current props and returns the property that matches the passed name
     fn find_prop_by_name(&self, prop_name: &str) -> Option<JsPropertyObjectMember>;
@@ -20,15 +36,22 @@ pub(crate) trait ReactApiCall {
 /// A convenient data structure that returns the three arguments of the [React.createElement] call
 ///
 ///[React.createElement]: https://reactjs.org/docs/react-api.html#createelement
+#[cfg(any(feature = "a11y", feature = "correctness", feature = "security"))]
 pub(crate) struct ReactCreateElementCall {
     /// The type of the react element
+    #[cfg_attr(not(any(feature = "a11y", feature = "correctness")), allow(dead_code))]
     pub(crate) element_type: AnyJsCallArgument,
     /// Optional props
     pub(crate) props: Option<JsObjectExpression>,
     /// Optional children
+    #[cfg_attr(
+        not(any(feature = "correctness", feature = "security")),
+        allow(dead_code)
+    )]
     pub(crate) children: Option<AnyJsExpression>,
 }
 
+#[cfg(any(feature = "a11y", feature = "correctness", feature = "security"))]
 impl ReactCreateElementCall {
     /// Checks if the current node is a possible `createElement` call.
     ///
@@ -96,6 +119,7 @@ impl ReactCreateElementCall {
     }
 }
 
+#[cfg(any(feature = "a11y", feature = "correctness", feature = "security"))]
 impl ReactApiCall for ReactCreateElementCall {
     /// It scans the current props and returns the property that matches the passed name
     fn find_prop_by_name(&self, prop_name: &str) -> Option<JsPropertyObjectMember> {
@@ -121,6 +145,7 @@ impl ReactApiCall for ReactCreateElementCall {
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum ReactLibrary {
     React,
+    #[cfg_attr(not(feature = "correctness"), allow(dead_code))]
     ReactDOM,
 }
 
@@ -143,6 +168,12 @@ impl ReactLibrary {
 /// List of valid [`React` API]
 ///
 /// [`React` API]: https://reactjs.org/docs/react-api.html
+#[cfg(any(
+    feature = "a11y",
+    feature = "correctness",
+    feature = "security",
+    feature = "suspicious"
+))]
 const VALID_REACT_API: [&str; 29] = [
     "Component",
     "PureComponent",
@@ -179,6 +210,12 @@ const VALID_REACT_API: [&str; 29] = [
 /// The function has accepts a `api_name` to check against
 ///
 /// [`React` API]: https://reactjs.org/docs/react-api.html
+#[cfg(any(
+    feature = "a11y",
+    feature = "correctness",
+    feature = "security",
+    feature = "suspicious"
+))]
 pub(crate) fn is_react_call_api(
     expression: AnyJsExpression,
     model: &SemanticModel,
@@ -226,6 +263,7 @@ pub(crate) fn is_react_call_api(
 ///
 /// In case the `React` is a valid reference, the function checks if it is exported from the
 /// `"react"` library
+#[cfg(any(feature = "complexity", feature = "style"))]
 pub(crate) fn jsx_member_name_is_react_fragment(
     member_name: &JsxMemberName,
     model: &SemanticModel,
@@ -251,6 +289,7 @@ pub(crate) fn jsx_member_name_is_react_fragment(
 ///
 /// In case the `Fragment` is a valid reference, the function checks if it is exported from the
 /// `"react"` library
+#[cfg(any(feature = "complexity", feature = "style"))]
 pub(crate) fn jsx_reference_identifier_is_fragment(
     name: &JsxReferenceIdentifier,
     model: &SemanticModel,
@@ -1,11 +1,19 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+#[cfg(feature = "a11y")]
 pub(crate) mod a11y;
+#[cfg(feature = "complexity")]
 pub(crate) mod complexity;
+#[cfg(feature = "correctness")]
 pub(crate) mod correctness;
+#[cfg(feature = "nursery")]
 pub(crate) mod nursery;
+#[cfg(feature = "performance")]
 pub(crate) mod performance;
+#[cfg(feature = "security")]
 pub(crate) mod security;
+#[cfg(feature = "style")]
 pub(crate) mod style;
+#[cfg(feature = "suspicious")]
 pub(crate) mod suspicious;
-::biome_analyze::declare_category! { pub (crate) SemanticAnalyzers { kind : Lint , groups : [self :: a11y :: A11y , self :: complexity :: Complexity , self :: correctness :: Correctness , self :: nursery :: Nursery , self :: performance :: Performance , self :: security :: Security , self :: style :: Style , self :: suspicious :: Suspicious ,] } }
+::biome_analyze::declare_category! { pub (crate) SemanticAnalyzers { kind : Lint , language : biome_js_syntax :: JsLanguage , groups : [# [cfg (feature = "a11y")] self :: a11y :: A11y , # [cfg (feature = "complexity")] self :: complexity :: Complexity , # [cfg (feature = "correctness")] self :: correctness :: Correctness , # [cfg (feature = "nursery")] self :: nursery :: Nursery , # [cfg (feature = "performance")] self :: performance :: Performance , # [cfg (feature = "security")] self :: security :: Security , # [cfg (feature = "style")] self :: style :: Style , # [cfg (feature = "suspicious")] self :: suspicious :: Suspicious ,] } }
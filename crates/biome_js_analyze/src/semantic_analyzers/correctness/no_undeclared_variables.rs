@@ -1,12 +1,9 @@
-use crate::globals::browser::BROWSER;
-use crate::globals::node::NODE;
-use crate::globals::runtime::{BUILTIN, ES_2021};
-use crate::globals::typescript::TYPESCRIPT_BUILTIN;
+use crate::globals::is_environment_global;
 use crate::semantic_services::SemanticServices;
 use biome_analyze::context::RuleContext;
-use biome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use biome_analyze::{declare_rule, GlobalAccess, Rule, RuleDiagnostic};
 use biome_console::markup;
-use biome_js_syntax::{JsFileSource, Language, TextRange, TsAsExpression, TsReferenceType};
+use biome_js_syntax::{JsFileSource, TextRange, TsAsExpression, TsReferenceType};
 use biome_rowan::AstNode;
 
 declare_rule! {
@@ -14,6 +11,9 @@ declare_rule! {
     ///
     /// If you need to allow-list some global bindings, you can use the [`javascript.globals`](/reference/configuration/#javascriptglobals) configuration.
     ///
+    /// The global bindings of the browser and Node.js are allowed by default.
+    /// The [`javascript.environments`](/reference/configuration/#javascriptenvironments) configuration changes the environments that provide global bindings.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -59,8 +59,14 @@ impl Rule for NoUndeclaredVariables {
 
                 let source_type = ctx.source_type::<JsFileSource>();
 
-                if ctx.is_global(text) {
-                    return None;
+                match ctx.global_access(text) {
+                    Some(GlobalAccess::Readonly | GlobalAccess::Writable) => return None,
+                    // The global is disabled, even if an environment provides it
+                    Some(GlobalAccess::Off) => {
+                        let span = token.text_trimmed_range();
+                        return Some((span, text.to_string()));
+                    }
+                    None => {}
                 }
 
                 // Typescript Const Assertion
@@ -68,7 +74,7 @@ impl Rule for NoUndeclaredVariables {
                     return None;
                 }
 
-                if is_global(text, source_type) {
+                if is_environment_global(text, ctx.global_environments(), source_type) {
                     return None;
                 }
 
@@ -89,16 +95,3 @@ impl Rule for NoUndeclaredVariables {
         ))
     }
 }
-
-fn is_global(reference_name: &str, source_type: &JsFileSource) -> bool {
-    ES_2021.binary_search(&reference_name).is_ok()
-        || BROWSER.binary_search(&reference_name).is_ok()
-        || NODE.binary_search(&reference_name).is_ok()
-        || match source_type.language() {
-            Language::JavaScript => BUILTIN.binary_search(&reference_name).is_ok(),
-            Language::TypeScript { .. } => {
-                BUILTIN.binary_search(&reference_name).is_ok()
-                    || TYPESCRIPT_BUILTIN.binary_search(&reference_name).is_ok()
-            }
-        }
-}
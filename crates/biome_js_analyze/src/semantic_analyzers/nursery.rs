@@ -2,6 +2,7 @@
 
 use biome_analyze::declare_group;
 
+pub(crate) mod no_global_assign;
 pub(crate) mod no_misleading_character_class;
 pub(crate) mod no_unused_imports;
 pub(crate) mod use_export_type;
@@ -11,6 +12,7 @@ declare_group! {
     pub (crate) Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_global_assign :: NoGlobalAssign ,
             self :: no_misleading_character_class :: NoMisleadingCharacterClass ,
             self :: no_unused_imports :: NoUnusedImports ,
             self :: use_export_type :: UseExportType ,
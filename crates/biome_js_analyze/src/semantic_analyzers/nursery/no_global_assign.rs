@@ -0,0 +1,91 @@
+use crate::globals::is_es_global;
+use crate::semantic_services::SemanticServices;
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, GlobalAccess, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsIdentifierUsage, TextRange};
+
+declare_rule! {
+    /// Disallow assignments to native objects and read-only global variables.
+    ///
+    /// JavaScript's environments contain numerous built-in global variables, such as `window` in browsers and `process` in Node.js.
+    /// Assigning values to these global variables can be unsafe, because it can override essential functionality.
+    ///
+    /// The rule reports the assignments to the builtins of the ECMAScript version,
+    /// and to the global variables declared as `"readonly"` in the [`javascript.globals`](/reference/configuration/#javascriptglobals) configuration.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-global-assign
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// Object = null;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// Array++;
+    /// ```
+    ///
+    /// ## Valid
+    ///
+    /// ```js
+    /// a = 0;
+    /// ```
+    ///
+    /// ```js
+    /// let Object = null;
+    /// Object = 0;
+    /// ```
+    pub(crate) NoGlobalAssign {
+        version: "next",
+        name: "noGlobalAssign",
+        recommended: false,
+    }
+}
+
+impl Rule for NoGlobalAssign {
+    type Query = SemanticServices;
+    type State = (TextRange, String);
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        ctx.query()
+            .all_unresolved_references()
+            .filter_map(|reference| {
+                let AnyJsIdentifierUsage::JsIdentifierAssignment(assignment) = reference.tree()
+                else {
+                    return None;
+                };
+                let token = assignment.name_token().ok()?;
+                let name = token.text_trimmed();
+                let is_readonly = match ctx.global_access(name) {
+                    Some(GlobalAccess::Readonly) => true,
+                    Some(GlobalAccess::Writable | GlobalAccess::Off) => false,
+                    None => is_es_global(name, ctx.global_environments()),
+                };
+                is_readonly.then(|| (token.text_trimmed_range(), name.to_string()))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, (span, name): &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *span,
+                markup! {
+                    "A global variable should not be reassigned."
+                },
+            )
+            .note(markup! {
+                "Assigning to a global variable can override essential functionality."
+            })
+            .note(markup! {
+                "If "<Emphasis>{name}</Emphasis>" isn't read-only, declare it as "<Emphasis>"writable"</Emphasis>" in the "<Emphasis>"javascript.globals"</Emphasis>" configuration."
+            }),
+        )
+    }
+}
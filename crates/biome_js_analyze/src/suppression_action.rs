@@ -22,10 +22,20 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
         token_offset,
         mutation,
         suppression_text,
+        suppression_reason,
         diagnostic_text_range,
     } = payload;
+    let suppression_reason = suppression_reason.unwrap_or("<explanation>");
     // retrieve the most suited, most left token where the diagnostics was emitted
-    let original_token = get_token_from_offset(token_offset, diagnostic_text_range);
+    let original_token = get_token_from_offset(token_offset, diagnostic_text_range)
+        // the comment can't be placed before the shebang of the file
+        .and_then(|token| {
+            if token.kind() == JsSyntaxKind::JS_SHEBANG {
+                token.next_token()
+            } else {
+                Some(token)
+            }
+        });
 
     // considering that our suppression system works via lines, we need to look for the first newline,
     // so we can place the comment there
@@ -61,7 +71,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                 let jsx_comment = jsx_expression_child(
                     token(T!['{']).with_trailing_trivia([(
                         TriviaPieceKind::SingleLineComment,
-                        format!("/* {}: <explanation> */", suppression_text).as_str(),
+                        format!("/* {suppression_text}: {suppression_reason} */").as_str(),
                     )]),
                     token(T!['}']),
                 )
@@ -92,13 +102,13 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                     );
                 }
             } else {
-                let mut new_token = token_to_apply_suppression.clone();
+                let mut new_token = without_trivia(&token_to_apply_suppression);
                 if !should_insert_leading_newline {
                     new_token = new_token.with_leading_trivia([
                         (TriviaPieceKind::Newline, "\n"),
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {}: <explanation>", suppression_text).as_str(),
+                            format!("// {suppression_text}: {suppression_reason}").as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -106,7 +116,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                     new_token = new_token.with_leading_trivia([
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {}: <explanation>", suppression_text).as_str(),
+                            format!("// {suppression_text}: {suppression_reason}").as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -114,14 +124,14 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                 mutation.replace_token_transfer_trivia(token_to_apply_suppression, new_token);
             }
         } else {
-            let mut new_token = token_to_apply_suppression.clone();
+            let mut new_token = without_trivia(&token_to_apply_suppression);
             if !should_insert_leading_newline {
                 if token_has_trailing_comments {
                     new_token = new_token.with_trailing_trivia([
                         (TriviaPieceKind::Newline, "\n"),
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {}: <explanation>", suppression_text).as_str(),
+                            format!("// {suppression_text}: {suppression_reason}").as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -130,7 +140,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                         (TriviaPieceKind::Newline, "\n"),
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {}: <explanation>", suppression_text).as_str(),
+                            format!("// {suppression_text}: {suppression_reason}").as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -139,7 +149,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                 new_token = new_token.with_trailing_trivia([
                     (
                         TriviaPieceKind::SingleLineComment,
-                        format!("// {}: <explanation>", suppression_text).as_str(),
+                        format!("// {suppression_text}: {suppression_reason}").as_str(),
                     ),
                     (TriviaPieceKind::Newline, "\n"),
                 ])
@@ -147,7 +157,7 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
                 new_token = new_token.with_leading_trivia([
                     (
                         TriviaPieceKind::SingleLineComment,
-                        format!("// {}: <explanation>", suppression_text).as_str(),
+                        format!("// {suppression_text}: {suppression_reason}").as_str(),
                     ),
                     (TriviaPieceKind::Newline, "\n"),
                 ])
@@ -157,6 +167,15 @@ pub(crate) fn apply_suppression_comment(payload: SuppressionCommentEmitterPayloa
     }
 }
 
+/// Returns a copy of `token` without its trivia, so only the trivia of the suppression
+/// comment is added to the original trivia when the token is replaced
+fn without_trivia(token: &JsSyntaxToken) -> JsSyntaxToken {
+    const NO_TRIVIA: [(TriviaPieceKind, &str); 0] = [];
+    token
+        .with_leading_trivia(NO_TRIVIA)
+        .with_trailing_trivia(NO_TRIVIA)
+}
+
 /// Convenient type to store useful information
 struct ApplySuppression {
     /// If the token is following by trailing comments
@@ -1,5 +1,12 @@
-use biome_js_syntax::{inner_string_text, AnyJsExpression, JsBinaryExpression, JsSyntaxNode};
-use biome_rowan::{AstNode, Direction, WalkEvent};
+#[cfg(any(feature = "nursery", feature = "suspicious"))]
+use biome_js_syntax::{inner_string_text, JsSyntaxNode};
+#[cfg(any(feature = "style", feature = "suspicious"))]
+use biome_js_syntax::{AnyJsExpression, JsBinaryExpression};
+#[cfg(any(feature = "style", feature = "suspicious"))]
+use biome_rowan::AstNode;
+#[cfg(any(feature = "nursery", feature = "suspicious"))]
+use biome_rowan::{Direction, WalkEvent};
+#[cfg(any(feature = "nursery", feature = "suspicious"))]
 use std::iter;
 
 pub mod batch;
@@ -8,16 +15,19 @@ pub mod rename;
 #[cfg(test)]
 pub mod tests;
 
+#[cfg(any(feature = "correctness", feature = "suspicious"))]
 #[derive(Debug, PartialEq)]
 pub(crate) enum EscapeError {
     EscapeAtEndOfString,
     InvalidEscapedChar(char),
 }
 
+#[cfg(any(feature = "correctness", feature = "suspicious"))]
 struct InterpretEscapedString<'a> {
     s: std::str::Chars<'a>,
 }
 
+#[cfg(any(feature = "correctness", feature = "suspicious"))]
 impl<'a> Iterator for InterpretEscapedString<'a> {
     type Item = Result<char, EscapeError>;
 
@@ -36,12 +46,14 @@ impl<'a> Iterator for InterpretEscapedString<'a> {
 
 /// unescape
 ///
+#[cfg(any(feature = "correctness", feature = "suspicious"))]
 pub(crate) fn escape_string(s: &str) -> Result<String, EscapeError> {
     (InterpretEscapedString { s: s.chars() }).collect()
 }
 
 /// Verifies that both nodes are equal by checking their descendants (nodes included) kinds
 /// and tokens (same kind and inner token text).
+#[cfg(any(feature = "nursery", feature = "suspicious"))]
 pub(crate) fn is_node_equal(a_node: &JsSyntaxNode, b_node: &JsSyntaxNode) -> bool {
     let a_tree = a_node.preorder_with_tokens(Direction::Next);
     let b_tree = b_node.preorder_with_tokens(Direction::Next);
@@ -71,6 +83,7 @@ pub(crate) fn is_node_equal(a_node: &JsSyntaxNode, b_node: &JsSyntaxNode) -> boo
     true
 }
 
+#[cfg(any(feature = "style", feature = "suspicious"))]
 #[derive(Debug, PartialEq)]
 pub(crate) enum VariablePosition {
     Right,
@@ -85,6 +98,7 @@ pub(crate) enum VariablePosition {
 ///
 /// Depending on your specific expression and variable placement,
 /// the result may vary between Left, Right, or None.
+#[cfg(any(feature = "style", feature = "suspicious"))]
 pub(crate) fn find_variable_position(
     binary_expression: &JsBinaryExpression,
     variable: &str,
@@ -112,7 +126,7 @@ pub(crate) fn find_variable_position(
         .next()
 }
 
-#[cfg(test)]
+#[cfg(all(test, any(feature = "style", feature = "suspicious")))]
 mod test {
     use crate::utils::{find_variable_position, VariablePosition};
     use biome_js_parser::{parse, JsParserOptions};
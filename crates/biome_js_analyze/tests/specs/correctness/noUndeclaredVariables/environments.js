@@ -0,0 +1,11 @@
+// available in the worker environment
+importScripts("script.js");
+// available in the commonjs environment
+module.exports = {};
+// not available in the configured environments
+document.body;
+process.exit(1);
+// not available in ES5
+new Map();
+// a builtin of ES5
+JSON.parse("{}");
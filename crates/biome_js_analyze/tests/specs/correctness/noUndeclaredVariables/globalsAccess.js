@@ -0,0 +1,5 @@
+// declared in the configuration
+Readonly;
+Writable;
+// disabled in the configuration
+window;
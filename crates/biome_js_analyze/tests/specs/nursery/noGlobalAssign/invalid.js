@@ -0,0 +1,5 @@
+Object = null;
+Array++;
+({ Map } = {});
+[Promise] = [];
+Readonly = 0;
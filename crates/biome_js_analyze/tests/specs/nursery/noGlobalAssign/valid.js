@@ -0,0 +1,10 @@
+/* should not generate diagnostics */
+a = 0;
+window = {};
+window.Object = null;
+Writable = 0;
+let Object = null;
+Object = 0;
+function f(Array) {
+	Array = [];
+}
@@ -78,6 +78,8 @@ impl CommentStyle for JsCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            // The formatting can't be suppressed for the whole file or for a range
+            .filter(|suppression| !suppression.is_file && suppression.range_marker.is_none())
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
     }
@@ -168,6 +168,18 @@ pub struct JsFormatOptions {
     /// Whether to hug the closing bracket of multiline HTML/JSX tags to the end of the last line, rather than being alone on the following line. Defaults to false.
     bracket_same_line: BracketSameLine,
 
+    /// Where the decorators of class members are printed. Defaults to "preserve".
+    decorator_placement: DecoratorPlacement,
+
+    /// The tags of the template literals whose content is reindented, for example `dedent`. Defaults to none.
+    dedent_tags: Vec<String>,
+
+    /// The tags of the template literals whose content is formatted as SQL, for example `sql`. Defaults to none.
+    sql_tags: Vec<String>,
+
+    /// Whether to match the output of Prettier where Biome intentionally diverges from it. Defaults to false.
+    prettier_compatibility: bool,
+
     /// Information related to the current file
     source_type: JsFileSource,
 }
@@ -188,9 +200,18 @@ impl JsFormatOptions {
             arrow_parentheses: ArrowParentheses::default(),
             bracket_spacing: BracketSpacing::default(),
             bracket_same_line: BracketSameLine::default(),
+            decorator_placement: DecoratorPlacement::default(),
+            dedent_tags: Vec::new(),
+            sql_tags: Vec::new(),
+            prettier_compatibility: false,
         }
     }
 
+    pub fn with_source_type(mut self, source_type: JsFileSource) -> Self {
+        self.source_type = source_type;
+        self
+    }
+
     pub fn with_arrow_parentheses(mut self, arrow_parentheses: ArrowParentheses) -> Self {
         self.arrow_parentheses = arrow_parentheses;
         self
@@ -206,6 +227,26 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_decorator_placement(mut self, decorator_placement: DecoratorPlacement) -> Self {
+        self.decorator_placement = decorator_placement;
+        self
+    }
+
+    pub fn with_dedent_tags(mut self, dedent_tags: Vec<String>) -> Self {
+        self.dedent_tags = dedent_tags;
+        self
+    }
+
+    pub fn with_sql_tags(mut self, sql_tags: Vec<String>) -> Self {
+        self.sql_tags = sql_tags;
+        self
+    }
+
+    pub fn with_prettier_compatibility(mut self, prettier_compatibility: bool) -> Self {
+        self.prettier_compatibility = prettier_compatibility;
+        self
+    }
+
     pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
         self.indent_style = indent_style;
         self
@@ -263,6 +304,22 @@ impl JsFormatOptions {
         self.bracket_same_line = bracket_same_line;
     }
 
+    pub fn set_decorator_placement(&mut self, decorator_placement: DecoratorPlacement) {
+        self.decorator_placement = decorator_placement;
+    }
+
+    pub fn set_dedent_tags(&mut self, dedent_tags: Vec<String>) {
+        self.dedent_tags = dedent_tags;
+    }
+
+    pub fn set_sql_tags(&mut self, sql_tags: Vec<String>) {
+        self.sql_tags = sql_tags;
+    }
+
+    pub fn set_prettier_compatibility(&mut self, prettier_compatibility: bool) {
+        self.prettier_compatibility = prettier_compatibility;
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -311,6 +368,22 @@ impl JsFormatOptions {
         self.bracket_same_line
     }
 
+    pub fn decorator_placement(&self) -> DecoratorPlacement {
+        self.decorator_placement
+    }
+
+    pub fn dedent_tags(&self) -> &[String] {
+        &self.dedent_tags
+    }
+
+    pub fn sql_tags(&self) -> &[String] {
+        &self.sql_tags
+    }
+
+    pub fn prettier_compatibility(&self) -> bool {
+        self.prettier_compatibility
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
@@ -375,7 +448,11 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Semicolons: {}", self.semicolons)?;
         writeln!(f, "Arrow parentheses: {}", self.arrow_parentheses)?;
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
-        writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())
+        writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
+        writeln!(f, "Decorator placement: {}", self.decorator_placement)?;
+        writeln!(f, "Dedent tags: {:?}", self.dedent_tags)?;
+        writeln!(f, "SQL tags: {:?}", self.sql_tags)?;
+        writeln!(f, "Prettier compatibility: {}", self.prettier_compatibility)
     }
 }
 
@@ -723,3 +800,80 @@ impl From<bool> for BracketSameLine {
         Self(value)
     }
 }
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum DecoratorPlacement {
+    /// A decorator is printed on its own line only if it was already on its own line in the source.
+    #[default]
+    Preserve,
+    /// A single decorator is printed on the same line as the class member it decorates, when it fits.
+    SameLine,
+    /// Decorators are always printed on their own line, above the class member they decorate.
+    OwnLine,
+}
+
+impl DecoratorPlacement {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_same_line(&self) -> bool {
+        matches!(self, Self::SameLine)
+    }
+
+    pub const fn is_own_line(&self) -> bool {
+        matches!(self, Self::OwnLine)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for DecoratorPlacement {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "same-line" | "SameLine" => Ok(Self::SameLine),
+            "own-line" | "OwnLine" => Ok(Self::OwnLine),
+            _ => Err("Value not supported for Decorator placement. Supported values are 'preserve', 'same-line' and 'own-line'."),
+        }
+    }
+}
+
+impl fmt::Display for DecoratorPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecoratorPlacement::Preserve => write!(f, "Preserve"),
+            DecoratorPlacement::SameLine => write!(f, "Same line"),
+            DecoratorPlacement::OwnLine => write!(f, "Own line"),
+        }
+    }
+}
+
+impl Deserializable for DecoratorPlacement {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        match Text::deserialize(value, name, diagnostics)?.text() {
+            "preserve" => Some(DecoratorPlacement::Preserve),
+            "sameLine" => Some(DecoratorPlacement::SameLine),
+            "ownLine" => Some(DecoratorPlacement::OwnLine),
+            unknown_value => {
+                const ALLOWED_VARIANTS: &[&str] = &["preserve", "sameLine", "ownLine"];
+                diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                    unknown_value,
+                    value.range(),
+                    ALLOWED_VARIANTS,
+                ));
+                None
+            }
+        }
+    }
+}
@@ -3,7 +3,8 @@ use biome_formatter::{format_args, write, CstFormatContext};
 
 use crate::parentheses::NeedsParentheses;
 use biome_js_syntax::{
-    AnyJsExpression, JsParenthesizedExpression, JsParenthesizedExpressionFields, JsSyntaxNode,
+    AnyJsExpression, AnyJsOptionalChainExpression, JsParenthesizedExpression,
+    JsParenthesizedExpressionFields, JsSyntaxNode, TsNonNullAssertionExpressionFields,
 };
 
 #[derive(Debug, Clone, Default)]
@@ -23,6 +24,40 @@ impl FormatNodeRule<JsParenthesizedExpression> for FormatJsParenthesizedExpressi
 
         let l_paren_token = l_paren_token?;
         let expression = expression?;
+
+        // Prettier moves the non-null assertion of a parenthesized optional chain outside the parentheses
+        // (a?.b!) -> (a?.b)!
+        if let AnyJsExpression::TsNonNullAssertionExpression(assertion) = &expression {
+            let TsNonNullAssertionExpressionFields {
+                expression: asserted,
+                excl_token,
+            } = assertion.as_fields();
+            let is_optional_chain = asserted.as_ref().is_ok_and(|asserted| {
+                AnyJsOptionalChainExpression::cast_ref(asserted.syntax())
+                    .is_some_and(|chain| chain.is_optional_chain())
+            });
+
+            if f.options().prettier_compatibility()
+                && is_optional_chain
+                && !f.comments().is_suppressed(assertion.syntax())
+            {
+                return write!(
+                    f,
+                    [
+                        format_leading_comments(assertion.syntax()),
+                        group(&format_args![
+                            l_paren_token.format(),
+                            soft_block_indent(&asserted.format()),
+                            r_paren_token.format()
+                        ]),
+                        excl_token.format(),
+                        format_dangling_comments(assertion.syntax()),
+                        format_trailing_comments(assertion.syntax())
+                    ]
+                );
+            }
+        }
+
         let comments = f.context().comments();
 
         let should_hug = !comments.has_comments(expression.syntax())
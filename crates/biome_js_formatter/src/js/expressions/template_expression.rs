@@ -4,6 +4,8 @@ use biome_formatter::write;
 use crate::js::expressions::static_member_expression::member_chain_callee_needs_parens;
 use crate::js::lists::template_element_list::FormatJsTemplateElementListOptions;
 use crate::parentheses::NeedsParentheses;
+use crate::utils::dedent_template::DedentTemplateElementList;
+use crate::utils::sql_template::SqlTemplateElementList;
 use crate::utils::test_call::is_test_each_pattern;
 use biome_js_syntax::{AnyJsExpression, JsSyntaxNode, JsTemplateExpression, TsTemplateLiteralType};
 use biome_js_syntax::{JsSyntaxToken, TsTypeArguments};
@@ -69,6 +71,18 @@ impl AnyJsTemplate {
     fn write_elements(&self, f: &mut JsFormatter) -> FormatResult<()> {
         match self {
             AnyJsTemplate::JsTemplateExpression(template) => {
+                if let Some(sql_list) =
+                    SqlTemplateElementList::new(template, f.options().sql_tags())
+                {
+                    return write!(f, [sql_list]);
+                }
+
+                if let Some(dedent_list) =
+                    DedentTemplateElementList::new(template, f.options().dedent_tags())
+                {
+                    return write!(f, [dedent_list]);
+                }
+
                 let is_test_each_pattern = is_test_each_pattern(template);
                 let options = FormatJsTemplateElementListOptions {
                     is_test_each_pattern,
@@ -49,11 +49,7 @@ pub(crate) enum AnyTemplateElementList {
 
 impl Format<JsFormatContext> for AnyTemplateElementList {
     fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
-        let layout = if self.is_simple(f.comments()) {
-            TemplateElementLayout::SingleLine
-        } else {
-            TemplateElementLayout::Fit
-        };
+        let layout = self.layout(f.comments());
 
         let mut indention = TemplateElementIndention::default();
         let mut after_new_line = false;
@@ -104,6 +100,15 @@ impl Format<JsFormatContext> for AnyTemplateElementList {
 }
 
 impl AnyTemplateElementList {
+    /// Returns the layout to use for the expressions of the template
+    pub(crate) fn layout(&self, comments: &JsComments) -> TemplateElementLayout {
+        if self.is_simple(comments) {
+            TemplateElementLayout::SingleLine
+        } else {
+            TemplateElementLayout::Fit
+        }
+    }
+
     /// Returns `true` for `JsTemplate` if all elements are simple expressions that should be printed on a single line.
     ///
     /// Simple expressions are:
@@ -1,8 +1,8 @@
 use crate::prelude::*;
 
 use biome_formatter::write;
-use biome_js_syntax::JsComputedMemberName;
 use biome_js_syntax::JsComputedMemberNameFields;
+use biome_js_syntax::{AnyJsExpression, JsComputedMemberName, JsSyntaxKind};
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsComputedMemberName;
@@ -15,6 +15,30 @@ impl FormatNodeRule<JsComputedMemberName> for FormatJsComputedMemberName {
             r_brack_token,
         } = node.as_fields();
 
+        // Prettier encloses an assignment in the computed key of an object property in parentheses,
+        // but not in the computed key of a class property
+        let expression = expression?;
+        let needs_parentheses = f.options().prettier_compatibility()
+            && node.syntax().parent().map(|parent| parent.kind())
+                == Some(JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER)
+            && matches!(
+                expression.clone().omit_parentheses(),
+                AnyJsExpression::JsAssignmentExpression(_)
+            );
+
+        if needs_parentheses {
+            return write![
+                f,
+                [
+                    l_brack_token.format(),
+                    text("("),
+                    expression.format(),
+                    text(")"),
+                    r_brack_token.format(),
+                ]
+            ];
+        }
+
         write![
             f,
             [
@@ -1,5 +1,8 @@
 //! Biome's official JavaScript formatter.
 //!
+//! With the `parser` feature, use `format_source` to format a source string with the given
+//! [JsFormatOptions](context::JsFormatOptions).
+//!
 //! ## Implement the formatter
 //!
 //! Our formatter is node based. Meaning that each AST node knows how to format itself. In order to implement
@@ -181,11 +184,15 @@ mod syntax_rewriter;
 
 use biome_formatter::format_element::tag::Label;
 use biome_formatter::prelude::*;
+#[cfg(feature = "parser")]
+use biome_formatter::FormatError;
 use biome_formatter::{
     comments::Comments, write, CstFormatContext, Format, FormatLanguage, FormatToken,
     TransformSourceMap,
 };
 use biome_formatter::{Buffer, FormatOwnedWithRule, FormatRefWithRule, Formatted, Printed};
+#[cfg(feature = "parser")]
+use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{
     AnyJsDeclaration, AnyJsStatement, JsLanguage, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
 };
@@ -462,7 +469,10 @@ impl FormatLanguage for JsFormatLanguage {
         &self,
         root: &SyntaxNode<Self::SyntaxLanguage>,
     ) -> Option<(SyntaxNode<Self::SyntaxLanguage>, TransformSourceMap)> {
-        Some(transform(root.clone()))
+        Some(transform(
+            root.clone(),
+            self.options.prettier_compatibility(),
+        ))
     }
 
     fn is_range_formatting_node(&self, node: &JsSyntaxNode) -> bool {
@@ -524,6 +534,40 @@ pub fn format_node(
     biome_formatter::format_node(root, JsFormatLanguage::new(options))
 }
 
+/// Formats the JavaScript (and its super languages) `source` code with the given `options`.
+///
+/// This is the entry point for the tools that embed the formatter, such as code generators:
+/// the source is parsed using the file source of the `options`, then formatted and printed.
+///
+/// It returns a [Printed] result that contains the formatted code, and a source map that maps
+/// the positions of the formatted code to the positions of the `source`.
+/// It returns [FormatError::SyntaxError] if the `source` contains syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_js_formatter::context::JsFormatOptions;
+/// use biome_js_formatter::format_source;
+/// use biome_js_syntax::JsFileSource;
+///
+/// let options = JsFormatOptions::new(JsFileSource::ts());
+/// let printed = format_source("let a:number=1", options).unwrap();
+///
+/// assert_eq!(printed.as_code(), "let a: number = 1;\n");
+/// assert!(!printed.sourcemap().is_empty());
+/// ```
+#[cfg(feature = "parser")]
+pub fn format_source(source: &str, options: JsFormatOptions) -> FormatResult<Printed> {
+    let parse = biome_js_parser::parse(source, options.source_type(), JsParserOptions::default());
+
+    if parse.has_errors() {
+        return Err(FormatError::SyntaxError);
+    }
+
+    let formatted = format_node(options, &parse.syntax())?;
+    Ok(formatted.print()?)
+}
+
 /// Formats a single node within a file, supported by Biome.
 ///
 /// This runs a simple heuristic to determine the initial indentation
@@ -559,9 +603,13 @@ impl Label for JsLabels {
 mod tests {
 
     use super::format_range;
+    #[cfg(feature = "parser")]
+    use super::format_source;
 
     use crate::context::JsFormatOptions;
     use biome_formatter::IndentStyle;
+    #[cfg(feature = "parser")]
+    use biome_formatter::{FormatError, SourceMarker};
     use biome_js_parser::{parse, parse_script, JsParserOptions};
     use biome_js_syntax::JsFileSource;
     use biome_rowan::{TextRange, TextSize};
@@ -754,4 +802,31 @@ function() {
 
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "parser")]
+    #[test]
+    fn format_source_returns_sourcemap() {
+        let src = "let   value=1";
+
+        let result = format_source(src, JsFormatOptions::new(JsFileSource::js_module()))
+            .expect("formatting failed");
+
+        assert_eq!(result.as_code(), "let value = 1;\n");
+        // `value` moved from the position 6 to the position 4
+        assert!(result.sourcemap().contains(&SourceMarker {
+            source: TextSize::from(6),
+            dest: TextSize::from(4),
+        }));
+    }
+
+    #[cfg(feature = "parser")]
+    #[test]
+    fn format_source_with_syntax_errors() {
+        let result = format_source(
+            "let value = ;",
+            JsFormatOptions::new(JsFileSource::js_module()),
+        );
+
+        assert_eq!(result, Err(FormatError::SyntaxError));
+    }
 }
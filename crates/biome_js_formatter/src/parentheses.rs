@@ -1075,7 +1075,7 @@ pub(crate) mod tests {
         );
 
         let root = parse.syntax();
-        let (transformed, _) = transform(root);
+        let (transformed, _) = transform(root, false);
         let matching_nodes: Vec<_> = transformed.descendants().filter_map(T::cast).collect();
 
         let node = if let Some(index) = index {
@@ -1116,7 +1116,7 @@ pub(crate) mod tests {
         );
 
         let root = parse.syntax();
-        let (transformed, _) = transform(root);
+        let (transformed, _) = transform(root, false);
         let matching_nodes: Vec<_> = transformed.descendants().filter_map(T::cast).collect();
 
         let node = if let Some(index) = index {
@@ -3,7 +3,7 @@ use crate::parentheses::AnyJsParenthesized;
 use biome_formatter::{TransformSourceMap, TransformSourceMapBuilder};
 use biome_js_syntax::{
     AnyJsAssignment, AnyJsExpression, AnyJsOptionalChainExpression, AnyTsType, JsLanguage,
-    JsLogicalExpression, JsSyntaxKind, JsSyntaxNode,
+    JsLogicalExpression, JsSyntaxKind, JsSyntaxNode, TsNonNullAssertionExpression,
 };
 use biome_rowan::syntax::SyntaxTrivia;
 use biome_rowan::{
@@ -12,8 +12,12 @@ use biome_rowan::{
 };
 use std::collections::BTreeSet;
 
-pub(super) fn transform(root: JsSyntaxNode) -> (JsSyntaxNode, TransformSourceMap) {
+pub(super) fn transform(
+    root: JsSyntaxNode,
+    prettier_compatibility: bool,
+) -> (JsSyntaxNode, TransformSourceMap) {
     let mut rewriter = JsFormatSyntaxRewriter::with_offset(root.text_range().start());
+    rewriter.prettier_compatibility = prettier_compatibility;
     let transformed = rewriter.transform(root);
     (transformed, rewriter.finish())
 }
@@ -41,6 +45,9 @@ struct JsFormatSyntaxRewriter {
     /// parenthesized expressions but the ranges of the `(` trailing trivia pieces no longer match the source ranges because
     /// they are now off by 1 because of the removed `(`.
     l_paren_source_position: BTreeSet<TextSize>,
+
+    /// Keeps the parentheses of non-null-asserted optional chains, `(a?.b)!` and `(a?.b!)`, like Prettier does.
+    prettier_compatibility: bool,
 }
 
 impl JsFormatSyntaxRewriter {
@@ -152,6 +159,9 @@ impl JsFormatSyntaxRewriter {
                     // Don't remove parentheses if it is an optional chain inside a chain that doesn't start by an optional token
                     // (a?.b).c
                     || (parenthesized.syntax().parent().and_then(AnyJsOptionalChainExpression::cast).is_some_and(|chain| chain.optional_chain_token().is_none()) && AnyJsOptionalChainExpression::cast_ref(&inner).is_some_and(|x| x.is_optional_chain()))
+                    // Prettier keeps the parentheses of a non-null-asserted optional chain
+                    // (a?.b)! and (a?.b!)
+                    || (self.prettier_compatibility && is_non_null_asserted_optional_chain(parenthesized.syntax(), &inner))
                 {
                     return VisitNodeSignal::Traverse(parenthesized.into_syntax());
                 } else {
@@ -447,6 +457,24 @@ impl SyntaxRewriter for JsFormatSyntaxRewriter {
     }
 }
 
+/// Returns `true` for the optional chain of `(a?.b)!` and the non-null assertion of `(a?.b!)`.
+fn is_non_null_asserted_optional_chain(parenthesized: &JsSyntaxNode, inner: &JsSyntaxNode) -> bool {
+    let is_optional_chain = |node: &JsSyntaxNode| {
+        AnyJsOptionalChainExpression::cast_ref(node).is_some_and(|chain| chain.is_optional_chain())
+    };
+
+    if parenthesized
+        .parent()
+        .is_some_and(|parent| parent.kind() == JsSyntaxKind::TS_NON_NULL_ASSERTION_EXPRESSION)
+    {
+        is_optional_chain(inner)
+    } else {
+        TsNonNullAssertionExpression::cast_ref(inner)
+            .and_then(|assertion| assertion.expression().ok())
+            .is_some_and(|expression| is_optional_chain(expression.syntax()))
+    }
+}
+
 fn has_type_cast_comment_or_skipped(trivia: &SyntaxTrivia<JsLanguage>) -> bool {
     trivia.pieces().any(|piece| {
         if let Some(comment) = piece.as_comments() {
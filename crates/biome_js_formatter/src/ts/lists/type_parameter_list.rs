@@ -14,7 +14,7 @@ impl FormatRule<TsTypeParameterList> for FormatTsTypeParameterList {
         // to avoid any ambiguity with JSX elements.
         // Thus, we have to add a trailing comma when there is a single type parameter.
         // The comma can be omitted in the case where the single parameter has a constraint,
-        // i.i. an `extends` clause.
+        // i.i. an `extends` clause, or a default type unless Prettier compatibility is enabled.
         let trailing_separator = if node.len() == 1
             // This only concern sources that allow JSX or a restricted standard variant.
             && !f.options().source_type().variant().is_standard()
@@ -22,7 +22,8 @@ impl FormatRule<TsTypeParameterList> for FormatTsTypeParameterList {
                 == Some(JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION)
             // Ignore Type parameter with an `extends`` clause or a default type.
             && !node.first().and_then(|param| param.ok())
-                .is_some_and(|type_parameter| type_parameter.constraint().is_some() || type_parameter.default().is_some())
+                .is_some_and(|type_parameter| type_parameter.constraint().is_some()
+                    || (type_parameter.default().is_some() && !f.options().prettier_compatibility()))
         {
             TrailingSeparator::Mandatory
         } else {
@@ -0,0 +1,221 @@
+use crate::js::auxiliary::template_element::TemplateElementOptions;
+use crate::js::lists::template_element_list::AnyTemplateElementList;
+use crate::prelude::*;
+use crate::utils::is_template_tagged_with;
+use biome_formatter::{write, CstFormatContext};
+use biome_js_syntax::{
+    AnyJsTemplateElement, JsTemplateChunkElement, JsTemplateElementList, JsTemplateExpression,
+};
+use biome_rowan::AstNode;
+
+/// Formats the content of a template literal passed to a dedent-style tag, such as
+/// [`dedent`](https://github.com/dmnd/dedent) or [`outdent`](https://github.com/cspotcode/outdent).
+///
+/// These tags remove the indentation that is common to all the lines of the template. This makes
+/// it safe to reindent the content, so it follows the indentation of the surrounding code:
+///
+/// ```javascript
+/// function foo() {
+///             const query = dedent`
+///     SELECT *
+///       FROM users
+///   `;
+/// }
+/// ```
+///
+/// Becomes
+///
+/// ```javascript
+/// function foo() {
+///     const query = dedent`
+///         SELECT *
+///           FROM users
+///     `;
+/// }
+/// ```
+///
+/// The template is left untouched if its first line isn't empty, or if one of its lines
+/// isn't indented.
+pub(crate) struct DedentTemplateElementList {
+    list: JsTemplateElementList,
+    /// Number of whitespace characters removed from the start of each line
+    common_indentation: usize,
+}
+
+impl DedentTemplateElementList {
+    pub(crate) fn new(template: &JsTemplateExpression, dedent_tags: &[String]) -> Option<Self> {
+        if !is_template_tagged_with(template, dedent_tags) {
+            return None;
+        }
+
+        let list = template.elements();
+        let common_indentation = common_indentation(&list)?;
+
+        Some(Self {
+            list,
+            common_indentation,
+        })
+    }
+}
+
+impl Format<JsFormatContext> for DedentTemplateElementList {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        let last_index = self.list.len().saturating_sub(1);
+        let layout =
+            AnyTemplateElementList::JsTemplateElementList(self.list.clone()).layout(f.comments());
+
+        let format_content = format_with(|f| {
+            for (index, element) in self.list.iter().enumerate() {
+                match element {
+                    AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                        let format_chunk = FormatDedentChunk {
+                            chunk: &chunk,
+                            is_first: index == 0,
+                            is_last: index == last_index,
+                            common_indentation: self.common_indentation,
+                        };
+
+                        write!(f, [format_chunk])?;
+                    }
+                    AnyJsTemplateElement::JsTemplateElement(element) => {
+                        let options = TemplateElementOptions {
+                            layout,
+                            ..TemplateElementOptions::default()
+                        };
+
+                        write!(f, [element.format().with_options(options)])?;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        write!(f, [indent(&format_content)])
+    }
+}
+
+/// Prints a single chunk of text of a dedent template.
+///
+/// New lines are printed as part of the text so that empty lines are preserved, followed by
+/// a hard line break that doesn't print anything but makes the printer indent the next line.
+struct FormatDedentChunk<'a> {
+    chunk: &'a JsTemplateChunkElement,
+    /// Whether this chunk starts right after the opening backtick
+    is_first: bool,
+    /// Whether this chunk ends right before the closing backtick
+    is_last: bool,
+    common_indentation: usize,
+}
+
+impl Format<JsFormatContext> for FormatDedentChunk<'_> {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        f.context()
+            .comments()
+            .mark_suppression_checked(self.chunk.syntax());
+
+        let token = self.chunk.template_chunk_token()?;
+        let position = token.text_trimmed_range().start();
+        // Per https://tc39.es/ecma262/multipage/ecmascript-language-lexical-grammar.html#sec-static-semantics-trv:
+        // In template literals, the '\r' and '\r\n' line terminators are normalized to '\n'
+        let text = normalize_newlines(token.text_trimmed(), ['\r']);
+
+        let format_lines = format_with(|f| {
+            let mut lines = text.split('\n').peekable();
+            let mut new_lines = String::new();
+
+            // The first line is the continuation of the current line: it's removed
+            // right after the opening backtick since it only contains whitespace
+            if let Some(first_line) = lines.next() {
+                if !self.is_first && !first_line.is_empty() {
+                    write!(f, [dynamic_text(first_line, position)])?;
+                }
+            }
+
+            while let Some(line) = lines.next() {
+                new_lines.push('\n');
+
+                let is_line_end = lines.peek().is_some();
+                let is_blank = line.trim_matches(is_indentation).is_empty();
+
+                if is_blank && is_line_end {
+                    // Empty lines are only printed as part of the next line
+                    continue;
+                }
+
+                write!(f, [dynamic_text(&new_lines, position)])?;
+                new_lines.clear();
+
+                if is_blank && self.is_last {
+                    // The closing backtick goes back to the indentation of the template
+                    write!(f, [dedent(&hard_line_break())])?;
+                } else {
+                    write!(f, [hard_line_break()])?;
+
+                    let indentation = line.len() - line.trim_start_matches(is_indentation).len();
+                    let line = &line[indentation.min(self.common_indentation)..];
+                    if !line.is_empty() {
+                        write!(f, [dynamic_text(line, position)])?;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        write!(f, [format_replaced(&token, &format_lines)])
+    }
+}
+
+fn is_indentation(c: char) -> bool {
+    matches!(c, ' ' | '\t')
+}
+
+/// Returns the number of whitespace characters that all the lines of the template start with,
+/// or `None` if the template can't be reindented.
+fn common_indentation(list: &JsTemplateElementList) -> Option<usize> {
+    let mut elements = list.iter().peekable();
+
+    // The content must start on the line following the opening backtick
+    let Some(AnyJsTemplateElement::JsTemplateChunkElement(first)) = elements.peek() else {
+        return None;
+    };
+    let first_text = first.template_chunk_token().ok()?;
+    let (first_line, _) = first_text.text_trimmed().split_once('\n')?;
+    let first_line = first_line.trim_end_matches('\r');
+    if !first_line.trim_matches(is_indentation).is_empty() {
+        return None;
+    }
+
+    let mut common_indentation: Option<usize> = None;
+
+    while let Some(element) = elements.next() {
+        let AnyJsTemplateElement::JsTemplateChunkElement(chunk) = element else {
+            continue;
+        };
+
+        let token = chunk.template_chunk_token().ok()?;
+        let mut lines = token.text_trimmed().split('\n').peekable();
+        // The first line of a chunk is the continuation of a line that started in a previous chunk
+        lines.next();
+
+        while let Some(line) = lines.next() {
+            let line = line.trim_end_matches('\r');
+            let content = line.trim_start_matches(is_indentation);
+            let is_line_end = lines.peek().is_some();
+
+            // Skip the lines that only contain whitespace, unless they're followed by an interpolation
+            if content.is_empty() && (is_line_end || elements.peek().is_none()) {
+                continue;
+            }
+
+            let indentation = line.len() - content.len();
+            common_indentation = Some(match common_indentation {
+                Some(common_indentation) => common_indentation.min(indentation),
+                None => indentation,
+            });
+        }
+    }
+
+    common_indentation.filter(|indentation| *indentation > 0)
+}
@@ -1,3 +1,4 @@
+use crate::context::DecoratorPlacement;
 use crate::prelude::*;
 use crate::utils::sort_modifiers_by_precedence;
 use crate::{AsFormat, IntoFormat};
@@ -24,7 +25,19 @@ where
 {
     fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
         let modifiers = sort_modifiers_by_precedence(&self.list);
-        let should_expand = should_expand_decorators(&self.list);
+        let decorators_count = self
+            .list
+            .iter()
+            .filter(|node| node.syntax().kind() == JS_DECORATOR)
+            .count();
+        let should_expand = match f.options().decorator_placement() {
+            DecoratorPlacement::Preserve => should_expand_decorators(&self.list),
+            // a single decorator stays on the same line as the member if it fits
+            DecoratorPlacement::SameLine => {
+                decorators_count > 1 && should_expand_decorators(&self.list)
+            }
+            DecoratorPlacement::OwnLine => decorators_count > 0,
+        };
 
         // Returning early here is important, because otherwise this node
         // returns a group that always has a soft line break, which causes
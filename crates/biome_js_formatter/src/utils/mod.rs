@@ -2,6 +2,7 @@ pub(crate) mod array;
 mod assignment_like;
 mod binary_like_expression;
 mod conditional;
+pub(crate) mod dedent_template;
 pub mod string_utils;
 
 pub(crate) mod format_class;
@@ -14,6 +15,7 @@ mod object_like;
 mod object_pattern_like;
 #[cfg(test)]
 mod quickcheck_utils;
+pub(crate) mod sql_template;
 pub(crate) mod test_call;
 pub(crate) mod test_each_template;
 mod typescript;
@@ -32,7 +34,8 @@ pub(crate) use binary_like_expression::{
 use biome_formatter::{format_args, write, Buffer};
 use biome_js_syntax::JsSyntaxToken;
 use biome_js_syntax::{
-    AnyJsExpression, AnyJsStatement, JsCallExpression, JsInitializerClause, JsLanguage, Modifiers,
+    AnyJsExpression, AnyJsStatement, JsCallExpression, JsInitializerClause, JsLanguage,
+    JsTemplateExpression, Modifiers,
 };
 use biome_rowan::{AstNode, AstNodeList};
 use biome_text_size::TextSize;
@@ -45,6 +48,32 @@ pub(crate) use typescript::{
     TsIntersectionOrUnionTypeList,
 };
 
+/// Returns `true` if the tag of `template` is one of the `tags`.
+///
+/// The tag matches if it's the name of the tag (`dedent`), a member expression
+/// (`String.dedent`), or a call of one of them (`dedent({ trimWhitespace: false })`).
+pub(crate) fn is_template_tagged_with(template: &JsTemplateExpression, tags: &[String]) -> bool {
+    let Some(mut tag) = template.tag() else {
+        return false;
+    };
+
+    if let AnyJsExpression::JsCallExpression(call) = &tag {
+        match call.callee() {
+            Ok(callee) => tag = callee,
+            Err(_) => return false,
+        }
+    }
+
+    match tag {
+        AnyJsExpression::JsIdentifierExpression(_)
+        | AnyJsExpression::JsStaticMemberExpression(_) => {
+            let text = tag.syntax().text_trimmed();
+            tags.iter().any(|name| text == name.as_str())
+        }
+        _ => false,
+    }
+}
+
 /// Tests if expression is a long curried call
 ///
 /// ```javascript
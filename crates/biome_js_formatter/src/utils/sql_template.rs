@@ -0,0 +1,732 @@
+use crate::js::auxiliary::template_element::TemplateElementOptions;
+use crate::js::lists::template_element_list::AnyTemplateElementList;
+use crate::prelude::*;
+use crate::utils::is_template_tagged_with;
+use biome_formatter::prelude::tag::Tag;
+use biome_formatter::{write, CstFormatContext};
+use biome_js_syntax::{AnyJsTemplateElement, JsTemplateElementList, JsTemplateExpression};
+use biome_rowan::{AstNode, TextSize};
+
+/// Formats the content of a template literal passed to a SQL tag, such as
+/// [`sql`](https://github.com/porsager/postgres) or [`Prisma.sql`](https://www.prisma.io/docs/concepts/components/prisma-client/raw-database-access).
+///
+/// The keywords are printed in uppercase, and each clause of the query starts on its own line:
+///
+/// ```javascript
+/// const users = sql`
+///   select id, name from users
+///   where age > ${age} and active = true order by name
+/// `;
+/// ```
+///
+/// Becomes
+///
+/// ```javascript
+/// const users = sql`
+///   SELECT id, name
+///   FROM users
+///   WHERE age > ${age}
+///     AND active = TRUE
+///   ORDER BY name
+/// `;
+/// ```
+///
+/// A query written on a single line stays on a single line. The interpolations are kept
+/// where they are, and the template is left untouched if the query can't be tokenized.
+pub(crate) struct SqlTemplateElementList {
+    list: JsTemplateElementList,
+    lines: Vec<SqlLine>,
+    /// Whether the query is printed on multiple lines, which is the case if it spans
+    /// multiple lines in the source
+    is_multiline: bool,
+}
+
+impl SqlTemplateElementList {
+    pub(crate) fn new(template: &JsTemplateExpression, sql_tags: &[String]) -> Option<Self> {
+        if !is_template_tagged_with(template, sql_tags) {
+            return None;
+        }
+
+        let list = template.elements();
+        let tokens = tokenize(&list)?;
+        if tokens.is_empty() {
+            return None;
+        }
+
+        let is_multiline = list.iter().any(|element| match element {
+            AnyJsTemplateElement::JsTemplateChunkElement(chunk) => chunk
+                .template_chunk_token()
+                .is_ok_and(|token| token.text_trimmed().contains('\n')),
+            AnyJsTemplateElement::JsTemplateElement(_) => false,
+        });
+
+        let lines = SqlLayout::new(is_multiline).layout(&tokens);
+
+        Some(Self {
+            list,
+            lines,
+            is_multiline,
+        })
+    }
+}
+
+impl Format<JsFormatContext> for SqlTemplateElementList {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        let layout =
+            AnyTemplateElementList::JsTemplateElementList(self.list.clone()).layout(f.comments());
+        let elements: Vec<_> = self.list.iter().collect();
+
+        // The text of the chunks is replaced by the formatted query
+        for element in &elements {
+            if let AnyJsTemplateElement::JsTemplateChunkElement(chunk) = element {
+                f.context()
+                    .comments()
+                    .mark_suppression_checked(chunk.syntax());
+                write!(f, [format_removed(&chunk.template_chunk_token()?)])?;
+            }
+        }
+
+        let format_line = |line: &SqlLine, f: &mut JsFormatter| -> FormatResult<()> {
+            for piece in &line.pieces {
+                match piece {
+                    SqlPiece::Text(text, position) => {
+                        write!(f, [dynamic_text(text, *position)])?;
+                    }
+                    SqlPiece::Placeholder(index) => {
+                        if let Some(AnyJsTemplateElement::JsTemplateElement(element)) =
+                            elements.get(*index)
+                        {
+                            let options = TemplateElementOptions {
+                                layout,
+                                ..TemplateElementOptions::default()
+                            };
+
+                            write!(f, [element.format().with_options(options)])?;
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        };
+
+        if !self.is_multiline {
+            return match self.lines.first() {
+                Some(line) => format_line(line, f),
+                None => Ok(()),
+            };
+        }
+
+        let format_lines = format_with(|f| {
+            for line in &self.lines {
+                for _ in 0..line.depth {
+                    f.write_element(FormatElement::Tag(Tag::StartIndent))?;
+                }
+
+                write!(f, [hard_line_break()])?;
+                format_line(line, f)?;
+
+                for _ in 0..line.depth {
+                    f.write_element(FormatElement::Tag(Tag::EndIndent))?;
+                }
+            }
+
+            Ok(())
+        });
+
+        write!(f, [indent(&format_lines), hard_line_break()])
+    }
+}
+
+/// A token of the embedded query
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum SqlToken {
+    /// A keyword, an identifier, a number, or a parameter
+    Word(String),
+    /// A string literal, a quoted identifier, or a dollar-quoted string `$tag$...$tag$`
+    Quoted(String),
+    Operator(String),
+    Comma,
+    Semicolon,
+    Dot,
+    /// The PostgreSQL cast operator `::`
+    Cast,
+    LParen {
+        /// Whether the parenthesis is preceded by whitespace in the source
+        has_leading_whitespace: bool,
+    },
+    RParen,
+    LineComment(String),
+    BlockComment(String),
+    /// An interpolation `${...}`, identified by its index in the template elements
+    Placeholder(usize),
+}
+
+/// A token paired with the position of the chunk it comes from
+type PositionedSqlToken = (SqlToken, TextSize);
+
+/// Splits the chunks of the template in SQL tokens, or returns `None` if the query contains
+/// an unterminated string or comment, or a character that isn't supported.
+///
+/// The tokens keep their text, so the formatter only changes the whitespace between them.
+fn tokenize(list: &JsTemplateElementList) -> Option<Vec<PositionedSqlToken>> {
+    let mut tokens = Vec::new();
+    let mut has_leading_whitespace = false;
+    let mut elements = list.iter().enumerate().peekable();
+
+    while let Some((index, element)) = elements.next() {
+        let chunk = match element {
+            AnyJsTemplateElement::JsTemplateElement(element) => {
+                let position = element.syntax().text_trimmed_range().start();
+                tokens.push((SqlToken::Placeholder(index), position));
+                has_leading_whitespace = false;
+                continue;
+            }
+            AnyJsTemplateElement::JsTemplateChunkElement(chunk) => chunk,
+        };
+
+        let token = chunk.template_chunk_token().ok()?;
+        let position = token.text_trimmed_range().start();
+        let text = normalize_newlines(token.text_trimmed(), ['\r']);
+        let is_last_chunk = elements.peek().is_none();
+        let mut rest: &str = &text;
+
+        while let Some(c) = rest.chars().next() {
+            if c.is_whitespace() {
+                rest = &rest[c.len_utf8()..];
+                has_leading_whitespace = true;
+                continue;
+            }
+
+            let (token, len) = if rest.starts_with("--") {
+                let len = match rest.find('\n') {
+                    Some(len) => len,
+                    // The interpolations that follow would end up in the comment
+                    None if !is_last_chunk => return None,
+                    None => rest.len(),
+                };
+                (
+                    SqlToken::LineComment(rest[..len].trim_end().to_string()),
+                    len,
+                )
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                let len = comment.find("*/")? + 4;
+                (SqlToken::BlockComment(rest[..len].to_string()), len)
+            } else if rest.starts_with("::") {
+                (SqlToken::Cast, 2)
+            } else if c == '\'' || c == '"' || c == '`' {
+                let len = quoted_len(rest, c)?;
+                (SqlToken::Quoted(rest[..len].to_string()), len)
+            } else if let Some(delimiter) = dollar_quote_delimiter(rest) {
+                // The content of the string, e.g. the body of a function, is kept as it is
+                let len = rest[delimiter.len()..].find(delimiter)? + 2 * delimiter.len();
+                (SqlToken::Quoted(rest[..len].to_string()), len)
+            } else if is_word_start(c, rest) {
+                let len = word_len(rest);
+                (SqlToken::Word(rest[..len].to_string()), len)
+            } else if is_operator_char(c) {
+                let len = operator_len(rest);
+                (SqlToken::Operator(rest[..len].to_string()), len)
+            } else {
+                let token = match c {
+                    ',' => SqlToken::Comma,
+                    ';' => SqlToken::Semicolon,
+                    '.' => SqlToken::Dot,
+                    '(' => SqlToken::LParen {
+                        has_leading_whitespace,
+                    },
+                    ')' => SqlToken::RParen,
+                    _ => return None,
+                };
+                (token, 1)
+            };
+
+            tokens.push((token, position));
+            rest = &rest[len..];
+            has_leading_whitespace = false;
+        }
+    }
+
+    Some(tokens)
+}
+
+fn is_operator_char(c: char) -> bool {
+    "+-*/<>=~!@#%^&|?".contains(c)
+}
+
+/// Returns the length of the operator that starts `text`. Like in PostgreSQL, an operator is
+/// the longest run of operator characters that doesn't contain a comment, such as `@>` or
+/// `#>>`, so the operators that the formatter doesn't know are never split. A run that ends
+/// with `+` or `-` only keeps them if it contains one of `~ ! @ # % ^ & | ?`, so `=-1` is the
+/// operator `=` followed by `-1`.
+fn operator_len(text: &str) -> usize {
+    let len = text
+        .char_indices()
+        .find(|(index, c)| {
+            !is_operator_char(*c)
+                || text[*index..].starts_with("--")
+                || text[*index..].starts_with("/*")
+        })
+        .map_or(text.len(), |(index, _)| index);
+    let operator = &text[..len];
+
+    if operator.contains(&['~', '!', '@', '#', '%', '^', '&', '|', '?']) {
+        len
+    } else {
+        operator.trim_end_matches(&['+', '-']).len().max(1)
+    }
+}
+
+fn is_word_start(c: char, text: &str) -> bool {
+    let next = text[c.len_utf8()..].chars().next();
+    match c {
+        // Named parameters, for example `:name`
+        ':' => next.is_some_and(is_word_part),
+        // Variables and positional parameters, unless they start an operator such as `@>`
+        '@' | '#' | '?' => !next.is_some_and(is_operator_char),
+        _ => is_word_part(c),
+    }
+}
+
+fn is_word_part(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$' | '@' | '#')
+}
+
+fn word_len(text: &str) -> usize {
+    let mut chars = text.char_indices().peekable();
+    // The first character was already checked
+    chars.next();
+
+    while let Some((index, c)) = chars.next() {
+        let starts_operator = matches!(c, '@' | '#')
+            && matches!(chars.peek(), Some((_, next)) if is_operator_char(*next));
+        if !is_word_part(c) || starts_operator {
+            return index;
+        }
+    }
+
+    text.len()
+}
+
+/// Returns the length of the string starting with the `quote`, or `None` if the string isn't
+/// terminated in this chunk.
+fn quoted_len(text: &str, quote: char) -> Option<usize> {
+    let mut chars = text.char_indices().skip(1).peekable();
+
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' {
+            chars.next();
+        } else if c == quote {
+            // A doubled quote is an escaped quote
+            if matches!(chars.peek(), Some((_, next)) if *next == quote) {
+                chars.next();
+            } else {
+                return Some(index + 1);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the opening delimiter of the PostgreSQL dollar-quoted string that starts `text`,
+/// `$$` or `$tag$`, or `None` if `text` doesn't start with one, e.g. a parameter `$1`.
+fn dollar_quote_delimiter(text: &str) -> Option<&str> {
+    let tag = text.strip_prefix('$')?;
+    let tag_len = tag.find('$')?;
+    let tag = &tag[..tag_len];
+    let is_tag = tag
+        .chars()
+        .enumerate()
+        .all(|(index, c)| c.is_alphabetic() || c == '_' || (index > 0 && c.is_ascii_digit()));
+    is_tag.then(|| &text[..tag_len + 2])
+}
+
+/// The keywords printed in uppercase, sorted for the binary search
+const KEYWORDS: [&str; 87] = [
+    "ALL",
+    "ALTER",
+    "AND",
+    "ANY",
+    "AS",
+    "ASC",
+    "BETWEEN",
+    "BY",
+    "CASCADE",
+    "CASE",
+    "CAST",
+    "COALESCE",
+    "CONFLICT",
+    "CONSTRAINT",
+    "COUNT",
+    "CREATE",
+    "CROSS",
+    "CURRENT_DATE",
+    "CURRENT_TIMESTAMP",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "DROP",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FETCH",
+    "FILTER",
+    "FIRST",
+    "FOR",
+    "FROM",
+    "FULL",
+    "GROUP",
+    "HAVING",
+    "ILIKE",
+    "IN",
+    "INDEX",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTERVAL",
+    "INTO",
+    "IS",
+    "JOIN",
+    "LAST",
+    "LATERAL",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NATURAL",
+    "NOT",
+    "NOTHING",
+    "NULL",
+    "NULLS",
+    "OFFSET",
+    "ON",
+    "ONLY",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "OVER",
+    "PARTITION",
+    "PRIMARY",
+    "RECURSIVE",
+    "REFERENCES",
+    "RETURNING",
+    "RIGHT",
+    "ROWS",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "THEN",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "VIEW",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+fn is_keyword(word: &str) -> bool {
+    KEYWORDS.binary_search(&word).is_ok()
+}
+
+/// The words that can precede `JOIN`
+const JOIN_PREFIXES: [&str; 6] = ["CROSS", "FULL", "INNER", "LEFT", "NATURAL", "RIGHT"];
+
+/// A line of the formatted query
+#[derive(Debug, Default)]
+struct SqlLine {
+    /// The number of indentation levels, relative to the template
+    depth: usize,
+    pieces: Vec<SqlPiece>,
+}
+
+#[derive(Debug)]
+enum SqlPiece {
+    Text(String, TextSize),
+    Placeholder(usize),
+}
+
+#[derive(Debug)]
+enum SqlParenthesis {
+    /// A parenthesis printed on the same line, for example the arguments of a function
+    Inline,
+    /// A parenthesis around a subquery, whose content goes on its own lines
+    Block {
+        /// The depth of the line that contains the opening parenthesis
+        line_depth: usize,
+        /// The state of the enclosing query, restored at the closing parenthesis
+        clause_depth: usize,
+        breaks_conditions: bool,
+        in_between: bool,
+    },
+}
+
+/// Splits the tokens in lines
+struct SqlLayout {
+    is_multiline: bool,
+    lines: Vec<SqlLine>,
+    current: SqlLine,
+    parentheses: Vec<SqlParenthesis>,
+    /// The depth of the clauses of the current query
+    clause_depth: usize,
+    /// Whether `AND` and `OR` start a new line, which is the case in the conditions of
+    /// `WHERE`, `HAVING`, and `JOIN`
+    breaks_conditions: bool,
+    /// Whether the next `AND` is part of a `BETWEEN`
+    in_between: bool,
+    /// Whether the current clause started with a `JOIN` prefix, such as `LEFT`
+    in_join_prefix: bool,
+    /// Whether the next token starts a new line, after a line comment or a semicolon
+    forces_new_line: bool,
+    previous: Option<SqlToken>,
+    /// Whether the previous token is a unary operator, for example the `-` in `= -1`
+    previous_is_unary: bool,
+}
+
+impl SqlLayout {
+    fn new(is_multiline: bool) -> Self {
+        Self {
+            is_multiline,
+            lines: Vec::new(),
+            current: SqlLine::default(),
+            parentheses: Vec::new(),
+            clause_depth: 0,
+            breaks_conditions: false,
+            in_between: false,
+            in_join_prefix: false,
+            forces_new_line: false,
+            previous: None,
+            previous_is_unary: false,
+        }
+    }
+
+    fn layout(mut self, tokens: &[PositionedSqlToken]) -> Vec<SqlLine> {
+        for (index, (token, position)) in tokens.iter().enumerate() {
+            if self.forces_new_line {
+                self.forces_new_line = false;
+                self.new_line(self.clause_depth);
+            }
+
+            let next_word = |offset: usize| match tokens.get(index + offset) {
+                Some((SqlToken::Word(word), _)) => Some(word.to_ascii_uppercase()),
+                _ => None,
+            };
+
+            match token {
+                SqlToken::Word(word) => {
+                    let upper = word.to_ascii_uppercase();
+                    let is_member = matches!(self.previous, Some(SqlToken::Dot));
+                    let text = if !is_member && is_keyword(&upper) {
+                        upper.clone()
+                    } else {
+                        word.clone()
+                    };
+
+                    if !is_member && self.breaks_lines() {
+                        self.break_before_word(&upper, next_word);
+                    }
+
+                    match upper.as_str() {
+                        "BETWEEN" => self.in_between = true,
+                        "AND" if self.in_between => self.in_between = false,
+                        _ => {}
+                    }
+
+                    self.push_text(token, text, *position);
+                }
+                SqlToken::Quoted(text)
+                | SqlToken::Operator(text)
+                | SqlToken::LineComment(text)
+                | SqlToken::BlockComment(text) => {
+                    self.push_text(token, text.clone(), *position);
+
+                    if matches!(token, SqlToken::LineComment(_)) {
+                        self.forces_new_line = self.is_multiline;
+                    }
+                }
+                SqlToken::Comma => self.push_text(token, ",".to_string(), *position),
+                SqlToken::Dot => self.push_text(token, ".".to_string(), *position),
+                SqlToken::Cast => self.push_text(token, "::".to_string(), *position),
+                SqlToken::Semicolon => {
+                    self.push_text(token, ";".to_string(), *position);
+                    self.breaks_conditions = false;
+                    self.in_between = false;
+                    self.forces_new_line = self.is_multiline && self.parentheses.is_empty();
+                }
+                SqlToken::LParen { .. } => {
+                    self.push_text(token, "(".to_string(), *position);
+
+                    let starts_subquery =
+                        matches!(next_word(1).as_deref(), Some("SELECT" | "WITH"));
+                    if self.breaks_lines() && starts_subquery {
+                        self.parentheses.push(SqlParenthesis::Block {
+                            line_depth: self.current.depth,
+                            clause_depth: self.clause_depth,
+                            breaks_conditions: self.breaks_conditions,
+                            in_between: self.in_between,
+                        });
+                        self.clause_depth = self.current.depth + 1;
+                        self.breaks_conditions = false;
+                        self.in_between = false;
+                        self.new_line(self.clause_depth);
+                    } else {
+                        self.parentheses.push(SqlParenthesis::Inline);
+                    }
+                }
+                SqlToken::RParen => {
+                    if let Some(SqlParenthesis::Block {
+                        line_depth,
+                        clause_depth,
+                        breaks_conditions,
+                        in_between,
+                    }) = self.parentheses.pop()
+                    {
+                        self.clause_depth = clause_depth;
+                        self.breaks_conditions = breaks_conditions;
+                        self.in_between = in_between;
+                        self.new_line(line_depth);
+                    }
+
+                    self.push_text(token, ")".to_string(), *position);
+                }
+                SqlToken::Placeholder(element) => {
+                    self.push(token, SqlPiece::Placeholder(*element), *position);
+                }
+            }
+        }
+
+        if !self.current.pieces.is_empty() {
+            self.lines.push(self.current);
+        }
+
+        self.lines
+    }
+
+    /// Returns `true` if the clauses start new lines, which isn't the case in single line
+    /// queries and inside parentheses that aren't subqueries
+    fn breaks_lines(&self) -> bool {
+        self.is_multiline && !matches!(self.parentheses.last(), Some(SqlParenthesis::Inline))
+    }
+
+    /// Starts a new line if `word` starts a clause, or a condition in a `WHERE` clause
+    fn break_before_word(&mut self, word: &str, next_word: impl Fn(usize) -> Option<String>) {
+        let previous_word = match &self.previous {
+            Some(SqlToken::Word(previous)) => Some(previous.to_ascii_uppercase()),
+            _ => None,
+        };
+
+        let starts_clause = match word {
+            "SELECT" | "WHERE" | "HAVING" | "LIMIT" | "OFFSET" | "VALUES" | "SET" | "RETURNING"
+            | "UNION" | "INTERSECT" | "EXCEPT" | "INSERT" | "DELETE" | "WINDOW" => true,
+            "FROM" => !matches!(previous_word.as_deref(), Some("DELETE" | "DISTINCT")),
+            "UPDATE" => !matches!(previous_word.as_deref(), Some("DO" | "FOR")),
+            "WITH" => self.current.pieces.is_empty(),
+            "GROUP" | "ORDER" => next_word(1).as_deref() == Some("BY"),
+            "ON" => next_word(1).as_deref() == Some("CONFLICT"),
+            "JOIN" => !self.in_join_prefix,
+            _ if JOIN_PREFIXES.contains(&word) => {
+                let is_join = (1..=3)
+                    .map(&next_word)
+                    .take_while(|next| {
+                        next.as_deref().is_some_and(|next| {
+                            next == "OUTER" || JOIN_PREFIXES.contains(&next) || next == "JOIN"
+                        })
+                    })
+                    .any(|next| next.as_deref() == Some("JOIN"));
+                is_join && !self.in_join_prefix
+            }
+            _ => false,
+        };
+
+        if starts_clause {
+            self.new_line(self.clause_depth);
+            self.breaks_conditions =
+                matches!(word, "WHERE" | "HAVING" | "JOIN") || JOIN_PREFIXES.contains(&word);
+            self.in_between = false;
+            self.in_join_prefix = JOIN_PREFIXES.contains(&word);
+        } else if word == "JOIN" {
+            self.in_join_prefix = false;
+        } else if matches!(word, "AND" | "OR")
+            && self.breaks_conditions
+            && !(word == "AND" && self.in_between)
+        {
+            self.new_line(self.clause_depth + 1);
+        }
+    }
+
+    fn new_line(&mut self, depth: usize) {
+        if !self.current.pieces.is_empty() {
+            let line = std::mem::take(&mut self.current);
+            self.lines.push(line);
+        }
+
+        self.current.depth = depth;
+    }
+
+    fn push_text(&mut self, token: &SqlToken, text: String, position: TextSize) {
+        self.push(token, SqlPiece::Text(text, position), position);
+    }
+
+    fn push(&mut self, token: &SqlToken, piece: SqlPiece, position: TextSize) {
+        if !self.current.pieces.is_empty() && self.needs_space_before(token) {
+            self.current
+                .pieces
+                .push(SqlPiece::Text(" ".to_string(), position));
+        }
+
+        self.previous_is_unary = match token {
+            SqlToken::Operator(operator) if matches!(operator.as_str(), "-" | "+" | "~") => {
+                match &self.previous {
+                    None
+                    | Some(
+                        SqlToken::Operator(_)
+                        | SqlToken::Comma
+                        | SqlToken::LParen { .. }
+                        | SqlToken::Cast,
+                    ) => true,
+                    Some(SqlToken::Word(word)) => is_keyword(&word.to_ascii_uppercase()),
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+
+        self.current.pieces.push(piece);
+        self.previous = Some(token.clone());
+    }
+
+    fn needs_space_before(&self, token: &SqlToken) -> bool {
+        let Some(previous) = &self.previous else {
+            return false;
+        };
+
+        match (previous, token) {
+            (
+                _,
+                SqlToken::Comma
+                | SqlToken::Semicolon
+                | SqlToken::RParen
+                | SqlToken::Dot
+                | SqlToken::Cast,
+            )
+            | (SqlToken::Dot | SqlToken::Cast | SqlToken::LParen { .. }, _) => false,
+            // Function calls, for example `count(*)`
+            (
+                SqlToken::Word(_) | SqlToken::Quoted(_) | SqlToken::Placeholder(_),
+                SqlToken::LParen {
+                    has_leading_whitespace,
+                },
+            ) => *has_leading_whitespace,
+            (SqlToken::Operator(_), _) => !self.previous_is_unary,
+            _ => true,
+        }
+    }
+}
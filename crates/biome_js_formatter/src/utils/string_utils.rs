@@ -50,8 +50,12 @@ impl<'token> FormatLiteralStringToken<'token> {
         };
         let chosen_quote_properties = options.quote_properties();
 
-        let mut string_cleaner =
-            LiteralStringNormaliser::new(self, chosen_quote_style, chosen_quote_properties);
+        let mut string_cleaner = LiteralStringNormaliser::new(
+            self,
+            chosen_quote_style,
+            chosen_quote_properties,
+            options.prettier_compatibility(),
+        );
 
         let content = string_cleaner.normalise_text(options.source_type().into());
         let normalized_text_width = content.width();
@@ -176,6 +180,8 @@ struct LiteralStringNormaliser<'token> {
     chosen_quote_style: QuoteStyle,
     /// When properties in objects are quoted that was set inside the configuration
     chosen_quote_properties: QuoteProperties,
+    /// Whether only the properties that are valid ES5 identifiers are unquoted, like Prettier does
+    prettier_compatibility: bool,
 }
 
 /// Convenience enum to map [biome_js_syntax::JsFileSource] by just reading
@@ -201,11 +207,13 @@ impl<'token> LiteralStringNormaliser<'token> {
         token: &'token FormatLiteralStringToken<'_>,
         chosen_quote_style: QuoteStyle,
         chosen_quote_properties: QuoteProperties,
+        prettier_compatibility: bool,
     ) -> Self {
         Self {
             token,
             chosen_quote_style,
             chosen_quote_properties,
+            prettier_compatibility,
         }
     }
 
@@ -266,7 +274,9 @@ impl<'token> LiteralStringNormaliser<'token> {
             return false;
         }
 
+        // ES5 identifiers can't contain characters outside of the Basic Multilingual Plane
         is_js_ident(text_to_check)
+            && (!self.prettier_compatibility || text_to_check.chars().all(|c| c <= '\u{FFFF}'))
     }
 
     fn normalise_type_member(
@@ -432,7 +442,7 @@ mod tests {
         let token = generate_syntax_token(input);
         let string_token = as_token.into_token(&token);
         let mut string_cleaner =
-            LiteralStringNormaliser::new(&string_token, quote, quote_properties);
+            LiteralStringNormaliser::new(&string_token, quote, quote_properties, false);
         let content = string_cleaner.normalise_text(source);
         assert_eq!(content, Cow::Borrowed(input))
     }
@@ -448,7 +458,7 @@ mod tests {
         let token = generate_syntax_token(input);
         let string_token = as_token.into_token(&token);
         let mut string_cleaner =
-            LiteralStringNormaliser::new(&string_token, quote, quote_properties);
+            LiteralStringNormaliser::new(&string_token, quote, quote_properties, false);
         let content = string_cleaner.normalise_text(source);
         let owned: Cow<str> = Cow::Owned(output.to_string());
         assert_eq!(content, owned)
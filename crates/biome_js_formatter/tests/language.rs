@@ -4,8 +4,8 @@ use biome_formatter::{
 use biome_formatter_test::TestFormatLanguage;
 use biome_js_formatter::context::trailing_comma::TrailingComma;
 use biome_js_formatter::context::{
-    ArrowParentheses, BracketSameLine, BracketSpacing, JsFormatContext, JsFormatOptions,
-    QuoteProperties, QuoteStyle, Semicolons,
+    ArrowParentheses, BracketSameLine, BracketSpacing, DecoratorPlacement, JsFormatContext,
+    JsFormatOptions, QuoteProperties, QuoteStyle, Semicolons,
 };
 use biome_js_formatter::{format_node, format_range, JsFormatLanguage};
 use biome_js_parser::{parse, JsParserOptions};
@@ -194,7 +194,24 @@ impl From<JsSerializableArrowParentheses> for ArrowParentheses {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsSerializableDecoratorPlacement {
+    Preserve,
+    SameLine,
+    OwnLine,
+}
+
+impl From<JsSerializableDecoratorPlacement> for DecoratorPlacement {
+    fn from(test: JsSerializableDecoratorPlacement) -> Self {
+        match test {
+            JsSerializableDecoratorPlacement::Preserve => DecoratorPlacement::Preserve,
+            JsSerializableDecoratorPlacement::SameLine => DecoratorPlacement::SameLine,
+            JsSerializableDecoratorPlacement::OwnLine => DecoratorPlacement::OwnLine,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct JsSerializableFormatOptions {
     /// The indent style.
     pub indent_style: Option<JsSerializableIndentStyle>,
@@ -231,6 +248,18 @@ pub struct JsSerializableFormatOptions {
 
     /// Whether to hug the closing bracket of multiline HTML/JSX tags to the end of the last line, rather than being alone on the following line. Defaults to false.
     pub bracket_same_line: Option<bool>,
+
+    /// Where the decorators of class members are printed. Defaults to "preserve".
+    pub decorator_placement: Option<JsSerializableDecoratorPlacement>,
+
+    /// The tags of the template literals whose content is reindented. Defaults to none.
+    pub dedent_tags: Option<Vec<String>>,
+
+    /// The tags of the template literals whose content is formatted as SQL. Defaults to none.
+    pub sql_tags: Option<Vec<String>>,
+
+    /// Whether to match the output of Prettier where Biome intentionally diverges from it. Defaults to false.
+    pub prettier_compatibility: Option<bool>,
 }
 
 impl JsSerializableFormatOptions {
@@ -280,6 +309,13 @@ impl JsSerializableFormatOptions {
                 self.bracket_same_line
                     .map_or_else(BracketSameLine::default, |value| value.into()),
             )
+            .with_decorator_placement(
+                self.decorator_placement
+                    .map_or_else(DecoratorPlacement::default, |value| value.into()),
+            )
+            .with_dedent_tags(self.dedent_tags.unwrap_or_default())
+            .with_sql_tags(self.sql_tags.unwrap_or_default())
+            .with_prettier_compatibility(self.prettier_compatibility.unwrap_or_default())
     }
 }
 
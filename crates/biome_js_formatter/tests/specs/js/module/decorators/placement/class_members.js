@@ -0,0 +1,15 @@
+class Foo {
+	@dec prop;
+	@dec
+	otherProp;
+	@dec static staticProp = 1;
+	@dec method() {}
+	@dec
+	otherMethod() {}
+	@dec get getter() { return 1; }
+	@dec1 @dec2 multiple;
+	@dec1
+	@dec2
+	multipleOnOwnLines;
+	static noDecorators;
+}
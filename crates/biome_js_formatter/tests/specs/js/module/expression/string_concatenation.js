@@ -0,0 +1,21 @@
+const message = "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.";
+foo("The quick brown fox jumps over the lazy dog. " + name + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.");
+const m = "The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy dog. The quick brown fox" + "x";
+x = `The quick brown fox jumps over the lazy dog. ${a}` + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.";
+logger.warn("The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.", { a });
+function g() { return "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog."; }
+it("The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox", () => {});
+a.b().c().d("The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox");
+const s = 'The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy dog.';
+const u = "The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy dog." + a;
+x += "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.";
+export default "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.";
+const arr = ["The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog."];
+if (s === "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.") {}
+const v = `${a}` + `The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy dog.` + `The quick brown fox jumps over the lazy dog.`;
+const w = "The quick brown fox jumps over the lazy dog. " /* c */ + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.";
+throw new Error("The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.");
+const o = { key: "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog." };
+const f = () => "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog.";
+const t = cond ? "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " : "The quick brown fox jumps over the lazy dog.";
+class A { p = "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog. " + "The quick brown fox jumps over the lazy dog."; }
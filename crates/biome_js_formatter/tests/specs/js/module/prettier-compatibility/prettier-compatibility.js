@@ -0,0 +1,17 @@
+const obj = {
+  'a': true,
+  b: true,
+  "𐊧": true,
+  "ab𐊧": true,
+};
+
+a = {
+  [x = 0]: 1,
+  [(y = 1)]: 2,
+  [z]: 3,
+  [w = 0]() {},
+};
+
+class C {
+  [x = 0] = 1;
+}
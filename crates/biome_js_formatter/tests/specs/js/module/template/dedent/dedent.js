@@ -0,0 +1,44 @@
+function query() {
+        return dedent`
+      SELECT *
+        FROM users
+
+          WHERE id = ${id}
+   `;
+}
+
+class Foo {
+    render() {
+        const text = outdent`
+            Hello ${name},
+            ${greeting}
+              - ${items.map((item) => item.name).join(", ")}`;
+    }
+}
+
+const member = String.dedent`
+		tabs
+		  and spaces
+`;
+
+const call = dedent({ escapeSpecialCharacters: false })`
+    called
+`;
+
+// not a dedent tag
+const other = html`
+      <div></div>
+  `;
+
+// content on the first line
+const first = dedent`first
+      second
+  `;
+
+// line without indentation
+const zero = dedent`
+    indented
+not indented
+  `;
+
+const single = dedent`single line`;
@@ -0,0 +1,57 @@
+const users = sql`
+  select id, name from users
+  where age > ${age} and active = true order by name
+`;
+
+async function findOrders(db, userId) {
+        return db.query(sql`
+      select o.id, o.total, count(*) as items
+      from orders o left outer join order_items i on i.order_id = o.id and i.deleted_at is null
+      where o.user_id = ${userId} and o.created_at between ${from} and ${to} or o.status in ('open', 'pending')
+      group by o.id, o.total having count(*) > 1
+      order by o.created_at desc limit 10
+  `);
+}
+
+const inline = sql`select * from users where id = ${id}`;
+
+const spaced = sql`SELECT   *  FROM   users   WHERE   id=${id}`;
+
+const subquery = Prisma.sql`
+  select name from users where id in (select user_id from orders where total > -100)
+`;
+
+const insert = sql`
+  insert into users (name, email) values (${name}, ${email})
+  on conflict (email) do update set name = excluded.name
+  returning id
+`;
+
+const withComments = sql`
+  -- Find the active users
+  select id /* primary key */ from users
+  where status = 'active' and data->>'role' = 'admin'
+`;
+
+const cte = sql`
+  with recent as (select * from orders where created_at > now() - interval '1 day')
+  select count(*)::int from recent;
+`;
+
+// Not tokenizable: left untouched
+const unterminated = sql`
+  select ${column} from users where name = 'O${name}'
+`;
+
+const notSql = other`select * from users`;
+
+const fragment = sql``;
+
+const dollarQuoted = sql`select $$it's -- text$$ as a, $x$ $$ $x$ from t`;
+
+const operators = sql`
+  select * from events
+  where tags @> ${tags} and ${tag} <@ tags and ranges && ${range}
+  and flags << 2 >> 1 = 0 and name ~* '^a' and data ?| ${keys}
+  and data #>> '{a,b}' = 'c' and total=-1 and f(x => 1) = 1
+`;
@@ -0,0 +1,6 @@
+<T = unknown>() => {};
+<T extends unknown>() => {};
+<T,>() => {};
+a?.b!;
+(a?.b)!;
+(a?.b!);
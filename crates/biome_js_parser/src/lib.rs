@@ -114,6 +114,7 @@ mod parser;
 #[macro_use]
 mod lexer;
 mod parse;
+mod reparse;
 mod rewrite;
 mod span;
 mod state;
@@ -134,6 +135,7 @@ pub use crate::{
     lexer::{JsLexContext, JsReLexContext},
     options::JsParserOptions,
     parse::*,
+    reparse::reparse_token,
 };
 use biome_js_factory::JsSyntaxFactory;
 use biome_js_syntax::{JsLanguage, JsSyntaxKind, LanguageVariant};
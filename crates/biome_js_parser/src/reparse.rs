@@ -0,0 +1,129 @@
+use crate::lexer::{JsLexContext, JsLexer};
+use biome_js_syntax::{JsSyntaxKind, JsSyntaxKind::*, JsSyntaxNode, JsSyntaxToken};
+use biome_parser::lexer::Lexer;
+use biome_rowan::{TextRange, TextSize, TriviaPiece};
+
+/// Updates `root`, the syntax tree of a text, after the `range` of the text was replaced.
+/// `text` is the whole text after the change.
+///
+/// Only the changes inside a single identifier, string or number of an expression are
+/// supported: the token is lexed again and replaced, and the returned tree shares all the other
+/// nodes of `root`. Returns `None` when the change can update the structure of the tree, or its
+/// diagnostics, in which case the text must be parsed again.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_js_parser::{parse_module, reparse_token, JsParserOptions};
+/// use biome_rowan::{TextRange, TextSize};
+///
+/// let parsed = parse_module("call(value);", JsParserOptions::default());
+///
+/// // `value` is renamed to `values`
+/// let range = TextRange::empty(TextSize::from(10));
+/// let root = reparse_token(&parsed.syntax(), range, "call(values);").unwrap();
+/// assert_eq!(root.text_trimmed().to_string(), "call(values);");
+///
+/// // The new text changes the structure of the tree
+/// assert!(reparse_token(&parsed.syntax(), range, "call(value, other);").is_none());
+/// ```
+pub fn reparse_token(root: &JsSyntaxNode, range: TextRange, text: &str) -> Option<JsSyntaxNode> {
+    let token = root
+        .token_at_offset(range.start())
+        .find(|token| token.text_trimmed_range().contains_range(range))?;
+    if !is_reparsable(&token) {
+        return None;
+    }
+
+    let token_range = token.text_trimmed_range();
+    // The new text could be lexed along with the end of the previous token
+    if range.start() == token_range.start() && token.leading_trivia().pieces().next().is_none() {
+        return None;
+    }
+
+    let inserted_len = (TextSize::try_from(text.len()).ok()? + range.len())
+        .checked_sub(root.text_range().len())?;
+    let new_token_range = TextRange::new(
+        token_range.start(),
+        (token_range.end() + inserted_len).checked_sub(range.len())?,
+    );
+    let token_text =
+        text.get(usize::from(new_token_range.start())..usize::from(new_token_range.end()))?;
+    if !is_reparsable_text(token.kind(), token.text_trimmed())
+        || !is_reparsable_text(token.kind(), token_text)
+    {
+        return None;
+    }
+
+    // The text that follows the token is lexed too, so the token doesn't merge with it
+    let mut lexer = JsLexer::from_str(&text[usize::from(new_token_range.start())..]);
+    let kind = lexer.next_token(JsLexContext::Regular);
+    if kind != token.kind()
+        || lexer.current_range().end() != new_token_range.len()
+        || !lexer.finish().is_empty()
+    {
+        return None;
+    }
+
+    let leading_trivia = token.leading_trivia();
+    let trailing_trivia = token.trailing_trivia();
+    let new_token = JsSyntaxToken::new_detached(
+        kind,
+        &format!(
+            "{}{token_text}{}",
+            leading_trivia.text(),
+            trailing_trivia.text()
+        ),
+        leading_trivia
+            .pieces()
+            .map(|piece| TriviaPiece::new(piece.kind(), piece.text_len()))
+            .collect::<Vec<_>>(),
+        trailing_trivia
+            .pieces()
+            .map(|piece| TriviaPiece::new(piece.kind(), piece.text_len()))
+            .collect::<Vec<_>>(),
+    );
+    root.clone().replace_child(token.into(), new_token.into())
+}
+
+/// Whether the parser accepts any other token of the same kind in place of `token`
+fn is_reparsable(token: &JsSyntaxToken) -> bool {
+    let Some(parent) = token.parent() else {
+        return false;
+    };
+    let grand_parent_kind = parent.parent().map(|node| node.kind());
+    match token.kind() {
+        IDENT => match parent.kind() {
+            // The exported names must be unique
+            JS_REFERENCE_IDENTIFIER => !matches!(
+                grand_parent_kind,
+                Some(JS_EXPORT_NAMED_SHORTHAND_SPECIFIER | JS_EXPORT_NAMED_SPECIFIER)
+            ),
+            JS_NAME => matches!(
+                grand_parent_kind,
+                Some(JS_STATIC_MEMBER_EXPRESSION | JS_STATIC_MEMBER_ASSIGNMENT)
+            ),
+            _ => false,
+        },
+        // The directives, such as `"use strict"`, aren't expressions
+        JS_STRING_LITERAL => parent.kind() == JS_STRING_LITERAL_EXPRESSION,
+        JS_NUMBER_LITERAL => parent.kind() == JS_NUMBER_LITERAL_EXPRESSION,
+        _ => false,
+    }
+}
+
+/// Whether the parser accepts `text` as a token of `kind` in any context. The escape sequences,
+/// the legacy octal numbers, and some identifiers are invalid in strict mode.
+fn is_reparsable_text(kind: JsSyntaxKind, text: &str) -> bool {
+    if text.contains('\\') {
+        return false;
+    }
+    match kind {
+        IDENT => !matches!(text, "arguments" | "eval"),
+        JS_NUMBER_LITERAL => {
+            let mut bytes = text.bytes();
+            !(bytes.next() == Some(b'0') && bytes.next().is_some_and(|byte| byte.is_ascii_digit()))
+        }
+        _ => true,
+    }
+}
@@ -1,14 +1,18 @@
 use crate::test_utils::has_bogus_nodes_or_empty_slots;
-use crate::{parse, parse_module, test_utils::assert_errors_are_absent, JsParserOptions, Parse};
+use crate::{
+    parse, parse_module, reparse_token, test_utils::assert_errors_are_absent, JsParserOptions,
+    Parse,
+};
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
 use biome_diagnostics::DiagnosticExt;
 use biome_diagnostics::PrintDiagnostic;
 use biome_js_syntax::{AnyJsRoot, JsFileSource, JsSyntaxKind};
 use biome_js_syntax::{JsCallArguments, JsLogicalExpression, JsSyntaxToken};
-use biome_rowan::{AstNode, Direction, TextSize};
+use biome_rowan::{AstNode, Direction, TextRange, TextSize};
 use expect_test::expect_file;
 use std::fmt::Write;
+use std::ops::Range;
 use std::panic::catch_unwind;
 use std::path::{Path, PathBuf};
 
@@ -404,6 +408,62 @@ fn diagnostics_print_correctly() {
     }
 }
 
+/// Replaces the `range` of `code` with `replacement`, and checks that the tree updated by
+/// [reparse_token] is the tree of the new code
+fn reparse(code: &str, range: Range<usize>, replacement: &str) -> Option<String> {
+    let mut new_code = code.to_string();
+    new_code.replace_range(range.clone(), replacement);
+    let root = parse_module(code, JsParserOptions::default()).syntax();
+    let range = TextRange::new(
+        TextSize::try_from(range.start).unwrap(),
+        TextSize::try_from(range.end).unwrap(),
+    );
+
+    let reparsed = reparse_token(&root, range, &new_code)?;
+    let parsed = parse_module(&new_code, JsParserOptions::default()).syntax();
+    assert_eq!(format!("{reparsed:#?}"), format!("{parsed:#?}"));
+    Some(reparsed.to_string())
+}
+
+#[test]
+pub fn reparse_token_replaces_the_changed_token() {
+    //          0123456789A123456789B
+    let code = "let a = b.c + 'd' + 1;";
+    assert_eq!(
+        reparse(code, 9..9, "x").as_deref(),
+        Some("let a = bx.c + 'd' + 1;")
+    );
+    assert_eq!(
+        reparse(code, 11..11, "d").as_deref(),
+        Some("let a = b.cd + 'd' + 1;")
+    );
+    assert_eq!(
+        reparse(code, 15..16, "e f").as_deref(),
+        Some("let a = b.c + 'e f' + 1;")
+    );
+    assert_eq!(
+        reparse(code, 21..21, ".5").as_deref(),
+        Some("let a = b.c + 'd' + 1.5;")
+    );
+}
+
+#[test]
+pub fn reparse_token_rejects_structural_changes() {
+    //          0123456789A123456789B
+    let code = "let a = b.c + 'd' + 1;";
+    // The change could merge the token with the previous one
+    assert_eq!(reparse(code, 8..8, "x"), None);
+    // The token is split
+    assert_eq!(reparse(code, 9..9, "("), None);
+    // The bindings must be unique
+    assert_eq!(reparse(code, 5..5, "b"), None);
+    // The string isn't terminated anymore
+    assert_eq!(reparse(code, 16..17, ""), None);
+    // The token is invalid in strict mode
+    assert_eq!(reparse("a + 0;", 5..5, "7"), None);
+    assert_eq!(reparse("a + eva;", 7..7, "l"), None);
+}
+
 #[ignore]
 #[test]
 pub fn quick_test() {
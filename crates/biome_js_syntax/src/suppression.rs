@@ -25,6 +25,20 @@ pub struct Suppression<'a> {
     pub reason: &'a str,
     /// If the comment is `// rome-ignore`
     pub is_legacy: bool,
+    /// If the comment is `// biome-ignore-all`, which suppresses the whole file
+    pub is_file: bool,
+    /// If the comment is `// biome-ignore-start` or `// biome-ignore-end`, which
+    /// suppress the code between them
+    pub range_marker: Option<RangeMarker>,
+}
+
+/// The comments that delimit a range of suppressed code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeMarker {
+    /// `// biome-ignore-start`, which starts suppressing the code
+    Start,
+    /// `// biome-ignore-end`, which stops suppressing the code
+    End,
 }
 
 pub fn parse_suppression_comment(
@@ -84,6 +98,8 @@ pub fn parse_suppression_comment(
         ];
 
         let mut is_legacy = false;
+        let mut is_file = false;
+        let mut range_marker = None;
         // it's a biome-ignore comment
         if line.starts_with("biome-ignore") {
             // Checks for `/biome[-_]ignore/i` without a regex, or skip the line
@@ -91,6 +107,26 @@ pub fn parse_suppression_comment(
             for pattern in PATTERN {
                 line = line.strip_prefix(pattern)?;
             }
+
+            // Checks for `biome-ignore-all`
+            if let Some(rest) = line
+                .strip_prefix("-all")
+                .filter(|rest| rest.starts_with(char::is_whitespace))
+            {
+                line = rest;
+                is_file = true;
+            }
+
+            // Checks for `biome-ignore-start` and `biome-ignore-end`
+            for (suffix, marker) in [("-start", RangeMarker::Start), ("-end", RangeMarker::End)] {
+                if let Some(rest) = line
+                    .strip_prefix(suffix)
+                    .filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+                {
+                    line = rest;
+                    range_marker = Some(marker);
+                }
+            }
         } else {
             is_legacy = true;
             for pattern in DEPRECATED_PATTERNS {
@@ -103,10 +139,12 @@ pub fn parse_suppression_comment(
 
         let line = line.trim_start();
         Some(
-            parse_suppression_line(line, is_legacy).map_err(|err| SuppressionDiagnostic {
-                message: err.message,
-                // Adjust the position of the diagnostic in the whole comment
-                span: err.span + offset_from(base, line),
+            parse_suppression_line(line, is_legacy, is_file, range_marker).map_err(|err| {
+                SuppressionDiagnostic {
+                    message: err.message,
+                    // Adjust the position of the diagnostic in the whole comment
+                    span: err.span + offset_from(base, line),
+                }
             }),
         )
     })
@@ -172,27 +210,36 @@ impl biome_console::fmt::Display for SuppressionDiagnosticKind {
 fn parse_suppression_line(
     base: &str,
     is_legacy: bool,
+    is_file: bool,
+    range_marker: Option<RangeMarker>,
 ) -> Result<Suppression, SuppressionDiagnostic> {
     let mut line = base;
     let mut categories = Vec::new();
 
     loop {
         // Find either a colon opening parenthesis or space
-        let separator = line
-            .find(|c: char| c == ':' || c == '(' || c.is_whitespace())
-            .ok_or_else(|| SuppressionDiagnostic {
-                message: SuppressionDiagnosticKind::MissingColon,
-                span: TextRange::at(offset_from(base, line), TextSize::of(line)),
-            })?;
+        let separator = match line.find(|c: char| c == ':' || c == '(' || c.is_whitespace()) {
+            Some(separator) => separator,
+            // The comments that delimit a range don't require a reason, e.g. `// biome-ignore-end`
+            None if range_marker.is_some() => {
+                if !line.is_empty() {
+                    categories.push((parse_category(base, line)?, None));
+                }
+                line = "";
+                break;
+            }
+            None => {
+                return Err(SuppressionDiagnostic {
+                    message: SuppressionDiagnosticKind::MissingColon,
+                    span: TextRange::at(offset_from(base, line), TextSize::of(line)),
+                })
+            }
+        };
 
         let (category, rest) = line.split_at(separator);
         let category = category.trim_end();
         let category: Option<&'static Category> = if !category.is_empty() {
-            let category = category.parse().map_err(|()| SuppressionDiagnostic {
-                message: SuppressionDiagnosticKind::ParseCategory(category.into()),
-                span: TextRange::at(offset_from(base, category), TextSize::of(category)),
-            })?;
-            Some(category)
+            Some(parse_category(base, category)?)
         } else {
             None
         };
@@ -247,6 +294,16 @@ fn parse_suppression_line(
         categories,
         reason,
         is_legacy,
+        is_file,
+        range_marker,
+    })
+}
+
+/// Parse the `category` of a suppression, a substring of the suppression line `base`
+fn parse_category(base: &str, category: &str) -> Result<&'static Category, SuppressionDiagnostic> {
+    category.parse().map_err(|()| SuppressionDiagnostic {
+        message: SuppressionDiagnosticKind::ParseCategory(category.into()),
+        span: TextRange::at(offset_from(base, category), TextSize::of(category)),
     })
 }
 
@@ -291,7 +348,9 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -300,7 +359,9 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -314,7 +375,9 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -329,7 +392,9 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
     }
@@ -340,7 +405,9 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -349,7 +416,9 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -358,7 +427,9 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
     }
@@ -374,7 +445,9 @@ mod tests_rome_ignore {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -387,7 +460,9 @@ mod tests_rome_ignore {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -404,7 +479,9 @@ mod tests_rome_ignore {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -422,7 +499,9 @@ mod tests_rome_ignore {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
     }
@@ -435,7 +514,9 @@ mod tests_rome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                is_file: false,
+                range_marker: None
             })],
         );
     }
@@ -505,7 +586,7 @@ mod tests_biome_ignore {
 
     use crate::suppression::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, RangeMarker, Suppression};
 
     #[test]
     fn parse_simple_suppression() {
@@ -514,7 +595,9 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -524,7 +607,9 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -538,7 +623,9 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -553,7 +640,73 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_file_suppression() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-all parse: explanation").collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("parse"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                is_file: true,
+                range_marker: None
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("/* biome-ignore-all lint(style/useWhile): explanation */")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint"), Some("style/useWhile"))],
+                reason: "explanation",
+                is_legacy: false,
+                is_file: true,
+                range_marker: None
+            })],
+        );
+    }
+
+    #[test]
+    fn parse_range_suppression() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-start lint/style/useWhile: explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/style/useWhile"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                is_file: false,
+                range_marker: Some(RangeMarker::Start)
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-start lint/style/useWhile")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/style/useWhile"), None)],
+                reason: "",
+                is_legacy: false,
+                is_file: false,
+                range_marker: Some(RangeMarker::Start)
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("/* biome-ignore-end */").collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![],
+                reason: "",
+                is_legacy: false,
+                is_file: false,
+                range_marker: Some(RangeMarker::End)
             })],
         );
     }
@@ -564,7 +717,9 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -573,7 +728,9 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -582,7 +739,9 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
     }
@@ -598,7 +757,9 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -611,7 +772,9 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -628,7 +791,9 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
 
@@ -646,7 +811,9 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
     }
@@ -659,7 +826,9 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                is_file: false,
+                range_marker: None
             })],
         );
     }
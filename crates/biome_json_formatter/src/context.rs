@@ -1,4 +1,5 @@
 use crate::JsonCommentStyle;
+use biome_deserialize::{Deserializable, DeserializableValue, DeserializationDiagnostic, Text};
 use biome_formatter::{prelude::*, IndentWidth};
 use biome_formatter::{
     CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
@@ -9,6 +10,7 @@ use crate::comments::{FormatJsonLeadingComment, JsonComments};
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
 use std::fmt;
 use std::rc::Rc;
+use std::str::FromStr;
 
 #[derive(Debug)]
 pub struct JsonFormatContext {
@@ -61,6 +63,8 @@ pub struct JsonFormatOptions {
     indent_width: IndentWidth,
     line_ending: LineEnding,
     line_width: LineWidth,
+    /// Which arrays are printed in fill mode. Defaults to "numbers".
+    array_fill: ArrayFill,
     _file_source: JsonFileSource,
 }
 
@@ -72,6 +76,7 @@ impl JsonFormatOptions {
             indent_width: IndentWidth::default(),
             line_ending: LineEnding::default(),
             line_width: LineWidth::default(),
+            array_fill: ArrayFill::default(),
         }
     }
 
@@ -95,6 +100,11 @@ impl JsonFormatOptions {
         self
     }
 
+    pub fn with_array_fill(mut self, array_fill: ArrayFill) -> Self {
+        self.array_fill = array_fill;
+        self
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -110,6 +120,14 @@ impl JsonFormatOptions {
     pub fn set_line_width(&mut self, line_width: LineWidth) {
         self.line_width = line_width;
     }
+
+    pub fn set_array_fill(&mut self, array_fill: ArrayFill) {
+        self.array_fill = array_fill;
+    }
+
+    pub fn array_fill(&self) -> ArrayFill {
+        self.array_fill
+    }
 }
 
 impl FormatOptions for JsonFormatOptions {
@@ -139,6 +157,82 @@ impl fmt::Display for JsonFormatOptions {
         writeln!(f, "Indent style: {}", self.indent_style)?;
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
-        writeln!(f, "Line width: {}", self.line_width.get())
+        writeln!(f, "Line width: {}", self.line_width.get())?;
+        writeln!(f, "Array fill: {}", self.array_fill)
+    }
+}
+
+/// Controls which arrays are printed in *fill* mode, where as many elements as possible
+/// are printed on each line instead of one element per line.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ArrayFill {
+    /// Only arrays that contain exclusively numbers are filled.
+    #[default]
+    Numbers,
+    /// Arrays that contain exclusively numbers or short strings are filled.
+    Literals,
+    /// Arrays are never filled: each element is printed on its own line when the array breaks.
+    Never,
+}
+
+impl ArrayFill {
+    pub const fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+
+    pub const fn is_literals(&self) -> bool {
+        matches!(self, Self::Literals)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for ArrayFill {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numbers" | "Numbers" => Ok(Self::Numbers),
+            "literals" | "Literals" => Ok(Self::Literals),
+            "never" | "Never" => Ok(Self::Never),
+            _ => Err("Value not supported for ArrayFill. Supported values are 'numbers', 'literals' and 'never'."),
+        }
+    }
+}
+
+impl fmt::Display for ArrayFill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArrayFill::Numbers => std::write!(f, "Numbers"),
+            ArrayFill::Literals => std::write!(f, "Literals"),
+            ArrayFill::Never => std::write!(f, "Never"),
+        }
+    }
+}
+
+impl Deserializable for ArrayFill {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        match Text::deserialize(value, name, diagnostics)?.text() {
+            "numbers" => Some(ArrayFill::Numbers),
+            "literals" => Some(ArrayFill::Literals),
+            "never" => Some(ArrayFill::Never),
+            unknown_value => {
+                const ALLOWED_VARIANTS: &[&str] = &["numbers", "literals", "never"];
+                diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                    unknown_value,
+                    value.range(),
+                    ALLOWED_VARIANTS,
+                ));
+                None
+            }
+        }
     }
 }
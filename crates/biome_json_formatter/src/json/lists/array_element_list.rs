@@ -1,6 +1,7 @@
+use crate::context::ArrayFill;
 use crate::prelude::*;
 use crate::separated::FormatAstSeparatedListExtension;
-use biome_formatter::write;
+use biome_formatter::{write, FormatContext};
 use biome_json_syntax::{AnyJsonValue, JsonArrayElementList};
 use biome_rowan::{AstNode, AstSeparatedList};
 
@@ -10,7 +11,8 @@ pub(crate) struct FormatJsonArrayElementList;
 impl FormatRule<JsonArrayElementList> for FormatJsonArrayElementList {
     type Context = JsonFormatContext;
     fn fmt(&self, node: &JsonArrayElementList, f: &mut JsonFormatter) -> FormatResult<()> {
-        let layout = if can_concisely_print_array_list(node) {
+        let array_fill = f.context().options().array_fill();
+        let layout = if can_concisely_print_array_list(node, array_fill) {
             ArrayLayout::Fill
         } else {
             ArrayLayout::OnePerLine
@@ -59,6 +61,15 @@ enum ArrayLayout {
     ///     5, 6,
     /// ]
     /// ```
+    ///
+    /// When [ArrayFill::Literals] is set, short strings are filled too.
+    ///
+    /// ```json
+    /// [
+    ///     "ab", "cd", "ef",
+    ///     "gh",
+    /// ]
+    /// ```
     Fill,
 
     /// Prints every element on a single line if the array exceeds the line width, or any
@@ -73,15 +84,31 @@ enum ArrayLayout {
     OnePerLine,
 }
 
+/// Strings longer than this, quotes included, prevent an array from being "fill-printed".
+const MAX_FILL_STRING_WIDTH: usize = 20;
+
 /// Returns `true` if the array can be "fill-printed" instead of breaking each element on
 /// a different line.
 ///
-/// An array can be "fill printed" if it only contains literal elements.
-pub(crate) fn can_concisely_print_array_list(list: &JsonArrayElementList) -> bool {
-    if list.is_empty() {
+/// An array can be "fill printed" if it only contains literal elements allowed by `array_fill`:
+/// - [ArrayFill::Numbers]: numbers only;
+/// - [ArrayFill::Literals]: numbers and short strings;
+/// - [ArrayFill::Never]: no array is fill-printed.
+pub(crate) fn can_concisely_print_array_list(
+    list: &JsonArrayElementList,
+    array_fill: ArrayFill,
+) -> bool {
+    if list.is_empty() || array_fill.is_never() {
         return false;
     }
 
-    list.iter()
-        .all(|node| matches!(node, Ok(AnyJsonValue::JsonNumberValue(_))))
+    list.iter().all(|node| match node {
+        Ok(AnyJsonValue::JsonNumberValue(_)) => true,
+        Ok(AnyJsonValue::JsonStringValue(string)) if array_fill.is_literals() => {
+            string.value_token().map_or(false, |token| {
+                token.text_trimmed().chars().count() <= MAX_FILL_STRING_WIDTH
+            })
+        }
+        _ => false,
+    })
 }
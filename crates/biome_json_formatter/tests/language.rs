@@ -2,7 +2,7 @@ use biome_formatter::{
     FormatContext, FormatResult, Formatted, IndentStyle, LineEnding, LineWidth, Printed,
 };
 use biome_formatter_test::TestFormatLanguage;
-use biome_json_formatter::context::{JsonFormatContext, JsonFormatOptions};
+use biome_json_formatter::context::{ArrayFill, JsonFormatContext, JsonFormatOptions};
 use biome_json_formatter::{format_node, format_range, JsonFormatLanguage};
 use biome_json_parser::{parse_json, JsonParserOptions};
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
@@ -101,6 +101,23 @@ impl From<JsonSerializableLineEnding> for LineEnding {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsonSerializableArrayFill {
+    Numbers,
+    Literals,
+    Never,
+}
+
+impl From<JsonSerializableArrayFill> for ArrayFill {
+    fn from(test: JsonSerializableArrayFill) -> Self {
+        match test {
+            JsonSerializableArrayFill::Numbers => ArrayFill::Numbers,
+            JsonSerializableArrayFill::Literals => ArrayFill::Literals,
+            JsonSerializableArrayFill::Never => ArrayFill::Never,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 pub struct JsonSerializableFormatOptions {
     /// The indent style.
@@ -114,6 +131,9 @@ pub struct JsonSerializableFormatOptions {
 
     /// What's the max width of a line. Defaults to 80.
     pub line_width: Option<u16>,
+
+    /// Which arrays are printed in fill mode. Defaults to numbers.
+    pub array_fill: Option<JsonSerializableArrayFill>,
 }
 
 impl From<JsonSerializableFormatOptions> for JsonFormatOptions {
@@ -126,6 +146,7 @@ impl From<JsonSerializableFormatOptions> for JsonFormatOptions {
                     .and_then(|width| LineWidth::try_from(width).ok())
                     .unwrap_or_default(),
             )
+            .with_array_fill(test.array_fill.map(Into::into).unwrap_or_default())
     }
 }
 
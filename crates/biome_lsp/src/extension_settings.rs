@@ -1,3 +1,4 @@
+use biome_service::Configuration;
 use serde::{Deserialize, Serialize};
 use serde_json::{Error, Value};
 use tracing::trace;
@@ -17,6 +18,25 @@ pub struct WorkspaceSettings {
 
     /// Only run Biome if a `biome.json` configuration file exists.
     pub require_configuration: Option<bool>,
+
+    /// The categories of inlay hints shown in the editor
+    #[serde(default)]
+    pub inlay_hints: InlayHintsSettings,
+
+    /// The configuration applied to the documents that aren't files, like the `untitled:`
+    /// documents of the editor. Biome's defaults are used when it's missing.
+    pub default_configuration: Option<Configuration>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+/// The categories of inlay hints, all of them are disabled by default
+pub struct InlayHintsSettings {
+    /// Show the names of the parameters at call sites
+    pub parameter_names: Option<bool>,
+
+    /// Show the return type of functions without a type annotation, when it's obvious
+    pub return_types: Option<bool>,
 }
 
 /// The `biome.*` extension settings
@@ -45,4 +65,12 @@ impl ExtensionSettings {
     pub(crate) fn requires_configuration(&self) -> bool {
         self.settings.require_configuration.unwrap_or_default()
     }
+
+    pub(crate) fn inlay_hints(&self) -> &InlayHintsSettings {
+        &self.settings.inlay_hints
+    }
+
+    pub(crate) fn default_configuration(&self) -> Option<&Configuration> {
+        self.settings.default_configuration.as_ref()
+    }
 }
@@ -1,4 +1,11 @@
 pub(crate) mod analysis;
+pub(crate) mod code_lens;
+pub(crate) mod definition;
+pub(crate) mod folding_ranges;
 pub(crate) mod formatting;
+pub(crate) mod inlay_hints;
+pub(crate) mod references;
 pub(crate) mod rename;
+pub(crate) mod semantic_tokens;
+pub(crate) mod symbols;
 pub(crate) mod text_document;
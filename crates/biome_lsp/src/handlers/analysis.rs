@@ -1,18 +1,19 @@
-use crate::converters::from_proto;
 use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto};
 use crate::session::Session;
 use crate::utils;
 use anyhow::{Context, Result};
 use biome_analyze::{ActionCategory, SourceActionKind};
 use biome_diagnostics::Applicability;
-use biome_fs::RomePath;
+use biome_fs::{OpenOptions, RomePath};
+use biome_service::configuration::{disable_rule_edit, RuleSelector};
 use biome_service::workspace::{
     FeatureName, FeaturesBuilder, FixFileMode, FixFileParams, PullActionsParams,
     SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use tower_lsp::lsp_types::{
     self as lsp, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
 };
@@ -20,7 +21,10 @@ use tracing::debug;
 
 const FIX_ALL_CATEGORY: ActionCategory = ActionCategory::Source(SourceActionKind::FixAll);
 
-fn fix_all_kind() -> CodeActionKind {
+const DISABLE_RULE_CATEGORY: ActionCategory =
+    ActionCategory::Other(Cow::Borrowed("quickfix.disableRule"));
+
+pub(crate) fn fix_all_kind() -> CodeActionKind {
     match FIX_ALL_CATEGORY.to_str() {
         Cow::Borrowed(kind) => CodeActionKind::from(kind),
         Cow::Owned(kind) => CodeActionKind::from(kind),
@@ -101,13 +105,24 @@ pub(crate) fn code_actions(
 
     // Generate an additional code action to apply all safe fixes on the
     // document if the action category "source.fixAll" was explicitly requested
-    // by the language client
-    let fix_all = if has_fix_all {
+    // by the language client. The fixes are applied until none is left, so the
+    // editors can run the action on save.
+    let fix_all = if has_fix_all && file_features.supports_for(&FeatureName::Lint) {
         fix_all(session, &url, rome_path, &doc.line_index, &diagnostics)?
     } else {
         None
     };
 
+    let disable_rules = if filters.is_empty()
+        || filters
+            .iter()
+            .any(|filter| DISABLE_RULE_CATEGORY.matches(filter))
+    {
+        disable_rule_actions(session, &url, &diagnostics)?
+    } else {
+        Vec::new()
+    };
+
     let mut has_fixes = false;
     let mut actions: Vec<_> = result
         .actions
@@ -142,6 +157,7 @@ pub(crate) fn code_actions(
             Some(CodeActionOrCommand::CodeAction(action))
         })
         .chain(fix_all)
+        .chain(disable_rules)
         .collect();
 
     // If any actions is marked as fixing a diagnostic, hide other actions
@@ -181,6 +197,8 @@ fn fix_all(
         path: rome_path,
         fix_file_mode: FixFileMode::SafeFixes,
         should_format,
+        only: Vec::new(),
+        suppression_reason: None,
     })?;
 
     if fixed.actions.is_empty() {
@@ -250,3 +268,113 @@ fn fix_all(
         data: None,
     })))
 }
+
+/// Generate the code actions that turn off the rules of the lint diagnostics in the
+/// configuration file of the workspace folder of the document
+fn disable_rule_actions(
+    session: &Session,
+    document_url: &lsp::Url,
+    diagnostics: &[lsp::Diagnostic],
+) -> Result<Vec<CodeActionOrCommand>> {
+    let mut rules: BTreeMap<&str, Vec<lsp::Diagnostic>> = BTreeMap::new();
+    for diagnostic in diagnostics {
+        let Some(lsp::NumberOrString::String(code)) = &diagnostic.code else {
+            continue;
+        };
+        if let Some(rule) = code.strip_prefix("lint/") {
+            rules.entry(rule).or_default().push(diagnostic.clone());
+        }
+    }
+    if rules.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let Some(path) = session.configuration_file_path(document_url) else {
+        return Ok(Vec::new());
+    };
+    let Ok(url) = lsp::Url::from_file_path(&path) else {
+        return Ok(Vec::new());
+    };
+
+    let content = match session
+        .fs
+        .open_with_options(&path, OpenOptions::default().read(true))
+    {
+        Ok(mut file) => {
+            let mut content = String::new();
+            file.read_to_string(&mut content)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            Some(content)
+        }
+        Err(_) => None,
+    };
+    // The configuration file is created by the edit if it doesn't exist
+    if content.is_none() && !session.can_create_files() {
+        return Ok(Vec::new());
+    }
+    let text = content.as_deref().unwrap_or_default();
+    let line_index = LineIndex::new(text);
+    let position_encoding = session.position_encoding();
+
+    let mut actions = Vec::new();
+    for (rule, diagnostics) in rules {
+        let Ok(selector) = rule.parse::<RuleSelector>() else {
+            continue;
+        };
+        // The configuration file isn't valid JSON
+        let Some((range, new_text)) = disable_rule_edit(text, &selector.group, &selector.rule)
+        else {
+            return Ok(Vec::new());
+        };
+        let text_edit = lsp::TextEdit {
+            range: to_proto::range(&line_index, range, position_encoding)?,
+            new_text,
+        };
+
+        let edit = if content.is_some() {
+            lsp::WorkspaceEdit {
+                changes: Some(HashMap::from([(url.clone(), vec![text_edit])])),
+                document_changes: None,
+                change_annotations: None,
+            }
+        } else {
+            lsp::WorkspaceEdit {
+                changes: None,
+                document_changes: Some(lsp::DocumentChanges::Operations(vec![
+                    lsp::DocumentChangeOperation::Op(lsp::ResourceOp::Create(lsp::CreateFile {
+                        uri: url.clone(),
+                        options: None,
+                        annotation_id: None,
+                    })),
+                    lsp::DocumentChangeOperation::Edit(lsp::TextDocumentEdit {
+                        text_document: lsp::OptionalVersionedTextDocumentIdentifier {
+                            uri: url.clone(),
+                            version: None,
+                        },
+                        edits: vec![lsp::OneOf::Left(text_edit)],
+                    }),
+                ])),
+                change_annotations: None,
+            }
+        };
+
+        let kind = format!(
+            "{}.{}.{}",
+            DISABLE_RULE_CATEGORY.to_str(),
+            selector.group,
+            selector.rule
+        );
+        actions.push(CodeActionOrCommand::CodeAction(lsp::CodeAction {
+            title: format!("Disable {rule} in configuration"),
+            kind: Some(CodeActionKind::from(kind)),
+            diagnostics: Some(diagnostics),
+            edit: Some(edit),
+            command: None,
+            is_preferred: None,
+            disabled: None,
+            data: None,
+        }));
+    }
+
+    Ok(actions)
+}
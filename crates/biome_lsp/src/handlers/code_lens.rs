@@ -0,0 +1,260 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::{anyhow, Context};
+use biome_analyze::RuleCategories;
+use biome_diagnostics::{Diagnostic, DiagnosticTags};
+use biome_fs::{FileSystemExt, OpenOptions};
+use biome_service::configuration::{disable_rule, RuleSelector};
+use biome_service::workspace::{
+    FeatureName, FeaturesBuilder, FixFileMode, FixFileParams, PullDiagnosticsParams,
+    SupportsFeatureParams,
+};
+use biome_service::WorkspaceError;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use tower_lsp::lsp_types::{self, CodeLens, Command, Url};
+use tracing::trace;
+
+/// Applies the fixes of a rule to a document. Its arguments are the URL of the document
+/// and the name of the rule, e.g. `suspicious/noDebugger`.
+pub(crate) const FIX_ALL_RULE_COMMAND: &str = "biome.fixAllRule";
+
+/// Turns off a rule in the configuration file of the workspace. Its arguments are the name of
+/// the rule, e.g. `suspicious/noDebugger`, and optionally the URL of the document, whose
+/// workspace folder has the configuration file to edit.
+pub(crate) const DISABLE_RULE_COMMAND: &str = "biome.disableRule";
+
+/// Shows the commands of the rules above the lines where they emit diagnostics
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn code_lens(
+    session: &Session,
+    params: lsp_types::CodeLensParams,
+) -> Result<Option<Vec<CodeLens>>, LspError> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+
+    if session.is_linting_and_formatting_disabled() {
+        return Ok(None);
+    }
+    let file_features = session.workspace.file_features(SupportsFeatureParams {
+        path: rome_path.clone(),
+        feature: FeaturesBuilder::new().with_linter().build(),
+    })?;
+    if !file_features.supports_for(&FeatureName::Lint) {
+        return Ok(None);
+    }
+
+    trace!("Computing code lenses...");
+
+    let doc = session.document(&url)?;
+
+    let result = session.workspace.pull_diagnostics(PullDiagnosticsParams {
+        path: rome_path,
+        categories: RuleCategories::LINT,
+        max_diagnostics: u64::MAX,
+        only: Vec::new(),
+        rule_timings: false,
+    });
+
+    let result = match result {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    // The lines of the diagnostics of each rule, and the rules that can fix their diagnostics
+    let mut lines = BTreeSet::new();
+    let mut fixable_rules = BTreeSet::new();
+    for diagnostic in &result.diagnostics {
+        let Some(rule) = diagnostic
+            .category()
+            .and_then(|category| category.name().strip_prefix("lint/"))
+        else {
+            continue;
+        };
+        let Some(line) = diagnostic
+            .location()
+            .span
+            .and_then(|span| doc.line_index.line_col(span.start()))
+        else {
+            continue;
+        };
+
+        lines.insert((line.line, rule));
+        if diagnostic.tags().contains(DiagnosticTags::FIXABLE) {
+            fixable_rules.insert(rule);
+        }
+    }
+
+    // The virtual documents don't have a configuration file where the rules can be disabled
+    let can_disable_rules = session.configuration_file_path(&url).is_some();
+
+    let mut lenses = vec![];
+    for (line, rule) in lines {
+        let start = lsp_types::Position::new(line, 0);
+        let range = lsp_types::Range::new(start, start);
+
+        if fixable_rules.contains(rule) {
+            lenses.push(CodeLens {
+                range,
+                command: Some(Command {
+                    title: format!("Fix all {rule} in file"),
+                    command: FIX_ALL_RULE_COMMAND.to_string(),
+                    arguments: Some(vec![Value::from(url.as_str()), Value::from(rule)]),
+                }),
+                data: None,
+            });
+        }
+        if can_disable_rules {
+            lenses.push(CodeLens {
+                range,
+                command: Some(Command {
+                    title: format!("Disable {rule} for project"),
+                    command: DISABLE_RULE_COMMAND.to_string(),
+                    arguments: Some(vec![Value::from(rule), Value::from(url.as_str())]),
+                }),
+                data: None,
+            });
+        }
+    }
+
+    Ok(Some(lenses))
+}
+
+/// Runs the commands of the code lenses
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn execute_command(
+    session: &Session,
+    params: lsp_types::ExecuteCommandParams,
+) -> Result<Option<Value>, LspError> {
+    let mut arguments = params.arguments.iter().map(Value::as_str);
+    match params.command.as_str() {
+        FIX_ALL_RULE_COMMAND => {
+            let (Some(Some(url)), Some(Some(rule))) = (arguments.next(), arguments.next()) else {
+                return Err(anyhow!("{FIX_ALL_RULE_COMMAND} expects a URL and a rule").into());
+            };
+            let url = Url::parse(url).context("invalid document URL")?;
+            fix_all_rule(session, url, parse_rule(rule)?).await?;
+        }
+        DISABLE_RULE_COMMAND => {
+            let Some(Some(rule)) = arguments.next() else {
+                return Err(anyhow!("{DISABLE_RULE_COMMAND} expects a rule").into());
+            };
+            let url = match arguments.next() {
+                Some(Some(url)) => Some(Url::parse(url).context("invalid document URL")?),
+                _ => None,
+            };
+            disable_rule_in_configuration(session, parse_rule(rule)?, url.as_ref()).await?;
+        }
+        command => return Err(anyhow!("unknown command {command}").into()),
+    }
+
+    Ok(None)
+}
+
+fn parse_rule(rule: &str) -> Result<RuleSelector, LspError> {
+    rule.parse::<RuleSelector>()
+        .map_err(|message| anyhow!(message).into())
+}
+
+/// Applies the fixes of `rule` to the document, including the unsafe ones since the user
+/// asked for the fixes of this rule
+async fn fix_all_rule(session: &Session, url: Url, rule: RuleSelector) -> Result<(), LspError> {
+    let rome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+
+    let fixed = session.workspace.fix_file(FixFileParams {
+        path: rome_path,
+        fix_file_mode: FixFileMode::SafeAndUnsafeFixes,
+        should_format: false,
+        only: vec![rule],
+        suppression_reason: None,
+    })?;
+    if fixed.actions.is_empty() {
+        return Ok(());
+    }
+
+    let mut changes = HashMap::new();
+    changes.insert(
+        url,
+        vec![lsp_types::TextEdit {
+            range: lsp_types::Range {
+                start: lsp_types::Position::new(0, 0),
+                end: lsp_types::Position::new(doc.line_index.len(), 0),
+            },
+            new_text: fixed.code,
+        }],
+    );
+
+    let response = session
+        .client
+        .apply_edit(lsp_types::WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        })
+        .await
+        .context("failed to apply the fixes")?;
+    if !response.applied {
+        return Err(anyhow!(
+            "the client didn't apply the fixes: {}",
+            response.failure_reason.unwrap_or_default()
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Turns off `rule` in the configuration file of the workspace, and reloads the configuration
+async fn disable_rule_in_configuration(
+    session: &Session,
+    rule: RuleSelector,
+    url: Option<&Url>,
+) -> Result<(), LspError> {
+    write_disabled_rule(session, &rule, url)?;
+
+    session.load_workspace_settings().await;
+    session.update_all_diagnostics().await;
+
+    Ok(())
+}
+
+/// Turns off `rule` in the configuration file of the workspace folder of the document, or of
+/// the root folder, which is created if it doesn't exist
+fn write_disabled_rule(
+    session: &Session,
+    rule: &RuleSelector,
+    url: Option<&Url>,
+) -> Result<(), LspError> {
+    let path = url
+        .and_then(|url| session.configuration_file_path(url))
+        .or_else(|| session.root_configuration_file_path())
+        .context("the workspace doesn't have a directory for its configuration file")?;
+
+    let existing_file = session
+        .fs
+        .open_with_options(&path, OpenOptions::default().read(true).write(true));
+    let (mut file, content) = match existing_file {
+        Ok(mut file) => {
+            let mut content = String::new();
+            file.read_to_string(&mut content)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            (file, content)
+        }
+        Err(_) => {
+            let file = session
+                .fs
+                .create_new(&path)
+                .with_context(|| format!("failed to create {}", path.display()))?;
+            (file, String::new())
+        }
+    };
+
+    let content = disable_rule(&content, &rule.group, &rule.rule)
+        .with_context(|| format!("failed to edit {}, it isn't valid JSON", path.display()))?;
+    file.set_content(content.as_bytes())
+        .with_context(|| format!("failed to write {}", path.display()))?;
+
+    Ok(())
+}
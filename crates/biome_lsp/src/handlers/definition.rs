@@ -0,0 +1,59 @@
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_service::resolver::resolve_module;
+use biome_service::workspace::{Definition, GotoDefinitionParams};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{self, GotoDefinitionResponse, Location, Position, Range, Url};
+use tracing::trace;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: lsp_types::GotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let rome_path = session.file_path(&url)?;
+
+    trace!("Finding definition...");
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let cursor_range = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    let result = session.workspace.goto_definition(GotoDefinitionParams {
+        path: rome_path,
+        symbol_at: cursor_range,
+    });
+
+    let result = match result {
+        Ok(result) => result,
+        // Other language servers may provide definitions for this file
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let location = match result.definition {
+        None => None,
+        Some(Definition::Local { range }) => {
+            let range = to_proto::range(&doc.line_index, range, position_encoding)?;
+            Some(Location::new(url, range))
+        }
+        Some(Definition::Module { specifier }) => {
+            // Modules can only be resolved for files that exist on disk
+            let Ok(importer) = url.to_file_path() else {
+                return Ok(None);
+            };
+            resolve_module(&*session.fs, &importer, &specifier)
+                .and_then(|path| Url::from_file_path(path).ok())
+                .map(|target| {
+                    Location::new(target, Range::new(Position::default(), Position::default()))
+                })
+        }
+    };
+
+    Ok(location.map(GotoDefinitionResponse::Scalar))
+}
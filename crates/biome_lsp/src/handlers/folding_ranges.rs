@@ -0,0 +1,65 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_service::workspace::{FoldingRangeKind, FoldingRangesParams};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{self, FoldingRange};
+use tracing::trace;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn folding_ranges(
+    session: &Session,
+    params: lsp_types::FoldingRangeParams,
+) -> Result<Option<Vec<FoldingRange>>, LspError> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+
+    trace!("Computing folding ranges...");
+
+    let doc = session.document(&url)?;
+
+    let result = session
+        .workspace
+        .folding_ranges(FoldingRangesParams { path: rome_path });
+
+    let result = match result {
+        Ok(result) => result,
+        // Other language servers may provide folding ranges for this file
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut ranges = vec![];
+    for folding_range in result.ranges {
+        // The folding ranges only use lines, so the position encoding doesn't matter
+        let (Some(start), Some(end)) = (
+            doc.line_index.line_col(folding_range.range.start()),
+            doc.line_index.line_col(folding_range.range.end()),
+        ) else {
+            continue;
+        };
+        // A range on a single line can't be folded
+        if end.line <= start.line {
+            continue;
+        }
+
+        ranges.push(FoldingRange {
+            start_line: start.line,
+            start_character: None,
+            end_line: end.line,
+            end_character: None,
+            kind: match folding_range.kind {
+                FoldingRangeKind::Code => None,
+                FoldingRangeKind::Comment => Some(lsp_types::FoldingRangeKind::Comment),
+                FoldingRangeKind::Imports => Some(lsp_types::FoldingRangeKind::Imports),
+                FoldingRangeKind::Region => Some(lsp_types::FoldingRangeKind::Region),
+            },
+            collapsed_text: None,
+        });
+    }
+
+    if let Some(limit) = session.folding_range_limit() {
+        ranges.truncate(limit);
+    }
+
+    Ok(Some(ranges))
+}
@@ -103,6 +103,11 @@ pub(crate) fn format_on_type(
         offset,
     })?;
 
+    // The character doesn't complete a statement or a block
+    if formatted.range().is_none() && formatted.as_code().is_empty() {
+        return Ok(None);
+    }
+
     // Recalculate the actual range that was reformatted from the formatter result
     let formatted_range = match formatted.range() {
         Some(range) => {
@@ -0,0 +1,72 @@
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_service::workspace::{InlayHintKind, InlayHintsParams};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{self, InlayHint, InlayHintLabel};
+use tracing::trace;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn inlay_hints(
+    session: &Session,
+    params: lsp_types::InlayHintParams,
+) -> Result<Option<Vec<InlayHint>>, LspError> {
+    let (parameter_names, return_types) = session
+        .extension_settings
+        .read()
+        .map(|config| {
+            let settings = config.inlay_hints();
+            (
+                settings.parameter_names.unwrap_or(false),
+                settings.return_types.unwrap_or(false),
+            )
+        })
+        .unwrap_or_default();
+    if !parameter_names && !return_types {
+        return Ok(None);
+    }
+
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+
+    trace!("Computing inlay hints...");
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let range = from_proto::text_range(&doc.line_index, params.range, position_encoding)?;
+
+    let result = session.workspace.inlay_hints(InlayHintsParams {
+        path: rome_path,
+        range,
+        parameter_names,
+        return_types,
+    });
+
+    let result = match result {
+        Ok(result) => result,
+        // Other language servers may provide inlay hints for this file
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut hints = Vec::with_capacity(result.hints.len());
+    for hint in result.hints {
+        let is_parameter_name = hint.kind == InlayHintKind::ParameterName;
+        hints.push(InlayHint {
+            position: to_proto::position(&doc.line_index, hint.position, position_encoding)?,
+            label: InlayHintLabel::String(hint.label),
+            kind: Some(match hint.kind {
+                InlayHintKind::ParameterName => lsp_types::InlayHintKind::PARAMETER,
+                InlayHintKind::ReturnType => lsp_types::InlayHintKind::TYPE,
+            }),
+            text_edits: None,
+            tooltip: None,
+            padding_left: None,
+            // Separates the name of the parameter from the argument
+            padding_right: is_parameter_name.then_some(true),
+            data: None,
+        });
+    }
+
+    Ok(Some(hints))
+}
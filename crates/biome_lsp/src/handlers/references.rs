@@ -0,0 +1,60 @@
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_service::workspace::{FindReferencesParams, SymbolReferenceKind};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{Location, ReferenceParams};
+use tracing::trace;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn references(
+    session: &Session,
+    params: ReferenceParams,
+) -> Result<Option<Vec<Location>>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+
+    trace!("Finding references...");
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let cursor_range = from_proto::offset(
+        &doc.line_index,
+        params.text_document_position.position,
+        position_encoding,
+    )
+    .with_context(|| {
+        format!(
+            "failed to access position {:?} in document {url}",
+            params.text_document_position.position
+        )
+    })?;
+
+    let result = session.workspace.find_references(FindReferencesParams {
+        path: rome_path,
+        symbol_at: cursor_range,
+    });
+
+    let result = match result {
+        Ok(result) => result,
+        // Other language servers may provide references for this file
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let include_declaration = params.context.include_declaration;
+    let locations = result
+        .references
+        .into_iter()
+        .filter(|reference| {
+            include_declaration || reference.kind != SymbolReferenceKind::Declaration
+        })
+        .map(|reference| {
+            let range = to_proto::range(&doc.line_index, reference.range, position_encoding)?;
+            Ok(Location::new(url.clone(), range))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(Some(locations))
+}
@@ -1,11 +1,54 @@
 use std::collections::HashMap;
 
-use crate::converters::from_proto;
+use crate::converters::{from_proto, to_proto};
 use crate::{session::Session, utils};
 use anyhow::{Context, Result};
-use tower_lsp::lsp_types::{RenameParams, WorkspaceEdit};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    PrepareRenameResponse, RenameParams, TextDocumentPositionParams, WorkspaceEdit,
+};
 use tracing::trace;
 
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let cursor_range = from_proto::offset(&doc.line_index, params.position, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access position {:?} in document {url}",
+                params.position
+            )
+        })?;
+
+    let result = session
+        .workspace
+        .prepare_rename(biome_service::workspace::PrepareRenameParams {
+            path: rome_path,
+            symbol_at: cursor_range,
+        });
+
+    let result = match result {
+        Ok(result) => result,
+        // The symbol under the cursor can't be renamed: let the client tell the user
+        Err(WorkspaceError::RenameError(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let range = to_proto::range(&doc.line_index, result.range, position_encoding)?;
+
+    Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+        range,
+        placeholder: result.name,
+    }))
+}
+
 #[tracing::instrument(level = "debug", skip(session), err)]
 pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
     let url = params.text_document_position.text_document.uri;
@@ -0,0 +1,174 @@
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_rowan::TextRange;
+use biome_service::workspace::{SemanticTokenKind, SemanticTokenModifier, SemanticTokensParams};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    self, SemanticToken, SemanticTokenModifier as LspModifier, SemanticTokenType, SemanticTokens,
+    SemanticTokensLegend, SemanticTokensRangeResult, SemanticTokensResult, Url,
+};
+use tracing::trace;
+
+/// The token types sent to the client, the index of a kind in this list is its
+/// identifier in the encoded tokens
+const TOKEN_KINDS: &[SemanticTokenKind] = &[
+    SemanticTokenKind::Class,
+    SemanticTokenKind::Comment,
+    SemanticTokenKind::Component,
+    SemanticTokenKind::Enum,
+    SemanticTokenKind::EnumMember,
+    SemanticTokenKind::Function,
+    SemanticTokenKind::Interface,
+    SemanticTokenKind::JsxTag,
+    SemanticTokenKind::Keyword,
+    SemanticTokenKind::Method,
+    SemanticTokenKind::Namespace,
+    SemanticTokenKind::Number,
+    SemanticTokenKind::Parameter,
+    SemanticTokenKind::Property,
+    SemanticTokenKind::Regexp,
+    SemanticTokenKind::String,
+    SemanticTokenKind::Type,
+    SemanticTokenKind::TypeParameter,
+    SemanticTokenKind::Variable,
+];
+
+/// The token modifiers sent to the client, the modifiers of a token are encoded
+/// as a bit set of the indices in this list
+const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+    SemanticTokenModifier::Declaration,
+    SemanticTokenModifier::Readonly,
+    SemanticTokenModifier::Imported,
+];
+
+/// Returns the legend that the client uses to decode the tokens
+pub(crate) fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_KINDS.iter().copied().map(to_lsp_token_type).collect(),
+        token_modifiers: TOKEN_MODIFIERS
+            .iter()
+            .copied()
+            .map(to_lsp_token_modifier)
+            .collect(),
+    }
+}
+
+fn to_lsp_token_type(kind: SemanticTokenKind) -> SemanticTokenType {
+    match kind {
+        SemanticTokenKind::Class => SemanticTokenType::CLASS,
+        SemanticTokenKind::Comment => SemanticTokenType::COMMENT,
+        SemanticTokenKind::Component => SemanticTokenType::new("component"),
+        SemanticTokenKind::Enum => SemanticTokenType::ENUM,
+        SemanticTokenKind::EnumMember => SemanticTokenType::ENUM_MEMBER,
+        SemanticTokenKind::Function => SemanticTokenType::FUNCTION,
+        SemanticTokenKind::Interface => SemanticTokenType::INTERFACE,
+        SemanticTokenKind::JsxTag => SemanticTokenType::new("tag"),
+        SemanticTokenKind::Keyword => SemanticTokenType::KEYWORD,
+        SemanticTokenKind::Method => SemanticTokenType::METHOD,
+        SemanticTokenKind::Namespace => SemanticTokenType::NAMESPACE,
+        SemanticTokenKind::Number => SemanticTokenType::NUMBER,
+        SemanticTokenKind::Parameter => SemanticTokenType::PARAMETER,
+        SemanticTokenKind::Property => SemanticTokenType::PROPERTY,
+        SemanticTokenKind::Regexp => SemanticTokenType::REGEXP,
+        SemanticTokenKind::String => SemanticTokenType::STRING,
+        SemanticTokenKind::Type => SemanticTokenType::TYPE,
+        SemanticTokenKind::TypeParameter => SemanticTokenType::TYPE_PARAMETER,
+        SemanticTokenKind::Variable => SemanticTokenType::VARIABLE,
+    }
+}
+
+fn to_lsp_token_modifier(modifier: SemanticTokenModifier) -> LspModifier {
+    match modifier {
+        SemanticTokenModifier::Declaration => LspModifier::DECLARATION,
+        SemanticTokenModifier::Readonly => LspModifier::READONLY,
+        SemanticTokenModifier::Imported => LspModifier::new("imported"),
+    }
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_full(
+    session: &Session,
+    params: lsp_types::SemanticTokensParams,
+) -> Result<Option<SemanticTokensResult>, LspError> {
+    let tokens = semantic_tokens(session, &params.text_document.uri, None)?;
+    Ok(tokens.map(SemanticTokensResult::Tokens))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_range(
+    session: &Session,
+    params: lsp_types::SemanticTokensRangeParams,
+) -> Result<Option<SemanticTokensRangeResult>, LspError> {
+    let url = params.text_document.uri;
+    let doc = session.document(&url)?;
+    let range = from_proto::text_range(&doc.line_index, params.range, session.position_encoding())?;
+
+    let tokens = semantic_tokens(session, &url, Some(range))?;
+    Ok(tokens.map(SemanticTokensRangeResult::Tokens))
+}
+
+fn semantic_tokens(
+    session: &Session,
+    url: &Url,
+    range: Option<TextRange>,
+) -> Result<Option<SemanticTokens>, LspError> {
+    let rome_path = session.file_path(url)?;
+
+    trace!("Computing semantic tokens...");
+
+    let doc = session.document(url)?;
+    let position_encoding = session.position_encoding();
+
+    let result = session.workspace.semantic_tokens(SemanticTokensParams {
+        path: rome_path,
+        range,
+    });
+
+    let result = match result {
+        Ok(result) => result,
+        // Other language servers may highlight this file
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    // Each token is encoded relatively to the previous one
+    let mut data = Vec::with_capacity(result.tokens.len());
+    let mut previous_line = 0;
+    let mut previous_start = 0;
+    for token in result.tokens {
+        let range = to_proto::range(&doc.line_index, token.range, position_encoding)?;
+        let line = range.start.line;
+        let start = range.start.character;
+
+        let token_type = TOKEN_KINDS
+            .iter()
+            .position(|kind| *kind == token.kind)
+            .unwrap_or_default();
+        let token_modifiers_bitset = token
+            .modifiers
+            .iter()
+            .filter_map(|modifier| TOKEN_MODIFIERS.iter().position(|known| known == modifier))
+            .fold(0, |bitset, index| bitset | (1 << index));
+
+        data.push(SemanticToken {
+            delta_line: line - previous_line,
+            delta_start: if line == previous_line {
+                start - previous_start
+            } else {
+                start
+            },
+            length: range.end.character - start,
+            token_type: token_type as u32,
+            token_modifiers_bitset,
+        });
+
+        previous_line = line;
+        previous_start = start;
+    }
+
+    Ok(Some(SemanticTokens {
+        result_id: None,
+        data,
+    }))
+}
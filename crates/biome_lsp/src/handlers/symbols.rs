@@ -0,0 +1,208 @@
+use crate::converters::line_index::LineIndex;
+use crate::converters::{to_proto, PositionEncoding};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use biome_fs::OpenOptions;
+use biome_service::workspace::{
+    DocumentSymbol, DocumentSymbolsParams, SymbolKind, WorkspaceSymbolsParams,
+};
+use biome_service::WorkspaceError;
+use rustc_hash::FxHashMap;
+use std::path::PathBuf;
+use tower_lsp::lsp_types::{self, DocumentSymbolResponse, Location, SymbolInformation, Url};
+use tracing::trace;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_symbols(
+    session: &Session,
+    params: lsp_types::DocumentSymbolParams,
+) -> Result<Option<DocumentSymbolResponse>, LspError> {
+    let url = params.text_document.uri;
+    let rome_path = session.file_path(&url)?;
+
+    trace!("Computing document symbols...");
+
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let result = session
+        .workspace
+        .document_symbols(DocumentSymbolsParams { path: rome_path });
+
+    let result = match result {
+        Ok(result) => result,
+        // Other language servers may provide symbols for this file
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let response = if session.supports_hierarchical_document_symbols() {
+        let symbols = result
+            .symbols
+            .into_iter()
+            .map(|symbol| to_lsp_document_symbol(symbol, &doc.line_index, position_encoding))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        DocumentSymbolResponse::Nested(symbols)
+    } else {
+        let mut symbols = vec![];
+        for symbol in result.symbols {
+            flatten_symbol(
+                symbol,
+                None,
+                &url,
+                &doc.line_index,
+                position_encoding,
+                &mut symbols,
+            )?;
+        }
+        DocumentSymbolResponse::Flat(symbols)
+    };
+
+    Ok(Some(response))
+}
+
+/// Searches the symbols exported by the files of the workspace
+#[allow(deprecated)]
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn workspace_symbols(
+    session: &Session,
+    params: lsp_types::WorkspaceSymbolParams,
+) -> Result<Option<Vec<SymbolInformation>>, LspError> {
+    let result = session
+        .workspace
+        .workspace_symbols(WorkspaceSymbolsParams {
+            query: params.query,
+        })?;
+
+    let base_path = session.base_path().unwrap_or_default();
+    let position_encoding = session.position_encoding();
+    // The files that aren't open are read from the disk, once per file
+    let mut line_indexes: FxHashMap<PathBuf, (Url, LineIndex)> = FxHashMap::default();
+
+    let mut symbols = Vec::with_capacity(result.symbols.len());
+    for symbol in result.symbols {
+        let path = base_path.join(symbol.path.as_path());
+        if !line_indexes.contains_key(&path) {
+            let Ok(url) = Url::from_file_path(&path) else {
+                continue;
+            };
+            let line_index = match session.document(&url) {
+                Ok(document) => document.line_index,
+                Err(_) => {
+                    let mut content = String::new();
+                    let read = session
+                        .fs
+                        .open_with_options(&path, OpenOptions::default().read(true))
+                        .and_then(|mut file| file.read_to_string(&mut content));
+                    if read.is_err() {
+                        continue;
+                    }
+                    LineIndex::new(&content)
+                }
+            };
+            line_indexes.insert(path.clone(), (url, line_index));
+        }
+
+        let (url, line_index) = &line_indexes[&path];
+        let Ok(range) = to_proto::range(line_index, symbol.range, position_encoding) else {
+            continue;
+        };
+        symbols.push(SymbolInformation {
+            name: symbol.name,
+            kind: to_lsp_symbol_kind(symbol.kind),
+            tags: None,
+            deprecated: None,
+            location: Location::new(url.clone(), range),
+            container_name: None,
+        });
+    }
+
+    Ok(Some(symbols))
+}
+
+#[allow(deprecated)]
+fn to_lsp_document_symbol(
+    symbol: DocumentSymbol,
+    line_index: &LineIndex,
+    position_encoding: PositionEncoding,
+) -> anyhow::Result<lsp_types::DocumentSymbol> {
+    let children = symbol
+        .children
+        .into_iter()
+        .map(|child| to_lsp_document_symbol(child, line_index, position_encoding))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(lsp_types::DocumentSymbol {
+        detail: symbol_detail(symbol.kind),
+        kind: to_lsp_symbol_kind(symbol.kind),
+        name: symbol.name,
+        range: to_proto::range(line_index, symbol.range, position_encoding)?,
+        selection_range: to_proto::range(line_index, symbol.selection_range, position_encoding)?,
+        children: Some(children),
+        tags: None,
+        deprecated: None,
+    })
+}
+
+/// Clients that don't support nested symbols receive a list where each symbol
+/// knows the name of its parent
+#[allow(deprecated)]
+fn flatten_symbol(
+    symbol: DocumentSymbol,
+    container_name: Option<&str>,
+    url: &Url,
+    line_index: &LineIndex,
+    position_encoding: PositionEncoding,
+    symbols: &mut Vec<SymbolInformation>,
+) -> anyhow::Result<()> {
+    symbols.push(SymbolInformation {
+        name: symbol.name.clone(),
+        kind: to_lsp_symbol_kind(symbol.kind),
+        tags: None,
+        deprecated: None,
+        location: Location::new(
+            url.clone(),
+            to_proto::range(line_index, symbol.range, position_encoding)?,
+        ),
+        container_name: container_name.map(String::from),
+    });
+
+    for child in symbol.children {
+        flatten_symbol(
+            child,
+            Some(&symbol.name),
+            url,
+            line_index,
+            position_encoding,
+            symbols,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn to_lsp_symbol_kind(kind: SymbolKind) -> lsp_types::SymbolKind {
+    match kind {
+        SymbolKind::Class => lsp_types::SymbolKind::CLASS,
+        SymbolKind::Component => lsp_types::SymbolKind::FUNCTION,
+        SymbolKind::Constant => lsp_types::SymbolKind::CONSTANT,
+        SymbolKind::Constructor => lsp_types::SymbolKind::CONSTRUCTOR,
+        SymbolKind::Enum => lsp_types::SymbolKind::ENUM,
+        SymbolKind::EnumMember => lsp_types::SymbolKind::ENUM_MEMBER,
+        SymbolKind::Function => lsp_types::SymbolKind::FUNCTION,
+        SymbolKind::Interface => lsp_types::SymbolKind::INTERFACE,
+        SymbolKind::Method => lsp_types::SymbolKind::METHOD,
+        SymbolKind::Module => lsp_types::SymbolKind::MODULE,
+        SymbolKind::Property => lsp_types::SymbolKind::PROPERTY,
+        SymbolKind::TypeAlias => lsp_types::SymbolKind::TYPE_PARAMETER,
+        SymbolKind::Variable => lsp_types::SymbolKind::VARIABLE,
+    }
+}
+
+/// The LSP doesn't have a kind for components, the detail tells them apart from functions
+fn symbol_detail(kind: SymbolKind) -> Option<String> {
+    match kind {
+        SymbolKind::Component => Some(String::from("component")),
+        _ => None,
+    }
+}
@@ -4,10 +4,11 @@ mod diagnostics;
 mod documents;
 mod extension_settings;
 mod handlers;
+mod progress;
 mod requests;
 mod server;
 mod session;
 mod utils;
 
-pub use crate::extension_settings::WorkspaceSettings;
+pub use crate::extension_settings::{InlayHintsSettings, WorkspaceSettings};
 pub use crate::server::{LSPServer, ServerConnection, ServerFactory};
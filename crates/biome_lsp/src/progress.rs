@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use tower_lsp::lsp_types::notification::Progress;
+use tower_lsp::lsp_types::request::WorkDoneProgressCreate;
+use tower_lsp::lsp_types::{
+    NumberOrString, ProgressParams, ProgressParamsValue, WorkDoneProgress, WorkDoneProgressBegin,
+    WorkDoneProgressCreateParams, WorkDoneProgressEnd, WorkDoneProgressReport,
+};
+use tracing::error;
+
+/// Used to create a unique token for each progress of the server
+static NEXT_TOKEN: AtomicU64 = AtomicU64::new(0);
+
+/// The progress of a task started by the server, shown by the client with
+/// `window/workDoneProgress`. The progress does nothing when the client doesn't
+/// support it, or when it refused to create it.
+pub(crate) struct WorkDoneProgressHandle {
+    client: tower_lsp::Client,
+    token: Option<NumberOrString>,
+}
+
+impl WorkDoneProgressHandle {
+    /// Asks the client to create a progress, and shows it with the given `title`
+    pub(crate) async fn begin(
+        client: tower_lsp::Client,
+        is_supported: bool,
+        title: impl Into<String>,
+    ) -> Self {
+        if !is_supported {
+            return Self {
+                client,
+                token: None,
+            };
+        }
+
+        let token = NumberOrString::String(format!(
+            "biome/{}",
+            NEXT_TOKEN.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = client
+            .send_request::<WorkDoneProgressCreate>(WorkDoneProgressCreateParams {
+                token: token.clone(),
+            })
+            .await;
+        if let Err(err) = result {
+            error!("The client couldn't create the progress: {err}");
+            return Self {
+                client,
+                token: None,
+            };
+        }
+
+        let progress = Self {
+            client,
+            token: Some(token),
+        };
+        progress
+            .send(WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                title: title.into(),
+                cancellable: Some(false),
+                message: None,
+                percentage: None,
+            }))
+            .await;
+        progress
+    }
+
+    /// Updates the message of the progress
+    pub(crate) async fn report(&self, message: impl Into<String>) {
+        self.send(WorkDoneProgress::Report(WorkDoneProgressReport {
+            cancellable: Some(false),
+            message: Some(message.into()),
+            percentage: None,
+        }))
+        .await;
+    }
+
+    /// Ends the progress, with an optional final message
+    pub(crate) async fn end(self, message: Option<String>) {
+        self.send(WorkDoneProgress::End(WorkDoneProgressEnd { message }))
+            .await;
+    }
+
+    async fn send(&self, value: WorkDoneProgress) {
+        let Some(token) = self.token.clone() else {
+            return;
+        };
+        self.client
+            .send_notification::<Progress>(ProgressParams {
+                token,
+                value: ProgressParamsValue::WorkDone(value),
+            })
+            .await;
+    }
+}
@@ -1 +1,2 @@
+pub(crate) mod pull_diagnostics;
 pub(crate) mod syntax_tree;
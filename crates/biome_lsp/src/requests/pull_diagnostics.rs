@@ -0,0 +1,69 @@
+//! The pull model of the diagnostics, where the client requests the diagnostics of a
+//! document instead of waiting for the server to publish them. The types of the protocol
+//! aren't part of the version of `lsp_types` used by the server yet.
+
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::request::Request;
+use tower_lsp::lsp_types::{Diagnostic, TextDocumentIdentifier};
+use tracing::trace;
+
+pub const DOCUMENT_DIAGNOSTIC_REQUEST: &str = "textDocument/diagnostic";
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentDiagnosticParams {
+    pub text_document: TextDocumentIdentifier,
+    /// The identifier of the provider, sent in the registration options
+    pub identifier: Option<String>,
+    /// The result ID of the previous report of the document, if the client has one
+    pub previous_result_id: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DocumentDiagnosticReport {
+    /// All the diagnostics of the document
+    #[serde(rename_all = "camelCase")]
+    Full {
+        result_id: Option<String>,
+        items: Vec<Diagnostic>,
+    },
+    /// The diagnostics of the document didn't change since the report with this result ID
+    #[serde(rename_all = "camelCase")]
+    Unchanged { result_id: String },
+}
+
+/// Asks the client to pull the diagnostics of its documents again, after the settings changed
+pub(crate) enum WorkspaceDiagnosticRefresh {}
+
+impl Request for WorkspaceDiagnosticRefresh {
+    type Params = ();
+    type Result = ();
+    const METHOD: &'static str = "workspace/diagnostic/refresh";
+}
+
+/// Handler for `textDocument/diagnostic` LSP requests
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) async fn document_diagnostic(
+    session: &Session,
+    params: DocumentDiagnosticParams,
+) -> Result<DocumentDiagnosticReport, LspError> {
+    session.start_pulling_diagnostics().await;
+
+    let url = params.text_document.uri;
+    let doc = session.document(&url)?;
+
+    let result_id = session.diagnostics_result_id(&doc);
+    if params.previous_result_id.as_ref() == Some(&result_id) {
+        trace!("The diagnostics of {url} didn't change");
+        return Ok(DocumentDiagnosticReport::Unchanged { result_id });
+    }
+
+    let items = session.compute_diagnostics(&url, &doc)?;
+    Ok(DocumentDiagnosticReport::Full {
+        result_id: Some(result_id),
+        items,
+    })
+}
@@ -1,5 +1,9 @@
 use crate::capabilities::server_capabilities;
 use crate::diagnostics::{handle_lsp_error, LspError};
+use crate::progress::WorkDoneProgressHandle;
+use crate::requests::pull_diagnostics::{
+    DocumentDiagnosticParams, DocumentDiagnosticReport, DOCUMENT_DIAGNOSTIC_REQUEST,
+};
 use crate::requests::syntax_tree::{SyntaxTreePayload, SYNTAX_TREE_REQUEST};
 use crate::session::{
     CapabilitySet, CapabilityStatus, ClientInformation, Session, SessionHandle, SessionKey,
@@ -17,16 +21,21 @@ use rustc_hash::FxHashMap;
 use serde_json::json;
 use std::panic::RefUnwindSafe;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::sync::Notify;
 use tokio::task::spawn_blocking;
+use tokio::time::timeout;
 use tower_lsp::jsonrpc::Result as LspResult;
 use tower_lsp::{lsp_types::*, ClientSocket};
 use tower_lsp::{LanguageServer, LspService, Server};
 use tracing::{error, info, trace, warn};
 
+/// How often the progress of the indexing of the workspace is sent to the client
+const PROGRESS_REPORT_INTERVAL: Duration = Duration::from_millis(250);
+
 pub struct LSPServer {
     session: SessionHandle,
     /// Map of all sessions connected to the same [ServerFactory] as this [LSPServer].
@@ -67,6 +76,15 @@ impl LSPServer {
         requests::syntax_tree::syntax_tree(&self.session, &url).map_err(into_lsp_error)
     }
 
+    async fn document_diagnostic_request(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> LspResult<DocumentDiagnosticReport> {
+        requests::pull_diagnostics::document_diagnostic(&self.session, params)
+            .await
+            .map_err(into_lsp_error)
+    }
+
     #[tracing::instrument(skip(self), name = "biome/rage", level = "debug")]
     async fn rage(&self, params: RageParams) -> LspResult<RageResult> {
         let mut entries = vec![
@@ -115,6 +133,44 @@ impl LSPServer {
         Ok(RageResult { entries })
     }
 
+    /// Indexes the symbols of the workspace in the background. The client shows the
+    /// number of indexed files while the workspace is scanned, if it supports it.
+    fn index_workspace_symbols(&self) {
+        let session = self.session.clone();
+        tokio::spawn(async move {
+            let progress = WorkDoneProgressHandle::begin(
+                session.client.clone(),
+                session.supports_work_done_progress(),
+                "Biome: indexing the workspace",
+            )
+            .await;
+
+            let indexed_files = Arc::new(AtomicUsize::new(0));
+            let mut task = spawn_blocking({
+                let session = session.clone();
+                let indexed_files = indexed_files.clone();
+                move || session.index_workspace_symbols(&indexed_files)
+            });
+
+            loop {
+                match timeout(PROGRESS_REPORT_INTERVAL, &mut task).await {
+                    Ok(Ok(())) => break,
+                    Ok(Err(err)) => {
+                        error!("Failed to index the workspace: {err}");
+                        break;
+                    }
+                    Err(_) => {
+                        let count = indexed_files.load(Ordering::Relaxed);
+                        progress.report(format!("{count} files")).await;
+                    }
+                }
+            }
+
+            let count = indexed_files.load(Ordering::Relaxed);
+            progress.end(Some(format!("Indexed {count} files"))).await;
+        });
+    }
+
     async fn setup_capabilities(&self) {
         let mut capabilities = CapabilitySet::default();
 
@@ -131,28 +187,52 @@ impl LSPServer {
         capabilities.add_capability(
             "biome_did_change_workspace_settings",
             "workspace/didChangeWatchedFiles",
-            if let Some(base_path) = self.session.base_path() {
-                CapabilityStatus::Enable(Some(json!(DidChangeWatchedFilesRegistrationOptions {
-                    watchers: vec![
-                        FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/biome.json",
-                                base_path.display()
-                            )),
-                            kind: Some(WatchKind::all()),
-                        },
-                        // TODO: Biome 2.0 remove it
+            {
+                let folders = self.session.workspace_folders();
+                if folders.is_empty() {
+                    CapabilityStatus::Disable
+                } else {
+                    let mut watchers: Vec<_> = folders
+                        .iter()
+                        .flat_map(|folder| {
+                            [
+                                FileSystemWatcher {
+                                    glob_pattern: GlobPattern::String(format!(
+                                        "{}/biome.json",
+                                        folder.display()
+                                    )),
+                                    kind: Some(WatchKind::all()),
+                                },
+                                // TODO: Biome 2.0 remove it
+                                FileSystemWatcher {
+                                    glob_pattern: GlobPattern::String(format!(
+                                        "{}/rome.json",
+                                        folder.display()
+                                    )),
+                                    kind: Some(WatchKind::all()),
+                                },
+                                // Keeps the index of the workspace symbols up to date
+                                FileSystemWatcher {
+                                    glob_pattern: GlobPattern::String(format!(
+                                        "{}/**/*.{{js,jsx,ts,tsx,mjs,cjs,mts,cts}}",
+                                        folder.display()
+                                    )),
+                                    kind: Some(WatchKind::all()),
+                                },
+                            ]
+                        })
+                        .collect();
+                    // The configuration files outside of the folders, like the extended ones
+                    watchers.extend(self.session.configuration_files().into_iter().map(|path| {
                         FileSystemWatcher {
-                            glob_pattern: GlobPattern::String(format!(
-                                "{}/rome.json",
-                                base_path.display()
-                            )),
+                            glob_pattern: GlobPattern::String(path.display().to_string()),
                             kind: Some(WatchKind::all()),
                         }
-                    ],
-                })))
-            } else {
-                CapabilityStatus::Disable
+                    }));
+                    CapabilityStatus::Enable(Some(json!(
+                        DidChangeWatchedFilesRegistrationOptions { watchers }
+                    )))
+                }
             },
         );
 
@@ -183,11 +263,23 @@ impl LSPServer {
                 CapabilityStatus::Enable(Some(json!(DocumentOnTypeFormattingRegistrationOptions {
                     document_selector: None,
                     first_trigger_character: String::from("}"),
-                    more_trigger_character: Some(vec![String::from("]"), String::from(")")]),
+                    more_trigger_character: Some(vec![String::from(";"), String::from("\n")]),
                 })))
             },
         );
 
+        // The clients that support the pull model of the diagnostics accept this registration
+        capabilities.add_capability(
+            "biome_pull_diagnostics",
+            DOCUMENT_DIAGNOSTIC_REQUEST,
+            CapabilityStatus::Enable(Some(json!({
+                "documentSelector": null,
+                "identifier": "biome",
+                "interFileDependencies": false,
+                "workspaceDiagnostics": false,
+            }))),
+        );
+
         let rename = {
             let config = self.session.extension_settings.read().ok();
             config.and_then(|x| x.settings.rename).unwrap_or(false)
@@ -197,7 +289,10 @@ impl LSPServer {
             "biome_rename",
             "textDocument/rename",
             if rename {
-                CapabilityStatus::Enable(None)
+                CapabilityStatus::Enable(Some(json!(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })))
             } else {
                 CapabilityStatus::Disable
             },
@@ -248,16 +343,13 @@ impl LanguageServer for LSPServer {
                 version: client_info.version,
             }),
             params.root_uri,
+            params.workspace_folders,
         );
 
         if params.root_path.is_some() {
             warn!("The Biome Server was initialized with the deprecated `root_path` parameter: this is not supported, use `root_uri` instead");
         }
 
-        if params.workspace_folders.is_some() {
-            warn!("The Biome Server was initialized with the `workspace_folders` parameter: this is unsupported at the moment, use `root_uri` instead");
-        }
-
         //
         let init = InitializeResult {
             capabilities: server_capabilities,
@@ -291,6 +383,8 @@ impl LanguageServer for LSPServer {
 
         // Diagnostics are disabled by default, so update them after fetching workspace config
         self.session.update_all_diagnostics().await;
+
+        self.index_workspace_symbols();
     }
 
     async fn shutdown(&self) -> LspResult<()> {
@@ -304,6 +398,13 @@ impl LanguageServer for LSPServer {
         self.session.load_extension_settings().await;
         self.setup_capabilities().await;
         self.session.update_all_diagnostics().await;
+
+        // The categories of inlay hints may have changed
+        if self.session.can_refresh_inlay_hints() {
+            if let Err(err) = self.session.client.inlay_hint_refresh().await {
+                error!("Failed to refresh the inlay hints: {err}");
+            }
+        }
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
@@ -315,21 +416,26 @@ impl LanguageServer for LSPServer {
         for file_path in file_paths {
             match file_path {
                 Ok(file_path) => {
-                    let base_path = self.session.base_path();
-                    if let Some(base_path) = base_path {
-                        let possible_rome_json = file_path.strip_prefix(&base_path);
-                        if let Ok(possible_rome_json) = possible_rome_json {
-                            if possible_rome_json.display().to_string() == ROME_JSON
-                                || possible_rome_json.display().to_string() == BIOME_JSON
-                            {
-                                self.session.load_workspace_settings().await;
-                                self.setup_capabilities().await;
-                                self.session.update_all_diagnostics().await;
-                                // for now we are only interested to the configuration file,
-                                // so it's OK to exist the loop
-                                break;
-                            }
-                        }
+                    self.session.index_file_symbols(&file_path);
+
+                    let is_configuration_file =
+                        self.session.workspace_folders().iter().any(|folder| {
+                            file_path
+                                .strip_prefix(folder)
+                                .is_ok_and(|possible_rome_json| {
+                                    possible_rome_json.display().to_string() == ROME_JSON
+                                        || possible_rome_json.display().to_string() == BIOME_JSON
+                                })
+                        }) || self.session.configuration_files().contains(&file_path);
+                    if is_configuration_file {
+                        self.session.load_workspace_settings().await;
+                        self.setup_capabilities().await;
+                        self.session.update_all_diagnostics().await;
+                        // the ignored files may have changed
+                        self.index_workspace_symbols();
+                        // for now we are only interested to the configuration file,
+                        // so it's OK to exist the loop
+                        break;
                     }
                 }
                 Err(_) => {
@@ -340,6 +446,17 @@ impl LanguageServer for LSPServer {
         }
     }
 
+    /// Called when the user added or removed folders of the workspace
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        self.session
+            .update_workspace_folders(params.event.added, params.event.removed);
+        self.session.load_workspace_settings().await;
+        self.setup_capabilities().await;
+        self.session.update_all_diagnostics().await;
+        self.index_workspace_symbols();
+    }
+
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
         handlers::text_document::did_open(&self.session, params)
             .await
@@ -353,9 +470,15 @@ impl LanguageServer for LSPServer {
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let file_path = params.text_document.uri.to_file_path();
         handlers::text_document::did_close(&self.session, params)
             .await
             .ok();
+
+        // The content on disk replaces the content of the document in the symbol index
+        if let Ok(file_path) = file_path {
+            self.session.index_file_symbols(&file_path);
+        }
     }
 
     async fn code_action(&self, params: CodeActionParams) -> LspResult<Option<CodeActionResponse>> {
@@ -398,6 +521,128 @@ impl LanguageServer for LSPServer {
         self.map_op_error(result).await
     }
 
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::definition::goto_definition(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::references::references(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::document_symbols(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> LspResult<Option<Vec<SymbolInformation>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::workspace_symbols(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> LspResult<Option<Vec<FoldingRange>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::folding_ranges::folding_ranges(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn code_lens(&self, params: CodeLensParams) -> LspResult<Option<Vec<CodeLens>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::code_lens::code_lens(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> LspResult<Option<serde_json::Value>> {
+        match handlers::code_lens::execute_command(&self.session, params).await {
+            Ok(result) => Ok(result),
+            Err(err) => handle_lsp_error(err, &self.session.client).await,
+        }
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> LspResult<Option<Vec<InlayHint>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::inlay_hints::inlay_hints(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> LspResult<Option<SemanticTokensResult>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_full(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> LspResult<Option<SemanticTokensRangeResult>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_range(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            let rename_enabled = self
+                .session
+                .extension_settings
+                .read()
+                .ok()
+                .and_then(|config| config.settings.rename)
+                .unwrap_or(false);
+
+            if rename_enabled {
+                handlers::rename::prepare_rename(&self.session, params).map_err(into_lsp_error)
+            } else {
+                Ok(None)
+            }
+        })
+        .map_err(into_lsp_error)?
+    }
+
     async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
         biome_diagnostics::panic::catch_unwind(move || {
             let rename_enabled = self
@@ -539,6 +784,10 @@ impl ServerFactory {
         });
 
         builder = builder.custom_method(SYNTAX_TREE_REQUEST, LSPServer::syntax_tree_request);
+        builder = builder.custom_method(
+            DOCUMENT_DIAGNOSTIC_REQUEST,
+            LSPServer::document_diagnostic_request,
+        );
 
         // "shutdown" is not part of the Workspace API
         builder = builder.custom_method("biome/shutdown", |server: &LSPServer, (): ()| {
@@ -553,6 +802,7 @@ impl ServerFactory {
         workspace_method!(builder, project_features);
         workspace_method!(builder, is_path_ignored);
         workspace_method!(builder, update_settings);
+        workspace_method!(builder, close_project);
         workspace_method!(builder, project_features);
         workspace_method!(builder, open_file);
         workspace_method!(builder, get_syntax_tree);
@@ -567,6 +817,10 @@ impl ServerFactory {
         workspace_method!(builder, format_range);
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
+        workspace_method!(builder, prepare_rename);
+        workspace_method!(builder, find_references);
+        workspace_method!(builder, goto_definition);
+        workspace_method!(builder, document_symbols);
         workspace_method!(builder, rename);
         workspace_method!(builder, organize_imports);
 
@@ -2,34 +2,47 @@ use crate::converters::{negotiated_encoding, PositionEncoding, WideEncoding};
 use crate::documents::Document;
 use crate::extension_settings::ExtensionSettings;
 use crate::extension_settings::CONFIGURATION_SECTION;
+use crate::requests::pull_diagnostics::WorkspaceDiagnosticRefresh;
 use crate::utils;
 use anyhow::Result;
 use biome_analyze::RuleCategories;
 use biome_console::markup;
-use biome_fs::{FileSystem, OsFileSystem, RomePath};
+use biome_diagnostics::{related_file_paths, DiagnosticExt, Error};
+use biome_fs::{FileSystem, OpenOptions, OsFileSystem, PathInterner, RomePath, TraversalContext};
+use biome_service::configuration::LoadedConfiguration;
+use biome_service::file_handlers::Language;
 use biome_service::workspace::{
-    FeatureName, FeaturesBuilder, PullDiagnosticsParams, SupportsFeatureParams,
+    CloseProjectParams, RageEntry, RageParams, RageResult, UpdateSettingsParams,
 };
-use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
-use biome_service::{load_config, ConfigurationBasePath, Workspace};
+use biome_service::workspace::{
+    FeatureName, FeaturesBuilder, GetFileContentParams, IndexFileParams, PullDiagnosticsParams,
+    SupportsFeatureParams,
+};
+use biome_service::{load_config, Configuration, ConfigurationBasePath, MergeWith, Workspace};
 use biome_service::{DynRef, WorkspaceError};
 use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
 use rustc_hash::FxHashMap;
 use serde_json::Value;
-use std::path::PathBuf;
-use std::sync::atomic::AtomicU8;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize};
 use std::sync::Arc;
 use std::sync::RwLock;
 use tokio::sync::Notify;
 use tokio::sync::OnceCell;
 use tower_lsp::lsp_types;
 use tower_lsp::lsp_types::Registration;
+use tower_lsp::lsp_types::ResourceOperationKind;
 use tower_lsp::lsp_types::Unregistration;
 use tower_lsp::lsp_types::Url;
 use tracing::{error, info, warn};
 
+/// The directory of the documents that don't have a path on the filesystem, like the
+/// `untitled:` documents of the editors. It's the project directory of their settings,
+/// which come from the `biome.defaultConfiguration` setting of the editor.
+const VIRTUAL_DOCUMENTS_DIRECTORY: &str = "/biome-virtual-documents";
+
 pub(crate) struct ClientInformation {
     /// The name of the client
     pub(crate) name: String,
@@ -67,6 +80,18 @@ pub(crate) struct Session {
     pub(crate) cancellation: Arc<Notify>,
 
     pub(crate) config_path: Option<PathBuf>,
+
+    /// The files of the configuration loaded by the workspace
+    configuration_files: RwLock<ConfigurationFiles>,
+
+    /// The workspace folders other than the root of the workspace
+    projects: RwLock<Vec<ProjectFolder>>,
+
+    /// Incremented each time the settings of the workspace or the extension are loaded
+    settings_version: AtomicU64,
+
+    /// Set when the client sends its first "textDocument/diagnostic" request
+    pulls_diagnostics: AtomicBool,
 }
 
 /// The parameters provided by the client in the "initialize" request
@@ -77,6 +102,35 @@ struct InitializeParams {
     root_uri: Option<Url>,
 }
 
+/// A workspace folder other than the root of the workspace. It's a project of the
+/// workspace, with its own configuration.
+#[derive(Debug, Clone)]
+struct ProjectFolder {
+    /// The directory of the folder
+    path: PathBuf,
+    /// The files of the configuration of the folder
+    configuration_files: ConfigurationFiles,
+}
+
+/// The files a configuration is loaded from. The settings are loaded again when they change.
+#[derive(Debug, Clone, Default)]
+struct ConfigurationFiles {
+    /// The path of the configuration file, if there's one
+    file_path: Option<PathBuf>,
+    /// The paths of the files in the `extends` field of the configuration
+    extended_file_paths: Vec<PathBuf>,
+}
+
+impl ConfigurationFiles {
+    fn paths(&self) -> Vec<PathBuf> {
+        self.file_path
+            .iter()
+            .chain(&self.extended_file_paths)
+            .cloned()
+            .collect()
+    }
+}
+
 #[repr(u8)]
 enum ConfigurationStatus {
     /// The configuration file was properly loaded
@@ -148,6 +202,10 @@ impl Session {
             fs: DynRef::Owned(Box::new(OsFileSystem)),
             cancellation,
             config_path: None,
+            configuration_files: RwLock::default(),
+            projects: RwLock::new(Vec::new()),
+            settings_version: AtomicU64::new(0),
+            pulls_diagnostics: AtomicBool::new(false),
         }
     }
 
@@ -161,7 +219,12 @@ impl Session {
         client_capabilities: lsp_types::ClientCapabilities,
         client_information: Option<ClientInformation>,
         root_uri: Option<Url>,
+        workspace_folders: Option<Vec<lsp_types::WorkspaceFolder>>,
     ) {
+        let workspace_folders = workspace_folders.unwrap_or_default();
+        // The first folder is the root of the workspace for the clients that don't send a root
+        let root_uri = root_uri.or_else(|| Some(workspace_folders.first()?.uri.clone()));
+
         let result = self.initialize_params.set(InitializeParams {
             client_capabilities,
             client_information,
@@ -171,6 +234,74 @@ impl Session {
         if let Err(err) = result {
             error!("Failed to initialize session: {err}");
         }
+
+        self.update_workspace_folders(workspace_folders, Vec::new());
+    }
+
+    /// Adds the `added` workspace folders as projects of the workspace, and removes the
+    /// `removed` ones. Their settings are loaded by [Session::load_workspace_settings].
+    pub(crate) fn update_workspace_folders(
+        &self,
+        added: Vec<lsp_types::WorkspaceFolder>,
+        removed: Vec<lsp_types::WorkspaceFolder>,
+    ) {
+        let base_path = self.base_path();
+        let mut projects = self.projects.write().unwrap();
+        for folder in removed {
+            let Ok(path) = folder.uri.to_file_path() else {
+                continue;
+            };
+            projects.retain(|project| project.path != path);
+            let result = self.workspace.close_project(CloseProjectParams {
+                project_directory: path,
+            });
+            if let Err(error) = result {
+                error!("Failed to close the project {}: {}", folder.uri, error);
+            }
+        }
+        for folder in added {
+            let Ok(path) = folder.uri.to_file_path() else {
+                error!(
+                    "The workspace folder {} could not be parsed as a filesystem path",
+                    folder.uri
+                );
+                continue;
+            };
+            let is_known = base_path.as_ref() == Some(&path)
+                || projects.iter().any(|project| project.path == path);
+            if !is_known {
+                projects.push(ProjectFolder {
+                    path,
+                    configuration_files: ConfigurationFiles::default(),
+                });
+            }
+        }
+    }
+
+    /// Returns the directories of the workspace folders, starting with the root of the workspace
+    pub(crate) fn workspace_folders(&self) -> Vec<PathBuf> {
+        self.base_path()
+            .into_iter()
+            .chain(
+                self.projects
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .map(|project| project.path.clone()),
+            )
+            .collect()
+    }
+
+    /// Returns the workspace folder other than the root that contains `path`, the innermost
+    /// one when the folders are nested
+    fn project_of(&self, path: &Path) -> Option<ProjectFolder> {
+        self.projects
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|project| path.starts_with(&project.path))
+            .max_by_key(|project| project.path.components().count())
+            .cloned()
     }
 
     /// Register a set of capabilities with the client
@@ -249,7 +380,11 @@ impl Session {
     }
 
     pub(crate) fn file_path(&self, url: &lsp_types::Url) -> Result<RomePath> {
-        let mut path_to_file = match url.to_file_path() {
+        if is_virtual_document(url) {
+            return Ok(RomePath::new(virtual_document_path(url)));
+        }
+
+        let path_to_file = match url.to_file_path() {
             Err(_) => {
                 // If we can't create a path, it's probably because the file doesn't exist.
                 // It can be a newly created file that it's not on disk
@@ -258,26 +393,53 @@ impl Session {
             Ok(path) => path,
         };
 
-        let relative_path = self.initialize_params.get().and_then(|initialize_params| {
-            let root_uri = initialize_params.root_uri.as_ref()?;
-            let root_path = root_uri.to_file_path().ok()?;
-            path_to_file.strip_prefix(&root_path).ok()
-        });
+        Ok(self.rome_path(path_to_file))
+    }
 
-        if let Some(relative_path) = relative_path {
-            path_to_file = relative_path.into();
+    /// Returns the path of a file in the workspace. The paths of the files of the root folder
+    /// are relative to it, while the paths of the files of the other folders are absolute, so
+    /// the workspace can find the project of the file.
+    fn rome_path(&self, path: PathBuf) -> RomePath {
+        if self.project_of(&path).is_some() {
+            return RomePath::new(path);
         }
 
-        Ok(RomePath::new(path_to_file))
+        let relative_path = self
+            .base_path()
+            .and_then(|base_path| path.strip_prefix(base_path).ok().map(Path::to_path_buf));
+        RomePath::new(relative_path.unwrap_or(path))
     }
 
     /// Computes diagnostics for the file matching the provided url and publishes
     /// them to the client. Called from [`handlers::text_document`] when a file's
     /// contents changes.
+    ///
+    /// Nothing is published when the client pulls the diagnostics instead.
     #[tracing::instrument(level = "debug", skip_all, fields(url = display(&url), diagnostic_count), err)]
     pub(crate) async fn update_diagnostics(&self, url: lsp_types::Url) -> Result<()> {
-        let rome_path = self.file_path(&url)?;
+        if self.pulls_diagnostics() {
+            return Ok(());
+        }
+
         let doc = self.document(&url)?;
+        let diagnostics = self.compute_diagnostics(&url, &doc)?;
+
+        tracing::Span::current().record("diagnostic_count", diagnostics.len());
+
+        self.client
+            .publish_diagnostics(url, diagnostics, Some(doc.version))
+            .await;
+
+        Ok(())
+    }
+
+    /// Computes the diagnostics of an open document
+    pub(crate) fn compute_diagnostics(
+        &self,
+        url: &lsp_types::Url,
+        doc: &Document,
+    ) -> Result<Vec<lsp_types::Diagnostic>> {
+        let rome_path = self.file_path(url)?;
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             feature: FeaturesBuilder::new()
                 .with_linter()
@@ -307,18 +469,30 @@ impl Session {
                 path: rome_path,
                 categories,
                 max_diagnostics: u64::MAX,
+                only: Vec::new(),
+                rule_timings: false,
             })?;
 
             tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
 
+            let mut related_urls = FxHashMap::default();
             let result = result
                 .diagnostics
                 .into_iter()
                 .filter_map(|d| {
+                    let mut diagnostic = Error::from(d);
+                    for path in related_file_paths(diagnostic.as_ref()) {
+                        if let Some((related_url, content)) = self.related_file(&path) {
+                            diagnostic = diagnostic.with_related_source_code(path.clone(), content);
+                            related_urls.insert(path, related_url);
+                        }
+                    }
+
                     match utils::diagnostic_to_lsp(
-                        d,
-                        &url,
+                        diagnostic,
+                        url,
                         &doc.line_index,
+                        &related_urls,
                         self.position_encoding(),
                     ) {
                         Ok(diag) => Some(diag),
@@ -335,17 +509,85 @@ impl Session {
             result
         };
 
-        tracing::Span::current().record("diagnostic_count", diagnostics.len());
+        Ok(diagnostics)
+    }
 
-        self.client
-            .publish_diagnostics(url, diagnostics, Some(doc.version))
-            .await;
+    /// Returns the URL and the content of the file at `path`, that a diagnostic of
+    /// another file points to. The path is relative to the base path of the session
+    /// when it isn't absolute.
+    fn related_file(&self, path: &str) -> Option<(lsp_types::Url, String)> {
+        let path = match self.base_path() {
+            Some(base_path) => base_path.join(path),
+            None => PathBuf::from(path),
+        };
+        let url = lsp_types::Url::from_file_path(&path).ok()?;
+
+        // The open documents may have changes that aren't saved yet
+        let content = self
+            .workspace
+            .get_file_content(GetFileContentParams {
+                path: self.file_path(&url).ok()?,
+            })
+            .or_else(|_| {
+                let mut content = String::new();
+                self.fs
+                    .open_with_options(&path, OpenOptions::default().read(true))?
+                    .read_to_string(&mut content)?;
+                Ok::<_, std::io::Error>(content)
+            })
+            .ok()?;
+
+        Some((url, content))
+    }
 
-        Ok(())
+    /// True if the client pulls the diagnostics with "textDocument/diagnostic" requests
+    pub(crate) fn pulls_diagnostics(&self) -> bool {
+        self.pulls_diagnostics.load(Ordering::Relaxed)
+    }
+
+    /// Stops publishing the diagnostics, since the client pulls them. The diagnostics
+    /// that were already published are cleared, otherwise the client would show them twice.
+    pub(crate) async fn start_pulling_diagnostics(&self) {
+        if self.pulls_diagnostics.swap(true, Ordering::Relaxed) {
+            return;
+        }
+
+        let documents: Vec<_> = self
+            .documents
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(url, doc)| (url.clone(), doc.version))
+            .collect();
+        for (url, version) in documents {
+            self.client
+                .publish_diagnostics(url, vec![], Some(version))
+                .await;
+        }
+    }
+
+    /// Returns the identifier of the diagnostics of a document. It changes when the
+    /// document or the settings change, so the client knows when the diagnostics that
+    /// it pulled before are still up to date.
+    pub(crate) fn diagnostics_result_id(&self, doc: &Document) -> String {
+        let settings_version = self.settings_version.load(Ordering::Relaxed);
+        format!("{}-{settings_version}", doc.version)
     }
 
     /// Updates diagnostics for every [`Document`] in this [`Session`]
     pub(crate) async fn update_all_diagnostics(&self) {
+        if self.pulls_diagnostics() {
+            // The client pulls the diagnostics of the documents it shows again
+            if let Err(err) = self
+                .client
+                .send_request::<WorkspaceDiagnosticRefresh>(())
+                .await
+            {
+                error!("Failed to refresh the diagnostics: {err}");
+            }
+            return;
+        }
+
         let mut futures: FuturesUnordered<_> = self
             .documents
             .read()
@@ -361,6 +603,60 @@ impl Session {
         }
     }
 
+    /// Indexes the symbols exported by the files of the workspace, so they can
+    /// be searched by "workspace/symbol" requests. `indexed_files` is incremented
+    /// after each file, so the progress can be reported while the files are indexed.
+    #[tracing::instrument(level = "debug", skip(self, indexed_files))]
+    pub(crate) fn index_workspace_symbols(&self, indexed_files: &AtomicUsize) {
+        let folders = self.workspace_folders();
+        if folders.is_empty() {
+            return;
+        }
+
+        let (interner, _) = PathInterner::new();
+        let ctx = SymbolIndexContext {
+            session: self,
+            interner,
+            indexed_files,
+        };
+        self.fs.traversal(Box::new(|scope| {
+            for folder in folders {
+                scope.spawn(&ctx, folder);
+            }
+        }));
+    }
+
+    /// Updates the symbols of the file at `path` in the symbol index, using its content on disk
+    pub(crate) fn index_file_symbols(&self, path: &Path) {
+        if !Language::from_path(path).is_javascript_like() {
+            return;
+        }
+
+        let content = self
+            .fs
+            .open_with_options(path, OpenOptions::default().read(true))
+            .and_then(|mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content)?;
+                Ok(content)
+            })
+            .ok();
+
+        let rome_path = self.rome_path(path.to_path_buf());
+
+        let result = self.workspace.index_file(IndexFileParams {
+            path: rome_path,
+            content,
+        });
+        if let Err(error) = result {
+            error!(
+                "Failed to index the symbols of {}: {}",
+                path.display(),
+                error
+            );
+        }
+    }
+
     /// True if the client supports dynamic registration of "workspace/didChangeConfiguration" requests
     pub(crate) fn can_register_did_change_configuration(&self) -> bool {
         self.initialize_params
@@ -371,6 +667,63 @@ impl Session {
             == Some(true)
     }
 
+    /// True if the client can show the progress of the work started by the server
+    pub(crate) fn supports_work_done_progress(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.window.as_ref())
+            .and_then(|c| c.work_done_progress)
+            == Some(true)
+    }
+
+    /// True if the client supports the "workspace/inlayHint/refresh" request
+    pub(crate) fn can_refresh_inlay_hints(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.workspace.as_ref())
+            .and_then(|c| c.inlay_hint.as_ref())
+            .and_then(|c| c.refresh_support)
+            == Some(true)
+    }
+
+    /// True if the client can create a file with the edits of a workspace
+    pub(crate) fn can_create_files(&self) -> bool {
+        let workspace_edit = self
+            .initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.workspace.as_ref())
+            .and_then(|c| c.workspace_edit.as_ref());
+        let Some(workspace_edit) = workspace_edit else {
+            return false;
+        };
+
+        workspace_edit.document_changes == Some(true)
+            && workspace_edit
+                .resource_operations
+                .as_ref()
+                .is_some_and(|operations| operations.contains(&ResourceOperationKind::Create))
+    }
+
+    /// True if the client can display the symbols of a document as a tree
+    pub(crate) fn supports_hierarchical_document_symbols(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .and_then(|c| c.document_symbol.as_ref())
+            .and_then(|c| c.hierarchical_document_symbol_support)
+            == Some(true)
+    }
+
+    /// The maximum number of folding ranges that the client wants to receive
+    pub(crate) fn folding_range_limit(&self) -> Option<usize> {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .and_then(|c| c.folding_range.as_ref())
+            .and_then(|c| c.range_limit)
+            .map(|limit| limit as usize)
+    }
+
     /// Returns the base path of the workspace on the filesystem if it has one
     pub(crate) fn base_path(&self) -> Option<PathBuf> {
         let initialize_params = self.initialize_params.get()?;
@@ -405,19 +758,15 @@ impl Session {
             }
         };
 
-        let status = match load_config(&self.fs, base_path) {
-            Ok(Some(payload)) => {
-                let (configuration, diagnostics) = payload.deserialized.consume();
-                let configuration = configuration.unwrap_or_default();
-                if !diagnostics.is_empty() {
-                    warn!("The deserialization of the configuration resulted in errors. Biome will use its defaults where possible.");
-                }
-
+        let (result, configuration_files) = self.load_configuration(base_path);
+        let status = match result {
+            Ok(Some(configuration)) => {
                 info!("Loaded workspace settings: {configuration:#?}");
 
-                let result = self
-                    .workspace
-                    .update_settings(UpdateSettingsParams { configuration });
+                let result = self.workspace.update_settings(UpdateSettingsParams {
+                    configuration,
+                    project_directory: None,
+                });
 
                 if let Err(error) = result {
                     error!("Failed to set workspace settings: {}", error);
@@ -427,7 +776,14 @@ impl Session {
                 }
             }
             Ok(None) => {
-                // Ignore, load_config already logs an error in this case
+                // The settings of a deleted configuration file don't apply anymore
+                let result = self.workspace.update_settings(UpdateSettingsParams {
+                    configuration: Configuration::default(),
+                    project_directory: None,
+                });
+                if let Err(error) = result {
+                    error!("Failed to reset workspace settings: {}", error);
+                }
                 ConfigurationStatus::Missing
             }
             Err(err) => {
@@ -436,9 +792,135 @@ impl Session {
             }
         };
 
+        *self.configuration_files.write().unwrap() = configuration_files;
+
+        let mut projects = self.projects.read().unwrap().clone();
+        for project in &mut projects {
+            project.configuration_files = self.load_project_settings(&project.path);
+        }
+        // The folders may have changed while the settings were loaded
+        for project in self.projects.write().unwrap().iter_mut() {
+            if let Some(loaded) = projects.iter().find(|loaded| loaded.path == project.path) {
+                project.configuration_files = loaded.configuration_files.clone();
+            }
+        }
+
+        self.settings_version.fetch_add(1, Ordering::Relaxed);
         self.set_configuration_status(status);
     }
 
+    /// Loads the configuration of the workspace folder at `path` into the settings of its
+    /// project, and returns the files it was loaded from. The folder uses the default
+    /// settings when no configuration file is found, rather than the settings of the root.
+    fn load_project_settings(&self, path: &Path) -> ConfigurationFiles {
+        let (result, configuration_files) =
+            self.load_configuration(ConfigurationBasePath::Lsp(path.to_path_buf()));
+        let configuration = match result {
+            Ok(configuration) => configuration.unwrap_or_default(),
+            Err(err) => {
+                error!(
+                    "Couldn't load the settings of {}, reason:\n {}",
+                    path.display(),
+                    err
+                );
+                Configuration::default()
+            }
+        };
+
+        info!(
+            "Loaded the settings of {}: {configuration:#?}",
+            path.display()
+        );
+        let result = self.workspace.update_settings(UpdateSettingsParams {
+            configuration,
+            project_directory: Some(path.to_path_buf()),
+        });
+        if let Err(error) = result {
+            error!(
+                "Failed to set the settings of {}: {}",
+                path.display(),
+                error
+            );
+        }
+
+        configuration_files
+    }
+
+    /// Loads the configuration found from `base_path` and applies the configurations it
+    /// extends. The files of the configuration are returned even when it can't be loaded, so
+    /// their changes are still watched.
+    fn load_configuration(
+        &self,
+        base_path: ConfigurationBasePath,
+    ) -> (
+        Result<Option<Configuration>, WorkspaceError>,
+        ConfigurationFiles,
+    ) {
+        let payload = match load_config(&self.fs, base_path) {
+            Ok(Some(payload)) => payload,
+            // Ignore, load_config already logs an error in this case
+            Ok(None) => return (Ok(None), ConfigurationFiles::default()),
+            Err(err) => return (Err(err), ConfigurationFiles::default()),
+        };
+
+        let loaded_configuration = LoadedConfiguration::from(Some(payload));
+        let configuration_files = ConfigurationFiles {
+            file_path: loaded_configuration.file_path.clone(),
+            extended_file_paths: loaded_configuration.extended_file_paths(&*self.fs),
+        };
+        let result = loaded_configuration
+            .apply_extends(&*self.fs, false)
+            .map(|loaded_configuration| loaded_configuration.apply_editorconfig(&*self.fs))
+            .map(|loaded_configuration| {
+                if !loaded_configuration.diagnostics.is_empty() {
+                    warn!("The deserialization of the configuration resulted in errors. Biome will use its defaults where possible.");
+                }
+                Some(loaded_configuration.configuration)
+            });
+
+        (result, configuration_files)
+    }
+
+    /// Returns the paths of the configuration files loaded by the workspace and its folders,
+    /// including the files they extend
+    pub(crate) fn configuration_files(&self) -> Vec<PathBuf> {
+        let mut files = self.configuration_files.read().unwrap().paths();
+        for project in self.projects.read().unwrap().iter() {
+            files.extend(project.configuration_files.paths());
+        }
+        files
+    }
+
+    /// Returns the path of the configuration file of the workspace folder that contains the
+    /// document. When the folder doesn't have one, this is the path where it should be created.
+    pub(crate) fn configuration_file_path(&self, url: &lsp_types::Url) -> Option<PathBuf> {
+        // The configuration of the virtual documents comes from the editor
+        if is_virtual_document(url) {
+            return None;
+        }
+
+        let project = url
+            .to_file_path()
+            .ok()
+            .and_then(|path| self.project_of(&path));
+        if let Some(project) = project {
+            let path = project.configuration_files.file_path;
+            return Some(path.unwrap_or_else(|| project.path.join(self.fs.config_name())));
+        }
+
+        self.root_configuration_file_path()
+    }
+
+    /// Returns the path of the configuration file of the root folder of the workspace. When
+    /// the folder doesn't have one, this is the path where it should be created.
+    pub(crate) fn root_configuration_file_path(&self) -> Option<PathBuf> {
+        if let Some(path) = self.configuration_files.read().unwrap().file_path.as_ref() {
+            return Some(path.clone());
+        }
+        let directory = self.config_path.clone().or_else(|| self.base_path())?;
+        Some(directory.join(self.fs.config_name()))
+    }
+
     /// Requests "workspace/configuration" from client and updates Session config
     #[tracing::instrument(level = "debug", skip(self))]
     pub(crate) async fn load_extension_settings(&self) {
@@ -447,25 +929,53 @@ impl Session {
             section: Some(String::from(CONFIGURATION_SECTION)),
         };
 
-        let client_configurations = match self.client.configuration(vec![item]).await {
-            Ok(client_configurations) => client_configurations,
+        match self.client.configuration(vec![item]).await {
+            Ok(client_configurations) => {
+                let client_configuration = client_configurations.into_iter().next();
+
+                if let Some(client_configuration) = client_configuration {
+                    info!("Loaded client configuration: {client_configuration:#?}");
+
+                    let mut config = self.extension_settings.write().unwrap();
+                    if let Err(err) = config.set_workspace_settings(client_configuration) {
+                        error!("Couldn't set client configuration: {}", err);
+                    }
+                    self.settings_version.fetch_add(1, Ordering::Relaxed);
+                } else {
+                    info!("Client did not return any configuration");
+                }
+            }
             Err(err) => {
                 error!("Couldn't read configuration from the client: {err}");
-                return;
             }
-        };
+        }
 
-        let client_configuration = client_configurations.into_iter().next();
+        self.load_virtual_documents_settings();
+    }
 
-        if let Some(client_configuration) = client_configuration {
-            info!("Loaded client configuration: {client_configuration:#?}");
+    /// Sets the settings of the documents that don't have a path on the filesystem from the
+    /// `biome.defaultConfiguration` setting of the editor. Like a configuration file, the
+    /// setting is applied on top of the default configuration.
+    fn load_virtual_documents_settings(&self) {
+        let mut configuration = Configuration::default();
+        if let Some(default_configuration) = self
+            .extension_settings
+            .read()
+            .unwrap()
+            .default_configuration()
+        {
+            configuration.merge_with(default_configuration.clone());
+        }
 
-            let mut config = self.extension_settings.write().unwrap();
-            if let Err(err) = config.set_workspace_settings(client_configuration) {
-                error!("Couldn't set client configuration: {}", err);
-            }
-        } else {
-            info!("Client did not return any configuration");
+        let result = self.workspace.update_settings(UpdateSettingsParams {
+            configuration,
+            project_directory: Some(PathBuf::from(VIRTUAL_DOCUMENTS_DIRECTORY)),
+        });
+        if let Err(error) = result {
+            error!(
+                "Failed to set the settings of the virtual documents: {}",
+                error
+            );
         }
     }
 
@@ -521,3 +1031,53 @@ impl Session {
             .unwrap_or(PositionEncoding::Wide(WideEncoding::Utf16))
     }
 }
+
+/// Traversal context used to index the symbols of the files in the workspace
+struct SymbolIndexContext<'a> {
+    session: &'a Session,
+    interner: PathInterner,
+    indexed_files: &'a AtomicUsize,
+}
+
+impl TraversalContext for SymbolIndexContext<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, error: biome_diagnostics::Error) {
+        warn!("Failed to read a file while indexing the workspace: {error:?}");
+    }
+
+    fn can_handle(&self, path: &RomePath) -> bool {
+        path.is_dir() || Language::from_path(path).is_javascript_like()
+    }
+
+    fn handle_file(&self, path: &Path) {
+        self.session.index_file_symbols(path);
+        self.indexed_files.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Returns true if the document doesn't have a path on the filesystem, like the `untitled:`
+/// documents or the cells of the notebooks
+fn is_virtual_document(url: &lsp_types::Url) -> bool {
+    url.scheme() != "file"
+}
+
+/// Returns the path of a virtual document in the workspace. The scheme is the first component
+/// of the path, and the fragment is kept in the file name, so documents that only differ by
+/// them, like the cells of a notebook, have different paths.
+fn virtual_document_path(url: &lsp_types::Url) -> PathBuf {
+    let mut path = Path::new(VIRTUAL_DOCUMENTS_DIRECTORY).join(url.scheme());
+    path.push(url.path().trim_start_matches('/'));
+
+    match url.fragment() {
+        Some(fragment) => {
+            let mut path = path.into_os_string();
+            path.push("#");
+            path.push(fragment);
+            PathBuf::from(path)
+        }
+        None => path,
+    }
+}
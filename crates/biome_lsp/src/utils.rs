@@ -4,14 +4,16 @@ use anyhow::{ensure, Context, Result};
 use biome_analyze::ActionCategory;
 use biome_console::fmt::Termcolor;
 use biome_console::fmt::{self, Formatter};
-use biome_console::MarkupBuf;
+use biome_console::{markup, MarkupBuf};
 use biome_diagnostics::termcolor::NoColor;
 use biome_diagnostics::{
-    Applicability, {Diagnostic, DiagnosticTags, Location, PrintDescription, Severity, Visit},
+    Applicability,
+    {DiagnosticTags, Error, Location, LogCategory, PrintDescription, Resource, Severity, Visit},
 };
 use biome_rowan::TextSize;
 use biome_service::workspace::CodeAction;
 use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use rustc_hash::FxHashMap;
 use std::any::Any;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
@@ -169,11 +171,14 @@ pub(crate) fn code_fix_to_lsp(
 /// Convert an [biome_diagnostics::Diagnostic] to a [lsp::Diagnostic], using the span
 /// of the diagnostic's primary label as the diagnostic range.
 /// Requires a [LineIndex] to convert a byte offset range to the line/col range
-/// expected by LSP.
-pub(crate) fn diagnostic_to_lsp<D: Diagnostic>(
-    diagnostic: D,
+/// expected by LSP. The advices pointing to other files are converted using
+/// `related_urls`, the URLs of these files by path, and the source code the
+/// diagnostic provides for them.
+pub(crate) fn diagnostic_to_lsp(
+    diagnostic: Error,
     url: &lsp::Url,
     line_index: &LineIndex,
+    related_urls: &FxHashMap<String, lsp::Url>,
     position_encoding: PositionEncoding,
 ) -> Result<lsp::Diagnostic> {
     let location = diagnostic.location();
@@ -208,7 +213,10 @@ pub(crate) fn diagnostic_to_lsp<D: Diagnostic>(
     let mut visitor = RelatedInformationVisitor {
         url,
         line_index,
+        resource: location.resource,
+        related_urls,
         position_encoding,
+        last_message: None,
         related_information: &mut related_information,
     };
 
@@ -249,30 +257,58 @@ pub(crate) fn diagnostic_to_lsp<D: Diagnostic>(
 struct RelatedInformationVisitor<'a> {
     url: &'a lsp::Url,
     line_index: &'a LineIndex,
+    /// The file of the diagnostic
+    resource: Option<Resource<&'a str>>,
+    related_urls: &'a FxHashMap<String, lsp::Url>,
     position_encoding: PositionEncoding,
+    /// The message of the last log advice, which describes the next code frame
+    last_message: Option<String>,
     related_information: &'a mut Option<Vec<lsp::DiagnosticRelatedInformation>>,
 }
 
 impl Visit for RelatedInformationVisitor<'_> {
+    fn record_log(&mut self, _category: LogCategory, text: &dyn fmt::Display) -> io::Result<()> {
+        self.last_message = Some(print_markup(&markup!({ text }).to_owned()));
+        Ok(())
+    }
+
     fn record_frame(&mut self, location: Location<'_>) -> io::Result<()> {
+        let last_message = self.last_message.take();
         let span = match location.span {
             Some(span) => span,
             None => return Ok(()),
         };
 
-        let range = match to_proto::range(self.line_index, span, self.position_encoding) {
-            Ok(range) => range,
-            Err(_) => return Ok(()),
+        let (uri, range, message) = match location.resource {
+            Some(Resource::File(path)) if location.resource != self.resource => {
+                // The clients don't show the code of the related information,
+                // so the message describing the other file is kept
+                let (Some(uri), Some(source_code)) =
+                    (self.related_urls.get(path), location.source_code)
+                else {
+                    return Ok(());
+                };
+                let line_index = LineIndex::new(source_code.text);
+                let range = match to_proto::range(&line_index, span, self.position_encoding) {
+                    Ok(range) => range,
+                    Err(_) => return Ok(()),
+                };
+                (uri.clone(), range, last_message.unwrap_or_default())
+            }
+            _ => {
+                let range = match to_proto::range(self.line_index, span, self.position_encoding) {
+                    Ok(range) => range,
+                    Err(_) => return Ok(()),
+                };
+                (self.url.clone(), range, String::new())
+            }
         };
 
         let related_information = self.related_information.get_or_insert_with(Vec::new);
 
         related_information.push(lsp::DiagnosticRelatedInformation {
-            location: lsp::Location {
-                uri: self.url.clone(),
-                range,
-            },
-            message: String::new(),
+            location: lsp::Location { uri, range },
+            message,
         });
 
         Ok(())
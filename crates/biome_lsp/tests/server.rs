@@ -5,7 +5,7 @@ use anyhow::Result;
 use biome_fs::RomePath;
 use biome_lsp::LSPServer;
 use biome_lsp::ServerFactory;
-use biome_lsp::WorkspaceSettings;
+use biome_lsp::{InlayHintsSettings, WorkspaceSettings};
 use biome_service::workspace::GetSyntaxTreeResult;
 use biome_service::workspace::{GetFileContentParams, GetSyntaxTreeParams};
 use futures::channel::mpsc::{channel, Sender};
@@ -15,10 +15,11 @@ use futures::Stream;
 use futures::StreamExt;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::{from_value, to_value};
+use serde_json::{from_value, to_value, Value};
 use std::any::type_name;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::fs;
 use std::slice;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -31,15 +32,24 @@ use tower_lsp::lsp_types::DidChangeTextDocumentParams;
 use tower_lsp::lsp_types::DidCloseTextDocumentParams;
 use tower_lsp::lsp_types::DidOpenTextDocumentParams;
 use tower_lsp::lsp_types::DocumentFormattingParams;
+use tower_lsp::lsp_types::DocumentSymbolParams;
+use tower_lsp::lsp_types::DocumentSymbolResponse;
 use tower_lsp::lsp_types::FormattingOptions;
+use tower_lsp::lsp_types::GotoDefinitionParams;
+use tower_lsp::lsp_types::GotoDefinitionResponse;
 use tower_lsp::lsp_types::InitializeResult;
 use tower_lsp::lsp_types::InitializedParams;
+use tower_lsp::lsp_types::Location;
 use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::PrepareRenameResponse;
 use tower_lsp::lsp_types::PublishDiagnosticsParams;
 use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::ReferenceContext;
+use tower_lsp::lsp_types::ReferenceParams;
 use tower_lsp::lsp_types::TextDocumentContentChangeEvent;
 use tower_lsp::lsp_types::TextDocumentIdentifier;
 use tower_lsp::lsp_types::TextDocumentItem;
+use tower_lsp::lsp_types::TextDocumentPositionParams;
 use tower_lsp::lsp_types::TextEdit;
 use tower_lsp::lsp_types::VersionedTextDocumentIdentifier;
 use tower_lsp::lsp_types::WorkDoneProgressParams;
@@ -139,9 +149,34 @@ impl Server {
     }
 
     /// Basic implementation of the `initialize` request for tests
+    async fn initialize(&mut self) -> Result<()> {
+        self.initialize_workspace(Some(url!("")), None).await
+    }
+
+    /// Implementation of the `initialize` request for the tests that need a workspace on disk
+    async fn initialize_workspace(
+        &mut self,
+        root_uri: Option<Url>,
+        workspace_folders: Option<Vec<lsp::WorkspaceFolder>>,
+    ) -> Result<()> {
+        self.initialize_with_capabilities(
+            root_uri,
+            workspace_folders,
+            ClientCapabilities::default(),
+        )
+        .await
+    }
+
+    /// Implementation of the `initialize` request for the tests that need some capabilities
+    /// of the client
     // The `root_path` field is deprecated, but we still need to specify it
     #[allow(deprecated)]
-    async fn initialize(&mut self) -> Result<()> {
+    async fn initialize_with_capabilities(
+        &mut self,
+        root_uri: Option<Url>,
+        workspace_folders: Option<Vec<lsp::WorkspaceFolder>>,
+        capabilities: ClientCapabilities,
+    ) -> Result<()> {
         let _res: InitializeResult = self
             .request(
                 "initialize",
@@ -149,11 +184,11 @@ impl Server {
                 InitializeParams {
                     process_id: None,
                     root_path: None,
-                    root_uri: Some(url!("")),
+                    root_uri,
                     initialization_options: None,
-                    capabilities: ClientCapabilities::default(),
+                    capabilities,
                     trace: None,
-                    workspace_folders: None,
+                    workspace_folders,
                     client_info: None,
                     locale: None,
                 },
@@ -267,9 +302,11 @@ impl Server {
 /// Number of notifications buffered by the server-to-client channel before it starts blocking the current task
 const CHANNEL_BUFFER_SIZE: usize = 8;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq)]
 enum ServerNotification {
     PublishDiagnostics(PublishDiagnosticsParams),
+    ApplyEdit(lsp::ApplyWorkspaceEditParams),
+    Progress(lsp::ProgressParams),
 }
 
 /// Basic handler for requests and notifications coming from the server for tests
@@ -295,6 +332,15 @@ where
             }
         }
 
+        if req.method() == "$/progress" {
+            let params = req.params().expect("invalid request");
+            let progress = from_value(params.clone()).expect("invalid params");
+            match notify.send(ServerNotification::Progress(progress)).await {
+                Ok(_) => continue,
+                Err(_) => break,
+            }
+        }
+
         let id = match req.id() {
             Some(id) => id,
             None => continue,
@@ -303,6 +349,14 @@ where
         let res = match req.method() {
             "workspace/configuration" => {
                 let settings = WorkspaceSettings {
+                    rename: Some(true),
+                    inlay_hints: InlayHintsSettings {
+                        parameter_names: Some(true),
+                        return_types: None,
+                    },
+                    default_configuration: Some(serde_json::from_str(
+                        r#"{ "formatter": { "indentStyle": "space", "indentWidth": 2 } }"#,
+                    )?),
                     ..WorkspaceSettings::default()
                 };
 
@@ -311,6 +365,21 @@ where
 
                 Response::from_ok(id.clone(), result)
             }
+            "workspace/applyEdit" => {
+                let params = req.params().expect("invalid request");
+                let edit = from_value(params.clone()).expect("invalid params");
+                notify.send(ServerNotification::ApplyEdit(edit)).await.ok();
+
+                let result = to_value(lsp::ApplyWorkspaceEditResponse {
+                    applied: true,
+                    failure_reason: None,
+                    failed_change: None,
+                })
+                .context("failed to serialize the response")?;
+
+                Response::from_ok(id.clone(), result)
+            }
+            "window/workDoneProgress/create" => Response::from_ok(id.clone(), Value::Null),
             _ => Response::from_error(id.clone(), jsonrpc::Error::method_not_found()),
         };
 
@@ -767,7 +836,51 @@ async fn pull_quick_fixes() -> Result<()> {
         data: None,
     });
 
-    assert_eq!(res, vec![expected_code_action, expected_suppression_action]);
+    let mut file_suppression_changes = HashMap::default();
+    file_suppression_changes.insert(
+        url!("document.js"),
+        vec![lsp::TextEdit {
+            range: lsp::Range {
+                start: lsp::Position {
+                    line: 0,
+                    character: 0,
+                },
+                end: lsp::Position {
+                    line: 0,
+                    character: 0,
+                },
+            },
+            new_text: String::from(
+                "// biome-ignore-all lint/suspicious/noCompareNegZero: <explanation>\n",
+            ),
+        }],
+    );
+
+    let expected_file_suppression_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Suppress rule lint/suspicious/noCompareNegZero for the whole file"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.suppressRule.biome.suspicious.noCompareNegZero",
+        )),
+        diagnostics: Some(vec![fixable_diagnostic(0)?]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(file_suppression_changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(
+        res,
+        vec![
+            expected_code_action,
+            expected_suppression_action,
+            expected_file_suppression_action
+        ]
+    );
 
     server.close_document().await?;
 
@@ -1085,7 +1198,7 @@ async fn pull_quick_fixes_include_unsafe() -> Result<()> {
         kind: Some(lsp::CodeActionKind::new(
             "quickfix.suppressRule.biome.suspicious.noDoubleEquals",
         )),
-        diagnostics: Some(vec![unsafe_fixable]),
+        diagnostics: Some(vec![unsafe_fixable.clone()]),
         edit: Some(lsp::WorkspaceEdit {
             changes: Some(suppression_changes),
             document_changes: None,
@@ -1097,7 +1210,51 @@ async fn pull_quick_fixes_include_unsafe() -> Result<()> {
         data: None,
     });
 
-    assert_eq!(res, vec![expected_code_action, expected_suppression_action]);
+    let mut file_suppression_changes = HashMap::default();
+    file_suppression_changes.insert(
+        url!("document.js"),
+        vec![lsp::TextEdit {
+            range: lsp::Range {
+                start: lsp::Position {
+                    line: 0,
+                    character: 0,
+                },
+                end: lsp::Position {
+                    line: 0,
+                    character: 0,
+                },
+            },
+            new_text: String::from(
+                "// biome-ignore-all lint/suspicious/noDoubleEquals: <explanation>\n",
+            ),
+        }],
+    );
+
+    let expected_file_suppression_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Suppress rule lint/suspicious/noDoubleEquals for the whole file"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.suppressRule.biome.suspicious.noDoubleEquals",
+        )),
+        diagnostics: Some(vec![unsafe_fixable]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(file_suppression_changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(
+        res,
+        vec![
+            expected_code_action,
+            expected_suppression_action,
+            expected_file_suppression_action
+        ]
+    );
 
     server.close_document().await?;
 
@@ -1654,6 +1811,195 @@ async fn pull_fix_all() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn fix_all_on_save() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document(
+            "import { b } from 'b';\nimport { a } from 'a';\nif(a === -0 || b === -0) {}\n",
+        )
+        .await?;
+
+    // The request sent by the editors on save, without any diagnostic or range
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "fix_all_on_save",
+            lsp::CodeActionParams {
+                text_document: lsp::TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: lsp::Range::default(),
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![],
+                    only: Some(vec![lsp::CodeActionKind::new("source.fixAll.biome")]),
+                    ..Default::default()
+                },
+                work_done_progress_params: lsp::WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let [lsp::CodeActionOrCommand::CodeAction(action)] = res.as_slice() else {
+        panic!("expected a single code action, got {res:?}");
+    };
+    assert_eq!(
+        action.kind,
+        Some(lsp::CodeActionKind::new("source.fixAll.biome"))
+    );
+
+    // Both fixes are applied in a single edit, and the imports are left to the
+    // organize imports action
+    let edits = action
+        .edit
+        .as_ref()
+        .and_then(|edit| edit.changes.as_ref())
+        .and_then(|changes| changes.get(&url!("document.js")))
+        .context("the action doesn't edit the document")?;
+    assert_eq!(
+        edits
+            .iter()
+            .map(|edit| edit.new_text.as_str())
+            .collect::<Vec<_>>(),
+        vec!["import { b } from \"b\";\nimport { a } from \"a\";\nif (a === 0 || b === 0) {\n}\n"]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn disable_rule_in_configuration() -> Result<()> {
+    let config_dir = std::env::temp_dir().join("biome_lsp_disable_rule_in_configuration");
+    fs::create_dir_all(&config_dir)?;
+    let config_path = config_dir.join("biome.json");
+    fs::write(
+        &config_path,
+        "{\n  \"$schema\": \"./node_modules/@biomejs/biome/configuration_schema.json\",\n  \"linter\": {\n    \"enabled\": true\n  }\n}\n",
+    )?;
+
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(Some(config_dir.clone())).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("debugger;\n").await?;
+
+    let diagnostic = lsp::Diagnostic {
+        range: lsp::Range {
+            start: lsp::Position::new(0, 0),
+            end: lsp::Position::new(0, 9),
+        },
+        severity: Some(lsp::DiagnosticSeverity::ERROR),
+        code: Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDebugger",
+        ))),
+        code_description: None,
+        source: Some(String::from("biome")),
+        message: String::from("This is an unexpected use of the debugger statement."),
+        related_information: None,
+        tags: None,
+        data: None,
+    };
+
+    let res: lsp::CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "disable_rule_in_configuration",
+            lsp::CodeActionParams {
+                text_document: lsp::TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: lsp::Range {
+                    start: lsp::Position::new(0, 0),
+                    end: lsp::Position::new(0, 0),
+                },
+                context: lsp::CodeActionContext {
+                    diagnostics: vec![diagnostic.clone()],
+                    only: Some(vec![lsp::CodeActionKind::new("quickfix.disableRule")]),
+                    ..Default::default()
+                },
+                work_done_progress_params: lsp::WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    // Only the linter section is edited, the rest of the file is kept
+    let mut changes = HashMap::default();
+    changes.insert(
+        lsp::Url::from_file_path(&config_path).unwrap(),
+        vec![lsp::TextEdit {
+            range: lsp::Range {
+                start: lsp::Position::new(3, 19),
+                end: lsp::Position::new(3, 19),
+            },
+            new_text: String::from(
+                ",\n    \"rules\": {\n      \"suspicious\": {\n        \"noDebugger\": \"off\"\n      }\n    }",
+            ),
+        }],
+    );
+
+    let expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: String::from("Disable suspicious/noDebugger in configuration"),
+        kind: Some(lsp::CodeActionKind::new(
+            "quickfix.disableRule.biome.suspicious.noDebugger",
+        )),
+        diagnostics: Some(vec![diagnostic]),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        command: None,
+        is_preferred: None,
+        disabled: None,
+        data: None,
+    });
+
+    assert_eq!(res, vec![expected_action]);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    fs::remove_dir_all(&config_dir)?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn change_document_remove_line() -> Result<()> {
     let factory = ServerFactory::default();
@@ -1771,7 +2117,7 @@ async fn format_with_syntax_errors() -> Result<()> {
 }
 
 #[tokio::test]
-async fn server_shutdown() -> Result<()> {
+async fn prepare_rename() -> Result<()> {
     let factory = ServerFactory::default();
     let (service, client) = factory.create(None).into_inner();
     let (stream, sink) = client.split();
@@ -1783,14 +2129,1262 @@ async fn server_shutdown() -> Result<()> {
     server.initialize().await?;
     server.initialized().await?;
 
-    let cancellation = factory.cancellation();
-    let cancellation = cancellation.notified();
-
-    server.biome_shutdown().await?;
+    server
+        .open_document("let value = 1;\nvalue + console.log(value);")
+        .await?;
 
-    cancellation.await;
+    let res: Option<PrepareRenameResponse> = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename",
+            TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                position: Position {
+                    line: 1,
+                    character: 2,
+                },
+            },
+        )
+        .await?
+        .context("prepareRename returned None")?;
 
-    reader.abort();
+    assert_eq!(
+        res,
+        Some(PrepareRenameResponse::RangeWithPlaceholder {
+            range: Range {
+                start: Position {
+                    line: 1,
+                    character: 0,
+                },
+                end: Position {
+                    line: 1,
+                    character: 5,
+                },
+            },
+            placeholder: String::from("value"),
+        })
+    );
 
-    Ok(())
+    // `console` is a global, it has no declaration in the document
+    let res: Option<PrepareRenameResponse> = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename_global",
+            TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                position: Position {
+                    line: 1,
+                    character: 10,
+                },
+            },
+        )
+        .await?
+        .context("prepareRename returned None")?;
+
+    assert_eq!(res, None);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn document_symbols() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("class Store {\n    add(item) {}\n}\nconst store = new Store();")
+        .await?;
+
+    let res: Option<DocumentSymbolResponse> = server
+        .request(
+            "textDocument/documentSymbol",
+            "document_symbol",
+            DocumentSymbolParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("documentSymbol returned None")?;
+
+    // The test client doesn't support hierarchical symbols
+    let Some(DocumentSymbolResponse::Flat(symbols)) = res else {
+        panic!("expected a flat list of symbols, got {res:?}");
+    };
+    let symbols = symbols
+        .into_iter()
+        .map(|symbol| (symbol.name, symbol.kind, symbol.container_name))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        symbols,
+        [
+            (String::from("Store"), lsp::SymbolKind::CLASS, None),
+            (
+                String::from("add"),
+                lsp::SymbolKind::METHOD,
+                Some(String::from("Store"))
+            ),
+            (String::from("store"), lsp::SymbolKind::CONSTANT, None),
+        ]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn workspace_symbols() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document(
+            "export function formatDate() {}\nfunction format() {}\nexport const FORMATS = [];",
+        )
+        .await?;
+
+    let res: Option<Vec<lsp::SymbolInformation>> = server
+        .request(
+            "workspace/symbol",
+            "workspace_symbol",
+            lsp::WorkspaceSymbolParams {
+                query: String::from("fd"),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("workspace/symbol returned None")?;
+
+    let symbols = res
+        .context("expected a list of symbols")?
+        .into_iter()
+        .map(|symbol| (symbol.name, symbol.kind, symbol.location))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        symbols,
+        [(
+            String::from("formatDate"),
+            lsp::SymbolKind::FUNCTION,
+            lsp::Location {
+                uri: url!("document.js"),
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 16,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 26,
+                    },
+                },
+            }
+        )]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn folding_ranges() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("/*\n * a\n */\nfunction a() {\n    return 1;\n}\n")
+        .await?;
+
+    let res: Vec<lsp::FoldingRange> = server
+        .request(
+            "textDocument/foldingRange",
+            "folding_range",
+            lsp::FoldingRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("foldingRange returned None")?;
+
+    let ranges = res
+        .into_iter()
+        .map(|range| (range.start_line, range.end_line, range.kind))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        ranges,
+        [(0, 2, Some(lsp::FoldingRangeKind::Comment)), (3, 4, None)]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn semantic_tokens() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("const a = 1;\n// b").await?;
+
+    let res: lsp::SemanticTokensResult = server
+        .request(
+            "textDocument/semanticTokens/full",
+            "semantic_tokens",
+            lsp::SemanticTokensParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("semanticTokens/full returned None")?;
+
+    let lsp::SemanticTokensResult::Tokens(tokens) = res else {
+        panic!("expected the tokens of the whole file, got {res:?}");
+    };
+    let tokens = tokens
+        .data
+        .into_iter()
+        .map(|token| {
+            (
+                token.delta_line,
+                token.delta_start,
+                token.length,
+                token.token_type,
+                token.token_modifiers_bitset,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // const: keyword, a: readonly variable declaration, 1: number, // b: comment
+    assert_eq!(
+        tokens,
+        [
+            (0, 0, 5, 8, 0),
+            (0, 6, 1, 18, 3),
+            (0, 4, 1, 11, 0),
+            (1, 0, 4, 1, 0)
+        ]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn goto_definition() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("import { missing } from \"./missing\";\nlet value = 1;\nvalue + missing;")
+        .await?;
+
+    let params = |line, character| GotoDefinitionParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier {
+                uri: url!("document.js"),
+            },
+            position: Position { line, character },
+        },
+        work_done_progress_params: WorkDoneProgressParams {
+            work_done_token: None,
+        },
+        partial_result_params: lsp::PartialResultParams {
+            partial_result_token: None,
+        },
+    };
+
+    let res: Option<GotoDefinitionResponse> = server
+        .request("textDocument/definition", "definition", params(2, 2))
+        .await?
+        .context("definition returned None")?;
+
+    assert_eq!(
+        res,
+        Some(GotoDefinitionResponse::Scalar(Location {
+            uri: url!("document.js"),
+            range: Range {
+                start: Position {
+                    line: 1,
+                    character: 4,
+                },
+                end: Position {
+                    line: 1,
+                    character: 9,
+                },
+            },
+        }))
+    );
+
+    // The imported module doesn't exist
+    let res: Option<GotoDefinitionResponse> = server
+        .request(
+            "textDocument/definition",
+            "definition_import",
+            params(2, 10),
+        )
+        .await?
+        .context("definition returned None")?;
+
+    assert_eq!(res, None);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_references() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("let value = 1;\nvalue = value + 1;")
+        .await?;
+
+    let range = |line, start, end| Range {
+        start: Position {
+            line,
+            character: start,
+        },
+        end: Position {
+            line,
+            character: end,
+        },
+    };
+
+    for (include_declaration, expected) in [
+        (true, vec![range(0, 4, 9), range(1, 0, 5), range(1, 8, 13)]),
+        (false, vec![range(1, 0, 5), range(1, 8, 13)]),
+    ] {
+        let res: Option<Vec<Location>> = server
+            .request(
+                "textDocument/references",
+                "references",
+                ReferenceParams {
+                    text_document_position: TextDocumentPositionParams {
+                        text_document: TextDocumentIdentifier {
+                            uri: url!("document.js"),
+                        },
+                        position: Position {
+                            line: 1,
+                            character: 9,
+                        },
+                    },
+                    work_done_progress_params: WorkDoneProgressParams {
+                        work_done_token: None,
+                    },
+                    partial_result_params: lsp::PartialResultParams {
+                        partial_result_token: None,
+                    },
+                    context: ReferenceContext {
+                        include_declaration,
+                    },
+                },
+            )
+            .await?
+            .context("references returned None")?;
+
+        let ranges = res
+            .context("expected a list of references")?
+            .into_iter()
+            .map(|location| location.range)
+            .collect::<Vec<_>>();
+
+        assert_eq!(ranges, expected);
+    }
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn server_shutdown() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    let cancellation = factory.cancellation();
+    let cancellation = cancellation.notified();
+
+    server.biome_shutdown().await?;
+
+    cancellation.await;
+
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inlay_hints() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("function add(left, right) {\n    return 1;\n}\nadd(1, right);\n")
+        .await?;
+
+    let res: Vec<lsp::InlayHint> = server
+        .request(
+            "textDocument/inlayHint",
+            "inlay_hint",
+            lsp::InlayHintParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 0,
+                    },
+                    end: Position {
+                        line: 4,
+                        character: 0,
+                    },
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("inlayHint returned None")?;
+
+    // The return types are disabled by the settings of the client
+    let hints = res
+        .into_iter()
+        .map(|hint| {
+            let lsp::InlayHintLabel::String(label) = hint.label else {
+                bail!("expected a string label");
+            };
+            Ok((hint.position, label, hint.kind))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(
+        hints,
+        [(
+            Position {
+                line: 3,
+                character: 4,
+            },
+            String::from("left:"),
+            Some(lsp::InlayHintKind::PARAMETER),
+        )]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn code_lens() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("debugger;\nconst a = 1;\ndebugger;\n")
+        .await?;
+
+    let res: Vec<lsp::CodeLens> = server
+        .request(
+            "textDocument/codeLens",
+            "code_lens",
+            lsp::CodeLensParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeLens returned None")?;
+
+    let lenses = res
+        .iter()
+        .map(|lens| {
+            let command = lens.command.as_ref().context("code lens without command")?;
+            Ok((lens.range.start.line, command.title.as_str()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(
+        lenses,
+        [
+            (0, "Fix all suspicious/noDebugger in file"),
+            (0, "Disable suspicious/noDebugger for project"),
+            (2, "Fix all suspicious/noDebugger in file"),
+            (2, "Disable suspicious/noDebugger for project"),
+        ]
+    );
+
+    let command = res[0]
+        .command
+        .clone()
+        .context("code lens without command")?;
+    let _: Option<serde_json::Value> = server
+        .request(
+            "workspace/executeCommand",
+            "execute_command",
+            lsp::ExecuteCommandParams {
+                command: command.command,
+                arguments: command.arguments.unwrap_or_default(),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?;
+
+    let edit = loop {
+        let notification = tokio::select! {
+            msg = receiver.next() => msg,
+            _ = sleep(Duration::from_secs(1)) => {
+                panic!("timed out waiting for the server to apply the fixes")
+            }
+        };
+        if let Some(ServerNotification::ApplyEdit(params)) = notification {
+            break params.edit;
+        }
+    };
+
+    let changes = edit.changes.context("the edit has no changes")?;
+    let edits = changes
+        .get(&url!("document.js"))
+        .context("the edit doesn't change the document")?;
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].new_text, "\nconst a = 1;\n");
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+async fn format_on_type(
+    server: &mut Server,
+    position: Position,
+    ch: &str,
+) -> Result<Option<Vec<TextEdit>>> {
+    server
+        .request(
+            "textDocument/onTypeFormatting",
+            "on_type_formatting",
+            lsp::DocumentOnTypeFormattingParams {
+                text_document_position: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: url!("document.js"),
+                    },
+                    position,
+                },
+                ch: ch.to_string(),
+                options: FormattingOptions {
+                    tab_size: 4,
+                    insert_spaces: false,
+                    properties: HashMap::default(),
+                    trim_trailing_whitespace: None,
+                    insert_final_newline: None,
+                    trim_final_newlines: None,
+                },
+            },
+        )
+        .await?
+        .context("onTypeFormatting didn't respond")
+}
+
+#[tokio::test]
+async fn on_type_formatting() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("const a=1;\nif(a){b( )}\nc(  )\n")
+        .await?;
+
+    // The statement completed by the semicolon is formatted
+    let edits = format_on_type(&mut server, Position::new(0, 10), ";")
+        .await?
+        .context("onTypeFormatting returned None")?;
+    assert_eq!(
+        edits,
+        [TextEdit {
+            range: Range::new(Position::new(0, 0), Position::new(0, 10)),
+            new_text: String::from("const a = 1;"),
+        }]
+    );
+
+    // After a newline, the statement of the previous line is formatted
+    let edits = format_on_type(&mut server, Position::new(2, 0), "\n")
+        .await?
+        .context("onTypeFormatting returned None")?;
+    assert_eq!(
+        edits,
+        [TextEdit {
+            range: Range::new(Position::new(1, 0), Position::new(1, 11)),
+            new_text: String::from("if (a) {\n\tb();\n}"),
+        }]
+    );
+
+    // The statement doesn't end with a semicolon yet
+    let edits = format_on_type(&mut server, Position::new(3, 0), "\n").await?;
+    assert_eq!(edits, None);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_diagnostics_request() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if(a == b) {}").await?;
+
+    let report: serde_json::Value = server
+        .request(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            serde_json::json!({
+                "textDocument": { "uri": url!("document.js") },
+            }),
+        )
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+
+    assert_eq!(report["kind"], "full");
+    let items = report["items"]
+        .as_array()
+        .context("the report has no items")?;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["code"], "lint/suspicious/noDoubleEquals");
+    let result_id = report["resultId"].clone();
+
+    // The diagnostics that were published before the client pulled them are cleared
+    let cleared = loop {
+        let notification = tokio::select! {
+            msg = receiver.next() => msg,
+            _ = sleep(Duration::from_secs(1)) => {
+                panic!("timed out waiting for the server to clear the diagnostics")
+            }
+        };
+        if let Some(ServerNotification::PublishDiagnostics(params)) = notification {
+            if params.diagnostics.is_empty() {
+                break params;
+            }
+        }
+    };
+    assert_eq!(cleared.uri, url!("document.js"));
+
+    let report: serde_json::Value = server
+        .request(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            serde_json::json!({
+                "textDocument": { "uri": url!("document.js") },
+                "previousResultId": result_id,
+            }),
+        )
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+
+    assert_eq!(
+        report,
+        serde_json::json!({ "kind": "unchanged", "resultId": result_id })
+    );
+
+    server
+        .change_document(
+            1,
+            vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: String::from("if(a === b) {}"),
+            }],
+        )
+        .await?;
+
+    let report: serde_json::Value = server
+        .request(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            serde_json::json!({
+                "textDocument": { "uri": url!("document.js") },
+                "previousResultId": result_id,
+            }),
+        )
+        .await?
+        .context("textDocument/diagnostic returned None")?;
+
+    assert_eq!(report["kind"], "full");
+    assert_eq!(report["items"], serde_json::json!([]));
+    assert_ne!(report["resultId"], result_id);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+/// Formats the document at `uri` and returns the new text of the edits
+async fn format_document(server: &mut Server, uri: Url) -> Result<Vec<String>> {
+    let res: Option<Vec<TextEdit>> = server
+        .request(
+            "textDocument/formatting",
+            "formatting",
+            DocumentFormattingParams {
+                text_document: TextDocumentIdentifier { uri },
+                options: FormattingOptions {
+                    tab_size: 4,
+                    insert_spaces: false,
+                    properties: HashMap::default(),
+                    trim_trailing_whitespace: None,
+                    insert_final_newline: None,
+                    trim_final_newlines: None,
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("formatting returned None")?;
+
+    let edits = res.context("formatting did not return an edit list")?;
+    Ok(edits.into_iter().map(|edit| edit.new_text).collect())
+}
+
+#[tokio::test]
+async fn workspace_folders_have_their_own_configuration() -> Result<()> {
+    let workspace_dir = std::env::temp_dir().join("biome_lsp_workspace_folders");
+    let root_dir = workspace_dir.join("root");
+    let project_dir = workspace_dir.join("project");
+    fs::create_dir_all(&root_dir)?;
+    fs::create_dir_all(&project_dir)?;
+    fs::write(
+        root_dir.join("biome.json"),
+        r#"{ "formatter": { "indentStyle": "tab" } }"#,
+    )?;
+    fs::write(
+        project_dir.join("biome.json"),
+        r#"{ "formatter": { "indentStyle": "space", "indentWidth": 2 } }"#,
+    )?;
+
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    let root_folder = lsp::WorkspaceFolder {
+        uri: Url::from_directory_path(&root_dir).unwrap(),
+        name: String::from("root"),
+    };
+    let project_folder = lsp::WorkspaceFolder {
+        uri: Url::from_directory_path(&project_dir).unwrap(),
+        name: String::from("project"),
+    };
+    server
+        .initialize_workspace(None, Some(vec![root_folder, project_folder.clone()]))
+        .await?;
+    server.initialized().await?;
+
+    let root_document = Url::from_file_path(root_dir.join("document.js")).unwrap();
+    let project_document = Url::from_file_path(project_dir.join("document.js")).unwrap();
+    server
+        .open_named_document("if(a){b}\n", root_document.clone(), "javascript")
+        .await?;
+    server
+        .open_named_document("if(a){b}\n", project_document.clone(), "javascript")
+        .await?;
+
+    let root_edits = format_document(&mut server, root_document.clone()).await?;
+    assert!(root_edits.concat().contains("\tb;"), "{root_edits:?}");
+
+    let project_edits = format_document(&mut server, project_document.clone()).await?;
+    assert!(project_edits.concat().contains("  b;"), "{project_edits:?}");
+
+    // The removed folder doesn't have its own configuration anymore
+    server
+        .notify(
+            "workspace/didChangeWorkspaceFolders",
+            lsp::DidChangeWorkspaceFoldersParams {
+                event: lsp::WorkspaceFoldersChangeEvent {
+                    added: Vec::new(),
+                    removed: vec![project_folder],
+                },
+            },
+        )
+        .await?;
+
+    let project_edits = format_document(&mut server, project_document).await?;
+    assert!(project_edits.concat().contains("\tb;"), "{project_edits:?}");
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reload_extended_configuration() -> Result<()> {
+    let workspace_dir = std::env::temp_dir().join("biome_lsp_reload_extended_configuration");
+    fs::create_dir_all(&workspace_dir)?;
+    fs::write(
+        workspace_dir.join("biome.json"),
+        r#"{ "extends": ["base.json"] }"#,
+    )?;
+    let base_path = workspace_dir.join("base.json");
+    fs::write(
+        &base_path,
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#,
+    )?;
+
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server
+        .initialize_workspace(
+            Some(Url::from_directory_path(&workspace_dir).unwrap()),
+            None,
+        )
+        .await?;
+    server.initialized().await?;
+
+    let document = Url::from_file_path(workspace_dir.join("document.js")).unwrap();
+    server
+        .open_named_document("debugger;\n", document.clone(), "javascript")
+        .await?;
+
+    let notification = tokio::select! {
+        msg = receiver.next() => msg,
+        _ = sleep(Duration::from_secs(1)) => {
+            panic!("timed out waiting for the server to send diagnostics")
+        }
+    };
+    let Some(ServerNotification::PublishDiagnostics(params)) = notification else {
+        panic!("unexpected notification {notification:?}");
+    };
+    assert!(params.diagnostics.is_empty(), "{:?}", params.diagnostics);
+
+    // The rule is turned on in the extended file, without restarting the server
+    fs::write(
+        &base_path,
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#,
+    )?;
+    server
+        .notify(
+            "workspace/didChangeWatchedFiles",
+            lsp::DidChangeWatchedFilesParams {
+                changes: vec![lsp::FileEvent {
+                    uri: Url::from_file_path(&base_path).unwrap(),
+                    typ: lsp::FileChangeType::CHANGED,
+                }],
+            },
+        )
+        .await?;
+
+    let notification = tokio::select! {
+        msg = receiver.next() => msg,
+        _ = sleep(Duration::from_secs(1)) => {
+            panic!("timed out waiting for the server to send diagnostics")
+        }
+    };
+    let Some(ServerNotification::PublishDiagnostics(params)) = notification else {
+        panic!("unexpected notification {notification:?}");
+    };
+    assert_eq!(params.uri, document);
+    let codes: Vec<_> = params
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code.clone())
+        .collect();
+    assert_eq!(
+        codes,
+        vec![Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDebugger"
+        )))]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn untitled_document_uses_the_default_configuration() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    // The language of the document comes from its language ID
+    let document = Url::parse("untitled:Untitled-1")?;
+    server
+        .open_named_document(
+            "const a: number = f();\nif(a){debugger}\n",
+            document.clone(),
+            "typescript",
+        )
+        .await?;
+
+    let notification = tokio::select! {
+        msg = receiver.next() => msg,
+        _ = sleep(Duration::from_secs(1)) => {
+            panic!("timed out waiting for the server to send diagnostics")
+        }
+    };
+    let Some(ServerNotification::PublishDiagnostics(params)) = notification else {
+        panic!("unexpected notification {notification:?}");
+    };
+    assert_eq!(params.uri, document);
+    let codes: Vec<_> = params
+        .diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code.clone())
+        .collect();
+    assert_eq!(
+        codes,
+        vec![Some(lsp::NumberOrString::String(String::from(
+            "lint/suspicious/noDebugger"
+        )))]
+    );
+
+    // The client sets the indentation of the default configuration to two spaces
+    let edits = format_document(&mut server, document).await?;
+    assert!(edits.concat().contains("  debugger;"), "{edits:?}");
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn workspace_indexing_progress() -> Result<()> {
+    let workspace_dir = std::env::temp_dir().join("biome_lsp_workspace_indexing_progress");
+    fs::create_dir_all(&workspace_dir)?;
+    for name in ["a.js", "b.ts", "c.jsx"] {
+        fs::write(workspace_dir.join(name), "export function f() {}\n")?;
+    }
+
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server
+        .initialize_with_capabilities(
+            Some(Url::from_directory_path(&workspace_dir).unwrap()),
+            None,
+            ClientCapabilities {
+                window: Some(lsp::WindowClientCapabilities {
+                    work_done_progress: Some(true),
+                    ..lsp::WindowClientCapabilities::default()
+                }),
+                ..ClientCapabilities::default()
+            },
+        )
+        .await?;
+    server.initialized().await?;
+
+    let mut values = vec![];
+    loop {
+        let notification = tokio::select! {
+            msg = receiver.next() => msg,
+            _ = sleep(Duration::from_secs(5)) => {
+                panic!("timed out waiting for the server to end the progress")
+            }
+        };
+        let Some(ServerNotification::Progress(params)) = notification else {
+            panic!("unexpected notification {notification:?}");
+        };
+        let lsp::ProgressParamsValue::WorkDone(value) = params.value;
+        let is_end = matches!(value, lsp::WorkDoneProgress::End(_));
+        values.push(value);
+        if is_end {
+            break;
+        }
+    }
+
+    assert!(
+        matches!(values.first(), Some(lsp::WorkDoneProgress::Begin(_))),
+        "{values:?}"
+    );
+    let Some(lsp::WorkDoneProgress::End(end)) = values.last() else {
+        panic!("the progress didn't end: {values:?}");
+    };
+    assert_eq!(end.message.as_deref(), Some("Indexed 3 files"));
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn range_formatting_in_markdown_code_block() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    let document = url!("document.md");
+    server
+        .open_named_document(
+            "Some   prose\n\n- item\n\n  ```js\n  const a   =  1;\n  const b   =  2;\n  ```\n",
+            document.clone(),
+            "markdown",
+        )
+        .await?;
+
+    // Only the selected statement of the code block is formatted
+    let edits = format_range(
+        &mut server,
+        document.clone(),
+        Range::new(Position::new(5, 2), Position::new(5, 17)),
+    )
+    .await?;
+    assert_eq!(
+        edits,
+        vec![TextEdit {
+            range: Range::new(Position::new(5, 2), Position::new(5, 17)),
+            new_text: String::from("const a = 1;"),
+        }]
+    );
+
+    // The prose isn't formatted when the range isn't inside a code block
+    let edits = format_range(
+        &mut server,
+        document,
+        Range::new(Position::new(0, 0), Position::new(8, 0)),
+    )
+    .await?;
+    assert_eq!(
+        edits,
+        vec![TextEdit {
+            range: Range::new(Position::new(5, 0), Position::new(7, 0)),
+            new_text: String::from("  const a = 1;\n  const b = 2;\n"),
+        }]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+async fn format_range(server: &mut Server, uri: Url, range: Range) -> Result<Vec<TextEdit>> {
+    let res: Option<Vec<TextEdit>> = server
+        .request(
+            "textDocument/rangeFormatting",
+            "range_formatting",
+            lsp::DocumentRangeFormattingParams {
+                text_document: TextDocumentIdentifier { uri },
+                range,
+                options: FormattingOptions {
+                    tab_size: 4,
+                    insert_spaces: false,
+                    properties: HashMap::default(),
+                    trim_trailing_whitespace: None,
+                    insert_final_newline: None,
+                    trim_final_newlines: None,
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("range formatting returned None")?;
+
+    res.context("range formatting did not return an edit list")
 }
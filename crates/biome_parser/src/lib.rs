@@ -916,7 +916,7 @@ impl AnyParse {
 
     pub fn file_source<'a, F, L>(&self, path: &'a Path) -> Result<F, FileSourceError>
     where
-        F: FileSource<'a, L> + 'static,
+        F: FileSource<'a, L> + Clone + 'static,
         L: Language + 'static,
     {
         self.file_source.unwrap_cast_from_path(path)
@@ -8,7 +8,9 @@ use biome_diagnostics::serde::Diagnostic;
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_rowan::Language;
 pub use license::generated::*;
-pub use node_js_project::{NodeJsProject, PackageJson};
+pub use node_js_project::{
+    CompilerOptions, NodeJsProject, PackageExports, PackageJson, TsConfigJson,
+};
 use std::any::TypeId;
 use std::fmt::Debug;
 use std::path::Path;
@@ -1,6 +1,8 @@
 mod package_json;
+mod tsconfig_json;
 
-pub use crate::node_js_project::package_json::PackageJson;
+pub use crate::node_js_project::package_json::{PackageExports, PackageJson};
+pub use crate::node_js_project::tsconfig_json::{CompilerOptions, TsConfigJson};
 use crate::{Manifest, Project, ProjectAnalyzeDiagnostic, ProjectAnalyzeResult, LICENSE_LIST};
 use biome_diagnostics::Error;
 use biome_json_syntax::JsonRoot;
@@ -18,6 +18,7 @@ pub struct PackageJson {
     pub dev_dependencies: Dependencies,
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
+    pub exports: Option<PackageExports>,
 }
 
 impl Manifest for PackageJson {
@@ -91,6 +92,9 @@ impl DeserializationVisitor for PackageJsonVisitor {
                         result.dev_dependencies = deps;
                     }
                 }
+                "exports" => {
+                    result.exports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "optionalDependencies" => {
                     if let Some(deps) = Deserializable::deserialize(&value, &key_text, diagnostics)
                     {
@@ -107,6 +111,146 @@ impl DeserializationVisitor for PackageJsonVisitor {
     }
 }
 
+/// The `exports` field of a `package.json`, which maps the subpaths of a package to its files.
+///
+/// See the [Node.js documentation](https://nodejs.org/api/packages.html#package-entry-points).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PackageExports {
+    /// A path relative to the package, e.g. `"./index.js"`
+    Path(String),
+    /// Targets tried in order, the first one that can be resolved is used
+    Fallbacks(Vec<PackageExports>),
+    /// Subpaths, e.g. `"./utils"`, or conditions, e.g. `"import"`, in the order of the file
+    Map(Vec<(String, PackageExports)>),
+    /// `null`, the subpath isn't exported
+    Excluded,
+}
+
+impl PackageExports {
+    /// Returns the path, relative to the package, that is exported as `subpath`, e.g. `"."` or
+    /// `"./utils"`. Nested conditions are matched against `conditions`, `"default"` always
+    /// matches.
+    pub fn resolve(&self, subpath: &str, conditions: &[&str]) -> Option<String> {
+        let Self::Map(entries) = self else {
+            return (subpath == ".").then(|| self.resolve_target(conditions, None))?;
+        };
+        if !entries.iter().any(|(key, _)| key.starts_with('.')) {
+            // The keys are conditions of the main entry point
+            return (subpath == ".").then(|| self.resolve_target(conditions, None))?;
+        }
+
+        if let Some((_, target)) = entries.iter().find(|(key, _)| key == subpath) {
+            return target.resolve_target(conditions, None);
+        }
+
+        // The pattern with the longest prefix wins, e.g. `"./features/*"`
+        entries
+            .iter()
+            .filter_map(|(key, target)| {
+                let (prefix, suffix) = key.split_once('*')?;
+                if subpath.len() < prefix.len() + suffix.len() {
+                    return None;
+                }
+                let captured = subpath.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                Some((prefix.len(), captured, target))
+            })
+            .max_by_key(|(prefix_len, ..)| *prefix_len)
+            .and_then(|(_, captured, target)| target.resolve_target(conditions, Some(captured)))
+    }
+
+    fn resolve_target(&self, conditions: &[&str], captured: Option<&str>) -> Option<String> {
+        match self {
+            Self::Path(path) => Some(match captured {
+                Some(captured) => path.replace('*', captured),
+                None => path.clone(),
+            }),
+            Self::Fallbacks(targets) => targets
+                .iter()
+                .find_map(|target| target.resolve_target(conditions, captured)),
+            Self::Map(entries) => entries
+                .iter()
+                .filter(|(condition, _)| {
+                    condition == "default" || conditions.contains(&condition.as_str())
+                })
+                .find_map(|(_, target)| target.resolve_target(conditions, captured)),
+            Self::Excluded => None,
+        }
+    }
+}
+
+impl Deserializable for PackageExports {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageExportsVisitor, name, diagnostics)
+    }
+}
+
+struct PackageExportsVisitor;
+impl DeserializationVisitor for PackageExportsVisitor {
+    type Output = PackageExports;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::NULL
+        .union(VisitableType::STR)
+        .union(VisitableType::ARRAY)
+        .union(VisitableType::MAP);
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Excluded)
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Path(value.text().to_string()))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Fallbacks(
+            items
+                .flatten()
+                .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+                .collect(),
+        ))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut entries = vec![];
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(target) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                entries.push((key_text.text().to_string(), target));
+            }
+        }
+        Some(PackageExports::Map(entries))
+    }
+}
+
 impl Deserializable for Dependencies {
     fn deserialize(
         value: &impl DeserializableValue,
@@ -0,0 +1,117 @@
+use crate::{LanguageRoot, Manifest};
+use biome_deserialize::json::deserialize_from_json_ast;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor,
+    Deserialized, Text, VisitableType,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_text_size::TextRange;
+use std::collections::BTreeMap;
+
+/// The subset of a `tsconfig.json` file that Biome uses to resolve modules
+#[derive(Debug, Default)]
+pub struct TsConfigJson {
+    pub compiler_options: CompilerOptions,
+}
+
+#[derive(Debug, Default)]
+pub struct CompilerOptions {
+    /// The directory used to resolve non-relative module names
+    pub base_url: Option<String>,
+    /// Maps module names, that can contain a `*` wildcard, to a list of locations
+    pub paths: BTreeMap<String, Vec<String>>,
+}
+
+impl Manifest for TsConfigJson {
+    type Language = JsonLanguage;
+
+    fn deserialize_manifest(root: &LanguageRoot<Self::Language>) -> Deserialized<Self> {
+        deserialize_from_json_ast::<TsConfigJson>(root)
+    }
+}
+
+impl Deserializable for TsConfigJson {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(TsConfigJsonVisitor, name, diagnostics)
+    }
+}
+
+struct TsConfigJsonVisitor;
+impl DeserializationVisitor for TsConfigJsonVisitor {
+    type Output = TsConfigJson;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if key_text.text() == "compilerOptions" {
+                if let Some(compiler_options) =
+                    Deserializable::deserialize(&value, &key_text, diagnostics)
+                {
+                    result.compiler_options = compiler_options;
+                }
+            }
+            // TypeScript has many more options, we only deserialize the ones that Biome needs
+        }
+        Some(result)
+    }
+}
+
+impl Deserializable for CompilerOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(CompilerOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct CompilerOptionsVisitor;
+impl DeserializationVisitor for CompilerOptionsVisitor {
+    type Output = CompilerOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "baseUrl" => {
+                    result.base_url = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "paths" => {
+                    if let Some(paths) = Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.paths = paths;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(result)
+    }
+}
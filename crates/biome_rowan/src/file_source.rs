@@ -1,7 +1,8 @@
 use crate::Language;
-use std::any::TypeId;
+use std::any::{Any, TypeId};
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Errors around the construct of the source type
 #[derive(Debug)]
@@ -55,9 +56,13 @@ impl Display for FileSourceError {
 pub trait FileSource<'a, L: Language + 'static>:
     TryFrom<&'a Path, Error = FileSourceError>
 {
-    fn as_any_file_source(&self) -> AnyFileSource {
+    fn as_any_file_source(&self) -> AnyFileSource
+    where
+        Self: Clone + Send + Sync + 'static,
+    {
         AnyFileSource {
             file_source: TypeId::of::<L>(),
+            source: Arc::new(self.clone()),
         }
     }
 }
@@ -66,24 +71,31 @@ pub trait FileSource<'a, L: Language + 'static>:
 #[derive(Clone)]
 pub struct AnyFileSource {
     pub(crate) file_source: TypeId,
+    /// The original file source, which can't always be retrieved from the path, e.g. when
+    /// the language of a file without extension was provided by an editor
+    source: Arc<dyn Any + Send + Sync>,
 }
 
 impl AnyFileSource {
-    /// Attempts to retrieve the original file source of the file
+    /// Attempts to retrieve the original file source of the file, or to create it from the
+    /// given [Path] if it has a different type
     ///
     /// ## Errors
     ///
-    /// The function will panic if:
-    /// - the original type and given type mismatch
-    /// - it's possible to retrieve the correct [FileSource] from the given [Path]
+    /// The function will return an error if:
+    /// - the original language and given language mismatch
+    /// - it's not possible to retrieve the correct [FileSource] from the given [Path]
     pub fn unwrap_cast_from_path<'a, F, L>(&self, path: &'a Path) -> Result<F, FileSourceError>
     where
-        F: FileSource<'a, L> + 'static,
+        F: FileSource<'a, L> + Clone + 'static,
         L: Language + 'static,
     {
         let file_source = TypeId::of::<L>();
         if file_source == self.file_source {
-            F::try_from(path)
+            match self.source.downcast_ref::<F>() {
+                Some(source) => Ok(source.clone()),
+                None => F::try_from(path),
+            }
         } else {
             Err(FileSourceError::MissingFileExtension(PathBuf::from(path)))
         }
@@ -99,7 +111,7 @@ mod test {
 
     #[test]
     fn should_cast_file_source() {
-        #[derive(Debug, Eq, PartialEq)]
+        #[derive(Clone, Debug, Eq, PartialEq)]
         struct Test {
             path: String,
         }
@@ -130,4 +142,37 @@ mod test {
 
         assert_eq!(cast, first_test)
     }
+
+    #[test]
+    fn should_keep_original_file_source() {
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        struct Test {
+            extension: String,
+        }
+
+        impl TryFrom<&Path> for Test {
+            type Error = FileSourceError;
+
+            fn try_from(value: &Path) -> Result<Self, Self::Error> {
+                let extension = value
+                    .extension()
+                    .ok_or_else(|| FileSourceError::MissingFileExtension(value.to_path_buf()))?;
+                Ok(Test {
+                    extension: extension.to_string_lossy().to_string(),
+                })
+            }
+        }
+
+        impl<'a> FileSource<'a, RawLanguage> for Test {}
+
+        let first_test = Test {
+            extension: String::from("ts"),
+        };
+        let send_first = first_test.as_any_file_source();
+
+        // The path doesn't have an extension, the original file source is returned
+        let cast = send_first.unwrap_cast_from_path::<Test, RawLanguage>(Path::new("untitled"));
+
+        assert_eq!(cast.unwrap(), first_test)
+    }
 }
@@ -11,7 +11,7 @@ pub(crate) use self::{
     trivia::GreenTrivia,
 };
 
-pub use self::node_cache::NodeCache;
+pub use self::node_cache::{NodeCache, NodeCacheStats};
 pub(crate) use self::node_cache::NodeCacheNodeEntryMut;
 
 /// RawSyntaxKind is a type tag for each token or node.
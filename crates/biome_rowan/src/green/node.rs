@@ -281,6 +281,12 @@ impl GreenNode {
         GreenNode { ptr: data }
     }
 
+    /// Whether this is the only reference to the node
+    #[inline]
+    pub(crate) fn is_unique(&self) -> bool {
+        self.ptr.with_arc(|arc| arc.is_unique())
+    }
+
     #[inline]
     pub(crate) fn into_raw(self) -> ptr::NonNull<GreenNodeData> {
         // SAFETY: casting from `HeaderSlice<GreenNodeHead, [green::node::Slot]>` to `GreenNodeData`
@@ -4,6 +4,7 @@ use rustc_hash::FxHasher;
 use std::fmt::{self, Debug, Formatter};
 use std::hash::{BuildHasherDefault, Hash, Hasher};
 use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
 use std::ops::Not;
 use std::ptr::NonNull;
 
@@ -69,6 +70,15 @@ impl<T: IntoRawPointer> GenerationalPointer<T> {
         let data = self.data & !1;
         self.data = data | generation as usize;
     }
+
+    /// Whether the cache holds the only reference to the value
+    fn is_unique(&self) -> bool {
+        let ptr = self.value() as *const _ as *mut _;
+        // SAFETY: The value is never dropped, so the reference held by the
+        // cache isn't released
+        let value = ManuallyDrop::new(unsafe { T::from_raw(ptr) });
+        value.is_unique()
+    }
 }
 
 impl<T: IntoRawPointer> Debug for GenerationalPointer<T>
@@ -97,6 +107,7 @@ trait IntoRawPointer {
     type Pointee;
     fn into_raw(self) -> *mut Self::Pointee;
     unsafe fn from_raw(ptr: *mut Self::Pointee) -> Self;
+    fn is_unique(&self) -> bool;
 }
 
 /// A token stored in the `NodeCache`.
@@ -115,6 +126,10 @@ impl IntoRawPointer for GreenToken {
     unsafe fn from_raw(ptr: *mut Self::Pointee) -> Self {
         GreenToken::from_raw(NonNull::new(ptr).unwrap())
     }
+
+    fn is_unique(&self) -> bool {
+        GreenToken::is_unique(self)
+    }
 }
 
 /// A node stored in the `NodeCache`. It stores a pre-computed hash
@@ -142,6 +157,10 @@ impl IntoRawPointer for GreenNode {
     unsafe fn from_raw(ptr: *mut Self::Pointee) -> Self {
         GreenNode::from_raw(NonNull::new(ptr).unwrap())
     }
+
+    fn is_unique(&self) -> bool {
+        GreenNode::is_unique(self)
+    }
 }
 
 /// Interner for GreenTokens and GreenNodes
@@ -169,6 +188,18 @@ pub struct NodeCache {
     tokens: HashMap<CachedToken, ()>,
     trivia: TriviaCache,
     generation: Generation,
+    /// Whether the cache is shared by the trees of several files, see [NodeCache::shared]
+    shared: bool,
+    /// The number of entries of a shared cache after its last sweep
+    swept_len: usize,
+}
+
+/// The number of elements interned in a [NodeCache]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NodeCacheStats {
+    pub nodes: usize,
+    pub tokens: usize,
+    pub trivia: usize,
 }
 
 /// Represents a "generation" in the garbage collection scheme of the node
@@ -216,6 +247,34 @@ impl NodeCache {
     /// one of its children wasn't cached.
     const UNCACHED_NODE_HASH: u64 = 0;
 
+    /// The number of entries under which a shared cache is never swept
+    const SHARED_SWEEP_MIN_LEN: usize = 1 << 14;
+
+    /// Creates a cache shared by the trees of several files, such as the files of a workspace.
+    ///
+    /// A default cache only keeps the entries used by the last tree built with it. A shared
+    /// cache keeps its entries as long as a tree references them, so the nodes, tokens and
+    /// trivia that are identical in all the trees are only allocated once.
+    pub fn shared() -> Self {
+        Self {
+            shared: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the number of nodes, tokens and trivia interned in the cache
+    pub fn stats(&self) -> NodeCacheStats {
+        NodeCacheStats {
+            nodes: self.nodes.len(),
+            tokens: self.tokens.len(),
+            trivia: self.trivia.cache.len(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len() + self.tokens.len() + self.trivia.cache.len()
+    }
+
     /// Tries to retrieve a node with the given `kind` and `children` from the cache.
     ///
     /// Returns an entry that allows the caller to:
@@ -323,6 +382,11 @@ impl NodeCache {
     /// this point onward will update the generation of the corresponding entry
     /// to this new value
     pub(crate) fn increment_generation(&mut self) {
+        // The entries of a shared cache are swept by reference count instead
+        if self.shared {
+            return;
+        }
+
         debug_assert!(
             self.nodes
                 .keys()
@@ -343,7 +407,21 @@ impl NodeCache {
 
     /// Removes nodes, tokens and trivia entries from the cache when their
     /// generation doesn't match the current generation of the whole cache
+    ///
+    /// A shared cache removes the entries that aren't referenced by any tree
+    /// instead, see [NodeCache::sweep_unreferenced]. Visiting all the entries
+    /// of the files of a workspace on each parse would make parsing a file
+    /// proportional to the size of the cache, so a shared cache is only swept
+    /// when it doubled in size since its last sweep.
     pub(crate) fn sweep_cache(&mut self) {
+        if self.shared {
+            let threshold = (self.swept_len * 2).max(Self::SHARED_SWEEP_MIN_LEN);
+            if self.len() >= threshold {
+                self.sweep_unreferenced();
+            }
+            return;
+        }
+
         self.nodes
             .drain_filter(|node, _| node.node.generation() != self.generation);
 
@@ -354,6 +432,17 @@ impl NodeCache {
             .cache
             .drain_filter(|trivia, _| trivia.0.generation() != self.generation);
     }
+
+    /// Removes the entries that aren't referenced by any tree. The children
+    /// of the removed nodes may only be removed by the next sweep.
+    fn sweep_unreferenced(&mut self) {
+        self.nodes.drain_filter(|node, _| node.node.is_unique());
+        self.tokens.drain_filter(|token, _| token.0.is_unique());
+        self.trivia
+            .cache
+            .drain_filter(|trivia, _| trivia.0.is_unique());
+        self.swept_len = self.len();
+    }
 }
 
 pub(crate) enum NodeCacheNodeEntryMut<'a> {
@@ -436,6 +525,10 @@ impl IntoRawPointer for GreenTrivia {
     unsafe fn from_raw(ptr: *mut Self::Pointee) -> Self {
         GreenTrivia::from_raw(ptr)
     }
+
+    fn is_unique(&self) -> bool {
+        GreenTrivia::is_unique(self)
+    }
 }
 
 #[derive(Debug)]
@@ -514,9 +607,20 @@ mod tests {
 
     use crate::green::node_cache::{token_hash, CachedNode, CachedToken, CachedTrivia};
     use crate::green::trivia::GreenTrivia;
-    use crate::{GreenToken, RawSyntaxKind};
+    use crate::raw_language::{RawLanguage, RawLanguageKind, RawSyntaxTreeBuilder};
+    use crate::{GreenToken, NodeCache, RawSyntaxKind, SyntaxNode};
     use biome_text_size::TextSize;
 
+    fn build_literal(cache: &mut NodeCache, text: &str) -> SyntaxNode<RawLanguage> {
+        let mut builder = RawSyntaxTreeBuilder::with_cache(cache);
+        builder.start_node(RawLanguageKind::ROOT);
+        builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token(RawLanguageKind::STRING_TOKEN, text);
+        builder.finish_node();
+        builder.finish_node();
+        builder.finish()
+    }
+
     #[test]
     fn green_token_hash() {
         let kind = RawSyntaxKind(0);
@@ -554,4 +658,46 @@ mod tests {
         assert_eq!(size_of::<CachedToken>(), 8);
         assert_eq!(size_of::<CachedTrivia>(), 8);
     }
+
+    #[test]
+    fn default_cache_keeps_the_entries_of_the_last_tree() {
+        let mut cache = NodeCache::default();
+        let _first = build_literal(&mut cache, "a");
+        let _second = build_literal(&mut cache, "b");
+
+        assert_eq!(cache.stats().tokens, 1);
+    }
+
+    #[test]
+    fn shared_cache_keeps_the_entries_of_the_live_trees() {
+        let mut cache = NodeCache::shared();
+        let first = build_literal(&mut cache, "a");
+        let _second = build_literal(&mut cache, "b");
+        assert_eq!(cache.stats().tokens, 2);
+
+        let reused = build_literal(&mut cache, "a");
+        assert_eq!(cache.stats().tokens, 2);
+
+        drop(first);
+        drop(reused);
+        // A node and its children can be removed by different sweeps
+        cache.sweep_unreferenced();
+        cache.sweep_unreferenced();
+        assert_eq!(cache.stats().tokens, 1);
+    }
+
+    #[test]
+    fn shared_cache_is_swept_when_it_doubles() {
+        let mut cache = NodeCache::shared();
+        build_literal(&mut cache, "a");
+        build_literal(&mut cache, "b");
+        // The cache is too small to be swept
+        assert_eq!(cache.stats().tokens, 2);
+
+        for index in 0..NodeCache::SHARED_SWEEP_MIN_LEN * 2 {
+            build_literal(&mut cache, &index.to_string());
+        }
+        // Without sweeping, each dropped tree would keep a root, a literal and a token
+        assert!(cache.len() < NodeCache::SHARED_SWEEP_MIN_LEN * 2);
+    }
 }
@@ -172,6 +172,12 @@ impl GreenToken {
         GreenToken { ptr }
     }
 
+    /// Whether this is the only reference to the token
+    #[inline]
+    pub(crate) fn is_unique(&self) -> bool {
+        self.ptr.with_arc(|arc| arc.is_unique())
+    }
+
     #[inline]
     pub(crate) fn into_raw(self) -> ptr::NonNull<GreenTokenData> {
         Arc::from_thin(self.ptr).into_raw().cast()
@@ -125,6 +125,14 @@ impl GreenTrivia {
         self.pieces().get(index)
     }
 
+    /// Whether this is the only reference to the trivia. The empty trivia isn't reference
+    /// counted, and is always unique.
+    pub(crate) fn is_unique(&self) -> bool {
+        self.ptr
+            .as_ref()
+            .map_or(true, |ptr| ptr.with_arc(|arc| arc.is_unique()))
+    }
+
     pub(crate) fn into_raw(self) -> *mut GreenTriviaData {
         self.ptr.map_or_else(ptr::null_mut, |ptr| {
             Arc::from_thin(ptr).into_raw().cast().as_ptr()
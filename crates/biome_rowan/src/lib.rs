@@ -38,7 +38,7 @@ pub use biome_text_size::{TextLen, TextRange, TextSize};
 pub use crate::{
     ast::*,
     file_source::{AnyFileSource, FileSource, FileSourceError},
-    green::{NodeCache, RawSyntaxKind},
+    green::{NodeCache, NodeCacheStats, RawSyntaxKind},
     syntax::{
         chain_trivia_pieces, trim_leading_trivia_pieces, trim_trailing_trivia_pieces,
         ChainTriviaPiecesIterator, Language, SendNode, SyntaxElement, SyntaxElementChildren,
@@ -272,6 +272,29 @@ impl From<CantLoadExtendFile> for WorkspaceError {
     }
 }
 
+/// Thrown when an option of the `.editorconfig` file can't be applied to the formatter
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+	category = "configuration",
+	severity = Warning,
+)]
+pub struct EditorconfigDiagnostic {
+    #[location(resource)]
+    file_path: String,
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+}
+
+impl EditorconfigDiagnostic {
+    pub fn new(file_path: impl Into<String>, message: impl Display) -> Self {
+        Self {
+            file_path: file_path.into(),
+            message: MessageAndDescription::from(markup! {{message}}.to_owned()),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Diagnostic)]
 #[diagnostic(
 	category = "configuration",
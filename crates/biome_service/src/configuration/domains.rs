@@ -0,0 +1,241 @@
+//! The domains of the configuration. A domain enables the rules and sets the options that fit
+//! a framework or a kind of project, so they don't need to be copied between projects.
+
+use crate::configuration::javascript::{
+    JavascriptEnvironment, JavascriptEnvironments, JavascriptGlobalAccess, JavascriptGlobals,
+};
+use crate::configuration::linter::{
+    Complexity, Correctness, Nursery, RuleConfiguration, RulePlainConfiguration, Rules, Security,
+    Suspicious,
+};
+use crate::configuration::{JavascriptConfiguration, LinterConfiguration};
+use crate::{Configuration, MergeWith};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// A list of domains
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Domains(pub Vec<Domain>);
+
+impl FromStr for Domains {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+/// A set of rules and options that fit a framework or a kind of project
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum Domain {
+    /// The rules of React components and hooks, along with the globals of the browser
+    React,
+    /// The rules of React, along with the globals of the browser and Node.js. Default exports
+    /// are allowed, because pages and layouts are default exports
+    Next,
+    /// The rules of JSX, without the rules of React hooks that don't apply to Solid
+    Solid,
+    /// The globals of Vitest, e.g. `describe`, `it`, `expect` or `vi`
+    Vitest,
+    /// The globals of Node.js, and the rules that fit a published library, e.g. no default
+    /// exports and no logging
+    #[serde(rename = "node-library")]
+    NodeLibrary,
+}
+
+impl FromStr for Domain {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "react" => Ok(Self::React),
+            "next" => Ok(Self::Next),
+            "solid" => Ok(Self::Solid),
+            "vitest" => Ok(Self::Vitest),
+            "node-library" => Ok(Self::NodeLibrary),
+            _ => Err("Value not supported for Domain"),
+        }
+    }
+}
+
+/// The globals declared by Vitest when its `globals` option is enabled
+const VITEST_GLOBALS: &[&str] = &[
+    "afterAll",
+    "afterEach",
+    "assert",
+    "assertType",
+    "beforeAll",
+    "beforeEach",
+    "bench",
+    "chai",
+    "describe",
+    "expect",
+    "expectTypeOf",
+    "it",
+    "suite",
+    "test",
+    "vi",
+    "vitest",
+];
+
+impl Domain {
+    /// The rules enabled or disabled by the domain
+    fn rules(&self) -> Rules {
+        let error = || Some(RuleConfiguration::Plain(RulePlainConfiguration::Error));
+        let warn = || Some(RuleConfiguration::Plain(RulePlainConfiguration::Warn));
+        let off = || Some(RuleConfiguration::Plain(RulePlainConfiguration::Off));
+        let react_rules = || Rules {
+            recommended: None,
+            complexity: Some(Complexity {
+                no_useless_fragments: error(),
+                ..Complexity::default()
+            }),
+            correctness: Some(Correctness {
+                no_children_prop: error(),
+                no_render_return_value: error(),
+                no_void_elements_with_children: error(),
+                use_exhaustive_dependencies: error(),
+                use_hook_at_top_level: error(),
+                ..Correctness::default()
+            }),
+            security: Some(Security {
+                no_dangerously_set_inner_html: error(),
+                no_dangerously_set_inner_html_with_children: error(),
+                ..Security::default()
+            }),
+            suspicious: Some(Suspicious {
+                no_array_index_key: error(),
+                no_duplicate_jsx_props: error(),
+                ..Suspicious::default()
+            }),
+            ..Rules::default()
+        };
+
+        match self {
+            Domain::React => react_rules(),
+            Domain::Next => {
+                let mut rules = react_rules();
+                rules.nursery = Some(Nursery {
+                    no_default_export: off(),
+                    ..Nursery::default()
+                });
+                rules
+            }
+            Domain::Solid => Rules {
+                recommended: None,
+                correctness: Some(Correctness {
+                    no_void_elements_with_children: error(),
+                    use_exhaustive_dependencies: off(),
+                    use_hook_at_top_level: off(),
+                    ..Correctness::default()
+                }),
+                suspicious: Some(Suspicious {
+                    no_duplicate_jsx_props: error(),
+                    ..Suspicious::default()
+                }),
+                ..Rules::default()
+            },
+            Domain::Vitest => Rules {
+                recommended: None,
+                ..Rules::default()
+            },
+            Domain::NodeLibrary => Rules {
+                recommended: None,
+                nursery: Some(Nursery {
+                    no_default_export: error(),
+                    no_global_assign: error(),
+                    ..Nursery::default()
+                }),
+                suspicious: Some(Suspicious {
+                    no_console_log: warn(),
+                    ..Suspicious::default()
+                }),
+                ..Rules::default()
+            },
+        }
+    }
+
+    /// The environments that provide the globals of the domain
+    fn environments(&self) -> &'static [JavascriptEnvironment] {
+        match self {
+            Domain::React | Domain::Solid => &[JavascriptEnvironment::Browser],
+            Domain::Next => &[JavascriptEnvironment::Browser, JavascriptEnvironment::Node],
+            Domain::Vitest => &[],
+            Domain::NodeLibrary => &[JavascriptEnvironment::Node],
+        }
+    }
+
+    /// The read-only globals declared by the domain
+    fn globals(&self) -> &'static [&'static str] {
+        match self {
+            Domain::Vitest => VITEST_GLOBALS,
+            Domain::React | Domain::Next | Domain::Solid | Domain::NodeLibrary => &[],
+        }
+    }
+}
+
+impl Configuration {
+    /// Applies the rules and the options of the domains of the configuration. The rules and the
+    /// options set by the configuration take precedence over the ones of the domains.
+    pub(crate) fn apply_domains(&mut self) {
+        let Some(Domains(domains)) = self.domains.take() else {
+            return;
+        };
+
+        let mut rules = Rules {
+            recommended: None,
+            ..Rules::default()
+        };
+        for domain in &domains {
+            rules.merge_with(domain.rules());
+        }
+        let linter = self.linter.get_or_insert_with(LinterConfiguration::default);
+        if let Some(configured_rules) = linter.rules.take() {
+            rules.merge_with(configured_rules);
+        }
+        linter.rules = Some(rules);
+
+        let javascript = self
+            .javascript
+            .get_or_insert_with(JavascriptConfiguration::default);
+        if javascript.environments.is_none() {
+            let mut environments: Vec<JavascriptEnvironment> = vec![];
+            for environment in domains.iter().flat_map(Domain::environments) {
+                if !environments.contains(environment) {
+                    environments.push(*environment);
+                }
+            }
+            if !environments.is_empty() {
+                javascript.environments = Some(JavascriptEnvironments(environments));
+            }
+        }
+
+        let mut globals: IndexMap<String, JavascriptGlobalAccess> = domains
+            .iter()
+            .flat_map(Domain::globals)
+            .copied()
+            .map(|name| (name.to_string(), JavascriptGlobalAccess::Readonly))
+            .collect();
+        if !globals.is_empty() {
+            match javascript.globals.take() {
+                Some(JavascriptGlobals::List(names)) => {
+                    globals.extend(
+                        names
+                            .into_index_set()
+                            .into_iter()
+                            .map(|name| (name, JavascriptGlobalAccess::Writable)),
+                    );
+                }
+                Some(JavascriptGlobals::Map(configured_globals)) => {
+                    globals.extend(configured_globals);
+                }
+                None => {}
+            }
+            javascript.globals = Some(JavascriptGlobals::Map(globals));
+        }
+    }
+}
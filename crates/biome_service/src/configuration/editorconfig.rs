@@ -0,0 +1,133 @@
+//! Reads the options of the formatter from an `.editorconfig` file.
+//!
+//! Only the properties of the `[*]` section are applied, because they're the ones that apply to
+//! all the files of the project. See <https://editorconfig.org> for the format of the file.
+
+use crate::configuration::diagnostics::EditorconfigDiagnostic;
+use crate::configuration::{FormatterConfiguration, PlainIndentStyle};
+use biome_console::markup;
+use biome_formatter::{LineEnding, LineWidth};
+use std::path::Path;
+
+/// The name of the file
+pub(crate) const EDITORCONFIG_FILE_NAME: &str = ".editorconfig";
+
+/// Returns the options of the formatter set by the `[*]` section of the `.editorconfig` file at
+/// `file_path`, along with the diagnostics of the properties that can't be applied
+pub(crate) fn parse_editorconfig(
+    file_path: &Path,
+    content: &str,
+) -> (FormatterConfiguration, Vec<EditorconfigDiagnostic>) {
+    let mut configuration = FormatterConfiguration::empty();
+    let mut diagnostics = vec![];
+    let file_path = file_path.display().to_string();
+    let mut in_global_section = false;
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(section) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            in_global_section = section.trim() == "*";
+            continue;
+        }
+        if !in_global_section {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let value = value.trim().to_ascii_lowercase();
+        let invalid_value = || {
+            EditorconfigDiagnostic::new(
+                file_path.clone(),
+                markup! {
+                    "The value "<Emphasis>{value}</Emphasis>" of the property "<Emphasis>{key}</Emphasis>" isn't supported by Biome."
+                },
+            )
+        };
+        match key.as_str() {
+            "indent_style" => match value.parse::<PlainIndentStyle>() {
+                Ok(indent_style) => configuration.indent_style = Some(indent_style),
+                Err(_) => diagnostics.push(invalid_value()),
+            },
+            "indent_size" => match value.parse::<u8>() {
+                Ok(indent_width) => configuration.indent_width = Some(indent_width),
+                // The width of a tab is decided by `tab_width`, that Biome doesn't use
+                Err(_) if value == "tab" => {}
+                Err(_) => diagnostics.push(invalid_value()),
+            },
+            "end_of_line" => match value.parse::<LineEnding>() {
+                Ok(line_ending) if line_ending != LineEnding::Auto => {
+                    configuration.line_ending = Some(line_ending)
+                }
+                _ => diagnostics.push(invalid_value()),
+            },
+            "max_line_length" => {
+                match value.parse::<u16>().ok().map(LineWidth::try_from) {
+                    Some(Ok(line_width)) => configuration.line_width = Some(line_width),
+                    // The formatter always has a maximum width
+                    None if value == "off" => {}
+                    _ => diagnostics.push(invalid_value()),
+                }
+            }
+            // Biome always ends the formatted files with a newline
+            "insert_final_newline" if value != "true" => {
+                diagnostics.push(EditorconfigDiagnostic::new(
+                    file_path.clone(),
+                    markup! {
+                        "Biome always inserts a final newline, the property "<Emphasis>"insert_final_newline = "{value}</Emphasis>" is ignored."
+                    },
+                ));
+            }
+            _ => {}
+        }
+    }
+    (configuration, diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_editorconfig;
+    use crate::configuration::PlainIndentStyle;
+    use biome_formatter::{LineEnding, LineWidth};
+    use std::path::Path;
+
+    #[test]
+    fn reads_the_global_section() {
+        let content = r#"root = true
+
+[*]
+indent_style = space
+indent_size = 4
+end_of_line = crlf
+max_line_length = 100
+insert_final_newline = true
+
+[*.md]
+indent_size = 8
+"#;
+        let (configuration, diagnostics) = parse_editorconfig(Path::new(".editorconfig"), content);
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(configuration.indent_style, Some(PlainIndentStyle::Space));
+        assert_eq!(configuration.indent_width, Some(4));
+        assert_eq!(configuration.line_ending, Some(LineEnding::Crlf));
+        assert_eq!(
+            configuration.line_width,
+            Some(LineWidth::try_from(100).unwrap())
+        );
+    }
+
+    #[test]
+    fn reports_unsupported_values() {
+        let content = r#"[*]
+indent_style = spaces
+insert_final_newline = false
+"#;
+        let (configuration, diagnostics) = parse_editorconfig(Path::new(".editorconfig"), content);
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(configuration.indent_style, None);
+    }
+}
@@ -14,11 +14,13 @@ use std::str::FromStr;
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct FormatterConfiguration {
     // if `false`, it disables the feature. `true` by default
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub enabled: Option<bool>,
 
     /// Stores whether formatting should be allowed to proceed if a given file
     /// has syntax errors
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub format_with_errors: Option<bool>,
 
@@ -39,17 +41,24 @@ pub struct FormatterConfiguration {
 
     /// The type of line ending.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[bpaf(long("line-ending"), argument("lf|crlf|cr"), optional)]
+    #[bpaf(long("line-ending"), argument("lf|crlf|cr|auto"), optional)]
     pub line_ending: Option<LineEnding>,
 
     /// What's the max width of a line. Defaults to 80.
     #[serde(
         deserialize_with = "deserialize_line_width",
-        serialize_with = "serialize_line_width"
+        serialize_with = "serialize_line_width",
+        skip_serializing_if = "Option::is_none"
     )]
     #[bpaf(long("line-width"), argument("NUMBER"), optional)]
     pub line_width: Option<LineWidth>,
 
+    /// Whether to match the output of Prettier where Biome intentionally diverges from it,
+    /// to make the migration from Prettier easier. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub prettier_compatibility: Option<bool>,
+
     /// A list of Unix shell style patterns. The formatter will ignore files/folders that will
     /// match these patterns.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,12 +70,35 @@ pub struct FormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub include: Option<StringSet>,
+
+    /// Whether to read the options of the formatter from the `.editorconfig` file of the
+    /// project. The options of the configuration take precedence. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub use_editorconfig: Option<bool>,
 }
 
 impl FormatterConfiguration {
     pub const fn is_disabled(&self) -> bool {
         matches!(self.enabled, Some(false))
     }
+
+    /// A configuration that doesn't set any option
+    pub const fn empty() -> Self {
+        Self {
+            enabled: None,
+            format_with_errors: None,
+            indent_style: None,
+            indent_size: None,
+            indent_width: None,
+            line_ending: None,
+            line_width: None,
+            prettier_compatibility: None,
+            ignore: None,
+            include: None,
+            use_editorconfig: None,
+        }
+    }
 }
 
 impl Default for FormatterConfiguration {
@@ -79,8 +111,10 @@ impl Default for FormatterConfiguration {
             indent_style: Some(PlainIndentStyle::default()),
             line_ending: Some(LineEnding::default()),
             line_width: Some(LineWidth::default()),
+            prettier_compatibility: Some(false),
             ignore: None,
             include: None,
+            use_editorconfig: Some(false),
         }
     }
 }
@@ -118,6 +152,9 @@ impl MergeWith<FormatterConfiguration> for FormatterConfiguration {
         if let Some(format_with_errors) = other.format_with_errors {
             self.format_with_errors = Some(format_with_errors);
         }
+        if let Some(prettier_compatibility) = other.prettier_compatibility {
+            self.prettier_compatibility = Some(prettier_compatibility);
+        }
         if let Some(ignore) = other.ignore {
             self.ignore = Some(ignore)
         }
@@ -125,6 +162,9 @@ impl MergeWith<FormatterConfiguration> for FormatterConfiguration {
         if let Some(include) = other.include {
             self.include = Some(include)
         }
+        if let Some(use_editorconfig) = other.use_editorconfig {
+            self.use_editorconfig = Some(use_editorconfig);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: FormatterConfiguration)
@@ -153,12 +193,13 @@ impl TryFrom<FormatterConfiguration> for FormatSettings {
             .unwrap_or_default();
 
         Ok(Self {
-            enabled: conf.enabled.unwrap_or_default(),
+            enabled: conf.enabled.unwrap_or(true),
             indent_style: Some(indent_style),
             indent_width: Some(indent_width),
             line_ending: conf.line_ending,
             line_width: conf.line_width,
             format_with_errors: conf.format_with_errors.unwrap_or_default(),
+            prettier_compatibility: conf.prettier_compatibility.unwrap_or_default(),
             ignored_files: to_matcher(conf.ignore.as_ref())?,
             included_files: to_matcher(conf.include.as_ref())?,
         })
@@ -187,6 +228,7 @@ impl TryFrom<OverrideFormatterConfiguration> for FormatSettings {
             line_ending: conf.line_ending,
             line_width: conf.line_width,
             format_with_errors: conf.format_with_errors.unwrap_or_default(),
+            prettier_compatibility: conf.prettier_compatibility.unwrap_or_default(),
             ignored_files: None,
             included_files: None,
         })
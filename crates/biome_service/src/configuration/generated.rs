@@ -13,11 +13,14 @@ pub(crate) fn push_to_analyzer_rules(
             if let Some(RuleConfiguration::WithOptions(rule_options)) =
                 rules.get_rule_configuration(rule_name)
             {
-                if let Some(possible_options) = &rule_options.options {
-                    if let Some(rule_key) = metadata.find_rule("a11y", rule_name) {
+                if let Some(rule_key) = metadata.find_rule("a11y", rule_name) {
+                    if let Some(possible_options) = &rule_options.options {
                         let rule_options = possible_options.extract_option(&rule_key);
                         analyzer_rules.push_rule(rule_key, rule_options);
                     }
+                    if let Some(fix) = rule_options.fix {
+                        analyzer_rules.push_rule_applicability(rule_key, fix.into());
+                    }
                 }
             }
         }
@@ -27,11 +30,14 @@ pub(crate) fn push_to_analyzer_rules(
             if let Some(RuleConfiguration::WithOptions(rule_options)) =
                 rules.get_rule_configuration(rule_name)
             {
-                if let Some(possible_options) = &rule_options.options {
-                    if let Some(rule_key) = metadata.find_rule("complexity", rule_name) {
+                if let Some(rule_key) = metadata.find_rule("complexity", rule_name) {
+                    if let Some(possible_options) = &rule_options.options {
                         let rule_options = possible_options.extract_option(&rule_key);
                         analyzer_rules.push_rule(rule_key, rule_options);
                     }
+                    if let Some(fix) = rule_options.fix {
+                        analyzer_rules.push_rule_applicability(rule_key, fix.into());
+                    }
                 }
             }
         }
@@ -41,11 +47,14 @@ pub(crate) fn push_to_analyzer_rules(
             if let Some(RuleConfiguration::WithOptions(rule_options)) =
                 rules.get_rule_configuration(rule_name)
             {
-                if let Some(possible_options) = &rule_options.options {
-                    if let Some(rule_key) = metadata.find_rule("correctness", rule_name) {
+                if let Some(rule_key) = metadata.find_rule("correctness", rule_name) {
+                    if let Some(possible_options) = &rule_options.options {
                         let rule_options = possible_options.extract_option(&rule_key);
                         analyzer_rules.push_rule(rule_key, rule_options);
                     }
+                    if let Some(fix) = rule_options.fix {
+                        analyzer_rules.push_rule_applicability(rule_key, fix.into());
+                    }
                 }
             }
         }
@@ -55,11 +64,14 @@ pub(crate) fn push_to_analyzer_rules(
             if let Some(RuleConfiguration::WithOptions(rule_options)) =
                 rules.get_rule_configuration(rule_name)
             {
-                if let Some(possible_options) = &rule_options.options {
-                    if let Some(rule_key) = metadata.find_rule("nursery", rule_name) {
+                if let Some(rule_key) = metadata.find_rule("nursery", rule_name) {
+                    if let Some(possible_options) = &rule_options.options {
                         let rule_options = possible_options.extract_option(&rule_key);
                         analyzer_rules.push_rule(rule_key, rule_options);
                     }
+                    if let Some(fix) = rule_options.fix {
+                        analyzer_rules.push_rule_applicability(rule_key, fix.into());
+                    }
                 }
             }
         }
@@ -69,11 +81,14 @@ pub(crate) fn push_to_analyzer_rules(
             if let Some(RuleConfiguration::WithOptions(rule_options)) =
                 rules.get_rule_configuration(rule_name)
             {
-                if let Some(possible_options) = &rule_options.options {
-                    if let Some(rule_key) = metadata.find_rule("performance", rule_name) {
+                if let Some(rule_key) = metadata.find_rule("performance", rule_name) {
+                    if let Some(possible_options) = &rule_options.options {
                         let rule_options = possible_options.extract_option(&rule_key);
                         analyzer_rules.push_rule(rule_key, rule_options);
                     }
+                    if let Some(fix) = rule_options.fix {
+                        analyzer_rules.push_rule_applicability(rule_key, fix.into());
+                    }
                 }
             }
         }
@@ -83,11 +98,14 @@ pub(crate) fn push_to_analyzer_rules(
             if let Some(RuleConfiguration::WithOptions(rule_options)) =
                 rules.get_rule_configuration(rule_name)
             {
-                if let Some(possible_options) = &rule_options.options {
-                    if let Some(rule_key) = metadata.find_rule("security", rule_name) {
+                if let Some(rule_key) = metadata.find_rule("security", rule_name) {
+                    if let Some(possible_options) = &rule_options.options {
                         let rule_options = possible_options.extract_option(&rule_key);
                         analyzer_rules.push_rule(rule_key, rule_options);
                     }
+                    if let Some(fix) = rule_options.fix {
+                        analyzer_rules.push_rule_applicability(rule_key, fix.into());
+                    }
                 }
             }
         }
@@ -97,11 +115,14 @@ pub(crate) fn push_to_analyzer_rules(
             if let Some(RuleConfiguration::WithOptions(rule_options)) =
                 rules.get_rule_configuration(rule_name)
             {
-                if let Some(possible_options) = &rule_options.options {
-                    if let Some(rule_key) = metadata.find_rule("style", rule_name) {
+                if let Some(rule_key) = metadata.find_rule("style", rule_name) {
+                    if let Some(possible_options) = &rule_options.options {
                         let rule_options = possible_options.extract_option(&rule_key);
                         analyzer_rules.push_rule(rule_key, rule_options);
                     }
+                    if let Some(fix) = rule_options.fix {
+                        analyzer_rules.push_rule_applicability(rule_key, fix.into());
+                    }
                 }
             }
         }
@@ -111,11 +132,14 @@ pub(crate) fn push_to_analyzer_rules(
             if let Some(RuleConfiguration::WithOptions(rule_options)) =
                 rules.get_rule_configuration(rule_name)
             {
-                if let Some(possible_options) = &rule_options.options {
-                    if let Some(rule_key) = metadata.find_rule("suspicious", rule_name) {
+                if let Some(rule_key) = metadata.find_rule("suspicious", rule_name) {
+                    if let Some(possible_options) = &rule_options.options {
                         let rule_options = possible_options.extract_option(&rule_key);
                         analyzer_rules.push_rule(rule_key, rule_options);
                     }
+                    if let Some(fix) = rule_options.fix {
+                        analyzer_rules.push_rule_applicability(rule_key, fix.into());
+                    }
                 }
             }
         }
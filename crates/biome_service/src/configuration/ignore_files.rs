@@ -0,0 +1,368 @@
+use crate::configuration::nested::{absolute_path, normalize_path};
+use crate::matcher::{MatchOptions, Pattern, PatternError};
+use crate::{ConfigurationDiagnostic, WorkspaceError};
+use biome_diagnostics::Error;
+use biome_fs::{FileSystem, OpenOptions};
+use rustc_hash::FxHashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The name of the files that list the paths that Biome ignores
+pub const IGNORE_FILE_NAME: &str = ".biomeignore";
+
+/// The name of the ignore files of git, which are also used by Jujutsu
+pub const VCS_IGNORE_FILE_NAME: &str = ".gitignore";
+
+/// The file of a git repository that lists the paths ignored only in the local clone
+pub const VCS_EXCLUDE_FILE_NAME: &str = ".git/info/exclude";
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// The `.biomeignore` files found in the directory of the root configuration and in its
+/// subdirectories, and the `.gitignore` files of the VCS when they are used.
+///
+/// An ignore file uses the syntax of `.gitignore`, and its patterns are relative to its
+/// directory. A path is ignored when it's matched by the ignore file of one of its parent
+/// directories, in addition to the option `files.ignore` of the configuration.
+///
+/// The directories are loaded lazily, while the file system is traversed.
+#[derive(Debug)]
+pub struct IgnoreFiles {
+    /// The directory of the root configuration, `.biomeignore` files are searched only
+    /// inside it
+    root_directory: PathBuf,
+    /// The working directory, used to resolve relative paths
+    working_directory: Option<PathBuf>,
+    /// The directory of the root ignore file of the VCS, the ignore files of the VCS are
+    /// searched only inside it. [None] when the ignore files of the VCS aren't used.
+    vcs_directory: Option<PathBuf>,
+    /// The patterns of the root ignore file of the VCS
+    vcs_root_patterns: Option<IgnorePatterns>,
+    /// The patterns of `.git/info/exclude`, which have a lower precedence than the patterns
+    /// of the ignore files
+    vcs_exclude_patterns: Option<IgnorePatterns>,
+    /// The directories that were already searched, along with the patterns of their ignore
+    /// files
+    directories: Mutex<FxHashMap<PathBuf, IgnoreDirectory>>,
+}
+
+/// The patterns of the ignore files of a directory
+#[derive(Debug, Default)]
+struct IgnoreDirectory {
+    /// The patterns of `.biomeignore`
+    biome: Option<IgnorePatterns>,
+    /// The patterns of `.gitignore`
+    vcs: Option<IgnorePatterns>,
+}
+
+impl IgnoreFiles {
+    pub fn new(root_directory: PathBuf, fs: &dyn FileSystem) -> Self {
+        let working_directory = fs.working_directory();
+        Self {
+            root_directory: normalize_path(&absolute_path(
+                working_directory.as_deref(),
+                &root_directory,
+            )),
+            working_directory,
+            vcs_directory: None,
+            vcs_root_patterns: None,
+            vcs_exclude_patterns: None,
+            directories: Mutex::default(),
+        }
+    }
+
+    /// Also applies the `.gitignore` files of the VCS, with the semantics of git.
+    ///
+    /// `root_ignore_file` is the content of the ignore file of `vcs_directory`, the ignore
+    /// files of its subdirectories are loaded while the file system is traversed.
+    /// `exclude_file` is the directory and the content of `.git/info/exclude`.
+    pub fn use_vcs_ignore_files(
+        &mut self,
+        vcs_directory: &Path,
+        root_ignore_file: Option<&str>,
+        exclude_file: Option<(&Path, &str)>,
+    ) -> Result<(), WorkspaceError> {
+        let vcs_directory = self.normalize(vcs_directory);
+        self.vcs_root_patterns = root_ignore_file
+            .map(|content| IgnorePatterns::parse(vcs_directory.clone(), content))
+            .transpose()?;
+        self.vcs_exclude_patterns = exclude_file
+            .map(|(directory, content)| IgnorePatterns::parse(self.normalize(directory), content))
+            .transpose()?;
+        self.vcs_directory = Some(vcs_directory);
+        Ok(())
+    }
+
+    /// Loads the ignore files of `directory` and of its parent directories that weren't
+    /// searched yet.
+    ///
+    /// Returns the diagnostics emitted while loading the ignore files.
+    pub fn load(&self, fs: &dyn FileSystem, directory: &Path) -> Vec<Error> {
+        let directory = self.normalize(directory);
+        let mut directories = self.directories.lock().unwrap();
+        let mut diagnostics = vec![];
+        for ancestor in directory.ancestors() {
+            if !self.contains(ancestor) || directories.contains_key(ancestor) {
+                break;
+            }
+            let mut ignore_directory = IgnoreDirectory::default();
+            if ancestor.starts_with(&self.root_directory) {
+                match load_ignore_file(fs, ancestor, IGNORE_FILE_NAME) {
+                    Ok(patterns) => ignore_directory.biome = patterns,
+                    Err(error) => diagnostics.push(error.into()),
+                }
+            }
+            // The root ignore file of the VCS was already loaded
+            let is_vcs_subdirectory = self
+                .vcs_directory
+                .as_deref()
+                .map_or(false, |vcs_directory| {
+                    ancestor.starts_with(vcs_directory) && ancestor != vcs_directory
+                });
+            if is_vcs_subdirectory {
+                match load_ignore_file(fs, ancestor, VCS_IGNORE_FILE_NAME) {
+                    Ok(patterns) => ignore_directory.vcs = patterns,
+                    Err(error) => diagnostics.push(error.into()),
+                }
+            }
+            directories.insert(ancestor.to_path_buf(), ignore_directory);
+        }
+
+        diagnostics
+    }
+
+    /// Whether `path` is ignored by the ignore files of its parent directories. The
+    /// directories must have been loaded with [IgnoreFiles::load].
+    ///
+    /// Like git, a path inside an ignored directory is ignored, even if one of the patterns
+    /// re-includes it.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        let path = self.normalize(path);
+        let directories = self.directories.lock().unwrap();
+        path.ancestors()
+            .skip(1)
+            .take_while(|ancestor| self.contains(ancestor))
+            .any(|ancestor| self.matches(&directories, ancestor, true))
+            || self.matches(&directories, &path, is_dir)
+    }
+
+    /// Whether `path` is ignored by the `.biomeignore` files or by the ignore files of the
+    /// VCS. In each case, the last pattern of the deepest ignore file that matches the path
+    /// decides whether it's ignored.
+    fn matches(
+        &self,
+        directories: &FxHashMap<PathBuf, IgnoreDirectory>,
+        path: &Path,
+        is_dir: bool,
+    ) -> bool {
+        let ignore_directories = || {
+            path.ancestors()
+                .skip(1)
+                .filter_map(|ancestor| directories.get(ancestor))
+        };
+        let is_ignored_by_biome = ignore_directories()
+            .filter_map(|directory| directory.biome.as_ref())
+            .find_map(|patterns| patterns.matches(path, is_dir));
+        if is_ignored_by_biome == Some(true) {
+            return true;
+        }
+        let is_ignored_by_vcs = ignore_directories()
+            .filter_map(|directory| directory.vcs.as_ref())
+            .chain(self.vcs_root_patterns.as_ref())
+            .chain(self.vcs_exclude_patterns.as_ref())
+            .find_map(|patterns| patterns.matches(path, is_dir));
+        is_ignored_by_vcs == Some(true)
+    }
+
+    /// Whether `path` is inside a directory where ignore files are searched
+    fn contains(&self, path: &Path) -> bool {
+        path.starts_with(&self.root_directory)
+            || self
+                .vcs_directory
+                .as_deref()
+                .map_or(false, |vcs_directory| path.starts_with(vcs_directory))
+    }
+
+    fn normalize(&self, path: &Path) -> PathBuf {
+        normalize_path(&absolute_path(self.working_directory.as_deref(), path))
+    }
+}
+
+/// The patterns of an ignore file, relative to its directory
+#[derive(Debug)]
+struct IgnorePatterns {
+    directory: PathBuf,
+    patterns: Vec<IgnorePattern>,
+}
+
+impl IgnorePatterns {
+    fn parse(directory: PathBuf, content: &str) -> Result<Self, WorkspaceError> {
+        let mut patterns = vec![];
+        for line in content.lines() {
+            match IgnorePattern::parse(line) {
+                Ok(Some(pattern)) => patterns.push(pattern),
+                Ok(None) => {}
+                Err(err) => {
+                    return Err(WorkspaceError::Configuration(
+                        ConfigurationDiagnostic::new_invalid_ignore_pattern(
+                            line.to_string(),
+                            err.msg.to_string(),
+                        ),
+                    ))
+                }
+            }
+        }
+        Ok(Self {
+            directory,
+            patterns,
+        })
+    }
+
+    /// Returns whether `path` is ignored according to the last pattern that matches it, or
+    /// [None] when no pattern matches it
+    fn matches(&self, path: &Path, is_dir: bool) -> Option<bool> {
+        let relative_path = path.strip_prefix(&self.directory).ok()?;
+        if relative_path.as_os_str().is_empty() {
+            return None;
+        }
+        self.patterns
+            .iter()
+            .rev()
+            .find(|pattern| pattern.matches(relative_path, is_dir))
+            .map(|pattern| !pattern.negated)
+    }
+}
+
+/// A line of an ignore file
+#[derive(Debug)]
+struct IgnorePattern {
+    pattern: Pattern,
+    /// Whether the pattern starts with `!`, and re-includes the paths ignored by a previous
+    /// pattern
+    negated: bool,
+    /// Whether the pattern contains a `/` before its end, and only matches paths relative
+    /// to the directory of the ignore file. The other patterns match the name of a file
+    /// at any depth.
+    anchored: bool,
+    /// Whether the pattern ends with `/`, and only matches directories
+    directory_only: bool,
+}
+
+impl IgnorePattern {
+    /// Parses a line of an ignore file, or returns [None] when the line is empty or is a
+    /// comment
+    fn parse(line: &str) -> Result<Option<Self>, PatternError> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+        let (negated, pattern) = match line.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, line),
+        };
+        // A pattern that starts with `\` escapes a leading `#` or `!`
+        let pattern = pattern.strip_prefix('\\').unwrap_or(pattern);
+        let (directory_only, pattern) = match pattern.strip_suffix('/') {
+            Some(pattern) => (true, pattern),
+            None => (false, pattern),
+        };
+        let anchored = pattern.contains('/');
+        let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+        if pattern.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            pattern: Pattern::new(pattern)?,
+            negated,
+            anchored,
+            directory_only,
+        }))
+    }
+
+    fn matches(&self, relative_path: &Path, is_dir: bool) -> bool {
+        if self.directory_only && !is_dir {
+            return false;
+        }
+        if self.anchored {
+            self.pattern.matches_path_with(relative_path, MATCH_OPTIONS)
+        } else {
+            relative_path.file_name().map_or(false, |file_name| {
+                self.pattern
+                    .matches_path_with(Path::new(file_name), MATCH_OPTIONS)
+            })
+        }
+    }
+}
+
+/// Loads the ignore file `file_name` of `directory`, without searching the parent directories
+fn load_ignore_file(
+    fs: &dyn FileSystem,
+    directory: &Path,
+    file_name: &str,
+) -> Result<Option<IgnorePatterns>, WorkspaceError> {
+    let file_path = directory.join(file_name);
+    let mut file = match fs.open_with_options(&file_path, OpenOptions::default().read(true)) {
+        Ok(file) => file,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(_) => {
+            return Err(WorkspaceError::cant_read_file(
+                file_path.display().to_string(),
+            ))
+        }
+    };
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .map_err(|_| WorkspaceError::cant_read_file(file_path.display().to_string()))?;
+
+    IgnorePatterns::parse(directory.to_path_buf(), &content).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IgnorePattern, IgnorePatterns};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn parses_ignore_file_lines() {
+        let pattern = IgnorePattern::parse("/build/  ").unwrap().unwrap();
+        assert_eq!(pattern.pattern.as_str(), "build");
+        assert!(pattern.anchored && pattern.directory_only && !pattern.negated);
+
+        let pattern = IgnorePattern::parse("!src/keep.js").unwrap().unwrap();
+        assert_eq!(pattern.pattern.as_str(), "src/keep.js");
+        assert!(pattern.anchored && !pattern.directory_only && pattern.negated);
+
+        let pattern = IgnorePattern::parse("\\#file.js").unwrap().unwrap();
+        assert_eq!(pattern.pattern.as_str(), "#file.js");
+        assert!(!pattern.anchored);
+
+        assert!(IgnorePattern::parse("# comment").unwrap().is_none());
+        assert!(IgnorePattern::parse("").unwrap().is_none());
+        assert!(IgnorePattern::parse("/").unwrap().is_none());
+    }
+
+    #[test]
+    fn matches_with_git_semantics() {
+        let patterns = IgnorePatterns::parse(
+            PathBuf::from("project"),
+            "*.log\n!keep.log\n/dist\nout/\ndocs/*.md\n",
+        )
+        .unwrap();
+        let matches = |path: &str, is_dir: bool| patterns.matches(Path::new(path), is_dir);
+
+        assert_eq!(matches("project/a/debug.log", false), Some(true));
+        assert_eq!(matches("project/a/keep.log", false), Some(false));
+        assert_eq!(matches("project/dist", true), Some(true));
+        assert_eq!(matches("project/a/dist", true), None);
+        assert_eq!(matches("project/a/out", true), Some(true));
+        assert_eq!(matches("project/a/out", false), None);
+        assert_eq!(matches("project/docs/index.md", false), Some(true));
+        assert_eq!(matches("project/docs/api/index.md", false), None);
+        assert_eq!(matches("other/debug.log", false), None);
+    }
+}
@@ -1,9 +1,12 @@
 use crate::configuration::PlainIndentStyle;
 use crate::configuration::{deserialize_line_width, serialize_line_width};
 use crate::MergeWith;
+use biome_deserialize::StringSet;
 use biome_formatter::{LineEnding, LineWidth};
 use biome_js_formatter::context::trailing_comma::TrailingComma;
-use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, QuoteStyle, Semicolons};
+use biome_js_formatter::context::{
+    ArrowParentheses, DecoratorPlacement, QuoteProperties, QuoteStyle, Semicolons,
+};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 
@@ -44,6 +47,22 @@ pub struct JavascriptFormatter {
     #[bpaf(long("bracket-same-line"), argument("true|false"), optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bracket_same_line: Option<bool>,
+    /// Whether the decorators of class members are printed on their own line or on the same line as the member. Defaults to "preserve".
+    #[bpaf(
+        long("decorator-placement"),
+        argument("preserve|same-line|own-line"),
+        optional
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decorator_placement: Option<DecoratorPlacement>,
+    /// The tags of the template literals whose content is reindented to follow the indentation of the code, such as `dedent` or `outdent`. Defaults to none.
+    #[bpaf(hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dedent_tags: Option<StringSet>,
+    /// The tags of the template literals whose content is formatted as SQL, such as `sql`. Defaults to none.
+    #[bpaf(hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql_tags: Option<StringSet>,
 
     /// Control the formatter for JavaScript (and its super languages) files.
     #[bpaf(long("javascript-formatter-enabled"), argument("true|false"), optional)]
@@ -77,7 +96,7 @@ pub struct JavascriptFormatter {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(
         long("javascript-formatter-line-ending"),
-        argument("lf|crlf|cr"),
+        argument("lf|crlf|cr|auto"),
         optional
     )]
     pub line_ending: Option<LineEnding>,
@@ -103,6 +122,15 @@ impl MergeWith<JavascriptFormatter> for JavascriptFormatter {
         if let Some(bracket_same_line) = other.bracket_same_line {
             self.bracket_same_line = Some(bracket_same_line);
         }
+        if let Some(decorator_placement) = other.decorator_placement {
+            self.decorator_placement = Some(decorator_placement);
+        }
+        if let Some(dedent_tags) = other.dedent_tags {
+            self.dedent_tags = Some(dedent_tags);
+        }
+        if let Some(sql_tags) = other.sql_tags {
+            self.sql_tags = Some(sql_tags);
+        }
         if let Some(quote_properties) = other.quote_properties {
             self.quote_properties = Some(quote_properties);
         }
@@ -2,9 +2,12 @@ mod formatter;
 
 pub use crate::configuration::javascript::formatter::{javascript_formatter, JavascriptFormatter};
 use crate::configuration::merge::MergeWith;
+use biome_analyze::GlobalAccess;
 use biome_deserialize::StringSet;
 use bpaf::Bpaf;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// A set of options applied to the JavaScript files
 #[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Bpaf)]
@@ -21,12 +24,21 @@ pub struct JavascriptConfiguration {
     #[bpaf(external(javascript_parser), optional)]
     pub parser: Option<JavascriptParser>,
 
-    /// A list of global bindings that should be ignored by the analyzers
+    /// The global bindings that should be ignored by the analyzers
     ///
     /// If defined here, they should not emit diagnostics.
+    /// It's either a list of writable global bindings, or an object that maps each global binding
+    /// to `"readonly"`, `"writable"` or `"off"`.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
-    pub globals: Option<StringSet>,
+    pub globals: Option<JavascriptGlobals>,
+
+    /// The environments that provide global bindings, e.g. `browser`, `node` or `es2024`.
+    ///
+    /// When it's not set, the global bindings of the browser and Node.js are available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub environments: Option<JavascriptEnvironments>,
     //
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(javascript_organize_imports), optional)]
@@ -97,6 +109,165 @@ impl JavascriptConfiguration {
 #[serde(default, deny_unknown_fields)]
 pub struct JavascriptOrganizeImports {}
 
+/// The global bindings declared in the configuration
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum JavascriptGlobals {
+    /// A list of writable global bindings
+    List(StringSet),
+    /// The global bindings, along with how they can be used
+    Map(IndexMap<String, JavascriptGlobalAccess>),
+}
+
+impl Default for JavascriptGlobals {
+    fn default() -> Self {
+        Self::List(StringSet::default())
+    }
+}
+
+impl FromStr for JavascriptGlobals {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+impl JavascriptGlobals {
+    /// Returns the global bindings along with their access
+    pub fn into_bindings(self) -> IndexMap<String, GlobalAccess> {
+        match self {
+            JavascriptGlobals::List(names) => names
+                .into_index_set()
+                .into_iter()
+                .map(|name| (name, GlobalAccess::Writable))
+                .collect(),
+            JavascriptGlobals::Map(globals) => globals
+                .into_iter()
+                .map(|(name, access)| (name, access.into()))
+                .collect(),
+        }
+    }
+}
+
+/// How a global binding can be used
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum JavascriptGlobalAccess {
+    /// The global binding can be read, but not assigned
+    Readonly,
+    /// The global binding can be read and assigned
+    Writable,
+    /// The global binding isn't available, even if an environment provides it
+    Off,
+}
+
+impl FromStr for JavascriptGlobalAccess {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "readonly" => Ok(Self::Readonly),
+            "writable" => Ok(Self::Writable),
+            "off" => Ok(Self::Off),
+            _ => Err("Value not supported for JavascriptGlobalAccess"),
+        }
+    }
+}
+
+impl From<JavascriptGlobalAccess> for GlobalAccess {
+    fn from(access: JavascriptGlobalAccess) -> Self {
+        match access {
+            JavascriptGlobalAccess::Readonly => GlobalAccess::Readonly,
+            JavascriptGlobalAccess::Writable => GlobalAccess::Writable,
+            JavascriptGlobalAccess::Off => GlobalAccess::Off,
+        }
+    }
+}
+
+/// A list of environments that provide global bindings
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JavascriptEnvironments(pub Vec<JavascriptEnvironment>);
+
+impl FromStr for JavascriptEnvironments {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+/// An environment that provides global bindings
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum JavascriptEnvironment {
+    /// The global bindings of the browsers, e.g. `window` or `document`
+    Browser,
+    /// The global bindings of Node.js, e.g. `process` or `require`
+    Node,
+    /// The global bindings of CommonJS modules, e.g. `module` or `require`
+    Commonjs,
+    /// The global bindings of web workers, e.g. `importScripts`
+    Worker,
+    /// The global bindings of service workers, e.g. `clients`
+    ServiceWorker,
+    /// The builtins of ECMAScript 5
+    Es5,
+    /// The builtins of ECMAScript 2015, e.g. `Promise` or `Map`
+    Es2015,
+    /// The builtins of ECMAScript 2017, e.g. `SharedArrayBuffer`
+    Es2017,
+    /// The builtins of ECMAScript 2020, e.g. `BigInt` or `globalThis`
+    Es2020,
+    /// The builtins of ECMAScript 2021, e.g. `WeakRef`
+    Es2021,
+    /// The builtins of ECMAScript 2024
+    Es2024,
+}
+
+impl JavascriptEnvironment {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            JavascriptEnvironment::Browser => "browser",
+            JavascriptEnvironment::Node => "node",
+            JavascriptEnvironment::Commonjs => "commonjs",
+            JavascriptEnvironment::Worker => "worker",
+            JavascriptEnvironment::ServiceWorker => "serviceWorker",
+            JavascriptEnvironment::Es5 => "es5",
+            JavascriptEnvironment::Es2015 => "es2015",
+            JavascriptEnvironment::Es2017 => "es2017",
+            JavascriptEnvironment::Es2020 => "es2020",
+            JavascriptEnvironment::Es2021 => "es2021",
+            JavascriptEnvironment::Es2024 => "es2024",
+        }
+    }
+}
+
+impl FromStr for JavascriptEnvironment {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "browser" => Ok(Self::Browser),
+            "node" => Ok(Self::Node),
+            "commonjs" => Ok(Self::Commonjs),
+            "worker" => Ok(Self::Worker),
+            "serviceWorker" => Ok(Self::ServiceWorker),
+            "es5" => Ok(Self::Es5),
+            "es2015" => Ok(Self::Es2015),
+            "es2017" => Ok(Self::Es2017),
+            "es2020" => Ok(Self::Es2020),
+            "es2021" => Ok(Self::Es2021),
+            "es2024" => Ok(Self::Es2024),
+            _ => Err("Value not supported for JavascriptEnvironment"),
+        }
+    }
+}
+
 /// Options that changes how the JavaScript parser behaves
 #[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
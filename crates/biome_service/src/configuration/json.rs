@@ -1,6 +1,7 @@
 use crate::configuration::merge::MergeWith;
 use crate::configuration::{deserialize_line_width, serialize_line_width, PlainIndentStyle};
 use biome_formatter::{LineEnding, LineWidth};
+use biome_json_formatter::context::ArrayFill;
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 
@@ -103,7 +104,11 @@ pub struct JsonFormatter {
 
     /// The type of line ending applied to JSON (and its super languages) files.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[bpaf(long("json-formatter-line-ending"), argument("lf|crlf|cr"), optional)]
+    #[bpaf(
+        long("json-formatter-line-ending"),
+        argument("lf|crlf|cr|auto"),
+        optional
+    )]
     pub line_ending: Option<LineEnding>,
 
     /// What's the max width of a line applied to JSON (and its super languages) files. Defaults to 80.
@@ -114,6 +119,15 @@ pub struct JsonFormatter {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("json-formatter-line-width"), argument("NUMBER"), optional)]
     pub line_width: Option<LineWidth>,
+
+    /// Which arrays are printed in fill mode, with as many elements as possible on each line. Defaults to "numbers".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(
+        long("json-formatter-array-fill"),
+        argument("numbers|literals|never"),
+        optional
+    )]
+    pub array_fill: Option<ArrayFill>,
 }
 
 impl MergeWith<JsonFormatter> for JsonFormatter {
@@ -130,6 +144,9 @@ impl MergeWith<JsonFormatter> for JsonFormatter {
         if let Some(line_width) = other.line_width {
             self.line_width = Some(line_width);
         }
+        if let Some(array_fill) = other.array_fill {
+            self.array_fill = Some(array_fill);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: JsonFormatter)
@@ -6,15 +6,23 @@ use crate::configuration::merge::MergeWith;
 use crate::configuration::overrides::OverrideLinterConfiguration;
 use crate::settings::{to_matcher, LinterSettings};
 use crate::WorkspaceError;
+use biome_analyze::{
+    GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleFilter, RuleGroup,
+    RuleMetadata,
+};
 use biome_deserialize::StringSet;
-use biome_diagnostics::Severity;
+use biome_diagnostics::{Applicability, Severity};
 use biome_js_analyze::options::{possible_options, PossibleOptions};
+use biome_js_syntax::JsLanguage;
+use biome_json_syntax::JsonLanguage;
 use bpaf::Bpaf;
 pub use rules::*;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::num::NonZeroU64;
 use std::str::FromStr;
+use std::time::Duration;
 
 #[derive(Deserialize, Serialize, Debug, Clone, Bpaf, Eq, PartialEq)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -41,6 +49,20 @@ pub struct LinterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub include: Option<StringSet>,
+
+    /// The maximum amount of time, in milliseconds, the linter can spend analyzing a single file.
+    /// When it's exceeded, the analysis of the file stops and a diagnostic reports the rule
+    /// that was running. By default, there's no limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("linter-timeout"), argument("NUMBER"), optional)]
+    pub timeout: Option<NonZeroU64>,
+
+    /// When `true`, the suppression comments must explain why the lints are disabled, e.g.
+    /// `// biome-ignore lint/suspicious/noDebugger: needed to debug the tests`. The
+    /// suppression comments without an explanation are reported. `false` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub require_suppression_reasons: Option<bool>,
 }
 
 impl MergeWith<LinterConfiguration> for LinterConfiguration {
@@ -48,6 +70,12 @@ impl MergeWith<LinterConfiguration> for LinterConfiguration {
         if let Some(enabled) = other.enabled {
             self.enabled = Some(enabled);
         }
+        if let Some(timeout) = other.timeout {
+            self.timeout = Some(timeout);
+        }
+        if let Some(require_suppression_reasons) = other.require_suppression_reasons {
+            self.require_suppression_reasons = Some(require_suppression_reasons);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: LinterConfiguration)
@@ -73,6 +101,8 @@ impl Default for LinterConfiguration {
             rules: Some(Rules::default()),
             ignore: None,
             include: None,
+            timeout: None,
+            require_suppression_reasons: None,
         }
     }
 }
@@ -86,6 +116,10 @@ impl TryFrom<LinterConfiguration> for LinterSettings {
             rules: conf.rules,
             ignored_files: to_matcher(conf.ignore.as_ref())?,
             included_files: to_matcher(conf.include.as_ref())?,
+            timeout: conf
+                .timeout
+                .map(|timeout| Duration::from_millis(timeout.get())),
+            require_suppression_reasons: conf.require_suppression_reasons.unwrap_or_default(),
         })
     }
 }
@@ -99,6 +133,8 @@ impl TryFrom<OverrideLinterConfiguration> for LinterSettings {
             rules: conf.rules,
             ignored_files: None,
             included_files: None,
+            timeout: None,
+            require_suppression_reasons: false,
         })
     }
 }
@@ -163,8 +199,10 @@ impl From<&RulePlainConfiguration> for Severity {
     fn from(conf: &RulePlainConfiguration) -> Self {
         match conf {
             RulePlainConfiguration::Warn => Severity::Warning,
-            RulePlainConfiguration::Error => Severity::Error,
-            _ => unreachable!("the rule is turned off, it should not step in here"),
+            RulePlainConfiguration::Info => Severity::Information,
+            RulePlainConfiguration::Hint => Severity::Hint,
+            // A rule turned off only runs when it's selected from the CLI, e.g. with `--rule`
+            RulePlainConfiguration::Error | RulePlainConfiguration::Off => Severity::Error,
         }
     }
 }
@@ -176,6 +214,12 @@ pub enum RulePlainConfiguration {
     #[default]
     Warn,
     Error,
+    /// The diagnostics of the rule are reported as information, they don't fail the
+    /// commands by default
+    Info,
+    /// The diagnostics of the rule are reported as hints, which are shown by the editors but
+    /// not printed by the CLI by default
+    Hint,
     Off,
 }
 
@@ -186,6 +230,8 @@ impl FromStr for RulePlainConfiguration {
         match s {
             "warn" => Ok(Self::Warn),
             "error" => Ok(Self::Error),
+            "info" => Ok(Self::Info),
+            "hint" => Ok(Self::Hint),
             "off" => Ok(Self::Off),
             _ => Err("Invalid configuration for rule".to_string()),
         }
@@ -200,6 +246,11 @@ pub struct RuleWithOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(possible_options), hide, optional)]
     pub options: Option<PossibleOptions>,
+    /// Overrides the kind of the fix of the rule: a safe fix is applied by `--apply`, while an
+    /// unsafe fix is only applied by `--apply-unsafe`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub fix: Option<RuleFixKind>,
 }
 
 impl FromStr for RuleWithOptions {
@@ -208,6 +259,170 @@ impl FromStr for RuleWithOptions {
         Ok(Self {
             level: RulePlainConfiguration::default(),
             options: None,
+            fix: None,
+        })
+    }
+}
+
+/// The kind of the fix of a rule
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Copy, Bpaf)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum RuleFixKind {
+    /// The fix is applied by `--apply`
+    Safe,
+    /// The fix is only applied by `--apply-unsafe`
+    Unsafe,
+}
+
+impl FromStr for RuleFixKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "safe" => Ok(Self::Safe),
+            "unsafe" => Ok(Self::Unsafe),
+            _ => Err("Invalid fix kind for rule".to_string()),
+        }
+    }
+}
+
+impl From<RuleFixKind> for Applicability {
+    fn from(kind: RuleFixKind) -> Self {
+        match kind {
+            RuleFixKind::Safe => Applicability::Always,
+            RuleFixKind::Unsafe => Applicability::MaybeIncorrect,
+        }
+    }
+}
+
+/// A rule selected from the CLI, using the syntax `<group>/<rule>`, e.g. `suspicious/noDebugger`
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct RuleSelector {
+    pub group: String,
+    pub rule: String,
+}
+
+impl RuleSelector {
+    /// Returns the filter of the analyzers that matches the selected rule, or [None] if the rule
+    /// doesn't exist
+    pub fn as_rule_filter(&self) -> Option<RuleFilter<'static>> {
+        let rule_key = biome_js_analyze::metadata()
+            .find_rule(&self.group, &self.rule)
+            .or_else(|| biome_json_analyze::metadata().find_rule(&self.group, &self.rule))?;
+        Some(RuleFilter::Rule(rule_key.group(), rule_key.rule_name()))
+    }
+}
+
+impl FromStr for RuleSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((group, rule)) = s.split_once('/') else {
+            return Err(format!(
+                "The rule {s} must follow the syntax <group>/<rule>, e.g. suspicious/noDebugger"
+            ));
+        };
+        let selector = Self {
+            group: group.to_string(),
+            rule: rule.to_string(),
+        };
+        if selector.as_rule_filter().is_none() {
+            return Err(format!("The rule {s} doesn't exist"));
+        }
+        Ok(selector)
+    }
+}
+
+impl std::fmt::Display for RuleSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.group, self.rule)
+    }
+}
+
+/// The documentation of a lint rule, as embedded in the binary
+pub struct RuleDocumentation {
+    /// The group of the rule
+    pub group: &'static str,
+    pub metadata: RuleMetadata,
+    /// The default options of the rule, or [None] if the rule doesn't accept options
+    pub default_options: Option<PossibleOptions>,
+}
+
+impl RuleDocumentation {
+    /// Returns the documentation of the rule called `name`. The name can be prefixed by
+    /// the group of the rule, e.g. `suspicious/noDebugger`.
+    pub fn find(name: &str) -> Option<Self> {
+        let (group, rule) = match name.trim_start_matches("lint/").split_once('/') {
+            Some((group, rule)) => (Some(group), rule),
+            None => (None, name),
+        };
+        Self::all().into_iter().find(|documentation| {
+            documentation.metadata.name == rule
+                && group.map_or(true, |group| group == documentation.group)
         })
     }
+
+    /// Returns the documentation of all the lint rules
+    pub fn all() -> Vec<Self> {
+        let mut visitor = RuleDocumentationVisitor::default();
+        biome_js_analyze::visit_registry(&mut visitor);
+        biome_json_analyze::visit_registry(&mut visitor);
+        visitor.documentations
+    }
+
+    /// Returns the filter that matches this rule
+    pub fn as_rule_filter(&self) -> RuleFilter<'static> {
+        RuleFilter::Rule(self.group, self.metadata.name)
+    }
+}
+
+#[derive(Default)]
+struct RuleDocumentationVisitor {
+    documentations: Vec<RuleDocumentation>,
+}
+
+impl RuleDocumentationVisitor {
+    fn record<R: Rule>(&mut self) {
+        self.documentations.push(RuleDocumentation {
+            group: <R::Group as RuleGroup>::NAME,
+            metadata: R::METADATA,
+            default_options: PossibleOptions::default_for_rule(R::METADATA.name),
+        });
+    }
+}
+
+impl RegistryVisitor<JsLanguage> for RuleDocumentationVisitor {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.record::<R>();
+    }
+}
+
+impl RegistryVisitor<JsonLanguage> for RuleDocumentationVisitor {
+    fn record_category<C: GroupCategory<Language = JsonLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = JsonLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.record::<R>();
+    }
 }
@@ -1,5 +1,6 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+use crate::configuration::linter::RulePlainConfiguration;
 use crate::{MergeWith, RuleConfiguration};
 use biome_analyze::RuleFilter;
 use biome_diagnostics::{Category, Severity};
@@ -149,8 +150,7 @@ impl Rules {
                 "a11y" => self
                     .a11y
                     .as_ref()
-                    .and_then(|a11y| a11y.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|a11y| a11y.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if A11y::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -161,8 +161,7 @@ impl Rules {
                 "complexity" => self
                     .complexity
                     .as_ref()
-                    .and_then(|complexity| complexity.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|complexity| complexity.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Complexity::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -173,8 +172,7 @@ impl Rules {
                 "correctness" => self
                     .correctness
                     .as_ref()
-                    .and_then(|correctness| correctness.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|correctness| correctness.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Correctness::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -185,8 +183,7 @@ impl Rules {
                 "nursery" => self
                     .nursery
                     .as_ref()
-                    .and_then(|nursery| nursery.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|nursery| nursery.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Nursery::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -197,8 +194,7 @@ impl Rules {
                 "performance" => self
                     .performance
                     .as_ref()
-                    .and_then(|performance| performance.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|performance| performance.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Performance::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -209,8 +205,7 @@ impl Rules {
                 "security" => self
                     .security
                     .as_ref()
-                    .and_then(|security| security.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|security| security.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Security::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -221,8 +216,7 @@ impl Rules {
                 "style" => self
                     .style
                     .as_ref()
-                    .and_then(|style| style.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|style| style.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Style::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -233,8 +227,7 @@ impl Rules {
                 "suspicious" => self
                     .suspicious
                     .as_ref()
-                    .and_then(|suspicious| suspicious.get_rule_configuration(rule_name))
-                    .map(|rule_setting| rule_setting.into())
+                    .and_then(|suspicious| suspicious.get_rule_severity(rule_name))
                     .unwrap_or_else(|| {
                         if Suspicious::is_recommended_rule(rule_name) {
                             Severity::Error
@@ -266,6 +259,7 @@ impl Rules {
         let mut disabled_rules = IndexSet::new();
         if let Some(group) = self.a11y.as_ref() {
             group.collect_preset_rules(
+                self.all,
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -281,6 +275,7 @@ impl Rules {
         }
         if let Some(group) = self.complexity.as_ref() {
             group.collect_preset_rules(
+                self.all,
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -296,6 +291,7 @@ impl Rules {
         }
         if let Some(group) = self.correctness.as_ref() {
             group.collect_preset_rules(
+                self.all,
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -311,6 +307,7 @@ impl Rules {
         }
         if let Some(group) = self.nursery.as_ref() {
             group.collect_preset_rules(
+                self.all,
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -326,6 +323,7 @@ impl Rules {
         }
         if let Some(group) = self.performance.as_ref() {
             group.collect_preset_rules(
+                self.all,
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -341,6 +339,7 @@ impl Rules {
         }
         if let Some(group) = self.security.as_ref() {
             group.collect_preset_rules(
+                self.all,
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -356,6 +355,7 @@ impl Rules {
         }
         if let Some(group) = self.style.as_ref() {
             group.collect_preset_rules(
+                self.all,
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -371,6 +371,7 @@ impl Rules {
         }
         if let Some(group) = self.suspicious.as_ref() {
             group.collect_preset_rules(
+                self.all,
                 self.is_recommended(),
                 &mut enabled_rules,
                 &mut disabled_rules,
@@ -429,6 +430,11 @@ pub struct A11y {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r#" It enables ALL rules for this group with the given severity, or disables them with `"off"`."#]
+    #[doc = r" The rules configured individually keep their own severity."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RulePlainConfiguration>,
     #[doc = "Enforce that the accessKey attribute is not used on any HTML element."]
     #[bpaf(long("no-access-key"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -589,6 +595,9 @@ pub struct A11y {
 }
 impl MergeWith<A11y> for A11y {
     fn merge_with(&mut self, other: A11y) {
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_access_key) = other.no_access_key {
             self.no_access_key = Some(no_access_key);
         }
@@ -821,11 +830,17 @@ impl A11y {
     pub(crate) const fn is_not_recommended(&self) -> bool {
         matches!(self.recommended, Some(false))
     }
-    pub(crate) fn is_all(&self) -> bool {
-        matches!(self.all, Some(true))
-    }
-    pub(crate) fn is_not_all(&self) -> bool {
-        matches!(self.all, Some(false))
+    #[doc = r" Returns `Some(true)` when all the rules of this group are enabled, `Some(false)` when"]
+    #[doc = r" they are all disabled, and [None] when the group doesn't decide."]
+    #[doc = r""]
+    #[doc = r" The level of the group takes precedence over its `all`, and the `all` of the linter"]
+    #[doc = r" applies only when the group doesn't select its rules."]
+    pub(crate) fn is_all(&self, parent_is_all: Option<bool>) -> Option<bool> {
+        self.level
+            .as_ref()
+            .map(|level| *level != RulePlainConfiguration::Off)
+            .or(self.all)
+            .or(parent_is_all.filter(|_| self.recommended.is_none()))
     }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
@@ -1132,16 +1147,18 @@ impl A11y {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: Option<bool>,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        let is_all = self.is_all(parent_is_all);
+        if is_all == Some(true) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
         }
-        if self.is_not_all() {
+        if is_all == Some(false) {
             disabled_rules.extend(Self::all_rules_as_filters());
         } else if self.is_not_recommended() {
             disabled_rules.extend(Self::recommended_rules_as_filters());
@@ -1186,6 +1203,12 @@ impl A11y {
             _ => None,
         }
     }
+    #[doc = r" Returns the severity of the rule, from its own configuration or from the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .map(Severity::from)
+            .or_else(|| self.level.as_ref().map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -1200,6 +1223,11 @@ pub struct Complexity {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r#" It enables ALL rules for this group with the given severity, or disables them with `"off"`."#]
+    #[doc = r" The rules configured individually keep their own severity."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RulePlainConfiguration>,
     #[doc = "Disallow primitive type aliases and misleading types."]
     #[bpaf(long("no-banned-types"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1339,6 +1367,9 @@ pub struct Complexity {
 }
 impl MergeWith<Complexity> for Complexity {
     fn merge_with(&mut self, other: Complexity) {
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_banned_types) = other.no_banned_types {
             self.no_banned_types = Some(no_banned_types);
         }
@@ -1531,11 +1562,17 @@ impl Complexity {
     pub(crate) const fn is_not_recommended(&self) -> bool {
         matches!(self.recommended, Some(false))
     }
-    pub(crate) fn is_all(&self) -> bool {
-        matches!(self.all, Some(true))
-    }
-    pub(crate) fn is_not_all(&self) -> bool {
-        matches!(self.all, Some(false))
+    #[doc = r" Returns `Some(true)` when all the rules of this group are enabled, `Some(false)` when"]
+    #[doc = r" they are all disabled, and [None] when the group doesn't decide."]
+    #[doc = r""]
+    #[doc = r" The level of the group takes precedence over its `all`, and the `all` of the linter"]
+    #[doc = r" applies only when the group doesn't select its rules."]
+    pub(crate) fn is_all(&self, parent_is_all: Option<bool>) -> Option<bool> {
+        self.level
+            .as_ref()
+            .map(|level| *level != RulePlainConfiguration::Off)
+            .or(self.all)
+            .or(parent_is_all.filter(|_| self.recommended.is_none()))
     }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
@@ -1808,16 +1845,18 @@ impl Complexity {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: Option<bool>,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        let is_all = self.is_all(parent_is_all);
+        if is_all == Some(true) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
         }
-        if self.is_not_all() {
+        if is_all == Some(false) {
             disabled_rules.extend(Self::all_rules_as_filters());
         } else if self.is_not_recommended() {
             disabled_rules.extend(Self::recommended_rules_as_filters());
@@ -1854,6 +1893,12 @@ impl Complexity {
             _ => None,
         }
     }
+    #[doc = r" Returns the severity of the rule, from its own configuration or from the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .map(Severity::from)
+            .or_else(|| self.level.as_ref().map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -1868,6 +1913,11 @@ pub struct Correctness {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r#" It enables ALL rules for this group with the given severity, or disables them with `"off"`."#]
+    #[doc = r" The rules configured individually keep their own severity."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RulePlainConfiguration>,
     #[doc = "Prevent passing of children as props."]
     #[bpaf(long("no-children-prop"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2073,6 +2123,9 @@ pub struct Correctness {
 }
 impl MergeWith<Correctness> for Correctness {
     fn merge_with(&mut self, other: Correctness) {
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_children_prop) = other.no_children_prop {
             self.no_children_prop = Some(no_children_prop);
         }
@@ -2323,11 +2376,17 @@ impl Correctness {
     pub(crate) const fn is_not_recommended(&self) -> bool {
         matches!(self.recommended, Some(false))
     }
-    pub(crate) fn is_all(&self) -> bool {
-        matches!(self.all, Some(true))
-    }
-    pub(crate) fn is_not_all(&self) -> bool {
-        matches!(self.all, Some(false))
+    #[doc = r" Returns `Some(true)` when all the rules of this group are enabled, `Some(false)` when"]
+    #[doc = r" they are all disabled, and [None] when the group doesn't decide."]
+    #[doc = r""]
+    #[doc = r" The level of the group takes precedence over its `all`, and the `all` of the linter"]
+    #[doc = r" applies only when the group doesn't select its rules."]
+    pub(crate) fn is_all(&self, parent_is_all: Option<bool>) -> Option<bool> {
+        self.level
+            .as_ref()
+            .map(|level| *level != RulePlainConfiguration::Off)
+            .or(self.all)
+            .or(parent_is_all.filter(|_| self.recommended.is_none()))
     }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
@@ -2684,16 +2743,18 @@ impl Correctness {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: Option<bool>,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        let is_all = self.is_all(parent_is_all);
+        if is_all == Some(true) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
         }
-        if self.is_not_all() {
+        if is_all == Some(false) {
             disabled_rules.extend(Self::all_rules_as_filters());
         } else if self.is_not_recommended() {
             disabled_rules.extend(Self::recommended_rules_as_filters());
@@ -2737,6 +2798,12 @@ impl Correctness {
             _ => None,
         }
     }
+    #[doc = r" Returns the severity of the rule, from its own configuration or from the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .map(Severity::from)
+            .or_else(|| self.level.as_ref().map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -2751,6 +2818,11 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r#" It enables ALL rules for this group with the given severity, or disables them with `"off"`."#]
+    #[doc = r" The rules configured individually keep their own severity."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RulePlainConfiguration>,
     #[doc = "Enforce that aria-hidden=\"true\" is not set on focusable elements."]
     #[bpaf(
         long("no-aria-hidden-on-focusable"),
@@ -2782,6 +2854,10 @@ pub struct Nursery {
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_empty_block_statements: Option<RuleConfiguration>,
+    #[doc = "Disallow assignments to native objects and read-only global variables."]
+    #[bpaf(long("no-global-assign"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_global_assign: Option<RuleConfiguration>,
     #[doc = "Disallow use of implicit any type on variable declarations."]
     #[bpaf(long("no-implicit-any-let"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2867,6 +2943,9 @@ pub struct Nursery {
 }
 impl MergeWith<Nursery> for Nursery {
     fn merge_with(&mut self, other: Nursery) {
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_aria_hidden_on_focusable) = other.no_aria_hidden_on_focusable {
             self.no_aria_hidden_on_focusable = Some(no_aria_hidden_on_focusable);
         }
@@ -2879,6 +2958,9 @@ impl MergeWith<Nursery> for Nursery {
         if let Some(no_empty_block_statements) = other.no_empty_block_statements {
             self.no_empty_block_statements = Some(no_empty_block_statements);
         }
+        if let Some(no_global_assign) = other.no_global_assign {
+            self.no_global_assign = Some(no_global_assign);
+        }
         if let Some(no_implicit_any_let) = other.no_implicit_any_let {
             self.no_implicit_any_let = Some(no_implicit_any_let);
         }
@@ -2930,11 +3012,12 @@ impl MergeWith<Nursery> for Nursery {
 }
 impl Nursery {
     const GROUP_NAME: &'static str = "nursery";
-    pub(crate) const GROUP_RULES: [&'static str; 17] = [
+    pub(crate) const GROUP_RULES: [&'static str; 18] = [
         "noAriaHiddenOnFocusable",
         "noDefaultExport",
         "noDuplicateJsonKeys",
         "noEmptyBlockStatements",
+        "noGlobalAssign",
         "noImplicitAnyLet",
         "noMisleadingCharacterClass",
         "noUnusedImports",
@@ -2961,13 +3044,13 @@ impl Nursery {
     const RECOMMENDED_RULES_AS_FILTERS: [RuleFilter<'static>; 7] = [
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]),
     ];
-    const ALL_RULES_AS_FILTERS: [RuleFilter<'static>; 17] = [
+    const ALL_RULES_AS_FILTERS: [RuleFilter<'static>; 18] = [
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
@@ -2985,6 +3068,7 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended(&self) -> bool {
@@ -2993,11 +3077,17 @@ impl Nursery {
     pub(crate) const fn is_not_recommended(&self) -> bool {
         matches!(self.recommended, Some(false))
     }
-    pub(crate) fn is_all(&self) -> bool {
-        matches!(self.all, Some(true))
-    }
-    pub(crate) fn is_not_all(&self) -> bool {
-        matches!(self.all, Some(false))
+    #[doc = r" Returns `Some(true)` when all the rules of this group are enabled, `Some(false)` when"]
+    #[doc = r" they are all disabled, and [None] when the group doesn't decide."]
+    #[doc = r""]
+    #[doc = r" The level of the group takes precedence over its `all`, and the `all` of the linter"]
+    #[doc = r" applies only when the group doesn't select its rules."]
+    pub(crate) fn is_all(&self, parent_is_all: Option<bool>) -> Option<bool> {
+        self.level
+            .as_ref()
+            .map(|level| *level != RulePlainConfiguration::Off)
+            .or(self.all)
+            .or(parent_is_all.filter(|_| self.recommended.is_none()))
     }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
@@ -3021,71 +3111,76 @@ impl Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_implicit_any_let.as_ref() {
+        if let Some(rule) = self.no_global_assign.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_misleading_character_class.as_ref() {
+        if let Some(rule) = self.no_implicit_any_let.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_unused_imports.as_ref() {
+        if let Some(rule) = self.no_misleading_character_class.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_unused_private_class_members.as_ref() {
+        if let Some(rule) = self.no_unused_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_useless_lone_block_statements.as_ref() {
+        if let Some(rule) = self.no_unused_private_class_members.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.use_await.as_ref() {
+        if let Some(rule) = self.no_useless_lone_block_statements.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.use_export_type.as_ref() {
+        if let Some(rule) = self.use_await.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.use_for_of.as_ref() {
+        if let Some(rule) = self.use_export_type.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.use_grouped_type_import.as_ref() {
+        if let Some(rule) = self.use_for_of.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.use_grouped_type_import.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.use_regex_literals.as_ref() {
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.use_shorthand_function_type.as_ref() {
+        if let Some(rule) = self.use_regex_literals.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.use_valid_aria_role.as_ref() {
+        if let Some(rule) = self.use_shorthand_function_type.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
+        if let Some(rule) = self.use_valid_aria_role.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
         index_set
     }
     pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
@@ -3110,71 +3205,76 @@ impl Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_implicit_any_let.as_ref() {
+        if let Some(rule) = self.no_global_assign.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_misleading_character_class.as_ref() {
+        if let Some(rule) = self.no_implicit_any_let.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_unused_imports.as_ref() {
+        if let Some(rule) = self.no_misleading_character_class.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_unused_private_class_members.as_ref() {
+        if let Some(rule) = self.no_unused_imports.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_useless_lone_block_statements.as_ref() {
+        if let Some(rule) = self.no_unused_private_class_members.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.use_await.as_ref() {
+        if let Some(rule) = self.no_useless_lone_block_statements.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.use_export_type.as_ref() {
+        if let Some(rule) = self.use_await.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.use_for_of.as_ref() {
+        if let Some(rule) = self.use_export_type.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.use_grouped_type_import.as_ref() {
+        if let Some(rule) = self.use_for_of.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.use_grouped_type_import.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.use_regex_literals.as_ref() {
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.use_shorthand_function_type.as_ref() {
+        if let Some(rule) = self.use_regex_literals.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.use_valid_aria_role.as_ref() {
+        if let Some(rule) = self.use_shorthand_function_type.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
+        if let Some(rule) = self.use_valid_aria_role.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -3188,22 +3288,24 @@ impl Nursery {
     pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 7] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
-    pub(crate) fn all_rules_as_filters() -> [RuleFilter<'static>; 17] {
+    pub(crate) fn all_rules_as_filters() -> [RuleFilter<'static>; 18] {
         Self::ALL_RULES_AS_FILTERS
     }
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: Option<bool>,
         _parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        let is_all = self.is_all(parent_is_all);
+        if is_all == Some(true) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
         }
-        if self.is_not_all() {
+        if is_all == Some(false) {
             disabled_rules.extend(Self::all_rules_as_filters());
         } else if self.is_not_recommended() {
             disabled_rules.extend(Self::recommended_rules_as_filters());
@@ -3215,6 +3317,7 @@ impl Nursery {
             "noDefaultExport" => self.no_default_export.as_ref(),
             "noDuplicateJsonKeys" => self.no_duplicate_json_keys.as_ref(),
             "noEmptyBlockStatements" => self.no_empty_block_statements.as_ref(),
+            "noGlobalAssign" => self.no_global_assign.as_ref(),
             "noImplicitAnyLet" => self.no_implicit_any_let.as_ref(),
             "noMisleadingCharacterClass" => self.no_misleading_character_class.as_ref(),
             "noUnusedImports" => self.no_unused_imports.as_ref(),
@@ -3231,6 +3334,12 @@ impl Nursery {
             _ => None,
         }
     }
+    #[doc = r" Returns the severity of the rule, from its own configuration or from the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .map(Severity::from)
+            .or_else(|| self.level.as_ref().map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -3245,6 +3354,11 @@ pub struct Performance {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r#" It enables ALL rules for this group with the given severity, or disables them with `"off"`."#]
+    #[doc = r" The rules configured individually keep their own severity."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RulePlainConfiguration>,
     #[doc = "Disallow the use of spread (...) syntax on accumulators."]
     #[bpaf(
         long("no-accumulating-spread"),
@@ -3261,6 +3375,9 @@ pub struct Performance {
 }
 impl MergeWith<Performance> for Performance {
     fn merge_with(&mut self, other: Performance) {
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_accumulating_spread) = other.no_accumulating_spread {
             self.no_accumulating_spread = Some(no_accumulating_spread);
         }
@@ -3296,11 +3413,17 @@ impl Performance {
     pub(crate) const fn is_not_recommended(&self) -> bool {
         matches!(self.recommended, Some(false))
     }
-    pub(crate) fn is_all(&self) -> bool {
-        matches!(self.all, Some(true))
-    }
-    pub(crate) fn is_not_all(&self) -> bool {
-        matches!(self.all, Some(false))
+    #[doc = r" Returns `Some(true)` when all the rules of this group are enabled, `Some(false)` when"]
+    #[doc = r" they are all disabled, and [None] when the group doesn't decide."]
+    #[doc = r""]
+    #[doc = r" The level of the group takes precedence over its `all`, and the `all` of the linter"]
+    #[doc = r" applies only when the group doesn't select its rules."]
+    pub(crate) fn is_all(&self, parent_is_all: Option<bool>) -> Option<bool> {
+        self.level
+            .as_ref()
+            .map(|level| *level != RulePlainConfiguration::Off)
+            .or(self.all)
+            .or(parent_is_all.filter(|_| self.recommended.is_none()))
     }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
@@ -3347,16 +3470,18 @@ impl Performance {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: Option<bool>,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        let is_all = self.is_all(parent_is_all);
+        if is_all == Some(true) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
         }
-        if self.is_not_all() {
+        if is_all == Some(false) {
             disabled_rules.extend(Self::all_rules_as_filters());
         } else if self.is_not_recommended() {
             disabled_rules.extend(Self::recommended_rules_as_filters());
@@ -3369,6 +3494,12 @@ impl Performance {
             _ => None,
         }
     }
+    #[doc = r" Returns the severity of the rule, from its own configuration or from the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .map(Severity::from)
+            .or_else(|| self.level.as_ref().map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -3383,6 +3514,11 @@ pub struct Security {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r#" It enables ALL rules for this group with the given severity, or disables them with `"off"`."#]
+    #[doc = r" The rules configured individually keep their own severity."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RulePlainConfiguration>,
     #[doc = "Prevent the usage of dangerous JSX props"]
     #[bpaf(
         long("no-dangerously-set-inner-html"),
@@ -3404,6 +3540,9 @@ pub struct Security {
 }
 impl MergeWith<Security> for Security {
     fn merge_with(&mut self, other: Security) {
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_dangerously_set_inner_html) = other.no_dangerously_set_inner_html {
             self.no_dangerously_set_inner_html = Some(no_dangerously_set_inner_html);
         }
@@ -3448,11 +3587,17 @@ impl Security {
     pub(crate) const fn is_not_recommended(&self) -> bool {
         matches!(self.recommended, Some(false))
     }
-    pub(crate) fn is_all(&self) -> bool {
-        matches!(self.all, Some(true))
-    }
-    pub(crate) fn is_not_all(&self) -> bool {
-        matches!(self.all, Some(false))
+    #[doc = r" Returns `Some(true)` when all the rules of this group are enabled, `Some(false)` when"]
+    #[doc = r" they are all disabled, and [None] when the group doesn't decide."]
+    #[doc = r""]
+    #[doc = r" The level of the group takes precedence over its `all`, and the `all` of the linter"]
+    #[doc = r" applies only when the group doesn't select its rules."]
+    pub(crate) fn is_all(&self, parent_is_all: Option<bool>) -> Option<bool> {
+        self.level
+            .as_ref()
+            .map(|level| *level != RulePlainConfiguration::Off)
+            .or(self.all)
+            .or(parent_is_all.filter(|_| self.recommended.is_none()))
     }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
@@ -3499,16 +3644,18 @@ impl Security {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: Option<bool>,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        let is_all = self.is_all(parent_is_all);
+        if is_all == Some(true) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
         }
-        if self.is_not_all() {
+        if is_all == Some(false) {
             disabled_rules.extend(Self::all_rules_as_filters());
         } else if self.is_not_recommended() {
             disabled_rules.extend(Self::recommended_rules_as_filters());
@@ -3523,6 +3670,12 @@ impl Security {
             _ => None,
         }
     }
+    #[doc = r" Returns the severity of the rule, from its own configuration or from the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .map(Severity::from)
+            .or_else(|| self.level.as_ref().map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -3537,6 +3690,11 @@ pub struct Style {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r#" It enables ALL rules for this group with the given severity, or disables them with `"off"`."#]
+    #[doc = r" The rules configured individually keep their own severity."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RulePlainConfiguration>,
     #[doc = "Disallow the use of arguments"]
     #[bpaf(long("no-arguments"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3718,6 +3876,9 @@ pub struct Style {
 }
 impl MergeWith<Style> for Style {
     fn merge_with(&mut self, other: Style) {
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_arguments) = other.no_arguments {
             self.no_arguments = Some(no_arguments);
         }
@@ -3943,11 +4104,17 @@ impl Style {
     pub(crate) const fn is_not_recommended(&self) -> bool {
         matches!(self.recommended, Some(false))
     }
-    pub(crate) fn is_all(&self) -> bool {
-        matches!(self.all, Some(true))
-    }
-    pub(crate) fn is_not_all(&self) -> bool {
-        matches!(self.all, Some(false))
+    #[doc = r" Returns `Some(true)` when all the rules of this group are enabled, `Some(false)` when"]
+    #[doc = r" they are all disabled, and [None] when the group doesn't decide."]
+    #[doc = r""]
+    #[doc = r" The level of the group takes precedence over its `all`, and the `all` of the linter"]
+    #[doc = r" applies only when the group doesn't select its rules."]
+    pub(crate) fn is_all(&self, parent_is_all: Option<bool>) -> Option<bool> {
+        self.level
+            .as_ref()
+            .map(|level| *level != RulePlainConfiguration::Off)
+            .or(self.all)
+            .or(parent_is_all.filter(|_| self.recommended.is_none()))
     }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
@@ -4294,16 +4461,18 @@ impl Style {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: Option<bool>,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        let is_all = self.is_all(parent_is_all);
+        if is_all == Some(true) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
         }
-        if self.is_not_all() {
+        if is_all == Some(false) {
             disabled_rules.extend(Self::all_rules_as_filters());
         } else if self.is_not_recommended() {
             disabled_rules.extend(Self::recommended_rules_as_filters());
@@ -4346,6 +4515,12 @@ impl Style {
             _ => None,
         }
     }
+    #[doc = r" Returns the severity of the rule, from its own configuration or from the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .map(Severity::from)
+            .or_else(|| self.level.as_ref().map(Severity::from))
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -4360,6 +4535,11 @@ pub struct Suspicious {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub all: Option<bool>,
+    #[doc = r#" It enables ALL rules for this group with the given severity, or disables them with `"off"`."#]
+    #[doc = r" The rules configured individually keep their own severity."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub level: Option<RulePlainConfiguration>,
     #[doc = "Usually, the definition in the standard library is more precise than what people come up with or the used constant exceeds the maximum precision of the number type."]
     #[bpaf(
         long("no-approximative-numeric-constant"),
@@ -4624,6 +4804,9 @@ pub struct Suspicious {
 }
 impl MergeWith<Suspicious> for Suspicious {
     fn merge_with(&mut self, other: Suspicious) {
+        if let Some(level) = other.level {
+            self.level = Some(level);
+        }
         if let Some(no_approximative_numeric_constant) = other.no_approximative_numeric_constant {
             self.no_approximative_numeric_constant = Some(no_approximative_numeric_constant);
         }
@@ -4953,11 +5136,17 @@ impl Suspicious {
     pub(crate) const fn is_not_recommended(&self) -> bool {
         matches!(self.recommended, Some(false))
     }
-    pub(crate) fn is_all(&self) -> bool {
-        matches!(self.all, Some(true))
-    }
-    pub(crate) fn is_not_all(&self) -> bool {
-        matches!(self.all, Some(false))
+    #[doc = r" Returns `Some(true)` when all the rules of this group are enabled, `Some(false)` when"]
+    #[doc = r" they are all disabled, and [None] when the group doesn't decide."]
+    #[doc = r""]
+    #[doc = r" The level of the group takes precedence over its `all`, and the `all` of the linter"]
+    #[doc = r" applies only when the group doesn't select its rules."]
+    pub(crate) fn is_all(&self, parent_is_all: Option<bool>) -> Option<bool> {
+        self.level
+            .as_ref()
+            .map(|level| *level != RulePlainConfiguration::Off)
+            .or(self.all)
+            .or(parent_is_all.filter(|_| self.recommended.is_none()))
     }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
@@ -5424,16 +5613,18 @@ impl Suspicious {
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
+        parent_is_all: Option<bool>,
         parent_is_recommended: bool,
         enabled_rules: &mut IndexSet<RuleFilter>,
         disabled_rules: &mut IndexSet<RuleFilter>,
     ) {
-        if self.is_all() {
+        let is_all = self.is_all(parent_is_all);
+        if is_all == Some(true) {
             enabled_rules.extend(Self::all_rules_as_filters());
         } else if parent_is_recommended || self.is_recommended() {
             enabled_rules.extend(Self::recommended_rules_as_filters());
         }
-        if self.is_not_all() {
+        if is_all == Some(false) {
             disabled_rules.extend(Self::all_rules_as_filters());
         } else if self.is_not_recommended() {
             disabled_rules.extend(Self::recommended_rules_as_filters());
@@ -5488,4 +5679,10 @@ impl Suspicious {
             _ => None,
         }
     }
+    #[doc = r" Returns the severity of the rule, from its own configuration or from the level of the group"]
+    pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+        self.get_rule_configuration(rule_name)
+            .map(Severity::from)
+            .or_else(|| self.level.as_ref().map(Severity::from))
+    }
 }
@@ -0,0 +1,273 @@
+use crate::configuration::diagnostics::CantLoadExtendFile;
+use crate::configuration::editorconfig::{parse_editorconfig, EDITORCONFIG_FILE_NAME};
+use crate::configuration::lock_file::{is_remote_extends, LockFile};
+use crate::configuration::ConfigurationPayload;
+use crate::resolver::resolve_package_export;
+use crate::{Configuration, MergeWith, WorkspaceError};
+use biome_console::markup;
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_deserialize::Deserialized;
+use biome_diagnostics::{DiagnosticExt, Error};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use std::path::{Path, PathBuf};
+
+/// The conditions of the `exports` field of a `package.json` that match the configuration
+/// files of the packages listed in `extends`, along with `"default"`.
+const EXTENDS_EXPORTS_CONDITIONS: &[&str] = &["biome"];
+
+/// A configuration loaded from the file system, along with the location of its file
+#[derive(Default, Debug)]
+pub struct LoadedConfiguration {
+    pub directory_path: Option<PathBuf>,
+    pub file_path: Option<PathBuf>,
+    pub configuration: Configuration,
+    pub diagnostics: Vec<Error>,
+}
+
+impl LoadedConfiguration {
+    /// Consumes itself to generate a new [LoadedConfiguration] where the new `configuration`
+    /// is the result of its `extends` fields applied from left to right, and the last one element
+    /// applied is itself.
+    ///
+    /// The configurations referenced by a URL are downloaded and checked against the hash
+    /// recorded in the lock file, or their hash is recorded when the lock file doesn't have it.
+    /// When `frozen` is `true`, the configurations missing from the lock file are reported.
+    ///
+    /// If a configuration can't be resolved from the file system, the operation will fail.
+    pub fn apply_extends(
+        mut self,
+        fs: &dyn FileSystem,
+        frozen: bool,
+    ) -> Result<Self, WorkspaceError> {
+        let deserialized = self.deserialize_extends(fs, frozen)?;
+        let directory_path = self.extends_directory_path(fs);
+        let (configurations, errors): (Vec<_>, Vec<_>) = deserialized
+            .into_iter()
+            .map(|d| d.consume())
+            .map(|(config, mut diagnostics)| {
+                let mut config = config.unwrap_or_default();
+                let variables_diagnostics = config.interpolate_variables(&directory_path);
+                diagnostics.extend(variables_diagnostics.into_iter().map(Error::from));
+                (config, diagnostics)
+            })
+            .unzip();
+
+        let extended_configuration = configurations.into_iter().reduce(
+            |mut previous_configuration, current_configuration| {
+                previous_configuration.merge_with(current_configuration);
+                previous_configuration
+            },
+        );
+        let configuration = if let Some(mut extended_configuration) = extended_configuration {
+            // The list of extended files is kept, so it's possible to tell where the
+            // configuration comes from
+            let extends = self.configuration.extends.clone();
+            // Here we want to keep only the values that aren't a default
+            extended_configuration.merge_with_if_not_default(self.configuration);
+            extended_configuration.extends = extends;
+            extended_configuration
+        } else {
+            self.configuration
+        };
+        self.diagnostics
+            .extend(errors.into_iter().flatten().collect::<Vec<_>>());
+
+        Ok(Self {
+            configuration,
+            diagnostics: self.diagnostics,
+            file_path: self.file_path,
+            directory_path: self.directory_path,
+        })
+    }
+
+    /// Consumes itself to generate a new [LoadedConfiguration] where the options of the
+    /// formatter that the configuration doesn't set are read from the `.editorconfig` file of
+    /// the configuration directory, when `formatter.useEditorconfig` is enabled.
+    ///
+    /// The options that can't be applied are reported as warnings.
+    #[must_use]
+    pub fn apply_editorconfig(mut self, fs: &dyn FileSystem) -> Self {
+        let use_editorconfig = self
+            .configuration
+            .formatter
+            .as_ref()
+            .and_then(|formatter| formatter.use_editorconfig)
+            .unwrap_or_default();
+        if !use_editorconfig {
+            return self;
+        }
+
+        let file_path = self.extends_directory_path(fs).join(EDITORCONFIG_FILE_NAME);
+        let Ok(mut file) = fs.open_with_options(&file_path, OpenOptions::default().read(true))
+        else {
+            return self;
+        };
+        let mut content = String::new();
+        if file.read_to_string(&mut content).is_err() {
+            return self;
+        }
+
+        let (mut formatter, diagnostics) = parse_editorconfig(&file_path, &content);
+        if let Some(configured_formatter) = self.configuration.formatter.take() {
+            formatter.merge_with(configured_formatter);
+        }
+        self.configuration.formatter = Some(formatter);
+        self.diagnostics
+            .extend(diagnostics.into_iter().map(Error::from));
+        self
+    }
+
+    /// Returns the paths of the files listed in the `extends` field of the configuration
+    pub fn extended_file_paths(&self, fs: &dyn FileSystem) -> Vec<PathBuf> {
+        let Some(extends) = &self.configuration.extends else {
+            return Vec::new();
+        };
+
+        let directory_path = self.extends_directory_path(fs);
+        extends
+            .iter()
+            .filter(|path| !is_remote_extends(path))
+            .map(|path| resolve_extends_path(fs, &directory_path, path))
+            .collect()
+    }
+
+    /// Returns the directory the paths of the `extends` field are relative to
+    fn extends_directory_path(&self, fs: &dyn FileSystem) -> PathBuf {
+        self.directory_path
+            .as_ref()
+            .cloned()
+            .unwrap_or(fs.working_directory().unwrap_or(PathBuf::from("./")))
+    }
+
+    fn deserialize_extends(
+        &mut self,
+        fs: &dyn FileSystem,
+        frozen: bool,
+    ) -> Result<Vec<Deserialized<Configuration>>, WorkspaceError> {
+        let Some(extends) = &self.configuration.extends else {
+            return Ok(vec![]);
+        };
+
+        let directory_path = self.extends_directory_path(fs);
+        // The lock file is only read when a configuration is referenced by a URL
+        let mut lock_file = extends
+            .iter()
+            .any(|path| is_remote_extends(path))
+            .then(|| LockFile::load(fs, &directory_path))
+            .transpose()?;
+        let mut deserialized_configurations = vec![];
+        for path in extends.iter() {
+            if let Some(lock_file) = lock_file.as_mut().filter(|_| is_remote_extends(path)) {
+                let content = lock_file.resolve(fs, path, frozen)?;
+                deserialized_configurations.push(deserialize_from_json_str::<Configuration>(
+                    content.as_str(),
+                    JsonParserOptions::default(),
+                ));
+                continue;
+            }
+            let config_path = resolve_extends_path(fs, &directory_path, path);
+            let mut file = fs
+					.open_with_options(config_path.as_path(), OpenOptions::default().read(true))
+					.map_err(|err| {
+						let diagnostic = CantLoadExtendFile::new(config_path.display().to_string(), err.to_string());
+						if is_package_specifier(path) {
+							diagnostic.with_verbose_advice(
+								markup!{
+									"Biome tried to resolve the package "<Emphasis>{path}</Emphasis>" from the "<Emphasis>"node_modules"</Emphasis>" folders of "<Emphasis>{directory_path.display().to_string()}</Emphasis>" and of its parents, using the "<Emphasis>"exports"</Emphasis>" field of its "<Emphasis>"package.json"</Emphasis>"."
+								}
+							)
+						} else {
+							diagnostic.with_verbose_advice(
+								markup!{
+									"Biome tried to load the configuration file "<Emphasis>{directory_path.display().to_string()}</Emphasis>" using "<Emphasis>{config_path.display().to_string()}</Emphasis>" as base path."
+								}
+							)
+						}
+					})?;
+            let mut content = String::new();
+            file.read_to_string(&mut content).map_err(|err| {
+					CantLoadExtendFile::new(config_path.display().to_string(), err.to_string()).with_verbose_advice(
+						markup!{
+							"It's possible that the file was created with a different user/group. Make sure you have the rights to read the file."
+						}
+					)
+
+				})?;
+            let deserialized = deserialize_from_json_str::<Configuration>(
+                content.as_str(),
+                JsonParserOptions::default(),
+            );
+            deserialized_configurations.push(deserialized)
+        }
+        if let Some(lock_file) = lock_file {
+            lock_file.save(fs)?;
+        }
+        Ok(deserialized_configurations)
+    }
+
+    /// Attaches the path of the configuration file to its diagnostics that don't have a file
+    #[must_use]
+    pub fn with_file_path(mut self) -> Self {
+        self.diagnostics = self
+            .diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                if diagnostic.location().resource.is_some() {
+                    diagnostic
+                } else if let Some(file_path) = &self.file_path {
+                    diagnostic.with_file_path(file_path.display().to_string())
+                } else {
+                    diagnostic
+                }
+            })
+            .collect::<Vec<_>>();
+        self
+    }
+}
+
+/// Returns the path of the configuration file referenced by `path` in the `extends` field.
+///
+/// The path is relative to `directory_path`. When there isn't such file and `path` is a bare
+/// specifier, e.g. `@org/biome-config`, it's resolved as a file exported by a package of
+/// `node_modules`.
+fn resolve_extends_path(fs: &dyn FileSystem, directory_path: &Path, path: &str) -> PathBuf {
+    let config_path = directory_path.join(path);
+    if fs.path_exists(&config_path) || !is_package_specifier(path) {
+        return config_path;
+    }
+    resolve_package_export(fs, directory_path, path, EXTENDS_EXPORTS_CONDITIONS)
+        .unwrap_or(config_path)
+}
+
+/// Whether `path` looks like the name of a package, e.g. `@org/biome-config`, rather than a
+/// relative or absolute path, e.g. `./base.json` or `base.json`
+fn is_package_specifier(path: &str) -> bool {
+    let first_segment = path.split('/').next().unwrap_or_default();
+    !path.starts_with('.') && !Path::new(path).has_root() && !first_segment.ends_with(".json")
+}
+
+impl From<Option<ConfigurationPayload>> for LoadedConfiguration {
+    fn from(value: Option<ConfigurationPayload>) -> Self {
+        if let Some(value) = value {
+            let ConfigurationPayload {
+                configuration_directory_path,
+                configuration_file_path,
+                deserialized,
+            } = value;
+            let (configuration, mut diagnostics) = deserialized.consume();
+            let mut configuration = configuration.unwrap_or_default();
+            let variables_diagnostics =
+                configuration.interpolate_variables(&configuration_directory_path);
+            diagnostics.extend(variables_diagnostics.into_iter().map(Error::from));
+            LoadedConfiguration {
+                configuration,
+                diagnostics,
+                directory_path: Some(configuration_directory_path),
+                file_path: Some(configuration_file_path),
+            }
+        } else {
+            LoadedConfiguration::default()
+        }
+    }
+}
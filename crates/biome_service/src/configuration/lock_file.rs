@@ -0,0 +1,233 @@
+use crate::configuration::diagnostics::CantLoadExtendFile;
+use crate::{ConfigurationDiagnostic, WorkspaceError};
+use biome_console::markup;
+use biome_fs::{FileSystem, FileSystemExt, OpenOptions};
+use biome_json_formatter::context::JsonFormatOptions;
+use biome_json_parser::{parse_json, JsonParserOptions};
+use ring::digest::{digest, SHA256};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The name of the file, next to the configuration file, that pins the configurations
+/// downloaded for `extends`
+pub const LOCK_FILE_NAME: &str = "biome.lock.json";
+
+/// The name of the file, next to the lock file, that keeps the content of the configurations
+/// downloaded for `extends`, by hash
+pub const EXTENDS_CACHE_FILE_NAME: &str = ".biome-extends-cache.json";
+
+/// The lock file of a configuration.
+///
+/// The lock file records the hash of each configuration referenced by a URL in `extends`,
+/// the first time it's downloaded, and the content of the configuration is kept in the cache
+/// file. The following runs read the configuration from the cache when its content matches
+/// the hash, and only download it again when it's missing from the cache. A downloaded
+/// configuration that doesn't match its hash is reported instead of being applied. Removing
+/// its entry records the hash of the new content.
+#[derive(Debug)]
+pub(crate) struct LockFile {
+    path: PathBuf,
+    content: LockFileContent,
+    /// Whether configurations were downloaded since the lock file was loaded
+    has_changes: bool,
+    cache_path: PathBuf,
+    /// The content of the downloaded configurations, by hash
+    cache: BTreeMap<String, String>,
+    /// Whether configurations were added to the cache since it was loaded
+    has_cache_changes: bool,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct LockFileContent {
+    /// The hash of the configurations downloaded for `extends`, e.g. `sha256:<hex digest>`,
+    /// by URL
+    #[serde(default)]
+    extends: BTreeMap<String, String>,
+}
+
+impl LockFile {
+    /// Loads the lock file of the configuration found in `directory_path`. An empty lock file
+    /// is returned when it doesn't exist.
+    pub(crate) fn load(fs: &dyn FileSystem, directory_path: &Path) -> Result<Self, WorkspaceError> {
+        let path = directory_path.join(LOCK_FILE_NAME);
+        let content = match fs.open_with_options(&path, OpenOptions::default().read(true)) {
+            Ok(mut file) => {
+                let mut content = String::new();
+                file.read_to_string(&mut content)
+                    .map_err(|_| WorkspaceError::cant_read_file(path.display().to_string()))?;
+                serde_json::from_str(&content).map_err(|error| {
+                    CantLoadExtendFile::new(path.display().to_string(), error.to_string())
+                        .with_verbose_advice(markup! {
+                            "Remove the file "<Emphasis>{LOCK_FILE_NAME}</Emphasis>" to record the configurations of "<Emphasis>"extends"</Emphasis>" again."
+                        })
+                })?
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => LockFileContent::default(),
+            Err(_) => return Err(WorkspaceError::cant_read_file(path.display().to_string())),
+        };
+
+        // An invalid cache is replaced
+        let cache_path = directory_path.join(EXTENDS_CACHE_FILE_NAME);
+        let cache = fs
+            .open_with_options(&cache_path, OpenOptions::default().read(true))
+            .ok()
+            .and_then(|mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content).ok()?;
+                serde_json::from_str(&content).ok()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            path,
+            content,
+            has_changes: false,
+            cache_path,
+            cache,
+            has_cache_changes: false,
+        })
+    }
+
+    /// Returns the content of the configuration at `url`. It's read from the cache when its
+    /// content matches the hash recorded in the lock file, otherwise it's downloaded and
+    /// checked against this hash. The hash of the content is recorded when the lock file
+    /// doesn't have it.
+    ///
+    /// When `frozen` is `true`, the configurations are never downloaded: the hashes aren't
+    /// recorded, and the configurations missing from the lock file or from the cache are
+    /// reported.
+    pub(crate) fn resolve(
+        &mut self,
+        fs: &dyn FileSystem,
+        url: &str,
+        frozen: bool,
+    ) -> Result<String, WorkspaceError> {
+        if !url.starts_with("https://") {
+            return Err(CantLoadExtendFile::new(
+                url,
+                "Only the configurations served over HTTPS can be extended.",
+            )
+            .into());
+        }
+
+        let locked_hash = self.content.extends.get(url);
+        if let Some(content) = locked_hash
+            .and_then(|locked_hash| self.cache.get(locked_hash))
+            .filter(|content| Some(&hash(content)) == locked_hash)
+        {
+            return Ok(content.clone());
+        }
+
+        if frozen {
+            let diagnostic = if locked_hash.is_none() {
+                CantLoadExtendFile::new(
+                    url,
+                    markup! {"The configuration isn't recorded in "<Emphasis>{LOCK_FILE_NAME}</Emphasis>", and "<Emphasis>"--frozen"</Emphasis>" prevents recording it."},
+                )
+                .with_verbose_advice(markup! {
+                    "Run Biome without "<Emphasis>"--frozen"</Emphasis>" to record the hash of the configuration in "<Emphasis>{LOCK_FILE_NAME}</Emphasis>"."
+                })
+            } else {
+                CantLoadExtendFile::new(
+                    url,
+                    markup! {"The configuration isn't in "<Emphasis>{EXTENDS_CACHE_FILE_NAME}</Emphasis>", and "<Emphasis>"--frozen"</Emphasis>" prevents downloading it."},
+                )
+                .with_verbose_advice(markup! {
+                    "Run Biome without "<Emphasis>"--frozen"</Emphasis>" to download the configuration, or keep "<Emphasis>{EXTENDS_CACHE_FILE_NAME}</Emphasis>" between the runs."
+                })
+            };
+            return Err(diagnostic.into());
+        }
+
+        let content = fs
+            .download(url)
+            .map_err(|error| CantLoadExtendFile::new(url, error.to_string()))?;
+        let content_hash = hash(&content);
+        match locked_hash {
+            Some(locked_hash) if *locked_hash != content_hash => {
+                return Err(CantLoadExtendFile::new(
+                    url,
+                    markup! {"The downloaded configuration doesn't match its hash in "<Emphasis>{LOCK_FILE_NAME}</Emphasis>"."},
+                )
+                .with_verbose_advice(markup! {
+                    "Remove its entry from "<Emphasis>{LOCK_FILE_NAME}</Emphasis>" if the change of the configuration is expected."
+                })
+                .into());
+            }
+            Some(_) => {}
+            None => {
+                self.content
+                    .extends
+                    .insert(url.to_string(), content_hash.clone());
+                self.has_changes = true;
+            }
+        }
+        self.cache.insert(content_hash, content.clone());
+        self.has_cache_changes = true;
+        Ok(content)
+    }
+
+    /// Writes the lock file when the hashes of configurations were recorded, and the cache
+    /// when configurations were downloaded
+    pub(crate) fn save(&self, fs: &dyn FileSystem) -> Result<(), WorkspaceError> {
+        if self.has_changes {
+            write_json(fs, &self.path, &self.content)?;
+        }
+        if self.has_cache_changes {
+            write_json(fs, &self.cache_path, &self.cache)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `value` in the file at `path`, formatted like a configuration file
+fn write_json(
+    fs: &dyn FileSystem,
+    path: &Path,
+    value: &impl Serialize,
+) -> Result<(), WorkspaceError> {
+    let content = serde_json::to_string_pretty(value).map_err(|_| {
+        WorkspaceError::Configuration(ConfigurationDiagnostic::new_serialization_error())
+    })?;
+    let parsed = parse_json(&content, JsonParserOptions::default());
+    let formatted =
+        biome_json_formatter::format_node(JsonFormatOptions::default(), &parsed.syntax())?
+            .print()
+            .expect("valid format document");
+
+    fs.create(path)
+        .and_then(|mut file| file.set_content(formatted.as_code().as_bytes()))
+        .map_err(|_| WorkspaceError::cant_read_file(path.display().to_string()))?;
+    Ok(())
+}
+
+/// Whether `path`, listed in `extends`, is the URL of a remote configuration
+pub(crate) fn is_remote_extends(path: &str) -> bool {
+    path.starts_with("https://") || path.starts_with("http://")
+}
+
+/// Returns the SHA-256 hash of `content`, e.g. `sha256:<hex digest>`
+fn hash(content: &str) -> String {
+    let digest = digest(&SHA256, content.as_bytes());
+    let mut hash = String::from("sha256:");
+    for byte in digest.as_ref() {
+        hash.push_str(&format!("{byte:02x}"));
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hash;
+
+    #[test]
+    fn hashes_content_with_sha256() {
+        assert_eq!(
+            hash("abc"),
+            "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
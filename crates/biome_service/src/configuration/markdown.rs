@@ -0,0 +1,64 @@
+use crate::configuration::merge::MergeWith;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to Markdown files
+#[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct MarkdownConfiguration {
+    /// Formatting options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(markdown_formatter), optional, hide)]
+    pub formatter: Option<MarkdownFormatter>,
+}
+
+impl MergeWith<MarkdownConfiguration> for MarkdownConfiguration {
+    fn merge_with(&mut self, other: MarkdownConfiguration) {
+        if let Some(other_formatter) = other.formatter {
+            let formatter = self
+                .formatter
+                .get_or_insert_with(MarkdownFormatter::default);
+            formatter.merge_with(other_formatter);
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: MarkdownConfiguration)
+    where
+        MarkdownConfiguration: Default,
+    {
+        if other != MarkdownConfiguration::default() {
+            self.merge_with(other)
+        }
+    }
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MarkdownFormatter {
+    /// Control the formatting of the fenced code blocks of Markdown files. Defaults to false.
+    ///
+    /// Only the code blocks written in a language supported by the formatter are formatted,
+    /// the rest of the document is left untouched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub enabled: Option<bool>,
+}
+
+impl MergeWith<MarkdownFormatter> for MarkdownFormatter {
+    fn merge_with(&mut self, other: MarkdownFormatter) {
+        if let Some(enabled) = other.enabled {
+            self.enabled = Some(enabled);
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: MarkdownFormatter)
+    where
+        MarkdownFormatter: Default,
+    {
+        if other != MarkdownFormatter::default() {
+            self.merge_with(other)
+        }
+    }
+}
@@ -3,27 +3,46 @@
 //! The configuration is divided by "tool", and then it's possible to further customise it
 //! by language. The language might further options divided by tool.
 pub mod diagnostics;
+pub mod domains;
+mod editorconfig;
 pub mod formatter;
 mod generated;
+mod ignore_files;
 pub mod javascript;
 pub mod json;
 pub mod linter;
+mod loaded;
+mod lock_file;
+pub mod markdown;
 mod merge;
+mod nested;
 pub mod organize_imports;
 mod overrides;
 mod parse;
+mod patch;
+mod variables;
 pub mod vcs;
 
 pub use crate::configuration::diagnostics::ConfigurationDiagnostic;
+use crate::configuration::domains::Domains;
 pub(crate) use crate::configuration::generated::push_to_analyzer_rules;
+pub use crate::configuration::ignore_files::{
+    IgnoreFiles, IGNORE_FILE_NAME, VCS_EXCLUDE_FILE_NAME, VCS_IGNORE_FILE_NAME,
+};
 use crate::configuration::json::JsonFormatter;
+pub use crate::configuration::loaded::LoadedConfiguration;
+pub use crate::configuration::lock_file::{EXTENDS_CACHE_FILE_NAME, LOCK_FILE_NAME};
 pub use crate::configuration::merge::MergeWith;
+pub use crate::configuration::nested::NestedConfigurations;
 use crate::configuration::organize_imports::{organize_imports, OrganizeImports};
-use crate::configuration::overrides::Overrides;
+pub use crate::configuration::overrides::{
+    OverrideFormatterConfiguration, OverrideLinterConfiguration, OverridePattern, Overrides,
+};
+pub use crate::configuration::patch::{disable_rule, disable_rule_edit};
 use crate::configuration::vcs::{vcs_configuration, VcsConfiguration};
 use crate::settings::WorkspaceSettings;
 use crate::{DynRef, WorkspaceError, VERSION};
-use biome_analyze::AnalyzerRules;
+use biome_analyze::{AnalyzerGlobals, AnalyzerRules};
 use biome_deserialize::json::deserialize_from_json_str;
 use biome_deserialize::{Deserialized, StringSet};
 use biome_fs::{AutoSearchResult, FileSystem, OpenOptions};
@@ -37,7 +56,11 @@ pub use formatter::{
 };
 pub use javascript::{javascript_configuration, JavascriptConfiguration, JavascriptFormatter};
 pub use json::{json_configuration, JsonConfiguration};
-pub use linter::{linter_configuration, LinterConfiguration, RuleConfiguration, Rules};
+pub use linter::{
+    linter_configuration, LinterConfiguration, RuleConfiguration, RuleDocumentation, RuleSelector,
+    Rules,
+};
+pub use markdown::{markdown_configuration, MarkdownConfiguration};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::io::ErrorKind;
@@ -55,6 +78,12 @@ pub struct Configuration {
     #[bpaf(hide)]
     pub schema: Option<String>,
 
+    /// Whether this is the root configuration. A nested configuration file marked as root
+    /// doesn't inherit the options of the configuration files of its parent directories
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub root: Option<bool>,
+
     /// The configuration of the VCS integration
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(vcs_configuration), optional, hide_usage)]
@@ -90,6 +119,11 @@ pub struct Configuration {
     #[bpaf(external(json_configuration), optional)]
     pub json: Option<JsonConfiguration>,
 
+    /// Specific configuration for the Markdown language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(markdown_configuration), optional, hide)]
+    pub markdown: Option<MarkdownConfiguration>,
+
     /// A list of paths to other JSON files, used to extends the current configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
@@ -99,6 +133,13 @@ pub struct Configuration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub overrides: Option<Overrides>,
+
+    /// A list of domains, e.g. `react` or `vitest`. Each domain enables the rules and sets the
+    /// options that fit a framework or a kind of project. The rules and the options set by the
+    /// configuration take precedence over the ones of the domains.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub domains: Option<Domains>,
 }
 
 impl Default for Configuration {
@@ -113,10 +154,13 @@ impl Default for Configuration {
             formatter: None,
             javascript: None,
             schema: None,
+            root: None,
             vcs: None,
             extends: None,
             json: None,
+            markdown: None,
             overrides: None,
+            domains: None,
         }
     }
 }
@@ -146,6 +190,10 @@ impl Configuration {
     pub fn is_vcs_disabled(&self) -> bool {
         self.vcs.as_ref().map(|f| f.is_disabled()).unwrap_or(true)
     }
+
+    pub fn is_root(&self) -> bool {
+        self.root.unwrap_or(false)
+    }
 }
 
 impl MergeWith<Configuration> for Configuration {
@@ -158,12 +206,18 @@ impl MergeWith<Configuration> for Configuration {
         self.merge_with(other_configuration.javascript);
         // linter
         self.merge_with(other_configuration.linter);
+        // markdown
+        self.merge_with(other_configuration.markdown);
         // organize imports
         self.merge_with(other_configuration.organize_imports);
         // VCS
         self.merge_with(other_configuration.vcs);
         // overrides
         self.merge_with(other_configuration.overrides);
+        // domains
+        if let Some(domains) = other_configuration.domains {
+            self.domains = Some(domains);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other_configuration: Configuration)
@@ -178,12 +232,18 @@ impl MergeWith<Configuration> for Configuration {
         self.merge_with_if_not_default(other_configuration.javascript);
         // linter
         self.merge_with_if_not_default(other_configuration.linter);
+        // markdown
+        self.merge_with_if_not_default(other_configuration.markdown);
         // organize imports
         self.merge_with_if_not_default(other_configuration.organize_imports);
         // VCS
         self.merge_with_if_not_default(other_configuration.vcs);
         // overrides
         self.merge_with_if_not_default(other_configuration.overrides);
+        // domains
+        if let Some(domains) = other_configuration.domains {
+            self.domains = Some(domains);
+        }
     }
 }
 
@@ -284,7 +344,7 @@ impl MergeWith<Option<FormatterConfiguration>> for Configuration {
         if let Some(other_formatter) = other {
             let formatter = self
                 .formatter
-                .get_or_insert_with(FormatterConfiguration::default);
+                .get_or_insert_with(FormatterConfiguration::empty);
             formatter.merge_with(other_formatter);
         }
     }
@@ -296,7 +356,7 @@ impl MergeWith<Option<FormatterConfiguration>> for Configuration {
         if let Some(other_formatter) = other {
             let formatter = self
                 .formatter
-                .get_or_insert_with(FormatterConfiguration::default);
+                .get_or_insert_with(FormatterConfiguration::empty);
             formatter.merge_with_if_not_default(other_formatter);
         }
     }
@@ -336,6 +396,29 @@ impl MergeWith<Option<JsonFormatter>> for Configuration {
     }
 }
 
+impl MergeWith<Option<MarkdownConfiguration>> for Configuration {
+    fn merge_with(&mut self, other: Option<MarkdownConfiguration>) {
+        if let Some(other_markdown) = other {
+            let markdown = self
+                .markdown
+                .get_or_insert_with(MarkdownConfiguration::default);
+            markdown.merge_with(other_markdown);
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: Option<MarkdownConfiguration>)
+    where
+        Option<MarkdownConfiguration>: Default,
+    {
+        if let Some(other_markdown) = other {
+            let markdown = self
+                .markdown
+                .get_or_insert_with(MarkdownConfiguration::default);
+            markdown.merge_with_if_not_default(other_markdown);
+        }
+    }
+}
+
 impl MergeWith<Option<Overrides>> for Configuration {
     fn merge_with(&mut self, other: Option<Overrides>) {
         if let Some(other) = other {
@@ -359,6 +442,7 @@ impl MergeWith<Option<Overrides>> for Configuration {
 pub struct FilesConfiguration {
     /// The maximum allowed size for source code files in bytes. Files above
     /// this limit will be ignored for performance reasons. Defaults to 1 MiB
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("files-max-size"), argument("NUMBER"))]
     pub max_size: Option<NonZeroU64>,
 
@@ -378,6 +462,13 @@ pub struct FilesConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("files-ignore-unknown"), argument("true|false"), optional)]
     pub ignore_unknown: Option<bool>,
+
+    /// Tells Biome to skip the files that look generated or minified: the files with a
+    /// `@generated` marker, the bundles ending with a source map URL, and the files with very
+    /// long lines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("files-exclude-generated"), argument("true|false"), optional)]
+    pub exclude_generated: Option<bool>,
 }
 
 impl MergeWith<FilesConfiguration> for FilesConfiguration {
@@ -394,6 +485,9 @@ impl MergeWith<FilesConfiguration> for FilesConfiguration {
         if let Some(ignore_unknown) = other.ignore_unknown {
             self.ignore_unknown = Some(ignore_unknown)
         }
+        if let Some(exclude_generated) = other.exclude_generated {
+            self.exclude_generated = Some(exclude_generated)
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: FilesConfiguration)
@@ -444,7 +538,8 @@ impl ConfigurationBasePath {
 /// The configuration file will be read from the `file_system`. A [base path](ConfigurationBasePath) should be provided.
 ///
 /// The function will try to traverse upwards the file system until if finds a `biome.json` file, or there
-/// aren't directories anymore.
+/// aren't directories anymore. When there's no `biome.json` file, the configuration is read from
+/// the `"biome"` field of the closest `package.json` that has one.
 ///
 /// If a the configuration base path was provided by the user, the function will error. If not, Biome will use
 /// its defaults.
@@ -480,11 +575,21 @@ pub fn load_config(
             auto_search_result = result;
         }
     } else {
-        auto_search_result = file_system.auto_search(
+        match file_system.auto_search(
             configuration_directory.clone(),
             deprecated_config_name,
             should_error,
-        )?;
+        ) {
+            Ok(result) => auto_search_result = result,
+            Err(error) => {
+                // A directory provided by the user can be configured by its `package.json`
+                return match load_package_json_config(file_system, &configuration_directory, false)
+                {
+                    Some(payload) => Ok(Some(payload)),
+                    None => Err(error.into()),
+                };
+            }
+        }
     }
 
     if let Some(auto_search_result) = auto_search_result {
@@ -501,10 +606,63 @@ pub fn load_config(
             configuration_directory_path: directory_path,
         }))
     } else {
-        Ok(None)
+        Ok(load_package_json_config(
+            file_system,
+            &configuration_directory,
+            !should_error,
+        ))
     }
 }
 
+/// The content of a `package.json` file that matters to the configuration
+#[derive(Debug, Default)]
+pub(crate) struct PackageJsonConfiguration {
+    /// The configuration in the `"biome"` field
+    pub(crate) biome: Option<Configuration>,
+}
+
+/// Loads the configuration from the `"biome"` field of the `package.json` file in
+/// `configuration_directory`. When `search_parents` is `true`, the `package.json` files of the
+/// parent directories are searched too, until one has a `"biome"` field.
+fn load_package_json_config(
+    file_system: &DynRef<dyn FileSystem>,
+    configuration_directory: &Path,
+    search_parents: bool,
+) -> Option<ConfigurationPayload> {
+    let directories =
+        configuration_directory
+            .ancestors()
+            .take(if search_parents { usize::MAX } else { 1 });
+    for directory in directories {
+        let file_path = directory.join("package.json");
+        let options = OpenOptions::default().read(true);
+        let Ok(mut file) = file_system.open_with_options(&file_path, options) else {
+            continue;
+        };
+        let mut content = String::new();
+        if file.read_to_string(&mut content).is_err() {
+            continue;
+        }
+        let mut has_biome_field = false;
+        let deserialized = deserialize_from_json_str::<PackageJsonConfiguration>(
+            &content,
+            JsonParserOptions::default(),
+        )
+        .and_then(|package_json| {
+            has_biome_field = package_json.biome.is_some();
+            package_json.biome
+        });
+        if has_biome_field {
+            return Some(ConfigurationPayload {
+                deserialized,
+                configuration_file_path: file_path,
+                configuration_directory_path: directory.to_path_buf(),
+            });
+        }
+    }
+    None
+}
+
 /// Creates a new configuration on file system
 ///
 /// ## Errors
@@ -570,3 +728,21 @@ pub fn to_analyzer_rules(settings: &WorkspaceSettings, path: &Path) -> AnalyzerR
 
     overrides.override_analyzer_rules(path, analyzer_rules)
 }
+
+/// Returns the global bindings available to a specific [Path], given the [WorkspaceSettings]
+pub fn to_analyzer_globals(settings: &WorkspaceSettings, path: &Path) -> AnalyzerGlobals {
+    let javascript = &settings.languages.javascript;
+    let analyzer_globals = AnalyzerGlobals {
+        environments: javascript.environments.clone(),
+        bindings: javascript
+            .globals
+            .iter()
+            .flatten()
+            .map(|(name, access)| (name.clone(), *access))
+            .collect(),
+    };
+
+    settings
+        .override_settings
+        .override_analyzer_globals(path, analyzer_globals)
+}
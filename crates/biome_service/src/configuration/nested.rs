@@ -0,0 +1,216 @@
+use crate::configuration::{ConfigurationPayload, LoadedConfiguration};
+use crate::workspace::UpdateSettingsParams;
+use crate::{Configuration, MergeWith, Workspace, WorkspaceError};
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_diagnostics::{Error, Severity};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use rustc_hash::FxHashMap;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+/// The configuration files found in the subdirectories of the root configuration.
+///
+/// A nested configuration applies to the files of its directory. Its options are applied on
+/// top of the configuration of the closest parent directory that has one, unless it's marked
+/// with `"root": true`.
+///
+/// The directories are loaded lazily, while the file system is traversed, and the settings of
+/// each nested configuration are registered in the [Workspace] as a project.
+#[derive(Debug)]
+pub struct NestedConfigurations {
+    /// The directory of the root configuration, nested configurations are searched only
+    /// inside it
+    root_directory: PathBuf,
+    /// The working directory, used to resolve relative paths
+    working_directory: Option<PathBuf>,
+    /// The configuration applied to the files that don't have a nested configuration
+    root_configuration: Configuration,
+    /// Whether the remote configurations of `extends` are only read from the lock files
+    frozen: bool,
+    /// The directories that were already searched, along with their resolved configuration
+    /// if they have one
+    directories: Mutex<FxHashMap<PathBuf, Option<Configuration>>>,
+}
+
+impl NestedConfigurations {
+    pub fn new(
+        root_directory: PathBuf,
+        root_configuration: Configuration,
+        frozen: bool,
+        fs: &dyn FileSystem,
+    ) -> Self {
+        let working_directory = fs.working_directory();
+        Self {
+            root_directory: normalize_path(&absolute_path(
+                working_directory.as_deref(),
+                &root_directory,
+            )),
+            working_directory,
+            root_configuration,
+            frozen,
+            directories: Mutex::default(),
+        }
+    }
+
+    /// Loads the configuration files of `directory` and of its parent directories that
+    /// weren't searched yet, and updates the settings of the [Workspace] with them.
+    ///
+    /// Returns the diagnostics emitted while loading the configuration files.
+    pub fn load(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+        directory: &Path,
+    ) -> Vec<Error> {
+        let mut directories = self.directories.lock().unwrap();
+        let unvisited: Vec<_> = directory
+            .ancestors()
+            .take_while(|ancestor| {
+                self.is_nested_directory(ancestor) && !directories.contains_key(*ancestor)
+            })
+            .collect();
+
+        let mut diagnostics = vec![];
+        // Parent directories are loaded first, so their children can inherit from them
+        for directory in unvisited.into_iter().rev() {
+            let configuration = match load_nested_configuration(fs, directory, self.frozen) {
+                Ok(Some(loaded_configuration)) => {
+                    let has_errors = loaded_configuration
+                        .diagnostics
+                        .iter()
+                        .any(|diagnostic| diagnostic.severity() >= Severity::Error);
+                    diagnostics.extend(loaded_configuration.diagnostics);
+                    if has_errors {
+                        None
+                    } else {
+                        Some(self.inherit(
+                            &directories,
+                            directory,
+                            loaded_configuration.configuration,
+                        ))
+                    }
+                }
+                Ok(None) => None,
+                Err(error) => {
+                    diagnostics.push(error.into());
+                    None
+                }
+            };
+
+            if let Some(configuration) = &configuration {
+                let result = workspace.update_settings(UpdateSettingsParams {
+                    configuration: configuration.clone(),
+                    project_directory: Some(directory.to_path_buf()),
+                });
+                if let Err(error) = result {
+                    diagnostics.push(error.into());
+                }
+            }
+            directories.insert(directory.to_path_buf(), configuration);
+        }
+
+        diagnostics
+    }
+
+    /// Returns the configuration applied to the files of `directory`: the configuration of the
+    /// closest directory loaded with [NestedConfigurations::load], or the root configuration
+    pub fn configuration_of(&self, directory: &Path) -> Configuration {
+        let directories = self.directories.lock().unwrap();
+        directory
+            .ancestors()
+            .take_while(|ancestor| self.is_nested_directory(ancestor))
+            .find_map(|ancestor| directories.get(ancestor).cloned().flatten())
+            .unwrap_or_else(|| self.root_configuration.clone())
+    }
+
+    /// Applies `configuration` on top of the configuration of the closest parent directory
+    /// of `directory`, unless it's a root configuration
+    fn inherit(
+        &self,
+        directories: &FxHashMap<PathBuf, Option<Configuration>>,
+        directory: &Path,
+        configuration: Configuration,
+    ) -> Configuration {
+        if configuration.is_root() {
+            return configuration;
+        }
+
+        let mut parent_configuration = directory
+            .ancestors()
+            .skip(1)
+            .take_while(|ancestor| self.is_nested_directory(ancestor))
+            .find_map(|ancestor| directories.get(ancestor).cloned().flatten())
+            .unwrap_or_else(|| self.root_configuration.clone());
+        parent_configuration.merge_with_if_not_default(configuration);
+        parent_configuration
+    }
+
+    /// Whether `directory` is a subdirectory of the directory of the root configuration
+    fn is_nested_directory(&self, directory: &Path) -> bool {
+        let directory =
+            normalize_path(&absolute_path(self.working_directory.as_deref(), directory));
+        directory != self.root_directory && directory.starts_with(&self.root_directory)
+    }
+}
+
+/// Loads the configuration file of `directory`, without searching the parent directories
+fn load_nested_configuration(
+    fs: &dyn FileSystem,
+    directory: &Path,
+    frozen: bool,
+) -> Result<Option<LoadedConfiguration>, WorkspaceError> {
+    for config_name in [fs.config_name(), fs.deprecated_config_name()] {
+        let file_path = directory.join(config_name);
+        let mut file = match fs.open_with_options(&file_path, OpenOptions::default().read(true)) {
+            Ok(file) => file,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            Err(_) => {
+                return Err(WorkspaceError::cant_read_file(
+                    file_path.display().to_string(),
+                ))
+            }
+        };
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .map_err(|_| WorkspaceError::cant_read_file(file_path.display().to_string()))?;
+
+        let deserialized =
+            deserialize_from_json_str::<Configuration>(&content, JsonParserOptions::default());
+        let loaded_configuration = LoadedConfiguration::from(Some(ConfigurationPayload {
+            deserialized,
+            configuration_file_path: file_path,
+            configuration_directory_path: directory.to_path_buf(),
+        }));
+        return Ok(Some(
+            loaded_configuration
+                .apply_extends(fs, frozen)?
+                .with_file_path(),
+        ));
+    }
+
+    Ok(None)
+}
+
+pub(super) fn absolute_path(working_directory: Option<&Path>, path: &Path) -> PathBuf {
+    match working_directory {
+        Some(working_directory) => working_directory.join(path),
+        None => path.to_path_buf(),
+    }
+}
+
+/// Removes the `.` and `..` components of `path`, without accessing the file system
+pub(super) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -1,18 +1,20 @@
 use crate::configuration::formatter::{deserialize_line_width, serialize_line_width};
 use crate::configuration::linter::rules;
 use crate::configuration::{
-    javascript_configuration, json_configuration, JavascriptConfiguration, JsonConfiguration,
-    PlainIndentStyle,
+    javascript_configuration, json_configuration, markdown_configuration, JavascriptConfiguration,
+    JsonConfiguration, MarkdownConfiguration, PlainIndentStyle,
 };
 use crate::settings::{
-    to_matcher, LanguageListSettings, OverrideFormatSettings, OverrideLinterSettings,
-    OverrideOrganizeImportsSettings, OverrideSettingPattern, OverrideSettings,
+    to_matcher, LanguageListSettings, OverrideFilesSettings, OverrideFormatSettings,
+    OverrideLinterSettings, OverrideMarkdownSettings, OverrideOrganizeImportsSettings,
+    OverrideSettingPattern, OverrideSettings,
 };
 use crate::{MergeWith, Rules, WorkspaceError};
 use biome_deserialize::StringSet;
 use biome_formatter::{IndentStyle, LineEnding, LineWidth};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::num::NonZeroU64;
 use std::str::FromStr;
 
 #[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Bpaf)]
@@ -75,6 +77,11 @@ pub struct OverridePattern {
     #[bpaf(external(json_configuration), optional, hide)]
     pub json: Option<JsonConfiguration>,
 
+    /// Specific configuration for the Markdown language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(markdown_configuration), optional, hide)]
+    pub markdown: Option<MarkdownConfiguration>,
+
     /// Specific configuration for the Json language
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(override_formatter_configuration), optional, hide)]
@@ -89,6 +96,11 @@ pub struct OverridePattern {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(override_organize_imports_configuration), optional, hide)]
     pub organize_imports: Option<OverrideOrganizeImportsConfiguration>,
+
+    /// Specific configuration for the filesystem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(override_files_configuration), optional, hide)]
+    pub files: Option<OverrideFilesConfiguration>,
 }
 
 impl FromStr for OverridePattern {
@@ -130,6 +142,12 @@ impl MergeWith<OverridePattern> for OverridePattern {
                 .get_or_insert(OverrideOrganizeImportsConfiguration::default());
             organize_imports.merge_with(other);
         }
+        if let Some(other) = other.files {
+            let files = self
+                .files
+                .get_or_insert(OverrideFilesConfiguration::default());
+            files.merge_with(other);
+        }
         if let Some(other) = other.javascript {
             let javascript = self
                 .javascript
@@ -140,6 +158,12 @@ impl MergeWith<OverridePattern> for OverridePattern {
             let json = self.json.get_or_insert(JsonConfiguration::default());
             json.merge_with(other)
         }
+        if let Some(other) = other.markdown {
+            let markdown = self
+                .markdown
+                .get_or_insert(MarkdownConfiguration::default());
+            markdown.merge_with(other)
+        }
     }
     fn merge_with_if_not_default(&mut self, other: OverridePattern)
     where
@@ -174,6 +198,12 @@ impl MergeWith<OverridePattern> for OverridePattern {
                 .get_or_insert(OverrideOrganizeImportsConfiguration::default());
             organize_imports.merge_with_if_not_default(other);
         }
+        if let Some(other) = other.files {
+            let files = self
+                .files
+                .get_or_insert(OverrideFilesConfiguration::default());
+            files.merge_with_if_not_default(other);
+        }
         if let Some(other) = other.javascript {
             let javascript = self
                 .javascript
@@ -184,6 +214,12 @@ impl MergeWith<OverridePattern> for OverridePattern {
             let json = self.json.get_or_insert(JsonConfiguration::default());
             json.merge_with_if_not_default(other)
         }
+        if let Some(other) = other.markdown {
+            let markdown = self
+                .markdown
+                .get_or_insert(MarkdownConfiguration::default());
+            markdown.merge_with_if_not_default(other)
+        }
     }
 }
 
@@ -192,11 +228,13 @@ impl MergeWith<OverridePattern> for OverridePattern {
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 pub struct OverrideFormatterConfiguration {
     // if `false`, it disables the feature. `true` by default
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub enabled: Option<bool>,
 
     /// Stores whether formatting should be allowed to proceed if a given file
     /// has syntax errors
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub format_with_errors: Option<bool>,
 
@@ -217,16 +255,23 @@ pub struct OverrideFormatterConfiguration {
 
     /// The type of line ending.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[bpaf(long("line-ending"), argument("lf|crlf|cr"), optional)]
+    #[bpaf(long("line-ending"), argument("lf|crlf|cr|auto"), optional)]
     pub line_ending: Option<LineEnding>,
 
     /// What's the max width of a line. Defaults to 80.
     #[serde(
         deserialize_with = "deserialize_line_width",
-        serialize_with = "serialize_line_width"
+        serialize_with = "serialize_line_width",
+        skip_serializing_if = "Option::is_none"
     )]
     #[bpaf(long("line-width"), argument("NUMBER"), optional)]
     pub line_width: Option<LineWidth>,
+
+    /// Whether to match the output of Prettier where Biome intentionally diverges from it,
+    /// to make the migration from Prettier easier. Defaults to false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub prettier_compatibility: Option<bool>,
 }
 
 impl MergeWith<OverrideFormatterConfiguration> for OverrideFormatterConfiguration {
@@ -243,6 +288,9 @@ impl MergeWith<OverrideFormatterConfiguration> for OverrideFormatterConfiguratio
         if let Some(indent_style) = other.indent_style {
             self.indent_style = Some(indent_style);
         }
+        if let Some(line_ending) = other.line_ending {
+            self.line_ending = Some(line_ending);
+        }
 
         if let Some(line_width) = other.line_width {
             self.line_width = Some(line_width);
@@ -251,6 +299,9 @@ impl MergeWith<OverrideFormatterConfiguration> for OverrideFormatterConfiguratio
         if let Some(format_with_errors) = other.format_with_errors {
             self.format_with_errors = Some(format_with_errors);
         }
+        if let Some(prettier_compatibility) = other.prettier_compatibility {
+            self.prettier_compatibility = Some(prettier_compatibility);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: OverrideFormatterConfiguration)
@@ -325,6 +376,34 @@ impl MergeWith<OverrideOrganizeImportsConfiguration> for OverrideOrganizeImports
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideFilesConfiguration {
+    /// The maximum allowed size for source code files in bytes. Files above
+    /// this limit will be skipped for performance reasons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide)]
+    pub max_size: Option<NonZeroU64>,
+}
+
+impl MergeWith<OverrideFilesConfiguration> for OverrideFilesConfiguration {
+    fn merge_with(&mut self, other: OverrideFilesConfiguration) {
+        if let Some(max_size) = other.max_size {
+            self.max_size = Some(max_size);
+        }
+    }
+
+    fn merge_with_if_not_default(&mut self, other: OverrideFilesConfiguration)
+    where
+        OverrideFilesConfiguration: Default,
+    {
+        if other != OverrideFilesConfiguration::default() {
+            self.merge_with(other)
+        }
+    }
+}
+
 impl TryFrom<Overrides> for OverrideSettings {
     type Error = WorkspaceError;
 
@@ -340,6 +419,10 @@ impl TryFrom<Overrides> for OverrideSettings {
             let organize_imports = pattern.organize_imports.take().unwrap_or_default();
             let organize_imports = OverrideOrganizeImportsSettings::try_from(organize_imports)?;
 
+            let files = OverrideFilesSettings {
+                max_size: pattern.files.take().and_then(|files| files.max_size),
+            };
+
             let mut languages = LanguageListSettings::default();
             if let Some(javascript) = pattern.javascript {
                 languages.javascript = javascript.into();
@@ -349,13 +432,22 @@ impl TryFrom<Overrides> for OverrideSettings {
                 languages.json = json.into();
             }
 
+            let markdown = OverrideMarkdownSettings {
+                formatter_enabled: pattern
+                    .markdown
+                    .and_then(|markdown| markdown.formatter)
+                    .and_then(|formatter| formatter.enabled),
+            };
+
             let pattern_setting = OverrideSettingPattern {
                 include: to_matcher(pattern.include.as_ref())?,
                 exclude: to_matcher(pattern.ignore.as_ref())?,
                 formatter,
                 linter,
                 organize_imports,
+                files,
                 languages,
+                markdown,
             };
 
             override_settings.patterns.push(pattern_setting);
@@ -369,24 +461,21 @@ impl TryFrom<OverrideFormatterConfiguration> for OverrideFormatSettings {
     type Error = WorkspaceError;
 
     fn try_from(conf: OverrideFormatterConfiguration) -> Result<Self, Self::Error> {
-        let indent_style = match conf.indent_style {
-            Some(PlainIndentStyle::Tab) => IndentStyle::Tab,
-            Some(PlainIndentStyle::Space) => IndentStyle::Space,
-            None => IndentStyle::default(),
-        };
+        // The options that the override doesn't set are left to the other settings
+        let indent_style = conf.indent_style.map(IndentStyle::from);
         let indent_width = conf
             .indent_width
             .map(Into::into)
-            .or(conf.indent_size.map(Into::into))
-            .unwrap_or_default();
+            .or(conf.indent_size.map(Into::into));
 
         Ok(Self {
             enabled: conf.enabled,
-            indent_style: Some(indent_style),
-            indent_width: Some(indent_width),
+            indent_style,
+            indent_width,
             line_ending: conf.line_ending,
             line_width: conf.line_width,
-            format_with_errors: conf.format_with_errors.unwrap_or_default(),
+            format_with_errors: conf.format_with_errors,
+            prettier_compatibility: conf.prettier_compatibility,
         })
     }
 }
@@ -1,3 +1,4 @@
+use crate::configuration::PackageJsonConfiguration;
 use crate::Configuration;
 use biome_deserialize::{
     Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
@@ -34,10 +35,13 @@ impl DeserializationVisitor for ConfigurationVisitor {
             "formatter",
             "javascript",
             "json",
+            "markdown",
             "$schema",
+            "root",
             "organizeImports",
             "extends",
             "overrides",
+            "domains",
         ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
@@ -48,6 +52,9 @@ impl DeserializationVisitor for ConfigurationVisitor {
                 "$schema" => {
                     result.schema = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "root" => {
+                    result.root = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "files" => {
                     result.files = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
@@ -66,6 +73,9 @@ impl DeserializationVisitor for ConfigurationVisitor {
                 "json" => {
                     result.json = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "markdown" => {
+                    result.markdown = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "organizeImports" => {
                     result.organize_imports =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
@@ -76,6 +86,9 @@ impl DeserializationVisitor for ConfigurationVisitor {
                 "overrides" => {
                     result.overrides = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "domains" => {
+                    result.domains = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -86,3 +99,40 @@ impl DeserializationVisitor for ConfigurationVisitor {
         Some(result)
     }
 }
+
+impl Deserializable for PackageJsonConfiguration {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageJsonConfigurationVisitor, name, diagnostics)
+    }
+}
+
+struct PackageJsonConfigurationVisitor;
+impl DeserializationVisitor for PackageJsonConfigurationVisitor {
+    type Output = PackageJsonConfiguration;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: biome_rowan::TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            // The other fields of `package.json` belong to other tools
+            if key_text.text() == "biome" {
+                result.biome = Deserializable::deserialize(&value, &key_text, diagnostics);
+            }
+        }
+        Some(result)
+    }
+}
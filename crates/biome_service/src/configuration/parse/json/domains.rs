@@ -0,0 +1,33 @@
+use crate::configuration::domains::{Domain, Domains};
+use biome_deserialize::{Deserializable, DeserializableValue, DeserializationDiagnostic, Text};
+
+impl Deserializable for Domains {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        Deserializable::deserialize(value, name, diagnostics).map(Domains)
+    }
+}
+
+impl Deserializable for Domain {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.text().parse::<Self>() {
+            Some(value)
+        } else {
+            const ALLOWED_VARIANTS: &[&str] = &["react", "next", "solid", "vitest", "node-library"];
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value_text.text(),
+                value.range(),
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
@@ -28,7 +28,13 @@ impl DeserializationVisitor for FilesConfigurationVisitor {
         _name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self::Output> {
-        const ALLOWED_KEYS: &[&str] = &["maxSize", "ignore", "include", "ignoreUnknown"];
+        const ALLOWED_KEYS: &[&str] = &[
+            "maxSize",
+            "ignore",
+            "include",
+            "ignoreUnknown",
+            "excludeGenerated",
+        ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
@@ -48,6 +54,10 @@ impl DeserializationVisitor for FilesConfigurationVisitor {
                     result.ignore_unknown =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "excludeGenerated" => {
+                    result.exclude_generated =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -38,8 +38,12 @@ impl DeserializationVisitor for FormatterConfigurationVisitor {
             "lineWidth",
             "ignore",
             "include",
+            "prettierCompatibility",
+            "useEditorconfig",
         ];
-        let mut result = Self::Output::default();
+        // Only the options of the configuration file are set, so they can be told apart from
+        // the options that come from other sources, e.g. `.editorconfig`
+        let mut result = Self::Output::empty();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
                 continue;
@@ -82,6 +86,14 @@ impl DeserializationVisitor for FormatterConfigurationVisitor {
                     result.format_with_errors =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "prettierCompatibility" => {
+                    result.prettier_compatibility =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "useEditorconfig" => {
+                    result.use_editorconfig =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
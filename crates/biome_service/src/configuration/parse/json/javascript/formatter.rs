@@ -36,6 +36,9 @@ impl DeserializationVisitor for JavascriptFormatterVisitor {
             "arrowParentheses",
             "bracketSpacing",
             "bracketSameLine",
+            "decoratorPlacement",
+            "dedentTags",
+            "sqlTags",
             "enabled",
             "indentStyle",
             "indentSize",
@@ -81,6 +84,17 @@ impl DeserializationVisitor for JavascriptFormatterVisitor {
                     result.bracket_same_line =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "decoratorPlacement" => {
+                    result.decorator_placement =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "dedentTags" => {
+                    result.dedent_tags =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "sqlTags" => {
+                    result.sql_tags = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "enabled" => {
                     result.enabled = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
@@ -1,11 +1,15 @@
 mod formatter;
 
-use crate::configuration::javascript::{JavascriptOrganizeImports, JavascriptParser};
+use crate::configuration::javascript::{
+    JavascriptEnvironment, JavascriptEnvironments, JavascriptGlobalAccess, JavascriptGlobals,
+    JavascriptOrganizeImports, JavascriptParser,
+};
 use crate::configuration::JavascriptConfiguration;
 use biome_deserialize::{
-    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
-    VisitableType,
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor,
+    StringSet, Text, VisitableType,
 };
+use indexmap::IndexMap;
 
 impl Deserializable for JavascriptConfiguration {
     fn deserialize(
@@ -30,7 +34,13 @@ impl DeserializationVisitor for JavascriptConfigurationVisitor {
         _name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self::Output> {
-        const ALLOWED_KEYS: &[&str] = &["formatter", "globals", "organizeImports", "parser"];
+        const ALLOWED_KEYS: &[&str] = &[
+            "environments",
+            "formatter",
+            "globals",
+            "organizeImports",
+            "parser",
+        ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
@@ -46,6 +56,10 @@ impl DeserializationVisitor for JavascriptConfigurationVisitor {
                 "globals" => {
                     result.globals = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "environments" => {
+                    result.environments =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "organizeImports" => {
                     result.organize_imports =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
@@ -63,6 +77,121 @@ impl DeserializationVisitor for JavascriptConfigurationVisitor {
     }
 }
 
+impl Deserializable for JavascriptGlobals {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(JavascriptGlobalsVisitor, name, diagnostics)
+    }
+}
+
+struct JavascriptGlobalsVisitor;
+impl DeserializationVisitor for JavascriptGlobalsVisitor {
+    type Output = JavascriptGlobals;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::ARRAY.union(VisitableType::MAP);
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: biome_rowan::TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(JavascriptGlobals::List(StringSet::new(
+            items
+                .flatten()
+                .filter_map(|item| Deserializable::deserialize(&item, "", diagnostics))
+                .collect(),
+        )))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: biome_rowan::TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = IndexMap::new();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(access) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                result.insert(key_text.text().to_string(), access);
+            }
+        }
+        Some(JavascriptGlobals::Map(result))
+    }
+}
+
+impl Deserializable for JavascriptGlobalAccess {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.text().parse::<Self>() {
+            Some(value)
+        } else {
+            const ALLOWED_VARIANTS: &[&str] = &["readonly", "writable", "off"];
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value_text.text(),
+                value.range(),
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
+
+impl Deserializable for JavascriptEnvironments {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        Deserializable::deserialize(value, name, diagnostics).map(JavascriptEnvironments)
+    }
+}
+
+impl Deserializable for JavascriptEnvironment {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.text().parse::<Self>() {
+            Some(value)
+        } else {
+            const ALLOWED_VARIANTS: &[&str] = &[
+                "browser",
+                "node",
+                "commonjs",
+                "worker",
+                "serviceWorker",
+                "es5",
+                "es2015",
+                "es2017",
+                "es2020",
+                "es2021",
+                "es2024",
+            ];
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value_text.text(),
+                value.range(),
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
+
 impl Deserializable for JavascriptOrganizeImports {
     fn deserialize(
         value: &impl DeserializableValue,
@@ -134,6 +134,7 @@ impl DeserializationVisitor for JsonFormatterVisitor {
             "indentWidth",
             "lineEnding",
             "lineWidth",
+            "arrayFill",
         ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
@@ -168,6 +169,9 @@ impl DeserializationVisitor for JsonFormatterVisitor {
                 "lineWidth" => {
                     result.line_width = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "arrayFill" => {
+                    result.array_fill = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => {
                     diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                         unknown_key,
@@ -1,4 +1,4 @@
-use crate::configuration::linter::{RulePlainConfiguration, RuleWithOptions};
+use crate::configuration::linter::{RuleFixKind, RulePlainConfiguration, RuleWithOptions};
 use crate::configuration::LinterConfiguration;
 use crate::RuleConfiguration;
 use biome_deserialize::{
@@ -30,7 +30,14 @@ impl DeserializationVisitor for LinterConfigurationVisitor {
         _name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self::Output> {
-        const ALLOWED_KEYS: &[&str] = &["enabled", "rules", "include", "ignore"];
+        const ALLOWED_KEYS: &[&str] = &[
+            "enabled",
+            "rules",
+            "include",
+            "ignore",
+            "timeout",
+            "requireSuppressionReasons",
+        ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
@@ -49,6 +56,13 @@ impl DeserializationVisitor for LinterConfigurationVisitor {
                 "rules" => {
                     result.rules = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "timeout" => {
+                    result.timeout = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "requireSuppressionReasons" => {
+                    result.require_suppression_reasons =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -94,7 +108,7 @@ impl DeserializationVisitor for RuleConfigurationVisitor {
         rule_name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self::Output> {
-        const ALLOWED_KEYS: &[&str] = &["level", "options"];
+        const ALLOWED_KEYS: &[&str] = &["level", "options", "fix"];
         let mut result = RuleWithOptions::default();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
@@ -107,6 +121,9 @@ impl DeserializationVisitor for RuleConfigurationVisitor {
                 "options" => {
                     result.options = Deserializable::deserialize(&value, rule_name, diagnostics);
                 }
+                "fix" => {
+                    result.fix = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -119,12 +136,12 @@ impl DeserializationVisitor for RuleConfigurationVisitor {
 }
 
 impl RulePlainConfiguration {
-    fn deserialize_from_str(
+    pub(crate) fn deserialize_from_str(
         value: Text,
         range: TextRange,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self> {
-        const ALLOWED_VARIANTS: &[&str] = &["error", "warn", "off"];
+        const ALLOWED_VARIANTS: &[&str] = &["error", "warn", "info", "hint", "off"];
         if let Ok(value) = value.text().parse::<Self>() {
             Some(value)
         } else {
@@ -148,3 +165,24 @@ impl Deserializable for RulePlainConfiguration {
         Self::deserialize_from_str(value_text, value.range(), diagnostics)
     }
 }
+
+impl Deserializable for RuleFixKind {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        const ALLOWED_VARIANTS: &[&str] = &["safe", "unsafe"];
+        let value_text = Text::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.text().parse::<Self>() {
+            Some(value)
+        } else {
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value_text.text(),
+                value.range(),
+                ALLOWED_VARIANTS,
+            ));
+            None
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::configuration::markdown::{MarkdownConfiguration, MarkdownFormatter};
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor, Text,
+    VisitableType,
+};
+
+impl Deserializable for MarkdownConfiguration {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(MarkdownConfigurationVisitor, name, diagnostics)
+    }
+}
+
+struct MarkdownConfigurationVisitor;
+impl DeserializationVisitor for MarkdownConfigurationVisitor {
+    type Output = MarkdownConfiguration;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: biome_rowan::TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["formatter"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "formatter" => {
+                    result.formatter = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                unknown_key => {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        unknown_key,
+                        key.range(),
+                        ALLOWED_KEYS,
+                    ));
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+impl Deserializable for MarkdownFormatter {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(MarkdownFormatterVisitor, name, diagnostics)
+    }
+}
+
+struct MarkdownFormatterVisitor;
+impl DeserializationVisitor for MarkdownFormatterVisitor {
+    type Output = MarkdownFormatter;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: biome_rowan::TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["enabled"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "enabled" => {
+                    result.enabled = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                unknown_key => {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        unknown_key,
+                        key.range(),
+                        ALLOWED_KEYS,
+                    ));
+                }
+            }
+        }
+        Some(result)
+    }
+}
@@ -2,11 +2,13 @@
 //!
 
 mod configuration;
+mod domains;
 mod files;
 mod formatter;
 mod javascript;
 mod json_impl;
 mod linter;
+mod markdown;
 mod organize_imports;
 mod overrides;
 mod rules;
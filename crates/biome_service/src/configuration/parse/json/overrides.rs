@@ -1,5 +1,5 @@
 use crate::configuration::overrides::{
-    OverrideFormatterConfiguration, OverrideLinterConfiguration,
+    OverrideFilesConfiguration, OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, OverridePattern, Overrides,
 };
 use biome_deserialize::{
@@ -50,8 +50,10 @@ impl DeserializationVisitor for OverridePatternVisitor {
             "formatter",
             "linter",
             "organizeImports",
+            "files",
             "javascript",
             "json",
+            "markdown",
         ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
@@ -75,12 +77,18 @@ impl DeserializationVisitor for OverridePatternVisitor {
                     result.organize_imports =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "files" => {
+                    result.files = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "javascript" => {
                     result.javascript = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
                 "json" => {
                     result.json = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "markdown" => {
+                    result.markdown = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -123,6 +131,7 @@ impl DeserializationVisitor for OverrideFormatterConfigurationVisitor {
             "indentWidth",
             "lineEnding",
             "lineWidth",
+            "prettierCompatibility",
         ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
@@ -161,6 +170,10 @@ impl DeserializationVisitor for OverrideFormatterConfigurationVisitor {
                     result.format_with_errors =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "prettierCompatibility" => {
+                    result.prettier_compatibility =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 unknown_key => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                     unknown_key,
                     key.range(),
@@ -266,3 +279,47 @@ impl DeserializationVisitor for OverrideOrganizeImportsConfigurationVisitor {
         Some(result)
     }
 }
+
+impl Deserializable for OverrideFilesConfiguration {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(OverrideFilesConfigurationVisitor, name, diagnostics)
+    }
+}
+
+struct OverrideFilesConfigurationVisitor;
+impl DeserializationVisitor for OverrideFilesConfigurationVisitor {
+    type Output = OverrideFilesConfiguration;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: biome_rowan::TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        const ALLOWED_KEYS: &[&str] = &["maxSize"];
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "maxSize" => {
+                    result.max_size = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                _ => diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                    key_text.text(),
+                    key.range(),
+                    ALLOWED_KEYS,
+                )),
+            }
+        }
+        Some(result)
+    }
+}
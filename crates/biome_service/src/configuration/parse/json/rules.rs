@@ -117,7 +117,21 @@ impl Deserializable for A11y {
         struct Visitor;
         impl DeserializationVisitor for Visitor {
             type Output = A11y;
-            const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+            const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+            fn visit_str(
+                self,
+                value: Text,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let level =
+                    RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)?;
+                Some(Self::Output {
+                    level: Some(level),
+                    ..Self::Output::default()
+                })
+            }
             fn visit_map(
                 self,
                 members: impl Iterator<
@@ -143,6 +157,10 @@ impl Deserializable for A11y {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noAccessKey" => {
                             result.no_access_key =
                                 Deserializable::deserialize(&value, "noAccessKey", diagnostics);
@@ -313,6 +331,7 @@ impl Deserializable for A11y {
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noAccessKey",
                                     "noAriaUnsupportedElements",
                                     "noAutofocus",
@@ -368,7 +387,21 @@ impl Deserializable for Complexity {
         struct Visitor;
         impl DeserializationVisitor for Visitor {
             type Output = Complexity;
-            const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+            const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+            fn visit_str(
+                self,
+                value: Text,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let level =
+                    RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)?;
+                Some(Self::Output {
+                    level: Some(level),
+                    ..Self::Output::default()
+                })
+            }
             fn visit_map(
                 self,
                 members: impl Iterator<
@@ -394,6 +427,10 @@ impl Deserializable for Complexity {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noBannedTypes" => {
                             result.no_banned_types =
                                 Deserializable::deserialize(&value, "noBannedTypes", diagnostics);
@@ -540,6 +577,7 @@ impl Deserializable for Complexity {
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noBannedTypes",
                                     "noExcessiveCognitiveComplexity",
                                     "noExtraBooleanCast",
@@ -591,7 +629,21 @@ impl Deserializable for Correctness {
         struct Visitor;
         impl DeserializationVisitor for Visitor {
             type Output = Correctness;
-            const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+            const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+            fn visit_str(
+                self,
+                value: Text,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let level =
+                    RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)?;
+                Some(Self::Output {
+                    level: Some(level),
+                    ..Self::Output::default()
+                })
+            }
             fn visit_map(
                 self,
                 members: impl Iterator<
@@ -617,6 +669,10 @@ impl Deserializable for Correctness {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noChildrenProp" => {
                             result.no_children_prop =
                                 Deserializable::deserialize(&value, "noChildrenProp", diagnostics);
@@ -819,6 +875,7 @@ impl Deserializable for Correctness {
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noChildrenProp",
                                     "noConstAssign",
                                     "noConstantCondition",
@@ -879,7 +936,21 @@ impl Deserializable for Nursery {
         struct Visitor;
         impl DeserializationVisitor for Visitor {
             type Output = Nursery;
-            const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+            const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+            fn visit_str(
+                self,
+                value: Text,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let level =
+                    RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)?;
+                Some(Self::Output {
+                    level: Some(level),
+                    ..Self::Output::default()
+                })
+            }
             fn visit_map(
                 self,
                 members: impl Iterator<
@@ -905,6 +976,10 @@ impl Deserializable for Nursery {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noAriaHiddenOnFocusable" => {
                             result.no_aria_hidden_on_focusable = Deserializable::deserialize(
                                 &value,
@@ -930,6 +1005,10 @@ impl Deserializable for Nursery {
                                 diagnostics,
                             );
                         }
+                        "noGlobalAssign" => {
+                            result.no_global_assign =
+                                Deserializable::deserialize(&value, "noGlobalAssign", diagnostics);
+                        }
                         "noImplicitAnyLet" => {
                             result.no_implicit_any_let = Deserializable::deserialize(
                                 &value,
@@ -1016,10 +1095,12 @@ impl Deserializable for Nursery {
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noAriaHiddenOnFocusable",
                                     "noDefaultExport",
                                     "noDuplicateJsonKeys",
                                     "noEmptyBlockStatements",
+                                    "noGlobalAssign",
                                     "noImplicitAnyLet",
                                     "noMisleadingCharacterClass",
                                     "noUnusedImports",
@@ -1060,7 +1141,21 @@ impl Deserializable for Performance {
         struct Visitor;
         impl DeserializationVisitor for Visitor {
             type Output = Performance;
-            const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+            const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+            fn visit_str(
+                self,
+                value: Text,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let level =
+                    RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)?;
+                Some(Self::Output {
+                    level: Some(level),
+                    ..Self::Output::default()
+                })
+            }
             fn visit_map(
                 self,
                 members: impl Iterator<
@@ -1086,6 +1181,10 @@ impl Deserializable for Performance {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noAccumulatingSpread" => {
                             result.no_accumulating_spread = Deserializable::deserialize(
                                 &value,
@@ -1101,7 +1200,13 @@ impl Deserializable for Performance {
                             diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                                 unknown_key,
                                 key.range(),
-                                &["recommended", "all", "noAccumulatingSpread", "noDelete"],
+                                &[
+                                    "recommended",
+                                    "all",
+                                    "level",
+                                    "noAccumulatingSpread",
+                                    "noDelete",
+                                ],
                             ));
                         }
                     }
@@ -1128,7 +1233,21 @@ impl Deserializable for Security {
         struct Visitor;
         impl DeserializationVisitor for Visitor {
             type Output = Security;
-            const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+            const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+            fn visit_str(
+                self,
+                value: Text,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let level =
+                    RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)?;
+                Some(Self::Output {
+                    level: Some(level),
+                    ..Self::Output::default()
+                })
+            }
             fn visit_map(
                 self,
                 members: impl Iterator<
@@ -1154,6 +1273,10 @@ impl Deserializable for Security {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noDangerouslySetInnerHtml" => {
                             result.no_dangerously_set_inner_html = Deserializable::deserialize(
                                 &value,
@@ -1176,6 +1299,7 @@ impl Deserializable for Security {
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noDangerouslySetInnerHtml",
                                     "noDangerouslySetInnerHtmlWithChildren",
                                 ],
@@ -1205,7 +1329,21 @@ impl Deserializable for Style {
         struct Visitor;
         impl DeserializationVisitor for Visitor {
             type Output = Style;
-            const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+            const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+            fn visit_str(
+                self,
+                value: Text,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let level =
+                    RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)?;
+                Some(Self::Output {
+                    level: Some(level),
+                    ..Self::Output::default()
+                })
+            }
             fn visit_map(
                 self,
                 members: impl Iterator<
@@ -1231,6 +1369,10 @@ impl Deserializable for Style {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noArguments" => {
                             result.no_arguments =
                                 Deserializable::deserialize(&value, "noArguments", diagnostics);
@@ -1435,6 +1577,7 @@ impl Deserializable for Style {
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noArguments",
                                     "noCommaOperator",
                                     "noImplicitBoolean",
@@ -1494,7 +1637,21 @@ impl Deserializable for Suspicious {
         struct Visitor;
         impl DeserializationVisitor for Visitor {
             type Output = Suspicious;
-            const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+            const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+            fn visit_str(
+                self,
+                value: Text,
+                range: TextRange,
+                _name: &str,
+                diagnostics: &mut Vec<DeserializationDiagnostic>,
+            ) -> Option<Self::Output> {
+                let level =
+                    RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)?;
+                Some(Self::Output {
+                    level: Some(level),
+                    ..Self::Output::default()
+                })
+            }
             fn visit_map(
                 self,
                 members: impl Iterator<
@@ -1520,6 +1677,10 @@ impl Deserializable for Suspicious {
                             result.all =
                                 Deserializable::deserialize(&value, &key_text, diagnostics);
                         }
+                        "level" => {
+                            result.level =
+                                Deserializable::deserialize(&value, &key_text, diagnostics);
+                        }
                         "noApproximativeNumericConstant" => {
                             result.no_approximative_numeric_constant = Deserializable::deserialize(
                                 &value,
@@ -1778,6 +1939,7 @@ impl Deserializable for Suspicious {
                                 &[
                                     "recommended",
                                     "all",
+                                    "level",
                                     "noApproximativeNumericConstant",
                                     "noArrayIndexKey",
                                     "noAssignInExpressions",
@@ -28,7 +28,13 @@ impl DeserializationVisitor for VcsConfigurationVisitor {
         _name: &str,
         diagnostics: &mut Vec<DeserializationDiagnostic>,
     ) -> Option<Self::Output> {
-        const ALLOWED_KEYS: &[&str] = &["clientKind", "enabled", "useIgnoreFile", "root"];
+        const ALLOWED_KEYS: &[&str] = &[
+            "clientKind",
+            "enabled",
+            "useIgnoreFile",
+            "root",
+            "defaultBranch",
+        ];
         let mut result = Self::Output::default();
         for (key, value) in members.flatten() {
             let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
@@ -49,6 +55,10 @@ impl DeserializationVisitor for VcsConfigurationVisitor {
                 "root" => {
                     result.root = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "defaultBranch" => {
+                    result.default_branch =
+                        Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_unknown_key(
                         &key_text,
@@ -82,7 +92,7 @@ impl Deserializable for VcsClientKind {
         if let Ok(value) = value_text.text().parse::<Self>() {
             Some(value)
         } else {
-            const ALLOWED_VARIANTS: &[&str] = &["git"];
+            const ALLOWED_VARIANTS: &[&str] = &["git", "mercurial", "jujutsu"];
             diagnostics.push(DeserializationDiagnostic::new_unknown_value(
                 value_text.text(),
                 value.range(),
@@ -0,0 +1,246 @@
+//! Edits the content of a configuration file. Only the edited members are printed,
+//! so the formatting of the rest of the file is kept.
+
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::{AnyJsonValue, JsonObjectValue, TextRange, TextSize};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// Returns the content of a configuration file where the rule `<group>/<rule>` is turned off,
+/// or `None` if the content can't be parsed. An empty content is a new configuration file.
+pub fn disable_rule(content: &str, group: &str, rule: &str) -> Option<String> {
+    let (range, text) = disable_rule_edit(content, group, rule)?;
+    let mut content = content.to_string();
+    content.replace_range(std::ops::Range::<usize>::from(range), &text);
+    Some(content)
+}
+
+/// Returns the edit of a configuration file that turns off the rule `<group>/<rule>`,
+/// or `None` if the content can't be parsed. An empty content is a new configuration file.
+pub fn disable_rule_edit(content: &str, group: &str, rule: &str) -> Option<(TextRange, String)> {
+    let rule_path = ["linter", "rules", group, rule];
+    if content.trim().is_empty() {
+        let member = print_member(&rule_path, "\"off\"", "\t", "\t");
+        return Some((
+            TextRange::up_to(TextSize::of(content)),
+            format!("{{\n\t{member}\n}}\n"),
+        ));
+    }
+
+    let parsed = parse_json(content, JsonParserOptions::default());
+    if parsed.has_errors() {
+        return None;
+    }
+    let AnyJsonValue::JsonObjectValue(root) = parsed.tree().value().ok()? else {
+        return None;
+    };
+
+    // The severity of a rule that has options is in its `level` member
+    let has_options = matches!(
+        find_member(&root, &rule_path),
+        Some(AnyJsonValue::JsonObjectValue(_))
+    );
+    let level_path = ["linter", "rules", group, rule, "level"];
+    let path: &[&str] = if has_options { &level_path } else { &rule_path };
+
+    set_member(content, &root, path, "\"off\"")
+}
+
+/// Returns the value at `path` in `object`
+fn find_member(object: &JsonObjectValue, path: &[&str]) -> Option<AnyJsonValue> {
+    let (key, rest) = path.split_first()?;
+    let value = member_value(object, key)?;
+    if rest.is_empty() {
+        return Some(value);
+    }
+    match value {
+        AnyJsonValue::JsonObjectValue(object) => find_member(&object, rest),
+        _ => None,
+    }
+}
+
+fn member_value(object: &JsonObjectValue, key: &str) -> Option<AnyJsonValue> {
+    object.json_member_list().iter().find_map(|member| {
+        let member = member.ok()?;
+        let name = member.name().ok()?.inner_string_text().ok()?;
+        if name.text() == key {
+            member.value().ok()
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the edit that sets the value at `path` in `object`. The objects of the path that
+/// don't exist are created.
+fn set_member(
+    content: &str,
+    object: &JsonObjectValue,
+    path: &[&str],
+    value: &str,
+) -> Option<(TextRange, String)> {
+    let (key, rest) = path.split_first()?;
+    let indent_unit = indent_unit(content);
+
+    if let Some(member_value) = member_value(object, key) {
+        let range = member_value.range();
+        return match member_value {
+            _ if rest.is_empty() => Some((range, value.to_string())),
+            AnyJsonValue::JsonObjectValue(object) => set_member(content, &object, rest, value),
+            // A value that isn't an object is replaced by the objects of the path
+            _ => {
+                let indent = line_indent(content, range.start());
+                let inner_indent = format!("{indent}{indent_unit}");
+                let member = print_member(rest, value, &inner_indent, indent_unit);
+                Some((range, format!("{{\n{inner_indent}{member}\n{indent}}}")))
+            }
+        };
+    }
+
+    let list = object.json_member_list();
+    match list.last() {
+        Some(last) => {
+            let last = last.ok()?;
+            let indent = line_indent(content, last.range().start());
+            let member = print_member(path, value, indent, indent_unit);
+            let end = list
+                .trailing_separator()
+                .map_or(last.range().end(), |separator| {
+                    separator.text_trimmed_range().end()
+                });
+            let separator = if list.trailing_separator().is_some() {
+                ""
+            } else {
+                ","
+            };
+            Some((
+                TextRange::empty(end),
+                format!("{separator}\n{indent}{member}"),
+            ))
+        }
+        None => {
+            let l_curly = object.l_curly_token().ok()?;
+            let r_curly = object.r_curly_token().ok()?;
+            let indent = line_indent(content, l_curly.text_trimmed_range().start());
+            let inner_indent = format!("{indent}{indent_unit}");
+            let member = print_member(path, value, &inner_indent, indent_unit);
+            Some((
+                TextRange::new(
+                    l_curly.text_trimmed_range().end(),
+                    r_curly.text_trimmed_range().start(),
+                ),
+                format!("\n{inner_indent}{member}\n{indent}"),
+            ))
+        }
+    }
+}
+
+/// Prints the member `"<key>": <value>` of the path, nesting the objects of the path
+fn print_member(path: &[&str], value: &str, indent: &str, indent_unit: &str) -> String {
+    match path.split_first() {
+        Some((key, [])) => format!("\"{key}\": {value}"),
+        Some((key, rest)) => {
+            let inner_indent = format!("{indent}{indent_unit}");
+            let member = print_member(rest, value, &inner_indent, indent_unit);
+            format!("\"{key}\": {{\n{inner_indent}{member}\n{indent}}}")
+        }
+        None => value.to_string(),
+    }
+}
+
+/// Returns the whitespace at the start of the line that contains `offset`
+fn line_indent(content: &str, offset: TextSize) -> &str {
+    let line_start = content[..usize::from(offset)]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1);
+    let line = &content[line_start..];
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+/// Returns the indentation used by the file, tabs if the file isn't indented
+fn indent_unit(content: &str) -> &str {
+    content
+        .lines()
+        .map(|line| &line[..line.len() - line.trim_start_matches([' ', '\t']).len()])
+        .find(|indent| !indent.is_empty())
+        .unwrap_or("\t")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{disable_rule, disable_rule_edit};
+    use biome_json_syntax::{TextRange, TextSize};
+
+    #[test]
+    fn creates_the_missing_members() {
+        assert_eq!(
+            disable_rule("", "suspicious", "noDebugger").unwrap(),
+            "{\n\t\"linter\": {\n\t\t\"rules\": {\n\t\t\t\"suspicious\": {\n\t\t\t\t\"noDebugger\": \"off\"\n\t\t\t}\n\t\t}\n\t}\n}\n"
+        );
+
+        let content = r#"{
+  "$schema": "./node_modules/@biomejs/biome/configuration_schema.json",
+  "linter": {
+    "rules": {
+      "recommended": true
+    }
+  }
+}
+"#;
+        assert_eq!(
+            disable_rule(content, "suspicious", "noDebugger").unwrap(),
+            r#"{
+  "$schema": "./node_modules/@biomejs/biome/configuration_schema.json",
+  "linter": {
+    "rules": {
+      "recommended": true,
+      "suspicious": {
+        "noDebugger": "off"
+      }
+    }
+  }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn replaces_the_level_of_the_rule() {
+        let content = r#"{
+	"linter": { "rules": { "style": { "noVar": "error" } } }
+}"#;
+        assert_eq!(
+            disable_rule(content, "style", "noVar").unwrap(),
+            r#"{
+	"linter": { "rules": { "style": { "noVar": "off" } } }
+}"#
+        );
+
+        let content = r#"{
+	"linter": { "rules": { "style": { "useNamingConvention": { "level": "warn", "options": {} } } } }
+}"#;
+        assert_eq!(
+            disable_rule(content, "style", "useNamingConvention").unwrap(),
+            r#"{
+	"linter": { "rules": { "style": { "useNamingConvention": { "level": "off", "options": {} } } } }
+}"#
+        );
+    }
+
+    #[test]
+    fn returns_only_the_edited_member() {
+        let content = r#"{ "linter": { "rules": { "style": { "noVar": "error" } } } }"#;
+        assert_eq!(
+            disable_rule_edit(content, "style", "noVar").unwrap(),
+            (
+                TextRange::new(TextSize::from(45), TextSize::from(52)),
+                String::from("\"off\"")
+            )
+        );
+    }
+
+    #[test]
+    fn ignores_invalid_content() {
+        assert!(disable_rule("{ \"linter\": ", "style", "noVar").is_none());
+        assert!(disable_rule("[]", "style", "noVar").is_none());
+    }
+}
@@ -0,0 +1,89 @@
+//! The variables that the strings of the configuration can reference: `${env:NAME}`, the
+//! environment variable `NAME`, and `${workspaceRoot}`, the directory of the configuration file.
+
+use crate::Configuration;
+use biome_deserialize::interpolation::{interpolate, Variable};
+use biome_deserialize::{DeserializationDiagnostic, StringSet};
+use std::path::Path;
+
+/// The name of the variable that references the directory of the configuration file
+const WORKSPACE_ROOT: &str = "workspaceRoot";
+
+impl Configuration {
+    /// Replaces the variables referenced by the paths and the patterns of the configuration:
+    /// `$schema`, `extends`, `vcs.root`, and the `include` and `ignore` lists.
+    ///
+    /// A value that references an unknown variable is left untouched, and a diagnostic is
+    /// returned.
+    pub(crate) fn interpolate_variables(
+        &mut self,
+        configuration_directory: &Path,
+    ) -> Vec<DeserializationDiagnostic> {
+        let resolve = |variable: &Variable| match variable {
+            Variable::Env(name) => std::env::var(name).ok(),
+            Variable::Named(WORKSPACE_ROOT) => {
+                Some(configuration_directory.to_string_lossy().to_string())
+            }
+            Variable::Named(_) => None,
+        };
+        let mut diagnostics = vec![];
+        let mut interpolate_string = |value: &mut String| match interpolate(value, resolve) {
+            Ok(interpolated) => *value = interpolated,
+            Err(diagnostic) => diagnostics.push(diagnostic.note_with_list(
+                "The variables that Biome knows:",
+                &["env:<NAME>", WORKSPACE_ROOT],
+            )),
+        };
+
+        if let Some(schema) = self.schema.as_mut() {
+            interpolate_string(schema);
+        }
+        if let Some(root) = self.vcs.as_mut().and_then(|vcs| vcs.root.as_mut()) {
+            interpolate_string(root);
+        }
+
+        let mut string_sets = vec![];
+        string_sets.extend(self.extends.as_mut());
+        if let Some(files) = self.files.as_mut() {
+            string_sets.extend(files.include.as_mut());
+            string_sets.extend(files.ignore.as_mut());
+        }
+        if let Some(formatter) = self.formatter.as_mut() {
+            string_sets.extend(formatter.include.as_mut());
+            string_sets.extend(formatter.ignore.as_mut());
+        }
+        if let Some(linter) = self.linter.as_mut() {
+            string_sets.extend(linter.include.as_mut());
+            string_sets.extend(linter.ignore.as_mut());
+        }
+        if let Some(organize_imports) = self.organize_imports.as_mut() {
+            string_sets.extend(organize_imports.include.as_mut());
+            string_sets.extend(organize_imports.ignore.as_mut());
+        }
+        for pattern in self
+            .overrides
+            .iter_mut()
+            .flat_map(|overrides| &mut overrides.0)
+        {
+            string_sets.extend(pattern.include.as_mut());
+            string_sets.extend(pattern.ignore.as_mut());
+        }
+        for string_set in string_sets {
+            if string_set.iter().all(|value| !value.contains("${")) {
+                continue;
+            }
+            let values = std::mem::take(string_set).into_index_set();
+            *string_set = StringSet::new(
+                values
+                    .into_iter()
+                    .map(|mut value| {
+                        interpolate_string(&mut value);
+                        value
+                    })
+                    .collect(),
+            );
+        }
+
+        diagnostics
+    }
+}
@@ -1,18 +1,21 @@
 use crate::configuration::merge::MergeWith;
+use biome_fs::VcsClient;
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 const GIT_IGNORE_FILE_NAME: &str = ".gitignore";
+const MERCURIAL_IGNORE_FILE_NAME: &str = ".hgignore";
 
 /// Set of properties to integrate Biome with a VCS software.
 #[derive(Debug, Default, Deserialize, Serialize, Clone, Bpaf, Eq, PartialEq)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct VcsConfiguration {
-    /// The kind of client.
+    /// The kind of client. When it isn't set, Biome detects it from the folders of the
+    /// repository, e.g. `.git`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[bpaf(long("vcs-client-kind"), argument("git"), optional)]
+    #[bpaf(long("vcs-client-kind"), argument("git|mercurial|jujutsu"), optional)]
     pub client_kind: Option<VcsClientKind>,
 
     /// Whether Biome should integrate itself with the VCS client
@@ -33,6 +36,12 @@ pub struct VcsConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("vcs-root"), argument("PATH"), optional)]
     pub root: Option<String>,
+
+    /// The main branch of the project. The `--changed` argument of the CLI processes the
+    /// files changed compared to this branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("vcs-default-branch"), argument("BRANCH"), optional)]
+    pub default_branch: Option<String>,
 }
 
 impl VcsConfiguration {
@@ -61,6 +70,9 @@ impl MergeWith<VcsConfiguration> for VcsConfiguration {
         if let Some(root) = other.root {
             self.root = Some(root);
         }
+        if let Some(default_branch) = other.default_branch {
+            self.default_branch = Some(default_branch);
+        }
     }
 
     fn merge_with_if_not_default(&mut self, other: VcsConfiguration)
@@ -80,12 +92,44 @@ pub enum VcsClientKind {
     #[default]
     /// Integration with the git client as VCS
     Git,
+    /// Integration with the Mercurial client as VCS
+    Mercurial,
+    /// Integration with the Jujutsu client as VCS
+    Jujutsu,
 }
 
 impl VcsClientKind {
+    /// The clients in the order they are detected. A Jujutsu repository can be colocated
+    /// with a git repository, so it's detected first.
+    pub const DETECTION_ORDER: [VcsClientKind; 3] = [
+        VcsClientKind::Jujutsu,
+        VcsClientKind::Mercurial,
+        VcsClientKind::Git,
+    ];
+
     pub const fn ignore_file(&self) -> &'static str {
         match self {
-            VcsClientKind::Git => GIT_IGNORE_FILE_NAME,
+            // Jujutsu reads the ignore files of git
+            VcsClientKind::Git | VcsClientKind::Jujutsu => GIT_IGNORE_FILE_NAME,
+            VcsClientKind::Mercurial => MERCURIAL_IGNORE_FILE_NAME,
+        }
+    }
+
+    /// The folder that the client creates at the root of a repository
+    pub const fn repository_folder(&self) -> &'static str {
+        match self {
+            VcsClientKind::Git => ".git",
+            VcsClientKind::Mercurial => ".hg",
+            VcsClientKind::Jujutsu => ".jj",
+        }
+    }
+
+    /// The command line client used to query the changed files
+    pub const fn client(&self) -> VcsClient {
+        match self {
+            VcsClientKind::Git => VcsClient::Git,
+            VcsClientKind::Mercurial => VcsClient::Mercurial,
+            VcsClientKind::Jujutsu => VcsClient::Jujutsu,
         }
     }
 }
@@ -96,6 +140,8 @@ impl FromStr for VcsClientKind {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "git" => Ok(Self::Git),
+            "mercurial" => Ok(Self::Mercurial),
+            "jujutsu" => Ok(Self::Jujutsu),
             _ => Err("Value not supported for VcsClientKind"),
         }
     }
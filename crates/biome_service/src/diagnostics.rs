@@ -49,6 +49,8 @@ pub enum WorkspaceError {
     FileIgnored(FileIgnored),
     /// Emitted when a file could not be parsed because it's larger than the size limit
     FileTooLarge(FileTooLarge),
+    /// Emitted when a file is skipped because it looks generated or minified
+    FileGenerated(FileGenerated),
     /// Diagnostics emitted when querying the file system
     FileSystem(FileSystemDiagnostic),
 }
@@ -70,6 +72,10 @@ impl WorkspaceError {
         Self::FileTooLarge(FileTooLarge { path, size, limit })
     }
 
+    pub fn file_generated(path: String) -> Self {
+        Self::FileGenerated(FileGenerated { path })
+    }
+
     pub fn file_ignored(path: String) -> Self {
         Self::FileIgnored(FileIgnored { path })
     }
@@ -131,6 +137,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::CantReadFile(error) => error.category(),
             WorkspaceError::FileIgnored(error) => error.category(),
             WorkspaceError::FileTooLarge(error) => error.category(),
+            WorkspaceError::FileGenerated(error) => error.category(),
             WorkspaceError::FileSystem(error) => error.category(),
         }
     }
@@ -152,6 +159,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::CantReadFile(error) => error.description(fmt),
             WorkspaceError::FileIgnored(error) => error.description(fmt),
             WorkspaceError::FileTooLarge(error) => error.description(fmt),
+            WorkspaceError::FileGenerated(error) => error.description(fmt),
             WorkspaceError::FileSystem(error) => error.description(fmt),
         }
     }
@@ -173,6 +181,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::CantReadFile(error) => error.message(fmt),
             WorkspaceError::FileIgnored(error) => error.message(fmt),
             WorkspaceError::FileTooLarge(error) => error.message(fmt),
+            WorkspaceError::FileGenerated(error) => error.message(fmt),
             WorkspaceError::FileSystem(error) => error.message(fmt),
         }
     }
@@ -194,6 +203,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::CantReadFile(error) => error.severity(),
             WorkspaceError::FileIgnored(error) => error.severity(),
             WorkspaceError::FileTooLarge(error) => error.severity(),
+            WorkspaceError::FileGenerated(error) => error.severity(),
             WorkspaceError::FileSystem(error) => error.severity(),
         }
     }
@@ -215,6 +225,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::CantReadFile(error) => error.tags(),
             WorkspaceError::FileIgnored(error) => error.tags(),
             WorkspaceError::FileTooLarge(error) => error.tags(),
+            WorkspaceError::FileGenerated(error) => error.tags(),
             WorkspaceError::FileSystem(error) => error.tags(),
         }
     }
@@ -236,6 +247,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::CantReadFile(error) => error.location(),
             WorkspaceError::FileIgnored(error) => error.location(),
             WorkspaceError::FileTooLarge(error) => error.location(),
+            WorkspaceError::FileGenerated(error) => error.location(),
             WorkspaceError::FileSystem(error) => error.location(),
         }
     }
@@ -257,6 +269,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::CantReadFile(error) => Diagnostic::source(error),
             WorkspaceError::FileIgnored(error) => Diagnostic::source(error),
             WorkspaceError::FileTooLarge(error) => Diagnostic::source(error),
+            WorkspaceError::FileGenerated(error) => Diagnostic::source(error),
             WorkspaceError::FileSystem(error) => Diagnostic::source(error),
         }
     }
@@ -278,6 +291,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::CantReadFile(error) => error.advices(visitor),
             WorkspaceError::FileIgnored(error) => error.advices(visitor),
             WorkspaceError::FileTooLarge(error) => error.advices(visitor),
+            WorkspaceError::FileGenerated(error) => error.advices(visitor),
             WorkspaceError::FileSystem(error) => error.advices(visitor),
         }
     }
@@ -298,6 +312,7 @@ impl Diagnostic for WorkspaceError {
             WorkspaceError::CantReadFile(error) => error.verbose_advices(visitor),
             WorkspaceError::FileIgnored(error) => error.verbose_advices(visitor),
             WorkspaceError::FileTooLarge(error) => error.verbose_advices(visitor),
+            WorkspaceError::FileGenerated(error) => error.verbose_advices(visitor),
             WorkspaceError::FileSystem(error) => error.verbose_advices(visitor),
         }
     }
@@ -410,6 +425,11 @@ impl Diagnostic for FileTooLarge {
         Some(category!("internalError/fs"))
     }
 
+    /// The file is skipped, which shouldn't fail the commands that error on warnings
+    fn severity(&self) -> Severity {
+        Severity::Information
+    }
+
     fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
         fmt.write_markup(
             markup!{
@@ -427,6 +447,20 @@ impl Diagnostic for FileTooLarge {
     }
 }
 
+/// The file is skipped, which shouldn't fail the commands that error on warnings
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "internalError/fs",
+    message(
+        message("The file "{self.path}" looks generated or minified, it was skipped because "<Emphasis>"files.excludeGenerated"</Emphasis>" is enabled."),
+        description = "The file {path} looks generated or minified, it was skipped because files.excludeGenerated is enabled."
+    ),
+    severity = Information
+)]
+pub struct FileGenerated {
+    path: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SourceFileNotSupported {
     language: Language,
@@ -527,8 +561,8 @@ impl Diagnostic for TransportError {
 #[cfg(test)]
 mod test {
     use crate::diagnostics::{
-        CantReadDirectory, CantReadFile, DirtyWorkspace, FileIgnored, FileTooLarge, NotFound,
-        SourceFileNotSupported,
+        CantReadDirectory, CantReadFile, DirtyWorkspace, FileGenerated, FileIgnored, FileTooLarge,
+        NotFound, SourceFileNotSupported,
     };
     use crate::file_handlers::Language;
     use crate::{TransportError, WorkspaceError};
@@ -632,6 +666,17 @@ mod test {
         )
     }
 
+    #[test]
+    fn file_generated() {
+        snap_diagnostic(
+            "file_generated",
+            WorkspaceError::FileGenerated(FileGenerated {
+                path: "bundle.js".to_string(),
+            })
+            .with_file_path("bundle.js"),
+        )
+    }
+
     #[test]
     fn transport_channel_closed() {
         snap_diagnostic(
@@ -1,44 +1,62 @@
+mod folding_ranges;
+mod inlay_hints;
+mod semantic_tokens;
+mod symbols;
+
 use super::{
     AnalyzerCapabilities, DebugCapabilities, ExtensionHandler, FormatterCapabilities, LintParams,
     LintResults, Mime, ParserCapabilities,
 };
-use crate::configuration::to_analyzer_rules;
-use crate::file_handlers::{is_diagnostic_error, Features, FixAllParams, Language as LanguageId};
+use crate::configuration::{to_analyzer_globals, to_analyzer_rules};
+use crate::file_handlers::{
+    is_diagnostic_error, resolve_line_ending, to_rule_timing_entries, Features, FixAllParams,
+    Language as LanguageId,
+};
 use crate::settings::OverrideSettings;
 use crate::workspace::OrganizeImportsResult;
 use crate::{
     settings::{FormatSettings, Language, LanguageListSettings, LanguageSettings, SettingsHandle},
     workspace::{
-        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
+        CodeAction, Definition, DocumentSymbol, DocumentSymbolsResult, FindReferencesResult,
+        FixAction, FixFileMode, FixFileResult, FoldingRangesResult, GetSyntaxTreeResult,
+        GotoDefinitionResult, InlayHintsParams, InlayHintsResult, PrepareRenameResult,
+        PullActionsResult, RenameResult, SemanticTokensResult, SymbolReference,
+        SymbolReferenceKind,
     },
     Rules, WorkspaceError,
 };
 use biome_analyze::{
     AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, GroupCategory, Never,
-    QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
+    QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup, RuleTimings,
 };
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
-use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_formatter::{
+    FormatError, FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
+};
 use biome_fs::RomePath;
-use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
+use biome_js_analyze::utils::rename::{
+    AnyJsRenamableDeclaration, RenamableNode, RenameError, RenameSymbolExtensions,
+};
 use biome_js_analyze::{
     analyze, analyze_with_inspect_matcher, visit_registry, ControlFlowGraph, RuleError,
 };
 use biome_js_formatter::context::trailing_comma::TrailingComma;
 use biome_js_formatter::context::{
-    ArrowParentheses, BracketSameLine, BracketSpacing, JsFormatOptions, QuoteProperties,
-    QuoteStyle, Semicolons,
+    ArrowParentheses, BracketSameLine, BracketSpacing, DecoratorPlacement, JsFormatOptions,
+    QuoteProperties, QuoteStyle, Semicolons,
 };
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
 use biome_js_semantic::{semantic_model, SemanticModelOptions};
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
+    binding_ext::AnyJsIdentifierBinding, AnyJsClassMember, AnyJsModuleItem, AnyJsRoot,
+    JsFileSource, JsImport, JsLanguage, JsModuleSource, JsSyntaxNode, TextRange, TextSize,
+    TokenAtOffset, T,
 };
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, BatchMutationExt, Direction, FileSource, NodeCache};
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::path::PathBuf;
@@ -55,6 +73,9 @@ pub struct JsFormatterSettings {
     pub arrow_parentheses: Option<ArrowParentheses>,
     pub bracket_spacing: Option<BracketSpacing>,
     pub bracket_same_line: Option<BracketSameLine>,
+    pub decorator_placement: Option<DecoratorPlacement>,
+    pub dedent_tags: Option<Vec<String>>,
+    pub sql_tags: Option<Vec<String>>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
     pub indent_width: Option<IndentWidth>,
@@ -65,14 +86,14 @@ pub struct JsFormatterSettings {
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsParserSettings {
-    pub parse_class_parameter_decorators: bool,
+    pub parse_class_parameter_decorators: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsonParserSettings {
-    pub allow_comments: bool,
-    pub allow_trailing_commas: bool,
+    pub allow_comments: Option<bool>,
+    pub allow_trailing_commas: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -117,10 +138,16 @@ impl Language for JsLanguage {
         } else {
             global.indent_width.unwrap_or_default()
         };
+        let line_ending = if let Some(line_ending) = language.line_ending {
+            line_ending
+        } else {
+            global.line_ending.unwrap_or_default()
+        };
         let options = JsFormatOptions::new(path.as_path().try_into().unwrap_or_default())
             .with_indent_style(indent_style)
             .with_indent_width(indent_width)
             .with_line_width(line_width)
+            .with_line_ending(line_ending)
             .with_quote_style(language.quote_style.unwrap_or_default())
             .with_jsx_quote_style(language.jsx_quote_style.unwrap_or_default())
             .with_quote_properties(language.quote_properties.unwrap_or_default())
@@ -128,7 +155,11 @@ impl Language for JsLanguage {
             .with_semicolons(language.semicolons.unwrap_or_default())
             .with_arrow_parentheses(language.arrow_parentheses.unwrap_or_default())
             .with_bracket_spacing(language.bracket_spacing.unwrap_or_default())
-            .with_bracket_same_line(language.bracket_same_line.unwrap_or_default());
+            .with_bracket_same_line(language.bracket_same_line.unwrap_or_default())
+            .with_decorator_placement(language.decorator_placement.unwrap_or_default())
+            .with_dedent_tags(language.dedent_tags.clone().unwrap_or_default())
+            .with_sql_tags(language.sql_tags.clone().unwrap_or_default())
+            .with_prettier_compatibility(global.prettier_compatibility);
 
         overrides.override_js_format_options(path, options)
     }
@@ -152,7 +183,10 @@ impl ExtensionHandler for JsFileHandler {
 
     fn capabilities(&self) -> super::Capabilities {
         super::Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: Some(reparse),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: Some(debug_control_flow),
@@ -162,12 +196,22 @@ impl ExtensionHandler for JsFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
+                prepare_rename: Some(prepare_rename),
+                find_references: Some(find_references),
+                goto_definition: Some(goto_definition),
+                document_symbols: Some(document_symbols),
+                exported_symbols: Some(exported_symbols),
+                semantic_tokens: Some(semantic_tokens),
+                folding_ranges: Some(folding_ranges),
+                inlay_hints: Some(inlay_hints),
                 rename: Some(rename),
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
+                format_text: None,
                 format_range: Some(format_range),
+                format_text_range: None,
                 format_on_type: Some(format_on_type),
             },
         }
@@ -193,19 +237,15 @@ fn parse(
     settings: SettingsHandle,
     cache: &mut NodeCache,
 ) -> AnyParse {
-    let source_type =
-        JsFileSource::try_from(rome_path.as_path()).unwrap_or_else(|_| match language_hint {
-            LanguageId::JavaScriptReact => JsFileSource::jsx(),
-            LanguageId::TypeScript => JsFileSource::ts(),
-            LanguageId::TypeScriptReact => JsFileSource::tsx(),
-            _ => JsFileSource::js_module(),
-        });
+    let source_type = file_source(rome_path, language_hint);
     let parser_settings = &settings.as_ref().languages.javascript.parser;
     let overrides = &settings.as_ref().override_settings;
     let options = overrides.override_js_parser_options(
         rome_path,
         JsParserOptions {
-            parse_class_parameter_decorators: parser_settings.parse_class_parameter_decorators,
+            parse_class_parameter_decorators: parser_settings
+                .parse_class_parameter_decorators
+                .unwrap_or_default(),
         },
     );
     let parse = biome_js_parser::parse_js_with_cache(text, source_type, options, cache);
@@ -219,6 +259,37 @@ fn parse(
     )
 }
 
+/// Updates the syntax tree of the file when the change is limited to a single token
+fn reparse(
+    rome_path: &RomePath,
+    language_hint: LanguageId,
+    parse: &AnyParse,
+    range: TextRange,
+    text: &str,
+) -> Option<AnyParse> {
+    // The diagnostics of the previous content can't be moved to the new content
+    if !parse.diagnostics().is_empty() {
+        return None;
+    }
+    let root = biome_js_parser::reparse_token(&parse.syntax::<JsLanguage>(), range, text)?;
+    Some(AnyParse::new(
+        root.as_send()?,
+        vec![],
+        file_source(rome_path, language_hint).as_any_file_source(),
+    ))
+}
+
+/// The source type of the file at `rome_path`, or of its language when the path doesn't
+/// tell it
+fn file_source(rome_path: &RomePath, language_hint: LanguageId) -> JsFileSource {
+    JsFileSource::try_from(rome_path.as_path()).unwrap_or_else(|_| match language_hint {
+        LanguageId::JavaScriptReact => JsFileSource::jsx(),
+        LanguageId::TypeScript => JsFileSource::ts(),
+        LanguageId::TypeScriptReact => JsFileSource::tsx(),
+        _ => JsFileSource::js_module(),
+    })
+}
+
 fn debug_syntax_tree(_rome_path: &RomePath, parse: AnyParse) -> GetSyntaxTreeResult {
     let syntax: JsSyntaxNode = parse.syntax();
     let tree: AnyJsRoot = parse.tree();
@@ -276,9 +347,9 @@ fn debug_formatter_ir(
     parse: AnyParse,
     settings: SettingsHandle,
 ) -> Result<String, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(rome_path);
-
     let tree = parse.syntax();
+    let mut options = settings.format_options::<JsLanguage>(rome_path);
+    options.set_line_ending(resolve_line_ending(options.line_ending(), &tree));
     let formatted = format_node(options, &tree)?;
 
     let root_element = formatted.into_document();
@@ -291,15 +362,19 @@ fn lint(params: LintParams) -> LintResults {
             errors: 0,
             diagnostics: vec![],
             skipped_diagnostics: 0,
+            skipped_diagnostics_by_category: BTreeMap::new(),
+            rule_timings: Vec::new(),
         };
     };
     let tree = params.parse.tree();
     let mut diagnostics = params.parse.into_diagnostics();
 
-    let analyzer_options =
+    let mut analyzer_options =
         compute_analyzer_options(&params.settings, PathBuf::from(params.path.as_path()));
+    analyzer_options.rule_timings = params.rule_timings.then(RuleTimings::default);
 
     let mut diagnostic_count = diagnostics.len() as u64;
+    let mut skipped_diagnostics_by_category = BTreeMap::new();
     let mut errors = diagnostics
         .iter()
         .filter(|diag| diag.severity() <= Severity::Error)
@@ -349,6 +424,10 @@ fn lint(params: LintParams) -> LintResults {
                     let error = diagnostic.with_severity(severity);
 
                     diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                } else if let Some(category) = diagnostic.category() {
+                    *skipped_diagnostics_by_category
+                        .entry(category.name().to_string())
+                        .or_insert(0) += 1;
                 }
             }
 
@@ -368,6 +447,8 @@ fn lint(params: LintParams) -> LintResults {
         diagnostics,
         errors,
         skipped_diagnostics,
+        skipped_diagnostics_by_category,
+        rule_timings: to_rule_timing_entries(analyzer_options.rule_timings),
     }
 }
 
@@ -475,6 +556,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
         should_format,
         rome_path,
         mut filter,
+        suppression_reason,
     } = params;
 
     let file_source = parse
@@ -487,7 +569,9 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
 
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(rome_path.as_path()));
+    let mut analyzer_options =
+        compute_analyzer_options(&settings, PathBuf::from(rome_path.as_path()));
+    analyzer_options.configuration.suppression_reason = suppression_reason.map(String::from);
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
             let current_diagnostic = signal.diagnostic();
@@ -499,8 +583,11 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
             }
 
             for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
+                // suppression actions should not be part of the fixes (safe or suggested), they
+                // are only applied when adding suppression comments
+                if action.is_suppression()
+                    != matches!(fix_file_mode, FixFileMode::ApplySuppressions)
+                {
                     continue;
                 }
 
@@ -523,6 +610,12 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                             return ControlFlow::Break(action);
                         }
                     }
+                    // The first suppression action adds the comment on the line of the
+                    // diagnostic, the next one suppresses the rule in the whole file
+                    FixFileMode::ApplySuppressions => {
+                        errors = errors.saturating_sub(1);
+                        return ControlFlow::Break(action);
+                    }
                 }
             }
 
@@ -554,12 +647,10 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
             }
             None => {
                 let code = if should_format {
-                    format_node(
-                        settings.format_options::<JsLanguage>(rome_path),
-                        tree.syntax(),
-                    )?
-                    .print()?
-                    .into_code()
+                    let mut options = settings.format_options::<JsLanguage>(rome_path);
+                    options
+                        .set_line_ending(resolve_line_ending(options.line_ending(), tree.syntax()));
+                    format_node(options, tree.syntax())?.print()?.into_code()
                 } else {
                     tree.syntax().to_string()
                 };
@@ -580,11 +671,12 @@ fn format(
     parse: AnyParse,
     settings: SettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(rome_path);
+    let tree = parse.syntax();
+    let mut options = settings.format_options::<JsLanguage>(rome_path);
+    options.set_line_ending(resolve_line_ending(options.line_ending(), &tree));
 
     debug!("Options used for format: \n{}", options);
 
-    let tree = parse.syntax();
     info!("Format file {}", rome_path.display());
     let formatted = format_node(options, &tree)?;
     match formatted.print() {
@@ -602,9 +694,10 @@ fn format_range(
     settings: SettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(rome_path);
-
     let tree = parse.syntax();
+    let mut options = settings.format_options::<JsLanguage>(rome_path);
+    options.set_line_ending(resolve_line_ending(options.line_ending(), &tree));
+
     let printed = biome_js_formatter::format_range(options, &tree, range)?;
     Ok(printed)
 }
@@ -616,9 +709,9 @@ fn format_on_type(
     settings: SettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(rome_path);
-
     let tree = parse.syntax();
+    let mut options = settings.format_options::<JsLanguage>(rome_path);
+    options.set_line_ending(resolve_line_ending(options.line_ending(), &tree));
 
     let range = tree.text_range();
     if offset < range.start() || offset > range.end() {
@@ -630,22 +723,194 @@ fn format_on_type(
 
     let token = match tree.token_at_offset(offset) {
         // File is empty, do nothing
-        TokenAtOffset::None => panic!("empty file"),
+        TokenAtOffset::None => return Ok(Printed::new_empty()),
         TokenAtOffset::Single(token) => token,
         // The cursor should be right after the closing character that was just typed,
         // select the previous token as the correct one
         TokenAtOffset::Between(token, _) => token,
     };
+    // After a newline, the cursor is in the leading trivia of the next token
+    let token = if token.text_trimmed_range().start() >= offset {
+        token.prev_token()
+    } else {
+        Some(token)
+    };
 
-    let root_node = match token.parent() {
-        Some(node) => node,
-        None => panic!("found a token with no parent"),
+    // Only the statements that were just completed are formatted
+    let Some(token) = token.filter(|token| matches!(token.kind(), T![;] | T!['}'])) else {
+        return Ok(Printed::new_empty());
+    };
+    let is_statement = |node: &JsSyntaxNode| {
+        AnyJsModuleItem::can_cast(node.kind()) || AnyJsClassMember::can_cast(node.kind())
+    };
+    let Some(mut statement) = token
+        .ancestors()
+        .find(is_statement)
+        .or_else(|| token.parent())
+    else {
+        return Ok(Printed::new_empty());
     };
+    // The statements that end with the token are complete too, e.g. the `if` of a block
+    while let Some(parent) = statement.ancestors().skip(1).find(is_statement) {
+        if parent.last_token().as_ref() != Some(&token) {
+            break;
+        }
+        statement = parent;
+    }
 
-    let printed = biome_js_formatter::format_sub_tree(options, &root_node)?;
+    let printed = biome_js_formatter::format_range(options, &tree, statement.text_trimmed_range())?;
     Ok(printed)
 }
 
+/// Returns the node of the token at `offset`, which may be a renamable node
+fn node_at_offset(parse: &AnyParse, offset: TextSize) -> Option<JsSyntaxNode> {
+    parse
+        .syntax::<JsLanguage>()
+        .descendants_tokens(Direction::Next)
+        .find(|token| token.text_range().contains(offset))
+        .and_then(|token| token.parent())
+}
+
+fn prepare_rename(
+    parse: AnyParse,
+    symbol_at: TextSize,
+) -> Result<PrepareRenameResult, WorkspaceError> {
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+
+    let Some(node) = node_at_offset(&parse, symbol_at) else {
+        return Err(WorkspaceError::RenameError(
+            RenameError::CannotFindDeclaration(String::new()),
+        ));
+    };
+    let name = node.text_trimmed().to_string();
+    let range = node.text_trimmed_range();
+    let declaration =
+        AnyJsRenamableDeclaration::try_from(node).map_err(WorkspaceError::RenameError)?;
+    // Globals and unresolved references don't have a binding in the file
+    if declaration.binding(&model).is_none() {
+        return Err(WorkspaceError::RenameError(
+            RenameError::CannotFindDeclaration(name),
+        ));
+    }
+
+    Ok(PrepareRenameResult { range, name })
+}
+
+fn find_references(
+    parse: AnyParse,
+    symbol_at: TextSize,
+) -> Result<FindReferencesResult, WorkspaceError> {
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+
+    let binding = node_at_offset(&parse, symbol_at)
+        .and_then(|node| AnyJsRenamableDeclaration::try_from(node).ok())
+        .and_then(|declaration| declaration.binding(&model))
+        .and_then(AnyJsIdentifierBinding::cast);
+
+    let Some(binding) = binding else {
+        return Ok(FindReferencesResult { references: vec![] });
+    };
+
+    let binding = model.as_binding(&binding);
+    let declaration = SymbolReference {
+        range: binding.syntax().text_trimmed_range(),
+        kind: SymbolReferenceKind::Declaration,
+    };
+    let mut references: Vec<_> = std::iter::once(declaration)
+        .chain(binding.all_references().map(|reference| SymbolReference {
+            range: reference.syntax().text_trimmed_range(),
+            kind: if reference.is_write() {
+                SymbolReferenceKind::Write
+            } else {
+                SymbolReferenceKind::Read
+            },
+        }))
+        .collect();
+    references.sort_by_key(|reference| reference.range.start());
+
+    Ok(FindReferencesResult { references })
+}
+
+fn goto_definition(
+    parse: AnyParse,
+    symbol_at: TextSize,
+) -> Result<GotoDefinitionResult, WorkspaceError> {
+    let Some(node) = node_at_offset(&parse, symbol_at) else {
+        return Ok(GotoDefinitionResult { definition: None });
+    };
+
+    // The cursor is on the source of an import or an export
+    if let Some(source) = JsModuleSource::cast_ref(&node) {
+        let definition = source
+            .inner_string_text()
+            .ok()
+            .map(|specifier| Definition::Module {
+                specifier: specifier.to_string(),
+            });
+        return Ok(GotoDefinitionResult { definition });
+    }
+
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+
+    let definition = AnyJsRenamableDeclaration::try_from(node)
+        .ok()
+        .and_then(|declaration| declaration.binding(&model))
+        .map(|binding| {
+            let specifier = binding
+                .ancestors()
+                .find_map(JsImport::cast)
+                .and_then(|import| import.source_text().ok());
+            match specifier {
+                Some(specifier) => Definition::Module {
+                    specifier: specifier.to_string(),
+                },
+                None => Definition::Local {
+                    range: binding.text_trimmed_range(),
+                },
+            }
+        });
+
+    Ok(GotoDefinitionResult { definition })
+}
+
+fn exported_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root = parse.tree();
+    symbols::exported_symbols(&root)
+}
+
+fn folding_ranges(parse: AnyParse) -> FoldingRangesResult {
+    let root = parse.tree();
+    FoldingRangesResult {
+        ranges: folding_ranges::folding_ranges(&root),
+    }
+}
+
+fn inlay_hints(parse: AnyParse, params: &InlayHintsParams) -> InlayHintsResult {
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    InlayHintsResult {
+        hints: inlay_hints::inlay_hints(&root, &model, params),
+    }
+}
+
+fn semantic_tokens(parse: AnyParse, range: Option<TextRange>) -> SemanticTokensResult {
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    SemanticTokensResult {
+        tokens: semantic_tokens::semantic_tokens(&root, &model, range),
+    }
+}
+
+fn document_symbols(parse: AnyParse) -> DocumentSymbolsResult {
+    let root = parse.tree();
+    DocumentSymbolsResult {
+        symbols: symbols::document_symbols(&root),
+    }
+}
+
 fn rename(
     _rome_path: &RomePath,
     parse: AnyParse,
@@ -655,12 +920,7 @@ fn rename(
     let root = parse.tree();
     let model = semantic_model(&root, SemanticModelOptions::default());
 
-    if let Some(node) = parse
-        .syntax()
-        .descendants_tokens(Direction::Next)
-        .find(|token| token.text_range().contains(symbol_at))
-        .and_then(|token| token.parent())
-    {
+    if let Some(node) = node_at_offset(&parse, symbol_at) {
         let original_name = node.text_trimmed();
         let range = node.text_range();
         match node.try_into() {
@@ -740,18 +1000,15 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
 fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
     let configuration = AnalyzerConfiguration {
         rules: to_analyzer_rules(settings.as_ref(), file_path.as_path()),
-        globals: if let Some(globals) = settings.as_ref().languages.javascript.globals.as_ref() {
-            globals
-                .iter()
-                .map(|global| global.to_string())
-                .collect::<Vec<_>>()
-        } else {
-            vec![]
-        },
+        globals: to_analyzer_globals(settings.as_ref(), file_path.as_path()),
+        timeout: settings.as_ref().linter.timeout,
+        require_suppression_reasons: settings.as_ref().linter.require_suppression_reasons,
+        suppression_reason: None,
     };
 
     AnalyzerOptions {
         configuration,
         file_path,
+        rule_timings: None,
     }
 }
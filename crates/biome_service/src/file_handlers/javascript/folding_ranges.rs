@@ -0,0 +1,162 @@
+//! Computation of the ranges of a JavaScript file that an editor can fold
+
+use crate::workspace::{FoldingRange, FoldingRangeKind};
+use biome_js_syntax::{
+    AnyJsModuleItem, AnyJsRoot, JsSyntaxKind, JsSyntaxToken, JsxElement, TextRange, TextSize, T,
+};
+use biome_rowan::{AstNode, Direction};
+
+/// Returns the foldable ranges of the file, sorted by their start
+pub(crate) fn folding_ranges(root: &AnyJsRoot) -> Vec<FoldingRange> {
+    let mut ranges = vec![];
+    // The start of the `#region` comments that haven't been closed yet
+    let mut regions: Vec<TextSize> = vec![];
+
+    for token in root.syntax().descendants_tokens(Direction::Next) {
+        for piece in token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces())
+        {
+            if piece.kind().is_multiline_comment() {
+                ranges.push(FoldingRange {
+                    range: piece.text_range(),
+                    kind: FoldingRangeKind::Comment,
+                });
+            } else if piece.kind().is_single_line_comment() {
+                match region_marker(piece.text()) {
+                    Some(RegionMarker::Start) => regions.push(piece.text_range().start()),
+                    Some(RegionMarker::End) => {
+                        if let Some(start) = regions.pop() {
+                            ranges.push(FoldingRange {
+                                range: TextRange::new(start, piece.text_range().end()),
+                                kind: FoldingRangeKind::Region,
+                            });
+                        }
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        if let Some(range) = delimited_range(&token) {
+            ranges.push(FoldingRange {
+                range,
+                kind: FoldingRangeKind::Code,
+            });
+        }
+    }
+
+    for element in root.syntax().descendants().filter_map(JsxElement::cast) {
+        let opening = element.opening_element().ok();
+        let closing = element.closing_element().ok();
+        let tokens = opening
+            .and_then(|opening| opening.syntax().last_token())
+            .zip(closing.and_then(|closing| closing.syntax().first_token()));
+        if let Some((opening, closing)) = tokens {
+            ranges.push(FoldingRange {
+                range: jsx_children_range(&opening, &closing),
+                kind: FoldingRangeKind::Code,
+            });
+        }
+    }
+
+    if let AnyJsRoot::JsModule(module) = root {
+        // Each group of consecutive imports can be folded
+        let mut imports: Option<TextRange> = None;
+        for item in module.items() {
+            if let AnyJsModuleItem::JsImport(import) = item {
+                let range = import.range();
+                imports = Some(imports.map_or(range, |imports| imports.cover(range)));
+            } else if let Some(range) = imports.take() {
+                ranges.push(FoldingRange {
+                    range,
+                    kind: FoldingRangeKind::Imports,
+                });
+            }
+        }
+        if let Some(range) = imports {
+            ranges.push(FoldingRange {
+                range,
+                kind: FoldingRangeKind::Imports,
+            });
+        }
+    }
+
+    ranges.sort_by_key(|folding_range| folding_range.range.start());
+    ranges
+}
+
+enum RegionMarker {
+    Start,
+    End,
+}
+
+/// Recognizes the `// #region` and `// #endregion` comments
+fn region_marker(comment: &str) -> Option<RegionMarker> {
+    let text = comment.strip_prefix("//")?.trim_start();
+    if text.starts_with("#endregion") {
+        Some(RegionMarker::End)
+    } else if text.starts_with("#region") {
+        Some(RegionMarker::Start)
+    } else {
+        None
+    }
+}
+
+/// Returns the range between an opening bracket and the closing bracket of the same node
+fn delimited_range(opening: &JsSyntaxToken) -> Option<TextRange> {
+    let closing_kind = match opening.kind() {
+        T!['{'] => T!['}'],
+        T!['['] => T![']'],
+        T!['('] => T![')'],
+        JsSyntaxKind::BACKTICK => JsSyntaxKind::BACKTICK,
+        _ => return None,
+    };
+
+    let closing = opening
+        .parent()?
+        .children_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| token.kind() == closing_kind)
+        .last()?;
+    if closing == *opening {
+        return None;
+    }
+
+    Some(inner_range(opening, &closing))
+}
+
+/// Returns the range of the children of a JSX element. The line breaks between JSX elements
+/// are JSX text instead of trivia.
+fn jsx_children_range(opening: &JsSyntaxToken, closing: &JsSyntaxToken) -> TextRange {
+    let range = inner_range(opening, closing);
+    let Some(text) = closing
+        .prev_token()
+        .filter(|token| token.kind() == JsSyntaxKind::JSX_TEXT_LITERAL)
+    else {
+        return range;
+    };
+
+    match text.text_trimmed().rfind('\n') {
+        Some(newline) if text.text_trimmed()[newline..].trim().is_empty() => {
+            let end = text.text_trimmed_range().start() + TextSize::from(newline as u32);
+            TextRange::new(range.start(), end.max(range.start()))
+        }
+        _ => range,
+    }
+}
+
+/// Returns the range between two tokens. When the closing token starts a line,
+/// the range ends on the previous line so that the closing token stays visible.
+fn inner_range(opening: &JsSyntaxToken, closing: &JsSyntaxToken) -> TextRange {
+    let end = closing
+        .leading_trivia()
+        .pieces()
+        .filter(|piece| piece.is_newline())
+        .last()
+        .map_or(closing.text_trimmed_range().start(), |newline| {
+            newline.text_range().start()
+        });
+    TextRange::new(opening.text_trimmed_range().end(), end)
+}
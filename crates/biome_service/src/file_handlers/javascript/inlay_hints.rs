@@ -0,0 +1,275 @@
+//! Computation of the inlay hints of a JavaScript file, using only the syntax and the semantic model
+
+use crate::workspace::{InlayHint, InlayHintKind, InlayHintsParams};
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument,
+    AnyJsExpression, AnyJsFormalParameter, AnyJsFunction, AnyJsFunctionBody,
+    AnyJsLiteralExpression, AnyJsParameter, AnyJsRoot, JsBinaryOperator, JsCallExpression,
+    JsParameters, JsReturnStatement, JsSyntaxKind, JsUnaryOperator, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList, WalkEvent};
+
+/// Returns the hints of the nodes of the file that intersect the range of `params`
+pub(crate) fn inlay_hints(
+    root: &AnyJsRoot,
+    model: &SemanticModel,
+    params: &InlayHintsParams,
+) -> Vec<InlayHint> {
+    let mut hints = vec![];
+
+    let mut preorder = root.syntax().preorder();
+    while let Some(event) = preorder.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        if !intersects(node.text_trimmed_range(), params.range) {
+            preorder.skip_subtree();
+            continue;
+        }
+
+        if params.parameter_names {
+            if let Some(call) = JsCallExpression::cast_ref(&node) {
+                parameter_name_hints(&call, model, &mut hints);
+            }
+        }
+
+        if params.return_types {
+            if let Some(function) = AnyJsFunction::cast_ref(&node) {
+                hints.extend(return_type_hint(&function));
+            }
+        }
+    }
+
+    hints.retain(|hint| params.range.contains_inclusive(hint.position));
+    hints.sort_by_key(|hint| hint.position);
+    hints
+}
+
+fn intersects(node_range: TextRange, range: TextRange) -> bool {
+    node_range.start() <= range.end() && node_range.end() >= range.start()
+}
+
+/// Shows the name of the parameters before the arguments of a call to a function
+/// declared in the file or imported by it, e.g. `clamp(/* value: */ 5, /* min: */ 0)`
+fn parameter_name_hints(
+    call: &JsCallExpression,
+    model: &SemanticModel,
+    hints: &mut Vec<InlayHint>,
+) {
+    let Ok(AnyJsExpression::JsIdentifierExpression(callee)) = call.callee() else {
+        return;
+    };
+    let Some(declaration) = callee
+        .name()
+        .ok()
+        .and_then(|reference| model.binding(&reference))
+        .and_then(|binding| binding.tree().declaration())
+    else {
+        return;
+    };
+    let Some(parameters) = function_parameters(declaration) else {
+        return;
+    };
+    let Ok(arguments) = call.arguments() else {
+        return;
+    };
+
+    for (argument, parameter) in arguments.args().iter().zip(parameters) {
+        let Ok(AnyJsCallArgument::AnyJsExpression(argument)) = argument else {
+            // The parameters that match a spread argument are unknown
+            break;
+        };
+        let Some(parameter) = parameter else {
+            continue;
+        };
+
+        // The name of the argument already tells the name of the parameter
+        let is_same_name = match argument.clone().omit_parentheses() {
+            AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+                .name()
+                .and_then(|name| name.value_token())
+                .is_ok_and(|name| name.text_trimmed() == parameter),
+            _ => false,
+        };
+        if is_same_name {
+            continue;
+        }
+
+        hints.push(InlayHint {
+            position: argument.range().start(),
+            label: format!("{parameter}:"),
+            kind: InlayHintKind::ParameterName,
+        });
+    }
+}
+
+/// Returns the names of the parameters of a function, `None` for the parameters that are
+/// destructured. The list stops at the rest parameter.
+fn function_parameters(declaration: AnyJsBindingDeclaration) -> Option<Vec<Option<String>>> {
+    let parameters = match declaration {
+        AnyJsBindingDeclaration::JsFunctionDeclaration(function) => function.parameters().ok()?,
+        AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(function) => {
+            function.parameters().ok()?
+        }
+        AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(function) => {
+            function.parameters().ok()?
+        }
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            let initializer = declarator.initializer()?.expression().ok()?;
+            match initializer.omit_parentheses() {
+                AnyJsExpression::JsFunctionExpression(function) => function.parameters().ok()?,
+                AnyJsExpression::JsArrowFunctionExpression(function) => {
+                    match function.parameters().ok()? {
+                        AnyJsArrowFunctionParameters::JsParameters(parameters) => parameters,
+                        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+                            return Some(vec![binding_name(&binding)]);
+                        }
+                    }
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    Some(parameters_names(&parameters))
+}
+
+fn parameters_names(parameters: &JsParameters) -> Vec<Option<String>> {
+    let mut names = vec![];
+    for parameter in parameters.items().iter() {
+        match parameter {
+            Ok(AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+                parameter,
+            ))) => {
+                let name = match parameter.binding() {
+                    Ok(AnyJsBindingPattern::AnyJsBinding(binding)) => binding_name(&binding),
+                    _ => None,
+                };
+                names.push(name);
+            }
+            // `this` isn't passed as an argument
+            Ok(AnyJsParameter::TsThisParameter(_)) => {}
+            _ => break,
+        }
+    }
+    names
+}
+
+fn binding_name(binding: &AnyJsBinding) -> Option<String> {
+    let name = binding.as_js_identifier_binding()?.name_token().ok()?;
+    Some(name.text_trimmed().to_string())
+}
+
+/// Shows the return type of a function that doesn't have a type annotation, when it's
+/// obvious from its return statements, e.g. `function isEmpty(list) /* : boolean */ {}`
+fn return_type_hint(function: &AnyJsFunction) -> Option<InlayHint> {
+    if function.return_type_annotation().is_some() || function.is_generator() {
+        return None;
+    }
+
+    let return_type = match function.body().ok()? {
+        AnyJsFunctionBody::AnyJsExpression(expression) => expression_type(&expression)?,
+        AnyJsFunctionBody::JsFunctionBody(body) => {
+            let mut return_type = None;
+            let mut preorder = body.syntax().preorder();
+            while let Some(event) = preorder.next() {
+                let WalkEvent::Enter(node) = event else {
+                    continue;
+                };
+                // The return statements of nested functions and classes belong to them
+                if has_own_returns(node.kind()) {
+                    preorder.skip_subtree();
+                    continue;
+                }
+                let Some(statement) = JsReturnStatement::cast(node) else {
+                    continue;
+                };
+
+                let statement_type = match statement.argument() {
+                    Some(argument) => expression_type(&argument)?,
+                    None => "void",
+                };
+                match return_type {
+                    Some(return_type) if return_type != statement_type => return None,
+                    _ => return_type = Some(statement_type),
+                }
+            }
+            return_type.unwrap_or("void")
+        }
+    };
+
+    let parameters_end = match function {
+        AnyJsFunction::JsArrowFunctionExpression(function) => function.parameters().ok()?.range(),
+        AnyJsFunction::JsFunctionDeclaration(function) => function.parameters().ok()?.range(),
+        AnyJsFunction::JsFunctionExportDefaultDeclaration(function) => {
+            function.parameters().ok()?.range()
+        }
+        AnyJsFunction::JsFunctionExpression(function) => function.parameters().ok()?.range(),
+    }
+    .end();
+
+    let label = if function.is_async() {
+        format!(": Promise<{return_type}>")
+    } else {
+        format!(": {return_type}")
+    };
+
+    Some(InlayHint {
+        position: parameters_end,
+        label,
+        kind: InlayHintKind::ReturnType,
+    })
+}
+
+fn has_own_returns(kind: JsSyntaxKind) -> bool {
+    AnyJsFunction::can_cast(kind)
+        || matches!(
+            kind,
+            JsSyntaxKind::JS_CLASS_EXPRESSION
+                | JsSyntaxKind::JS_CLASS_DECLARATION
+                | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+        )
+}
+
+/// Returns the type of an expression when it doesn't depend on other expressions
+fn expression_type(expression: &AnyJsExpression) -> Option<&'static str> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => Some(match literal {
+            AnyJsLiteralExpression::JsBigintLiteralExpression(_) => "bigint",
+            AnyJsLiteralExpression::JsBooleanLiteralExpression(_) => "boolean",
+            AnyJsLiteralExpression::JsNullLiteralExpression(_) => "null",
+            AnyJsLiteralExpression::JsNumberLiteralExpression(_) => "number",
+            AnyJsLiteralExpression::JsRegexLiteralExpression(_) => "RegExp",
+            AnyJsLiteralExpression::JsStringLiteralExpression(_) => "string",
+        }),
+        AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+            Some("string")
+        }
+        AnyJsExpression::JsUnaryExpression(unary) => match unary.operator().ok()? {
+            JsUnaryOperator::LogicalNot | JsUnaryOperator::Delete => Some("boolean"),
+            JsUnaryOperator::Typeof => Some("string"),
+            JsUnaryOperator::Void => Some("undefined"),
+            _ => None,
+        },
+        AnyJsExpression::JsBinaryExpression(binary) => match binary.operator().ok()? {
+            JsBinaryOperator::LessThan
+            | JsBinaryOperator::GreaterThan
+            | JsBinaryOperator::LessThanOrEqual
+            | JsBinaryOperator::GreaterThanOrEqual
+            | JsBinaryOperator::Equality
+            | JsBinaryOperator::StrictEquality
+            | JsBinaryOperator::Inequality
+            | JsBinaryOperator::StrictInequality => Some("boolean"),
+            _ => None,
+        },
+        AnyJsExpression::JsInExpression(_) | AnyJsExpression::JsInstanceofExpression(_) => {
+            Some("boolean")
+        }
+        _ => None,
+    }
+}
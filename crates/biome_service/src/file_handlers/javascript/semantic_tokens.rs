@@ -0,0 +1,282 @@
+//! Classification of the tokens of a JavaScript file, used by editors to highlight the code
+
+use crate::workspace::{SemanticToken, SemanticTokenKind, SemanticTokenModifier};
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsRoot, JsIdentifierAssignment, JsReferenceIdentifier, JsSyntaxKind,
+    JsSyntaxNode, JsSyntaxToken, JsVariableDeclarator, JsxReferenceIdentifier, TextRange, TextSize,
+};
+use biome_rowan::{AstNode, Direction};
+
+use SemanticTokenKind as Kind;
+use SemanticTokenModifier as Modifier;
+
+/// Returns the classified tokens of the file that intersect `range`, or of the whole file
+/// if `range` is `None`.
+///
+/// The tokens are sorted, and a token that spans multiple lines, like a block comment,
+/// is split into one token per line.
+pub(crate) fn semantic_tokens(
+    root: &AnyJsRoot,
+    model: &SemanticModel,
+    range: Option<TextRange>,
+) -> Vec<SemanticToken> {
+    let intersects = |token_range: TextRange| match range {
+        Some(range) => token_range.start() < range.end() && token_range.end() > range.start(),
+        None => true,
+    };
+
+    let mut tokens = vec![];
+    for token in root.syntax().descendants_tokens(Direction::Next) {
+        if !intersects(token.text_range()) {
+            continue;
+        }
+
+        for piece in token.leading_trivia().pieces() {
+            if piece.is_comments() && intersects(piece.text_range()) {
+                push_token(
+                    &mut tokens,
+                    piece.text_range(),
+                    piece.text(),
+                    Kind::Comment,
+                    vec![],
+                );
+            }
+        }
+
+        if let Some((kind, modifiers)) = classify_token(&token, model) {
+            push_token(
+                &mut tokens,
+                token.text_trimmed_range(),
+                token.text_trimmed(),
+                kind,
+                modifiers,
+            );
+        }
+
+        for piece in token.trailing_trivia().pieces() {
+            if piece.is_comments() && intersects(piece.text_range()) {
+                push_token(
+                    &mut tokens,
+                    piece.text_range(),
+                    piece.text(),
+                    Kind::Comment,
+                    vec![],
+                );
+            }
+        }
+    }
+    tokens
+}
+
+/// Adds a token for each line of `text`
+fn push_token(
+    tokens: &mut Vec<SemanticToken>,
+    range: TextRange,
+    text: &str,
+    kind: SemanticTokenKind,
+    modifiers: Vec<SemanticTokenModifier>,
+) {
+    let mut offset = range.start();
+    for line in text.split('\n') {
+        let line_range = TextRange::at(offset, TextSize::of(line.trim_end_matches('\r')));
+        offset += TextSize::of(line) + TextSize::of('\n');
+        if !line_range.is_empty() {
+            tokens.push(SemanticToken {
+                range: line_range,
+                kind,
+                modifiers: modifiers.clone(),
+            });
+        }
+    }
+}
+
+fn classify_token(
+    token: &JsSyntaxToken,
+    model: &SemanticModel,
+) -> Option<(SemanticTokenKind, Vec<SemanticTokenModifier>)> {
+    let kind = token.kind();
+    if kind.is_keyword() {
+        return Some((Kind::Keyword, vec![]));
+    }
+
+    match kind {
+        JsSyntaxKind::JS_STRING_LITERAL
+        | JsSyntaxKind::JSX_STRING_LITERAL
+        | JsSyntaxKind::TEMPLATE_CHUNK => Some((Kind::String, vec![])),
+        JsSyntaxKind::JS_NUMBER_LITERAL | JsSyntaxKind::JS_BIGINT_LITERAL => {
+            Some((Kind::Number, vec![]))
+        }
+        JsSyntaxKind::JS_REGEX_LITERAL => Some((Kind::Regexp, vec![])),
+        JsSyntaxKind::IDENT | JsSyntaxKind::JSX_IDENT => classify_name(&token.parent()?, model),
+        _ => None,
+    }
+}
+
+/// Classifies an identifier using the node that contains it
+fn classify_name(
+    node: &JsSyntaxNode,
+    model: &SemanticModel,
+) -> Option<(SemanticTokenKind, Vec<SemanticTokenModifier>)> {
+    if let Some(binding) = AnyJsIdentifierBinding::cast_ref(node) {
+        let (kind, mut modifiers) = classify_binding(&binding);
+        modifiers.insert(0, Modifier::Declaration);
+        return Some((kind, modifiers));
+    }
+
+    if let Some(reference) = JsReferenceIdentifier::cast_ref(node) {
+        return match model.binding(&reference) {
+            Some(binding) => Some(classify_binding(&binding.tree())),
+            // Unresolved types are globals, like `Promise` in `Promise<void>`
+            None if node.parent()?.kind() == JsSyntaxKind::TS_REFERENCE_TYPE => {
+                Some((Kind::Type, vec![]))
+            }
+            None => Some((Kind::Variable, vec![])),
+        };
+    }
+
+    if let Some(assignment) = JsIdentifierAssignment::cast_ref(node) {
+        let kind = match model.binding(&assignment) {
+            Some(binding) => classify_binding(&binding.tree()).0,
+            None => Kind::Variable,
+        };
+        return Some((kind, vec![]));
+    }
+
+    // Only components are referenced in JSX, intrinsic elements are `JsxName`
+    if let Some(reference) = JsxReferenceIdentifier::cast_ref(node) {
+        let modifiers = match model.binding(&reference) {
+            Some(binding) => classify_binding(&binding.tree()).1,
+            None => vec![],
+        };
+        return Some((Kind::Component, modifiers));
+    }
+
+    let parent = node.parent()?;
+    match node.kind() {
+        JsSyntaxKind::JSX_NAME => {
+            // The name of an attribute can be namespaced too, e.g. `xlink:href`
+            let is_attribute = node
+                .ancestors()
+                .skip(1)
+                .find(|ancestor| ancestor.kind() != JsSyntaxKind::JSX_NAMESPACE_NAME)
+                .is_some_and(|ancestor| ancestor.kind() == JsSyntaxKind::JSX_ATTRIBUTE);
+            if is_attribute {
+                Some((Kind::Property, vec![]))
+            } else {
+                Some((Kind::JsxTag, vec![]))
+            }
+        }
+        JsSyntaxKind::JS_NAME => match parent.kind() {
+            // `<Layout.Header />`
+            JsSyntaxKind::JSX_MEMBER_NAME => Some((Kind::Component, vec![])),
+            JsSyntaxKind::TS_QUALIFIED_NAME => Some((Kind::Type, vec![])),
+            JsSyntaxKind::JS_STATIC_MEMBER_EXPRESSION => {
+                let is_callee = parent.parent().is_some_and(|grand_parent| {
+                    grand_parent.kind() == JsSyntaxKind::JS_CALL_EXPRESSION
+                });
+                if is_callee {
+                    Some((Kind::Method, vec![]))
+                } else {
+                    Some((Kind::Property, vec![]))
+                }
+            }
+            JsSyntaxKind::JS_STATIC_MEMBER_ASSIGNMENT => Some((Kind::Property, vec![])),
+            _ => None,
+        },
+        JsSyntaxKind::JS_LITERAL_MEMBER_NAME | JsSyntaxKind::JS_PRIVATE_CLASS_MEMBER_NAME => {
+            let kind = match parent.kind() {
+                JsSyntaxKind::TS_ENUM_MEMBER => Kind::EnumMember,
+                JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+                | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+                | JsSyntaxKind::TS_METHOD_SIGNATURE_CLASS_MEMBER
+                | JsSyntaxKind::TS_METHOD_SIGNATURE_TYPE_MEMBER => Kind::Method,
+                _ => Kind::Property,
+            };
+            Some((kind, vec![Modifier::Declaration]))
+        }
+        JsSyntaxKind::JS_PRIVATE_NAME => Some((Kind::Property, vec![])),
+        _ => None,
+    }
+}
+
+/// Classifies a binding using its declaration. References to the binding share its classification.
+fn classify_binding(
+    binding: &AnyJsIdentifierBinding,
+) -> (SemanticTokenKind, Vec<SemanticTokenModifier>) {
+    let Some(declaration) = binding.declaration() else {
+        return (Kind::Variable, vec![]);
+    };
+
+    match declaration {
+        AnyJsBindingDeclaration::TsIndexSignatureParameter(_) => (Kind::Parameter, vec![]),
+        AnyJsBindingDeclaration::TsInferType(_)
+        | AnyJsBindingDeclaration::TsMappedType(_)
+        | AnyJsBindingDeclaration::TsTypeParameter(_) => (Kind::TypeParameter, vec![]),
+        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExpression(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_) => {
+            (Kind::Function, vec![])
+        }
+        AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExpression(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_) => (Kind::Class, vec![]),
+        AnyJsBindingDeclaration::TsInterfaceDeclaration(_) => (Kind::Interface, vec![]),
+        AnyJsBindingDeclaration::TsTypeAliasDeclaration(_) => (Kind::Type, vec![]),
+        AnyJsBindingDeclaration::TsEnumDeclaration(_) => (Kind::Enum, vec![]),
+        AnyJsBindingDeclaration::TsModuleDeclaration(_) => (Kind::Namespace, vec![]),
+        AnyJsBindingDeclaration::JsImportNamespaceClause(_)
+        | AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_) => (
+            Kind::Namespace,
+            vec![Modifier::Readonly, Modifier::Imported],
+        ),
+        AnyJsBindingDeclaration::JsImportDefaultClause(_)
+        | AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsBogusNamedImportSpecifier(_)
+        | AnyJsBindingDeclaration::JsDefaultImportSpecifier(_)
+        | AnyJsBindingDeclaration::TsImportEqualsDeclaration(_) => {
+            (Kind::Variable, vec![Modifier::Readonly, Modifier::Imported])
+        }
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => classify_variable(&declarator),
+        AnyJsBindingDeclaration::JsArrowFunctionExpression(_)
+        | AnyJsBindingDeclaration::JsFormalParameter(_)
+        | AnyJsBindingDeclaration::JsRestParameter(_)
+        | AnyJsBindingDeclaration::JsBogusParameter(_)
+        | AnyJsBindingDeclaration::TsPropertyParameter(_) => (Kind::Parameter, vec![]),
+        AnyJsBindingDeclaration::JsCatchDeclaration(_) => (Kind::Variable, vec![]),
+    }
+}
+
+fn classify_variable(
+    declarator: &JsVariableDeclarator,
+) -> (SemanticTokenKind, Vec<SemanticTokenModifier>) {
+    let is_const = declarator
+        .declaration()
+        .is_some_and(|declaration| declaration.is_const());
+    let modifiers = if is_const {
+        vec![Modifier::Readonly]
+    } else {
+        vec![]
+    };
+
+    let is_function = declarator
+        .initializer()
+        .and_then(|initializer| initializer.expression().ok())
+        .is_some_and(|expression| {
+            matches!(
+                expression.omit_parentheses(),
+                AnyJsExpression::JsArrowFunctionExpression(_)
+                    | AnyJsExpression::JsFunctionExpression(_)
+            )
+        });
+    if is_function {
+        (Kind::Function, modifiers)
+    } else {
+        (Kind::Variable, modifiers)
+    }
+}
@@ -0,0 +1,432 @@
+//! Extraction of the symbols that make up the outline of a JavaScript file
+
+use crate::workspace::{DocumentSymbol, SymbolKind};
+use biome_js_syntax::{
+    AnyJsBindingPattern, AnyJsClass, AnyJsClassMember, AnyJsDeclaration, AnyJsDeclarationClause,
+    AnyJsExportClause, AnyJsExportDefaultDeclaration, AnyJsExportNamedSpecifier, AnyJsExpression,
+    AnyJsFunction, AnyJsFunctionBody, AnyJsModuleItem, AnyJsRoot, AnyJsStatement,
+    AnyTsExternalModuleDeclarationBody, AnyTsTypeMember, JsModuleItemList, JsStatementList,
+    JsSyntaxNode, JsVariableDeclaration, JsxTagExpression, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+use rustc_hash::FxHashSet;
+
+/// Returns the top level symbols of the file, with their nested symbols as children
+pub(crate) fn document_symbols(root: &AnyJsRoot) -> Vec<DocumentSymbol> {
+    match root {
+        AnyJsRoot::JsModule(module) => module_items_symbols(module.items()),
+        AnyJsRoot::JsScript(script) => statements_symbols(script.statements()),
+        AnyJsRoot::JsExpressionSnipped(_) => vec![],
+    }
+}
+
+/// Returns the top level symbols exported by the file, without their children
+pub(crate) fn exported_symbols(root: &AnyJsRoot) -> Vec<DocumentSymbol> {
+    let AnyJsRoot::JsModule(module) = root else {
+        return vec![];
+    };
+
+    let mut symbols = vec![];
+    // Declarations exported by name, e.g. `export { a, b as c }`
+    let mut exported_names = FxHashSet::default();
+    let mut local_symbols = vec![];
+    for item in module.items() {
+        match item {
+            AnyJsModuleItem::AnyJsStatement(statement) => {
+                local_symbols.extend(statement_symbols(statement));
+            }
+            AnyJsModuleItem::JsExport(export) => match export.export_clause() {
+                Ok(AnyJsExportClause::JsExportNamedClause(clause)) => {
+                    for specifier in clause.specifiers().iter().flatten() {
+                        let local_name = match specifier {
+                            AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(
+                                specifier,
+                            ) => specifier.name(),
+                            AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                                specifier.local_name()
+                            }
+                        };
+                        if let Ok(local_name) = local_name {
+                            exported_names.insert(local_name.text());
+                        }
+                    }
+                }
+                Ok(clause) => symbols.extend(export_clause_symbols(clause)),
+                Err(_) => {}
+            },
+            AnyJsModuleItem::JsImport(_) => {}
+        }
+    }
+    symbols.extend(
+        local_symbols
+            .into_iter()
+            .filter(|symbol| exported_names.contains(&symbol.name)),
+    );
+
+    for symbol in &mut symbols {
+        symbol.children.clear();
+    }
+    symbols.sort_by_key(|symbol| symbol.range.start());
+    symbols
+}
+
+fn module_items_symbols(items: JsModuleItemList) -> Vec<DocumentSymbol> {
+    let mut symbols = vec![];
+    for item in items {
+        match item {
+            AnyJsModuleItem::AnyJsStatement(statement) => {
+                symbols.extend(statement_symbols(statement));
+            }
+            AnyJsModuleItem::JsExport(export) => {
+                if let Ok(clause) = export.export_clause() {
+                    symbols.extend(export_clause_symbols(clause));
+                }
+            }
+            AnyJsModuleItem::JsImport(_) => {}
+        }
+    }
+    symbols
+}
+
+fn statements_symbols(statements: JsStatementList) -> Vec<DocumentSymbol> {
+    statements.into_iter().flat_map(statement_symbols).collect()
+}
+
+fn statement_symbols(statement: AnyJsStatement) -> Vec<DocumentSymbol> {
+    let declaration = match statement {
+        AnyJsStatement::JsClassDeclaration(node) => AnyJsDeclaration::from(node),
+        AnyJsStatement::JsFunctionDeclaration(node) => node.into(),
+        AnyJsStatement::JsVariableStatement(node) => match node.declaration() {
+            Ok(declaration) => declaration.into(),
+            Err(_) => return vec![],
+        },
+        AnyJsStatement::TsDeclareFunctionDeclaration(node) => node.into(),
+        AnyJsStatement::TsEnumDeclaration(node) => node.into(),
+        AnyJsStatement::TsExternalModuleDeclaration(node) => node.into(),
+        AnyJsStatement::TsGlobalDeclaration(node) => node.into(),
+        AnyJsStatement::TsInterfaceDeclaration(node) => node.into(),
+        AnyJsStatement::TsModuleDeclaration(node) => node.into(),
+        AnyJsStatement::TsTypeAliasDeclaration(node) => node.into(),
+        AnyJsStatement::TsDeclareStatement(node) => match node.declaration() {
+            Ok(clause) => return declaration_clause_symbols(clause),
+            Err(_) => return vec![],
+        },
+        _ => return vec![],
+    };
+    declaration_symbols(declaration)
+}
+
+fn export_clause_symbols(clause: AnyJsExportClause) -> Vec<DocumentSymbol> {
+    match clause {
+        AnyJsExportClause::AnyJsDeclarationClause(clause) => declaration_clause_symbols(clause),
+        AnyJsExportClause::TsExportDeclareClause(clause) => match clause.declaration() {
+            Ok(clause) => declaration_clause_symbols(clause),
+            Err(_) => vec![],
+        },
+        AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+            let symbol = match clause.declaration() {
+                Ok(AnyJsExportDefaultDeclaration::JsClassExportDefaultDeclaration(node)) => {
+                    class_symbol(AnyJsClass::from(node))
+                }
+                Ok(AnyJsExportDefaultDeclaration::JsFunctionExportDefaultDeclaration(node)) => {
+                    let name = node_name_or_default(&node.id());
+                    function_symbol(node.into(), name)
+                }
+                Ok(AnyJsExportDefaultDeclaration::TsDeclareFunctionExportDefaultDeclaration(
+                    node,
+                )) => Some(symbol(
+                    node_name_or_default(&node.id()),
+                    SymbolKind::Function,
+                    node.syntax(),
+                    node.id().map(|id| id.range()),
+                )),
+                Ok(AnyJsExportDefaultDeclaration::TsInterfaceDeclaration(node)) => {
+                    declaration_symbols(node.into()).into_iter().next()
+                }
+                Err(_) => None,
+            };
+            symbol.into_iter().collect()
+        }
+        _ => vec![],
+    }
+}
+
+fn declaration_clause_symbols(clause: AnyJsDeclarationClause) -> Vec<DocumentSymbol> {
+    let declaration = match clause {
+        AnyJsDeclarationClause::JsClassDeclaration(node) => AnyJsDeclaration::from(node),
+        AnyJsDeclarationClause::JsFunctionDeclaration(node) => node.into(),
+        AnyJsDeclarationClause::JsVariableDeclarationClause(node) => match node.declaration() {
+            Ok(declaration) => declaration.into(),
+            Err(_) => return vec![],
+        },
+        AnyJsDeclarationClause::TsDeclareFunctionDeclaration(node) => node.into(),
+        AnyJsDeclarationClause::TsEnumDeclaration(node) => node.into(),
+        AnyJsDeclarationClause::TsExternalModuleDeclaration(node) => node.into(),
+        AnyJsDeclarationClause::TsGlobalDeclaration(node) => node.into(),
+        AnyJsDeclarationClause::TsImportEqualsDeclaration(node) => node.into(),
+        AnyJsDeclarationClause::TsInterfaceDeclaration(node) => node.into(),
+        AnyJsDeclarationClause::TsModuleDeclaration(node) => node.into(),
+        AnyJsDeclarationClause::TsTypeAliasDeclaration(node) => node.into(),
+    };
+    declaration_symbols(declaration)
+}
+
+fn declaration_symbols(declaration: AnyJsDeclaration) -> Vec<DocumentSymbol> {
+    let symbol = match declaration {
+        AnyJsDeclaration::JsClassDeclaration(node) => class_symbol(node.into()),
+        AnyJsDeclaration::JsFunctionDeclaration(node) => {
+            let name = node.id().ok().map(|id| id.text());
+            name.and_then(|name| function_symbol(node.into(), name))
+        }
+        AnyJsDeclaration::JsVariableDeclaration(node) => return variable_symbols(node),
+        AnyJsDeclaration::TsDeclareFunctionDeclaration(node) => node.id().ok().map(|id| {
+            symbol(
+                id.text(),
+                SymbolKind::Function,
+                node.syntax(),
+                Some(id.range()),
+            )
+        }),
+        AnyJsDeclaration::TsEnumDeclaration(node) => node.id().ok().map(|id| {
+            let mut symbol = symbol(id.text(), SymbolKind::Enum, node.syntax(), Some(id.range()));
+            symbol.children = node
+                .members()
+                .iter()
+                .flatten()
+                .filter_map(|member| {
+                    let name = member.name().ok()?;
+                    Some(self::symbol(
+                        name.text(),
+                        SymbolKind::EnumMember,
+                        member.syntax(),
+                        Some(name.range()),
+                    ))
+                })
+                .collect();
+            symbol
+        }),
+        AnyJsDeclaration::TsExternalModuleDeclaration(node) => node.source().ok().map(|source| {
+            let mut symbol = symbol(
+                source.text(),
+                SymbolKind::Module,
+                node.syntax(),
+                Some(source.range()),
+            );
+            if let Some(AnyTsExternalModuleDeclarationBody::TsModuleBlock(block)) = node.body() {
+                symbol.children = module_items_symbols(block.items());
+            }
+            symbol
+        }),
+        AnyJsDeclaration::TsGlobalDeclaration(node) => {
+            let mut symbol = symbol(
+                String::from("global"),
+                SymbolKind::Module,
+                node.syntax(),
+                node.global_token()
+                    .ok()
+                    .map(|token| token.text_trimmed_range()),
+            );
+            if let Ok(block) = node.body() {
+                symbol.children = module_items_symbols(block.items());
+            }
+            Some(symbol)
+        }
+        AnyJsDeclaration::TsImportEqualsDeclaration(_) => None,
+        AnyJsDeclaration::TsInterfaceDeclaration(node) => node.id().ok().map(|id| {
+            let mut symbol = symbol(
+                id.text(),
+                SymbolKind::Interface,
+                node.syntax(),
+                Some(id.range()),
+            );
+            symbol.children = node
+                .members()
+                .into_iter()
+                .filter_map(|member| {
+                    let (name, kind) = match &member {
+                        AnyTsTypeMember::TsPropertySignatureTypeMember(member) => {
+                            (member.name().ok()?, SymbolKind::Property)
+                        }
+                        AnyTsTypeMember::TsMethodSignatureTypeMember(member) => {
+                            (member.name().ok()?, SymbolKind::Method)
+                        }
+                        _ => return None,
+                    };
+                    Some(self::symbol(
+                        name.text(),
+                        kind,
+                        member.syntax(),
+                        Some(name.range()),
+                    ))
+                })
+                .collect();
+            symbol
+        }),
+        AnyJsDeclaration::TsModuleDeclaration(node) => node.name().ok().map(|name| {
+            let mut symbol = symbol(
+                name.text(),
+                SymbolKind::Module,
+                node.syntax(),
+                Some(name.range()),
+            );
+            if let Ok(block) = node.body() {
+                symbol.children = module_items_symbols(block.items());
+            }
+            symbol
+        }),
+        AnyJsDeclaration::TsTypeAliasDeclaration(node) => {
+            node.binding_identifier().ok().map(|id| {
+                symbol(
+                    id.text(),
+                    SymbolKind::TypeAlias,
+                    node.syntax(),
+                    Some(id.range()),
+                )
+            })
+        }
+    };
+    symbol.into_iter().collect()
+}
+
+fn variable_symbols(declaration: JsVariableDeclaration) -> Vec<DocumentSymbol> {
+    let is_const = declaration
+        .kind()
+        .is_ok_and(|kind| kind.text_trimmed() == "const");
+    declaration
+        .declarators()
+        .iter()
+        .flatten()
+        .filter_map(|declarator| {
+            // Destructuring patterns don't have a single name
+            let Ok(AnyJsBindingPattern::AnyJsBinding(id)) = declarator.id() else {
+                return None;
+            };
+            let name = id.text();
+            let initializer = declarator
+                .initializer()
+                .and_then(|initializer| initializer.expression().ok());
+            let symbol = match initializer {
+                Some(AnyJsExpression::JsArrowFunctionExpression(function)) => {
+                    function_symbol(function.into(), name)
+                }
+                Some(AnyJsExpression::JsFunctionExpression(function)) => {
+                    function_symbol(function.into(), name)
+                }
+                Some(AnyJsExpression::JsClassExpression(class)) => {
+                    class_symbol(class.into()).map(|mut symbol| {
+                        symbol.name = name;
+                        symbol
+                    })
+                }
+                _ => {
+                    let kind = if is_const {
+                        SymbolKind::Constant
+                    } else {
+                        SymbolKind::Variable
+                    };
+                    Some(symbol(name, kind, declarator.syntax(), None))
+                }
+            };
+            // The symbol covers the whole declarator, not only its initializer
+            symbol.map(|mut symbol| {
+                symbol.range = declarator.range();
+                symbol.selection_range = id.range();
+                symbol
+            })
+        })
+        .collect()
+}
+
+fn class_symbol(class: AnyJsClass) -> Option<DocumentSymbol> {
+    let id = class.id();
+    let mut symbol = symbol(
+        node_name_or_default(&id),
+        SymbolKind::Class,
+        class.syntax(),
+        id.map(|id| id.range()),
+    );
+    symbol.children = class
+        .members()
+        .into_iter()
+        .filter_map(|member| {
+            let kind = match &member {
+                AnyJsClassMember::JsConstructorClassMember(_)
+                | AnyJsClassMember::TsConstructorSignatureClassMember(_) => SymbolKind::Constructor,
+                AnyJsClassMember::JsMethodClassMember(_)
+                | AnyJsClassMember::TsMethodSignatureClassMember(_) => SymbolKind::Method,
+                AnyJsClassMember::JsGetterClassMember(_)
+                | AnyJsClassMember::JsSetterClassMember(_)
+                | AnyJsClassMember::JsPropertyClassMember(_)
+                | AnyJsClassMember::TsGetterSignatureClassMember(_)
+                | AnyJsClassMember::TsSetterSignatureClassMember(_)
+                | AnyJsClassMember::TsPropertySignatureClassMember(_)
+                | AnyJsClassMember::TsInitializedPropertySignatureClassMember(_) => {
+                    SymbolKind::Property
+                }
+                _ => return None,
+            };
+            let name = member.name().ok()??;
+            Some(self::symbol(
+                name.text(),
+                kind,
+                member.syntax(),
+                Some(name.range()),
+            ))
+        })
+        .collect();
+    Some(symbol)
+}
+
+fn function_symbol(function: AnyJsFunction, name: String) -> Option<DocumentSymbol> {
+    let selection_range = function.id().ok().flatten().map(|id| id.range());
+    // A component is a function with a capitalized name that renders JSX
+    let is_component = name.starts_with(|c: char| c.is_ascii_uppercase())
+        && function
+            .syntax()
+            .descendants()
+            .any(|node| JsxTagExpression::can_cast(node.kind()));
+    let kind = if is_component {
+        SymbolKind::Component
+    } else {
+        SymbolKind::Function
+    };
+
+    let mut symbol = symbol(name, kind, function.syntax(), selection_range);
+    if let Ok(AnyJsFunctionBody::JsFunctionBody(body)) = function.body() {
+        // Only nested functions and classes are part of the outline, local variables aren't
+        symbol.children = statements_symbols(body.statements())
+            .into_iter()
+            .filter(|symbol| {
+                matches!(
+                    symbol.kind,
+                    SymbolKind::Class | SymbolKind::Component | SymbolKind::Function
+                )
+            })
+            .collect();
+    }
+    Some(symbol)
+}
+
+/// Creates a symbol without children. When `selection_range` is `None`, the range
+/// of the whole node is used.
+fn symbol(
+    name: String,
+    kind: SymbolKind,
+    node: &JsSyntaxNode,
+    selection_range: Option<TextRange>,
+) -> DocumentSymbol {
+    let range = node.text_trimmed_range();
+    DocumentSymbol {
+        name,
+        kind,
+        range,
+        selection_range: selection_range.unwrap_or(range),
+        children: vec![],
+    }
+}
+
+/// Anonymous classes and functions can be exported by default
+fn node_name_or_default<N: AstNode>(id: &Option<N>) -> String {
+    id.as_ref()
+        .map_or_else(|| String::from("default"), |id| id.text())
+}
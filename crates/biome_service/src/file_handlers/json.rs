@@ -2,8 +2,8 @@ use super::{ExtensionHandler, Mime};
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::javascript::JsonParserSettings;
 use crate::file_handlers::{
-    AnalyzerCapabilities, Capabilities, FixAllParams, FormatterCapabilities, LintParams,
-    LintResults, ParserCapabilities,
+    resolve_line_ending, to_rule_timing_entries, AnalyzerCapabilities, Capabilities, FixAllParams,
+    FormatterCapabilities, LintParams, LintResults, ParserCapabilities,
 };
 use crate::file_handlers::{DebugCapabilities, Language as LanguageId};
 use crate::settings::{
@@ -14,19 +14,25 @@ use crate::workspace::{
     FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::{Configuration, Rules, WorkspaceError};
-use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, RuleCategories};
+use biome_analyze::{
+    AnalyzerConfiguration, AnalyzerGlobals, AnalyzerOptions, ControlFlow, Never, RuleCategories,
+    RuleTimings,
+};
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
-use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_formatter::{
+    FormatError, FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
+};
 use biome_fs::{RomePath, BIOME_JSON, ROME_JSON};
 use biome_json_analyze::analyze;
-use biome_json_formatter::context::JsonFormatOptions;
+use biome_json_formatter::context::{ArrayFill, JsonFormatOptions};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode, T};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, FileSource, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -36,6 +42,7 @@ pub struct JsonFormatterSettings {
     pub line_width: Option<LineWidth>,
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
+    pub array_fill: Option<ArrayFill>,
     pub enabled: Option<bool>,
 }
 
@@ -70,13 +77,20 @@ impl Language for JsonLanguage {
         } else {
             global.indent_width.unwrap_or_default()
         };
+        let line_ending = if let Some(line_ending) = language.line_ending {
+            line_ending
+        } else {
+            global.line_ending.unwrap_or_default()
+        };
 
         overrides.override_json_format_options(
             path,
             JsonFormatOptions::new(path.as_path().try_into().unwrap_or_default())
                 .with_indent_style(indent_style)
                 .with_indent_width(indent_width)
-                .with_line_width(line_width),
+                .with_line_width(line_width)
+                .with_line_ending(line_ending)
+                .with_array_fill(language.array_fill.unwrap_or_default()),
         )
     }
 }
@@ -99,7 +113,10 @@ impl ExtensionHandler for JsonFileHandler {
 
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -108,13 +125,23 @@ impl ExtensionHandler for JsonFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                prepare_rename: None,
+                find_references: None,
+                goto_definition: None,
+                document_symbols: None,
+                exported_symbols: None,
+                semantic_tokens: None,
+                folding_ranges: None,
+                inlay_hints: None,
                 rename: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
+                format_text: None,
                 format_range: Some(format_range),
+                format_text_range: None,
                 format_on_type: Some(format_on_type),
             },
         }
@@ -144,15 +171,16 @@ fn parse(
             LanguageId::Jsonc => JsonFileSource::jsonc(),
             _ => JsonFileSource::json(),
         });
-    let options: JsonParserOptions =
-        overrides
-            .as_json_parser_options(rome_path)
-            .unwrap_or(JsonParserOptions {
-                allow_comments: parser.allow_comments
-                    || source_type.is_jsonc()
-                    || is_file_allowed(rome_path),
-                allow_trailing_commas: parser.allow_trailing_commas || is_file_allowed(rome_path),
-            });
+    let options = overrides.override_json_parser_options(
+        rome_path,
+        JsonParserOptions {
+            allow_comments: parser.allow_comments.unwrap_or_default()
+                || source_type.is_jsonc()
+                || is_file_allowed(rome_path),
+            allow_trailing_commas: parser.allow_trailing_commas.unwrap_or_default()
+                || is_file_allowed(rome_path),
+        },
+    );
     let parse = biome_json_parser::parse_json_with_cache(text, cache, options);
     let root = parse.syntax();
     let diagnostics = parse.into_diagnostics();
@@ -178,9 +206,9 @@ fn debug_formatter_ir(
     parse: AnyParse,
     settings: SettingsHandle,
 ) -> Result<String, WorkspaceError> {
-    let options = settings.format_options::<JsonLanguage>(rome_path);
-
     let tree = parse.syntax();
+    let mut options = settings.format_options::<JsonLanguage>(rome_path);
+    options.set_line_ending(resolve_line_ending(options.line_ending(), &tree));
     let formatted = format_node(options, &tree)?;
 
     let root_element = formatted.into_document();
@@ -193,11 +221,12 @@ fn format(
     parse: AnyParse,
     settings: SettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsonLanguage>(rome_path);
+    let tree = parse.syntax();
+    let mut options = settings.format_options::<JsonLanguage>(rome_path);
+    options.set_line_ending(resolve_line_ending(options.line_ending(), &tree));
 
     tracing::debug!("Format with the following options: \n{}", options);
 
-    let tree = parse.syntax();
     let formatted = format_node(options, &tree)?;
 
     match formatted.print() {
@@ -212,9 +241,10 @@ fn format_range(
     settings: SettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsonLanguage>(rome_path);
-
     let tree = parse.syntax();
+    let mut options = settings.format_options::<JsonLanguage>(rome_path);
+    options.set_line_ending(resolve_line_ending(options.line_ending(), &tree));
+
     let printed = biome_json_formatter::format_range(options, &tree, range)?;
     Ok(printed)
 }
@@ -225,9 +255,9 @@ fn format_on_type(
     settings: SettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsonLanguage>(rome_path);
-
     let tree = parse.syntax();
+    let mut options = settings.format_options::<JsonLanguage>(rome_path);
+    options.set_line_ending(resolve_line_ending(options.line_ending(), &tree));
 
     let range = tree.text_range();
     if offset < range.start() || offset > range.end() {
@@ -239,16 +269,25 @@ fn format_on_type(
 
     let token = match tree.token_at_offset(offset) {
         // File is empty, do nothing
-        TokenAtOffset::None => panic!("empty file"),
+        TokenAtOffset::None => return Ok(Printed::new_empty()),
         TokenAtOffset::Single(token) => token,
         // The cursor should be right after the closing character that was just typed,
         // select the previous token as the correct one
         TokenAtOffset::Between(token, _) => token,
     };
+    // After a newline, the cursor is in the leading trivia of the next token
+    let token = if token.text_trimmed_range().start() >= offset {
+        token.prev_token()
+    } else {
+        Some(token)
+    };
 
-    let root_node = match token.parent() {
-        Some(node) => node,
-        None => panic!("found a token with no parent"),
+    // Only the objects and the arrays that were just closed are formatted
+    let Some(root_node) = token
+        .filter(|token| matches!(token.kind(), T!['}'] | T![']']))
+        .and_then(|token| token.parent())
+    else {
+        return Ok(Printed::new_empty());
     };
 
     let printed = biome_json_formatter::format_sub_tree(options, &root_node)?;
@@ -273,16 +312,16 @@ fn lint(params: LintParams) -> LintResults {
         }
 
         let mut diagnostic_count = diagnostics.len() as u64;
+        let mut skipped_diagnostics_by_category = BTreeMap::new();
         let mut errors = diagnostics
             .iter()
             .filter(|diag| diag.severity() <= Severity::Error)
             .count();
 
-        let skipped_diagnostics = diagnostic_count - diagnostics.len() as u64;
-
         let has_lint = params.filter.categories.contains(RuleCategories::LINT);
-        let analyzer_options =
+        let mut analyzer_options =
             compute_analyzer_options(&params.settings, PathBuf::from(params.path.as_path()));
+        analyzer_options.rule_timings = params.rule_timings.then(RuleTimings::default);
 
         let (_, analyze_diagnostics) = analyze(&root, params.filter, &analyzer_options, |signal| {
             if let Some(mut diagnostic) = signal.diagnostic() {
@@ -320,6 +359,10 @@ fn lint(params: LintParams) -> LintResults {
                     let error = diagnostic.with_severity(severity);
 
                     diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                } else if let Some(category) = diagnostic.category() {
+                    *skipped_diagnostics_by_category
+                        .entry(category.name().to_string())
+                        .or_insert(0) += 1;
                 }
             }
 
@@ -332,11 +375,14 @@ fn lint(params: LintParams) -> LintResults {
                 .map(biome_diagnostics::serde::Diagnostic::new)
                 .collect::<Vec<_>>(),
         );
+        let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u64);
 
         LintResults {
             diagnostics,
             errors,
             skipped_diagnostics,
+            skipped_diagnostics_by_category,
+            rule_timings: to_rule_timing_entries(analyzer_options.rule_timings),
         }
     })
 }
@@ -371,10 +417,14 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
 fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
     let configuration = AnalyzerConfiguration {
         rules: to_analyzer_rules(settings.as_ref(), file_path.as_path()),
-        globals: vec![],
+        globals: AnalyzerGlobals::default(),
+        timeout: settings.as_ref().linter.timeout,
+        require_suppression_reasons: settings.as_ref().linter.require_suppression_reasons,
+        suppression_reason: None,
     };
     AnalyzerOptions {
         configuration,
         file_path,
+        rule_timings: None,
     }
 }
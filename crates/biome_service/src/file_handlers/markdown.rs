@@ -0,0 +1,457 @@
+use super::{Capabilities, ExtensionHandler, FormatterCapabilities, Language, Mime};
+use crate::settings::SettingsHandle;
+use crate::WorkspaceError;
+use biome_formatter::Printed;
+use biome_fs::RomePath;
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_json_parser::JsonParserOptions;
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{TextRange, TextSize};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MarkdownFileHandler {}
+
+impl ExtensionHandler for MarkdownFileHandler {
+    fn language(&self) -> Language {
+        Language::Markdown
+    }
+
+    fn mime(&self) -> Mime {
+        Mime::Markdown
+    }
+
+    fn may_use_tabs(&self) -> bool {
+        true
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            formatter: FormatterCapabilities {
+                format_text: Some(format_text),
+                format_text_range: Some(format_text_range),
+                ..FormatterCapabilities::default()
+            },
+            ..Capabilities::default()
+        }
+    }
+}
+
+/// The languages of the fenced code blocks that can be formatted
+#[derive(Debug, Clone, Copy)]
+enum CodeBlockLanguage {
+    Js(JsFileSource),
+    Json { allow_comments: bool },
+}
+
+impl CodeBlockLanguage {
+    /// Returns the language corresponding to the info string of a fenced code block.
+    ///
+    /// Only the first word of the info string is taken into account, so that
+    /// ` ```js title="example.js" ` is formatted as JavaScript.
+    fn from_info_string(info: &str) -> Option<Self> {
+        let name = info.split_whitespace().next()?;
+        let language = match name.to_lowercase().as_str() {
+            "js" | "javascript" | "mjs" | "cjs" => Self::Js(JsFileSource::js_module()),
+            "jsx" => Self::Js(JsFileSource::jsx()),
+            "ts" | "typescript" | "mts" | "cts" => Self::Js(JsFileSource::ts()),
+            "tsx" => Self::Js(JsFileSource::tsx()),
+            "json" => Self::Json {
+                allow_comments: false,
+            },
+            "jsonc" => Self::Json {
+                allow_comments: true,
+            },
+            // There's no CSS formatter yet, CSS code blocks are left untouched
+            _ => return None,
+        };
+        Some(language)
+    }
+}
+
+/// An opening code fence, e.g. ` ```js `
+#[derive(Debug)]
+struct CodeFence<'a> {
+    /// The number of spaces before the fence
+    indent: usize,
+    /// The character used by the fence, either `` ` `` or `~`
+    marker: char,
+    /// The number of markers of the fence
+    len: usize,
+    /// The text that follows the markers
+    info: &'a str,
+}
+
+impl<'a> CodeFence<'a> {
+    /// Returns the opening fence of a fenced code block, if `line` is one
+    fn opening(line: &'a str) -> Option<Self> {
+        let (indent, rest) = split_fence_indent(line)?;
+        let marker = rest.chars().next()?;
+        if marker != '`' && marker != '~' {
+            return None;
+        }
+        let len = rest.chars().take_while(|c| *c == marker).count();
+        if len < 3 {
+            return None;
+        }
+        let info = rest[len..].trim();
+        // The info string of a backtick fence can't contain backticks
+        if marker == '`' && info.contains('`') {
+            return None;
+        }
+        Some(Self {
+            indent,
+            marker,
+            len,
+            info,
+        })
+    }
+
+    /// Whether `line` closes the code block opened by this fence
+    fn is_closed_by(&self, line: &str) -> bool {
+        let Some((_, rest)) = split_fence_indent(line) else {
+            return false;
+        };
+        let len = rest.chars().take_while(|c| *c == self.marker).count();
+        len >= self.len && rest[len..].trim().is_empty()
+    }
+}
+
+/// A closed fenced code block of a Markdown document
+struct CodeBlock<'a> {
+    fence: CodeFence<'a>,
+    /// The lines between the fences, with their line endings
+    lines: &'a [&'a str],
+    /// The range of the lines between the fences in the document
+    range: TextRange,
+}
+
+impl CodeBlock<'_> {
+    /// The code of the block, without the indentation of the fence, and with `\n` line endings
+    fn code(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| {
+                let line = line.trim_end_matches(['\r', '\n']);
+                &line[self.stripped_indent(line)..]
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The number of spaces removed from the start of a line of the block
+    fn stripped_indent(&self, line: &str) -> usize {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        indent.min(self.fence.indent)
+    }
+
+    /// Converts an offset of the document in the block to an offset of its code
+    fn to_code_offset(&self, offset: TextSize) -> TextSize {
+        let mut line_start = self.range.start();
+        let mut code_start = TextSize::from(0);
+        for line in self.lines {
+            let content = line.trim_end_matches(['\r', '\n']);
+            let stripped = TextSize::from(self.stripped_indent(content) as u32);
+            let code_len = TextSize::from(content.len() as u32) - stripped;
+            let line_end = line_start + TextSize::from(line.len() as u32);
+            if offset < line_end {
+                let column = offset
+                    .checked_sub(line_start + stripped)
+                    .unwrap_or_default();
+                return code_start + column.min(code_len);
+            }
+            line_start = line_end;
+            code_start += code_len + TextSize::from(1);
+        }
+        code_start
+            .checked_sub(TextSize::from(1))
+            .unwrap_or_default()
+    }
+
+    /// Converts an offset of the code of the block to an offset of the document
+    fn to_document_offset(&self, offset: TextSize) -> TextSize {
+        let mut line_start = self.range.start();
+        let mut code_start = TextSize::from(0);
+        for line in self.lines {
+            let content = line.trim_end_matches(['\r', '\n']);
+            let stripped = TextSize::from(self.stripped_indent(content) as u32);
+            let code_len = TextSize::from(content.len() as u32) - stripped;
+            if offset <= code_start + code_len {
+                return line_start + stripped + (offset - code_start);
+            }
+            line_start += TextSize::from(line.len() as u32);
+            code_start += code_len + TextSize::from(1);
+        }
+        self.range.end()
+    }
+}
+
+/// Returns the closed fenced code blocks of a Markdown document split in `lines`
+fn code_blocks<'a>(lines: &'a [&'a str]) -> Vec<CodeBlock<'a>> {
+    let mut blocks = vec![];
+    let mut offset = TextSize::from(0);
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+        offset += TextSize::from(line.len() as u32);
+
+        let Some(fence) = CodeFence::opening(line.trim_end_matches(['\r', '\n'])) else {
+            continue;
+        };
+        let Some(length) = lines[index..]
+            .iter()
+            .position(|line| fence.is_closed_by(line.trim_end_matches(['\r', '\n'])))
+        else {
+            // An unclosed code block extends to the end of the document, leave it as is
+            continue;
+        };
+        let content_lines = &lines[index..index + length];
+        let content_len: usize = content_lines.iter().map(|line| line.len()).sum();
+        let range = TextRange::at(offset, TextSize::from(content_len as u32));
+        blocks.push(CodeBlock {
+            fence,
+            lines: content_lines,
+            range,
+        });
+
+        // The content and the closing fence
+        index += length + 1;
+        offset = range.end() + TextSize::from(lines[index - 1].len() as u32);
+    }
+    blocks
+}
+
+/// Splits the indentation of a fence from the rest of the line.
+/// A fence can't be indented by more than three spaces.
+fn split_fence_indent(line: &str) -> Option<(usize, &str)> {
+    let rest = line.trim_start_matches(' ');
+    let indent = line.len() - rest.len();
+    (indent <= 3).then_some((indent, rest))
+}
+
+/// Formats the fenced code blocks of a Markdown document written in a language supported
+/// by the formatter. The prose, the code blocks written in other languages, and the code
+/// blocks that contain syntax errors are left untouched.
+fn format_text(
+    rome_path: &RomePath,
+    text: &str,
+    settings: SettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let line_ending = if text.contains("\r\n") { "\r\n" } else { "\n" };
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let mut output = String::with_capacity(text.len());
+
+    let mut offset = TextSize::from(0);
+    for block in code_blocks(&lines) {
+        output.push_str(&text[TextRange::new(offset, block.range.start())]);
+        output.push_str(&format_block(rome_path, &block, line_ending, &settings)?);
+        offset = block.range.end();
+    }
+    output.push_str(&text[usize::from(offset)..]);
+
+    Ok(Printed::new(output, None, vec![], vec![]))
+}
+
+/// Formats the code of a Markdown document in `range`.
+///
+/// When the range is inside a fenced code block, the code of the range is formatted by the
+/// formatter of the language of the block. Otherwise, the code blocks that intersect with the
+/// range are formatted, and the prose between them is left untouched.
+fn format_text_range(
+    rome_path: &RomePath,
+    text: &str,
+    settings: SettingsHandle,
+    range: TextRange,
+) -> Result<Printed, WorkspaceError> {
+    let line_ending = if text.contains("\r\n") { "\r\n" } else { "\n" };
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let blocks = code_blocks(&lines);
+    let unchanged = || Printed::new(text[range].to_string(), Some(range), vec![], vec![]);
+
+    if let Some(block) = blocks
+        .iter()
+        .find(|block| block.range.contains_range(range))
+    {
+        let printed = format_block_range(rome_path, block, range, line_ending, &settings)?;
+        return Ok(printed.unwrap_or_else(unchanged));
+    }
+
+    let blocks: Vec<_> = blocks
+        .iter()
+        .filter(|block| block.range.start() < range.end() && range.start() < block.range.end())
+        .collect();
+    let (Some(first), Some(last)) = (blocks.first(), blocks.last()) else {
+        return Ok(unchanged());
+    };
+
+    let mut output = String::new();
+    let mut offset = first.range.start();
+    for block in &blocks {
+        output.push_str(&text[TextRange::new(offset, block.range.start())]);
+        output.push_str(&format_block(rome_path, block, line_ending, &settings)?);
+        offset = block.range.end();
+    }
+
+    Ok(Printed::new(
+        output,
+        Some(TextRange::new(first.range.start(), last.range.end())),
+        vec![],
+        vec![],
+    ))
+}
+
+/// Returns the formatted content of a code block, indented like its fence, or its original
+/// content when it can't be formatted
+fn format_block(
+    rome_path: &RomePath,
+    block: &CodeBlock,
+    line_ending: &str,
+    settings: &SettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let formatted = match CodeBlockLanguage::from_info_string(block.fence.info) {
+        Some(language) => format_code_block(rome_path, &block.code(), language, settings)?,
+        None => None,
+    };
+
+    let Some(formatted) = formatted else {
+        return Ok(block.lines.concat());
+    };
+    let mut output = String::new();
+    for formatted_line in formatted.lines() {
+        if !formatted_line.is_empty() {
+            output.extend(std::iter::repeat(' ').take(block.fence.indent));
+            output.push_str(formatted_line);
+        }
+        output.push_str(line_ending);
+    }
+    Ok(output)
+}
+
+/// Formats the code of a code block in `range`, which is a range of the document.
+///
+/// Returns [None] when the code block can't be formatted.
+fn format_block_range(
+    rome_path: &RomePath,
+    block: &CodeBlock,
+    range: TextRange,
+    line_ending: &str,
+    settings: &SettingsHandle,
+) -> Result<Option<Printed>, WorkspaceError> {
+    let Some(language) = CodeBlockLanguage::from_info_string(block.fence.info) else {
+        return Ok(None);
+    };
+    let code = block.code();
+    let code_range = TextRange::new(
+        block.to_code_offset(range.start()),
+        block.to_code_offset(range.end()),
+    );
+
+    let printed = match language {
+        CodeBlockLanguage::Js(source_type) => {
+            if settings.as_ref().javascript_formatter_disabled() {
+                return Ok(None);
+            }
+            let parse = biome_js_parser::parse(&code, source_type, JsParserOptions::default());
+            if parse.has_errors() {
+                return Ok(None);
+            }
+            let options = settings
+                .format_options::<JsLanguage>(rome_path)
+                .with_source_type(source_type);
+            biome_js_formatter::format_range(options, &parse.syntax(), code_range)
+        }
+        CodeBlockLanguage::Json { allow_comments } => {
+            if settings.as_ref().json_formatter_disabled() {
+                return Ok(None);
+            }
+            let parse = biome_json_parser::parse_json(
+                &code,
+                JsonParserOptions {
+                    allow_comments,
+                    ..JsonParserOptions::default()
+                },
+            );
+            if parse.has_errors() {
+                return Ok(None);
+            }
+            let options = settings.format_options::<JsonLanguage>(rome_path);
+            biome_json_formatter::format_range(options, &parse.syntax(), code_range)
+        }
+    };
+    let printed = printed.map_err(WorkspaceError::FormatError)?;
+    let Some(formatted_range) = printed.range() else {
+        return Ok(None);
+    };
+
+    // The lines of the formatted code are indented like the fence
+    let mut output = String::new();
+    for (index, line) in printed.as_code().split('\n').enumerate() {
+        if index > 0 {
+            output.push_str(line_ending);
+            if !line.is_empty() {
+                output.extend(std::iter::repeat(' ').take(block.fence.indent));
+            }
+        }
+        output.push_str(line);
+    }
+
+    Ok(Some(Printed::new(
+        output,
+        Some(TextRange::new(
+            block.to_document_offset(formatted_range.start()),
+            block.to_document_offset(formatted_range.end()),
+        )),
+        vec![],
+        vec![],
+    )))
+}
+
+/// Formats the content of a fenced code block.
+///
+/// Returns [None] when the formatter is disabled for the language of the code block,
+/// or when the code block contains syntax errors.
+fn format_code_block(
+    rome_path: &RomePath,
+    code: &str,
+    language: CodeBlockLanguage,
+    settings: &SettingsHandle,
+) -> Result<Option<String>, WorkspaceError> {
+    let formatted = match language {
+        CodeBlockLanguage::Js(source_type) => {
+            if settings.as_ref().javascript_formatter_disabled() {
+                return Ok(None);
+            }
+            let parse = biome_js_parser::parse(code, source_type, JsParserOptions::default());
+            if parse.has_errors() {
+                return Ok(None);
+            }
+            let options = settings
+                .format_options::<JsLanguage>(rome_path)
+                .with_source_type(source_type);
+            biome_js_formatter::format_node(options, &parse.syntax())?.print()
+        }
+        CodeBlockLanguage::Json { allow_comments } => {
+            if settings.as_ref().json_formatter_disabled() {
+                return Ok(None);
+            }
+            let parse = biome_json_parser::parse_json(
+                code,
+                JsonParserOptions {
+                    allow_comments,
+                    ..JsonParserOptions::default()
+                },
+            );
+            if parse.has_errors() {
+                return Ok(None);
+            }
+            let options = settings.format_options::<JsonLanguage>(rome_path);
+            biome_json_formatter::format_node(options, &parse.syntax())?.print()
+        }
+    };
+
+    match formatted {
+        Ok(printed) => Ok(Some(printed.into_code())),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
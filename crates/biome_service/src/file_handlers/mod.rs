@@ -1,25 +1,35 @@
-use self::{javascript::JsFileHandler, json::JsonFileHandler, unknown::UnknownFileHandler};
+use self::{
+    javascript::JsFileHandler, json::JsonFileHandler, markdown::MarkdownFileHandler,
+    unknown::UnknownFileHandler,
+};
 use crate::workspace::{FixFileMode, OrganizeImportsResult};
 use crate::{
     settings::SettingsHandle,
-    workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
+    workspace::{
+        DocumentSymbol, DocumentSymbolsResult, FindReferencesResult, FixFileResult,
+        FoldingRangesResult, GetSyntaxTreeResult, GotoDefinitionResult, InlayHintsParams,
+        InlayHintsResult, PrepareRenameResult, PullActionsResult, RenameResult, RuleTimingEntry,
+        SemanticTokensResult,
+    },
     Rules, WorkspaceError,
 };
-use biome_analyze::{AnalysisFilter, AnalyzerDiagnostic};
+use biome_analyze::{AnalysisFilter, AnalyzerDiagnostic, RuleTimings};
 use biome_console::fmt::Formatter;
 use biome_console::markup;
 use biome_diagnostics::{Diagnostic, Severity};
-use biome_formatter::Printed;
+use biome_formatter::{LineEnding, Printed};
 use biome_fs::RomePath;
 use biome_js_syntax::{TextRange, TextSize};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_rowan::{NodeCache, SyntaxNode};
 pub use javascript::JsFormatterSettings;
+use std::collections::BTreeMap;
 use std::ffi::OsStr;
 use std::path::Path;
 
 mod javascript;
 mod json;
+mod markdown;
 mod unknown;
 
 /// Supported languages by Biome
@@ -38,6 +48,8 @@ pub enum Language {
     Json,
     /// JSONC
     Jsonc,
+    /// Markdown
+    Markdown,
     /// Any language that is not supported
     #[default]
     Unknown,
@@ -69,6 +81,7 @@ impl Language {
             "tsx" => Language::TypeScriptReact,
             "json" => Language::Json,
             "jsonc" => Language::Jsonc,
+            "md" | "mdx" => Language::Markdown,
             _ => Language::Unknown,
         }
     }
@@ -103,6 +116,7 @@ impl Language {
             "typescriptreact" => Language::TypeScriptReact,
             "json" => Language::Json,
             "jsonc" => Language::Jsonc,
+            "markdown" | "mdx" => Language::Markdown,
             _ => Language::Unknown,
         }
     }
@@ -161,6 +175,7 @@ impl biome_console::fmt::Display for Language {
             Language::TypeScriptReact => fmt.write_markup(markup! { "TSX" }),
             Language::Json => fmt.write_markup(markup! { "JSON" }),
             Language::Jsonc => fmt.write_markup(markup! { "JSONC" }),
+            Language::Markdown => fmt.write_markup(markup! { "Markdown" }),
             Language::Unknown => fmt.write_markup(markup! { "Unknown" }),
         }
     }
@@ -172,6 +187,7 @@ pub(crate) enum Mime {
     Javascript,
     Json,
     Css,
+    Markdown,
     Text,
 }
 
@@ -181,6 +197,7 @@ impl std::fmt::Display for Mime {
             Mime::Css => write!(f, "text/css"),
             Mime::Json => write!(f, "application/json"),
             Mime::Javascript => write!(f, "application/javascript"),
+            Mime::Markdown => write!(f, "text/markdown"),
             Mime::Text => write!(f, "text/plain"),
         }
     }
@@ -201,6 +218,8 @@ pub struct FixAllParams<'a> {
     /// Whether it should format the code action
     pub(crate) should_format: bool,
     pub(crate) rome_path: &'a RomePath,
+    /// The explanation of the suppression comments added by [FixFileMode::ApplySuppressions]
+    pub(crate) suppression_reason: Option<&'a str>,
 }
 
 #[derive(Default)]
@@ -213,11 +232,15 @@ pub struct Capabilities {
 }
 
 type Parse = fn(&RomePath, Language, &str, SettingsHandle, &mut NodeCache) -> AnyParse;
+type Reparse = fn(&RomePath, Language, &AnyParse, TextRange, &str) -> Option<AnyParse>;
 
 #[derive(Default)]
 pub struct ParserCapabilities {
     /// Parse a file
     pub(crate) parse: Option<Parse>,
+    /// Update the syntax tree of a file after the given range of its content changed, without
+    /// parsing the whole new content. It returns `None` when the file must be parsed again.
+    pub(crate) reparse: Option<Reparse>,
 }
 
 type DebugSyntaxTree = fn(&RomePath, AnyParse) -> GetSyntaxTreeResult;
@@ -241,18 +264,30 @@ pub(crate) struct LintParams<'a> {
     pub(crate) settings: SettingsHandle<'a>,
     pub(crate) max_diagnostics: u64,
     pub(crate) path: &'a RomePath,
+    /// Whether the time spent running each rule is recorded
+    pub(crate) rule_timings: bool,
 }
 
 pub(crate) struct LintResults {
     pub(crate) diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
     pub(crate) errors: usize,
     pub(crate) skipped_diagnostics: u64,
+    pub(crate) skipped_diagnostics_by_category: BTreeMap<String, u64>,
+    pub(crate) rule_timings: Vec<RuleTimingEntry>,
 }
 
 type Lint = fn(LintParams) -> LintResults;
 type CodeActions =
     fn(AnyParse, TextRange, Option<&Rules>, SettingsHandle, &RomePath) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
+type PrepareRename = fn(AnyParse, TextSize) -> Result<PrepareRenameResult, WorkspaceError>;
+type FindReferences = fn(AnyParse, TextSize) -> Result<FindReferencesResult, WorkspaceError>;
+type GotoDefinition = fn(AnyParse, TextSize) -> Result<GotoDefinitionResult, WorkspaceError>;
+type DocumentSymbols = fn(AnyParse) -> DocumentSymbolsResult;
+type ExportedSymbols = fn(AnyParse) -> Vec<DocumentSymbol>;
+type SemanticTokens = fn(AnyParse, Option<TextRange>) -> SemanticTokensResult;
+type FoldingRanges = fn(AnyParse) -> FoldingRangesResult;
+type InlayHints = fn(AnyParse, &InlayHintsParams) -> InlayHintsResult;
 type Rename = fn(&RomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
 type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
 
@@ -264,6 +299,22 @@ pub struct AnalyzerCapabilities {
     pub(crate) code_actions: Option<CodeActions>,
     /// Applies fixes to a file
     pub(crate) fix_all: Option<FixAll>,
+    /// It checks that a binding inside a file can be renamed
+    pub(crate) prepare_rename: Option<PrepareRename>,
+    /// It finds all the references of a binding inside a file
+    pub(crate) find_references: Option<FindReferences>,
+    /// It finds where a symbol used inside a file is defined
+    pub(crate) goto_definition: Option<GotoDefinition>,
+    /// It extracts the outline of a file
+    pub(crate) document_symbols: Option<DocumentSymbols>,
+    /// It extracts the top level symbols exported by a file
+    pub(crate) exported_symbols: Option<ExportedSymbols>,
+    /// It classifies the tokens of a file for semantic highlighting
+    pub(crate) semantic_tokens: Option<SemanticTokens>,
+    /// It computes the ranges of a file that can be folded
+    pub(crate) folding_ranges: Option<FoldingRanges>,
+    /// It computes the inlay hints of a file
+    pub(crate) inlay_hints: Option<InlayHints>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
     /// It organize imports
@@ -271,8 +322,11 @@ pub struct AnalyzerCapabilities {
 }
 
 type Format = fn(&RomePath, AnyParse, SettingsHandle) -> Result<Printed, WorkspaceError>;
+type FormatText = fn(&RomePath, &str, SettingsHandle) -> Result<Printed, WorkspaceError>;
 type FormatRange =
     fn(&RomePath, AnyParse, SettingsHandle, TextRange) -> Result<Printed, WorkspaceError>;
+type FormatTextRange =
+    fn(&RomePath, &str, SettingsHandle, TextRange) -> Result<Printed, WorkspaceError>;
 type FormatOnType =
     fn(&RomePath, AnyParse, SettingsHandle, TextSize) -> Result<Printed, WorkspaceError>;
 
@@ -280,8 +334,12 @@ type FormatOnType =
 pub(crate) struct FormatterCapabilities {
     /// It formats a file
     pub(crate) format: Option<Format>,
+    /// It formats the text of a file that can't be parsed by Biome
+    pub(crate) format_text: Option<FormatText>,
     /// It formats a portion of text of a file
     pub(crate) format_range: Option<FormatRange>,
+    /// It formats a portion of the text of a file that can't be parsed by Biome
+    pub(crate) format_text_range: Option<FormatTextRange>,
     /// It formats a file while typing
     pub(crate) format_on_type: Option<FormatOnType>,
 }
@@ -317,6 +375,7 @@ pub(crate) trait ExtensionHandler {
 pub(crate) struct Features {
     js: JsFileHandler,
     json: JsonFileHandler,
+    markdown: MarkdownFileHandler,
     unknown: UnknownFileHandler,
 }
 
@@ -325,6 +384,7 @@ impl Features {
         Features {
             js: JsFileHandler {},
             json: JsonFileHandler {},
+            markdown: MarkdownFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
     }
@@ -354,11 +414,25 @@ impl Features {
             | Language::TypeScript
             | Language::TypeScriptReact => self.js.capabilities(),
             Language::Json | Language::Jsonc => self.json.capabilities(),
+            Language::Markdown => self.markdown.capabilities(),
             Language::Unknown => self.unknown.capabilities(),
         }
     }
 }
 
+/// Returns the line ending used to print `root`.
+///
+/// [LineEnding::Auto] is resolved to the line ending that is the most used in the file.
+pub(crate) fn resolve_line_ending<L: biome_rowan::Language>(
+    line_ending: LineEnding,
+    root: &SyntaxNode<L>,
+) -> LineEnding {
+    match line_ending {
+        LineEnding::Auto => LineEnding::detect(&root.text().to_string()),
+        line_ending => line_ending,
+    }
+}
+
 /// Checks whether a diagnostic coming from the analyzer is an [error](Severity::Error)
 ///
 /// The function checks the diagnostic against the current configured rules.
@@ -378,3 +452,19 @@ pub(crate) fn is_diagnostic_error(
 
     severity >= Severity::Error
 }
+
+/// Converts the time spent running the rules during an analysis, when it was recorded, into the
+/// entries returned by the workspace
+pub(crate) fn to_rule_timing_entries(timings: Option<RuleTimings>) -> Vec<RuleTimingEntry> {
+    timings
+        .map(RuleTimings::into_timings)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(rule, timing)| RuleTimingEntry {
+            group: rule.group().to_string(),
+            rule: rule.rule_name().to_string(),
+            nanos: u64::try_from(timing.duration.as_nanos()).unwrap_or(u64::MAX),
+            runs: timing.runs,
+        })
+        .collect()
+}
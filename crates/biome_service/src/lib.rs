@@ -6,6 +6,7 @@ use std::ops::{Deref, DerefMut};
 pub mod configuration;
 pub mod file_handlers;
 pub mod project_handlers;
+pub mod resolver;
 
 pub mod matcher;
 pub mod settings;
@@ -4,17 +4,32 @@ use biome_console::markup;
 use biome_diagnostics::Diagnostic;
 pub use pattern::{MatchOptions, Pattern, PatternError};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 
 /// A data structure to use when there's need to match a string or a path a against
-/// a unix shell style patterns
+/// a unix shell style patterns.
+///
+/// A pattern that starts with `!` is negated: the strings and the paths it matches don't
+/// match the [Matcher]. When several patterns match, the last one wins, so a negated pattern
+/// excludes a part of what the patterns before it match.
 #[derive(Debug)]
 pub struct Matcher {
-    patterns: Vec<Pattern>,
+    patterns: Vec<MatcherPattern>,
     options: MatchOptions,
-    /// Whether the string was already checked
-    already_checked: RwLock<HashMap<String, bool>>,
+    /// The directory the patterns are relative to, it's removed from the paths before
+    /// matching them
+    root: Option<PathBuf>,
+    /// The index of the pattern that matched a string already checked, or [None] if no
+    /// pattern matched it
+    already_checked: RwLock<HashMap<String, Option<usize>>>,
+}
+
+#[derive(Debug)]
+struct MatcherPattern {
+    pattern: Pattern,
+    /// Whether the pattern started with `!`
+    negated: bool,
 }
 
 impl Matcher {
@@ -25,78 +40,98 @@ impl Matcher {
         Self {
             patterns: Vec::new(),
             options,
+            root: None,
             already_checked: RwLock::new(HashMap::default()),
         }
     }
 
-    /// It adds a unix shell style pattern
+    /// Sets the directory the patterns are relative to
+    pub fn set_root(&mut self, root: PathBuf) {
+        self.root = Some(root);
+        self.already_checked.write().unwrap().clear();
+    }
+
+    /// It adds a unix shell style pattern. The pattern is negated when it starts with `!`
     pub fn add_pattern(&mut self, pattern: &str) -> Result<(), PatternError> {
+        let (pattern, negated) = match pattern.strip_prefix('!') {
+            Some(pattern) => (pattern, true),
+            None => (pattern, false),
+        };
         let pattern = Pattern::new(pattern)?;
-        self.patterns.push(pattern);
+        self.patterns.push(MatcherPattern { pattern, negated });
         Ok(())
     }
 
+    /// The index of the last pattern that matches, if it isn't negated
+    fn last_match(&self, matches: impl Fn(&Pattern) -> bool) -> Option<usize> {
+        self.patterns
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, pattern)| matches(&pattern.pattern))
+            .filter(|(_, pattern)| !pattern.negated)
+            .map(|(index, _)| index)
+    }
+
     /// It matches the given string against the stored patterns.
     ///
     /// It returns [true] if there's at least a match
     pub fn matches(&self, source: &str) -> bool {
         let mut already_ignored = self.already_checked.write().unwrap();
         if let Some(matches) = already_ignored.get(source) {
-            return *matches;
-        }
-        for pattern in &self.patterns {
-            if pattern.matches_with(source, self.options) || source.contains(pattern.as_str()) {
-                already_ignored.insert(source.to_string(), true);
-                return true;
-            }
+            return matches.is_some();
         }
-        already_ignored.insert(source.to_string(), false);
-        false
+        let matches = self.last_match(|pattern| {
+            pattern.matches_with(source, self.options) || source.contains(pattern.as_str())
+        });
+        already_ignored.insert(source.to_string(), matches);
+        matches.is_some()
     }
 
     /// It matches the given path against the stored patterns
     ///
     /// It returns [true] if there's a lest a match
     pub fn matches_path(&self, source: &Path) -> bool {
+        self.matching_pattern(source).is_some()
+    }
+
+    /// It returns the pattern that decided that the given path matches, or [None]
+    /// if there isn't a match
+    pub fn matching_pattern(&self, source: &Path) -> Option<&str> {
+        let source = self
+            .root
+            .as_ref()
+            .and_then(|root| source.strip_prefix(root).ok())
+            .unwrap_or(source);
         let mut already_checked = self.already_checked.write().unwrap();
         let source_as_string = source.to_str();
         if let Some(source_as_string) = source_as_string {
             if let Some(matches) = already_checked.get(source_as_string) {
-                return *matches;
+                return matches.map(|index| self.patterns[index].pattern.as_str());
             }
         }
-        let matches = {
-            for pattern in &self.patterns {
-                let matches = if pattern.matches_path_with(source, self.options) {
-                    true
-                } else {
-                    // Here we cover cases where the user specifies single files inside the patterns.
-                    // The pattern library doesn't support single files, we here we just do a check
-                    // on contains
-                    //
-                    // Given the pattern `out`:
-                    // - `out/index.html` -> matches
-                    // - `out/` -> matches
-                    // - `layout.tsx` -> does not match
-                    // - `routes/foo.ts` -> does not match
-                    source
-                        .ancestors()
-                        .any(|ancestor| ancestor.ends_with(pattern.as_str()))
-                };
-
-                if matches {
-                    return true;
-                }
+        let matches = self.last_match(|pattern| {
+            pattern.matches_path_with(source, self.options) || {
+                // Here we cover cases where the user specifies single files inside the patterns.
+                // The pattern library doesn't support single files, we here we just do a check
+                // on contains
+                //
+                // Given the pattern `out`:
+                // - `out/index.html` -> matches
+                // - `out/` -> matches
+                // - `layout.tsx` -> does not match
+                // - `routes/foo.ts` -> does not match
+                source
+                    .ancestors()
+                    .any(|ancestor| ancestor.ends_with(pattern.as_str()))
             }
-
-            false
-        };
+        });
 
         if let Some(source_as_string) = source_as_string {
             already_checked.insert(source_as_string.to_string(), matches);
         }
 
-        matches
+        matches.map(|index| self.patterns[index].pattern.as_str())
     }
 }
 
@@ -115,6 +150,7 @@ mod test {
     use crate::matcher::pattern::MatchOptions;
     use crate::matcher::Matcher;
     use std::env;
+    use std::path::{Path, PathBuf};
 
     #[test]
     fn matches() {
@@ -159,6 +195,36 @@ mod test {
         assert!(result);
     }
 
+    #[test]
+    fn matches_path_relative_to_root() {
+        let mut ignore = Matcher::new(MatchOptions::default());
+        ignore.add_pattern("src/**/*.js").unwrap();
+
+        ignore.set_root(PathBuf::from("/workspace/project"));
+        assert!(ignore.matches_path(Path::new("/workspace/project/src/index.js")));
+        assert!(ignore.matches_path(Path::new("src/index.js")));
+        assert!(!ignore.matches_path(Path::new("/workspace/project/lib/index.js")));
+    }
+
+    #[test]
+    fn matches_path_with_negated_patterns() {
+        let mut ignore = Matcher::new(MatchOptions::default());
+        ignore.add_pattern("src/**").unwrap();
+        ignore.add_pattern("!src/generated/**").unwrap();
+        ignore.add_pattern("src/generated/keep.js").unwrap();
+
+        assert_eq!(
+            ignore.matching_pattern(Path::new("src/index.js")),
+            Some("src/**")
+        );
+        assert!(!ignore.matches_path(Path::new("src/generated/schema.js")));
+        assert_eq!(
+            ignore.matching_pattern(Path::new("src/generated/keep.js")),
+            Some("src/generated/keep.js")
+        );
+        assert!(!ignore.matches_path(Path::new("lib/index.js")));
+    }
+
     #[test]
     fn matches_single_path() {
         let dir = "workspace.rs";
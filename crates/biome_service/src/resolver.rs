@@ -0,0 +1,391 @@
+//! Resolution of the module specifiers used by import and export statements.
+//!
+//! The resolver follows the rules that TypeScript applies when `moduleResolution` is set to
+//! `bundler`: relative specifiers are resolved from the importing file, and the other specifiers
+//! are resolved using the `paths` and `baseUrl` options of the closest `tsconfig.json`.
+//! Packages installed in `node_modules` aren't resolved by [resolve_module].
+//!
+//! The files that packages export through the `exports` field of their `package.json` are
+//! resolved by [resolve_package_export].
+
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_fs::{FileSystem, OpenOptions};
+use biome_json_parser::JsonParserOptions;
+use biome_project::{PackageJson, TsConfigJson};
+use std::path::{Component, Path, PathBuf};
+
+const TSCONFIG_JSON: &str = "tsconfig.json";
+const PACKAGE_JSON: &str = "package.json";
+const NODE_MODULES: &str = "node_modules";
+
+/// Extensions that are tried, in order, when a specifier doesn't point to an existing file.
+const EXTENSIONS: &[&str] = &[
+    "ts", "tsx", "d.ts", "js", "jsx", "mts", "mjs", "cts", "cjs", "json",
+];
+
+/// Returns the path of the file imported by `specifier` from the file at `importer`,
+/// or `None` if the module can't be found.
+pub fn resolve_module(fs: &dyn FileSystem, importer: &Path, specifier: &str) -> Option<PathBuf> {
+    let directory = importer.parent()?;
+
+    if specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || specifier == "."
+        || specifier == ".."
+    {
+        return resolve_path(fs, &directory.join(specifier));
+    }
+
+    if Path::new(specifier).is_absolute() {
+        return resolve_path(fs, Path::new(specifier));
+    }
+
+    let (tsconfig_directory, tsconfig) = find_tsconfig(fs, directory)?;
+    let compiler_options = tsconfig.compiler_options;
+    let base_directory = match &compiler_options.base_url {
+        Some(base_url) => tsconfig_directory.join(base_url),
+        None => tsconfig_directory,
+    };
+
+    // TypeScript uses the pattern with the longest prefix that matches the specifier
+    let matched_pattern = compiler_options
+        .paths
+        .iter()
+        .filter_map(|(pattern, targets)| {
+            let captured = match_path_pattern(pattern, specifier)?;
+            let prefix_len = pattern.find('*').unwrap_or(pattern.len());
+            Some((prefix_len, captured, targets))
+        })
+        .max_by_key(|(prefix_len, ..)| *prefix_len);
+
+    if let Some((_, captured, targets)) = matched_pattern {
+        return targets.iter().find_map(|target| {
+            let target = target.replacen('*', captured, 1);
+            resolve_path(fs, &base_directory.join(target))
+        });
+    }
+
+    if compiler_options.base_url.is_some() {
+        return resolve_path(fs, &base_directory.join(specifier));
+    }
+
+    None
+}
+
+/// Returns the path of the file exported as `specifier`, e.g. `@org/package/subpath`, by a
+/// package installed in the `node_modules` folder of `directory` or of its ancestors.
+///
+/// The `exports` field of the `package.json` of the package is matched against `conditions`.
+/// When the package doesn't have an `exports` field, the subpath is resolved as a file of
+/// the package.
+pub fn resolve_package_export(
+    fs: &dyn FileSystem,
+    directory: &Path,
+    specifier: &str,
+    conditions: &[&str],
+) -> Option<PathBuf> {
+    let (package_name, subpath) = split_package_specifier(specifier)?;
+
+    // Like Node.js, the closest installation of the package is used
+    let package_directory = directory
+        .ancestors()
+        .map(|directory| directory.join(NODE_MODULES).join(package_name))
+        .find(|package_directory| fs.path_exists(&package_directory.join(PACKAGE_JSON)))?;
+
+    let mut file = fs
+        .open_with_options(
+            &package_directory.join(PACKAGE_JSON),
+            OpenOptions::default().read(true),
+        )
+        .ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    let package_json =
+        deserialize_from_json_str::<PackageJson>(&content, JsonParserOptions::default())
+            .into_deserialized()?;
+
+    let target = match &package_json.exports {
+        Some(exports) => exports.resolve(&subpath, conditions)?,
+        None if subpath != "." => subpath,
+        None => return None,
+    };
+    let path = normalize_path(&package_directory.join(target));
+    // The exported files can't be outside of the package
+    if !path.starts_with(normalize_path(&package_directory)) || !fs.path_exists(&path) {
+        return None;
+    }
+    Some(path)
+}
+
+/// Splits a bare specifier into the name of the package and the subpath it refers to,
+/// e.g. `@org/package/subpath` becomes `@org/package` and `./subpath`.
+fn split_package_specifier(specifier: &str) -> Option<(&str, String)> {
+    if specifier.is_empty()
+        || specifier.starts_with('.')
+        || specifier.starts_with('/')
+        || Path::new(specifier).is_absolute()
+    {
+        return None;
+    }
+
+    let separator = if specifier.starts_with('@') {
+        let scope_end = specifier.find('/')?;
+        specifier[scope_end + 1..]
+            .find('/')
+            .map(|index| scope_end + 1 + index)
+    } else {
+        specifier.find('/')
+    };
+    match separator {
+        Some(separator) => Some((
+            &specifier[..separator],
+            format!(".{}", &specifier[separator..]),
+        )),
+        None => Some((specifier, String::from("."))),
+    }
+}
+
+/// Returns the part of `specifier` that is matched by the `*` of `pattern`.
+fn match_path_pattern<'a>(pattern: &str, specifier: &'a str) -> Option<&'a str> {
+    match pattern.split_once('*') {
+        None => (pattern == specifier).then_some(""),
+        Some((prefix, suffix)) => {
+            if specifier.len() < prefix.len() + suffix.len() {
+                return None;
+            }
+            specifier.strip_prefix(prefix)?.strip_suffix(suffix)
+        }
+    }
+}
+
+/// Finds the closest `tsconfig.json` file, starting from `directory` and walking up its ancestors.
+fn find_tsconfig(fs: &dyn FileSystem, directory: &Path) -> Option<(PathBuf, TsConfigJson)> {
+    directory.ancestors().find_map(|directory| {
+        let path = directory.join(TSCONFIG_JSON);
+        if !fs.path_exists(&path) {
+            return None;
+        }
+
+        let mut file = fs
+            .open_with_options(&path, OpenOptions::default().read(true))
+            .ok()?;
+        let mut content = String::new();
+        file.read_to_string(&mut content).ok()?;
+
+        let options = JsonParserOptions::default()
+            .with_allow_comments()
+            .with_allow_trailing_commas();
+        let tsconfig =
+            deserialize_from_json_str::<TsConfigJson>(&content, options).into_deserialized()?;
+        Some((directory.to_path_buf(), tsconfig))
+    })
+}
+
+/// Finds the file that `path` refers to, trying the known extensions and `index` files.
+fn resolve_path(fs: &dyn FileSystem, path: &Path) -> Option<PathBuf> {
+    let path = normalize_path(path);
+
+    let has_known_extension = path
+        .file_name()
+        .and_then(|file_name| file_name.to_str())
+        .is_some_and(|file_name| {
+            EXTENSIONS
+                .iter()
+                .any(|extension| file_name.ends_with(&format!(".{extension}")))
+        });
+    if has_known_extension && fs.path_exists(&path) {
+        return Some(path);
+    }
+
+    // TypeScript files import each other using the extension of the emitted file
+    let source_extensions: &[&str] = match path.extension().and_then(|extension| extension.to_str())
+    {
+        Some("js") => &["ts", "tsx"],
+        Some("jsx") => &["tsx"],
+        Some("mjs") => &["mts"],
+        Some("cjs") => &["cts"],
+        _ => &[],
+    };
+    let source_file = source_extensions
+        .iter()
+        .map(|extension| path.with_extension(extension))
+        .find(|path| fs.path_exists(path));
+    if source_file.is_some() {
+        return source_file;
+    }
+
+    let mut candidates = EXTENSIONS.iter().map(|extension| {
+        let mut file_name = path.as_os_str().to_owned();
+        file_name.push(".");
+        file_name.push(extension);
+        PathBuf::from(file_name)
+    });
+    if let Some(file) = candidates.find(|path| fs.path_exists(path)) {
+        return Some(file);
+    }
+
+    EXTENSIONS
+        .iter()
+        .map(|extension| path.join(format!("index.{extension}")))
+        .find(|path| fs.path_exists(path))
+}
+
+/// Removes the `.` and `..` components of `path` without accessing the file system.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod test {
+    use super::{resolve_module, resolve_package_export};
+    use biome_fs::MemoryFileSystem;
+    use std::path::{Path, PathBuf};
+
+    fn fs_with_files(files: &[&str]) -> MemoryFileSystem {
+        let mut fs = MemoryFileSystem::default();
+        for file in files {
+            fs.insert(PathBuf::from(file), "");
+        }
+        fs
+    }
+
+    #[test]
+    fn resolves_relative_specifiers() {
+        let fs = fs_with_files(&[
+            "/project/src/utils.ts",
+            "/project/src/components/index.tsx",
+            "/project/data.json",
+        ]);
+        let importer = Path::new("/project/src/main.ts");
+
+        assert_eq!(
+            resolve_module(&fs, importer, "./utils"),
+            Some(PathBuf::from("/project/src/utils.ts"))
+        );
+        assert_eq!(
+            resolve_module(&fs, importer, "./utils.js"),
+            Some(PathBuf::from("/project/src/utils.ts"))
+        );
+        assert_eq!(
+            resolve_module(&fs, importer, "./components"),
+            Some(PathBuf::from("/project/src/components/index.tsx"))
+        );
+        assert_eq!(
+            resolve_module(&fs, importer, "../data.json"),
+            Some(PathBuf::from("/project/data.json"))
+        );
+        assert_eq!(resolve_module(&fs, importer, "./missing"), None);
+    }
+
+    #[test]
+    fn resolves_tsconfig_paths() {
+        let mut fs = fs_with_files(&[
+            "/project/src/lib/format.ts",
+            "/project/src/config.ts",
+            "/project/types/env.d.ts",
+        ]);
+        fs.insert(
+            PathBuf::from("/project/tsconfig.json"),
+            r#"{
+    // comments are allowed in tsconfig.json
+    "compilerOptions": {
+        "baseUrl": "./src",
+        "paths": {
+            "@lib/*": ["lib/*"],
+            "@env": ["../types/env.d.ts"],
+        },
+    }
+}"#,
+        );
+        let importer = Path::new("/project/src/app/main.ts");
+
+        assert_eq!(
+            resolve_module(&fs, importer, "@lib/format"),
+            Some(PathBuf::from("/project/src/lib/format.ts"))
+        );
+        assert_eq!(
+            resolve_module(&fs, importer, "@env"),
+            Some(PathBuf::from("/project/types/env.d.ts"))
+        );
+        assert_eq!(
+            resolve_module(&fs, importer, "config"),
+            Some(PathBuf::from("/project/src/config.ts"))
+        );
+        assert_eq!(resolve_module(&fs, importer, "react"), None);
+    }
+
+    #[test]
+    fn resolves_package_exports() {
+        let mut fs = fs_with_files(&[
+            "/project/node_modules/@org/config/biome.json",
+            "/project/node_modules/@org/config/strict.json",
+            "/project/node_modules/@org/config/presets/react.json",
+            "/project/node_modules/legacy/biome.json",
+        ]);
+        fs.insert(
+            PathBuf::from("/project/node_modules/@org/config/package.json"),
+            r#"{
+    "name": "@org/config",
+    "exports": {
+        ".": { "biome": "./biome.json", "default": "./index.js" },
+        "./strict": "./strict.json",
+        "./presets/*": "./presets/*.json",
+        "./private": null
+    }
+}"#,
+        );
+        fs.insert(
+            PathBuf::from("/project/node_modules/legacy/package.json"),
+            r#"{ "name": "legacy" }"#,
+        );
+        let directory = Path::new("/project/packages/app");
+        let conditions = &["biome"];
+
+        assert_eq!(
+            resolve_package_export(&fs, directory, "@org/config", conditions),
+            Some(PathBuf::from(
+                "/project/node_modules/@org/config/biome.json"
+            ))
+        );
+        assert_eq!(
+            resolve_package_export(&fs, directory, "@org/config/strict", conditions),
+            Some(PathBuf::from(
+                "/project/node_modules/@org/config/strict.json"
+            ))
+        );
+        assert_eq!(
+            resolve_package_export(&fs, directory, "@org/config/presets/react", conditions),
+            Some(PathBuf::from(
+                "/project/node_modules/@org/config/presets/react.json"
+            ))
+        );
+        assert_eq!(
+            resolve_package_export(&fs, directory, "@org/config/private", conditions),
+            None
+        );
+        assert_eq!(
+            resolve_package_export(&fs, directory, "legacy/biome.json", conditions),
+            Some(PathBuf::from("/project/node_modules/legacy/biome.json"))
+        );
+        assert_eq!(
+            resolve_package_export(&fs, directory, "legacy", conditions),
+            None
+        );
+        assert_eq!(
+            resolve_package_export(&fs, directory, "missing", conditions),
+            None
+        );
+    }
+}
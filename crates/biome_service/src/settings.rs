@@ -1,9 +1,13 @@
-use crate::configuration::{push_to_analyzer_rules, JavascriptConfiguration, JsonConfiguration};
+use crate::configuration::{
+    push_to_analyzer_rules, JavascriptConfiguration, JsonConfiguration, MarkdownConfiguration,
+};
+use crate::file_handlers;
+use crate::workspace::FeatureName;
 use crate::{
     configuration::FilesConfiguration, Configuration, ConfigurationDiagnostic, MatchOptions,
     Matcher, MergeWith, Rules, WorkspaceError,
 };
-use biome_analyze::{AnalyzerRules, RuleFilter};
+use biome_analyze::{AnalyzerGlobals, AnalyzerRules, GlobalAccess, RuleFilter};
 use biome_deserialize::StringSet;
 use biome_diagnostics::Category;
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
@@ -15,12 +19,13 @@ use biome_js_syntax::JsLanguage;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use std::ops::{BitOr, Sub};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::{
     num::NonZeroU64,
     sync::{RwLock, RwLockReadGuard},
+    time::Duration,
 };
 
 /// Global settings for the entire workspace
@@ -32,6 +37,8 @@ pub struct WorkspaceSettings {
     pub linter: LinterSettings,
     /// Language specific settings
     pub languages: LanguageListSettings,
+    /// Markdown settings
+    pub markdown: MarkdownSettings,
     /// Filesystem settings for the workspace
     pub files: FilesSettings,
     /// Analyzer settings
@@ -46,6 +53,20 @@ impl WorkspaceSettings {
         &self.formatter
     }
 
+    /// Whether `path` can be formatted when it has syntax errors
+    pub fn format_with_errors(&self, path: &Path) -> bool {
+        self.override_settings
+            .format_with_errors(path)
+            .unwrap_or(self.formatter.format_with_errors)
+    }
+
+    /// The maximum size, in bytes, of the files that Biome processes at `path`
+    pub fn max_size(&self, path: &Path) -> NonZeroU64 {
+        self.override_settings
+            .max_size(path)
+            .unwrap_or(self.files.max_size)
+    }
+
     /// Whether the formatter is disabled for JavaScript files
     pub fn javascript_formatter_disabled(&self) -> bool {
         let enabled = self.languages.javascript.formatter.enabled.as_ref();
@@ -58,6 +79,11 @@ impl WorkspaceSettings {
         enabled == Some(&false)
     }
 
+    /// Whether the formatter is disabled for Markdown files
+    pub fn markdown_formatter_disabled(&self) -> bool {
+        !self.markdown.formatter_enabled
+    }
+
     /// Retrieves the settings of the linter
     pub fn linter(&self) -> &LinterSettings {
         &self.linter
@@ -72,8 +98,10 @@ impl WorkspaceSettings {
     #[tracing::instrument(level = "trace", skip(self))]
     pub fn merge_with_configuration(
         &mut self,
-        configuration: Configuration,
+        mut configuration: Configuration,
     ) -> Result<(), WorkspaceError> {
+        configuration.apply_domains();
+
         // formatter part
         if let Some(formatter) = configuration.formatter {
             self.formatter = FormatSettings::try_from(formatter)?;
@@ -105,10 +133,38 @@ impl WorkspaceSettings {
         if let Some(json) = configuration.json {
             self.languages.json = json.into();
         }
+        // markdown settings
+        if let Some(markdown) = configuration.markdown {
+            self.markdown = markdown.into();
+        }
 
         Ok(())
     }
 
+    /// Makes the glob patterns of the settings relative to the directory of their project
+    pub(crate) fn set_project_directory(&mut self, directory: &Path) {
+        let matchers = [
+            &mut self.files.ignored_files,
+            &mut self.files.included_files,
+            &mut self.formatter.ignored_files,
+            &mut self.formatter.included_files,
+            &mut self.linter.ignored_files,
+            &mut self.linter.included_files,
+            &mut self.organize_imports.ignored_files,
+            &mut self.organize_imports.included_files,
+        ]
+        .into_iter()
+        .chain(
+            self.override_settings
+                .patterns
+                .iter_mut()
+                .flat_map(|pattern| [&mut pattern.include, &mut pattern.exclude]),
+        );
+        for matcher in matchers.flatten() {
+            matcher.set_root(directory.to_path_buf());
+        }
+    }
+
     /// It retrieves the severity based on the `code` of the rule and the current configuration.
     ///
     /// The code of the has the following pattern: `{group}/{rule_name}`.
@@ -134,6 +190,113 @@ impl WorkspaceSettings {
             .as_ref()
             .map(|rules| overrides.override_as_rules(path, rules.clone()))
     }
+
+    /// It tells whether `path` is ignored by the `feature`, along with the setting and the
+    /// glob pattern that decided it.
+    ///
+    /// The overrides have top priority, then come the settings of the feature, and
+    /// then the `files` settings.
+    pub fn path_ignore_status(&self, path: &Path, feature: &FeatureName) -> PathIgnoreStatus {
+        for (index, pattern) in self.override_settings.patterns.iter().enumerate() {
+            if let Some(pattern) = pattern
+                .exclude
+                .as_ref()
+                .and_then(|exclude| exclude.matching_pattern(path))
+            {
+                return PathIgnoreStatus::matched(
+                    true,
+                    format!("overrides[{index}].ignore"),
+                    pattern,
+                );
+            }
+        }
+        for (index, pattern) in self.override_settings.patterns.iter().enumerate() {
+            if let Some(pattern) = pattern
+                .include
+                .as_ref()
+                .and_then(|include| include.matching_pattern(path))
+            {
+                return PathIgnoreStatus::matched(
+                    false,
+                    format!("overrides[{index}].include"),
+                    pattern,
+                );
+            }
+        }
+
+        let (name, ignored_files, included_files) = match feature {
+            FeatureName::Format => (
+                "formatter",
+                &self.formatter.ignored_files,
+                &self.formatter.included_files,
+            ),
+            FeatureName::Lint => (
+                "linter",
+                &self.linter.ignored_files,
+                &self.linter.included_files,
+            ),
+            FeatureName::OrganizeImports => (
+                "organizeImports",
+                &self.organize_imports.ignored_files,
+                &self.organize_imports.included_files,
+            ),
+        };
+        if let Some(pattern) = ignored_files
+            .as_ref()
+            .and_then(|matcher| matcher.matching_pattern(path))
+        {
+            return PathIgnoreStatus::matched(true, format!("{name}.ignore"), pattern);
+        }
+        if let Some(pattern) = included_files
+            .as_ref()
+            .and_then(|matcher| matcher.matching_pattern(path))
+        {
+            return PathIgnoreStatus::matched(false, format!("{name}.include"), pattern);
+        }
+
+        if let Some(pattern) = self
+            .files
+            .ignored_files
+            .as_ref()
+            .and_then(|matcher| matcher.matching_pattern(path))
+        {
+            PathIgnoreStatus::matched(true, "files.ignore", pattern)
+        } else if let Some(matcher) = self.files.included_files.as_ref() {
+            match matcher.matching_pattern(path) {
+                Some(pattern) => PathIgnoreStatus::matched(false, "files.include", pattern),
+                None => PathIgnoreStatus {
+                    ignored: true,
+                    setting: Some(String::from("files.include")),
+                    pattern: None,
+                },
+            }
+        } else {
+            PathIgnoreStatus::default()
+        }
+    }
+}
+
+/// Whether a path is ignored, and why
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct PathIgnoreStatus {
+    /// Whether the path is ignored
+    pub ignored: bool,
+    /// The name of the setting that decided whether the path is ignored, e.g. `files.ignore`.
+    /// It's [None] when no setting matches the path.
+    pub setting: Option<String>,
+    /// The glob pattern of the setting that matched the path. It's [None] when the path
+    /// is ignored because no pattern of `files.include` matches it.
+    pub pattern: Option<String>,
+}
+
+impl PathIgnoreStatus {
+    fn matched(ignored: bool, setting: impl Into<String>, pattern: &str) -> Self {
+        Self {
+            ignored,
+            setting: Some(setting.into()),
+            pattern: Some(pattern.to_string()),
+        }
+    }
 }
 
 /// Formatter settings for the entire workspace
@@ -148,6 +311,8 @@ pub struct FormatSettings {
     pub indent_width: Option<IndentWidth>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
+    /// Whether to match the output of Prettier where Biome intentionally diverges from it
+    pub prettier_compatibility: bool,
     /// List of ignore paths/files
     pub ignored_files: Option<Matcher>,
     /// List of included paths/files
@@ -163,6 +328,7 @@ impl Default for FormatSettings {
             indent_width: Some(IndentWidth::default()),
             line_ending: Some(LineEnding::default()),
             line_width: Some(LineWidth::default()),
+            prettier_compatibility: false,
             ignored_files: None,
             included_files: None,
         }
@@ -176,11 +342,12 @@ pub struct OverrideFormatSettings {
     pub enabled: Option<bool>,
     /// Stores whether formatting should be allowed to proceed if a given file
     /// has syntax errors
-    pub format_with_errors: bool,
+    pub format_with_errors: Option<bool>,
     pub indent_style: Option<IndentStyle>,
     pub indent_width: Option<IndentWidth>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
+    pub prettier_compatibility: Option<bool>,
 }
 
 /// Linter settings for the entire workspace
@@ -197,6 +364,12 @@ pub struct LinterSettings {
 
     /// List of included paths/files to match
     pub included_files: Option<Matcher>,
+
+    /// Maximum amount of time the linter can spend analyzing a single file
+    pub timeout: Option<Duration>,
+
+    /// Whether the suppression comments without an explanation are reported
+    pub require_suppression_reasons: bool,
 }
 
 impl Default for LinterSettings {
@@ -206,6 +379,8 @@ impl Default for LinterSettings {
             rules: Some(Rules::default()),
             ignored_files: None,
             included_files: None,
+            timeout: None,
+            require_suppression_reasons: false,
         }
     }
 }
@@ -243,6 +418,31 @@ impl Default for OrganizeImportsSettings {
     }
 }
 
+/// Markdown settings for the entire workspace
+#[derive(Debug, Default)]
+pub struct MarkdownSettings {
+    /// Whether the fenced code blocks are formatted. Disabled by default
+    pub formatter_enabled: bool,
+}
+
+impl From<MarkdownConfiguration> for MarkdownSettings {
+    fn from(markdown: MarkdownConfiguration) -> Self {
+        Self {
+            formatter_enabled: markdown
+                .formatter
+                .and_then(|formatter| formatter.enabled)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Markdown settings applied by an override
+#[derive(Debug, Default)]
+pub struct OverrideMarkdownSettings {
+    /// Whether the fenced code blocks are formatted
+    pub formatter_enabled: Option<bool>,
+}
+
 /// Linter settings for the entire workspace
 #[derive(Debug)]
 pub struct OverrideOrganizeImportsSettings {
@@ -250,6 +450,13 @@ pub struct OverrideOrganizeImportsSettings {
     pub enabled: Option<bool>,
 }
 
+/// Filesystem settings applied by an override
+#[derive(Debug, Default)]
+pub struct OverrideFilesSettings {
+    /// File size limit in bytes
+    pub max_size: Option<NonZeroU64>,
+}
+
 /// Static map of language names to language-specific settings
 #[derive(Debug, Default)]
 pub struct LanguageListSettings {
@@ -271,8 +478,16 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
             language_setting.formatter.bracket_spacing = formatter.bracket_spacing.map(Into::into);
             language_setting.formatter.bracket_same_line =
                 formatter.bracket_same_line.map(Into::into);
+            language_setting.formatter.decorator_placement = formatter.decorator_placement;
+            language_setting.formatter.dedent_tags = formatter
+                .dedent_tags
+                .map(|tags| tags.into_index_set().into_iter().collect());
+            language_setting.formatter.sql_tags = formatter
+                .sql_tags
+                .map(|tags| tags.into_index_set().into_iter().collect());
             language_setting.formatter.enabled = formatter.enabled;
             language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
             language_setting.formatter.indent_width = formatter
                 .indent_width
                 .map(Into::into)
@@ -281,15 +496,21 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
         }
 
         if let Some(parser) = javascript.parser {
-            language_setting.parser.parse_class_parameter_decorators = parser
-                .unsafe_parameter_decorators_enabled
-                .unwrap_or_default();
+            language_setting.parser.parse_class_parameter_decorators =
+                parser.unsafe_parameter_decorators_enabled;
         }
 
         let organize_imports = javascript.organize_imports;
         if let Some(_organize_imports) = organize_imports {}
 
-        language_setting.globals = javascript.globals.map(|global| global.into_index_set());
+        language_setting.globals = javascript.globals.map(|globals| globals.into_bindings());
+        language_setting.environments = javascript.environments.map(|environments| {
+            environments
+                .0
+                .iter()
+                .map(|environment| environment.as_str().to_string())
+                .collect()
+        });
 
         language_setting
     }
@@ -299,18 +520,19 @@ impl From<JsonConfiguration> for LanguageSettings<JsonLanguage> {
     fn from(json: JsonConfiguration) -> Self {
         let mut language_setting: LanguageSettings<JsonLanguage> = LanguageSettings::default();
         if let Some(parser) = json.parser {
-            language_setting.parser.allow_comments = parser.allow_comments.unwrap_or_default();
-            language_setting.parser.allow_trailing_commas =
-                parser.allow_trailing_commas.unwrap_or_default();
+            language_setting.parser.allow_comments = parser.allow_comments;
+            language_setting.parser.allow_trailing_commas = parser.allow_trailing_commas;
         }
         if let Some(formatter) = json.formatter {
             language_setting.formatter.enabled = formatter.enabled;
             language_setting.formatter.line_width = formatter.line_width;
+            language_setting.formatter.line_ending = formatter.line_ending;
             language_setting.formatter.indent_width = formatter
                 .indent_width
                 .map(Into::into)
                 .or(formatter.indent_size.map(Into::into));
             language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
+            language_setting.formatter.array_fill = formatter.array_fill;
         }
         language_setting
     }
@@ -351,8 +573,11 @@ pub struct LanguageSettings<L: Language> {
     /// Linter settings for this language
     pub linter: L::LinterSettings,
 
-    /// Globals variables/bindings that can be found in a file
-    pub globals: Option<IndexSet<String>>,
+    /// Globals variables/bindings that can be found in a file, along with how they can be used
+    pub globals: Option<IndexMap<String, GlobalAccess>>,
+
+    /// The environments that provide globals variables/bindings
+    pub environments: Option<Vec<String>>,
 
     /// Organize imports settings for this language
     pub organize_imports: L::OrganizeImportsSettings,
@@ -375,6 +600,9 @@ pub struct FilesSettings {
 
     /// Files not recognized by Biome should not emit a diagnostic
     pub ignore_unknown: bool,
+
+    /// The generated and minified files are skipped
+    pub exclude_generated: bool,
 }
 
 /// Limit the size of files to 1.0 MiB by default
@@ -389,6 +617,7 @@ impl Default for FilesSettings {
             ignored_files: None,
             included_files: None,
             ignore_unknown: false,
+            exclude_generated: false,
         }
     }
 }
@@ -402,41 +631,94 @@ impl TryFrom<FilesConfiguration> for FilesSettings {
             ignored_files: to_matcher(config.ignore.as_ref())?,
             included_files: to_matcher(config.include.as_ref())?,
             ignore_unknown: config.ignore_unknown.unwrap_or_default(),
+            exclude_generated: config.exclude_generated.unwrap_or_default(),
         })
     }
 }
 
+/// The settings of the workspace and of its projects. A project is a directory of the
+/// workspace with its own configuration, the files outside of the projects use the
+/// settings of the workspace.
+#[derive(Debug, Default)]
+pub struct ProjectsSettings {
+    workspace: WorkspaceSettings,
+    /// The directories of the projects, along with their settings
+    projects: Vec<(PathBuf, WorkspaceSettings)>,
+}
+
+impl ProjectsSettings {
+    /// Returns the index of the project that contains `path`. The innermost project wins
+    /// when the projects are nested.
+    fn project_of(&self, path: &Path) -> Option<usize> {
+        self.projects
+            .iter()
+            .enumerate()
+            .filter(|(_, (directory, _))| path.starts_with(directory))
+            .max_by_key(|(_, (directory, _))| directory.components().count())
+            .map(|(index, _)| index)
+    }
+
+    fn get(&self, project: Option<usize>) -> &WorkspaceSettings {
+        project.map_or(&self.workspace, |index| &self.projects[index].1)
+    }
+
+    /// Sets the settings of the project in `directory`, or of the workspace if there
+    /// isn't a directory
+    pub(crate) fn insert(&mut self, directory: Option<&Path>, settings: WorkspaceSettings) {
+        let Some(directory) = directory else {
+            self.workspace = settings;
+            return;
+        };
+        match self
+            .projects
+            .iter_mut()
+            .find(|(project, _)| project == directory)
+        {
+            Some((_, project_settings)) => *project_settings = settings,
+            None => self.projects.push((directory.to_path_buf(), settings)),
+        }
+    }
+
+    /// Removes the settings of the project in `directory`
+    pub(crate) fn remove(&mut self, directory: &Path) {
+        self.projects.retain(|(project, _)| project != directory);
+    }
+}
+
 /// Handle object holding a temporary lock on the workspace settings until
 /// the deferred language-specific options resolution is called
 #[derive(Debug)]
 pub struct SettingsHandle<'a> {
-    inner: RwLockReadGuard<'a, WorkspaceSettings>,
+    inner: RwLockReadGuard<'a, ProjectsSettings>,
+    /// The project of the file the settings are used for
+    project: Option<usize>,
 }
 
 impl<'a> SettingsHandle<'a> {
-    pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>) -> Self {
-        Self {
-            inner: settings.read().unwrap(),
-        }
+    pub(crate) fn new(settings: &'a RwLock<ProjectsSettings>, path: &Path) -> Self {
+        let inner = settings.read().unwrap();
+        let project = inner.project_of(path);
+        Self { inner, project }
     }
 }
 
 impl<'a> AsRef<WorkspaceSettings> for SettingsHandle<'a> {
     fn as_ref(&self) -> &WorkspaceSettings {
-        &self.inner
+        self.inner.get(self.project)
     }
 }
 
 impl<'a> SettingsHandle<'a> {
     /// Resolve the formatting context for the given language
-    pub(crate) fn format_options<L>(self, path: &RomePath) -> L::FormatOptions
+    pub(crate) fn format_options<L>(&self, path: &RomePath) -> L::FormatOptions
     where
         L: Language,
     {
+        let settings = self.as_ref();
         L::resolve_format_options(
-            &self.inner.formatter,
-            &self.inner.override_settings,
-            &L::lookup_settings(&self.inner.languages).formatter,
+            &settings.formatter,
+            &settings.override_settings,
+            &L::lookup_settings(&settings.languages).formatter,
             path,
         )
     }
@@ -499,6 +781,12 @@ impl OverrideSettings {
                 if let Some(line_width) = js_formatter.line_width.or(formatter.line_width) {
                     options.set_line_width(line_width);
                 }
+                if let Some(line_ending) = js_formatter.line_ending.or(formatter.line_ending) {
+                    options.set_line_ending(line_ending);
+                }
+                if let Some(prettier_compatibility) = formatter.prettier_compatibility {
+                    options.set_prettier_compatibility(prettier_compatibility);
+                }
                 if let Some(quote_style) = js_formatter.quote_style {
                     options.set_quote_style(quote_style);
                 }
@@ -523,6 +811,15 @@ impl OverrideSettings {
                 if let Some(bracket_same_line) = js_formatter.bracket_same_line {
                     options.set_bracket_same_line(bracket_same_line);
                 }
+                if let Some(decorator_placement) = js_formatter.decorator_placement {
+                    options.set_decorator_placement(decorator_placement);
+                }
+                if let Some(dedent_tags) = &js_formatter.dedent_tags {
+                    options.set_dedent_tags(dedent_tags.clone());
+                }
+                if let Some(sql_tags) = &js_formatter.sql_tags {
+                    options.set_sql_tags(sql_tags.clone());
+                }
             }
 
             options
@@ -561,6 +858,14 @@ impl OverrideSettings {
                 {
                     options.set_line_width(line_width);
                 }
+                if let Some(line_ending) =
+                    json_formatter.line_ending.or(pattern.formatter.line_ending)
+                {
+                    options.set_line_ending(line_ending);
+                }
+                if let Some(array_fill) = json_formatter.array_fill {
+                    options.set_array_fill(array_fill);
+                }
             }
 
             options
@@ -581,29 +886,75 @@ impl OverrideSettings {
             if included == Some(true) {
                 let js_parser = &pattern.languages.javascript.parser;
 
-                options.parse_class_parameter_decorators =
-                    js_parser.parse_class_parameter_decorators;
+                if let Some(parse_class_parameter_decorators) =
+                    js_parser.parse_class_parameter_decorators
+                {
+                    options.parse_class_parameter_decorators = parse_class_parameter_decorators;
+                }
             }
             options
         })
     }
 
-    pub fn as_json_parser_options(&self, path: &Path) -> Option<JsonParserOptions> {
-        for pattern in &self.patterns {
+    pub fn override_json_parser_options(
+        &self,
+        path: &Path,
+        options: JsonParserOptions,
+    ) -> JsonParserOptions {
+        self.patterns.iter().fold(options, |mut options, pattern| {
             let included = pattern.include.as_ref().map(|p| p.matches_path(path));
             let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
-
-            if included == Some(true) || excluded == Some(false) {
+            if excluded == Some(true) {
+                return options;
+            }
+            if included == Some(true) {
                 let json_parser = &pattern.languages.json.parser;
 
-                return Some(JsonParserOptions {
-                    allow_comments: json_parser.allow_comments,
-                    allow_trailing_commas: json_parser.allow_trailing_commas,
-                });
+                if let Some(allow_comments) = json_parser.allow_comments {
+                    options.allow_comments = allow_comments;
+                }
+                if let Some(allow_trailing_commas) = json_parser.allow_trailing_commas {
+                    options.allow_trailing_commas = allow_trailing_commas;
+                }
             }
-        }
+            options
+        })
+    }
 
-        None
+    /// Retrieves the globals and the environments of the JavaScript files that have been
+    /// overridden. The globals of the overrides are added to the ones of the configuration,
+    /// while their environments replace them.
+    pub fn override_analyzer_globals(
+        &self,
+        path: &Path,
+        analyzer_globals: AnalyzerGlobals,
+    ) -> AnalyzerGlobals {
+        self.patterns
+            .iter()
+            .fold(analyzer_globals, |mut analyzer_globals, pattern| {
+                let included = pattern.include.as_ref().map(|p| p.matches_path(path));
+                let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
+                if excluded == Some(true) {
+                    return analyzer_globals;
+                }
+                if included == Some(true) {
+                    let javascript = &pattern.languages.javascript;
+                    if let Some(environments) = &javascript.environments {
+                        analyzer_globals.environments = Some(environments.clone());
+                    }
+                    for (name, access) in javascript.globals.iter().flatten() {
+                        match analyzer_globals
+                            .bindings
+                            .iter_mut()
+                            .find(|(binding, _)| binding == name)
+                        {
+                            Some((_, binding_access)) => *binding_access = *access,
+                            None => analyzer_globals.bindings.push((name.clone(), *access)),
+                        }
+                    }
+                }
+                analyzer_globals
+            })
     }
 
     /// Retrieves the options of lint rules that have been overridden
@@ -666,14 +1017,28 @@ impl OverrideSettings {
         })
     }
 
-    /// Scans the overrides and checks if there's an override that disable the formatter for `path`
-    pub fn formatter_disabled(&self, path: &Path) -> Option<bool> {
+    /// Scans the overrides and checks if there's an override that disable the formatter for `path`.
+    /// The formatter of the `language` of the file takes precedence over the generic one.
+    pub fn formatter_disabled(
+        &self,
+        path: &Path,
+        language: &file_handlers::Language,
+    ) -> Option<bool> {
         for pattern in &self.patterns {
             let included = pattern.include.as_ref().map(|p| p.matches_path(path));
             let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
 
             if included == Some(true) || excluded == Some(false) {
-                if let Some(enabled) = pattern.formatter.enabled {
+                let language_enabled = if language.is_javascript_like() {
+                    pattern.languages.javascript.formatter.enabled
+                } else if language.is_json_like() {
+                    pattern.languages.json.formatter.enabled
+                } else if *language == file_handlers::Language::Markdown {
+                    pattern.markdown.formatter_enabled
+                } else {
+                    None
+                };
+                if let Some(enabled) = language_enabled.or(pattern.formatter.enabled) {
                     return Some(!enabled);
                 }
             }
@@ -681,6 +1046,38 @@ impl OverrideSettings {
         None
     }
 
+    /// Scans the overrides and checks if there's an override that allows the formatting of
+    /// `path` when it has syntax errors
+    pub fn format_with_errors(&self, path: &Path) -> Option<bool> {
+        for pattern in &self.patterns {
+            let included = pattern.include.as_ref().map(|p| p.matches_path(path));
+            let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
+
+            if included == Some(true) || excluded == Some(false) {
+                if let Some(format_with_errors) = pattern.formatter.format_with_errors {
+                    return Some(format_with_errors);
+                }
+            }
+        }
+        None
+    }
+
+    /// Scans the overrides and checks if there's an override that changes the maximum size of
+    /// the files at `path`
+    pub fn max_size(&self, path: &Path) -> Option<NonZeroU64> {
+        for pattern in &self.patterns {
+            let included = pattern.include.as_ref().map(|p| p.matches_path(path));
+            let excluded = pattern.exclude.as_ref().map(|p| p.matches_path(path));
+
+            if included == Some(true) || excluded == Some(false) {
+                if let Some(max_size) = pattern.files.max_size {
+                    return Some(max_size);
+                }
+            }
+        }
+        None
+    }
+
     /// Scans the overrides and checks if there's an override that disable the linter for `path`
     pub fn linter_disabled(&self, path: &Path) -> Option<bool> {
         for pattern in &self.patterns {
@@ -721,8 +1118,12 @@ pub struct OverrideSettingPattern {
     pub linter: OverrideLinterSettings,
     /// Linter settings applied to all files in the workspace
     pub organize_imports: OverrideOrganizeImportsSettings,
+    /// Filesystem settings applied to the matched files
+    pub files: OverrideFilesSettings,
     /// Language specific settings
     pub languages: LanguageListSettings,
+    /// Markdown settings
+    pub markdown: OverrideMarkdownSettings,
 }
 
 /// Creates a [Matcher] from a [StringSet]
@@ -51,6 +51,7 @@
 //! document does not implement the required capability: for instance trying to
 //! format a file with a language that does not have a formatter
 
+use crate::configuration::RuleSelector;
 use crate::file_handlers::Capabilities;
 use crate::{Configuration, Deserialize, Serialize, WorkspaceError};
 use biome_analyze::ActionCategory;
@@ -61,9 +62,9 @@ use biome_formatter::Printed;
 use biome_fs::RomePath;
 use biome_js_syntax::{TextRange, TextSize};
 use biome_text_edit::TextEdit;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::OsStr;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
 use tracing::debug;
 
@@ -72,6 +73,10 @@ pub use crate::file_handlers::Language;
 use crate::settings::WorkspaceSettings;
 
 mod client;
+mod fuzzy;
+mod generated;
+mod node_cache;
+mod rule_timings;
 mod server;
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -95,7 +100,7 @@ pub struct FileFeaturesResult {
 
 impl FileFeaturesResult {
     /// Files that should not be processed no matter the cases
-    pub(crate) const FILES_TO_NOT_PROCESS: &'static [&'static str; 11] = &[
+    pub(crate) const FILES_TO_NOT_PROCESS: &'static [&'static str; 14] = &[
         "package.json",
         "package-lock.json",
         "npm-shrinkwrap.json",
@@ -107,6 +112,9 @@ impl FileFeaturesResult {
         "jsconfig.json",
         "deno.json",
         "deno.jsonc",
+        "biome.lock.json",
+        ".biome-cache.json",
+        ".biome-extends-cache.json",
     ];
 
     /// Checks whether this file can be processed
@@ -131,7 +139,7 @@ impl FileFeaturesResult {
     }
 
     pub fn with_capabilities(mut self, capabilities: &Capabilities) -> Self {
-        if capabilities.formatter.format.is_some() {
+        if capabilities.formatter.format.is_some() || capabilities.formatter.format_text.is_some() {
             self.features_supported
                 .insert(FeatureName::Format, SupportKind::Supported);
         }
@@ -154,16 +162,20 @@ impl FileFeaturesResult {
         path: &Path,
     ) -> Self {
         if self.can_process(path) {
-            let formatter_disabled =
-                if let Some(disabled) = settings.override_settings.formatter_disabled(path) {
-                    disabled
-                } else if language.is_javascript_like() {
-                    !settings.formatter().enabled || settings.javascript_formatter_disabled()
-                } else if language.is_json_like() {
-                    !settings.formatter().enabled || settings.json_formatter_disabled()
-                } else {
-                    !settings.formatter().enabled
-                };
+            let formatter_disabled = if let Some(disabled) = settings
+                .override_settings
+                .formatter_disabled(path, language)
+            {
+                disabled
+            } else if language.is_javascript_like() {
+                !settings.formatter().enabled || settings.javascript_formatter_disabled()
+            } else if language.is_json_like() {
+                !settings.formatter().enabled || settings.json_formatter_disabled()
+            } else if *language == Language::Markdown {
+                !settings.formatter().enabled || settings.markdown_formatter_disabled()
+            } else {
+                !settings.formatter().enabled
+            };
             if formatter_disabled {
                 self.features_supported
                     .insert(FeatureName::Format, SupportKind::FeatureNotEnabled);
@@ -306,6 +318,17 @@ impl FeaturesBuilder {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateSettingsParams {
     pub configuration: Configuration,
+    /// The directory of the project the configuration applies to, the configuration
+    /// applies to the whole workspace if it's `None`
+    #[serde(default)]
+    pub project_directory: Option<PathBuf>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CloseProjectParams {
+    /// The directory of the project, as given to [Workspace::update_settings]
+    pub project_directory: PathBuf,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -380,6 +403,12 @@ pub struct PullDiagnosticsParams {
     pub path: RomePath,
     pub categories: RuleCategories,
     pub max_diagnostics: u64,
+    /// When it isn't empty, only these rules are run, regardless of the configuration
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// Whether the time spent running each rule is returned
+    #[serde(default)]
+    pub rule_timings: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -388,6 +417,25 @@ pub struct PullDiagnosticsResult {
     pub diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
     pub errors: usize,
     pub skipped_diagnostics: u64,
+    /// The number of diagnostics that weren't returned because of `max_diagnostics`,
+    /// by category of the diagnostic
+    #[serde(default)]
+    pub skipped_diagnostics_by_category: BTreeMap<String, u64>,
+    /// The time spent running each rule, when it was requested with `rule_timings`
+    #[serde(default)]
+    pub rule_timings: Vec<RuleTimingEntry>,
+}
+
+/// The time spent running a rule on a file
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleTimingEntry {
+    pub group: String,
+    pub rule: String,
+    /// The time spent running the rule, in nanoseconds
+    pub nanos: u64,
+    /// The number of times the rule ran
+    pub runs: u64,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -439,6 +487,8 @@ pub enum FixFileMode {
     SafeFixes,
     /// Applies [safe](biome_diagnostics::Applicability::Always) and [unsafe](biome_diagnostics::Applicability::MaybeIncorrect) fixes
     SafeAndUnsafeFixes,
+    /// Adds a suppression comment for each diagnostic, instead of applying its fix
+    ApplySuppressions,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -447,6 +497,14 @@ pub struct FixFileParams {
     pub path: RomePath,
     pub fix_file_mode: FixFileMode,
     pub should_format: bool,
+    /// When it isn't empty, only the fixes of these rules are applied, regardless of the
+    /// configuration
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// The explanation of the suppression comments added by
+    /// [FixFileMode::ApplySuppressions]
+    #[serde(default)]
+    pub suppression_reason: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -473,6 +531,293 @@ pub struct FixAction {
     pub range: TextRange,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PrepareRenameParams {
+    pub path: RomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PrepareRenameResult {
+    /// Range of the symbol that can be renamed
+    pub range: TextRange,
+    /// Current name of the symbol
+    pub name: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FindReferencesParams {
+    pub path: RomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FindReferencesResult {
+    /// The declaration of the symbol and all its references, ordered by position
+    pub references: Vec<SymbolReference>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SymbolReference {
+    pub range: TextRange,
+    pub kind: SymbolReferenceKind,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SymbolReferenceKind {
+    /// The place where the symbol is declared
+    Declaration,
+    /// The value of the symbol is read
+    Read,
+    /// A new value is assigned to the symbol
+    Write,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GotoDefinitionParams {
+    pub path: RomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GotoDefinitionResult {
+    /// Where the symbol is defined, `None` if it isn't declared in the file
+    pub definition: Option<Definition>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum Definition {
+    /// The symbol is declared in the same file
+    Local { range: TextRange },
+    /// The symbol is imported from another module. Use the [resolver](crate::resolver)
+    /// to find the file that the specifier refers to.
+    Module { specifier: String },
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbolsParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbolsResult {
+    /// The top level symbols of the file
+    pub symbols: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Range of the whole declaration of the symbol
+    pub range: TextRange,
+    /// Range of the name of the symbol, it's contained in `range`
+    pub selection_range: TextRange,
+    /// The symbols declared inside this symbol, like the members of a class
+    pub children: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SymbolKind {
+    Class,
+    /// A function with a capitalized name that renders JSX
+    Component,
+    Constant,
+    Constructor,
+    Enum,
+    EnumMember,
+    Function,
+    Interface,
+    Method,
+    /// A TypeScript namespace or module declaration
+    Module,
+    Property,
+    TypeAlias,
+    Variable,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticTokensParams {
+    pub path: RomePath,
+    /// Only the tokens that intersect this range are returned, all the tokens of the file if `None`
+    pub range: Option<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticTokensResult {
+    /// The classified tokens, sorted by position. Each token spans a single line.
+    pub tokens: Vec<SemanticToken>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SemanticToken {
+    pub range: TextRange,
+    pub kind: SemanticTokenKind,
+    pub modifiers: Vec<SemanticTokenModifier>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticTokenKind {
+    Class,
+    Comment,
+    /// A component used in JSX, e.g. `Button` in `<Button />`
+    Component,
+    Enum,
+    EnumMember,
+    Function,
+    Interface,
+    /// An intrinsic JSX element, e.g. `div` in `<div />`
+    JsxTag,
+    Keyword,
+    Method,
+    Namespace,
+    Number,
+    Parameter,
+    Property,
+    Regexp,
+    String,
+    Type,
+    TypeParameter,
+    Variable,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticTokenModifier {
+    /// The token is the name of a declaration
+    Declaration,
+    /// The binding can't be reassigned, like a `const` or an import
+    Readonly,
+    /// The binding is declared by an import
+    Imported,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRangesParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRangesResult {
+    /// The foldable ranges, sorted by their start
+    pub ranges: Vec<FoldingRange>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRange {
+    /// The text that is hidden when the range is folded
+    pub range: TextRange,
+    pub kind: FoldingRangeKind,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum FoldingRangeKind {
+    /// The content of a block, of brackets or of a JSX element
+    Code,
+    Comment,
+    /// A group of consecutive import statements
+    Imports,
+    /// The code between a `// #region` and a `// #endregion` comment
+    Region,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InlayHintsParams {
+    pub path: RomePath,
+    /// Only the hints inside this range are returned
+    pub range: TextRange,
+    /// Show the names of the parameters before the arguments of a call
+    pub parameter_names: bool,
+    /// Show the return type of the functions without a type annotation, when it's obvious
+    pub return_types: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InlayHintsResult {
+    /// The hints, sorted by position
+    pub hints: Vec<InlayHint>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InlayHint {
+    /// The hint is displayed before the character at this position
+    pub position: TextSize,
+    pub label: String,
+    pub kind: InlayHintKind,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum InlayHintKind {
+    ParameterName,
+    ReturnType,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IndexFileParams {
+    pub path: RomePath,
+    /// The content of the file, `None` if the file was deleted
+    pub content: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorkspaceSymbolsParams {
+    /// A fuzzy query matched against the name of the symbols, all the symbols match an empty query
+    pub query: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorkspaceSymbolsResult {
+    /// The matching symbols, the best matches come first
+    pub symbols: Vec<WorkspaceSymbol>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct WorkspaceSymbol {
+    /// The file that exports the symbol
+    pub path: RomePath,
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Range of the name of the symbol
+    pub range: TextRange,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RenameParams {
@@ -585,9 +930,14 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// If the file path matches, than `true` is returned and it should be considered ignored.
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError>;
 
-    /// Update the global settings for this workspace
+    /// Update the settings of this workspace, or of one of its projects. The settings are
+    /// replaced by the ones of the configuration. The files of a project use its settings
+    /// instead of the settings of the workspace.
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError>;
 
+    /// Removes the settings of a project
+    fn close_project(&self, params: CloseProjectParams) -> Result<(), WorkspaceError>;
+
     /// Add a new file to the workspace
     fn open_file(&self, params: OpenFileParams) -> Result<(), WorkspaceError>;
 
@@ -632,13 +982,66 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Runs a range of an open document through the formatter
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError>;
 
-    /// Runs a "block" ending at the specified character of an open document
-    /// through the formatter
+    /// Runs the statement or the "block" ending at the specified character of an open
+    /// document through the formatter. The character is the one just typed, or the
+    /// newline that follows it. Nothing is formatted if it doesn't close a statement
+    /// or a block, in which case the result is empty and has no range.
     fn format_on_type(&self, params: FormatOnTypeParams) -> Result<Printed, WorkspaceError>;
 
     /// Return the content of the file with all safe code actions applied
     fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError>;
 
+    /// Checks that the symbol at the given position can be renamed, and returns its
+    /// range and its current name
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError>;
+
+    /// Returns the declaration and all the references of the symbol at a given position
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError>;
+
+    /// Returns where the symbol at a given position is defined
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError>;
+
+    /// Returns the outline of a file
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError>;
+
+    /// Return the classification of the tokens of a file, used for semantic highlighting
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError>;
+
+    /// Return the ranges of a file that can be folded by an editor
+    fn folding_ranges(
+        &self,
+        params: FoldingRangesParams,
+    ) -> Result<FoldingRangesResult, WorkspaceError>;
+
+    /// Return the inlay hints of a file, like the names of the parameters at call sites
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError>;
+
+    /// Adds the symbols exported by a file to the index searched by [Workspace::workspace_symbols],
+    /// or removes them if the file was deleted. The files opened in the workspace are indexed
+    /// automatically.
+    fn index_file(&self, params: IndexFileParams) -> Result<(), WorkspaceError>;
+
+    /// Searches the symbols exported by the indexed files
+    fn workspace_symbols(
+        &self,
+        params: WorkspaceSymbolsParams,
+    ) -> Result<WorkspaceSymbolsResult, WorkspaceError>;
+
     /// Return the content of the file after renaming a symbol
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
@@ -720,11 +1123,15 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         categories: RuleCategories,
         max_diagnostics: u64,
+        only: Vec<RuleSelector>,
+        rule_timings: bool,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
             path: self.path.clone(),
             categories,
             max_diagnostics,
+            only,
+            rule_timings,
         })
     }
 
@@ -759,11 +1166,15 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         fix_file_mode: FixFileMode,
         should_format: bool,
+        only: Vec<RuleSelector>,
+        suppression_reason: Option<String>,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
             fix_file_mode,
             should_format,
+            only,
+            suppression_reason,
         })
     }
 
@@ -772,6 +1183,28 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             path: self.path.clone(),
         })
     }
+
+    pub fn document_symbols(&self) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        self.workspace.document_symbols(DocumentSymbolsParams {
+            path: self.path.clone(),
+        })
+    }
+
+    pub fn folding_ranges(&self) -> Result<FoldingRangesResult, WorkspaceError> {
+        self.workspace.folding_ranges(FoldingRangesParams {
+            path: self.path.clone(),
+        })
+    }
+
+    pub fn semantic_tokens(
+        &self,
+        range: Option<TextRange>,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        self.workspace.semantic_tokens(SemanticTokensParams {
+            path: self.path.clone(),
+            range,
+        })
+    }
 }
 
 impl<'app, W: Workspace + ?Sized> Drop for FileGuard<'app, W> {
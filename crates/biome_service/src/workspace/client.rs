@@ -1,6 +1,8 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, ProjectFeaturesParams, ProjectFeaturesResult, RageParams, RageResult,
+    FileFeaturesResult, FoldingRangesParams, FoldingRangesResult, GetFileContentParams,
+    InlayHintsParams, InlayHintsResult, IsPathIgnoredParams, OrganizeImportsParams,
+    OrganizeImportsResult, PrepareRenameParams, PrepareRenameResult, ProjectFeaturesParams,
+    ProjectFeaturesResult, RageParams, RageResult, SemanticTokensParams, SemanticTokensResult,
     ServerInfo,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
@@ -13,11 +15,13 @@ use std::{
 };
 
 use super::{
-    ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult,
-    SupportsFeatureParams, UpdateSettingsParams,
+    ChangeFileParams, CloseFileParams, CloseProjectParams, DocumentSymbolsParams,
+    DocumentSymbolsResult, FindReferencesParams, FindReferencesResult, FixFileParams,
+    FixFileResult, FormatFileParams, FormatOnTypeParams, FormatRangeParams,
+    GetControlFlowGraphParams, GetFormatterIRParams, GetSyntaxTreeParams, GetSyntaxTreeResult,
+    GotoDefinitionParams, GotoDefinitionResult, IndexFileParams, OpenFileParams, PullActionsParams,
+    PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult,
+    SupportsFeatureParams, UpdateSettingsParams, WorkspaceSymbolsParams, WorkspaceSymbolsResult,
 };
 
 pub struct WorkspaceClient<T> {
@@ -116,6 +120,10 @@ where
         self.request("biome/update_settings", params)
     }
 
+    fn close_project(&self, params: CloseProjectParams) -> Result<(), WorkspaceError> {
+        self.request("biome/close_project", params)
+    }
+
     fn project_features(
         &self,
         params: ProjectFeaturesParams,
@@ -184,6 +192,63 @@ where
         self.request("biome/fix_file", params)
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        self.request("biome/prepare_rename", params)
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        self.request("biome/find_references", params)
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        self.request("biome/goto_definition", params)
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        self.request("biome/document_symbols", params)
+    }
+
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        self.request("biome/semantic_tokens", params)
+    }
+
+    fn folding_ranges(
+        &self,
+        params: FoldingRangesParams,
+    ) -> Result<FoldingRangesResult, WorkspaceError> {
+        self.request("biome/folding_ranges", params)
+    }
+
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError> {
+        self.request("biome/inlay_hints", params)
+    }
+
+    fn index_file(&self, params: IndexFileParams) -> Result<(), WorkspaceError> {
+        self.request("biome/index_file", params)
+    }
+
+    fn workspace_symbols(
+        &self,
+        params: WorkspaceSymbolsParams,
+    ) -> Result<WorkspaceSymbolsResult, WorkspaceError> {
+        self.request("biome/workspace_symbols", params)
+    }
+
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError> {
         self.request("biome/rename", params)
     }
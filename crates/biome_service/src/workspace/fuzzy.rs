@@ -0,0 +1,85 @@
+//! Fuzzy matching of symbol names, used to search the symbols of the workspace
+
+/// Returns how well `candidate` matches `query`, or `None` if it doesn't match.
+///
+/// The characters of the query must appear in the candidate in the same order, ignoring
+/// the case. Matches at the start of the candidate, at the start of a word
+/// (`fooBar`, `foo_bar`) and consecutive matches get a higher score.
+pub(super) fn fuzzy_score(query: &str, candidate: &str) -> Option<u32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let mut score: u32 = 0;
+    let mut query_chars = query.chars().flat_map(char::to_lowercase).peekable();
+    let mut previous: Option<char> = None;
+    let mut previous_matched = false;
+
+    for (index, char) in candidate.chars().enumerate() {
+        let Some(query_char) = query_chars.peek() else {
+            break;
+        };
+
+        let matches = char.to_lowercase().eq(std::iter::once(*query_char));
+        if matches {
+            query_chars.next();
+            score += 1;
+            if index == 0 {
+                score += 8;
+            } else if is_word_start(previous, char) {
+                score += 4;
+            }
+            if previous_matched {
+                score += 2;
+            }
+        }
+
+        previous_matched = matches;
+        previous = Some(char);
+    }
+
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    // Among the candidates that match equally well, the shortest ones come first
+    let unmatched = candidate
+        .chars()
+        .count()
+        .saturating_sub(query.chars().count());
+    Some((score * 64).saturating_sub(unmatched.min(63) as u32))
+}
+
+fn is_word_start(previous: Option<char>, char: char) -> bool {
+    match previous {
+        None => true,
+        Some(previous) => {
+            matches!(previous, '_' | '-' | '$' | '.')
+                || (previous.is_lowercase() && char.is_uppercase())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::fuzzy_score;
+
+    #[test]
+    fn matches_subsequences() {
+        assert!(fuzzy_score("usst", "useState").is_some());
+        assert!(fuzzy_score("USESTATE", "useState").is_some());
+        assert!(fuzzy_score("stateuse", "useState").is_none());
+        assert_eq!(fuzzy_score("", "useState"), Some(0));
+    }
+
+    #[test]
+    fn ranks_word_starts_and_prefixes_first() {
+        let word_starts = fuzzy_score("us", "useState").unwrap();
+        let scattered = fuzzy_score("us", "mouseEvents").unwrap();
+        assert!(word_starts > scattered);
+
+        let short = fuzzy_score("format", "format").unwrap();
+        let long = fuzzy_score("format", "formatter").unwrap();
+        assert!(short > long);
+    }
+}
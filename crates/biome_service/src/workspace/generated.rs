@@ -0,0 +1,94 @@
+//! Detection of the generated and minified files, skipped with `files.excludeGenerated`
+
+/// The number of lines, at the start of a file, searched for a marker of generated code
+const HEADER_LINES: usize = 10;
+
+/// The average length of the lines of a minified file
+const MINIFIED_LINE_LENGTH: usize = 200;
+
+/// The size, in bytes, under which a file isn't considered minified, so a short file with a
+/// long line, such as a small JSON document, is still processed
+const MINIFIED_MIN_SIZE: usize = 1024;
+
+/// Whether `content` looks generated by a tool or minified:
+/// - one of its first comments contains `@generated` or `DO NOT EDIT`, or disables the linters
+///   for the whole file with `eslint-disable`;
+/// - its last line is a source map URL, like the bundles emitted by the build tools;
+/// - its lines are too long on average to be written by hand.
+pub(super) fn is_generated(content: &str) -> bool {
+    has_generated_marker(content) || has_source_map_url(content) || is_minified(content)
+}
+
+fn has_generated_marker(content: &str) -> bool {
+    content
+        .lines()
+        .take(HEADER_LINES)
+        .map(str::trim)
+        .filter(|line| is_comment(line))
+        .any(|comment| {
+            if comment.contains("@generated") || comment.contains("DO NOT EDIT") {
+                return true;
+            }
+            let text = comment
+                .trim_start_matches(['/', '*'])
+                .trim_end_matches(['/', '*'])
+                .trim();
+            text == "eslint-disable"
+        })
+}
+
+fn is_comment(line: &str) -> bool {
+    line.starts_with("//") || line.starts_with("/*") || line.starts_with('*')
+}
+
+fn has_source_map_url(content: &str) -> bool {
+    content
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .is_some_and(|line| {
+            line.starts_with("//# sourceMappingURL=") || line.starts_with("/*# sourceMappingURL=")
+        })
+}
+
+fn is_minified(content: &str) -> bool {
+    if content.len() < MINIFIED_MIN_SIZE {
+        return false;
+    }
+    let lines = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .count();
+    content.len() / lines.max(1) > MINIFIED_LINE_LENGTH
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_generated;
+
+    #[test]
+    fn detects_generated_markers() {
+        assert!(is_generated("// @generated\nexport const a = 1;\n"));
+        assert!(is_generated(
+            "/**\n * This file was generated, DO NOT EDIT.\n */\nexport const a = 1;\n"
+        ));
+        assert!(is_generated("/* eslint-disable */\nexport const a = 1;\n"));
+
+        assert!(!is_generated(
+            "/* eslint-disable no-console */\nconsole.log(1);\n"
+        ));
+        assert!(!is_generated("const marker = \"@generated\";\n"));
+    }
+
+    #[test]
+    fn detects_bundles() {
+        assert!(is_generated(
+            "console.log(1);\n//# sourceMappingURL=index.js.map\n"
+        ));
+        assert!(is_generated(&format!("{};\n", "a".repeat(2048))));
+
+        assert!(!is_generated(&"statement();\n".repeat(200)));
+        assert!(!is_generated(&format!("{};\n", "a".repeat(512))));
+    }
+}
@@ -0,0 +1,51 @@
+//! The interners of the syntax trees of the documents of the workspace
+
+use crate::file_handlers::Language;
+use biome_rowan::{NodeCache, NodeCacheStats};
+use std::sync::Mutex;
+
+/// The [NodeCache] shared by all the documents of the workspace, so the nodes, tokens and trivia
+/// that are identical in several documents are only allocated once.
+///
+/// The documents can be parsed at the same time by several threads, so the workspace keeps a
+/// pool of shared caches instead of a single one: a document is parsed with any cache of its
+/// language that isn't in use, and a new cache is only created when all of them are. The syntax
+/// kinds of the languages overlap, so their trees never share a cache.
+#[derive(Debug, Default)]
+pub(super) struct SharedNodeCache {
+    caches: Mutex<Vec<(Language, NodeCache)>>,
+}
+
+impl SharedNodeCache {
+    /// Calls `func` with a cache of `language` that isn't used by another thread
+    pub(super) fn with_cache<R>(
+        &self,
+        language: Language,
+        func: impl FnOnce(&mut NodeCache) -> R,
+    ) -> R {
+        let mut cache = {
+            let mut caches = self.caches.lock().unwrap();
+            caches
+                .iter()
+                .position(|(cache_language, _)| *cache_language == language)
+                .map_or_else(NodeCache::shared, |index| caches.swap_remove(index).1)
+        };
+        let result = func(&mut cache);
+        self.caches.lock().unwrap().push((language, cache));
+        result
+    }
+
+    /// Returns the number of elements interned in the caches that aren't in use
+    pub(super) fn stats(&self) -> NodeCacheStats {
+        self.caches
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, cache)| cache.stats())
+            .fold(NodeCacheStats::default(), |total, stats| NodeCacheStats {
+                nodes: total.nodes + stats.nodes,
+                tokens: total.tokens + stats.tokens,
+                trivia: total.trivia + stats.trivia,
+            })
+    }
+}
@@ -0,0 +1,52 @@
+//! The time spent running each rule, across all the files linted by the workspace
+
+use super::RuleTimingEntry;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// The number of rules listed by `rage`
+const RAGE_RULES: usize = 10;
+
+/// Aggregates the time spent running each rule when the diagnostics of the files are pulled
+/// with `rule_timings`, so `rage` can list the slowest rules
+#[derive(Debug, Default)]
+pub(super) struct RuleTimingsReport {
+    /// The time spent running each rule, in nanoseconds, and the number of runs, by group and rule
+    rules: Mutex<BTreeMap<(String, String), (u64, u64)>>,
+}
+
+impl RuleTimingsReport {
+    /// Adds the time spent running the rules on a file
+    pub(super) fn record(&self, entries: &[RuleTimingEntry]) {
+        if entries.is_empty() {
+            return;
+        }
+        let mut rules = self.rules.lock().unwrap();
+        for entry in entries {
+            let (nanos, runs) = rules
+                .entry((entry.group.clone(), entry.rule.clone()))
+                .or_default();
+            *nanos += entry.nanos;
+            *runs += entry.runs;
+        }
+    }
+
+    /// Returns the rules that took the most time to run, the slowest first
+    pub(super) fn slowest(&self) -> Vec<RuleTimingEntry> {
+        let mut entries: Vec<_> = self
+            .rules
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|((group, rule), (nanos, runs))| RuleTimingEntry {
+                group: group.clone(),
+                rule: rule.clone(),
+                nanos: *nanos,
+                runs: *runs,
+            })
+            .collect();
+        entries.sort_by(|left, right| right.nanos.cmp(&left.nanos));
+        entries.truncate(RAGE_RULES);
+        entries
+    }
+}
@@ -1,20 +1,26 @@
 use super::{
-    ChangeFileParams, CloseFileParams, FeatureName, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameResult, SupportsFeatureParams,
-    UpdateSettingsParams,
+    ChangeFileParams, CloseFileParams, CloseProjectParams, DocumentSymbol, DocumentSymbolsParams,
+    DocumentSymbolsResult, FeatureName, FindReferencesParams, FindReferencesResult, FixFileResult,
+    FoldingRangesParams, FoldingRangesResult, FormatFileParams, FormatOnTypeParams,
+    FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams, GetSyntaxTreeParams,
+    GetSyntaxTreeResult, GotoDefinitionParams, GotoDefinitionResult, IndexFileParams,
+    InlayHintsParams, InlayHintsResult, OpenFileParams, PullActionsParams, PullActionsResult,
+    PullDiagnosticsParams, PullDiagnosticsResult, RenameResult, SemanticTokensParams,
+    SemanticTokensResult, SupportsFeatureParams, UpdateSettingsParams, WorkspaceSymbol,
+    WorkspaceSymbolsParams, WorkspaceSymbolsResult,
 };
+use crate::configuration::RuleSelector;
 use crate::file_handlers::{Capabilities, FixAllParams, Language, LintParams};
 use crate::project_handlers::{ProjectCapabilities, ProjectHandlers};
 use crate::settings::OverrideSettings;
 use crate::workspace::{
     FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    OrganizeImportsResult, PrepareRenameParams, PrepareRenameResult, RageEntry, RageParams,
+    RageResult, ServerInfo,
 };
 use crate::{
     file_handlers::Features,
-    settings::{SettingsHandle, WorkspaceSettings},
+    settings::{ProjectsSettings, SettingsHandle, WorkspaceSettings},
     Rules, Workspace, WorkspaceError,
 };
 use biome_analyze::{AnalysisFilter, RuleFilter};
@@ -24,18 +30,28 @@ use biome_diagnostics::{
 use biome_formatter::Printed;
 use biome_fs::RomePath;
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_rowan::{TextRange, TextSize};
 use dashmap::{mapref::entry::Entry, DashMap};
+use std::collections::BTreeMap;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::time::Duration;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{info_span, trace};
 
+use super::fuzzy::fuzzy_score;
+use super::generated::is_generated;
+use super::node_cache::SharedNodeCache;
+use super::rule_timings::RuleTimingsReport;
+
+/// The maximum number of symbols returned by [Workspace::workspace_symbols]
+const MAX_WORKSPACE_SYMBOLS: usize = 256;
+
 pub(super) struct WorkspaceServer {
     /// features available throughout the application
     features: Features,
-    /// global settings object for this workspace
-    settings: RwLock<WorkspaceSettings>,
+    /// settings of the workspace and of its projects
+    settings: RwLock<ProjectsSettings>,
     /// Stores the document (text content + version number) associated with a URL
     documents: DashMap<RomePath, Document>,
     /// Stores the result of the parser (syntax tree + diagnostics) for a given URL
@@ -44,6 +60,12 @@ pub(super) struct WorkspaceServer {
     file_features: DashMap<RomePath, FileFeaturesResult>,
     /// Handlers that know how to handle a specific project
     project_handlers: ProjectHandlers,
+    /// Stores the symbols exported by the files of the project that aren't open
+    symbol_index: DashMap<RomePath, Vec<DocumentSymbol>>,
+    /// Interns the nodes, tokens and trivia of the syntax trees of all the documents
+    node_cache: SharedNodeCache,
+    /// The time spent running each rule on the files whose diagnostics were pulled with timings
+    rule_timings: RuleTimingsReport,
 }
 
 /// The `Workspace` object is long lived, so we want it to be able to cross
@@ -59,7 +81,6 @@ pub(crate) struct Document {
     pub(crate) content: String,
     pub(crate) version: i32,
     pub(crate) language_hint: Language,
-    node_cache: NodeCache,
 }
 
 impl WorkspaceServer {
@@ -76,11 +97,15 @@ impl WorkspaceServer {
             syntax: DashMap::default(),
             file_features: DashMap::default(),
             project_handlers: ProjectHandlers::new(),
+            symbol_index: DashMap::default(),
+            node_cache: SharedNodeCache::default(),
+            rule_timings: RuleTimingsReport::default(),
         }
     }
 
-    fn settings(&self) -> SettingsHandle {
-        SettingsHandle::new(&self.settings)
+    /// Returns the settings of the project that contains `path`
+    fn settings(&self, path: &RomePath) -> SettingsHandle {
+        SettingsHandle::new(&self.settings, path.as_path())
     }
 
     /// Get the supported capabilities for a given file path
@@ -146,6 +171,13 @@ impl WorkspaceServer {
         }
     }
 
+    /// Returns the size, in bytes, above which the file at `rome_path` isn't parsed
+    fn size_limit(&self, rome_path: &RomePath) -> usize {
+        let settings = self.settings(rome_path);
+        let limit = settings.as_ref().max_size(rome_path).get();
+        usize::try_from(limit).unwrap_or(usize::MAX)
+    }
+
     /// Get the parser result for a given file
     ///
     /// Returns and error if no file exists in the workspace with this path or
@@ -177,9 +209,9 @@ impl WorkspaceServer {
                 let rome_path = entry.key();
                 let capabilities = self.get_file_capabilities(rome_path);
 
-                let mut document = self
+                let document = self
                     .documents
-                    .get_mut(rome_path)
+                    .get(rome_path)
                     .ok_or_else(WorkspaceError::not_found)?;
 
                 let parse = capabilities
@@ -187,14 +219,8 @@ impl WorkspaceServer {
                     .parse
                     .ok_or_else(self.build_capability_error(rome_path))?;
 
-                let size_limit = {
-                    let settings = self.settings();
-                    let settings = settings.as_ref();
-                    let limit = settings.files.max_size.get();
-                    usize::try_from(limit).unwrap_or(usize::MAX)
-                };
+                let size_limit = self.size_limit(rome_path);
 
-                let document = &mut *document;
                 let size = document.content.as_bytes().len();
                 if size >= size_limit {
                     return Err(WorkspaceError::file_too_large(
@@ -204,44 +230,33 @@ impl WorkspaceServer {
                     ));
                 }
 
-                let settings = self.settings();
-                let parsed = parse(
-                    rome_path,
-                    document.language_hint,
-                    document.content.as_str(),
-                    settings,
-                    &mut document.node_cache,
-                );
+                let settings = self.settings(rome_path);
+                if settings.as_ref().files.exclude_generated
+                    && is_generated(document.content.as_str())
+                {
+                    return Err(WorkspaceError::file_generated(
+                        rome_path.to_path_buf().display().to_string(),
+                    ));
+                }
+
+                let language = Features::get_language(rome_path).or(document.language_hint);
+                let parsed =
+                    tracing::trace_span!("parse", path = ?rome_path.as_path()).in_scope(|| {
+                        self.node_cache.with_cache(language, |cache| {
+                            parse(
+                                rome_path,
+                                document.language_hint,
+                                document.content.as_str(),
+                                settings,
+                                cache,
+                            )
+                        })
+                    });
 
                 Ok(entry.insert(parsed).clone())
             }
         }
     }
-
-    /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
-    fn is_ignored_by_top_level_config(&self, path: &Path) -> bool {
-        let settings = self.settings();
-
-        let is_ignored_by_file_config = settings
-            .as_ref()
-            .files
-            .ignored_files
-            .as_ref()
-            .map(|matcher| matcher.matches_path(path));
-        let is_included_by_file_config = settings
-            .as_ref()
-            .files
-            .included_files
-            .as_ref()
-            .map(|matcher| matcher.matches_path(path));
-        if let Some(ignored) = is_ignored_by_file_config {
-            ignored
-        } else if let Some(included) = is_included_by_file_config {
-            !included
-        } else {
-            false
-        }
-    }
 }
 
 impl Workspace for WorkspaceServer {
@@ -257,11 +272,13 @@ impl Workspace for WorkspaceServer {
             }
             Entry::Vacant(entry) => {
                 let capabilities = self.get_file_capabilities(&params.path);
-                let language = Language::from_path(&params.path);
-                let settings = self.settings.read().unwrap();
+                let language =
+                    Language::from_path(&params.path).or(self.get_language(&params.path));
+                let settings = self.settings(&params.path);
+                let settings = settings.as_ref();
                 let mut file_features = FileFeaturesResult::new()
                     .with_capabilities(&capabilities)
-                    .with_settings_and_language(&settings, &language, params.path.as_path());
+                    .with_settings_and_language(settings, &language, params.path.as_path());
 
                 if settings.files.ignore_unknown {
                     let language = self.get_language(&params.path);
@@ -286,86 +303,48 @@ impl Workspace for WorkspaceServer {
     }
 
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
-        let settings = self.settings();
+        let settings = self.settings(&params.rome_path);
         let path = params.rome_path.as_path();
 
-        let excluded_by_override = settings.as_ref().override_settings.is_path_excluded(path);
-        let included_by_override = settings.as_ref().override_settings.is_path_included(path);
-
-        // Overrides have top priority
-        if let Some(excluded_by_override) = excluded_by_override {
-            if excluded_by_override {
-                return Ok(true);
-            }
-        }
-
-        if let Some(included_by_override) = included_by_override {
-            if included_by_override {
-                return Ok(!included_by_override);
-            }
-        }
-
-        Ok(match params.feature {
-            FeatureName::Format => {
-                if let Some(matcher) = settings.as_ref().formatter.ignored_files.as_ref() {
-                    let ignored = matcher.matches_path(path);
-                    if ignored {
-                        return Ok(ignored);
-                    }
-                } else if let Some(matcher) = settings.as_ref().formatter.included_files.as_ref() {
-                    let included = matcher.matches_path(path);
-                    if included {
-                        return Ok(!included);
-                    }
-                }
-                self.is_ignored_by_top_level_config(path)
-            }
-            FeatureName::Lint => {
-                if let Some(matcher) = settings.as_ref().linter.ignored_files.as_ref() {
-                    let ignored = matcher.matches_path(path);
-                    if ignored {
-                        return Ok(ignored);
-                    }
-                } else if let Some(matcher) = settings.as_ref().linter.included_files.as_ref() {
-                    let included = matcher.matches_path(path);
-                    if included {
-                        return Ok(!included);
-                    }
-                }
-
-                self.is_ignored_by_top_level_config(path)
-            }
-            FeatureName::OrganizeImports => {
-                if let Some(matcher) = settings.as_ref().organize_imports.ignored_files.as_ref() {
-                    let ignored = matcher.matches_path(path);
-                    if ignored {
-                        return Ok(ignored);
-                    }
-                } else if let Some(matcher) =
-                    settings.as_ref().organize_imports.included_files.as_ref()
-                {
-                    let included = matcher.matches_path(path);
-                    if included {
-                        return Ok(!included);
-                    }
-                }
-
-                self.is_ignored_by_top_level_config(path)
-            }
-        })
+        Ok(settings
+            .as_ref()
+            .path_ignore_status(path, &params.feature)
+            .ignored)
     }
 
-    /// Update the global settings for this workspace
+    /// Update the settings of this workspace, or of one of its projects
     ///
     /// ## Panics
     /// This function may panic if the internal settings mutex has been poisoned
     /// by another thread having previously panicked while holding the lock
     #[tracing::instrument(level = "debug", skip(self))]
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
-        let mut settings = self.settings.write().unwrap();
+        let mut settings = WorkspaceSettings::default();
         settings.merge_with_configuration(params.configuration)?;
+        if let Some(directory) = params.project_directory.as_deref() {
+            settings.set_project_directory(directory);
+        }
+        self.settings
+            .write()
+            .unwrap()
+            .insert(params.project_directory.as_deref(), settings);
         // settings changed, hence everything that is computed from the settings needs to be purged
         self.file_features.clear();
+        self.syntax.clear();
+        Ok(())
+    }
+
+    /// Removes the settings of a project, its files use the settings of the workspace again
+    ///
+    /// ## Panics
+    /// This function may panic if the internal settings mutex has been poisoned
+    /// by another thread having previously panicked while holding the lock
+    fn close_project(&self, params: CloseProjectParams) -> Result<(), WorkspaceError> {
+        self.settings
+            .write()
+            .unwrap()
+            .remove(&params.project_directory);
+        self.file_features.clear();
         Ok(())
     }
 
@@ -378,7 +357,6 @@ impl Workspace for WorkspaceServer {
                 content: params.content,
                 version: params.version,
                 language_hint: params.language_hint,
-                node_cache: NodeCache::default(),
             },
         );
         Ok(())
@@ -423,10 +401,10 @@ impl Workspace for WorkspaceServer {
             .debug
             .debug_formatter_ir
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(&params.path) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -443,6 +421,7 @@ impl Workspace for WorkspaceServer {
 
     /// Change the content of an open file
     fn change_file(&self, params: ChangeFileParams) -> Result<(), WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
         let mut document = self
             .documents
             .get_mut(&params.path)
@@ -450,9 +429,32 @@ impl Workspace for WorkspaceServer {
 
         debug_assert!(params.version > document.version);
         document.version = params.version;
-        document.content = params.content;
-
-        self.syntax.remove(&params.path);
+        let previous_content = std::mem::replace(&mut document.content, params.content);
+
+        // The syntax tree of the previous content is updated when the change allows it,
+        // instead of parsing the whole new content
+        let reparsed = self.syntax.get(&params.path).and_then(|parse| {
+            let reparse = capabilities.parser.reparse?;
+            if document.content.len() >= self.size_limit(&params.path) {
+                return None;
+            }
+            let range = changed_range(&previous_content, &document.content);
+            reparse(
+                &params.path,
+                document.language_hint,
+                &parse,
+                range,
+                &document.content,
+            )
+        });
+        match reparsed {
+            Some(parse) => {
+                self.syntax.insert(params.path, parse);
+            }
+            None => {
+                self.syntax.remove(&params.path);
+            }
+        }
         Ok(())
     }
 
@@ -471,6 +473,18 @@ impl Workspace for WorkspaceServer {
         &self,
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        if capabilities.parser.parse.is_none() && capabilities.formatter.format_text.is_some() {
+            // The files formatted as text, such as Markdown files, don't have a syntax to check
+            return Ok(PullDiagnosticsResult {
+                diagnostics: Vec::new(),
+                errors: 0,
+                skipped_diagnostics: 0,
+                skipped_diagnostics_by_category: BTreeMap::new(),
+                rule_timings: Vec::new(),
+            });
+        }
+
         let feature = if params.categories.is_syntax() {
             FeatureName::Format
         } else {
@@ -478,37 +492,60 @@ impl Workspace for WorkspaceServer {
         };
 
         let parse = self.get_parse(params.path.clone(), Some(feature))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
 
-        let (diagnostics, errors, skipped_diagnostics) = if let Some(lint) =
-            self.get_file_capabilities(&params.path).analyzer.lint
-        {
+        let (
+            diagnostics,
+            errors,
+            skipped_diagnostics,
+            skipped_diagnostics_by_category,
+            rule_timings,
+        ) = if let Some(lint) = self.get_file_capabilities(&params.path).analyzer.lint {
             let rules = settings.linter().rules.as_ref();
             let overrides = &settings.override_settings;
-            let mut rule_filter_list =
-                self.build_rule_filter_list(rules, overrides, params.path.as_path());
-            if settings.organize_imports.enabled && !params.categories.is_syntax() {
-                rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
-            }
+            let rule_filter_list = if params.only.is_empty() {
+                let mut rule_filter_list =
+                    self.build_rule_filter_list(rules, overrides, params.path.as_path());
+                if settings.organize_imports.enabled && !params.categories.is_syntax() {
+                    rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
+                }
+                rule_filter_list
+            } else {
+                params
+                    .only
+                    .iter()
+                    .filter_map(RuleSelector::as_rule_filter)
+                    .collect()
+            };
             let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
             filter.categories = params.categories;
 
-            info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
+            let span = info_span!(
+                "Pulling diagnostics",
+                path =? params.path.as_path(),
+                categories =? params.categories
+            );
+            span.in_scope(|| {
                 trace!("Analyzer filter to apply to lint: {:?}", &filter);
 
                 let results = lint(LintParams {
                     parse,
                     filter,
                     rules,
-                    settings: self.settings(),
+                    settings: self.settings(&params.path),
                     max_diagnostics: params.max_diagnostics,
                     path: &params.path,
+                    rule_timings: params.rule_timings,
                 });
+                self.rule_timings.record(&results.rule_timings);
 
                 (
                     results.diagnostics,
                     results.errors,
                     results.skipped_diagnostics,
+                    results.skipped_diagnostics_by_category,
+                    results.rule_timings,
                 )
             })
         } else {
@@ -518,7 +555,7 @@ impl Workspace for WorkspaceServer {
                 .filter(|diag| diag.severity() <= Severity::Error)
                 .count();
 
-            (parse_diagnostics, errors, 0)
+            (parse_diagnostics, errors, 0, BTreeMap::new(), Vec::new())
         };
 
         Ok(PullDiagnosticsResult {
@@ -531,6 +568,8 @@ impl Workspace for WorkspaceServer {
                 .collect(),
             errors,
             skipped_diagnostics,
+            skipped_diagnostics_by_category,
+            rule_timings,
         })
     }
 
@@ -544,13 +583,14 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
         let rules = settings.linter().rules.as_ref();
         Ok(code_actions(
             parse,
             params.range,
             rules,
-            self.settings(),
+            self.settings(&params.path),
             &params.path,
         ))
     }
@@ -559,14 +599,32 @@ impl Workspace for WorkspaceServer {
     /// and returns the resulting source code
     fn format_file(&self, params: FormatFileParams) -> Result<Printed, WorkspaceError> {
         let capabilities = self.get_file_capabilities(&params.path);
+        if let Some(format_text) = capabilities.formatter.format_text {
+            let ignored = self.is_path_ignored(IsPathIgnoredParams {
+                rome_path: params.path.clone(),
+                feature: FeatureName::Format,
+            })?;
+            if ignored {
+                return Err(WorkspaceError::file_ignored(format!(
+                    "{}",
+                    params.path.to_path_buf().display()
+                )));
+            }
+            let document = self
+                .documents
+                .get(&params.path)
+                .ok_or_else(WorkspaceError::not_found)?;
+            return format_text(&params.path, &document.content, self.settings(&params.path));
+        }
+
         let format = capabilities
             .formatter
             .format
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(&params.path) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -575,14 +633,37 @@ impl Workspace for WorkspaceServer {
 
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError> {
         let capabilities = self.get_file_capabilities(&params.path);
+        if let Some(format_text_range) = capabilities.formatter.format_text_range {
+            let ignored = self.is_path_ignored(IsPathIgnoredParams {
+                rome_path: params.path.clone(),
+                feature: FeatureName::Format,
+            })?;
+            if ignored {
+                return Err(WorkspaceError::file_ignored(format!(
+                    "{}",
+                    params.path.to_path_buf().display()
+                )));
+            }
+            let document = self
+                .documents
+                .get(&params.path)
+                .ok_or_else(WorkspaceError::not_found)?;
+            return format_text_range(
+                &params.path,
+                &document.content,
+                self.settings(&params.path),
+                params.range,
+            );
+        }
+
         let format_range = capabilities
             .formatter
             .format_range
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(&params.path) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -596,9 +677,9 @@ impl Workspace for WorkspaceServer {
             .format_on_type
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(&params.path) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -611,22 +692,231 @@ impl Workspace for WorkspaceServer {
             .analyzer
             .fix_all
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
 
         let rules = settings.as_rules(params.path.as_path());
         let overrides = &settings.override_settings;
-        let rule_filter_list =
-            self.build_rule_filter_list(rules.as_ref(), overrides, params.path.as_path());
+        let rule_filter_list = if params.only.is_empty() {
+            self.build_rule_filter_list(rules.as_ref(), overrides, params.path.as_path())
+        } else {
+            params
+                .only
+                .iter()
+                .filter_map(RuleSelector::as_rule_filter)
+                .collect()
+        };
         let filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
         fix_all(FixAllParams {
             parse,
             rules: rules.as_ref(),
             fix_file_mode: params.fix_file_mode,
             filter,
-            settings: self.settings(),
+            settings: self.settings(&params.path),
             should_format: params.should_format,
             rome_path: &params.path,
+            suppression_reason: params.suppression_reason.as_deref(),
+        })
+    }
+
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let prepare_rename = capabilities
+            .analyzer
+            .prepare_rename
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        prepare_rename(parse, params.symbol_at)
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let find_references = capabilities
+            .analyzer
+            .find_references
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        find_references(parse, params.symbol_at)
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let goto_definition = capabilities
+            .analyzer
+            .goto_definition
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        goto_definition(parse, params.symbol_at)
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let document_symbols = capabilities
+            .analyzer
+            .document_symbols
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        Ok(document_symbols(parse))
+    }
+
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let semantic_tokens = capabilities
+            .analyzer
+            .semantic_tokens
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        Ok(semantic_tokens(parse, params.range))
+    }
+
+    fn folding_ranges(
+        &self,
+        params: FoldingRangesParams,
+    ) -> Result<FoldingRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let folding_ranges = capabilities
+            .analyzer
+            .folding_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        Ok(folding_ranges(parse))
+    }
+
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let inlay_hints = capabilities
+            .analyzer
+            .inlay_hints
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        Ok(inlay_hints(parse, &params))
+    }
+
+    fn index_file(&self, params: IndexFileParams) -> Result<(), WorkspaceError> {
+        let Some(content) = params.content else {
+            self.symbol_index.remove(&params.path);
+            return Ok(());
+        };
+
+        let capabilities = self.get_file_capabilities(&params.path);
+        let (Some(parse), Some(exported_symbols)) = (
+            capabilities.parser.parse,
+            capabilities.analyzer.exported_symbols,
+        ) else {
+            return Ok(());
+        };
+
+        let settings = self.settings(&params.path);
+        let is_indexed = {
+            let settings = settings.as_ref();
+            let limit =
+                usize::try_from(settings.max_size(&params.path).get()).unwrap_or(usize::MAX);
+            let ignored = settings
+                .files
+                .ignored_files
+                .as_ref()
+                .is_some_and(|matcher| matcher.matches_path(params.path.as_path()))
+                || settings
+                    .files
+                    .included_files
+                    .as_ref()
+                    .is_some_and(|matcher| !matcher.matches_path(params.path.as_path()));
+            content.len() < limit && !ignored
+        };
+        if !is_indexed {
+            self.symbol_index.remove(&params.path);
+            return Ok(());
+        }
+
+        let language = Features::get_language(&params.path);
+        let parsed = self.node_cache.with_cache(language, |cache| {
+            parse(&params.path, Language::default(), &content, settings, cache)
+        });
+        self.symbol_index
+            .insert(params.path, exported_symbols(parsed));
+        Ok(())
+    }
+
+    fn workspace_symbols(
+        &self,
+        params: WorkspaceSymbolsParams,
+    ) -> Result<WorkspaceSymbolsResult, WorkspaceError> {
+        // The content of the open documents is more recent than the content of the index
+        let open_paths: Vec<RomePath> = self
+            .documents
+            .iter()
+            .map(|document| document.key().clone())
+            .collect();
+        let mut open_symbols = Vec::new();
+        for path in open_paths {
+            let capabilities = self.get_file_capabilities(&path);
+            let Some(exported_symbols) = capabilities.analyzer.exported_symbols else {
+                continue;
+            };
+            if let Ok(parse) = self.get_parse(path.clone(), None) {
+                open_symbols.push((path, exported_symbols(parse)));
+            }
+        }
+
+        let mut matches = Vec::new();
+        let mut push_matches = |path: &RomePath, symbols: &[DocumentSymbol]| {
+            for symbol in symbols {
+                if let Some(score) = fuzzy_score(&params.query, &symbol.name) {
+                    matches.push((
+                        score,
+                        WorkspaceSymbol {
+                            path: path.clone(),
+                            name: symbol.name.clone(),
+                            kind: symbol.kind,
+                            range: symbol.selection_range,
+                        },
+                    ));
+                }
+            }
+        };
+        for (path, symbols) in &open_symbols {
+            push_matches(path, symbols);
+        }
+        for entry in self.symbol_index.iter() {
+            if !self.documents.contains_key(entry.key()) {
+                push_matches(entry.key(), entry.value());
+            }
+        }
+
+        matches.sort_by(|(left_score, left), (right_score, right)| {
+            right_score
+                .cmp(left_score)
+                .then_with(|| left.name.cmp(&right.name))
+                .then_with(|| left.path.as_path().cmp(right.path.as_path()))
+                .then_with(|| left.range.start().cmp(&right.range.start()))
+        });
+        matches.truncate(MAX_WORKSPACE_SYMBOLS);
+
+        Ok(WorkspaceSymbolsResult {
+            symbols: matches.into_iter().map(|(_, symbol)| symbol).collect(),
         })
     }
 
@@ -644,11 +934,30 @@ impl Workspace for WorkspaceServer {
     }
 
     fn rage(&self, _: RageParams) -> Result<RageResult, WorkspaceError> {
-        let entries = vec![
+        let node_cache = self.node_cache.stats();
+        let mut entries = vec![
             RageEntry::section("Workspace"),
             RageEntry::pair("Open Documents", &format!("{}", self.documents.len())),
+            RageEntry::pair("Interned Nodes", &format!("{}", node_cache.nodes)),
+            RageEntry::pair("Interned Tokens", &format!("{}", node_cache.tokens)),
+            RageEntry::pair("Interned Trivia", &format!("{}", node_cache.trivia)),
         ];
 
+        let slowest_rules = self.rule_timings.slowest();
+        if !slowest_rules.is_empty() {
+            entries.push(RageEntry::section("Rule Timings"));
+            for entry in slowest_rules {
+                entries.push(RageEntry::pair(
+                    &format!("{}/{}", entry.group, entry.rule),
+                    &format!(
+                        "{:?} ({} runs)",
+                        Duration::from_nanos(entry.nanos),
+                        entry.runs
+                    ),
+                ));
+            }
+        }
+
         Ok(RageResult { entries })
     }
 
@@ -672,3 +981,22 @@ impl Workspace for WorkspaceServer {
         Ok(result)
     }
 }
+
+/// Returns the range of `previous_content` that was replaced to get `content`
+fn changed_range(previous_content: &str, content: &str) -> TextRange {
+    let prefix_len = previous_content
+        .bytes()
+        .zip(content.bytes())
+        .take_while(|(previous, current)| previous == current)
+        .count();
+    let suffix_len = previous_content.as_bytes()[prefix_len..]
+        .iter()
+        .rev()
+        .zip(content.as_bytes()[prefix_len..].iter().rev())
+        .take_while(|(previous, current)| previous == current)
+        .count();
+    TextRange::new(
+        TextSize::from(prefix_len as u32),
+        TextSize::from((previous_content.len() - suffix_len) as u32),
+    )
+}
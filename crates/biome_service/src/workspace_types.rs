@@ -453,10 +453,11 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 18] {
+pub fn methods() -> [WorkspaceMethod; 28] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("file_features"),
         workspace_method!(update_settings),
+        workspace_method!(close_project),
         workspace_method!(project_features),
         workspace_method!(open_file),
         workspace_method!(change_file),
@@ -472,6 +473,15 @@ pub fn methods() -> [WorkspaceMethod; 18] {
         workspace_method!(format_range),
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
+        workspace_method!(prepare_rename),
+        workspace_method!(find_references),
+        workspace_method!(goto_definition),
+        workspace_method!(document_symbols),
+        workspace_method!(semantic_tokens),
+        workspace_method!(folding_ranges),
+        workspace_method!(inlay_hints),
+        workspace_method!(index_file),
+        workspace_method!(workspace_symbols),
         workspace_method!(rename),
     ]
 }
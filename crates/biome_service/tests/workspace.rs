@@ -1,6 +1,9 @@
 use biome_fs::RomePath;
-use biome_js_syntax::TextSize;
-use biome_service::workspace::{server, FileGuard, Language, OpenFileParams};
+use biome_js_syntax::{TextRange, TextSize};
+use biome_service::workspace::{
+    server, DocumentSymbol, FileGuard, IndexFileParams, InlayHintsParams, Language, OpenFileParams,
+    WorkspaceSymbolsParams,
+};
 
 #[test]
 fn debug_control_flow() {
@@ -27,3 +30,424 @@ fn debug_control_flow() {
 
     assert_eq!(cfg, GRAPH);
 }
+
+#[test]
+fn document_symbols() {
+    const SOURCE: &str = r#"import { useState } from "react";
+
+export const MAX = 10;
+let counter = 0;
+
+export function Counter() {
+    function increment() {}
+    const local = 1;
+    return <button onClick={increment}>{counter}</button>;
+}
+
+export default class Store {
+    #items = [];
+    constructor() {}
+    add(item) {}
+    get size() { return this.#items.length; }
+}
+
+export const format = (value) => `${value}`;
+"#;
+
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.jsx"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScriptReact,
+        },
+    )
+    .unwrap();
+
+    fn outline(symbols: &[DocumentSymbol], depth: usize, lines: &mut Vec<String>) {
+        for symbol in symbols {
+            let indent = "  ".repeat(depth);
+            lines.push(format!("{indent}{:?} {}", symbol.kind, symbol.name));
+            outline(&symbol.children, depth + 1, lines);
+        }
+    }
+
+    let result = file.document_symbols().unwrap();
+    let mut lines = vec![];
+    outline(&result.symbols, 0, &mut lines);
+
+    assert_eq!(
+        lines,
+        [
+            "Constant MAX",
+            "Variable counter",
+            "Component Counter",
+            "  Function increment",
+            "Class Store",
+            "  Property #items",
+            "  Constructor constructor",
+            "  Method add",
+            "  Property size",
+            "Function format",
+        ]
+    );
+
+    let counter = &result.symbols[2];
+    assert_eq!(&SOURCE[counter.selection_range], "Counter");
+    assert!(SOURCE[counter.range].starts_with("function Counter()"));
+}
+
+#[test]
+fn workspace_symbols() {
+    let workspace = server();
+
+    workspace
+        .index_file(IndexFileParams {
+            path: RomePath::new("src/state.ts"),
+            content: Some(
+                "export function useStore() {}\nfunction internal() {}\nconst userStore = 1;\nexport { userStore };"
+                    .into(),
+            ),
+        })
+        .unwrap();
+    workspace
+        .index_file(IndexFileParams {
+            path: RomePath::new("src/deleted.ts"),
+            content: Some("export const useDeleted = 1;".into()),
+        })
+        .unwrap();
+    workspace
+        .index_file(IndexFileParams {
+            path: RomePath::new("src/deleted.ts"),
+            content: None,
+        })
+        .unwrap();
+
+    // The content of open documents replaces the content of the index
+    workspace
+        .index_file(IndexFileParams {
+            path: RomePath::new("src/open.js"),
+            content: Some("export const stale = 1;".into()),
+        })
+        .unwrap();
+    let _file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("src/open.js"),
+            content: "export class UserService {}".into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+
+    let search = |query: &str| {
+        workspace
+            .workspace_symbols(WorkspaceSymbolsParams {
+                query: query.into(),
+            })
+            .unwrap()
+            .symbols
+            .into_iter()
+            .map(|symbol| format!("{} {}", symbol.path.as_path().display(), symbol.name))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        search("us"),
+        [
+            "src/state.ts useStore",
+            "src/state.ts userStore",
+            "src/open.js UserService",
+        ]
+    );
+    assert_eq!(
+        search("ustore"),
+        ["src/state.ts useStore", "src/state.ts userStore"]
+    );
+    assert!(search("internal").is_empty());
+    assert!(search("stale").is_empty());
+    assert!(search("deleted").is_empty());
+}
+
+#[test]
+fn semantic_tokens() {
+    const SOURCE: &str = r#"import { format } from "./format";
+/* a
+   b */
+const LIMIT = 2;
+function Item({ label }) {
+    let count = label.length;
+    return <li title="item">{format(count, LIMIT)}</li>;
+}
+const view = <Item label={`x`} />;
+"#;
+
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.jsx"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScriptReact,
+        },
+    )
+    .unwrap();
+
+    let classify = |range: Option<TextRange>| {
+        file.semantic_tokens(range)
+            .unwrap()
+            .tokens
+            .into_iter()
+            .map(|token| {
+                let text = &SOURCE[token.range];
+                let mut line = format!("{text}: {:?}", token.kind);
+                for modifier in token.modifiers {
+                    line.push_str(&format!(" {modifier:?}"));
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let tokens = classify(None);
+    assert_eq!(
+        tokens,
+        [
+            "import: Keyword",
+            "format: Variable Declaration Readonly Imported",
+            "from: Keyword",
+            "\"./format\": String",
+            "/* a: Comment",
+            "   b */: Comment",
+            "const: Keyword",
+            "LIMIT: Variable Declaration Readonly",
+            "2: Number",
+            "function: Keyword",
+            "Item: Function Declaration",
+            "label: Parameter Declaration",
+            "let: Keyword",
+            "count: Variable Declaration",
+            "label: Parameter",
+            "length: Property",
+            "return: Keyword",
+            "li: JsxTag",
+            "title: Property",
+            "\"item\": String",
+            "format: Variable Readonly Imported",
+            "count: Variable",
+            "LIMIT: Variable Readonly",
+            "li: JsxTag",
+            "const: Keyword",
+            "view: Variable Declaration Readonly",
+            "Item: Component",
+            "label: Property",
+            "x: String",
+        ]
+    );
+
+    let start = TextSize::try_from(SOURCE.find("let").unwrap()).unwrap();
+    let range = TextRange::at(start, TextSize::from(9));
+    assert_eq!(
+        classify(Some(range)),
+        ["let: Keyword", "count: Variable Declaration"]
+    );
+}
+
+#[test]
+fn folding_ranges() {
+    const SOURCE: &str = r#"import a from "a";
+import b from "b";
+
+/**
+ * Docs
+ */
+// #region helpers
+function sum(values) {
+    return values.reduce((total, value) => {
+        return total + value;
+    }, 0);
+}
+// #endregion
+
+const view = (
+    <ul>
+        <li>{sum([1, 2])}</li>
+    </ul>
+);
+"#;
+
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.jsx"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScriptReact,
+        },
+    )
+    .unwrap();
+
+    let line = |offset: TextSize| SOURCE[..usize::from(offset)].matches('\n').count();
+    let ranges = file
+        .folding_ranges()
+        .unwrap()
+        .ranges
+        .into_iter()
+        .map(|folding_range| {
+            (
+                format!("{:?}", folding_range.kind),
+                line(folding_range.range.start()),
+                line(folding_range.range.end()),
+            )
+        })
+        // Ranges on a single line can't be folded
+        .filter(|(_, start, end)| start < end)
+        .collect::<Vec<_>>();
+
+    let expected = [
+        ("Imports", 0, 1),
+        ("Comment", 3, 5),
+        ("Region", 6, 12),
+        ("Code", 7, 10),
+        ("Code", 8, 10),
+        ("Code", 8, 9),
+        ("Code", 14, 17),
+        ("Code", 15, 16),
+    ];
+    assert_eq!(
+        ranges,
+        expected.map(|(kind, start, end)| (kind.to_string(), start, end))
+    );
+}
+
+#[test]
+fn inlay_hints() {
+    const SOURCE: &str = r#"function clamp(value, min, max) {
+    if (value < min) {
+        return min;
+    }
+    return value > max;
+}
+const isPositive = (value) => value > 0;
+async function load(path) {
+    return `./${path}`;
+}
+function log(message) {}
+
+const min = 0;
+clamp(5, min, 10);
+isPositive(-1);
+load("file.js");
+log(...messages);
+"#;
+
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+
+    let hints = workspace
+        .inlay_hints(InlayHintsParams {
+            path: RomePath::new("file.js"),
+            range: TextRange::up_to(TextSize::of(SOURCE)),
+            parameter_names: true,
+            return_types: true,
+        })
+        .unwrap()
+        .hints
+        .into_iter()
+        .map(|hint| {
+            let offset = usize::from(hint.position);
+            let line = SOURCE[..offset].matches('\n').count();
+            (line, hint.label)
+        })
+        .collect::<Vec<_>>();
+
+    let expected = [
+        (6, ": boolean"),
+        (7, ": Promise<string>"),
+        (10, ": void"),
+        (13, "value:"),
+        (13, "max:"),
+        (14, "value:"),
+        (15, "path:"),
+    ];
+    assert_eq!(
+        hints,
+        expected.map(|(line, label)| (line, label.to_string()))
+    );
+
+    let hints = workspace
+        .inlay_hints(InlayHintsParams {
+            path: RomePath::new("file.js"),
+            range: TextRange::up_to(TextSize::of(SOURCE)),
+            parameter_names: false,
+            return_types: true,
+        })
+        .unwrap()
+        .hints;
+    assert_eq!(hints.len(), 3);
+
+    drop(file);
+}
+
+#[test]
+fn change_file_updates_the_syntax_tree() {
+    const SOURCE: &str = "function sum(values) {\n    return values.length + 1;\n}\n";
+
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.js"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::JavaScript,
+        },
+    )
+    .unwrap();
+    file.get_syntax_tree().unwrap();
+
+    // The first change is inside a token, the second one changes the structure of the tree
+    let changes = [
+        SOURCE.replace("values.length", "values.size"),
+        SOURCE.replace("+ 1", "+ 1 - 2"),
+    ];
+    for (index, content) in changes.into_iter().enumerate() {
+        file.change_file(index as i32 + 1, content.clone()).unwrap();
+
+        let expected_workspace = server();
+        let expected_file = FileGuard::open(
+            expected_workspace.as_ref(),
+            OpenFileParams {
+                path: RomePath::new("file.js"),
+                content,
+                version: 0,
+                language_hint: Language::JavaScript,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            file.get_syntax_tree().unwrap().cst,
+            expected_file.get_syntax_tree().unwrap().cst
+        );
+    }
+}
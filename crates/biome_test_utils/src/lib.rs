@@ -5,7 +5,7 @@ use biome_diagnostics::termcolor::Buffer;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic};
 use biome_json_parser::{JsonParserOptions, ParseDiagnostic};
 use biome_rowan::{SyntaxKind, SyntaxNode, SyntaxSlot};
-use biome_service::configuration::to_analyzer_rules;
+use biome_service::configuration::{to_analyzer_globals, to_analyzer_rules};
 use biome_service::settings::{Language, WorkspaceSettings};
 use biome_service::Configuration;
 use json_comments::StripComments;
@@ -60,7 +60,10 @@ pub fn create_analyzer_options(
             settings.merge_with_configuration(configuration).unwrap();
             let configuration = AnalyzerConfiguration {
                 rules: to_analyzer_rules(&settings, input_file),
-                globals: vec![],
+                globals: to_analyzer_globals(&settings, input_file),
+                timeout: None,
+                require_suppression_reasons: settings.linter.require_suppression_reasons,
+                suppression_reason: None,
             };
             options = AnalyzerOptions {
                 configuration,
@@ -2,10 +2,12 @@ use js_sys::Error;
 use wasm_bindgen::prelude::*;
 
 use biome_service::workspace::{
-    self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
+    self, ChangeFileParams, CloseFileParams, CloseProjectParams, DocumentSymbolsParams,
+    FindReferencesParams, FixFileParams, FoldingRangesParams, FormatFileParams, FormatOnTypeParams,
     FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RenameParams, UpdateSettingsParams,
+    GetSyntaxTreeParams, GotoDefinitionParams, IndexFileParams, InlayHintsParams,
+    OrganizeImportsParams, PrepareRenameParams, PullActionsParams, PullDiagnosticsParams,
+    RenameParams, SemanticTokensParams, UpdateSettingsParams, WorkspaceSymbolsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -56,6 +58,13 @@ impl Workspace {
         self.inner.update_settings(params).map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = closeProject)]
+    pub fn close_project(&self, params: ICloseProjectParams) -> Result<(), Error> {
+        let params: CloseProjectParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        self.inner.close_project(params).map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = openFile)]
     pub fn open_file(&self, params: IOpenFileParams) -> Result<(), Error> {
         let params: OpenFileParams =
@@ -186,6 +195,114 @@ impl Workspace {
             .map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = prepareRename)]
+    pub fn prepare_rename(
+        &self,
+        params: IPrepareRenameParams,
+    ) -> Result<IPrepareRenameResult, Error> {
+        let params: PrepareRenameParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.prepare_rename(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IPrepareRenameResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = findReferences)]
+    pub fn find_references(
+        &self,
+        params: IFindReferencesParams,
+    ) -> Result<IFindReferencesResult, Error> {
+        let params: FindReferencesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.find_references(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IFindReferencesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = gotoDefinition)]
+    pub fn goto_definition(
+        &self,
+        params: IGotoDefinitionParams,
+    ) -> Result<IGotoDefinitionResult, Error> {
+        let params: GotoDefinitionParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.goto_definition(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGotoDefinitionResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = documentSymbols)]
+    pub fn document_symbols(
+        &self,
+        params: IDocumentSymbolsParams,
+    ) -> Result<IDocumentSymbolsResult, Error> {
+        let params: DocumentSymbolsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.document_symbols(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IDocumentSymbolsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = semanticTokens)]
+    pub fn semantic_tokens(
+        &self,
+        params: ISemanticTokensParams,
+    ) -> Result<ISemanticTokensResult, Error> {
+        let params: SemanticTokensParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.semantic_tokens(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ISemanticTokensResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = foldingRanges)]
+    pub fn folding_ranges(
+        &self,
+        params: IFoldingRangesParams,
+    ) -> Result<IFoldingRangesResult, Error> {
+        let params: FoldingRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.folding_ranges(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IFoldingRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = inlayHints)]
+    pub fn inlay_hints(&self, params: IInlayHintsParams) -> Result<IInlayHintsResult, Error> {
+        let params: InlayHintsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.inlay_hints(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IInlayHintsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = indexFile)]
+    pub fn index_file(&self, params: IIndexFileParams) -> Result<(), Error> {
+        let params: IndexFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        self.inner.index_file(params).map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = workspaceSymbols)]
+    pub fn workspace_symbols(
+        &self,
+        params: IWorkspaceSymbolsParams,
+    ) -> Result<IWorkspaceSymbolsResult, Error> {
+        let params: WorkspaceSymbolsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.workspace_symbols(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IWorkspaceSymbolsResult::from)
+            .map_err(into_error)
+    }
+
     pub fn rename(&self, params: IRenameParams) -> Result<IRenameResult, Error> {
         let params: RenameParams =
             serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
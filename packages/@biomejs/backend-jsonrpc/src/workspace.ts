@@ -18,6 +18,10 @@ export type SupportKind =
 	| "FileNotSupported";
 export interface UpdateSettingsParams {
 	configuration: Configuration;
+	/**
+	 * The directory of the project the configuration applies to, the configuration applies to the whole workspace if it's `None`
+	 */
+	project_directory?: string;
 }
 /**
  * The configuration that is contained inside the file `biome.json`
@@ -27,6 +31,10 @@ export interface Configuration {
 	 * A field for the [JSON schema](https://json-schema.org/) specification
 	 */
 	$schema?: string;
+	/**
+	 * A list of domains, e.g. `react` or `vitest`. Each domain enables the rules and sets the options that fit a framework or a kind of project. The rules and the options set by the configuration take precedence over the ones of the domains.
+	 */
+	domains?: Domains;
 	/**
 	 * A list of paths to other JSON files, used to extends the current configuration.
 	 */
@@ -51,6 +59,10 @@ export interface Configuration {
 	 * The configuration for the linter
 	 */
 	linter?: LinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * The configuration of the import sorting
 	 */
@@ -59,16 +71,28 @@ export interface Configuration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	 * Whether this is the root configuration. A nested configuration file marked as root doesn't inherit the options of the configuration files of its parent directories
+	 */
+	root?: boolean;
 	/**
 	 * The configuration of the VCS integration
 	 */
 	vcs?: VcsConfiguration;
 }
+/**
+ * A list of domains
+ */
+export type Domains = Domain[];
 export type StringSet = string[];
 /**
  * The configuration of the filesystem
  */
 export interface FilesConfiguration {
+	/**
+	 * Tells Biome to skip the files that look generated or minified: the files with a `@generated` marker, the bundles ending with a source map URL, and the files with very long lines
+	 */
+	excludeGenerated?: boolean;
 	/**
 	 * A list of Unix shell style patterns. Biome will ignore files/folders that will match these patterns.
 	 */
@@ -123,21 +147,35 @@ export interface FormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * Whether to match the output of Prettier where Biome intentionally diverges from it, to make the migration from Prettier easier. Defaults to false.
+	 */
+	prettierCompatibility?: boolean;
+	/**
+	 * Whether to read the options of the formatter from the `.editorconfig` file of the project. The options of the configuration take precedence. Defaults to false.
+	 */
+	useEditorconfig?: boolean;
 }
 /**
  * A set of options applied to the JavaScript files
  */
 export interface JavascriptConfiguration {
+	/**
+	* The environments that provide global bindings, e.g. `browser`, `node` or `es2024`.
+
+When it's not set, the global bindings of the browser and Node.js are available. 
+	 */
+	environments?: JavascriptEnvironments;
 	/**
 	 * Formatting options
 	 */
 	formatter?: JavascriptFormatter;
 	/**
-	* A list of global bindings that should be ignored by the analyzers
+	* The global bindings that should be ignored by the analyzers
 
-If defined here, they should not emit diagnostics. 
+If defined here, they should not emit diagnostics. It's either a list of writable global bindings, or an object that maps each global binding to `"readonly"`, `"writable"` or `"off"`. 
 	 */
-	globals?: StringSet;
+	globals?: JavascriptGlobals;
 	organize_imports?: JavascriptOrganizeImports;
 	/**
 	 * Parsing options
@@ -170,10 +208,27 @@ export interface LinterConfiguration {
 	 * A list of Unix shell style patterns. The formatter will include files/folders that will match these patterns.
 	 */
 	include?: StringSet;
+	/**
+	 * When `true`, the suppression comments must explain why the lints are disabled, e.g. `// biome-ignore lint/suspicious/noDebugger: needed to debug the tests`. The suppression comments without an explanation are reported. `false` by default.
+	 */
+	requireSuppressionReasons?: boolean;
 	/**
 	 * List of rules
 	 */
 	rules?: Rules;
+	/**
+	 * The maximum amount of time, in milliseconds, the linter can spend analyzing a single file. When it's exceeded, the analysis of the file stops and a diagnostic reports the rule that was running. By default, there's no limit.
+	 */
+	timeout?: number;
+}
+/**
+ * Options applied to Markdown files
+ */
+export interface MarkdownConfiguration {
+	/**
+	 * Formatting options
+	 */
+	formatter?: MarkdownFormatter;
 }
 export interface OrganizeImports {
 	/**
@@ -195,9 +250,13 @@ export type Overrides = OverridePattern[];
  */
 export interface VcsConfiguration {
 	/**
-	 * The kind of client.
+	 * The kind of client. When it isn't set, Biome detects it from the folders of the repository, e.g. `.git`.
 	 */
 	clientKind?: VcsClientKind;
+	/**
+	 * The main branch of the project. The `--changed` argument of the CLI processes the files changed compared to this branch.
+	 */
+	defaultBranch?: string;
 	/**
 	 * Whether Biome should integrate itself with the VCS client
 	 */
@@ -213,14 +272,22 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: boolean;
 }
+/**
+ * A set of rules and options that fit a framework or a kind of project
+ */
+export type Domain = "react" | "next" | "solid" | "vitest" | "node-library";
 export type PlainIndentStyle = "tab" | "space";
-export type LineEnding = "lf" | "crlf" | "cr";
+export type LineEnding = "lf" | "crlf" | "cr" | "auto";
 /**
 	* Validated value for the `line_width` formatter options
 
 The allowed range of values is 1..=320 
 	 */
 export type LineWidth = number;
+/**
+ * A list of environments that provide global bindings
+ */
+export type JavascriptEnvironments = JavascriptEnvironment[];
 /**
  * Formatting options specific to the JavaScript files
  */
@@ -237,6 +304,14 @@ export interface JavascriptFormatter {
 	 * Whether to insert spaces around brackets in object literals. Defaults to true.
 	 */
 	bracketSpacing?: boolean;
+	/**
+	 * Whether the decorators of class members are printed on their own line or on the same line as the member. Defaults to "preserve".
+	 */
+	decoratorPlacement?: DecoratorPlacement;
+	/**
+	 * The tags of the template literals whose content is reindented to follow the indentation of the code, such as `dedent` or `outdent`. Defaults to none.
+	 */
+	dedentTags?: StringSet;
 	/**
 	 * Control the formatter for JavaScript (and its super languages) files.
 	 */
@@ -277,11 +352,19 @@ export interface JavascriptFormatter {
 	 * Whether the formatter prints semicolons for all statements or only in for statements where it is necessary because of ASI.
 	 */
 	semicolons?: Semicolons;
+	/**
+	 * The tags of the template literals whose content is formatted as SQL, such as `sql`. Defaults to none.
+	 */
+	sqlTags?: StringSet;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
 	 */
 	trailingComma?: TrailingComma;
 }
+/**
+ * The global bindings declared in the configuration
+ */
+export type JavascriptGlobals = StringSet | {};
 export interface JavascriptOrganizeImports {}
 /**
  * Options that changes how the JavaScript parser behaves
@@ -295,6 +378,10 @@ These decorators belong to an old proposal, and they are subject to change.
 	unsafeParameterDecoratorsEnabled?: boolean;
 }
 export interface JsonFormatter {
+	/**
+	 * Which arrays are printed in fill mode, with as many elements as possible on each line. Defaults to "numbers".
+	 */
+	arrayFill?: ArrayFill;
 	/**
 	 * Control the formatter for JSON (and its super languages) files.
 	 */
@@ -351,7 +438,19 @@ export interface Rules {
 	style?: Style;
 	suspicious?: Suspicious;
 }
+export interface MarkdownFormatter {
+	/**
+	* Control the formatting of the fenced code blocks of Markdown files. Defaults to false.
+
+Only the code blocks written in a language supported by the formatter are formatted, the rest of the document is left untouched. 
+	 */
+	enabled?: boolean;
+}
 export interface OverridePattern {
+	/**
+	 * Specific configuration for the filesystem
+	 */
+	files?: OverrideFilesConfiguration;
 	/**
 	 * Specific configuration for the Json language
 	 */
@@ -376,13 +475,33 @@ export interface OverridePattern {
 	 * Specific configuration for the Json language
 	 */
 	linter?: OverrideLinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * Specific configuration for the Json language
 	 */
 	organizeImports?: OverrideOrganizeImportsConfiguration;
 }
-export type VcsClientKind = "git";
+export type VcsClientKind = "git" | "mercurial" | "jujutsu";
+/**
+ * An environment that provides global bindings
+ */
+export type JavascriptEnvironment =
+	| "browser"
+	| "node"
+	| "commonjs"
+	| "worker"
+	| "serviceWorker"
+	| "es5"
+	| "es2015"
+	| "es2017"
+	| "es2020"
+	| "es2021"
+	| "es2024";
 export type ArrowParentheses = "always" | "asNeeded";
+export type DecoratorPlacement = "preserve" | "sameLine" | "ownLine";
 export type QuoteStyle = "double" | "single";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type Semicolons = "always" | "asNeeded";
@@ -390,6 +509,10 @@ export type Semicolons = "always" | "asNeeded";
  * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
  */
 export type TrailingComma = "all" | "es5" | "none";
+/**
+ * Controls which arrays are printed in *fill* mode, where as many elements as possible are printed on each line instead of one element per line.
+ */
+export type ArrayFill = "numbers" | "literals" | "never";
 /**
  * A list of rules that belong to this group
  */
@@ -398,6 +521,10 @@ export interface A11y {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * It enables ALL rules for this group with the given severity, or disables them with `"off"`. The rules configured individually keep their own severity.
+	 */
+	level?: RulePlainConfiguration;
 	/**
 	 * Enforce that the accessKey attribute is not used on any HTML element.
 	 */
@@ -523,6 +650,10 @@ export interface Complexity {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * It enables ALL rules for this group with the given severity, or disables them with `"off"`. The rules configured individually keep their own severity.
+	 */
+	level?: RulePlainConfiguration;
 	/**
 	 * Disallow primitive type aliases and misleading types.
 	 */
@@ -632,6 +763,10 @@ export interface Correctness {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * It enables ALL rules for this group with the given severity, or disables them with `"off"`. The rules configured individually keep their own severity.
+	 */
+	level?: RulePlainConfiguration;
 	/**
 	 * Prevent passing of children as props.
 	 */
@@ -777,6 +912,10 @@ export interface Nursery {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * It enables ALL rules for this group with the given severity, or disables them with `"off"`. The rules configured individually keep their own severity.
+	 */
+	level?: RulePlainConfiguration;
 	/**
 	 * Enforce that aria-hidden="true" is not set on focusable elements.
 	 */
@@ -793,6 +932,10 @@ export interface Nursery {
 	 * Disallow empty block statements and static blocks.
 	 */
 	noEmptyBlockStatements?: RuleConfiguration;
+	/**
+	 * Disallow assignments to native objects and read-only global variables.
+	 */
+	noGlobalAssign?: RuleConfiguration;
 	/**
 	 * Disallow use of implicit any type on variable declarations.
 	 */
@@ -858,6 +1001,10 @@ export interface Performance {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * It enables ALL rules for this group with the given severity, or disables them with `"off"`. The rules configured individually keep their own severity.
+	 */
+	level?: RulePlainConfiguration;
 	/**
 	 * Disallow the use of spread (...) syntax on accumulators.
 	 */
@@ -879,6 +1026,10 @@ export interface Security {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * It enables ALL rules for this group with the given severity, or disables them with `"off"`. The rules configured individually keep their own severity.
+	 */
+	level?: RulePlainConfiguration;
 	/**
 	 * Prevent the usage of dangerous JSX props
 	 */
@@ -900,6 +1051,10 @@ export interface Style {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * It enables ALL rules for this group with the given severity, or disables them with `"off"`. The rules configured individually keep their own severity.
+	 */
+	level?: RulePlainConfiguration;
 	/**
 	 * Disallow the use of arguments
 	 */
@@ -1041,6 +1196,10 @@ export interface Suspicious {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * It enables ALL rules for this group with the given severity, or disables them with `"off"`. The rules configured individually keep their own severity.
+	 */
+	level?: RulePlainConfiguration;
 	/**
 	 * Usually, the definition in the standard library is more precise than what people come up with or the used constant exceeds the maximum precision of the number type.
 	 */
@@ -1222,6 +1381,12 @@ export interface Suspicious {
 	 */
 	useValidTypeof?: RuleConfiguration;
 }
+export interface OverrideFilesConfiguration {
+	/**
+	 * The maximum allowed size for source code files in bytes. Files above this limit will be skipped for performance reasons.
+	 */
+	maxSize?: number;
+}
 export interface OverrideFormatterConfiguration {
 	enabled?: boolean;
 	/**
@@ -1248,6 +1413,10 @@ export interface OverrideFormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * Whether to match the output of Prettier where Biome intentionally diverges from it, to make the migration from Prettier easier. Defaults to false.
+	 */
+	prettierCompatibility?: boolean;
 }
 export interface OverrideLinterConfiguration {
 	/**
@@ -1266,11 +1435,19 @@ export interface OverrideOrganizeImportsConfiguration {
 	enabled?: boolean;
 }
 export type RuleConfiguration = RulePlainConfiguration | RuleWithOptions;
-export type RulePlainConfiguration = "warn" | "error" | "off";
+export type RulePlainConfiguration = "warn" | "error" | "off" | "info" | "hint";
 export interface RuleWithOptions {
+	/**
+	 * Overrides the kind of the fix of the rule: a safe fix is applied by `--apply`, while an unsafe fix is only applied by `--apply-unsafe`
+	 */
+	fix?: RuleFixKind;
 	level: RulePlainConfiguration;
 	options?: PossibleOptions;
 }
+/**
+ * The kind of the fix of a rule
+ */
+export type RuleFixKind = "safe" | "unsafe";
 export type PossibleOptions =
 	| ComplexityOptions
 	| HooksOptions
@@ -1317,8 +1494,17 @@ export interface RestrictedGlobalsOptions {
 	 */
 	deniedGlobals?: string[];
 }
+/**
+ * Options for the rule `useValidAriaRole`.
+ */
 export interface ValidAriaRoleOptions {
-	allowedInvalidRoles: string[];
+	/**
+	 * A list of roles that are allowed, even if they aren't valid ARIA roles
+	 */
+	allowInvalidRoles: string[];
+	/**
+	 * If `true`, the roles of the custom components aren't checked
+	 */
 	ignoreNonDom: boolean;
 }
 export interface Hooks {
@@ -1341,6 +1527,12 @@ export interface Hooks {
  * Supported cases for TypeScript `enum` member names.
  */
 export type EnumMemberCase = "PascalCase" | "CONSTANT_CASE" | "camelCase";
+export interface CloseProjectParams {
+	/**
+	 * The directory of the project, as given to [Workspace::update_settings]
+	 */
+	project_directory: string;
+}
 export interface ProjectFeaturesParams {
 	manifest_path: RomePath;
 }
@@ -1361,6 +1553,7 @@ export type Language =
 	| "TypeScriptReact"
 	| "Json"
 	| "Jsonc"
+	| "Markdown"
 	| "Unknown";
 export interface ChangeFileParams {
 	content: string;
@@ -1397,14 +1590,37 @@ export interface GetFormatterIRParams {
 export interface PullDiagnosticsParams {
 	categories: RuleCategories;
 	max_diagnostics: number;
+	/**
+	 * When it isn't empty, only these rules are run, regardless of the configuration
+	 */
+	only?: RuleSelector[];
 	path: RomePath;
+	/**
+	 * Whether the time spent running each rule is returned
+	 */
+	rule_timings?: boolean;
 }
 export type RuleCategories = RuleCategory[];
+/**
+ * A rule selected from the CLI, using the syntax `<group>/<rule>`, e.g. `suspicious/noDebugger`
+ */
+export interface RuleSelector {
+	group: string;
+	rule: string;
+}
 export type RuleCategory = "Syntax" | "Lint" | "Action" | "Transformation";
 export interface PullDiagnosticsResult {
 	diagnostics: Diagnostic[];
 	errors: number;
+	/**
+	 * The time spent running each rule, when it was requested with `rule_timings`
+	 */
+	rule_timings?: RuleTimingEntry[];
 	skipped_diagnostics: number;
+	/**
+	 * The number of diagnostics that weren't returned because of `max_diagnostics`, by category of the diagnostic
+	 */
+	skipped_diagnostics_by_category?: {};
 }
 /**
  * Serializable representation for a [Diagnostic](super::Diagnostic).
@@ -1420,6 +1636,21 @@ export interface Diagnostic {
 	tags: DiagnosticTags;
 	verbose_advices: Advices;
 }
+/**
+ * The time spent running a rule on a file
+ */
+export interface RuleTimingEntry {
+	group: string;
+	/**
+	 * The time spent running the rule, in nanoseconds
+	 */
+	nanos: number;
+	rule: string;
+	/**
+	 * The number of times the rule ran
+	 */
+	runs: number;
+}
 /**
  * Implementation of [Visitor] collecting serializable [Advice] into a vector.
  */
@@ -1517,6 +1748,7 @@ export type Category =
 	| "lint/nursery/noDefaultExport"
 	| "lint/nursery/noDuplicateJsonKeys"
 	| "lint/nursery/noEmptyBlockStatements"
+	| "lint/nursery/noGlobalAssign"
 	| "lint/nursery/noImplicitAnyLet"
 	| "lint/nursery/noMisleadingCharacterClass"
 	| "lint/nursery/noUnusedImports"
@@ -1623,6 +1855,7 @@ export type Category =
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
+	| "analyzer/timeout"
 	| "parse"
 	| "parse/noSuperWithoutExtends"
 	| "parse/noInitializerWithDefinite"
@@ -1640,7 +1873,10 @@ export type Category =
 	| "suppressions/unknownGroup"
 	| "suppressions/unknownRule"
 	| "suppressions/unused"
+	| "suppressions/misplaced"
 	| "suppressions/deprecatedSuppressionComment"
+	| "suppressions/missingReason"
+	| "suppressions/unmatchedRange"
 	| "args/fileNotFound"
 	| "flags/invalid"
 	| "semanticTests";
@@ -1789,6 +2025,9 @@ export type Applicability = "Always" | "MaybeIncorrect";
 export interface FormatFileParams {
 	path: RomePath;
 }
+/**
+ * The result of printing a formatted document: the formatted code, and the [SourceMarker]s that map positions in the formatted code to positions in the source code.
+ */
 export interface Printed {
 	code: string;
 	range?: TextRange;
@@ -1818,13 +2057,24 @@ export interface FormatOnTypeParams {
 }
 export interface FixFileParams {
 	fix_file_mode: FixFileMode;
+	/**
+	 * When it isn't empty, only the fixes of these rules are applied, regardless of the configuration
+	 */
+	only?: RuleSelector[];
 	path: RomePath;
 	should_format: boolean;
+	/**
+	 * The explanation of the suppression comments added by [FixFileMode::ApplySuppressions]
+	 */
+	suppression_reason?: string;
 }
 /**
  * Which fixes should be applied during the analyzing phase
  */
-export type FixFileMode = "SafeFixes" | "SafeAndUnsafeFixes";
+export type FixFileMode =
+	| "SafeFixes"
+	| "SafeAndUnsafeFixes"
+	| "ApplySuppressions";
 export interface FixFileResult {
 	/**
 	 * List of all the code actions applied to the file
@@ -1853,6 +2103,204 @@ export interface FixAction {
 	 */
 	rule_name?: [string, string];
 }
+export interface PrepareRenameParams {
+	path: RomePath;
+	symbol_at: TextSize;
+}
+export interface PrepareRenameResult {
+	/**
+	 * Current name of the symbol
+	 */
+	name: string;
+	/**
+	 * Range of the symbol that can be renamed
+	 */
+	range: TextRange;
+}
+export interface FindReferencesParams {
+	path: RomePath;
+	symbol_at: TextSize;
+}
+export interface FindReferencesResult {
+	/**
+	 * The declaration of the symbol and all its references, ordered by position
+	 */
+	references: SymbolReference[];
+}
+export interface SymbolReference {
+	kind: SymbolReferenceKind;
+	range: TextRange;
+}
+export type SymbolReferenceKind = "declaration" | "read" | "write";
+export interface GotoDefinitionParams {
+	path: RomePath;
+	symbol_at: TextSize;
+}
+export interface GotoDefinitionResult {
+	/**
+	 * Where the symbol is defined, `None` if it isn't declared in the file
+	 */
+	definition?: Definition;
+}
+export type Definition =
+	| { local: { range: TextRange } }
+	| { module: { specifier: string } };
+export interface DocumentSymbolsParams {
+	path: RomePath;
+}
+export interface DocumentSymbolsResult {
+	/**
+	 * The top level symbols of the file
+	 */
+	symbols: DocumentSymbol[];
+}
+export interface DocumentSymbol {
+	/**
+	 * The symbols declared inside this symbol, like the members of a class
+	 */
+	children: DocumentSymbol[];
+	kind: SymbolKind;
+	name: string;
+	/**
+	 * Range of the whole declaration of the symbol
+	 */
+	range: TextRange;
+	/**
+	 * Range of the name of the symbol, it's contained in `range`
+	 */
+	selectionRange: TextRange;
+}
+export type SymbolKind =
+	| "class"
+	| "constant"
+	| "constructor"
+	| "enum"
+	| "enumMember"
+	| "function"
+	| "interface"
+	| "method"
+	| "property"
+	| "typeAlias"
+	| "variable"
+	| "component"
+	| "module";
+export interface SemanticTokensParams {
+	path: RomePath;
+	/**
+	 * Only the tokens that intersect this range are returned, all the tokens of the file if `None`
+	 */
+	range?: TextRange;
+}
+export interface SemanticTokensResult {
+	/**
+	 * The classified tokens, sorted by position. Each token spans a single line.
+	 */
+	tokens: SemanticToken[];
+}
+export interface SemanticToken {
+	kind: SemanticTokenKind;
+	modifiers: SemanticTokenModifier[];
+	range: TextRange;
+}
+export type SemanticTokenKind =
+	| "class"
+	| "comment"
+	| "enum"
+	| "enumMember"
+	| "function"
+	| "interface"
+	| "keyword"
+	| "method"
+	| "namespace"
+	| "number"
+	| "parameter"
+	| "property"
+	| "regexp"
+	| "string"
+	| "type"
+	| "typeParameter"
+	| "variable"
+	| "component"
+	| "jsxTag";
+export type SemanticTokenModifier = "declaration" | "readonly" | "imported";
+export interface FoldingRangesParams {
+	path: RomePath;
+}
+export interface FoldingRangesResult {
+	/**
+	 * The foldable ranges, sorted by their start
+	 */
+	ranges: FoldingRange[];
+}
+export interface FoldingRange {
+	kind: FoldingRangeKind;
+	/**
+	 * The text that is hidden when the range is folded
+	 */
+	range: TextRange;
+}
+export type FoldingRangeKind = "comment" | "code" | "imports" | "region";
+export interface InlayHintsParams {
+	/**
+	 * Show the names of the parameters before the arguments of a call
+	 */
+	parameter_names: boolean;
+	path: RomePath;
+	/**
+	 * Only the hints inside this range are returned
+	 */
+	range: TextRange;
+	/**
+	 * Show the return type of the functions without a type annotation, when it's obvious
+	 */
+	return_types: boolean;
+}
+export interface InlayHintsResult {
+	/**
+	 * The hints, sorted by position
+	 */
+	hints: InlayHint[];
+}
+export interface InlayHint {
+	kind: InlayHintKind;
+	label: string;
+	/**
+	 * The hint is displayed before the character at this position
+	 */
+	position: TextSize;
+}
+export type InlayHintKind = "parameterName" | "returnType";
+export interface IndexFileParams {
+	/**
+	 * The content of the file, `None` if the file was deleted
+	 */
+	content?: string;
+	path: RomePath;
+}
+export interface WorkspaceSymbolsParams {
+	/**
+	 * A fuzzy query matched against the name of the symbols, all the symbols match an empty query
+	 */
+	query: string;
+}
+export interface WorkspaceSymbolsResult {
+	/**
+	 * The matching symbols, the best matches come first
+	 */
+	symbols: WorkspaceSymbol[];
+}
+export interface WorkspaceSymbol {
+	kind: SymbolKind;
+	name: string;
+	/**
+	 * The file that exports the symbol
+	 */
+	path: RomePath;
+	/**
+	 * Range of the name of the symbol
+	 */
+	range: TextRange;
+}
 export interface RenameParams {
 	new_name: string;
 	path: RomePath;
@@ -1871,6 +2319,7 @@ export interface RenameResult {
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<SupportsFeatureResult>;
 	updateSettings(params: UpdateSettingsParams): Promise<void>;
+	closeProject(params: CloseProjectParams): Promise<void>;
 	projectFeatures(
 		params: ProjectFeaturesParams,
 	): Promise<ProjectFeaturesResult>;
@@ -1892,6 +2341,19 @@ export interface Workspace {
 	formatRange(params: FormatRangeParams): Promise<Printed>;
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
+	prepareRename(params: PrepareRenameParams): Promise<PrepareRenameResult>;
+	findReferences(params: FindReferencesParams): Promise<FindReferencesResult>;
+	gotoDefinition(params: GotoDefinitionParams): Promise<GotoDefinitionResult>;
+	documentSymbols(
+		params: DocumentSymbolsParams,
+	): Promise<DocumentSymbolsResult>;
+	semanticTokens(params: SemanticTokensParams): Promise<SemanticTokensResult>;
+	foldingRanges(params: FoldingRangesParams): Promise<FoldingRangesResult>;
+	inlayHints(params: InlayHintsParams): Promise<InlayHintsResult>;
+	indexFile(params: IndexFileParams): Promise<void>;
+	workspaceSymbols(
+		params: WorkspaceSymbolsParams,
+	): Promise<WorkspaceSymbolsResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
 	destroy(): void;
 }
@@ -1903,6 +2365,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		updateSettings(params) {
 			return transport.request("biome/update_settings", params);
 		},
+		closeProject(params) {
+			return transport.request("biome/close_project", params);
+		},
 		projectFeatures(params) {
 			return transport.request("biome/project_features", params);
 		},
@@ -1948,6 +2413,33 @@ export function createWorkspace(transport: Transport): Workspace {
 		fixFile(params) {
 			return transport.request("biome/fix_file", params);
 		},
+		prepareRename(params) {
+			return transport.request("biome/prepare_rename", params);
+		},
+		findReferences(params) {
+			return transport.request("biome/find_references", params);
+		},
+		gotoDefinition(params) {
+			return transport.request("biome/goto_definition", params);
+		},
+		documentSymbols(params) {
+			return transport.request("biome/document_symbols", params);
+		},
+		semanticTokens(params) {
+			return transport.request("biome/semantic_tokens", params);
+		},
+		foldingRanges(params) {
+			return transport.request("biome/folding_ranges", params);
+		},
+		inlayHints(params) {
+			return transport.request("biome/inlay_hints", params);
+		},
+		indexFile(params) {
+			return transport.request("biome/index_file", params);
+		},
+		workspaceSymbols(params) {
+			return transport.request("biome/workspace_symbols", params);
+		},
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
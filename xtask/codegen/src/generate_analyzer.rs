@@ -15,24 +15,38 @@ pub fn generate_analyzer() -> Result<()> {
 
 fn generate_js_analyzer() -> Result<()> {
     let base_path = project_root().join("crates/biome_js_analyze/src");
+    // The lint rule groups can be compiled out with the features of the same name
+    let lint_language = Some(quote! { biome_js_syntax::JsLanguage });
+
     let mut analyzers = BTreeMap::new();
-    generate_category("analyzers", &mut analyzers, base_path.clone())?;
+    generate_category(
+        "analyzers",
+        &mut analyzers,
+        base_path.clone(),
+        lint_language.clone(),
+    )?;
 
     let mut semantic_analyzers = BTreeMap::new();
     generate_category(
         "semantic_analyzers",
         &mut semantic_analyzers,
         base_path.clone(),
+        lint_language.clone(),
     )?;
 
     let mut aria_analyzers = BTreeMap::new();
-    generate_category("aria_analyzers", &mut aria_analyzers, base_path.clone())?;
+    generate_category(
+        "aria_analyzers",
+        &mut aria_analyzers,
+        base_path.clone(),
+        lint_language,
+    )?;
 
     let mut assists = BTreeMap::new();
-    generate_category("assists", &mut assists, base_path.clone())?;
+    generate_category("assists", &mut assists, base_path.clone(), None)?;
 
     let mut syntax = BTreeMap::new();
-    generate_category("syntax", &mut syntax, base_path)?;
+    generate_category("syntax", &mut syntax, base_path, None)?;
 
     update_js_registry_builder(
         analyzers,
@@ -49,15 +63,21 @@ fn generate_json_analyzer() -> Result<()> {
         "analyzers",
         &mut analyzers,
         project_root().join("crates/biome_json_analyze/src"),
+        None,
     )?;
 
     update_json_registry_builder(analyzers)
 }
 
+/// Generates the module of the category `name` and the modules of its groups.
+///
+/// When the `language` of the category is given, each group is only compiled when the feature
+/// of the same name is enabled.
 fn generate_category(
     name: &'static str,
     entries: &mut BTreeMap<&'static str, TokenStream>,
     base_path: PathBuf,
+    language: Option<TokenStream>,
 ) -> Result<()> {
     let path = base_path.join(name);
 
@@ -79,15 +99,19 @@ fn generate_category(
 
         let module_name = format_ident!("{}", file_name);
         let group_name = format_ident!("{}", to_pascal_case(file_name)?);
+        let cfg = language
+            .is_some()
+            .then(|| quote! { #[cfg(feature = #file_name)] });
 
         groups.insert(
             file_name.to_string(),
             (
                 quote! {
+                   #cfg
                    pub(crate) mod #module_name;
                 },
                 quote! {
-                    self::#module_name::#group_name
+                    #cfg self::#module_name::#group_name
                 },
             ),
         );
@@ -114,11 +138,13 @@ fn generate_category(
     );
 
     let (modules, paths): (Vec<_>, Vec<_>) = groups.into_values().unzip();
+    let language = language.map(|language| quote! { language: #language, });
     let tokens = xtask::reformat(quote! {
         #( #modules )*
         ::biome_analyze::declare_category! {
             pub(crate) #category_name {
                 kind: #kind,
+                #language
                 groups: [
                     #( #paths, )*
                 ]
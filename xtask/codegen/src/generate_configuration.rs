@@ -113,7 +113,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
 
         group_as_default_rules.push(quote! {
             if let Some(group) = self.#property_group_name.as_ref() {
-                group.collect_preset_rules(self.is_recommended(), &mut enabled_rules, &mut disabled_rules);
+                group.collect_preset_rules(self.all, self.is_recommended(), &mut enabled_rules, &mut disabled_rules);
                 enabled_rules.extend(&group.get_enabled_rules());
                 disabled_rules.extend(&group.get_disabled_rules());
             } else if self.is_all() {
@@ -135,8 +135,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
             #group => self
                 .#property_group_name
                 .as_ref()
-                .and_then(|#property_group_name| #property_group_name.get_rule_configuration(rule_name))
-                .map(|rule_setting| rule_setting.into())
+                .and_then(|#property_group_name| #property_group_name.get_rule_severity(rule_name))
                 .unwrap_or_else(|| {
                     if #group_struct_name::is_recommended_rule(rule_name) {
                         Severity::Error
@@ -161,6 +160,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         #[cfg(feature = "schema")]
         use schemars::JsonSchema;
         use crate::{MergeWith, RuleConfiguration};
+        use crate::configuration::linter::RulePlainConfiguration;
         use biome_analyze::RuleFilter;
         use indexmap::IndexSet;
         use bpaf::Bpaf;
@@ -533,12 +533,12 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
     let (group_recommended, parent_parameter) = if group == "nursery" {
         (
             quote! { self.is_recommended() },
-            quote! { _parent_is_recommended: bool, },
+            quote! { parent_is_all: Option<bool>, _parent_is_recommended: bool, },
         )
     } else {
         (
             quote! { parent_is_recommended || self.is_recommended() },
-            quote! { parent_is_recommended: bool, },
+            quote! { parent_is_all: Option<bool>, parent_is_recommended: bool, },
         )
     };
     quote! {
@@ -557,11 +557,20 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
             #[bpaf(hide)]
             pub all: Option<bool>,
 
+            /// It enables ALL rules for this group with the given severity, or disables them with `"off"`.
+            /// The rules configured individually keep their own severity.
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[bpaf(hide)]
+            pub level: Option<RulePlainConfiguration>,
+
             #( #schema_lines_rules ),*
         }
 
         impl MergeWith<#group_struct_name> for #group_struct_name {
             fn merge_with(&mut self, other: #group_struct_name) {
+                if let Some(level) = other.level {
+                    self.level = Some(level);
+                }
                 #( #merge_with_lines_rules )*
             }
 
@@ -601,12 +610,17 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
                 matches!(self.recommended, Some(false))
             }
 
-            pub(crate) fn is_all(&self) -> bool {
-                matches!(self.all, Some(true))
-            }
-
-            pub(crate) fn is_not_all(&self) -> bool {
-                matches!(self.all, Some(false))
+            /// Returns `Some(true)` when all the rules of this group are enabled, `Some(false)` when
+            /// they are all disabled, and [None] when the group doesn't decide.
+            ///
+            /// The level of the group takes precedence over its `all`, and the `all` of the linter
+            /// applies only when the group doesn't select its rules.
+            pub(crate) fn is_all(&self, parent_is_all: Option<bool>) -> Option<bool> {
+                self.level
+                    .as_ref()
+                    .map(|level| *level != RulePlainConfiguration::Off)
+                    .or(self.all)
+                    .or(parent_is_all.filter(|_| self.recommended.is_none()))
             }
 
             pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
@@ -646,12 +660,13 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
                 enabled_rules: &mut IndexSet<RuleFilter>,
                 disabled_rules: &mut IndexSet<RuleFilter>,
             ) {
-                if self.is_all() {
+                let is_all = self.is_all(parent_is_all);
+                if is_all == Some(true) {
                     enabled_rules.extend(Self::all_rules_as_filters());
                 } else if #group_recommended {
                     enabled_rules.extend(Self::recommended_rules_as_filters());
                 }
-                if self.is_not_all() {
+                if is_all == Some(false) {
                     disabled_rules.extend(Self::all_rules_as_filters());
                 } else if self.is_not_recommended() {
                     disabled_rules.extend(Self::recommended_rules_as_filters());
@@ -664,13 +679,24 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
                     _ => None
                 }
             }
+
+            /// Returns the severity of the rule, from its own configuration or from the level of the group
+            pub(crate) fn get_rule_severity(&self, rule_name: &str) -> Option<Severity> {
+                self.get_rule_configuration(rule_name)
+                    .map(Severity::from)
+                    .or_else(|| self.level.as_ref().map(Severity::from))
+            }
         }
     }
 }
 
 fn generate_visitor(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) -> TokenStream {
     let group_struct_name = Ident::new(&group.to_capitalized(), Span::call_site());
-    let mut group_rules = vec![Literal::string("recommended"), Literal::string("all")];
+    let mut group_rules = vec![
+        Literal::string("recommended"),
+        Literal::string("all"),
+        Literal::string("level"),
+    ];
     let mut visitor_rule_line = Vec::new();
 
     for rule_name in rules.keys() {
@@ -693,7 +719,20 @@ fn generate_visitor(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) -
                 struct Visitor;
                 impl DeserializationVisitor for Visitor  {
                     type Output =#group_struct_name;
-                    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+                    const EXPECTED_TYPE: VisitableType = VisitableType::STR.union(VisitableType::MAP);
+                    fn visit_str(
+                        self,
+                        value: Text,
+                        range: TextRange,
+                        _name: &str,
+                        diagnostics: &mut Vec<DeserializationDiagnostic>,
+                    ) -> Option<Self::Output> {
+                        let level = RulePlainConfiguration::deserialize_from_str(value, range, diagnostics)?;
+                        Some(Self::Output {
+                            level: Some(level),
+                            ..Self::Output::default()
+                        })
+                    }
                     fn visit_map(
                         self,
                         members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
@@ -715,6 +754,9 @@ fn generate_visitor(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) -
                                 "all" => {
                                     result.all = Deserializable::deserialize(&value, &key_text, diagnostics);
                                 }
+                                "level" => {
+                                    result.level = Deserializable::deserialize(&value, &key_text, diagnostics);
+                                }
                                 #( #visitor_rule_line ),*,
                                 unknown_key => {
                                     diagnostics.push(DeserializationDiagnostic::new_unknown_key(
@@ -752,10 +794,13 @@ fn generate_push_to_analyzer_rules(group: &str) -> TokenStream {
                 if let Some(RuleConfiguration::WithOptions(rule_options)) =
                     rules.get_rule_configuration(rule_name)
                 {
-                    if let Some(possible_options) = &rule_options.options {
-                        if let Some(rule_key) = metadata.find_rule(#group, rule_name) {
-                        let rule_options = possible_options.extract_option(&rule_key);
-                        analyzer_rules.push_rule(rule_key, rule_options);
+                    if let Some(rule_key) = metadata.find_rule(#group, rule_name) {
+                        if let Some(possible_options) = &rule_options.options {
+                            let rule_options = possible_options.extract_option(&rule_key);
+                            analyzer_rules.push_rule(rule_key, rule_options);
+                        }
+                        if let Some(fix) = rule_options.fix {
+                            analyzer_rules.push_rule_applicability(rule_key, fix.into());
                         }
                     }
                 }
@@ -1,15 +1,23 @@
+use biome_js_analyze::options::PossibleOptions;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::{parse_json, JsonParserOptions};
-use biome_service::Configuration;
-use schemars::schema_for;
+use biome_service::configuration::linter::{RuleFixKind, RulePlainConfiguration};
+use biome_service::{Configuration, RuleConfiguration, Rules};
+use schemars::schema::{
+    InstanceType, Metadata, ObjectValidation, RootSchema, Schema, SchemaObject, SubschemaValidation,
+};
+use schemars::{schema_for, JsonSchema};
 use serde_json::to_string;
+use std::collections::BTreeMap;
 use xtask::{project_root, Mode, Result};
 use xtask_codegen::update;
 
 pub(crate) fn generate_configuration_schema(mode: Mode) -> Result<()> {
     let schema_path_npm = project_root().join("packages/@biomejs/biome/configuration_schema.json");
 
-    let schema = schema_for!(Configuration);
+    let mut schema = schema_for!(Configuration);
+    add_rule_options(&mut schema)?;
+    add_group_levels(&mut schema);
     let json_schema = to_string(&schema)?;
 
     let parsed = parse_json(&json_schema, JsonParserOptions::default());
@@ -23,3 +31,164 @@ pub(crate) fn generate_configuration_schema(mode: Mode) -> Result<()> {
 
     Ok(())
 }
+
+/// The rules that accept options reference a configuration that only accepts their own options,
+/// instead of the options of any rule, e.g. `HooksConfiguration` for `useExhaustiveDependencies`
+fn add_rule_options(schema: &mut RootSchema) -> Result<()> {
+    let rule_configuration = reference(&RuleConfiguration::schema_name());
+    let mut rule_definitions = BTreeMap::new();
+    for definition in schema.definitions.values_mut() {
+        let Schema::Object(SchemaObject {
+            object: Some(object),
+            ..
+        }) = definition
+        else {
+            continue;
+        };
+        for (rule_name, rule_schema) in &mut object.properties {
+            let Some(default_options) = PossibleOptions::default_for_rule(rule_name) else {
+                continue;
+            };
+            let Schema::Object(SchemaObject {
+                subschemas: Some(subschemas),
+                ..
+            }) = rule_schema
+            else {
+                continue;
+            };
+            let Some(configuration) = subschemas
+                .any_of
+                .iter_mut()
+                .flatten()
+                .find(|schema| **schema == rule_configuration)
+            else {
+                continue;
+            };
+
+            let options_name = match &default_options {
+                PossibleOptions::Complexity(options) => schema_name_of(options),
+                PossibleOptions::Hooks(options) => schema_name_of(options),
+                PossibleOptions::NamingConvention(options) => schema_name_of(options),
+                PossibleOptions::RestrictedGlobals(options) => schema_name_of(options),
+                PossibleOptions::ValidAriaRole(options) => schema_name_of(options),
+            };
+            let name = options_name.trim_end_matches("Options");
+            let configuration_name = format!("{name}Configuration");
+            let with_options_name = format!("RuleWith{name}Options");
+
+            *configuration = reference(&configuration_name);
+            rule_definitions.insert(
+                configuration_name,
+                Schema::Object(SchemaObject {
+                    subschemas: Some(Box::new(SubschemaValidation {
+                        any_of: Some(vec![
+                            reference(&RulePlainConfiguration::schema_name()),
+                            reference(&with_options_name),
+                        ]),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }),
+            );
+            rule_definitions.insert(
+                with_options_name,
+                rule_with_options(&options_name, serde_json::to_value(&default_options)?),
+            );
+        }
+    }
+    schema.definitions.extend(rule_definitions);
+    Ok(())
+}
+
+/// The groups of rules also accept a severity for all their rules, e.g. `"suspicious": "warn"`
+fn add_group_levels(schema: &mut RootSchema) {
+    let Some(Schema::Object(SchemaObject {
+        object: Some(rules),
+        ..
+    })) = schema.definitions.get_mut(&Rules::schema_name())
+    else {
+        return;
+    };
+    // Only the groups are described with subschemas, `all` and `recommended` are booleans
+    for group_schema in rules.properties.values_mut() {
+        if let Schema::Object(SchemaObject {
+            subschemas: Some(subschemas),
+            ..
+        }) = group_schema
+        {
+            if let Some(any_of) = &mut subschemas.any_of {
+                any_of.insert(0, reference(&RulePlainConfiguration::schema_name()));
+            }
+        }
+    }
+}
+
+/// The description of the `fix` field of `RuleWithOptions`
+const FIX_DESCRIPTION: &str = "Overrides the kind of the fix of the rule: a safe fix is applied by `--apply`, while an unsafe fix is only applied by `--apply-unsafe`";
+
+/// The schema of `RuleWithOptions`, restricted to the options `options_name` and documenting
+/// their default value
+fn rule_with_options(options_name: &str, default_options: serde_json::Value) -> Schema {
+    let mut object = ObjectValidation {
+        additional_properties: Some(Box::new(Schema::Bool(false))),
+        ..Default::default()
+    };
+    object.required.insert("level".to_string());
+    object.properties.insert(
+        "level".to_string(),
+        reference(&RulePlainConfiguration::schema_name()),
+    );
+    object.properties.insert(
+        "fix".to_string(),
+        Schema::Object(SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some(FIX_DESCRIPTION.to_string()),
+                ..Default::default()
+            })),
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(vec![
+                    reference(&RuleFixKind::schema_name()),
+                    Schema::Object(SchemaObject {
+                        instance_type: Some(InstanceType::Null.into()),
+                        ..Default::default()
+                    }),
+                ]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }),
+    );
+    object.properties.insert(
+        "options".to_string(),
+        Schema::Object(SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                default: Some(default_options),
+                ..Default::default()
+            })),
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(vec![
+                    reference(options_name),
+                    Schema::Object(SchemaObject {
+                        instance_type: Some(InstanceType::Null.into()),
+                        ..Default::default()
+                    }),
+                ]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }),
+    );
+    Schema::Object(SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        object: Some(Box::new(object)),
+        ..Default::default()
+    })
+}
+
+fn schema_name_of<T: JsonSchema>(_: &T) -> String {
+    T::schema_name()
+}
+
+fn reference(definition: &str) -> Schema {
+    Schema::new_ref(format!("#/definitions/{definition}"))
+}